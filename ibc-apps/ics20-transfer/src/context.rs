@@ -1,8 +1,15 @@
 //! Defines the main context traits and IBC module callbacks
 
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::authorization::TransferAuthorization;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_app_transfer_types::policy::DenomPolicy;
+use ibc_app_transfer_types::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::Version;
 use ibc_core::host::types::error::HostError;
-use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 
@@ -20,12 +27,35 @@ pub trait TokenTransferValidationContext {
     /// get_port returns the portID for the transfer module.
     fn get_port(&self) -> Result<PortId, HostError>;
 
+    /// Returns the ICS-20 version negotiated on the given channel, without
+    /// the metadata of the middleware wrapping the token transfer
+    /// application, if any. The data of the packets sent over the channel
+    /// is decoded according to this version.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, HostError>;
+
     /// Returns Ok() if the host chain supports sending coins.
     fn can_send_coins(&self) -> Result<(), HostError>;
 
     /// Returns Ok() if the host chain supports receiving coins.
     fn can_receive_coins(&self) -> Result<(), HostError>;
 
+    /// Returns the policy restricting the denominations of the tokens sent and
+    /// received over the given channel, if any.
+    ///
+    /// Implement only if the host chain restricts the denominations it
+    /// transfers.
+    fn denom_policy(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Option<DenomPolicy>, HostError> {
+        Ok(None)
+    }
+
     /// Validates that the tokens can be escrowed successfully.
     ///
     /// `memo` field allows incorporating additional contextual details in the
@@ -66,6 +96,15 @@ pub trait TokenTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), HostError>;
 
+    /// Returns the total amount of tokens of the given denomination held in
+    /// escrow over all the channels.
+    ///
+    /// Tokens can only be unescrowed up to this total. Hosts which escrowed
+    /// tokens before tracking it must therefore seed it with the balances of
+    /// their escrow accounts, through `store_total_escrow_for_denom`, as the
+    /// ibc-go v7.1 migration does.
+    fn total_escrow_for_denom(&self, denom: &PrefixedDenom) -> Result<Amount, HostError>;
+
     /// Returns a hash of the prefixed denom.
     /// Implement only if the host chain supports hashed denominations.
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
@@ -73,8 +112,55 @@ pub trait TokenTransferValidationContext {
     }
 }
 
+/// Methods required to keep track of the denominations of the vouchers minted
+/// by the token transfer application, to be implemented by the host.
+///
+/// The traces let the hashed denominations, as returned by
+/// [`TokenTransferValidationContext::denom_hash_string`], be resolved back
+/// to their full path. Hosts which do not hash denominations never get any
+/// trace to store.
+pub trait DenomTraceContext {
+    /// Returns the denomination whose hash is the given one, if its trace was
+    /// stored.
+    fn denom_trace_by_hash(&self, hash: &str) -> Result<Option<PrefixedDenom>, HostError>;
+
+    /// Stores the trace of the given denomination under its hash.
+    fn store_denom_trace(&mut self, hash: String, denom: PrefixedDenom) -> Result<(), HostError>;
+}
+
+/// Methods required to let an account send tokens on behalf of another one,
+/// which granted it a [`TransferAuthorization`], to be implemented by the host.
+pub trait TransferAuthorizationContext {
+    /// Returns the transfer authorization granted by the granter to the
+    /// grantee, if any.
+    fn transfer_authorization(
+        &self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<Option<TransferAuthorization>, HostError>;
+
+    /// Stores the transfer authorization granted by the granter to the
+    /// grantee, which replaces the previous one.
+    fn store_transfer_authorization(
+        &mut self,
+        granter: Signer,
+        grantee: Signer,
+        authorization: TransferAuthorization,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the transfer authorization granted by the granter to the
+    /// grantee, once it is used up.
+    fn delete_transfer_authorization(
+        &mut self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<(), HostError>;
+}
+
 /// Methods required in token transfer execution, to be implemented by the host.
-pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
+pub trait TokenTransferExecutionContext:
+    TokenTransferValidationContext + DenomTraceContext
+{
     /// Executes the escrow of the tokens in a user account.
     ///
     /// `memo` field allows incorporating additional contextual details in the
@@ -114,4 +200,58 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), HostError>;
+
+    /// Stores the total amount of tokens of the given denomination held in
+    /// escrow, which the handlers update as they escrow and unescrow tokens.
+    fn store_total_escrow_for_denom(
+        &mut self,
+        denom: &PrefixedDenom,
+        amount: Amount,
+    ) -> Result<(), HostError>;
+}
+
+/// Methods required to forward the tokens of the packets which carry ICS-20
+/// v2 forwarding hops, to be implemented by the hosts supporting it.
+pub trait TokenForwardingExecutionContext: TokenTransferExecutionContext {
+    /// Returns the account which receives the tokens sent over the given
+    /// channel, before they are forwarded.
+    ///
+    /// The account must not be controlled by anyone, as it holds the tokens
+    /// until the forwarded packet is acknowledged or times out.
+    fn forwarding_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, HostError>;
+
+    /// Returns the received packet whose tokens were forwarded with the given
+    /// packet, if the latter has not been acknowledged or timed out yet.
+    fn forwarded_packet(&self, packet_id: &PacketId) -> Result<Option<Packet>, HostError>;
+
+    /// Stores the received packet whose tokens were forwarded with the given
+    /// packet.
+    fn store_forwarded_packet(
+        &mut self,
+        packet_id: PacketId,
+        packet: Packet,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the received packet whose tokens were forwarded with the given
+    /// packet, once the latter is acknowledged or timed out.
+    fn delete_forwarded_packet(&mut self, packet_id: &PacketId) -> Result<(), HostError>;
+
+    /// Sends the given token transfer, and returns the sequence of its
+    /// packet.
+    ///
+    /// This is usually done with the `send_transfer` handler, passing it the
+    /// module stack bound to the transfer port.
+    fn send_transfer(&mut self, msg: MsgTransfer) -> Result<Sequence, TokenTransferError>;
+
+    /// Writes the acknowledgement of a received packet, which was held while
+    /// its tokens were being forwarded.
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), HostError>;
 }
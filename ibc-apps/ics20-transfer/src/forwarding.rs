@@ -0,0 +1,269 @@
+//! Implements the forwarding of the tokens received along with ICS-20 v2
+//! forwarding hops.
+//!
+//! The callbacks of this module replace the receive, acknowledgement and
+//! timeout execution callbacks of the [`module`](crate::module) for the hosts
+//! supporting forwarding. The tokens of a packet to forward are received by
+//! the forwarding account, and sent on over the next hop. The acknowledgement
+//! of the received packet is only written once the forwarded packet is
+//! acknowledged or times out, so that the origin gets a single
+//! acknowledgement for the whole path.
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::events::RecvEvent;
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{ack_success_b64, Forwarding, Memo, PrefixedCoin};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::TokenForwardingExecutionContext;
+use crate::handler::{
+    decode_recv_packet_data, escrow_execute, process_recv_packet_execute, received_coin,
+};
+use crate::module;
+
+/// Receives the tokens of the packet, and forwards them over the next hop if
+/// the packet carries forwarding hops, in which case no acknowledgement is
+/// returned until the forwarded packet is acknowledged or times out.
+pub fn on_recv_packet_execute(
+    ctx_b: &mut impl TokenForwardingExecutionContext,
+    packet: &Packet,
+) -> (ModuleExtras, Option<Acknowledgement>) {
+    let data = match decode_recv_packet_data(ctx_b, packet) {
+        Ok(data) if !data.forwarding.is_empty() => data,
+        _ => {
+            let (extras, ack) = module::on_recv_packet_execute(ctx_b, packet);
+            return (extras, Some(ack));
+        }
+    };
+
+    let (mut extras, result) = forward_packet_execute(ctx_b, packet, &data);
+
+    for token in data.tokens {
+        let coin = PrefixedCoin::from(token);
+
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: coin.denom,
+            amount: coin.amount,
+            memo: data.forwarding.destination_memo.clone(),
+            success: result.is_ok(),
+        };
+        extras.events.push(recv_event.into());
+    }
+
+    match result {
+        Ok(()) => (extras, None),
+        Err(err) => (
+            extras,
+            Some(AcknowledgementStatus::error(err.into()).into()),
+        ),
+    }
+}
+
+/// Refunds the tokens of the packet if it failed, and then writes the
+/// acknowledgement of the received packet whose tokens it forwarded, if any.
+///
+/// The tokens received for a failed forwarding are returned, so that the
+/// origin can be refunded upon the error acknowledgement.
+pub fn on_acknowledgement_packet_execute(
+    ctx: &mut impl TokenForwardingExecutionContext,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), TokenTransferError>) {
+    let (mut extras, result) =
+        module::on_acknowledgement_packet_execute(ctx, packet, acknowledgement, relayer);
+
+    if result.is_err() {
+        return (extras, result);
+    }
+
+    let successful = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+        .is_ok_and(|status| status.is_successful());
+
+    // The success acknowledgement of the forwarded packet is not passed on,
+    // as it is specific to the last hop.
+    let acknowledgement = if successful {
+        AcknowledgementStatus::success(ack_success_b64()).into()
+    } else {
+        acknowledgement.clone()
+    };
+
+    if let Err(err) = settle_forwarded_packet(ctx, packet, acknowledgement, successful) {
+        extras.log.push(format!(
+            "IBC token forwarding: failed to acknowledge the received packet: {err}"
+        ));
+    }
+
+    (extras, Ok(()))
+}
+
+/// Refunds the tokens of the packet, and then writes an error
+/// acknowledgement for the received packet whose tokens it forwarded, if any.
+pub fn on_timeout_packet_execute(
+    ctx: &mut impl TokenForwardingExecutionContext,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), TokenTransferError>) {
+    let (mut extras, result) = module::on_timeout_packet_execute(ctx, packet, relayer);
+
+    if result.is_err() {
+        return (extras, result);
+    }
+
+    let error = TokenTransferError::ForwardedPacketTimeout {
+        port_id: packet.port_id_on_a.clone(),
+        channel_id: packet.chan_id_on_a.clone(),
+    };
+
+    if let Err(err) = settle_forwarded_packet(
+        ctx,
+        packet,
+        AcknowledgementStatus::error(error.into()).into(),
+        false,
+    ) {
+        extras.log.push(format!(
+            "IBC token forwarding: failed to acknowledge the received packet: {err}"
+        ));
+    }
+
+    (extras, Ok(()))
+}
+
+/// Receives the tokens of the packet into the forwarding account, and sends
+/// them over the next hop along with the remaining ones.
+fn forward_packet_execute(
+    ctx_b: &mut impl TokenForwardingExecutionContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> (ModuleExtras, Result<(), TokenTransferError>) {
+    let Some((next_hop, hops)) = data.forwarding.hops.split_first() else {
+        return (ModuleExtras::empty(), Ok(()));
+    };
+
+    let forwarding_account =
+        match ctx_b.forwarding_account(&packet.port_id_on_b, &packet.chan_id_on_b) {
+            Ok(account) => account,
+            Err(err) => return (ModuleExtras::empty(), Err(err.into())),
+        };
+
+    let received_data = PacketDataV2 {
+        receiver: forwarding_account.clone(),
+        forwarding: Default::default(),
+        ..data.clone()
+    };
+
+    let mut extras = match process_recv_packet_execute(ctx_b, packet, received_data) {
+        Ok(extras) => extras,
+        Err((extras, err)) => return (extras, Err(err)),
+    };
+
+    let msg = MsgTransfer {
+        port_id_on_a: next_hop.port_id.clone(),
+        chan_id_on_a: next_hop.channel_id.clone(),
+        packet_data: PacketDataV2 {
+            tokens: data
+                .tokens
+                .iter()
+                .map(|token| received_coin(packet, token.clone()).0.into())
+                .collect(),
+            sender: forwarding_account,
+            receiver: data.receiver.clone(),
+            memo: data.forwarding.destination_memo.clone(),
+            forwarding: Default::default(),
+        },
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: packet.timeout_timestamp_on_b,
+        forwarding: Forwarding::new(false, hops.to_vec()),
+    };
+
+    let result = ctx_b.send_transfer(msg).and_then(|sequence| {
+        let packet_id = PacketId::new(
+            next_hop.port_id.clone(),
+            next_hop.channel_id.clone(),
+            sequence,
+        );
+
+        Ok(ctx_b.store_forwarded_packet(packet_id, packet.clone())?)
+    });
+
+    if let Err(err) = result {
+        if let Err(revert_err) = revert_received_tokens(ctx_b, packet, data) {
+            extras.log.push(format!(
+                "IBC token forwarding: failed to revert the received tokens: {revert_err}"
+            ));
+        }
+
+        return (extras, Err(err));
+    }
+
+    (extras, Ok(()))
+}
+
+/// Writes the acknowledgement of the received packet whose tokens were
+/// forwarded with the given packet, if any, after reverting the reception of
+/// its tokens if the forwarding failed.
+fn settle_forwarded_packet(
+    ctx: &mut impl TokenForwardingExecutionContext,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+    successful: bool,
+) -> Result<(), TokenTransferError> {
+    let packet_id = PacketId::new(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+    );
+
+    let Some(received_packet) = ctx.forwarded_packet(&packet_id)? else {
+        return Ok(());
+    };
+
+    if !successful {
+        let data = decode_recv_packet_data(ctx, &received_packet)?;
+        revert_received_tokens(ctx, &received_packet, &data)?;
+    }
+
+    ctx.delete_forwarded_packet(&packet_id)?;
+    ctx.write_acknowledgement(&received_packet, acknowledgement)?;
+
+    Ok(())
+}
+
+/// Takes the tokens of the received packet back from the forwarding account:
+/// the tokens unescrowed are escrowed again, and the vouchers minted are
+/// burned.
+fn revert_received_tokens<Ctx: TokenForwardingExecutionContext>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<(), TokenTransferError> {
+    let forwarding_account = ctx.forwarding_account(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+    let account = ctx.receiver_account(&forwarding_account)?;
+    let memo = Memo::default();
+
+    for token in &data.tokens {
+        let (coin, unescrowed) = received_coin(packet, token.clone());
+
+        if unescrowed {
+            escrow_execute(
+                ctx,
+                &account,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &coin,
+                &memo,
+            )?;
+        } else {
+            ctx.burn_coins_execute(&account, &coin, &memo)?;
+        }
+    }
+
+    Ok(())
+}
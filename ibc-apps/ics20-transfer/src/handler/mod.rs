@@ -1,39 +1,225 @@
 //! Implements the processing logic for ICS20 (token transfer) message.
 mod on_recv_packet;
+mod send_authorized_transfer;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
-use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::packet::{PacketData, PacketDataV2};
+use ibc_app_transfer_types::{is_sender_chain_source, Memo, PrefixedCoin, VERSION_V2};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Protobuf;
 pub use on_recv_packet::*;
+pub use send_authorized_transfer::*;
 pub use send_transfer::*;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
+/// Decodes the data of a token transfer packet sent over a channel with the
+/// given version.
+///
+/// The data is Protobuf encoded on ICS-20 v2 channels, and JSON encoded
+/// otherwise. ICS-20 v1 packet data is converted to its ICS-20 v2
+/// counterpart so that both versions are processed alike.
+pub fn decode_packet_data(
+    version: &Version,
+    data: &[u8],
+) -> Result<PacketDataV2, TokenTransferError> {
+    if version.as_str() == VERSION_V2 {
+        return PacketDataV2::decode_vec(data)
+            .map_err(|_| TokenTransferError::FailedToDeserializePacketData);
+    }
+
+    serde_json::from_slice::<PacketData>(data)
+        .map(Into::into)
+        .map_err(|_| TokenTransferError::FailedToDeserializePacketData)
+}
+
+/// Decodes the data of a packet received by the host, according to the
+/// version of the channel it was received over.
+pub fn decode_recv_packet_data(
+    ctx_b: &impl TokenTransferValidationContext,
+    packet: &Packet,
+) -> Result<PacketDataV2, TokenTransferError> {
+    let version = ctx_b.channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+
+    decode_packet_data(&version, &packet.data)
+}
+
+/// Decodes the data of a packet sent by the host, according to the version
+/// of the channel it was sent over.
+pub fn decode_sent_packet_data(
+    ctx_a: &impl TokenTransferValidationContext,
+    packet: &Packet,
+) -> Result<PacketDataV2, TokenTransferError> {
+    let version = ctx_a.channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+    decode_packet_data(&version, &packet.data)
+}
+
+/// Encodes the data of a token transfer packet sent over a channel with the
+/// given version.
+///
+/// The data is Protobuf encoded on ICS-20 v2 channels, and JSON encoded
+/// otherwise, in which case it must carry a single token and no forwarding
+/// hops.
+pub fn encode_packet_data(
+    version: &Version,
+    data: PacketDataV2,
+) -> Result<Vec<u8>, TokenTransferError> {
+    if version.as_str() == VERSION_V2 {
+        return Ok(data.encode_vec());
+    }
+
+    if !data.forwarding.is_empty() {
+        return Err(TokenTransferError::UnsupportedForwarding(version.clone()));
+    }
+
+    let count = data.tokens.len();
+    let data =
+        PacketData::try_from(data).map_err(|_| TokenTransferError::UnsupportedTokenCount {
+            version: version.clone(),
+            count,
+        })?;
+
+    Ok(serde_json::to_vec(&data).expect("PacketData's infallible Serialize impl failed"))
+}
+
+/// Returns whether the acknowledgement of a token transfer packet reports a
+/// success.
+///
+/// Acknowledgements which do not use the standard format are considered
+/// successful, which is what middleware wrapping applications with their own
+/// format expect.
+pub fn is_ack_successful(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_bytes())
+        .map(|status| status.is_successful())
+        .unwrap_or(true)
+}
+
+/// Checks the denominations of the coins against the policy of the channel
+/// they are transferred over, if any.
+fn check_denom_policy<'a>(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coins: impl IntoIterator<Item = &'a PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let Some(policy) = ctx.denom_policy(port_id, channel_id)? else {
+        return Ok(());
+    };
+
+    for coin in coins {
+        policy.check(&coin.denom)?;
+    }
+
+    Ok(())
+}
+
+/// Escrows the coin, and adds it to the total escrow of its denomination.
+pub fn escrow_execute<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    from_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+    memo: &Memo,
+) -> Result<(), TokenTransferError> {
+    ctx.escrow_coins_execute(from_account, port_id, channel_id, coin, memo)?;
+
+    let total_escrow = ctx
+        .total_escrow_for_denom(&coin.denom)?
+        .checked_add(coin.amount)
+        .ok_or_else(|| TokenTransferError::TotalEscrowOverflow(coin.denom.clone()))?;
+
+    ctx.store_total_escrow_for_denom(&coin.denom, total_escrow)?;
+
+    Ok(())
+}
+
+/// Validates that the total escrow of each denomination covers the coins to
+/// be unescrowed, the coins of the same denomination being summed up.
+pub fn unescrow_total_validate<'a>(
+    ctx: &impl TokenTransferValidationContext,
+    coins: impl IntoIterator<Item = &'a PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let mut remaining = BTreeMap::new();
+
+    for coin in coins {
+        let escrowed = match remaining.remove(&coin.denom) {
+            Some(escrowed) => escrowed,
+            None => ctx.total_escrow_for_denom(&coin.denom)?,
+        };
+
+        let rest = escrowed.checked_sub(coin.amount).ok_or_else(|| {
+            TokenTransferError::InsufficientEscrow {
+                denom: coin.denom.clone(),
+                amount: coin.amount,
+                escrowed,
+            }
+        })?;
+
+        remaining.insert(coin.denom.clone(), rest);
+    }
+
+    Ok(())
+}
+
+/// Unescrows the coin, and subtracts it from the total escrow of its
+/// denomination.
+pub fn unescrow_execute<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    to_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let escrowed = ctx.total_escrow_for_denom(&coin.denom)?;
+
+    let total_escrow = escrowed.checked_sub(coin.amount).ok_or_else(|| {
+        TokenTransferError::InsufficientEscrow {
+            denom: coin.denom.clone(),
+            amount: coin.amount,
+            escrowed,
+        }
+    })?;
+
+    ctx.unescrow_coins_execute(to_account, port_id, channel_id, coin)?;
+    ctx.store_total_escrow_for_denom(&coin.denom, total_escrow)?;
+
+    Ok(())
+}
+
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = ctx_a.sender_account(&data.sender)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_execute(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )?;
-    }
-    // mint vouchers back to sender
-    else {
-        ctx_a.mint_coins_execute(&sender, &data.token)?;
+    for token in &data.tokens {
+        let coin = PrefixedCoin::from(token.clone());
+
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &coin.denom,
+        ) {
+            unescrow_execute(
+                ctx_a,
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                &coin,
+            )?;
+        }
+        // mint vouchers back to sender
+        else {
+            ctx_a.mint_coins_execute(&sender, &coin)?;
+        }
     }
 
     Ok(())
@@ -42,24 +228,30 @@ pub fn refund_packet_token_execute(
 pub fn refund_packet_token_validate(
     ctx_a: &impl TokenTransferValidationContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = ctx_a.sender_account(&data.sender)?;
+    let mut unescrowed = Vec::new();
+
+    for token in &data.tokens {
+        let coin = PrefixedCoin::from(token.clone());
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_validate(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )?;
-    } else {
-        ctx_a.mint_coins_validate(&sender, &data.token)?;
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &coin.denom,
+        ) {
+            ctx_a.unescrow_coins_validate(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                &coin,
+            )?;
+            unescrowed.push(coin);
+        } else {
+            ctx_a.mint_coins_validate(&sender, &coin)?;
+        }
     }
 
-    Ok(())
+    unescrow_total_validate(ctx_a, &unescrowed)
 }
@@ -1,11 +1,15 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::DenomTraceEvent;
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{
+    is_receiver_chain_source, PrefixedCoin, PrefixedDenom, Token, TracePrefix,
+};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::error::HostError;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::{check_denom_policy, unescrow_execute, unescrow_total_validate};
 use crate::context::TokenTransferExecutionContext;
 
 /// This function handles the transfer receiving logic.
@@ -17,7 +21,7 @@ use crate::context::TokenTransferExecutionContext;
 pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
     ctx_b: &mut Ctx,
     packet: &Packet,
-    data: PacketData,
+    data: PacketDataV2,
 ) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
     ctx_b
         .can_receive_coins()
@@ -27,87 +31,128 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         .receiver_account(&data.receiver)
         .map_err(|err| (ModuleExtras::empty(), err.into()))?;
 
-    let extras = if is_receiver_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        // sender chain is not the source, unescrow tokens
-        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.remove_trace_prefix(&prefix);
-            c
-        };
+    let coins: Vec<(PrefixedCoin, bool)> = data
+        .tokens
+        .into_iter()
+        .map(|token| received_coin(packet, token))
+        .collect();
+
+    check_denom_policy(
+        ctx_b,
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        coins.iter().map(|(coin, _)| coin),
+    )
+    .map_err(|err| (ModuleExtras::empty(), err))?;
 
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .unescrow_coins_validate(
+    let extras = ModuleExtras {
+        events: coins
+            .iter()
+            .filter(|(_, unescrow)| !unescrow)
+            .map(|(coin, _)| {
+                DenomTraceEvent {
+                    trace_hash: ctx_b.denom_hash_string(&coin.denom),
+                    denom: coin.denom.clone(),
+                }
+                .into()
+            })
+            .collect(),
+        log: Vec::new(),
+    };
+
+    // Note: it is correct to do the validation here because `recv_packet()`
+    // works slightly differently. We do not have a
+    // `on_recv_packet_validate()` callback because regardless of whether or
+    // not the app succeeds to receive the packet, we want to run the
+    // `execute()` phase. And this is because the app failing to receive
+    // does not constitute a failure of the message processing.
+    // Specifically, when the app fails to receive, we need to return
+    // a `TokenTransferAcknowledgement::Error` acknowledgement, which
+    // gets relayed back to the sender so that the escrowed tokens
+    // can be refunded.
+    //
+    // All the coins are validated before any of them is received, as the
+    // sender is refunded all of them if the reception fails.
+    for (coin, unescrow) in &coins {
+        if *unescrow {
+            ctx_b.unescrow_coins_validate(
                 &receiver_account,
                 &packet.port_id_on_b,
                 &packet.chan_id_on_b,
-                &coin,
+                coin,
             )
-            .map_err(|err| (ModuleExtras::empty(), err.into()))?;
-        ctx_b
-            .unescrow_coins_execute(
+        } else {
+            ctx_b.mint_coins_validate(&receiver_account, coin)
+        }
+        .map_err(|err| (extras.clone(), err.into()))?;
+    }
+
+    unescrow_total_validate(
+        ctx_b,
+        coins
+            .iter()
+            .filter(|(_, unescrow)| *unescrow)
+            .map(|(coin, _)| coin),
+    )
+    .map_err(|err| (extras.clone(), err))?;
+
+    for (coin, unescrow) in &coins {
+        if *unescrow {
+            unescrow_execute(
+                ctx_b,
                 &receiver_account,
                 &packet.port_id_on_b,
                 &packet.chan_id_on_b,
-                &coin,
+                coin,
             )
-            .map_err(|err| (ModuleExtras::empty(), err.into()))?;
+        } else {
+            ctx_b
+                .mint_coins_execute(&receiver_account, coin)
+                .and_then(|_| store_denom_trace(ctx_b, &coin.denom))
+                .map_err(TokenTransferError::from)
+        }
+        .map_err(|err| (extras.clone(), err))?;
+    }
+
+    Ok(extras)
+}
 
-        ModuleExtras::empty()
+/// Converts a token of the packet to the coin received on this chain, along
+/// with whether the tokens are unescrowed, as they originated from this
+/// chain, or minted as vouchers otherwise.
+pub fn received_coin(packet: &Packet, token: Token) -> (PrefixedCoin, bool) {
+    let mut coin = PrefixedCoin::from(token);
+
+    if is_receiver_chain_source(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        &coin.denom,
+    ) {
+        // sender chain is not the source, unescrow tokens
+        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+        coin.denom.remove_trace_prefix(&prefix);
+        (coin, true)
     } else {
         // sender chain is the source, mint vouchers
         let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.add_trace_prefix(prefix);
-            c
-        };
-
-        let extras = {
-            let denom_trace_event = DenomTraceEvent {
-                trace_hash: ctx_b.denom_hash_string(&coin.denom),
-                denom: coin.denom.clone(),
-            };
-            ModuleExtras {
-                events: vec![denom_trace_event.into()],
-                log: Vec::new(),
-            }
-        };
-
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .mint_coins_validate(&receiver_account, &coin)
-            .map_err(|err| (extras.clone(), err.into()))?;
-
-        ctx_b
-            .mint_coins_execute(&receiver_account, &coin)
-            .map_err(|err| (extras.clone(), err.into()))?;
+        coin.denom.add_trace_prefix(prefix);
+        (coin, false)
+    }
+}
 
-        extras
+/// Stores the trace of a minted voucher, unless the host does not hash
+/// denominations or already knows it.
+fn store_denom_trace<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    denom: &PrefixedDenom,
+) -> Result<(), HostError> {
+    let Some(hash) = ctx_b.denom_hash_string(denom) else {
+        return Ok(());
     };
 
-    Ok(extras)
+    if ctx_b.denom_trace_by_hash(&hash)?.is_none() {
+        ctx_b.store_denom_trace(hash, denom.clone())?;
+    }
+
+    Ok(())
 }
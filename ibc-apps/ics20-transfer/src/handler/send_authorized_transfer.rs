@@ -0,0 +1,109 @@
+use ibc_app_transfer_types::authorization::TransferAuthorization;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+
+use super::{resolve_forwarding, send_transfer_execute, send_transfer_validate};
+use crate::context::{
+    TokenTransferExecutionContext, TokenTransferValidationContext, TransferAuthorizationContext,
+};
+
+/// Initiates a token transfer signed by the given signer, which is either the
+/// sender or an account the sender granted a transfer authorization to.
+///
+/// Equivalent to calling [`send_authorized_transfer_validate`], followed by
+/// [`send_authorized_transfer_execute`].
+pub fn send_authorized_transfer<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    module: &mut dyn Module,
+    signer: &Signer,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext + TransferAuthorizationContext,
+{
+    send_authorized_transfer_validate(send_packet_ctx_a, token_ctx_a, signer, msg.clone())?;
+    send_authorized_transfer_execute(send_packet_ctx_a, token_ctx_a, module, signer, msg)
+}
+
+/// Validates the token transfer, which must be allowed by the transfer
+/// authorization granted to the signer if it is not the sender.
+pub fn send_authorized_transfer_validate<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    token_ctx_a: &TokenCtx,
+    signer: &Signer,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    TokenCtx: TokenTransferValidationContext + TransferAuthorizationContext,
+{
+    if signer != &msg.packet_data.sender {
+        accept_transfer(token_ctx_a, signer, &msg)?;
+    }
+
+    send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg)
+}
+
+/// Executes the token transfer, and updates the transfer authorization it
+/// was sent under, if any. The packet is sent through `module`, the module
+/// stack bound to the transfer port. A prior call to
+/// [`send_authorized_transfer_validate`] MUST have succeeded.
+pub fn send_authorized_transfer_execute<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    module: &mut dyn Module,
+    signer: &Signer,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext + TransferAuthorizationContext,
+{
+    if signer != &msg.packet_data.sender {
+        let granter = &msg.packet_data.sender;
+
+        match accept_transfer(token_ctx_a, signer, &msg)? {
+            Some(authorization) => token_ctx_a.store_transfer_authorization(
+                granter.clone(),
+                signer.clone(),
+                authorization,
+            )?,
+            None => token_ctx_a.delete_transfer_authorization(granter, signer)?,
+        }
+    }
+
+    send_transfer_execute(send_packet_ctx_a, token_ctx_a, module, msg)
+}
+
+/// Checks the transfer against the authorization granted by its sender to
+/// the signer, and returns the authorization left.
+///
+/// The allocations of the authorization do not cover the chains the tokens
+/// would be forwarded through, so grantees cannot forward tokens.
+fn accept_transfer<Ctx: TransferAuthorizationContext>(
+    ctx: &Ctx,
+    signer: &Signer,
+    msg: &MsgTransfer,
+) -> Result<Option<TransferAuthorization>, TokenTransferError> {
+    if !msg.forwarding.is_empty() {
+        return Err(TokenTransferError::UnauthorizedForwarding);
+    }
+
+    let msg = resolve_forwarding(msg.clone())?;
+    let granter = &msg.packet_data.sender;
+
+    let authorization = ctx
+        .transfer_authorization(granter, signer)?
+        .ok_or_else(|| TokenTransferError::MissingTransferAuthorization {
+            granter: granter.clone(),
+            grantee: signer.clone(),
+        })?;
+
+    authorization.accept(&msg)
+}
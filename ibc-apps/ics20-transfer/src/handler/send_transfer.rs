@@ -1,21 +1,29 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::TransferEvent;
 use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
-use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{
+    is_sender_chain_source, Forwarding, ForwardingPacketData, PrefixedCoin, MAX_FORWARDING_HOPS,
+    MODULE_ID_STR,
+};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
-use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::handler::{send_module_packet, send_packet_validate};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
 use ibc_core::handler::types::events::MessageEvent;
 use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
+use ibc_core::router::module::Module;
 use ibc_core::router::types::event::ModuleEvent;
 
+use super::{check_denom_policy, encode_packet_data, escrow_execute};
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
+    module: &mut dyn Module,
     msg: MsgTransfer,
 ) -> Result<(), TokenTransferError>
 where
@@ -23,7 +31,7 @@ where
     TokenCtx: TokenTransferExecutionContext,
 {
     send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.clone())?;
-    send_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
+    send_transfer_execute(send_packet_ctx_a, token_ctx_a, module, msg)
 }
 
 /// Validates the token transfer. If this succeeds, then it is legal to initiate the transfer with [`send_transfer_execute`].
@@ -36,6 +44,8 @@ where
     SendPacketCtx: SendPacketValidationContext,
     TokenCtx: TokenTransferValidationContext,
 {
+    let msg = resolve_forwarding(msg)?;
+
     token_ctx_a.can_send_coins()?;
 
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
@@ -54,29 +64,42 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
+    if msg.packet_data.tokens.is_empty() {
+        return Err(TokenTransferError::MissingTokens);
+    }
 
     let sender = token_ctx_a.sender_account(&msg.packet_data.sender)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_validate(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+    let coins: Vec<PrefixedCoin> = msg
+        .packet_data
+        .tokens
+        .iter()
+        .cloned()
+        .map(PrefixedCoin::from)
+        .collect();
+
+    check_denom_policy(token_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, &coins)?;
+
+    for coin in coins {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &coin.denom,
+        ) {
+            token_ctx_a.escrow_coins_validate(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                &coin,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            token_ctx_a.burn_coins_validate(&sender, &coin, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = encode_packet_data(chan_end_on_a.version(), msg.packet_data)?;
 
         Packet {
             seq_on_a: sequence,
@@ -96,15 +119,22 @@ where
 }
 
 /// Executes the token transfer. A prior call to [`send_transfer_validate`] MUST have succeeded.
+///
+/// The packet is sent through `module`, the module stack bound to the
+/// transfer port, so that the middleware wrapping the transfer module gets to
+/// process it.
 pub fn send_transfer_execute<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
+    module: &mut dyn Module,
     msg: MsgTransfer,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = resolve_forwarding(msg)?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -122,31 +152,31 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender = token_ctx_a.sender_account(&msg.packet_data.sender)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_execute(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+    for token in &msg.packet_data.tokens {
+        let coin = PrefixedCoin::from(token.clone());
+
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &coin.denom,
+        ) {
+            escrow_execute(
+                token_ctx_a,
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                &coin,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            token_ctx_a.burn_coins_execute(&sender, &coin, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = encode_packet_data(chan_end_on_a.version(), msg.packet_data.clone())?;
 
         Packet {
             seq_on_a: sequence,
@@ -160,25 +190,100 @@ where
         }
     };
 
-    send_packet_execute(send_packet_ctx_a, packet)?;
+    send_module_packet(send_packet_ctx_a, module, packet)?;
 
     {
-        send_packet_ctx_a.log_message(format!(
-            "IBC fungible token transfer: {} --({})--> {}",
-            msg.packet_data.sender, token, msg.packet_data.receiver
-        ))?;
-
-        let transfer_event = TransferEvent {
-            sender: msg.packet_data.sender,
-            receiver: msg.packet_data.receiver,
-            amount: msg.packet_data.token.amount,
-            denom: msg.packet_data.token.denom,
-            memo: msg.packet_data.memo,
-        };
-        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        let PacketDataV2 {
+            tokens,
+            sender,
+            receiver,
+            memo,
+            ..
+        } = msg.packet_data;
+
+        for token in tokens {
+            let coin = PrefixedCoin::from(token);
+
+            send_packet_ctx_a.log_message(format!(
+                "IBC fungible token transfer: {sender} --({coin})--> {receiver}"
+            ))?;
+
+            let transfer_event = TransferEvent {
+                sender: sender.clone(),
+                receiver: receiver.clone(),
+                amount: coin.amount,
+                denom: coin.denom,
+                memo: memo.clone(),
+            };
+            send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        }
 
         send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
     }
 
     Ok(())
 }
+
+/// Resolves the forwarding of a transfer into the message which is actually
+/// sent.
+///
+/// Unwound tokens are sent back over the channel they were last received
+/// from, which the message must be sent over, and the rest of their trace is
+/// prepended to the forwarding hops. The
+/// hops are then carried by the packet data, along with the memo, which is
+/// passed on to the final receiver.
+pub fn resolve_forwarding(mut msg: MsgTransfer) -> Result<MsgTransfer, TokenTransferError> {
+    let Forwarding { unwind, mut hops } = core::mem::take(&mut msg.forwarding);
+
+    if unwind {
+        let mut tokens = msg.packet_data.tokens.iter();
+        let denom = &tokens
+            .next()
+            .ok_or(TokenTransferError::MissingTokens)?
+            .denom;
+
+        // The tokens all go back along the same path.
+        if tokens.any(|token| token.denom.trace != denom.trace) {
+            return Err(TokenTransferError::MismatchedUnwindTraces);
+        }
+
+        let Some((last_hop, trace)) = denom.trace.split_first() else {
+            return Err(TokenTransferError::CannotUnwindNativeDenom(
+                denom.clone().into(),
+            ));
+        };
+
+        if msg.port_id_on_a != last_hop.port_id || msg.chan_id_on_a != last_hop.channel_id {
+            return Err(TokenTransferError::MismatchedUnwindChannel {
+                port_id: last_hop.port_id.clone(),
+                channel_id: last_hop.channel_id.clone(),
+            });
+        }
+
+        hops = trace.iter().cloned().chain(hops).collect();
+    }
+
+    if !hops.is_empty() {
+        let destination_memo = core::mem::take(&mut msg.packet_data.memo);
+        msg.packet_data.forwarding = ForwardingPacketData::new(destination_memo, hops);
+    }
+
+    let forwarding = &msg.packet_data.forwarding;
+
+    if !forwarding.is_empty() {
+        if forwarding.hops.len() > MAX_FORWARDING_HOPS {
+            return Err(TokenTransferError::TooManyForwardingHops {
+                count: forwarding.hops.len(),
+                max: MAX_FORWARDING_HOPS,
+            });
+        }
+
+        // Heights differ from one chain to another, so the intermediate
+        // chains only reuse the timeout timestamp.
+        if msg.timeout_height_on_b != TimeoutHeight::Never {
+            return Err(TokenTransferError::TimeoutHeightWithForwarding);
+        }
+    }
+
+    Ok(msg)
+}
@@ -26,6 +26,8 @@ pub mod types {
 
 pub mod context;
 #[cfg(feature = "serde")]
+pub mod forwarding;
+#[cfg(feature = "serde")]
 pub mod handler;
 #[cfg(feature = "serde")]
 pub mod module;
@@ -1,7 +1,6 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
+use ibc_app_transfer_types::{ack_success_b64, PrefixedCoin, VERSION, VERSION_V2};
 use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
@@ -13,9 +12,20 @@ use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
-    process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
+    decode_recv_packet_data, decode_sent_packet_data, process_recv_packet_execute,
+    refund_packet_token_execute, refund_packet_token_validate,
 };
 
+/// Returns an error unless the given version is one of the supported ICS-20
+/// versions.
+fn verify_supported_version(version: &Version) -> Result<(), TokenTransferError> {
+    if [VERSION, VERSION_V2].contains(&version.as_str()) {
+        Ok(())
+    } else {
+        Err(TokenTransferError::UnsupportedVersion(version.clone()))
+    }
+}
+
 pub fn on_chan_open_init_validate(
     ctx: &impl TokenTransferValidationContext,
     order: Order,
@@ -40,7 +50,7 @@ pub fn on_chan_open_init_validate(
     }
 
     if !version.is_empty() {
-        version.verify_is_expected(Version::new(VERSION.to_string()))?;
+        verify_supported_version(version)?;
     }
 
     Ok(())
@@ -53,9 +63,16 @@ pub fn on_chan_open_init_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    // Channels are opened with ICS-20 v1 unless another version is proposed
+    let version = if version.is_empty() {
+        Version::new(VERSION.to_string())
+    } else {
+        version.clone()
+    };
+
+    Ok((ModuleExtras::empty(), version))
 }
 
 pub fn on_chan_open_try_validate(
@@ -65,7 +82,7 @@ pub fn on_chan_open_try_validate(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    counterparty_version: &Version,
+    _counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
     if order != Order::Unordered {
         return Err(TokenTransferError::MismatchedChannelOrders {
@@ -74,8 +91,6 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version.verify_is_expected(Version::new(VERSION.to_string()))?;
-
     Ok(())
 }
 
@@ -86,9 +101,17 @@ pub fn on_chan_open_try_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    // As in ibc-go, a counterparty proposing an unsupported version is
+    // answered with ICS-20 v1, which it may accept or not.
+    let version = if verify_supported_version(counterparty_version).is_ok() {
+        counterparty_version.clone()
+    } else {
+        Version::new(VERSION.to_string())
+    };
+
+    Ok((ModuleExtras::empty(), version))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -97,7 +120,7 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version.verify_is_expected(Version::new(VERSION.to_string()))?;
+    verify_supported_version(counterparty_version)?;
 
     Ok(())
 }
@@ -159,30 +182,155 @@ pub fn on_chan_close_confirm_execute(
     Ok(ModuleExtras::empty())
 }
 
+/// Returns the version a channel is upgraded to, which is ICS-20 v1 unless
+/// another version is proposed.
+fn upgrade_version(proposed_version: &Version) -> Version {
+    if proposed_version.is_empty() {
+        Version::new(VERSION.to_string())
+    } else {
+        proposed_version.clone()
+    }
+}
+
+pub fn on_chan_upgrade_init_validate(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    proposed_order: Order,
+    _proposed_connection_hops: &[ConnectionId],
+    proposed_version: &Version,
+) -> Result<Version, TokenTransferError> {
+    if proposed_order != Order::Unordered {
+        return Err(TokenTransferError::MismatchedChannelOrders {
+            expected: Order::Unordered,
+            actual: proposed_order,
+        });
+    }
+    let bound_port = ctx.get_port()?;
+    if port_id != &bound_port {
+        return Err(TokenTransferError::MismatchedPortIds {
+            actual: port_id.clone(),
+            expected: bound_port,
+        });
+    }
+
+    let version = upgrade_version(proposed_version);
+
+    verify_supported_version(&version)?;
+
+    Ok(version)
+}
+
+pub fn on_chan_upgrade_init_execute(
+    _ctx: &mut impl TokenTransferExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _proposed_order: Order,
+    _proposed_connection_hops: &[ConnectionId],
+    proposed_version: &Version,
+) -> Result<(ModuleExtras, Version), TokenTransferError> {
+    Ok((ModuleExtras::empty(), upgrade_version(proposed_version)))
+}
+
+pub fn on_chan_upgrade_try_validate(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    proposed_order: Order,
+    _proposed_connection_hops: &[ConnectionId],
+    counterparty_version: &Version,
+) -> Result<Version, TokenTransferError> {
+    if proposed_order != Order::Unordered {
+        return Err(TokenTransferError::MismatchedChannelOrders {
+            expected: Order::Unordered,
+            actual: proposed_order,
+        });
+    }
+    let bound_port = ctx.get_port()?;
+    if port_id != &bound_port {
+        return Err(TokenTransferError::MismatchedPortIds {
+            actual: port_id.clone(),
+            expected: bound_port,
+        });
+    }
+
+    verify_supported_version(counterparty_version)?;
+
+    Ok(counterparty_version.clone())
+}
+
+pub fn on_chan_upgrade_try_execute(
+    _ctx: &mut impl TokenTransferExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _proposed_order: Order,
+    _proposed_connection_hops: &[ConnectionId],
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), TokenTransferError> {
+    Ok((ModuleExtras::empty(), counterparty_version.clone()))
+}
+
+pub fn on_chan_upgrade_ack_validate(
+    _ctx: &impl TokenTransferValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), TokenTransferError> {
+    verify_supported_version(counterparty_version)?;
+
+    Ok(())
+}
+
+pub fn on_chan_upgrade_ack_execute(
+    _ctx: &mut impl TokenTransferExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, TokenTransferError> {
+    Ok(ModuleExtras::empty())
+}
+
 pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        let ack =
-            AcknowledgementStatus::error(TokenTransferError::FailedToDeserializePacketData.into());
-        return (ModuleExtras::empty(), ack.into());
+    let data = match decode_recv_packet_data(ctx_b, packet) {
+        Ok(data) => data,
+        Err(err) => {
+            let ack = AcknowledgementStatus::error(err.into());
+            return (ModuleExtras::empty(), ack.into());
+        }
+    };
+
+    // The tokens of packets carrying forwarding hops are only received by the
+    // callbacks of the `forwarding` module.
+    let result = if data.forwarding.is_empty() {
+        process_recv_packet_execute(ctx_b, packet, data.clone())
+    } else {
+        Err((
+            ModuleExtras::empty(),
+            TokenTransferError::ForwardingNotSupported,
+        ))
     };
 
-    let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
+    let (mut extras, ack) = match result {
         Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
         Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
     };
 
-    let recv_event = RecvEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        success: ack.is_successful(),
-    };
-    extras.events.push(recv_event.into());
+    for token in data.tokens {
+        let coin = PrefixedCoin::from(token);
+
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: coin.denom,
+            amount: coin.amount,
+            memo: data.memo.clone(),
+            success: ack.is_successful(),
+        };
+        extras.events.push(recv_event.into());
+    }
 
     (extras, ack.into())
 }
@@ -196,8 +344,7 @@ pub fn on_acknowledgement_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::FailedToDeserializePacketData)?;
+    let data = decode_sent_packet_data(ctx, packet)?;
 
     let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
         .map_err(|_| TokenTransferError::FailedToDeserializeAck)?;
@@ -215,11 +362,9 @@ pub fn on_acknowledgement_packet_execute(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        return (
-            ModuleExtras::empty(),
-            Err(TokenTransferError::FailedToDeserializePacketData),
-        );
+    let data = match decode_sent_packet_data(ctx, packet) {
+        Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     let Ok(acknowledgement) =
@@ -237,17 +382,27 @@ pub fn on_acknowledgement_packet_execute(
         }
     }
 
-    let ack_event = AckEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        acknowledgement: acknowledgement.clone(),
-    };
+    let mut events: Vec<_> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            let coin = PrefixedCoin::from(token);
+
+            AckEvent {
+                sender: data.sender.clone(),
+                receiver: data.receiver.clone(),
+                denom: coin.denom,
+                amount: coin.amount,
+                memo: data.memo.clone(),
+                acknowledgement: acknowledgement.clone(),
+            }
+            .into()
+        })
+        .collect();
+    events.push(AckStatusEvent { acknowledgement }.into());
 
     let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -262,8 +417,7 @@ pub fn on_timeout_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::FailedToDeserializePacketData)?;
+    let data = decode_sent_packet_data(ctx, packet)?;
 
     refund_packet_token_validate(ctx, packet, &data)?;
 
@@ -275,26 +429,33 @@ pub fn on_timeout_packet_execute(
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        return (
-            ModuleExtras::empty(),
-            Err(TokenTransferError::FailedToDeserializePacketData),
-        );
+    let data = match decode_sent_packet_data(ctx, packet) {
+        Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
         return (ModuleExtras::empty(), Err(err));
     }
 
-    let timeout_event = TimeoutEvent {
-        refund_receiver: data.sender,
-        refund_denom: data.token.denom,
-        refund_amount: data.token.amount,
-        memo: data.memo,
-    };
+    let events = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            let coin = PrefixedCoin::from(token);
+
+            TimeoutEvent {
+                refund_receiver: data.sender.clone(),
+                refund_denom: coin.denom,
+                refund_amount: coin.amount,
+                memo: data.memo.clone(),
+            }
+            .into()
+        })
+        .collect();
 
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events,
         log: Vec::new(),
     };
 
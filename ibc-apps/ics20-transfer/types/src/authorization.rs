@@ -0,0 +1,438 @@
+//! Defines the transfer authorization, which lets an account send tokens on
+//! behalf of another one, within the limits set by the latter.
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::transfer::v1::{
+    Allocation as RawAllocation, TransferAuthorization as RawTransferAuthorization,
+};
+use ibc_proto::Protobuf;
+use primitive_types::U256;
+
+use crate::error::TokenTransferError;
+use crate::msgs::transfer::MsgTransfer;
+use crate::{Amount, PrefixedCoin};
+
+pub const TRANSFER_AUTHORIZATION_TYPE_URL: &str =
+    "/ibc.applications.transfer.v1.TransferAuthorization";
+
+/// The allowed packet data entry which permits any memo.
+pub const ALLOW_ALL_PACKET_DATA: &str = "*";
+
+/// Returns the spend limit under which the amount of a denomination is not
+/// limited.
+pub fn unbounded_spend_limit() -> Amount {
+    U256::MAX.into()
+}
+
+/// The transfers allowed over a channel by a [`TransferAuthorization`].
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    /// The port on which the packets are sent
+    pub port_id: PortId,
+    /// The channel by which the packets are sent
+    pub channel_id: ChannelId,
+    /// The amount of each denomination which may still be sent, which is not
+    /// limited if set to [`unbounded_spend_limit`]
+    pub spend_limit: Vec<PrefixedCoin>,
+    /// The receivers allowed, any receiver being allowed if empty
+    pub allow_list: Vec<Signer>,
+    /// The memos allowed, only the empty memo being allowed if empty, and any
+    /// memo if it only holds [`ALLOW_ALL_PACKET_DATA`]
+    pub allowed_packet_data: Vec<String>,
+}
+
+impl Allocation {
+    fn is_receiver_allowed(&self, receiver: &Signer) -> bool {
+        self.allow_list.is_empty() || self.allow_list.contains(receiver)
+    }
+
+    fn is_memo_allowed(&self, memo: &str) -> bool {
+        let memo = memo.trim();
+
+        match self.allowed_packet_data.as_slice() {
+            [] => memo.is_empty(),
+            [allowed] if allowed == ALLOW_ALL_PACKET_DATA => true,
+            allowed => allowed.iter().any(|allowed| allowed.trim() == memo),
+        }
+    }
+
+    /// Subtracts the coin from the spend limit of its denomination, which
+    /// is dropped once it is used up.
+    fn spend(&mut self, coin: &PrefixedCoin) -> Result<(), TokenTransferError> {
+        let index = self
+            .spend_limit
+            .iter()
+            .position(|limit| limit.denom == coin.denom)
+            .ok_or_else(|| TokenTransferError::SpendLimitExceeded(coin.denom.clone()))?;
+
+        let limit = &mut self.spend_limit[index];
+
+        if limit.amount == unbounded_spend_limit() {
+            return Ok(());
+        }
+
+        limit.amount = limit
+            .amount
+            .checked_sub(coin.amount)
+            .ok_or_else(|| TokenTransferError::SpendLimitExceeded(coin.denom.clone()))?;
+
+        if limit.amount == Amount::from(0u64) {
+            self.spend_limit.remove(index);
+        }
+
+        Ok(())
+    }
+}
+
+/// Authorization allowing a grantee to send tokens from the account of the
+/// granter, over the channels it has an allocation for.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferAuthorization {
+    pub allocations: Vec<Allocation>,
+}
+
+impl TransferAuthorization {
+    /// Checks that the transfer is allowed by the allocation of its channel,
+    /// and spends its tokens from the spend limit.
+    ///
+    /// Returns the authorization left once the transfer is accepted, which is
+    /// `None` if all the allocations are used up, in which case the
+    /// authorization is to be deleted.
+    pub fn accept(&self, msg: &MsgTransfer) -> Result<Option<Self>, TokenTransferError> {
+        let index = self
+            .allocations
+            .iter()
+            .position(|allocation| {
+                allocation.port_id == msg.port_id_on_a && allocation.channel_id == msg.chan_id_on_a
+            })
+            .ok_or_else(|| TokenTransferError::MissingAllocation {
+                port_id: msg.port_id_on_a.clone(),
+                channel_id: msg.chan_id_on_a.clone(),
+            })?;
+
+        let mut allocations = self.allocations.clone();
+        let allocation = &mut allocations[index];
+
+        if !allocation.is_receiver_allowed(&msg.packet_data.receiver) {
+            return Err(TokenTransferError::UnauthorizedReceiver(
+                msg.packet_data.receiver.clone(),
+            ));
+        }
+
+        if !allocation.is_memo_allowed(msg.packet_data.memo.as_ref()) {
+            return Err(TokenTransferError::UnauthorizedMemo(
+                msg.packet_data.memo.to_string(),
+            ));
+        }
+
+        for token in &msg.packet_data.tokens {
+            allocation.spend(&PrefixedCoin::from(token.clone()))?;
+        }
+
+        if allocation.spend_limit.is_empty() {
+            allocations.remove(index);
+        }
+
+        if allocations.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self { allocations }))
+    }
+}
+
+impl TryFrom<RawAllocation> for Allocation {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAllocation) -> Result<Self, Self::Error> {
+        let spend_limit: Vec<PrefixedCoin> = raw
+            .spend_limit
+            .into_iter()
+            .map(PrefixedCoin::try_from)
+            .collect::<Result<_, _>>()?;
+
+        if spend_limit.is_empty() {
+            return Err(DecodingError::missing_raw_data("allocation spend limit"));
+        }
+
+        if spend_limit
+            .iter()
+            .any(|coin| coin.amount == Amount::from(0u64))
+        {
+            return Err(DecodingError::invalid_raw_data(
+                "allocation spend limit must be positive",
+            ));
+        }
+
+        Ok(Self {
+            port_id: raw.source_port.parse()?,
+            channel_id: raw.source_channel.parse()?,
+            spend_limit,
+            allow_list: raw.allow_list.into_iter().map(Signer::from).collect(),
+            allowed_packet_data: raw.allowed_packet_data,
+        })
+    }
+}
+
+impl From<Allocation> for RawAllocation {
+    fn from(allocation: Allocation) -> Self {
+        Self {
+            source_port: allocation.port_id.to_string(),
+            source_channel: allocation.channel_id.to_string(),
+            spend_limit: allocation.spend_limit.into_iter().map(Into::into).collect(),
+            allow_list: allocation
+                .allow_list
+                .into_iter()
+                .map(|receiver| receiver.to_string())
+                .collect(),
+            allowed_packet_data: allocation.allowed_packet_data,
+        }
+    }
+}
+
+impl Protobuf<RawTransferAuthorization> for TransferAuthorization {}
+
+impl TryFrom<RawTransferAuthorization> for TransferAuthorization {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawTransferAuthorization) -> Result<Self, Self::Error> {
+        let allocations: Vec<Allocation> = raw
+            .allocations
+            .into_iter()
+            .map(Allocation::try_from)
+            .collect::<Result<_, _>>()?;
+
+        if allocations.is_empty() {
+            return Err(DecodingError::missing_raw_data(
+                "transfer authorization allocations",
+            ));
+        }
+
+        for (i, allocation) in allocations.iter().enumerate() {
+            if allocations[..i].iter().any(|other| {
+                other.port_id == allocation.port_id && other.channel_id == allocation.channel_id
+            }) {
+                return Err(DecodingError::invalid_raw_data(format!(
+                    "duplicate allocation for channel {} on port {}",
+                    allocation.channel_id, allocation.port_id
+                )));
+            }
+        }
+
+        Ok(Self { allocations })
+    }
+}
+
+impl From<TransferAuthorization> for RawTransferAuthorization {
+    fn from(authorization: TransferAuthorization) -> Self {
+        Self {
+            allocations: authorization
+                .allocations
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for TransferAuthorization {}
+
+impl TryFrom<Any> for TransferAuthorization {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let TRANSFER_AUTHORIZATION_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawTransferAuthorization>::decode_vec(&raw.value).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: TRANSFER_AUTHORIZATION_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<TransferAuthorization> for Any {
+    fn from(authorization: TransferAuthorization) -> Self {
+        Self {
+            type_url: TRANSFER_AUTHORIZATION_TYPE_URL.to_string(),
+            value: Protobuf::<RawTransferAuthorization>::encode_vec(authorization),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+
+    use super::*;
+    use crate::packet::PacketDataV2;
+
+    fn allocation(spend_limit: &str) -> Allocation {
+        Allocation {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            spend_limit: PrefixedCoin::from_string_list(spend_limit).unwrap(),
+            allow_list: vec![],
+            allowed_packet_data: vec![],
+        }
+    }
+
+    fn msg_transfer(coins: &str, receiver: &str, memo: &str) -> MsgTransfer {
+        MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            packet_data: PacketDataV2 {
+                tokens: PrefixedCoin::from_string_list(coins)
+                    .unwrap()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                sender: "granter".to_string().into(),
+                receiver: receiver.to_string().into(),
+                memo: memo.to_string().into(),
+                forwarding: Default::default(),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: TimeoutTimestamp::Never,
+            forwarding: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_accept_spends_limit() {
+        let authorization = TransferAuthorization {
+            allocations: vec![allocation("100uatom,50uosmo")],
+        };
+
+        let updated = authorization
+            .accept(&msg_transfer("40uatom,50uosmo", "receiver", ""))
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.allocations, vec![allocation("60uatom")]);
+
+        assert!(matches!(
+            updated.accept(&msg_transfer("61uatom", "receiver", "")),
+            Err(TokenTransferError::SpendLimitExceeded(_))
+        ));
+        assert!(matches!(
+            updated.accept(&msg_transfer("1uosmo", "receiver", "")),
+            Err(TokenTransferError::SpendLimitExceeded(_))
+        ));
+        assert_eq!(
+            updated
+                .accept(&msg_transfer("60uatom", "receiver", ""))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_accept_unbounded_spend_limit() {
+        let mut allocation = allocation("1uatom");
+        allocation.spend_limit[0].amount = unbounded_spend_limit();
+        let authorization = TransferAuthorization {
+            allocations: vec![allocation],
+        };
+
+        let updated = authorization
+            .accept(&msg_transfer("1000000uatom", "receiver", ""))
+            .unwrap();
+        assert_eq!(updated, Some(authorization));
+    }
+
+    #[test]
+    fn test_accept_checks_receiver_and_memo() {
+        let authorization = TransferAuthorization {
+            allocations: vec![Allocation {
+                allow_list: vec!["receiver".to_string().into()],
+                allowed_packet_data: vec!["memo".to_string()],
+                ..allocation("100uatom")
+            }],
+        };
+
+        assert!(authorization
+            .accept(&msg_transfer("1uatom", "receiver", "memo"))
+            .is_ok());
+        assert!(matches!(
+            authorization.accept(&msg_transfer("1uatom", "other", "memo")),
+            Err(TokenTransferError::UnauthorizedReceiver(_))
+        ));
+        assert!(matches!(
+            authorization.accept(&msg_transfer("1uatom", "receiver", "other")),
+            Err(TokenTransferError::UnauthorizedMemo(_))
+        ));
+
+        let authorization = TransferAuthorization {
+            allocations: vec![allocation("100uatom")],
+        };
+        assert!(authorization
+            .accept(&msg_transfer("1uatom", "receiver", "memo"))
+            .is_err());
+
+        let authorization = TransferAuthorization {
+            allocations: vec![Allocation {
+                allowed_packet_data: vec![ALLOW_ALL_PACKET_DATA.to_string()],
+                ..allocation("100uatom")
+            }],
+        };
+        assert!(authorization
+            .accept(&msg_transfer("1uatom", "receiver", "memo"))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_accept_without_allocation() {
+        let authorization = TransferAuthorization {
+            allocations: vec![Allocation {
+                channel_id: ChannelId::new(1),
+                ..allocation("100uatom")
+            }],
+        };
+
+        assert!(matches!(
+            authorization.accept(&msg_transfer("1uatom", "receiver", "")),
+            Err(TokenTransferError::MissingAllocation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_transfer_authorization_proto_roundtrip() {
+        let authorization = TransferAuthorization {
+            allocations: vec![allocation("100uatom")],
+        };
+
+        let any = Any::from(authorization.clone());
+        assert_eq!(TransferAuthorization::try_from(any).unwrap(), authorization);
+
+        let duplicated = RawTransferAuthorization {
+            allocations: vec![allocation("100uatom").into(), allocation("10uosmo").into()],
+        };
+        assert!(TransferAuthorization::try_from(duplicated).is_err());
+    }
+}
@@ -10,6 +10,8 @@ use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
 
+use crate::{Denom, Hop};
+
 /// The "base" of a denomination.
 ///
 /// For example, given the token `my_port-1/my_channel-1/my_port-2/my_channel-2/base_denom`,
@@ -148,6 +150,11 @@ impl TracePath {
         self.0.last().map(|p| p == prefix).unwrap_or(false)
     }
 
+    /// Returns true iff this path starts with all the prefixes of the specified path
+    pub fn starts_with_path(&self, path: &TracePath) -> bool {
+        self.0.ends_with(&path.0)
+    }
+
     /// Removes the specified prefix from the path if there is a match, otherwise does nothing.
     pub fn remove_prefix(&mut self, prefix: &TracePrefix) {
         if self.starts_with(prefix) {
@@ -165,6 +172,11 @@ impl TracePath {
         self.0.is_empty()
     }
 
+    /// Returns the number of prefixes, i.e. of hops, in the path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     /// Return empty trace path
     pub fn empty() -> Self {
         Self(vec![])
@@ -188,11 +200,7 @@ impl TracePath {
         let mut trace_prefixes = vec![];
         let mut current_remaining_opt = Some(s);
 
-        loop {
-            let Some(current_remaining_s) = current_remaining_opt else {
-                break;
-            };
-
+        while let Some(current_remaining_s) = current_remaining_opt {
             let Some((trace_prefix, next_remaining_opt)) = TracePrefix::strip(current_remaining_s)
             else {
                 break;
@@ -394,6 +402,44 @@ impl From<BaseDenom> for PrefixedDenom {
     }
 }
 
+impl From<TracePrefix> for Hop {
+    fn from(prefix: TracePrefix) -> Self {
+        Self::new(prefix.port_id, prefix.channel_id)
+    }
+}
+
+impl From<Hop> for TracePrefix {
+    fn from(hop: Hop) -> Self {
+        Self::new(hop.port_id, hop.channel_id)
+    }
+}
+
+impl From<PrefixedDenom> for Denom {
+    fn from(denom: PrefixedDenom) -> Self {
+        // The trace path is stored in reverse order
+        let trace = denom
+            .trace_path
+            .0
+            .into_iter()
+            .rev()
+            .map(Into::into)
+            .collect();
+
+        Self::new(denom.base_denom, trace)
+    }
+}
+
+impl From<Denom> for PrefixedDenom {
+    fn from(denom: Denom) -> Self {
+        let trace_prefixes = denom.trace.into_iter().rev().map(Into::into).collect();
+
+        Self {
+            trace_path: TracePath(trace_prefixes),
+            base_denom: denom.base,
+        }
+    }
+}
+
 impl Display for PrefixedDenom {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         if self.trace_path.0.is_empty() {
@@ -3,9 +3,13 @@ use displaydoc::Display;
 use ibc_core::channel::types::acknowledgement::StatusValue;
 use ibc_core::channel::types::channel::Order;
 use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::Version;
 use ibc_core::host::types::error::{DecodingError, HostError};
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::{Amount, PrefixedDenom};
 
 #[derive(Display, Debug, derive_more::From)]
 pub enum TokenTransferError {
@@ -30,6 +34,80 @@ pub enum TokenTransferError {
     FailedToDeserializePacketData,
     /// failed to deserialize acknowledgement
     FailedToDeserializeAck,
+    /// unsupported ICS-20 version `{0}`
+    #[from(skip)]
+    UnsupportedVersion(Version),
+    /// missing tokens to transfer
+    MissingTokens,
+    /// channel version `{version}` does not support transferring `{count}` tokens at once
+    UnsupportedTokenCount { version: Version, count: usize },
+    /// cannot unescrow `{amount}` of denom `{denom}`: only `{escrowed}` held in escrow
+    InsufficientEscrow {
+        denom: PrefixedDenom,
+        amount: Amount,
+        escrowed: Amount,
+    },
+    /// overflow of the total escrow of denom `{0}`
+    #[from(skip)]
+    TotalEscrowOverflow(PrefixedDenom),
+    /// missing transfer authorization granted by `{granter}` to `{grantee}`
+    #[from(skip)]
+    MissingTransferAuthorization { granter: Signer, grantee: Signer },
+    /// missing transfer allocation for channel `{channel_id}` on port `{port_id}`
+    #[from(skip)]
+    MissingAllocation {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// receiver `{0}` is not allowed by the transfer authorization
+    #[from(skip)]
+    UnauthorizedReceiver(Signer),
+    /// memo `{0}` is not allowed by the transfer authorization
+    #[from(skip)]
+    UnauthorizedMemo(String),
+    /// spend limit of denom `{0}` exceeded
+    #[from(skip)]
+    SpendLimitExceeded(PrefixedDenom),
+    /// denom `{0}` is blocked on this channel
+    #[from(skip)]
+    BlockedDenom(PrefixedDenom),
+    /// denom `{0}` is not allowed on this channel
+    #[from(skip)]
+    DenomNotAllowed(PrefixedDenom),
+    /// trace of denom `{denom}` has `{depth}` hops, exceeding the maximum of `{max_depth}`
+    TraceTooDeep {
+        denom: PrefixedDenom,
+        depth: u64,
+        max_depth: u64,
+    },
+    /// forwarding hops exceed the maximum of `{max}`, got `{count}`
+    TooManyForwardingHops { count: usize, max: usize },
+    /// timeout height cannot be set along with forwarding hops
+    TimeoutHeightWithForwarding,
+    /// channel version `{0}` does not support forwarding
+    #[from(skip)]
+    UnsupportedForwarding(Version),
+    /// forwarding is not supported by this chain
+    ForwardingNotSupported,
+    /// cannot unwind native denom `{0}`
+    #[from(skip)]
+    CannotUnwindNativeDenom(PrefixedDenom),
+    /// cannot unwind tokens with different traces
+    MismatchedUnwindTraces,
+    /// unwound tokens must be sent over channel `{channel_id}` on port `{port_id}`, which they were last received over
+    #[from(skip)]
+    MismatchedUnwindChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// transfer authorizations do not allow forwarding tokens
+    UnauthorizedForwarding,
+    /// forwarded packet timed out on channel `{channel_id}` of port `{port_id}`
+    #[from(skip)]
+    ForwardedPacketTimeout {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -44,6 +122,17 @@ impl std::error::Error for TokenTransferError {
     }
 }
 
+impl From<TokenTransferError> for ChannelError {
+    fn from(err: TokenTransferError) -> Self {
+        match err {
+            TokenTransferError::Channel(e) => e,
+            e => ChannelError::AppSpecific {
+                description: e.to_string(),
+            },
+        }
+    }
+}
+
 impl From<TokenTransferError> for StatusValue {
     fn from(e: TokenTransferError) -> Self {
         StatusValue::new(e.to_string()).expect("error message must not be empty")
@@ -0,0 +1,189 @@
+//! Defines the forwarding types of ICS-20 v2, which let the tokens of a
+//! transfer go through intermediate chains before reaching their receiver.
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::prelude::*;
+
+use crate::proto::v1::{Forwarding as RawForwarding, Hop as RawHop};
+use crate::proto::v2::ForwardingPacketData as RawForwardingPacketData;
+use crate::{Hop, Memo};
+
+/// The maximum number of hops a transfer can be forwarded through.
+pub const MAX_FORWARDING_HOPS: usize = 8;
+
+/// The forwarding instructions of a transfer message.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Forwarding {
+    /// Whether the tokens are first sent back to their source chain, along
+    /// the reverse of their trace.
+    ///
+    /// The message must then be sent over the port and channel the tokens
+    /// were last received over.
+    pub unwind: bool,
+    /// The ports and channels over which the intermediate chains forward the
+    /// tokens, in order.
+    pub hops: Vec<Hop>,
+}
+
+impl Forwarding {
+    pub fn new(unwind: bool, hops: Vec<Hop>) -> Self {
+        Self { unwind, hops }
+    }
+
+    /// Returns true if the transfer is neither unwound nor forwarded.
+    pub fn is_empty(&self) -> bool {
+        !self.unwind && self.hops.is_empty()
+    }
+}
+
+impl TryFrom<RawForwarding> for Forwarding {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawForwarding) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unwind: raw.unwind,
+            hops: decode_hops(raw.hops)?,
+        })
+    }
+}
+
+impl From<Forwarding> for RawForwarding {
+    fn from(forwarding: Forwarding) -> Self {
+        Self {
+            unwind: forwarding.unwind,
+            hops: forwarding.hops.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The forwarding hops left for the tokens of a packet, carried along with
+/// the memo meant for their final receiver.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ForwardingPacketData {
+    pub destination_memo: Memo,
+    pub hops: Vec<Hop>,
+}
+
+impl ForwardingPacketData {
+    pub fn new(destination_memo: Memo, hops: Vec<Hop>) -> Self {
+        Self {
+            destination_memo,
+            hops,
+        }
+    }
+
+    /// Returns true if the packet is not to be forwarded.
+    pub fn is_empty(&self) -> bool {
+        self.hops.is_empty()
+    }
+}
+
+impl TryFrom<RawForwardingPacketData> for ForwardingPacketData {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawForwardingPacketData) -> Result<Self, Self::Error> {
+        let hops = decode_hops(raw.hops)?;
+
+        if hops.is_empty() && !raw.destination_memo.is_empty() {
+            return Err(DecodingError::invalid_raw_data(
+                "forwarding destination memo cannot be set without hops",
+            ));
+        }
+
+        Ok(Self {
+            destination_memo: raw.destination_memo.into(),
+            hops,
+        })
+    }
+}
+
+impl From<ForwardingPacketData> for RawForwardingPacketData {
+    fn from(forwarding: ForwardingPacketData) -> Self {
+        Self {
+            destination_memo: forwarding.destination_memo.to_string(),
+            hops: forwarding.hops.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+fn decode_hops(raw_hops: Vec<RawHop>) -> Result<Vec<Hop>, DecodingError> {
+    if raw_hops.len() > MAX_FORWARDING_HOPS {
+        return Err(DecodingError::invalid_raw_data(format!(
+            "forwarding hops exceed the maximum of {MAX_FORWARDING_HOPS}, got {}",
+            raw_hops.len()
+        )));
+    }
+
+    raw_hops.into_iter().map(Hop::try_from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+    use super::*;
+
+    fn raw_hops(count: u64) -> Vec<RawHop> {
+        (0..count)
+            .map(|i| Hop::new(PortId::transfer(), ChannelId::new(i)).into())
+            .collect()
+    }
+
+    #[test]
+    fn test_forwarding_conversion() {
+        let raw = RawForwarding {
+            unwind: true,
+            hops: raw_hops(2),
+        };
+
+        let forwarding = Forwarding::try_from(raw.clone()).unwrap();
+        assert!(forwarding.unwind);
+        assert_eq!(forwarding.hops[1].to_string(), "transfer/channel-1");
+        assert_eq!(RawForwarding::from(forwarding), raw);
+
+        assert!(Forwarding::try_from(RawForwarding {
+            unwind: false,
+            hops: raw_hops(MAX_FORWARDING_HOPS as u64 + 1),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_forwarding_packet_data_conversion() {
+        let raw = RawForwardingPacketData {
+            destination_memo: "memo".to_string(),
+            hops: raw_hops(1),
+        };
+
+        let forwarding = ForwardingPacketData::try_from(raw.clone()).unwrap();
+        assert_eq!(forwarding.destination_memo.as_ref(), "memo");
+        assert_eq!(RawForwardingPacketData::from(forwarding), raw);
+
+        assert!(ForwardingPacketData::try_from(RawForwardingPacketData {
+            destination_memo: "memo".to_string(),
+            hops: vec![],
+        })
+        .is_err());
+    }
+}
@@ -18,22 +18,31 @@ extern crate std;
 mod amount;
 mod coin;
 mod denom;
+mod forwarding;
 mod memo;
+mod token;
 
 pub use amount::*;
 pub use coin::*;
 pub use denom::*;
+pub use forwarding::*;
+pub mod authorization;
 pub mod error;
 pub mod events;
 pub mod msgs;
 pub mod packet;
+pub mod policy;
 pub use memo::*;
 /// Re-exports `U256` from `primitive-types` crate for convenience.
 pub use primitive_types::U256;
+pub use token::*;
 
 /// Re-exports ICS-20 token transfer proto types from the `ibc-proto` crate.
 pub mod proto {
     pub use ibc_proto::ibc::apps::transfer;
+
+    pub mod v1;
+    pub mod v2;
 }
 
 /// Module identifier for the ICS20 application.
@@ -46,6 +55,10 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+/// ICS20 application version supporting the transfer of multiple tokens in a
+/// single packet.
+pub const VERSION_V2: &str = "ics20-2";
+
 /// The successful string, used for creating an acknowledgement status.
 /// It is equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
@@ -5,10 +5,11 @@ use ibc_core::host::types::error::DecodingError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
 
-use crate::packet::PacketData;
+use crate::packet::PacketDataV2;
+use crate::proto::v1::MsgTransfer as RawMsgTransfer;
+use crate::{Forwarding, PrefixedCoin, Token};
 
 pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 
@@ -33,17 +34,29 @@ pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MsgTransfer {
     /// the port on which the packet will be sent
+    ///
+    /// Unwound tokens must be sent over the port and channel they were last
+    /// received over, which their raw message leaves out.
     pub port_id_on_a: PortId,
     /// the channel by which the packet will be sent
     pub chan_id_on_a: ChannelId,
     /// token transfer packet data of the packet that will be sent
-    pub packet_data: PacketData,
+    ///
+    /// Several tokens can only be transferred at once over ICS-20 v2
+    /// channels.
+    pub packet_data: PacketDataV2,
     /// Timeout height relative to the current block height.
     /// The timeout is disabled when set to None.
     pub timeout_height_on_b: TimeoutHeight,
     /// Timeout timestamp relative to the current block timestamp.
     /// The timeout is disabled when set to 0.
     pub timeout_timestamp_on_b: TimeoutTimestamp,
+    /// The forwarding of the tokens through intermediate chains, which is
+    /// only supported over ICS-20 v2 channels.
+    ///
+    /// The packet data carries the forwarding hops once the transfer is
+    /// sent, with the memo of the message passed on to the final receiver.
+    pub forwarding: Forwarding,
 }
 
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
@@ -53,35 +66,120 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
         let timeout_height_on_b: TimeoutHeight = raw_msg.timeout_height.try_into()?;
         let timeout_timestamp_on_b: TimeoutTimestamp = raw_msg.timeout_timestamp.into();
 
+        // Messages encoded by ibc-go always carry the `token` field, which is
+        // left empty when `tokens` is used instead.
+        let token = raw_msg.token.filter(|coin| !coin.denom.is_empty());
+
+        let coins = match (token, raw_msg.tokens.is_empty()) {
+            (Some(token), true) => vec![token],
+            (None, false) => raw_msg.tokens,
+            (Some(_), false) => {
+                return Err(DecodingError::invalid_raw_data(
+                    "msg transfer cannot set both token and tokens",
+                ))
+            }
+            (None, true) => return Err(DecodingError::missing_raw_data("msg transfer tokens")),
+        };
+
+        let forwarding = raw_msg
+            .forwarding
+            .map(Forwarding::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        // The intermediate chains reuse the timeout timestamp, as heights
+        // differ from one chain to another.
+        if !forwarding.hops.is_empty() && timeout_height_on_b != TimeoutHeight::Never {
+            return Err(DecodingError::invalid_raw_data(
+                "msg transfer cannot set a timeout height along with forwarding hops",
+            ));
+        }
+
+        let tokens: Vec<Token> = coins
+            .into_iter()
+            .map(|coin| PrefixedCoin::try_from(coin).map(Into::into))
+            .collect::<Result<_, _>>()?;
+
+        // As in ibc-go, unwound tokens are sent back over the channel they
+        // were last received over, so the message leaves out its own.
+        let (port_id_on_a, chan_id_on_a) = if forwarding.unwind {
+            if !raw_msg.source_port.is_empty() || !raw_msg.source_channel.is_empty() {
+                return Err(DecodingError::invalid_raw_data(
+                    "msg transfer cannot set a source port and channel when unwinding",
+                ));
+            }
+
+            let last_hop = tokens
+                .first()
+                .and_then(|token| token.denom.trace.first())
+                .ok_or_else(|| {
+                    DecodingError::invalid_raw_data("msg transfer cannot unwind native tokens")
+                })?;
+
+            (last_hop.port_id.clone(), last_hop.channel_id.clone())
+        } else {
+            (
+                raw_msg.source_port.parse()?,
+                raw_msg.source_channel.parse()?,
+            )
+        };
+
         Ok(MsgTransfer {
-            port_id_on_a: raw_msg.source_port.parse()?,
-            chan_id_on_a: raw_msg.source_channel.parse()?,
-            packet_data: PacketData {
-                token: raw_msg
-                    .token
-                    .ok_or(DecodingError::missing_raw_data("msg transfer token"))?
-                    .try_into()?,
+            port_id_on_a,
+            chan_id_on_a,
+            packet_data: PacketDataV2 {
+                tokens,
                 sender: raw_msg.sender.into(),
                 receiver: raw_msg.receiver.into(),
                 memo: raw_msg.memo.into(),
+                forwarding: Default::default(),
             },
             timeout_height_on_b,
             timeout_timestamp_on_b,
+            forwarding,
         })
     }
 }
 
 impl From<MsgTransfer> for RawMsgTransfer {
     fn from(domain_msg: MsgTransfer) -> Self {
+        let mut coins: Vec<_> = domain_msg
+            .packet_data
+            .tokens
+            .into_iter()
+            .map(|token| PrefixedCoin::from(token).into())
+            .collect();
+
+        // A single token is sent in the `token` field, which is understood by
+        // chains that do not support ICS-20 v2
+        let (token, tokens) = if coins.len() == 1 {
+            (coins.pop(), Vec::new())
+        } else {
+            (None, coins)
+        };
+
+        let (source_port, source_channel) = if domain_msg.forwarding.unwind {
+            (String::new(), String::new())
+        } else {
+            (
+                domain_msg.port_id_on_a.to_string(),
+                domain_msg.chan_id_on_a.to_string(),
+            )
+        };
+
+        let forwarding = (!domain_msg.forwarding.is_empty()).then(|| domain_msg.forwarding.into());
+
         RawMsgTransfer {
-            source_port: domain_msg.port_id_on_a.to_string(),
-            source_channel: domain_msg.chan_id_on_a.to_string(),
-            token: Some(domain_msg.packet_data.token.into()),
+            source_port,
+            source_channel,
+            token,
             sender: domain_msg.packet_data.sender.to_string(),
             receiver: domain_msg.packet_data.receiver.to_string(),
             timeout_height: domain_msg.timeout_height_on_b.into(),
             timeout_timestamp: domain_msg.timeout_timestamp_on_b.nanoseconds(),
             memo: domain_msg.packet_data.memo.to_string(),
+            tokens,
+            forwarding,
         }
     }
 }
@@ -102,3 +200,116 @@ impl TryFrom<Any> for MsgTransfer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use super::*;
+    use crate::proto::v1::{Forwarding as RawForwarding, Hop as RawHop};
+
+    fn raw_coin(denom: &str) -> ProtoCoin {
+        ProtoCoin {
+            denom: denom.to_string(),
+            amount: "10".to_string(),
+        }
+    }
+
+    fn raw_msg_transfer() -> RawMsgTransfer {
+        RawMsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Some(raw_coin("uatom")),
+            sender: "sender".to_string(),
+            receiver: "receiver".to_string(),
+            timeout_height: TimeoutHeight::Never.into(),
+            timeout_timestamp: 1,
+            memo: "".to_string(),
+            tokens: vec![],
+            forwarding: None,
+        }
+    }
+
+    #[test]
+    fn test_msg_transfer_tokens() {
+        let msg = MsgTransfer::try_from(raw_msg_transfer()).unwrap();
+        assert_eq!(msg.packet_data.tokens.len(), 1);
+        assert_eq!(RawMsgTransfer::from(msg), raw_msg_transfer());
+
+        let raw_msg = RawMsgTransfer {
+            token: Some(raw_coin("")),
+            tokens: vec![raw_coin("uatom"), raw_coin("transfer/channel-1/uosmo")],
+            ..raw_msg_transfer()
+        };
+        let msg = MsgTransfer::try_from(raw_msg).unwrap();
+        assert_eq!(msg.packet_data.tokens.len(), 2);
+        assert_eq!(msg.packet_data.tokens[1].denom.trace.len(), 1);
+        assert_eq!(RawMsgTransfer::from(msg).tokens.len(), 2);
+
+        for raw_msg in [
+            RawMsgTransfer {
+                token: None,
+                ..raw_msg_transfer()
+            },
+            RawMsgTransfer {
+                tokens: vec![raw_coin("uosmo")],
+                ..raw_msg_transfer()
+            },
+        ] {
+            assert!(MsgTransfer::try_from(raw_msg).is_err());
+        }
+    }
+
+    #[test]
+    fn test_msg_transfer_forwarding() {
+        let raw_forwarding = RawForwarding {
+            unwind: true,
+            hops: vec![RawHop {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-1".to_string(),
+            }],
+        };
+
+        // Unwound tokens are sent over the channel they were last received
+        // over, which the message leaves out.
+        let raw_msg = RawMsgTransfer {
+            source_port: "".to_string(),
+            source_channel: "".to_string(),
+            token: Some(raw_coin("transfer/channel-2/uatom")),
+            forwarding: Some(raw_forwarding.clone()),
+            ..raw_msg_transfer()
+        };
+        let msg = MsgTransfer::try_from(raw_msg.clone()).unwrap();
+        assert!(msg.forwarding.unwind);
+        assert_eq!(msg.forwarding.hops.len(), 1);
+        assert_eq!(msg.chan_id_on_a, ChannelId::new(2));
+        assert_eq!(RawMsgTransfer::from(msg), raw_msg);
+
+        for raw_msg in [
+            RawMsgTransfer {
+                token: Some(raw_coin("transfer/channel-2/uatom")),
+                forwarding: Some(raw_forwarding.clone()),
+                ..raw_msg_transfer()
+            },
+            RawMsgTransfer {
+                source_port: "".to_string(),
+                source_channel: "".to_string(),
+                forwarding: Some(raw_forwarding.clone()),
+                ..raw_msg_transfer()
+            },
+        ] {
+            assert!(MsgTransfer::try_from(raw_msg).is_err());
+        }
+
+        let raw_msg = RawMsgTransfer {
+            timeout_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 10,
+            }),
+            forwarding: Some(raw_forwarding),
+            ..raw_msg_transfer()
+        };
+        assert!(MsgTransfer::try_from(raw_msg).is_err());
+    }
+}
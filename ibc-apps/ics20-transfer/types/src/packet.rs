@@ -1,4 +1,5 @@
-//! Contains the `PacketData` type that defines the structure of token transfers' packet bytes
+//! Contains the `PacketData` and `PacketDataV2` types that define the structure
+//! of token transfers' packet bytes
 
 use core::str::FromStr;
 
@@ -6,8 +7,10 @@ use ibc_core::host::types::error::DecodingError;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
 
-use super::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use super::{Amount, ForwardingPacketData, Memo, PrefixedCoin, PrefixedDenom, Token};
+use crate::proto::v2::FungibleTokenPacketDataV2 as RawPacketDataV2;
 
 /// Defines the structure of token transfers' packet bytes
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -61,6 +64,113 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+/// Defines the structure of the packet bytes of ICS-20 v2 token transfers,
+/// which carry any number of tokens.
+///
+/// Unlike [`PacketData`], which is JSON encoded, this packet data is encoded
+/// with Protobuf.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataV2 {
+    pub tokens: Vec<Token>,
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub memo: Memo,
+    /// The hops left for the tokens to be forwarded through, which cannot be
+    /// carried over ICS-20 v1 channels.
+    pub forwarding: ForwardingPacketData,
+}
+
+impl Protobuf<RawPacketDataV2> for PacketDataV2 {}
+
+impl TryFrom<RawPacketDataV2> for PacketDataV2 {
+    type Error = DecodingError;
+
+    fn try_from(raw_pkt_data: RawPacketDataV2) -> Result<Self, Self::Error> {
+        if raw_pkt_data.tokens.is_empty() {
+            return Err(DecodingError::missing_raw_data("packet data tokens"));
+        }
+
+        Ok(Self {
+            tokens: raw_pkt_data
+                .tokens
+                .into_iter()
+                .map(Token::try_from)
+                .collect::<Result<_, _>>()?,
+            sender: raw_pkt_data.sender.into(),
+            receiver: raw_pkt_data.receiver.into(),
+            memo: raw_pkt_data.memo.into(),
+            forwarding: raw_pkt_data
+                .forwarding
+                .map(ForwardingPacketData::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl From<PacketDataV2> for RawPacketDataV2 {
+    fn from(pkt_data: PacketDataV2) -> Self {
+        Self {
+            tokens: pkt_data.tokens.into_iter().map(Into::into).collect(),
+            sender: pkt_data.sender.to_string(),
+            receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo.to_string(),
+            forwarding: (!pkt_data.forwarding.is_empty()).then(|| pkt_data.forwarding.into()),
+        }
+    }
+}
+
+impl From<PacketData> for PacketDataV2 {
+    fn from(pkt_data: PacketData) -> Self {
+        Self {
+            tokens: vec![pkt_data.token.into()],
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+            forwarding: ForwardingPacketData::default(),
+        }
+    }
+}
+
+impl TryFrom<PacketDataV2> for PacketData {
+    type Error = DecodingError;
+
+    /// Converts ICS-20 v2 packet data carrying exactly one token, and no
+    /// forwarding hops.
+    fn try_from(pkt_data: PacketDataV2) -> Result<Self, Self::Error> {
+        if !pkt_data.forwarding.is_empty() {
+            return Err(DecodingError::invalid_raw_data(
+                "ICS-20 v1 packet data cannot carry forwarding hops",
+            ));
+        }
+
+        let [token] = <[Token; 1]>::try_from(pkt_data.tokens).map_err(|tokens| {
+            DecodingError::invalid_raw_data(format!(
+                "ICS-20 v1 packet data carries a single token, got {}",
+                tokens.len()
+            ))
+        })?;
+
+        Ok(Self {
+            token: token.into(),
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use primitive_types::U256;
@@ -119,4 +229,44 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    #[test]
+    fn test_packet_data_v2_conversion() {
+        let pkt_data = PacketDataV2::from(PacketData::new_dummy());
+
+        let decoded = PacketDataV2::decode_vec(&pkt_data.clone().encode_vec()).unwrap();
+        assert_eq!(decoded, pkt_data);
+        assert_eq!(
+            PacketData::try_from(decoded).unwrap(),
+            PacketData::new_dummy()
+        );
+
+        let multi_token = PacketDataV2 {
+            tokens: vec![pkt_data.tokens[0].clone(), pkt_data.tokens[0].clone()],
+            ..pkt_data.clone()
+        };
+        assert!(PacketData::try_from(multi_token).is_err());
+
+        let forwarded = PacketDataV2 {
+            forwarding: ForwardingPacketData::new(
+                "memo".into(),
+                vec![crate::Hop::new(
+                    "transfer".parse().unwrap(),
+                    "channel-1".parse().unwrap(),
+                )],
+            ),
+            ..pkt_data.clone()
+        };
+        assert_eq!(
+            PacketDataV2::decode_vec(&forwarded.clone().encode_vec()).unwrap(),
+            forwarded
+        );
+        assert!(PacketData::try_from(forwarded).is_err());
+
+        let no_token = RawPacketDataV2 {
+            tokens: vec![],
+            ..pkt_data.into()
+        };
+        assert!(PacketDataV2::try_from(no_token).is_err());
+    }
 }
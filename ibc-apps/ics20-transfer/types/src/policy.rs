@@ -0,0 +1,153 @@
+//! Defines the denomination policy, which restricts the tokens sent and
+//! received over a channel.
+use ibc_core::primitives::prelude::*;
+
+use crate::error::TokenTransferError;
+use crate::{BaseDenom, PrefixedDenom, TracePath};
+
+/// Restrictions on the denominations of the tokens sent and received over a
+/// channel, as named on this chain.
+///
+/// The blocklists take precedence over the allowlists, which allow any
+/// denomination when empty.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DenomPolicy {
+    /// The base denominations allowed
+    pub allowed_base_denoms: Vec<BaseDenom>,
+    /// The base denominations rejected
+    pub blocked_base_denoms: Vec<BaseDenom>,
+    /// The trace paths which the trace of a voucher must start with. Native
+    /// denominations, which have no trace, are not subject to it.
+    pub allowed_trace_prefixes: Vec<TracePath>,
+    /// The trace paths which the trace of a voucher must not start with
+    pub blocked_trace_prefixes: Vec<TracePath>,
+    /// The maximum number of hops in the trace of a voucher
+    pub max_trace_depth: Option<u64>,
+}
+
+impl DenomPolicy {
+    /// Checks that the policy lets the tokens of the given denomination
+    /// through.
+    pub fn check(&self, denom: &PrefixedDenom) -> Result<(), TokenTransferError> {
+        let base_denom = &denom.base_denom;
+        let trace_path = &denom.trace_path;
+
+        if self.blocked_base_denoms.contains(base_denom)
+            || self
+                .blocked_trace_prefixes
+                .iter()
+                .any(|prefix| trace_path.starts_with_path(prefix))
+        {
+            return Err(TokenTransferError::BlockedDenom(denom.clone()));
+        }
+
+        if !self.allowed_base_denoms.is_empty() && !self.allowed_base_denoms.contains(base_denom) {
+            return Err(TokenTransferError::DenomNotAllowed(denom.clone()));
+        }
+
+        if !trace_path.is_empty()
+            && !self.allowed_trace_prefixes.is_empty()
+            && !self
+                .allowed_trace_prefixes
+                .iter()
+                .any(|prefix| trace_path.starts_with_path(prefix))
+        {
+            return Err(TokenTransferError::DenomNotAllowed(denom.clone()));
+        }
+
+        if let Some(max_depth) = self.max_trace_depth {
+            let depth = trace_path.len() as u64;
+
+            if depth > max_depth {
+                return Err(TokenTransferError::TraceTooDeep {
+                    denom: denom.clone(),
+                    depth,
+                    max_depth,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn policy() -> DenomPolicy {
+        DenomPolicy {
+            allowed_base_denoms: vec![],
+            blocked_base_denoms: vec!["ujunk".parse().unwrap()],
+            allowed_trace_prefixes: vec!["transfer/channel-0".parse().unwrap()],
+            blocked_trace_prefixes: vec!["transfer/channel-0/transfer/channel-9".parse().unwrap()],
+            max_trace_depth: Some(2),
+        }
+    }
+
+    #[rstest]
+    #[case("uatom")]
+    #[case("transfer/channel-0/uatom")]
+    #[case("transfer/channel-0/transfer/channel-1/uatom")]
+    fn test_allowed_denom(#[case] denom: &str) {
+        assert!(policy().check(&denom.parse().unwrap()).is_ok());
+    }
+
+    #[rstest]
+    #[case("ujunk")]
+    #[case("transfer/channel-0/ujunk")]
+    #[case("transfer/channel-0/transfer/channel-9/uatom")]
+    fn test_blocked_denom(#[case] denom: &str) {
+        assert!(matches!(
+            policy().check(&denom.parse().unwrap()),
+            Err(TokenTransferError::BlockedDenom(_))
+        ));
+    }
+
+    #[test]
+    fn test_denom_not_allowed() {
+        assert!(matches!(
+            policy().check(&"transfer/channel-1/uatom".parse().unwrap()),
+            Err(TokenTransferError::DenomNotAllowed(_))
+        ));
+
+        let policy = DenomPolicy {
+            allowed_base_denoms: vec!["uatom".parse().unwrap()],
+            ..DenomPolicy::default()
+        };
+        assert!(policy.check(&"uatom".parse().unwrap()).is_ok());
+        assert!(matches!(
+            policy.check(&"uosmo".parse().unwrap()),
+            Err(TokenTransferError::DenomNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_trace_too_deep() {
+        assert!(matches!(
+            policy().check(
+                &"transfer/channel-0/transfer/channel-1/transfer/channel-2/uatom"
+                    .parse()
+                    .unwrap()
+            ),
+            Err(TokenTransferError::TraceTooDeep {
+                depth: 3,
+                max_depth: 2,
+                ..
+            })
+        ));
+    }
+}
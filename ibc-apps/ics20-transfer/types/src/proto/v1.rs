@@ -0,0 +1,85 @@
+//! Protobuf definitions of the `ibc.applications.transfer.v1` package
+//! introduced alongside ICS-20 v2, which are not yet shipped by `ibc-proto`.
+
+use ibc_core::primitives::prelude::*;
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// Message used to transfer one or more tokens over a channel.
+///
+/// Extends the `MsgTransfer` shipped by `ibc-proto` with the `tokens` field.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgTransfer {
+    /// The port on which the packet will be sent
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    /// The channel by which the packet will be sent
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    /// The token to be transferred. Cannot be set along with `tokens`.
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<Coin>,
+    /// The sender address
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    /// The recipient address on the destination chain
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    /// Timeout height relative to the current block height
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<Height>,
+    /// Timeout timestamp in absolute nanoseconds since unix epoch
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    /// Optional memo
+    #[prost(string, tag = "8")]
+    pub memo: String,
+    /// The tokens to be transferred. Cannot be set along with `token`.
+    #[prost(message, repeated, tag = "9")]
+    pub tokens: Vec<Coin>,
+    /// Optional forwarding of the tokens through intermediate chains
+    #[prost(message, optional, tag = "10")]
+    pub forwarding: Option<Forwarding>,
+}
+
+/// The forwarding of the tokens of a transfer through intermediate chains.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Forwarding {
+    /// Whether the tokens are first sent back along their trace to their
+    /// source chain
+    #[prost(bool, tag = "1")]
+    pub unwind: bool,
+    /// The ports and channels over which the intermediate chains forward the
+    /// tokens
+    #[prost(message, repeated, tag = "2")]
+    pub hops: Vec<Hop>,
+}
+
+/// A token with its structured denomination.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Token {
+    #[prost(message, optional, tag = "1")]
+    pub denom: Option<Denom>,
+    /// The amount of the token, as a decimal string
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+/// A denomination split into its base and the hops it went through.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Denom {
+    #[prost(string, tag = "1")]
+    pub base: String,
+    /// The hops the token went through, the most recent one first
+    #[prost(message, repeated, tag = "3")]
+    pub trace: Vec<Hop>,
+}
+
+/// The port and channel through which a token was received.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Hop {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
@@ -0,0 +1,32 @@
+//! Protobuf definitions of the ICS-20 v2 packet data in the
+//! `ibc.applications.transfer.v2` package, which are not yet shipped by
+//! `ibc-proto`.
+
+use ibc_core::primitives::prelude::*;
+
+use super::v1::{Hop, Token};
+
+/// Packet data of an ICS-20 v2 transfer, carrying any number of tokens.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FungibleTokenPacketDataV2 {
+    #[prost(message, repeated, tag = "1")]
+    pub tokens: Vec<Token>,
+    #[prost(string, tag = "2")]
+    pub sender: String,
+    #[prost(string, tag = "3")]
+    pub receiver: String,
+    #[prost(string, tag = "4")]
+    pub memo: String,
+    #[prost(message, optional, tag = "5")]
+    pub forwarding: Option<ForwardingPacketData>,
+}
+
+/// The forwarding hops left to go through by the tokens of a packet.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardingPacketData {
+    /// The memo passed on to the final receiver
+    #[prost(string, tag = "1")]
+    pub destination_memo: String,
+    #[prost(message, repeated, tag = "2")]
+    pub hops: Vec<Hop>,
+}
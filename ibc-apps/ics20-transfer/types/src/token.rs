@@ -0,0 +1,255 @@
+//! Defines the structured token types used by ICS-20 v2, where the trace of a
+//! denomination is a list of hops rather than a `/`-separated string.
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+use crate::proto::v1::{Denom as RawDenom, Hop as RawHop, Token as RawToken};
+use crate::{Amount, BaseDenom, PrefixedCoin};
+
+/// The port and channel through which a token was received.
+///
+/// This is the structured counterpart of a [`TracePrefix`](crate::TracePrefix).
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Hop {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl Hop {
+    pub fn new(port_id: PortId, channel_id: ChannelId) -> Self {
+        Self {
+            port_id,
+            channel_id,
+        }
+    }
+}
+
+impl Display for Hop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}/{}", self.port_id, self.channel_id)
+    }
+}
+
+impl TryFrom<RawHop> for Hop {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHop) -> Result<Self, Self::Error> {
+        Ok(Self::new(raw.port_id.parse()?, raw.channel_id.parse()?))
+    }
+}
+
+impl From<Hop> for RawHop {
+    fn from(hop: Hop) -> Self {
+        Self {
+            port_id: hop.port_id.to_string(),
+            channel_id: hop.channel_id.to_string(),
+        }
+    }
+}
+
+/// A denomination made of its base and the hops the token went through.
+///
+/// Unlike [`PrefixedDenom`](crate::PrefixedDenom), the trace is stored in the
+/// order of the path: the first hop is the one the token went through last.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Denom {
+    pub base: BaseDenom,
+    pub trace: Vec<Hop>,
+}
+
+impl Denom {
+    pub fn new(base: BaseDenom, trace: Vec<Hop>) -> Self {
+        Self { base, trace }
+    }
+
+    /// Returns true if the token was received through the given hop last.
+    pub fn starts_with(&self, hop: &Hop) -> bool {
+        self.trace.first() == Some(hop)
+    }
+}
+
+impl Display for Denom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        for hop in &self.trace {
+            write!(f, "{hop}/")?;
+        }
+        write!(f, "{}", self.base)
+    }
+}
+
+impl TryFrom<RawDenom> for Denom {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawDenom) -> Result<Self, Self::Error> {
+        Ok(Self {
+            base: BaseDenom::from_str(&raw.base)?,
+            trace: raw
+                .trace
+                .into_iter()
+                .map(Hop::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Denom> for RawDenom {
+    fn from(denom: Denom) -> Self {
+        Self {
+            base: denom.base.to_string(),
+            trace: denom.trace.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A token of an ICS-20 v2 transfer, with a structured denomination.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Token {
+    pub denom: Denom,
+    pub amount: Amount,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl TryFrom<RawToken> for Token {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawToken) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom: raw
+                .denom
+                .ok_or(DecodingError::missing_raw_data("token denom"))?
+                .try_into()?,
+            amount: raw.amount.parse()?,
+        })
+    }
+}
+
+impl From<Token> for RawToken {
+    fn from(token: Token) -> Self {
+        Self {
+            denom: Some(token.denom.into()),
+            amount: token.amount.to_string(),
+        }
+    }
+}
+
+impl From<PrefixedCoin> for Token {
+    fn from(coin: PrefixedCoin) -> Self {
+        Self {
+            denom: coin.denom.into(),
+            amount: coin.amount,
+        }
+    }
+}
+
+impl From<Token> for PrefixedCoin {
+    fn from(token: Token) -> Self {
+        Self {
+            denom: token.denom.into(),
+            amount: token.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrefixedDenom;
+
+    #[test]
+    fn test_denom_conversion() {
+        let prefixed_denom =
+            PrefixedDenom::from_str("transfer/channel-1/transfer/channel-0/uatom").unwrap();
+        let denom = Denom::from(prefixed_denom.clone());
+
+        assert_eq!(denom.base.as_str(), "uatom");
+        assert_eq!(
+            denom.trace,
+            vec![
+                Hop::new(PortId::transfer(), ChannelId::new(1)),
+                Hop::new(PortId::transfer(), ChannelId::new(0)),
+            ]
+        );
+        assert!(denom.starts_with(&Hop::new(PortId::transfer(), ChannelId::new(1))));
+        assert_eq!(denom.to_string(), prefixed_denom.to_string());
+        assert_eq!(PrefixedDenom::from(denom), prefixed_denom);
+    }
+
+    #[test]
+    fn test_token_conversion() {
+        let raw_token = RawToken {
+            denom: Some(RawDenom {
+                base: "uatom".to_string(),
+                trace: vec![RawHop {
+                    port_id: "transfer".to_string(),
+                    channel_id: "channel-0".to_string(),
+                }],
+            }),
+            amount: "10".to_string(),
+        };
+
+        let token = Token::try_from(raw_token.clone()).unwrap();
+        assert_eq!(token.to_string(), "10transfer/channel-0/uatom");
+        assert_eq!(RawToken::from(token), raw_token);
+
+        assert!(Token::try_from(RawToken {
+            denom: None,
+            ..raw_token.clone()
+        })
+        .is_err());
+        assert!(Token::try_from(RawToken {
+            amount: "-1".to_string(),
+            ..raw_token
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,59 @@
+//! Defines the context traits required by the controller submodule
+
+use ibc_app_interchain_accounts_types::ControllerParams;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+
+/// Methods required in controller submodule validation, to be implemented by
+/// the host
+pub trait IcaControllerValidationContext {
+    /// Returns the current parameters of the controller submodule.
+    fn controller_params(&self) -> Result<ControllerParams, HostError>;
+
+    /// Returns the active channel of the interchain account owned by the
+    /// given controller port on the given connection, if any.
+    fn active_channel(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Option<ChannelId>, HostError>;
+
+    /// Returns the identifier of the connection the given channel is built
+    /// upon.
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, HostError>;
+
+    /// Returns the address of the interchain account owned by the given
+    /// controller port on the given connection, if it was registered.
+    fn interchain_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Option<String>, HostError>;
+}
+
+/// Methods required in controller submodule execution, to be implemented by
+/// the host
+pub trait IcaControllerExecutionContext: IcaControllerValidationContext {
+    /// Records the channel as the active channel of the interchain account
+    /// owned by the given controller port on the given connection.
+    fn store_active_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), HostError>;
+
+    /// Records the address of the interchain account owned by the given
+    /// controller port on the given connection, as reported by the host chain.
+    fn store_interchain_account_address(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        address: String,
+    ) -> Result<(), HostError>;
+}
@@ -0,0 +1,21 @@
+//! Implements the processing logic for the messages of the ICS-27 controller
+//! submodule.
+mod register_interchain_account;
+mod send_tx;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_core::primitives::prelude::*;
+pub use register_interchain_account::*;
+pub use send_tx::*;
+
+use crate::controller::context::IcaControllerValidationContext;
+
+pub(crate) fn ensure_controller_enabled(
+    ica_ctx: &impl IcaControllerValidationContext,
+) -> Result<(), InterchainAccountError> {
+    if !ica_ctx.controller_params()?.controller_enabled {
+        return Err(InterchainAccountError::ControllerDisabled);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::msgs::MsgRegisterInterchainAccount;
+use ibc_app_interchain_accounts_types::{controller_port_id, host_port_id, Metadata};
+use ibc_core::channel::handler::{chan_open_init_execute, chan_open_init_validate};
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::msgs::MsgChannelOpenInit;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::module::Module;
+
+use super::ensure_controller_enabled;
+use crate::controller::context::IcaControllerValidationContext;
+use crate::metadata::metadata_version;
+
+/// Validates the registration of an interchain account. If this succeeds,
+/// then it is legal to initiate the channel opening handshake with
+/// [`register_interchain_account_execute`].
+///
+/// An owner may register a new channel for its interchain account only if
+/// the previous one is no longer open, e.g. after an ordered channel was
+/// closed by a packet timeout.
+pub fn register_interchain_account_validate<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    module: &dyn Module,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: IcaControllerValidationContext,
+{
+    ensure_controller_enabled(ica_ctx_a)?;
+
+    let msg = chan_open_init_msg(ctx_a, msg)?;
+
+    if let Some(channel_id) =
+        ica_ctx_a.active_channel(&msg.connection_hops_on_a[0], &msg.port_id_on_a)?
+    {
+        let chan_end_path = ChannelEndPath::new(&msg.port_id_on_a, &channel_id);
+
+        if ctx_a.channel_end(&chan_end_path)?.is_open() {
+            return Err(InterchainAccountError::ActiveChannelAlreadySet {
+                port_id: msg.port_id_on_a,
+                channel_id,
+            });
+        }
+    }
+
+    chan_open_init_validate(ctx_a, module, msg)?;
+
+    Ok(())
+}
+
+/// Initiates the channel opening handshake for the interchain account, and
+/// returns the identifier of the new channel. A prior call to
+/// [`register_interchain_account_validate`] MUST have succeeded.
+pub fn register_interchain_account_execute<Ctx>(
+    ctx_a: &mut Ctx,
+    module: &mut dyn Module,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<ChannelId, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+{
+    let msg = chan_open_init_msg(ctx_a, msg)?;
+
+    let channel_id = ChannelId::new(ctx_a.channel_counter()?);
+
+    chan_open_init_execute(ctx_a, module, msg)?;
+
+    Ok(channel_id)
+}
+
+/// Builds the channel opening message for the owner's controller port. When
+/// no version is given, the default metadata for the connection is proposed.
+fn chan_open_init_msg<Ctx>(
+    ctx_a: &Ctx,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<MsgChannelOpenInit, InterchainAccountError>
+where
+    Ctx: ValidationContext,
+{
+    let port_id = controller_port_id(&msg.owner).map_err(DecodingError::from)?;
+
+    if !matches!(
+        msg.ordering,
+        Order::Ordered | Order::Unordered | Order::OrderedAllowTimeout
+    ) {
+        return Err(InterchainAccountError::UnsupportedOrder {
+            order: msg.ordering,
+        });
+    }
+
+    let version = if msg.version.is_empty() {
+        let connection_end = ctx_a.connection_end(&msg.connection_id)?;
+
+        let host_connection_id = connection_end
+            .counterparty()
+            .connection_id()
+            .ok_or_else(|| InterchainAccountError::MissingCounterpartyConnection {
+                connection_id: msg.connection_id.clone(),
+            })?
+            .clone();
+
+        metadata_version(&Metadata::new(
+            msg.connection_id.clone(),
+            host_connection_id,
+        ))
+    } else {
+        msg.version
+    };
+
+    Ok(MsgChannelOpenInit {
+        port_id_on_a: port_id,
+        connection_hops_on_a: vec![msg.connection_id],
+        port_id_on_b: host_port_id(),
+        ordering: msg.ordering,
+        signer: msg.owner,
+        version_proposal: version,
+    })
+}
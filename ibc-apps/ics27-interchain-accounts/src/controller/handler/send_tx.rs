@@ -0,0 +1,142 @@
+use core::time::Duration;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::msgs::MsgSendTx;
+use ibc_app_interchain_accounts_types::{controller_port_id, CONTROLLER_MODULE_ID_STR};
+use ibc_core::channel::handler::{send_module_packet, send_packet_validate};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::Sequence;
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::module::Module;
+
+use super::ensure_controller_enabled;
+use crate::controller::context::IcaControllerValidationContext;
+
+/// Sends a transaction to be executed by an interchain account. Equivalent to
+/// calling [`send_tx_validate`], followed by [`send_tx_execute`].
+pub fn send_tx<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    ica_ctx_a: &IcaCtx,
+    module: &mut dyn Module,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: IcaControllerValidationContext,
+{
+    send_tx_validate(ctx_a, ica_ctx_a, msg.clone())?;
+    send_tx_execute(ctx_a, ica_ctx_a, module, msg)
+}
+
+/// Validates the transaction. If this succeeds, then it is legal to send it
+/// with [`send_tx_execute`].
+pub fn send_tx_validate<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: IcaControllerValidationContext,
+{
+    ensure_controller_enabled(ica_ctx_a)?;
+
+    msg.packet_data.validate_basic()?;
+
+    let packet = build_packet(ctx_a, ica_ctx_a, msg)?;
+
+    send_packet_validate(ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Sends the transaction over the active channel of the interchain account,
+/// and returns the sequence of the packet carrying it. The packet is sent
+/// through `module`, the module stack bound to the controller port of the
+/// owner. A prior call to [`send_tx_validate`] MUST have succeeded.
+pub fn send_tx_execute<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    ica_ctx_a: &IcaCtx,
+    module: &mut dyn Module,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: IcaControllerValidationContext,
+{
+    let packet = build_packet(ctx_a, ica_ctx_a, msg)?;
+    let sequence = packet.seq_on_a;
+
+    send_module_packet(ctx_a, module, packet)?;
+
+    ctx_a.emit_ibc_event(MessageEvent::Module(CONTROLLER_MODULE_ID_STR.to_string()).into())?;
+
+    Ok(sequence)
+}
+
+/// Builds the packet carrying the transaction over the active channel of the
+/// owner's interchain account, which must be open.
+fn build_packet<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Packet, InterchainAccountError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: IcaControllerValidationContext,
+{
+    let port_id_on_a = controller_port_id(&msg.owner).map_err(DecodingError::from)?;
+
+    let chan_id_on_a = ica_ctx_a
+        .active_channel(&msg.connection_id, &port_id_on_a)?
+        .ok_or_else(|| InterchainAccountError::MissingActiveChannel {
+            connection_id: msg.connection_id.clone(),
+            port_id: port_id_on_a.clone(),
+        })?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let chan_id_on_b = match chan_end_on_a.counterparty().channel_id() {
+        Some(chan_id_on_b) if chan_end_on_a.is_open() => chan_id_on_b.clone(),
+        _ => {
+            return Err(InterchainAccountError::InactiveChannel {
+                port_id: port_id_on_a,
+                channel_id: chan_id_on_a,
+            })
+        }
+    };
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+
+    if msg.relative_timeout == 0 {
+        return Err(InterchainAccountError::InvalidTimeout {
+            description: "relative timeout must be non-zero".to_string(),
+        });
+    }
+
+    let host_timestamp = ctx_a.host_timestamp()?;
+    let timeout_timestamp_on_b =
+        (TimeoutTimestamp::At(host_timestamp) + Duration::from_nanos(msg.relative_timeout))?;
+
+    let seq_send_path_on_a = SeqSendPath::new(&port_id_on_a, &chan_id_on_a);
+    let seq_on_a = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let data = serde_json::to_vec(&msg.packet_data)
+        .expect("InterchainAccountPacketData's infallible Serialize impl failed");
+
+    Ok(Packet {
+        seq_on_a,
+        port_id_on_a,
+        chan_id_on_a,
+        port_id_on_b,
+        chan_id_on_b,
+        data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b,
+    })
+}
@@ -0,0 +1,7 @@
+//! The controller submodule, which registers interchain accounts on a host
+//! chain and sends them transactions to execute.
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod module;
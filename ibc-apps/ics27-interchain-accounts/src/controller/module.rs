@@ -0,0 +1,259 @@
+//! Implements the IBC module callbacks of the controller submodule
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::{host_port_id, is_controller_port};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use super::handler::ensure_controller_enabled;
+use crate::controller::context::{IcaControllerExecutionContext, IcaControllerValidationContext};
+use crate::metadata::parse_metadata;
+
+pub fn on_chan_open_init_validate(
+    ctx: &impl IcaControllerValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(), InterchainAccountError> {
+    ensure_controller_enabled(ctx)?;
+
+    if !is_controller_port(port_id) {
+        return Err(InterchainAccountError::InvalidControllerPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    let host_port_id = host_port_id();
+    if counterparty.port_id() != &host_port_id {
+        return Err(InterchainAccountError::InvalidHostPort {
+            expected: host_port_id,
+            actual: counterparty.port_id().clone(),
+        });
+    }
+
+    if !matches!(
+        order,
+        Order::Ordered | Order::Unordered | Order::OrderedAllowTimeout
+    ) {
+        return Err(InterchainAccountError::UnsupportedOrder { order });
+    }
+
+    let metadata = parse_metadata(version)?;
+    metadata.validate_controller_connection(connection_hops)?;
+
+    // A channel reopened for an existing interchain account must not
+    // propose a different account address.
+    if let Some(address) =
+        ctx.interchain_account_address(&metadata.controller_connection_id, port_id)?
+    {
+        if !metadata.address.is_empty() && metadata.address != address {
+            return Err(InterchainAccountError::MismatchedAccountAddress {
+                expected: address,
+                address: metadata.address,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    Ok((ModuleExtras::empty(), version.clone()))
+}
+
+pub fn on_chan_open_try_validate(
+    _ctx: &impl IcaControllerValidationContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::UnsupportedAction {
+        description: "channel handshake must be initiated by the controller".to_string(),
+    })
+}
+
+pub fn on_chan_open_try_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    Err(InterchainAccountError::UnsupportedAction {
+        description: "channel handshake must be initiated by the controller".to_string(),
+    })
+}
+
+pub fn on_chan_open_ack_validate(
+    ctx: &impl IcaControllerValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    if !is_controller_port(port_id) {
+        return Err(InterchainAccountError::InvalidControllerPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    let metadata = parse_metadata(counterparty_version)?;
+
+    let connection_id = ctx.channel_connection_id(port_id, channel_id)?;
+    metadata.validate_controller_connection(&[connection_id])?;
+
+    if metadata.address.is_empty() {
+        return Err(InterchainAccountError::InvalidMetadata {
+            description: "host did not report the interchain account address".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn on_chan_open_ack_execute(
+    ctx: &mut impl IcaControllerExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    let metadata = parse_metadata(counterparty_version)?;
+
+    ctx.store_active_channel(&metadata.controller_connection_id, port_id, channel_id)?;
+    ctx.store_interchain_account_address(
+        &metadata.controller_connection_id,
+        port_id,
+        metadata.address,
+    )?;
+
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl IcaControllerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::UnsupportedAction {
+        description: "channel handshake must be initiated by the controller".to_string(),
+    })
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(InterchainAccountError::UnsupportedAction {
+        description: "channel handshake must be initiated by the controller".to_string(),
+    })
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl IcaControllerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::UnsupportedAction {
+        description: "interchain account channels cannot be closed by users".to_string(),
+    })
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(InterchainAccountError::UnsupportedAction {
+        description: "interchain account channels cannot be closed by users".to_string(),
+    })
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl IcaControllerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_recv_packet_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _packet: &Packet,
+) -> (ModuleExtras, Acknowledgement) {
+    let error = InterchainAccountError::UnsupportedAction {
+        description: "controller chain does not accept packets".to_string(),
+    };
+
+    (
+        ModuleExtras::empty(),
+        AcknowledgementStatus::error(error.into()).into(),
+    )
+}
+
+/// The controller submodule has no state to update on acknowledgement; the
+/// result of the transaction is for the owner to process, e.g. in a
+/// middleware wrapping the controller module.
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl IcaControllerValidationContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_acknowledgement_packet_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (ModuleExtras::empty(), Ok(()))
+}
+
+/// On ordered channels, the core handler closes the channel on timeout, after
+/// which the owner may register a new channel for the same account.
+pub fn on_timeout_packet_validate(
+    _ctx: &impl IcaControllerValidationContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_timeout_packet_execute(
+    _ctx: &mut impl IcaControllerExecutionContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (ModuleExtras::empty(), Ok(()))
+}
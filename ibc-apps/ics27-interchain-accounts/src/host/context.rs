@@ -0,0 +1,66 @@
+//! Defines the context traits required by the host submodule
+
+use ibc_app_interchain_accounts_types::HostParams;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+
+/// Methods required in host submodule validation, to be implemented by the
+/// host
+pub trait IcaHostValidationContext {
+    /// Returns the current parameters of the host submodule.
+    fn host_params(&self) -> Result<HostParams, HostError>;
+
+    /// Returns the interchain account owned by the given controller port on
+    /// the given connection, if it was created.
+    fn interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<Signer>, HostError>;
+
+    /// Returns the active channel of the interchain account owned by the
+    /// given controller port on the given connection, if any.
+    fn active_channel(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<ChannelId>, HostError>;
+
+    /// Returns the identifier of the connection the given channel is built
+    /// upon.
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, HostError>;
+}
+
+/// Methods required in host submodule execution, to be implemented by the
+/// host
+pub trait IcaHostExecutionContext: IcaHostValidationContext {
+    /// Creates and records a new interchain account owned by the given
+    /// controller port on the given connection, and returns its address.
+    fn new_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Signer, HostError>;
+
+    /// Records the channel as the active channel of the interchain account
+    /// owned by the given controller port on the given connection.
+    fn store_active_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), HostError>;
+
+    /// Executes the messages on behalf of the interchain account, atomically,
+    /// and returns one response per message.
+    ///
+    /// If an error is returned, none of the messages must have been applied.
+    fn execute_tx(&mut self, account: &Signer, messages: Vec<Any>) -> Result<Vec<Any>, HostError>;
+}
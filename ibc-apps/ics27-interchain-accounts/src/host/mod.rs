@@ -0,0 +1,5 @@
+//! The host submodule, which creates the interchain accounts requested by
+//! controller chains and executes the transactions they send.
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod module;
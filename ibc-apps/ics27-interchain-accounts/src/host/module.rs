@@ -0,0 +1,335 @@
+//! Implements the IBC module callbacks of the host submodule
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::events::HostPacketEvent;
+use ibc_app_interchain_accounts_types::{
+    host_port_id, is_controller_port, InterchainAccountPacketData,
+};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+use ibc_proto::cosmos::base::abci::v1beta1::TxMsgData;
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use crate::host::context::{IcaHostExecutionContext, IcaHostValidationContext};
+use crate::metadata::{metadata_version, parse_metadata};
+
+fn ensure_host_enabled(ctx: &impl IcaHostValidationContext) -> Result<(), InterchainAccountError> {
+    if !ctx.host_params()?.host_enabled {
+        return Err(InterchainAccountError::HostDisabled);
+    }
+
+    Ok(())
+}
+
+fn unsupported_action(description: &str) -> InterchainAccountError {
+    InterchainAccountError::UnsupportedAction {
+        description: description.to_string(),
+    }
+}
+
+pub fn on_chan_open_init_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported_action(
+        "channel handshake must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    Err(unsupported_action(
+        "channel handshake must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_try_validate(
+    ctx: &impl IcaHostValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    ensure_host_enabled(ctx)?;
+
+    let host_port_id = host_port_id();
+    if port_id != &host_port_id {
+        return Err(InterchainAccountError::InvalidHostPort {
+            expected: host_port_id,
+            actual: port_id.clone(),
+        });
+    }
+
+    if !is_controller_port(counterparty.port_id()) {
+        return Err(InterchainAccountError::InvalidControllerPort {
+            port_id: counterparty.port_id().clone(),
+        });
+    }
+
+    if !matches!(
+        order,
+        Order::Ordered | Order::Unordered | Order::OrderedAllowTimeout
+    ) {
+        return Err(InterchainAccountError::UnsupportedOrder { order });
+    }
+
+    let metadata = parse_metadata(counterparty_version)?;
+    metadata.validate_host_connection(connection_hops)?;
+
+    // The controller may only propose the address of the account it already
+    // owns, when reopening a channel for it.
+    if !metadata.address.is_empty() {
+        let account =
+            ctx.interchain_account(&metadata.host_connection_id, counterparty.port_id())?;
+
+        if account.as_ref().map(AsRef::as_ref) != Some(metadata.address.as_str()) {
+            return Err(InterchainAccountError::MismatchedAccountAddress {
+                expected: account.map(|a| a.to_string()).unwrap_or_default(),
+                address: metadata.address,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Reuses the interchain account owned by the controller port on the
+/// connection, or creates it, and reports its address in the version.
+pub fn on_chan_open_try_execute(
+    ctx: &mut impl IcaHostExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    let mut metadata = parse_metadata(counterparty_version)?;
+    let connection_id = &metadata.host_connection_id;
+    let controller_port_id = counterparty.port_id();
+
+    let account = match ctx.interchain_account(connection_id, controller_port_id)? {
+        Some(account) => account,
+        None => ctx.new_interchain_account(connection_id, controller_port_id)?,
+    };
+
+    ctx.store_active_channel(connection_id, controller_port_id, channel_id)?;
+
+    metadata.address = account.to_string();
+
+    Ok((ModuleExtras::empty(), metadata_version(&metadata)))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported_action(
+        "channel handshake must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(unsupported_action(
+        "channel handshake must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported_action(
+        "interchain account channels cannot be closed by users",
+    ))
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(unsupported_action(
+        "interchain account channels cannot be closed by users",
+    ))
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Executes the transaction carried by the packet on behalf of the
+/// interchain account. On success, the acknowledgement result is the
+/// base64-encoded `TxMsgData` holding the message responses.
+pub fn on_recv_packet_execute(
+    ctx: &mut impl IcaHostExecutionContext,
+    packet: &Packet,
+) -> (ModuleExtras, Acknowledgement) {
+    let (ack, error) = match process_recv_packet_execute(ctx, packet) {
+        Ok(msg_responses) => {
+            let tx_msg_data = TxMsgData {
+                msg_responses,
+                ..Default::default()
+            };
+            let result = BASE64_STANDARD.encode(tx_msg_data.encode_to_vec());
+
+            match StatusValue::new(result) {
+                Ok(result) => (AcknowledgementStatus::success(result), None),
+                Err(e) => (
+                    AcknowledgementStatus::error(InterchainAccountError::from(e).into()),
+                    Some(String::from("empty transaction result")),
+                ),
+            }
+        }
+        Err(e) => {
+            let error = e.to_string();
+            (AcknowledgementStatus::error(e.into()), Some(error))
+        }
+    };
+
+    let extras = ModuleExtras {
+        events: vec![HostPacketEvent {
+            host_channel_id: packet.chan_id_on_b.clone(),
+            error,
+        }
+        .into()],
+        log: Vec::new(),
+    };
+
+    (extras, ack.into())
+}
+
+fn process_recv_packet_execute(
+    ctx: &mut impl IcaHostExecutionContext,
+    packet: &Packet,
+) -> Result<Vec<Any>, InterchainAccountError> {
+    ensure_host_enabled(ctx)?;
+
+    let data = serde_json::from_slice::<InterchainAccountPacketData>(&packet.data)
+        .map_err(|_| InterchainAccountError::FailedToDeserializePacketData)?;
+    data.validate_basic()?;
+
+    let tx = data.cosmos_tx()?;
+
+    let params = ctx.host_params()?;
+    if let Some(msg) = tx
+        .messages
+        .iter()
+        .find(|msg| !params.is_message_allowed(&msg.type_url))
+    {
+        return Err(InterchainAccountError::MessageNotAllowed {
+            type_url: msg.type_url.clone(),
+        });
+    }
+
+    let connection_id = ctx.channel_connection_id(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+
+    let account = ctx
+        .interchain_account(&connection_id, &packet.port_id_on_a)?
+        .ok_or_else(|| InterchainAccountError::MissingInterchainAccount {
+            connection_id,
+            port_id: packet.port_id_on_a.clone(),
+        })?;
+
+    Ok(ctx.execute_tx(&account, tx.messages)?)
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported_action("host chain does not send packets"))
+}
+
+pub fn on_acknowledgement_packet_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (
+        ModuleExtras::empty(),
+        Err(unsupported_action("host chain does not send packets")),
+    )
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl IcaHostValidationContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported_action("host chain does not send packets"))
+}
+
+pub fn on_timeout_packet_execute(
+    _ctx: &mut impl IcaHostExecutionContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (
+        ModuleExtras::empty(),
+        Err(unsupported_action("host chain does not send packets")),
+    )
+}
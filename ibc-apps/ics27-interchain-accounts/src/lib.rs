@@ -0,0 +1,37 @@
+//! Implementation of the IBC [Interchain
+//! Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) application logic.
+//!
+//! The application is split into two submodules: the [`controller`], which
+//! registers interchain accounts and sends them transactions to execute, and
+//! the [`host`], which creates the accounts and executes the transactions on
+//! their behalf.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod controller;
+pub mod host;
+#[cfg(feature = "serde")]
+mod metadata;
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_interchain_accounts_types::*;
+}
@@ -0,0 +1,25 @@
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::Metadata;
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+
+/// Parses the JSON-encoded metadata negotiated as the channel version, and
+/// checks it against the versions supported by this implementation.
+pub(crate) fn parse_metadata(version: &Version) -> Result<Metadata, InterchainAccountError> {
+    let metadata = serde_json::from_str::<Metadata>(version.as_str()).map_err(|_| {
+        InterchainAccountError::InvalidMetadata {
+            description: format!("failed to deserialize version `{version}`"),
+        }
+    })?;
+
+    metadata.validate_basic()?;
+
+    Ok(metadata)
+}
+
+/// Encodes the metadata as a channel version.
+pub(crate) fn metadata_version(metadata: &Metadata) -> Version {
+    Version::new(
+        serde_json::to_string(metadata).expect("Metadata's infallible Serialize impl failed"),
+    )
+}
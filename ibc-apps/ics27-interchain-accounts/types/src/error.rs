@@ -0,0 +1,120 @@
+//! Defines the Interchain Accounts (ICS-27) error types.
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::host::types::error::{DecodingError, HostError};
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountError {
+    /// host error: {0}
+    Host(HostError),
+    /// channel error: {0}
+    Channel(ChannelError),
+    /// decoding error: {0}
+    Decoding(DecodingError),
+    /// controller submodule is disabled
+    ControllerDisabled,
+    /// host submodule is disabled
+    HostDisabled,
+    /// invalid version: expected `{expected}`, actual `{actual}`
+    InvalidVersion { expected: String, actual: String },
+    /// invalid version metadata: {description}
+    InvalidMetadata { description: String },
+    /// invalid controller port `{port_id}`
+    InvalidControllerPort { port_id: PortId },
+    /// invalid host port: expected `{expected}`, actual `{actual}`
+    InvalidHostPort { expected: PortId, actual: PortId },
+    /// unsupported channel order `{order}`
+    UnsupportedOrder { order: Order },
+    /// mismatched connection IDs: expected `{expected}`, actual `{actual}`
+    MismatchedConnectionIds {
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// channel `{channel_id}` on port `{port_id}` is already active for the interchain account
+    ActiveChannelAlreadySet {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// missing active channel for port `{port_id}` on connection `{connection_id}`
+    MissingActiveChannel {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// active channel `{channel_id}` on port `{port_id}` is not open
+    InactiveChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// counterparty of connection `{connection_id}` has no connection identifier yet
+    MissingCounterpartyConnection { connection_id: ConnectionId },
+    /// missing interchain account for port `{port_id}` on connection `{connection_id}`
+    MissingInterchainAccount {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// interchain account address `{address}` does not match the registered one `{expected}`
+    MismatchedAccountAddress { expected: String, address: String },
+    /// message type `{type_url}` is not allowed on the host chain
+    MessageNotAllowed { type_url: String },
+    /// transaction carries no messages
+    EmptyTx,
+    /// unsupported packet data type
+    UnsupportedPacketType,
+    /// failed to deserialize packet data
+    FailedToDeserializePacketData,
+    /// invalid timeout: {description}
+    InvalidTimeout { description: String },
+    /// unsupported action: {description}
+    UnsupportedAction { description: String },
+}
+
+impl From<HostError> for InterchainAccountError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+impl From<ChannelError> for InterchainAccountError {
+    fn from(e: ChannelError) -> Self {
+        Self::Channel(e)
+    }
+}
+
+impl From<DecodingError> for InterchainAccountError {
+    fn from(e: DecodingError) -> Self {
+        Self::Decoding(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InterchainAccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Host(e) => Some(e),
+            Self::Channel(e) => Some(e),
+            Self::Decoding(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InterchainAccountError> for ChannelError {
+    fn from(err: InterchainAccountError) -> Self {
+        match err {
+            InterchainAccountError::Channel(e) => e,
+            e => ChannelError::AppSpecific {
+                description: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<InterchainAccountError> for StatusValue {
+    fn from(e: InterchainAccountError) -> Self {
+        StatusValue::new(e.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,41 @@
+//! Defines all interchain accounts event types
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+/// The module name reported in the events, shared by both submodules.
+const MODULE_NAME: &str = "interchainaccounts";
+
+const EVENT_TYPE_PACKET: &str = "ics27_packet";
+
+/// Event emitted by the host submodule when it receives a packet, reporting
+/// whether the transaction it carries was executed successfully
+pub struct HostPacketEvent {
+    pub host_channel_id: ChannelId,
+    /// The reason the transaction failed, if it did
+    pub error: Option<String>,
+}
+
+impl From<HostPacketEvent> for ModuleEvent {
+    fn from(ev: HostPacketEvent) -> Self {
+        let HostPacketEvent {
+            host_channel_id,
+            error,
+        } = ev;
+
+        let mut attributes = vec![
+            ("module", MODULE_NAME).into(),
+            ("host_channel_id", host_channel_id).into(),
+            ("success", error.is_none()).into(),
+        ];
+
+        if let Some(error) = error {
+            attributes.push(("error", error).into());
+        }
+
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes,
+        }
+    }
+}
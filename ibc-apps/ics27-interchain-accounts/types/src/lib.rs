@@ -0,0 +1,78 @@
+//! Implementation of the IBC [Interchain
+//! Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod metadata;
+mod packet;
+mod params;
+
+pub use metadata::*;
+pub use packet::*;
+pub use params::*;
+pub mod error;
+pub mod events;
+pub mod msgs;
+
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// Re-exports ICS-27 interchain accounts proto types from the `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::apps::interchain_accounts;
+}
+
+/// Module identifier for the ICS-27 controller submodule.
+pub const CONTROLLER_MODULE_ID_STR: &str = "icacontroller";
+
+/// Module identifier for the ICS-27 host submodule.
+pub const HOST_MODULE_ID_STR: &str = "icahost";
+
+/// The port identifier the host submodule binds to.
+pub const HOST_PORT_ID_STR: &str = "icahost";
+
+/// The prefix of the port identifiers the controller submodule binds to, one
+/// per interchain account owner.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// ICS-27 interchain accounts current version.
+pub const VERSION: &str = "ics27-1";
+
+/// The only supported encoding of the transactions carried by the packets.
+pub const ENCODING_PROTOBUF: &str = "proto3";
+
+/// The only supported transaction type, where each packet carries a list of
+/// Cosmos SDK messages.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+
+/// Returns the port identifier the host submodule binds to.
+pub fn host_port_id() -> PortId {
+    PortId::new(HOST_PORT_ID_STR.to_string()).expect("never fails")
+}
+
+/// Returns the controller port identifier of the given interchain account
+/// owner.
+pub fn controller_port_id(owner: &Signer) -> Result<PortId, IdentifierError> {
+    PortId::new(format!("{CONTROLLER_PORT_PREFIX}{owner}"))
+}
+
+/// Returns true if the given port identifier is a controller port.
+pub fn is_controller_port(port_id: &PortId) -> bool {
+    port_id.as_str().starts_with(CONTROLLER_PORT_PREFIX)
+}
@@ -0,0 +1,177 @@
+//! Defines the version metadata negotiated on interchain account channels.
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::apps::interchain_accounts::v1::Metadata as RawMetadata;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountError;
+use crate::{ENCODING_PROTOBUF, TX_TYPE_SDK_MULTI_MSG, VERSION};
+
+/// The channel version of an interchain account channel.
+///
+/// It is JSON encoded in the channel version. The controller proposes it with
+/// an empty `address`, which the host fills in with the address of the
+/// interchain account.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Returns the metadata proposed by the controller for a new channel,
+    /// with the default encoding and transaction type.
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            controller_connection_id,
+            host_connection_id,
+            address: String::new(),
+            encoding: ENCODING_PROTOBUF.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    /// Checks the version, encoding and transaction type against the ones
+    /// supported by this implementation.
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountError> {
+        if self.version != VERSION {
+            return Err(InterchainAccountError::InvalidVersion {
+                expected: VERSION.to_string(),
+                actual: self.version.clone(),
+            });
+        }
+
+        if self.encoding != ENCODING_PROTOBUF {
+            return Err(InterchainAccountError::InvalidMetadata {
+                description: format!("unsupported encoding `{}`", self.encoding),
+            });
+        }
+
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(InterchainAccountError::InvalidMetadata {
+                description: format!("unsupported transaction type `{}`", self.tx_type),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the metadata was negotiated over the given connection
+    /// hops, on the controller end of the channel.
+    pub fn validate_controller_connection(
+        &self,
+        connection_hops: &[ConnectionId],
+    ) -> Result<(), InterchainAccountError> {
+        validate_connection(&self.controller_connection_id, connection_hops)
+    }
+
+    /// Checks that the metadata was negotiated over the given connection
+    /// hops, on the host end of the channel.
+    pub fn validate_host_connection(
+        &self,
+        connection_hops: &[ConnectionId],
+    ) -> Result<(), InterchainAccountError> {
+        validate_connection(&self.host_connection_id, connection_hops)
+    }
+}
+
+fn validate_connection(
+    expected: &ConnectionId,
+    connection_hops: &[ConnectionId],
+) -> Result<(), InterchainAccountError> {
+    let [connection_id] = connection_hops else {
+        return Err(InterchainAccountError::InvalidMetadata {
+            description: format!(
+                "expected a single connection hop, got {}",
+                connection_hops.len()
+            ),
+        });
+    };
+
+    if connection_id != expected {
+        return Err(InterchainAccountError::MismatchedConnectionIds {
+            expected: expected.clone(),
+            actual: connection_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+impl Protobuf<RawMetadata> for Metadata {}
+
+impl TryFrom<RawMetadata> for Metadata {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMetadata) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: raw.version,
+            controller_connection_id: raw.controller_connection_id.parse()?,
+            host_connection_id: raw.host_connection_id.parse()?,
+            address: raw.address,
+            encoding: raw.encoding,
+            tx_type: raw.tx_type,
+        })
+    }
+}
+
+impl From<Metadata> for RawMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            version: metadata.version,
+            controller_connection_id: metadata.controller_connection_id.to_string(),
+            host_connection_id: metadata.host_connection_id.to_string(),
+            address: metadata.address,
+            encoding: metadata.encoding,
+            tx_type: metadata.tx_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_json() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+
+        let json = serde_json::to_string(&metadata).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#
+        );
+        assert_eq!(serde_json::from_str::<Metadata>(&json).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_validate_connection() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+
+        assert!(metadata
+            .validate_controller_connection(&[ConnectionId::new(0)])
+            .is_ok());
+        assert!(metadata
+            .validate_host_connection(&[ConnectionId::new(0)])
+            .is_err());
+        assert!(metadata.validate_controller_connection(&[]).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//! Defines the interchain accounts (ICS-27) controller message types.
+pub mod register_interchain_account;
+pub mod send_tx;
+
+pub use register_interchain_account::*;
+pub use send_tx::*;
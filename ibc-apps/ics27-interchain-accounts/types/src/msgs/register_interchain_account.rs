@@ -0,0 +1,106 @@
+//! Defines the `MsgRegisterInterchainAccount` message type
+
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::interchain_accounts::controller::v1::MsgRegisterInterchainAccount as RawMsgRegisterInterchainAccount;
+use ibc_proto::Protobuf;
+
+pub(crate) const TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+
+/// Message used by an owner to register an interchain account on the host
+/// chain at the other end of the given connection, which opens the channel
+/// between the controller and the host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterInterchainAccount {
+    pub owner: Signer,
+    pub connection_id: ConnectionId,
+    /// the JSON encoded version metadata, which defaults to the one of the
+    /// connection if empty
+    pub version: Version,
+    /// the channel ordering, which defaults to ordered if unspecified
+    pub ordering: Order,
+}
+
+impl TryFrom<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgRegisterInterchainAccount) -> Result<Self, Self::Error> {
+        let ordering = match Order::from_i32(raw_msg.ordering)
+            .map_err(|e| DecodingError::invalid_raw_data(e.to_string()))?
+        {
+            Order::None => Order::Ordered,
+            ordering => ordering,
+        };
+
+        Ok(MsgRegisterInterchainAccount {
+            owner: raw_msg.owner.into(),
+            connection_id: raw_msg.connection_id.parse()?,
+            version: Version::new(raw_msg.version),
+            ordering,
+        })
+    }
+}
+
+impl From<MsgRegisterInterchainAccount> for RawMsgRegisterInterchainAccount {
+    fn from(domain_msg: MsgRegisterInterchainAccount) -> Self {
+        RawMsgRegisterInterchainAccount {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.connection_id.to_string(),
+            version: domain_msg.version.to_string(),
+            ordering: domain_msg.ordering as i32,
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {}
+
+impl TryFrom<Any> for MsgRegisterInterchainAccount {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let TYPE_URL = raw.type_url.as_str() {
+            MsgRegisterInterchainAccount::decode_vec(&raw.value).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unspecified_ordering_defaults_to_ordered() {
+        let raw = RawMsgRegisterInterchainAccount {
+            owner: "cosmos1owner".to_string(),
+            connection_id: "connection-0".to_string(),
+            version: String::new(),
+            ordering: 0,
+        };
+
+        let msg = MsgRegisterInterchainAccount::try_from(raw).unwrap();
+
+        assert_eq!(msg.ordering, Order::Ordered);
+    }
+}
@@ -0,0 +1,80 @@
+//! Defines the `MsgSendTx` message type
+
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::interchain_accounts::controller::v1::MsgSendTx as RawMsgSendTx;
+use ibc_proto::Protobuf;
+
+use crate::InterchainAccountPacketData;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+
+/// Message used by an owner to execute a transaction with its interchain
+/// account on the host chain at the other end of the given connection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgSendTx {
+    pub owner: Signer,
+    pub connection_id: ConnectionId,
+    pub packet_data: InterchainAccountPacketData,
+    /// the timeout of the packet, in nanoseconds relative to the current
+    /// block timestamp
+    pub relative_timeout: u64,
+}
+
+impl TryFrom<RawMsgSendTx> for MsgSendTx {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgSendTx) -> Result<Self, Self::Error> {
+        Ok(MsgSendTx {
+            owner: raw_msg.owner.into(),
+            connection_id: raw_msg.connection_id.parse()?,
+            packet_data: raw_msg
+                .packet_data
+                .ok_or(DecodingError::missing_raw_data("msg send tx packet data"))?
+                .try_into()?,
+            relative_timeout: raw_msg.relative_timeout,
+        })
+    }
+}
+
+impl From<MsgSendTx> for RawMsgSendTx {
+    fn from(domain_msg: MsgSendTx) -> Self {
+        RawMsgSendTx {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.connection_id.to_string(),
+            packet_data: Some(domain_msg.packet_data.into()),
+            relative_timeout: domain_msg.relative_timeout,
+        }
+    }
+}
+
+impl Protobuf<RawMsgSendTx> for MsgSendTx {}
+
+impl TryFrom<Any> for MsgSendTx {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let TYPE_URL = raw.type_url.as_str() {
+            MsgSendTx::decode_vec(&raw.value).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Contains the `InterchainAccountPacketData` type that defines the structure
+//! of interchain accounts' packet bytes, and the `CosmosTx` it carries.
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::interchain_accounts::v1::{
+    CosmosTx as RawCosmosTx, InterchainAccountPacketData as RawPacketData, Type as RawType,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountError;
+
+/// The type of the packets sent from a controller to a host.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketType {
+    /// Executes a transaction with the interchain account.
+    ExecuteTx,
+}
+
+/// Defines the structure of interchain accounts' packet bytes, which are JSON
+/// encoded.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "RawPacketData", into = "RawPacketData")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainAccountPacketData {
+    pub packet_type: PacketType,
+    /// The protobuf encoded [`CosmosTx`] to execute.
+    pub data: Vec<u8>,
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    /// Builds the packet data executing the given transaction on the host.
+    pub fn execute_tx(tx: CosmosTx, memo: String) -> Self {
+        Self {
+            packet_type: PacketType::ExecuteTx,
+            data: tx.encode_vec(),
+            memo,
+        }
+    }
+
+    /// Checks that the packet carries a non-empty transaction.
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountError> {
+        if self.data.is_empty() {
+            return Err(InterchainAccountError::EmptyTx);
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the transaction carried by the packet.
+    pub fn cosmos_tx(&self) -> Result<CosmosTx, InterchainAccountError> {
+        let tx = CosmosTx::decode_vec(&self.data).map_err(DecodingError::from)?;
+
+        if tx.messages.is_empty() {
+            return Err(InterchainAccountError::EmptyTx);
+        }
+
+        Ok(tx)
+    }
+}
+
+impl Protobuf<RawPacketData> for InterchainAccountPacketData {}
+
+impl TryFrom<RawPacketData> for InterchainAccountPacketData {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawPacketData) -> Result<Self, Self::Error> {
+        let packet_type = match RawType::try_from(raw.r#type) {
+            Ok(RawType::ExecuteTx) => PacketType::ExecuteTx,
+            _ => {
+                return Err(DecodingError::invalid_raw_data(format!(
+                    "unsupported packet type {}",
+                    raw.r#type
+                )))
+            }
+        };
+
+        Ok(Self {
+            packet_type,
+            data: raw.data,
+            memo: raw.memo,
+        })
+    }
+}
+
+impl From<InterchainAccountPacketData> for RawPacketData {
+    fn from(packet_data: InterchainAccountPacketData) -> Self {
+        let r#type = match packet_data.packet_type {
+            PacketType::ExecuteTx => RawType::ExecuteTx,
+        };
+
+        Self {
+            r#type: r#type.into(),
+            data: packet_data.data,
+            memo: packet_data.memo,
+        }
+    }
+}
+
+/// A list of Cosmos SDK messages, to be executed atomically by the interchain
+/// account on a Cosmos SDK host chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosmosTx {
+    pub messages: Vec<Any>,
+}
+
+impl CosmosTx {
+    pub fn new(messages: Vec<Any>) -> Self {
+        Self { messages }
+    }
+}
+
+impl Protobuf<RawCosmosTx> for CosmosTx {}
+
+impl From<RawCosmosTx> for CosmosTx {
+    fn from(raw: RawCosmosTx) -> Self {
+        Self {
+            messages: raw.messages,
+        }
+    }
+}
+
+impl From<CosmosTx> for RawCosmosTx {
+    fn from(tx: CosmosTx) -> Self {
+        Self {
+            messages: tx.messages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_tx() -> CosmosTx {
+        CosmosTx::new(vec![Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        }])
+    }
+
+    #[test]
+    fn test_packet_data_json() {
+        let packet_data = InterchainAccountPacketData {
+            packet_type: PacketType::ExecuteTx,
+            data: vec![1, 2, 3],
+            memo: "memo".to_string(),
+        };
+
+        let json = serde_json::to_string(&packet_data).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":"TYPE_EXECUTE_TX","data":"AQID","memo":"memo"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<InterchainAccountPacketData>(&json).unwrap(),
+            packet_data
+        );
+    }
+
+    #[test]
+    fn test_unspecified_packet_type_is_rejected() {
+        let json = r#"{"type":"TYPE_UNSPECIFIED","data":"AQID"}"#;
+
+        assert!(serde_json::from_str::<InterchainAccountPacketData>(json).is_err());
+    }
+
+    #[test]
+    fn test_cosmos_tx_roundtrip() {
+        let packet_data = InterchainAccountPacketData::execute_tx(dummy_tx(), String::new());
+
+        assert_eq!(packet_data.cosmos_tx().unwrap(), dummy_tx());
+    }
+
+    #[test]
+    fn test_empty_cosmos_tx_is_rejected() {
+        let packet_data =
+            InterchainAccountPacketData::execute_tx(CosmosTx::new(vec![]), String::new());
+
+        assert!(packet_data.cosmos_tx().is_err());
+    }
+}
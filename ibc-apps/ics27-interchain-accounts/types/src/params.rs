@@ -0,0 +1,158 @@
+//! Defines the parameters of the controller and host submodules.
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::apps::interchain_accounts::controller::v1::Params as RawControllerParams;
+use ibc_proto::ibc::apps::interchain_accounts::host::v1::Params as RawHostParams;
+use ibc_proto::Protobuf;
+
+/// The wildcard which allows any message type on the host.
+pub const ALLOW_ALL_MESSAGES: &str = "*";
+
+/// Parameters of the controller submodule.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ControllerParams {
+    pub controller_enabled: bool,
+}
+
+impl Default for ControllerParams {
+    fn default() -> Self {
+        Self {
+            controller_enabled: true,
+        }
+    }
+}
+
+impl Protobuf<RawControllerParams> for ControllerParams {}
+
+impl From<RawControllerParams> for ControllerParams {
+    fn from(raw: RawControllerParams) -> Self {
+        Self {
+            controller_enabled: raw.controller_enabled,
+        }
+    }
+}
+
+impl From<ControllerParams> for RawControllerParams {
+    fn from(params: ControllerParams) -> Self {
+        Self {
+            controller_enabled: params.controller_enabled,
+        }
+    }
+}
+
+/// Parameters of the host submodule.
+///
+/// Interchain accounts may only execute the messages whose type URL is in the
+/// `allow_messages` list, unless it contains [`ALLOW_ALL_MESSAGES`].
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostParams {
+    pub host_enabled: bool,
+    pub allow_messages: Vec<String>,
+}
+
+impl HostParams {
+    pub fn new(host_enabled: bool, allow_messages: Vec<String>) -> Self {
+        Self {
+            host_enabled,
+            allow_messages,
+        }
+    }
+
+    /// Returns true if interchain accounts may execute messages of the given
+    /// type.
+    pub fn is_message_allowed(&self, type_url: &str) -> bool {
+        self.allow_messages
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_MESSAGES || allowed == type_url)
+    }
+}
+
+impl Default for HostParams {
+    fn default() -> Self {
+        Self {
+            host_enabled: true,
+            allow_messages: vec![ALLOW_ALL_MESSAGES.to_string()],
+        }
+    }
+}
+
+impl Protobuf<RawHostParams> for HostParams {}
+
+impl From<RawHostParams> for HostParams {
+    fn from(raw: RawHostParams) -> Self {
+        Self {
+            host_enabled: raw.host_enabled,
+            allow_messages: raw.allow_messages,
+        }
+    }
+}
+
+impl From<HostParams> for RawHostParams {
+    fn from(params: HostParams) -> Self {
+        Self {
+            host_enabled: params.host_enabled,
+            allow_messages: params.allow_messages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(vec![], "/cosmos.bank.v1beta1.MsgSend", false)]
+    #[case(vec!["*"], "/cosmos.bank.v1beta1.MsgSend", true)]
+    #[case(vec!["/cosmos.bank.v1beta1.MsgSend"], "/cosmos.bank.v1beta1.MsgSend", true)]
+    #[case(
+        vec!["/cosmos.staking.v1beta1.MsgDelegate"],
+        "/cosmos.bank.v1beta1.MsgSend",
+        false
+    )]
+    fn test_is_message_allowed(
+        #[case] allow_messages: Vec<&str>,
+        #[case] type_url: &str,
+        #[case] expected: bool,
+    ) {
+        let params = HostParams::new(
+            true,
+            allow_messages
+                .into_iter()
+                .map(ToString::to_string)
+                .collect(),
+        );
+
+        assert_eq!(params.is_message_allowed(type_url), expected);
+    }
+}
@@ -0,0 +1,117 @@
+//! Defines the main context traits of the fee middleware
+
+use ibc_app_fee_types::{Fee, IdentifiedPacketFees, PacketFee};
+use ibc_app_transfer_types::BaseCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// Methods required in fee middleware validation, to be implemented by the host
+pub trait FeeValidationContext {
+    /// Native chain account id.
+    type AccountId;
+
+    /// Attempt to convert a [`Signer`] to a native chain account.
+    fn fee_account(&self, signer: &Signer) -> Result<Self::AccountId, HostError>;
+
+    /// Returns true if fees are enabled on the given channel.
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<bool, HostError>;
+
+    /// Returns the payee registered by the `relayer` on the given channel, if any.
+    fn payee(&self, channel_id: &ChannelId, relayer: &Signer) -> Result<Option<Signer>, HostError>;
+
+    /// Returns the counterparty payee registered by the `relayer` on the
+    /// given channel, if any.
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, HostError>;
+
+    /// Returns all the fees escrowed for the given packet, which is empty if
+    /// no fee was paid for it.
+    fn fees_in_escrow(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, HostError>;
+
+    /// Returns the fees escrowed for all the packets sent on the given channel.
+    fn fees_in_escrow_on_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, HostError>;
+
+    /// Returns the address of the relayer which relayed the given received
+    /// packet, if its acknowledgement is to be written asynchronously.
+    fn relayer_address_for_async_ack(
+        &self,
+        packet_id: &PacketId,
+    ) -> Result<Option<Signer>, HostError>;
+
+    /// Validates that the `payer` can afford escrowing the given fee.
+    fn escrow_fee_validate(&self, payer: &Self::AccountId, fee: &Fee) -> Result<(), HostError>;
+}
+
+/// Methods required in fee middleware execution, to be implemented by the host.
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Enables fees on the given channel.
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), HostError>;
+
+    /// Disables fees on the given channel.
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), HostError>;
+
+    /// Stores the payee registered by the `relayer` on the given channel.
+    fn store_payee(
+        &mut self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        payee: &Signer,
+    ) -> Result<(), HostError>;
+
+    /// Stores the counterparty payee registered by the `relayer` on the given
+    /// channel.
+    fn store_counterparty_payee(
+        &mut self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        counterparty_payee: &Signer,
+    ) -> Result<(), HostError>;
+
+    /// Stores all the fees escrowed for the given packet.
+    fn store_fees_in_escrow(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the fees escrowed for the given packet, once they have been
+    /// distributed.
+    fn delete_fees_in_escrow(&mut self, packet_id: &PacketId) -> Result<(), HostError>;
+
+    /// Stores the address of the relayer which relayed the given received
+    /// packet, until its acknowledgement is written asynchronously.
+    fn store_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+        relayer: &Signer,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the relayer address stored for the given received packet.
+    fn delete_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+    ) -> Result<(), HostError>;
+
+    /// Executes the escrow of the total of the given fee from the `payer`
+    /// account into the fee middleware account.
+    fn escrow_fee_execute(&mut self, payer: &Self::AccountId, fee: &Fee) -> Result<(), HostError>;
+
+    /// Executes the transfer of the given coins out of the fee middleware
+    /// account into the `receiver` account, either to pay a relayer or to
+    /// refund an unspent fee.
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[BaseCoin],
+    ) -> Result<(), HostError>;
+}
@@ -0,0 +1,258 @@
+//! Implements the processing logic for ICS-29 (fee middleware) messages, and
+//! the distribution of the escrowed fees.
+mod pay_packet_fee;
+mod pay_packet_fee_async;
+mod register_counterparty_payee;
+mod register_payee;
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::{DistributeFeeEvent, IncentivizedPacketEvent};
+use ibc_app_fee_types::{add_coins, PacketFee};
+use ibc_app_transfer_types::BaseCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+pub use pay_packet_fee::*;
+pub use pay_packet_fee_async::*;
+pub use register_counterparty_payee::*;
+pub use register_payee::*;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+fn ensure_fee_enabled(
+    fee_ctx: &impl FeeValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), FeeError> {
+    if !fee_ctx.is_fee_enabled(port_id, channel_id)? {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+fn escrow_packet_fee_validate(
+    fee_ctx: &impl FeeValidationContext,
+    packet_fee: &PacketFee,
+) -> Result<(), FeeError> {
+    packet_fee.validate_basic()?;
+
+    let payer = fee_ctx.fee_account(&packet_fee.refund_address)?;
+
+    fee_ctx.escrow_fee_validate(&payer, &packet_fee.fee)?;
+
+    Ok(())
+}
+
+/// Escrows the given fee and records it among the fees paid for the packet.
+///
+/// Returns the event carrying the total of the fees escrowed for the packet.
+fn escrow_packet_fee_execute(
+    fee_ctx: &mut impl FeeExecutionContext,
+    packet_id: PacketId,
+    packet_fee: PacketFee,
+) -> Result<ModuleEvent, FeeError> {
+    let payer = fee_ctx.fee_account(&packet_fee.refund_address)?;
+
+    fee_ctx.escrow_fee_execute(&payer, &packet_fee.fee)?;
+
+    let mut packet_fees = fee_ctx.fees_in_escrow(&packet_id)?;
+    packet_fees.push(packet_fee);
+
+    let mut total_recv_fee = Vec::new();
+    let mut total_ack_fee = Vec::new();
+    let mut total_timeout_fee = Vec::new();
+
+    for packet_fee in &packet_fees {
+        add_coins(&mut total_recv_fee, &packet_fee.fee.recv_fee)?;
+        add_coins(&mut total_ack_fee, &packet_fee.fee.ack_fee)?;
+        add_coins(&mut total_timeout_fee, &packet_fee.fee.timeout_fee)?;
+    }
+
+    fee_ctx.store_fees_in_escrow(&packet_id, packet_fees)?;
+
+    Ok(IncentivizedPacketEvent {
+        packet_id,
+        total_recv_fee,
+        total_ack_fee,
+        total_timeout_fee,
+    }
+    .into())
+}
+
+/// Pays the given coins out of escrow to the `receiver`, and returns the
+/// corresponding event. Nothing is paid if there are no coins to distribute.
+fn distribute_fee<Ctx>(
+    fee_ctx: &mut Ctx,
+    receiver: &Signer,
+    receiver_account: &Ctx::AccountId,
+    coins: &[BaseCoin],
+    events: &mut Vec<ModuleEvent>,
+) -> Result<(), FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    if coins.is_empty() {
+        return Ok(());
+    }
+
+    fee_ctx.distribute_fee_execute(receiver_account, coins)?;
+
+    events.push(
+        DistributeFeeEvent {
+            receiver: receiver.clone(),
+            fee: coins.to_vec(),
+        }
+        .into(),
+    );
+
+    Ok(())
+}
+
+/// Distributes the fees escrowed for an acknowledged packet.
+///
+/// The receive fee is paid to the `forward_relayer`, which is the
+/// counterparty payee reported in the incentivized acknowledgement, or
+/// refunded if it is not a valid account on this chain. The acknowledgement
+/// fee is paid to the `reverse_relayer`, and the timeout fee is refunded.
+pub fn distribute_fees_on_acknowledgement<Ctx>(
+    fee_ctx: &mut Ctx,
+    forward_relayer: &Signer,
+    reverse_relayer: &Signer,
+    packet_fees: &[PacketFee],
+) -> Result<Vec<ModuleEvent>, FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let mut events = Vec::new();
+
+    let forward_account = if forward_relayer.as_ref().is_empty() {
+        None
+    } else {
+        fee_ctx.fee_account(forward_relayer).ok()
+    };
+    let reverse_account = fee_ctx.fee_account(reverse_relayer)?;
+
+    for packet_fee in packet_fees {
+        let refund_address = &packet_fee.refund_address;
+        let refund_account = fee_ctx.fee_account(refund_address)?;
+        let fee = &packet_fee.fee;
+
+        match &forward_account {
+            Some(forward_account) => distribute_fee(
+                fee_ctx,
+                forward_relayer,
+                forward_account,
+                &fee.recv_fee,
+                &mut events,
+            )?,
+            None => distribute_fee(
+                fee_ctx,
+                refund_address,
+                &refund_account,
+                &fee.recv_fee,
+                &mut events,
+            )?,
+        }
+
+        distribute_fee(
+            fee_ctx,
+            reverse_relayer,
+            &reverse_account,
+            &fee.ack_fee,
+            &mut events,
+        )?;
+
+        distribute_fee(
+            fee_ctx,
+            refund_address,
+            &refund_account,
+            &fee.timeout_fee,
+            &mut events,
+        )?;
+    }
+
+    Ok(events)
+}
+
+/// Distributes the fees escrowed for a timed out packet.
+///
+/// The timeout fee is paid to the `timeout_relayer`, while the receive and
+/// acknowledgement fees are refunded.
+pub fn distribute_fees_on_timeout<Ctx>(
+    fee_ctx: &mut Ctx,
+    timeout_relayer: &Signer,
+    packet_fees: &[PacketFee],
+) -> Result<Vec<ModuleEvent>, FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let mut events = Vec::new();
+
+    let timeout_account = fee_ctx.fee_account(timeout_relayer)?;
+
+    for packet_fee in packet_fees {
+        let refund_address = &packet_fee.refund_address;
+        let refund_account = fee_ctx.fee_account(refund_address)?;
+        let fee = &packet_fee.fee;
+
+        let mut refund = Vec::new();
+        add_coins(&mut refund, &fee.recv_fee)?;
+        add_coins(&mut refund, &fee.ack_fee)?;
+
+        distribute_fee(
+            fee_ctx,
+            refund_address,
+            &refund_account,
+            &refund,
+            &mut events,
+        )?;
+
+        distribute_fee(
+            fee_ctx,
+            timeout_relayer,
+            &timeout_account,
+            &fee.timeout_fee,
+            &mut events,
+        )?;
+    }
+
+    Ok(events)
+}
+
+/// Refunds all the fees escrowed for the packets sent on a channel that is
+/// being closed, as they can no longer be relayed.
+pub fn refund_fees_on_channel_close<Ctx>(
+    fee_ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Vec<ModuleEvent>, FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let mut events = Vec::new();
+
+    for identified_fees in fee_ctx.fees_in_escrow_on_channel(port_id, channel_id)? {
+        for packet_fee in &identified_fees.packet_fees {
+            let refund_address = &packet_fee.refund_address;
+            let refund_account = fee_ctx.fee_account(refund_address)?;
+
+            distribute_fee(
+                fee_ctx,
+                refund_address,
+                &refund_account,
+                &packet_fee.fee.total()?,
+                &mut events,
+            )?;
+        }
+
+        fee_ctx.delete_fees_in_escrow(&identified_fees.packet_id)?;
+    }
+
+    Ok(events)
+}
@@ -0,0 +1,81 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::msgs::MsgPayPacketFee;
+use ibc_app_fee_types::{PacketFee, MODULE_ID_STR};
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use super::{ensure_fee_enabled, escrow_packet_fee_execute, escrow_packet_fee_validate};
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for the next packet sent on a channel. Equivalent to calling
+/// [`pay_packet_fee_validate`], followed by [`pay_packet_fee_execute`].
+pub fn pay_packet_fee<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the fee payment. If this succeeds, then it is legal to escrow
+/// the fee with [`pay_packet_fee_execute`].
+pub fn pay_packet_fee_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+
+    ensure_fee_enabled(fee_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    let packet_fee = PacketFee::new(msg.fee, msg.signer, msg.relayers);
+
+    escrow_packet_fee_validate(fee_ctx_a, &packet_fee)
+}
+
+/// Executes the fee payment, escrowing the fee for the packet which is sent
+/// next on the channel.
+pub fn pay_packet_fee_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet_id = PacketId::new(msg.port_id_on_a, msg.chan_id_on_a, sequence);
+    let packet_fee = PacketFee::new(msg.fee, msg.signer, msg.relayers);
+
+    let incentivized_packet_event =
+        escrow_packet_fee_execute(fee_ctx_a, packet_id.clone(), packet_fee)?;
+
+    {
+        ctx_a.log_message(format!(
+            "IBC fee middleware: fee escrowed for packet {packet_id}"
+        ))?;
+
+        ctx_a.emit_ibc_event(incentivized_packet_event.into())?;
+
+        ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::msgs::MsgPayPacketFeeAsync;
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use super::{ensure_fee_enabled, escrow_packet_fee_execute, escrow_packet_fee_validate};
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for a packet which is already in flight. Equivalent to
+/// calling [`pay_packet_fee_async_validate`], followed by
+/// [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_async_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_async_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the asynchronous fee payment. If this succeeds, then it is legal
+/// to escrow the fee with [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let packet_id = &msg.packet_id;
+
+    ctx_a.validate_message_signer(&msg.packet_fee.refund_address)?;
+
+    ctx_a.channel_end(&ChannelEndPath::new(&packet_id.port_id, &packet_id.chan_id))?;
+
+    ensure_fee_enabled(fee_ctx_a, &packet_id.port_id, &packet_id.chan_id)?;
+
+    let next_seq_send =
+        ctx_a.get_next_sequence_send(&SeqSendPath::new(&packet_id.port_id, &packet_id.chan_id))?;
+
+    if packet_id.seq >= next_seq_send {
+        return Err(FeeError::PacketNotSent {
+            sequence: packet_id.seq,
+            next_sequence: next_seq_send,
+        });
+    }
+
+    // The packet commitment is deleted once the packet is acknowledged or
+    // timed out, after which the fee could no longer be distributed.
+    ctx_a
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet_id.port_id,
+            &packet_id.chan_id,
+            packet_id.seq,
+        ))
+        .map_err(|_| FeeError::PacketNotInFlight {
+            packet_id: packet_id.clone(),
+        })?;
+
+    escrow_packet_fee_validate(fee_ctx_a, &msg.packet_fee)
+}
+
+/// Executes the asynchronous fee payment, escrowing the fee for the given
+/// packet.
+pub fn pay_packet_fee_async_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let packet_id = msg.packet_id;
+
+    let incentivized_packet_event =
+        escrow_packet_fee_execute(fee_ctx_a, packet_id.clone(), msg.packet_fee)?;
+
+    {
+        ctx_a.log_message(format!(
+            "IBC fee middleware: fee escrowed for packet {packet_id}"
+        ))?;
+
+        ctx_a.emit_ibc_event(incentivized_packet_event.into())?;
+
+        ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
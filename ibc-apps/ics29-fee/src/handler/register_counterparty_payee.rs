@@ -0,0 +1,82 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::RegisterCounterpartyPayeeEvent;
+use ibc_app_fee_types::msgs::MsgRegisterCounterpartyPayee;
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use super::ensure_fee_enabled;
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Registers the counterparty payee of a relayer. Equivalent to calling
+/// [`register_counterparty_payee_validate`], followed by
+/// [`register_counterparty_payee_execute`].
+pub fn register_counterparty_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_counterparty_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_counterparty_payee_execute(ctx, fee_ctx, msg)
+}
+
+pub fn register_counterparty_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ctx.validate_message_signer(&msg.relayer)?;
+
+    // The counterparty payee is an address on the counterparty chain, so it
+    // can only be checked for emptiness here.
+    if msg.counterparty_payee.as_ref().trim().is_empty() {
+        return Err(FeeError::InvalidPayee {
+            description: "counterparty payee must not be empty".to_string(),
+        });
+    }
+
+    ctx.channel_end(&ChannelEndPath::new(&msg.port_id, &msg.channel_id))?;
+
+    ensure_fee_enabled(fee_ctx, &msg.port_id, &msg.channel_id)
+}
+
+pub fn register_counterparty_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_counterparty_payee(&msg.channel_id, &msg.relayer, &msg.counterparty_payee)?;
+
+    {
+        ctx.log_message(format!(
+            "IBC fee middleware: registered counterparty payee {} for relayer {} on channel {}",
+            msg.counterparty_payee, msg.relayer, msg.channel_id
+        ))?;
+
+        let register_counterparty_payee_event = RegisterCounterpartyPayeeEvent {
+            relayer: msg.relayer,
+            counterparty_payee: msg.counterparty_payee,
+            channel_id: msg.channel_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(register_counterparty_payee_event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::RegisterPayeeEvent;
+use ibc_app_fee_types::msgs::MsgRegisterPayee;
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use super::ensure_fee_enabled;
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Registers the payee of a relayer. Equivalent to calling
+/// [`register_payee_validate`], followed by [`register_payee_execute`].
+pub fn register_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_payee_execute(ctx, fee_ctx, msg)
+}
+
+pub fn register_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ctx.validate_message_signer(&msg.relayer)?;
+
+    if msg.relayer == msg.payee {
+        return Err(FeeError::InvalidPayee {
+            description: "relayer and payee must not be the same".to_string(),
+        });
+    }
+
+    ctx.channel_end(&ChannelEndPath::new(&msg.port_id, &msg.channel_id))?;
+
+    ensure_fee_enabled(fee_ctx, &msg.port_id, &msg.channel_id)?;
+
+    fee_ctx.fee_account(&msg.payee)?;
+
+    Ok(())
+}
+
+pub fn register_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_payee(&msg.channel_id, &msg.relayer, &msg.payee)?;
+
+    {
+        ctx.log_message(format!(
+            "IBC fee middleware: registered payee {} for relayer {} on channel {}",
+            msg.payee, msg.relayer, msg.channel_id
+        ))?;
+
+        let register_payee_event = RegisterPayeeEvent {
+            relayer: msg.relayer,
+            payee: msg.payee,
+            channel_id: msg.channel_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(register_payee_event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,812 @@
+//! Provides the [`FeeMiddleware`], which implements the ICS-29 module
+//! callbacks on top of any wrapped IBC application.
+use core::fmt::Debug;
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{IncentivizedAcknowledgement, Metadata, VERSION};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::FeeExecutionContext;
+use crate::handler::{
+    distribute_fees_on_acknowledgement, distribute_fees_on_timeout, refund_fees_on_channel_close,
+};
+
+/// Wraps an IBC application to incentivize the relaying of its packets.
+///
+/// On fee enabled channels, the channel version wraps the version of the
+/// application in a [`Metadata`], and the acknowledgements written by the
+/// application are wrapped in an [`IncentivizedAcknowledgement`]. Both are
+/// unwrapped before being handed to the application, so that it is unaware of
+/// the middleware.
+#[derive(Debug)]
+pub struct FeeMiddleware<M, Ctx> {
+    app: M,
+    ctx: Ctx,
+}
+
+impl<M, Ctx> FeeMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: FeeExecutionContext + Debug,
+{
+    pub fn new(app: M, ctx: Ctx) -> Self {
+        Self { app, ctx }
+    }
+
+    /// Returns the fee context of the middleware.
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    /// Wraps an acknowledgement written asynchronously by the application
+    /// for the given received packet.
+    ///
+    /// On fee enabled channels, the acknowledgement must go through this
+    /// method before being committed, so that the relayer of the
+    /// `MsgRecvPacket` gets paid. This is done by the `write_acknowledgement`
+    /// of the middleware stack.
+    pub fn wrap_async_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, FeeError> {
+        if !self
+            .ctx
+            .is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b)?
+        {
+            return Ok(acknowledgement);
+        }
+
+        let packet_id = PacketId::new(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+        let relayer = self.ctx.relayer_address_for_async_ack(&packet_id)?.ok_or(
+            FeeError::PacketNotInFlight {
+                packet_id: packet_id.clone(),
+            },
+        )?;
+
+        self.ctx.delete_relayer_address_for_async_ack(&packet_id)?;
+
+        self.incentivize_acknowledgement(&packet.chan_id_on_b, &relayer, acknowledgement)
+    }
+
+    fn incentivize_acknowledgement(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, FeeError> {
+        // If the relayer has not registered a counterparty payee, the receive
+        // fee is refunded on the counterparty.
+        let forward_relayer = self
+            .ctx
+            .counterparty_payee(channel_id, relayer)?
+            .unwrap_or_else(|| String::new().into());
+
+        let underlying_app_success = is_successful(&acknowledgement);
+
+        let incentivized_ack = IncentivizedAcknowledgement::new(
+            acknowledgement,
+            forward_relayer,
+            underlying_app_success,
+        );
+
+        Ok(serde_json::to_vec(&incentivized_ack)
+            .expect("IncentivizedAcknowledgement's infallible Serialize impl failed")
+            .try_into()
+            .expect("incentivized acknowledgement is never supposed to be empty"))
+    }
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        matches!(self.ctx.is_fee_enabled(port_id, channel_id), Ok(true))
+    }
+}
+
+/// Parses the fee version metadata out of a channel version.
+///
+/// Returns `None` if the version is not a fee version metadata, which is the
+/// case when the channel is not fee enabled, or when the version belongs to
+/// another middleware lower in the stack.
+fn parse_metadata(version: &Version) -> Result<Option<Metadata>, FeeError> {
+    let Ok(metadata) = serde_json::from_str::<Metadata>(version.as_str()) else {
+        return Ok(None);
+    };
+
+    if metadata.fee_version != VERSION {
+        return Err(FeeError::InvalidFeeVersion {
+            expected: VERSION.to_string(),
+            actual: metadata.fee_version,
+        });
+    }
+
+    Ok(Some(metadata))
+}
+
+/// Wraps the version of the application into a fee version metadata.
+fn wrap_version(app_version: &Version) -> Version {
+    let metadata = Metadata::new(app_version.to_string());
+
+    Version::new(
+        serde_json::to_string(&metadata).expect("Metadata's infallible Serialize impl failed"),
+    )
+}
+
+/// Applications which do not use the standard acknowledgement format are
+/// considered to always succeed.
+fn is_successful(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_bytes())
+        .map(|status| status.is_successful())
+        .unwrap_or(true)
+}
+
+fn decode_incentivized_ack(
+    acknowledgement: &Acknowledgement,
+) -> Result<IncentivizedAcknowledgement, FeeError> {
+    serde_json::from_slice(acknowledgement.as_bytes()).map_err(|_| FeeError::FailedToDeserializeAck)
+}
+
+fn merge_extras(mut extras: ModuleExtras, other: ModuleExtras) -> ModuleExtras {
+    extras.events.extend(other.events);
+    extras.log.extend(other.log);
+    extras
+}
+
+impl<M, Ctx> Middleware for FeeMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: FeeExecutionContext + Debug,
+{
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.app
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.app
+    }
+
+    fn on_write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, ChannelError> {
+        Ok(self.wrap_async_acknowledgement(packet, acknowledgement)?)
+    }
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        // An empty version defaults to a fee enabled channel.
+        let metadata = if version.is_empty() {
+            Metadata::new(String::new())
+        } else {
+            match parse_metadata(version)? {
+                Some(metadata) => metadata,
+                None => {
+                    return self.app.on_chan_open_init_validate(
+                        order,
+                        connection_hops,
+                        port_id,
+                        channel_id,
+                        counterparty,
+                        version,
+                    )
+                }
+            }
+        };
+
+        let app_version = self.app.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &Version::new(metadata.app_version),
+        )?;
+
+        Ok(wrap_version(&app_version))
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let metadata = if version.is_empty() {
+            Metadata::new(String::new())
+        } else {
+            match parse_metadata(version)? {
+                Some(metadata) => metadata,
+                None => {
+                    return self.app.on_chan_open_init_execute(
+                        order,
+                        connection_hops,
+                        port_id,
+                        channel_id,
+                        counterparty,
+                        version,
+                    )
+                }
+            }
+        };
+
+        let (extras, app_version) = self.app.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &Version::new(metadata.app_version),
+        )?;
+
+        self.ctx
+            .enable_fee(port_id, channel_id)
+            .map_err(FeeError::from)?;
+
+        Ok((extras, wrap_version(&app_version)))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let Some(metadata) = parse_metadata(counterparty_version)? else {
+            return self.app.on_chan_open_try_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            );
+        };
+
+        let app_version = self.app.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &Version::new(metadata.app_version),
+        )?;
+
+        Ok(wrap_version(&app_version))
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(metadata) = parse_metadata(counterparty_version)? else {
+            return self.app.on_chan_open_try_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            );
+        };
+
+        let (extras, app_version) = self.app.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &Version::new(metadata.app_version),
+        )?;
+
+        self.ctx
+            .enable_fee(port_id, channel_id)
+            .map_err(FeeError::from)?;
+
+        Ok((extras, wrap_version(&app_version)))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        // A handshake initiated with fees enabled must complete with fees
+        // enabled, and conversely.
+        if !self.is_fee_enabled(port_id, channel_id) {
+            return self
+                .app
+                .on_chan_open_ack_validate(port_id, channel_id, counterparty_version);
+        }
+
+        let metadata =
+            parse_metadata(counterparty_version)?.ok_or(FeeError::InvalidVersionMetadata {
+                version: counterparty_version.to_string(),
+            })?;
+
+        self.app
+            .on_chan_open_ack_validate(port_id, channel_id, &Version::new(metadata.app_version))
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        if !self.is_fee_enabled(port_id, channel_id) {
+            return self
+                .app
+                .on_chan_open_ack_execute(port_id, channel_id, counterparty_version);
+        }
+
+        let metadata =
+            parse_metadata(counterparty_version)?.ok_or(FeeError::InvalidVersionMetadata {
+                version: counterparty_version.to_string(),
+            })?;
+
+        self.app
+            .on_chan_open_ack_execute(port_id, channel_id, &Version::new(metadata.app_version))
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let extras = self.app.on_chan_close_init_execute(port_id, channel_id)?;
+
+        if !self.is_fee_enabled(port_id, channel_id) {
+            return Ok(extras);
+        }
+
+        let events = refund_fees_on_channel_close(&mut self.ctx, port_id, channel_id)?;
+
+        Ok(merge_extras(
+            extras,
+            ModuleExtras {
+                events,
+                log: Vec::new(),
+            },
+        ))
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let extras = self
+            .app
+            .on_chan_close_confirm_execute(port_id, channel_id)?;
+
+        if !self.is_fee_enabled(port_id, channel_id) {
+            return Ok(extras);
+        }
+
+        let events = refund_fees_on_channel_close(&mut self.ctx, port_id, channel_id)?;
+
+        Ok(merge_extras(
+            extras,
+            ModuleExtras {
+                events,
+                log: Vec::new(),
+            },
+        ))
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        // Upgrading to a version without fee metadata disables fees.
+        let Some(metadata) = parse_metadata(proposed_version)? else {
+            return self.app.on_chan_upgrade_init_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                proposed_version,
+            );
+        };
+
+        let app_version = self.app.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &Version::new(metadata.app_version),
+        )?;
+
+        Ok(wrap_version(&app_version))
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(metadata) = parse_metadata(proposed_version)? else {
+            return self.app.on_chan_upgrade_init_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                proposed_version,
+            );
+        };
+
+        let (extras, app_version) = self.app.on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &Version::new(metadata.app_version),
+        )?;
+
+        Ok((extras, wrap_version(&app_version)))
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let Some(metadata) = parse_metadata(counterparty_version)? else {
+            return self.app.on_chan_upgrade_try_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                counterparty_version,
+            );
+        };
+
+        let app_version = self.app.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &Version::new(metadata.app_version),
+        )?;
+
+        Ok(wrap_version(&app_version))
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let Some(metadata) = parse_metadata(counterparty_version)? else {
+            return self.app.on_chan_upgrade_try_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                counterparty_version,
+            );
+        };
+
+        let (extras, app_version) = self.app.on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &Version::new(metadata.app_version),
+        )?;
+
+        Ok((extras, wrap_version(&app_version)))
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        let app_version = match parse_metadata(counterparty_version)? {
+            Some(metadata) => Version::new(metadata.app_version),
+            None => counterparty_version.clone(),
+        };
+
+        self.app
+            .on_chan_upgrade_ack_validate(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let app_version = match parse_metadata(counterparty_version)? {
+            Some(metadata) => Version::new(metadata.app_version),
+            None => counterparty_version.clone(),
+        };
+
+        self.app
+            .on_chan_upgrade_ack_execute(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        // The upgraded version was already validated during the handshake.
+        let (fee_result, app_version) = match parse_metadata(version).ok().flatten() {
+            Some(metadata) => (
+                self.ctx.enable_fee(port_id, channel_id),
+                Version::new(metadata.app_version),
+            ),
+            None => (self.ctx.disable_fee(port_id, channel_id), version.clone()),
+        };
+
+        let mut extras = self.app.on_chan_upgrade_open_execute(
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            &app_version,
+        );
+
+        if let Err(e) = fee_result {
+            extras.log.push(format!(
+                "IBC fee middleware: failed to update fee status of the upgraded channel: {e}"
+            ));
+        }
+
+        extras
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        if !self.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        }
+
+        let (mut extras, ack) = self.app.on_recv_packet_execute(packet, relayer);
+
+        let Some(ack) = ack else {
+            // The acknowledgement is written asynchronously, so the relayer is
+            // remembered until then.
+            let packet_id = PacketId::new(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                packet.seq_on_a,
+            );
+
+            if let Err(e) = self
+                .ctx
+                .store_relayer_address_for_async_ack(&packet_id, relayer)
+            {
+                extras.log.push(format!(
+                    "IBC fee middleware: failed to store relayer for async acknowledgement: {e}"
+                ));
+            }
+
+            return (extras, None);
+        };
+
+        let ack = match self.incentivize_acknowledgement(&packet.chan_id_on_b, relayer, ack) {
+            Ok(ack) => ack,
+            Err(e) => AcknowledgementStatus::error(e.into()).into(),
+        };
+
+        (extras, Some(ack))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+            return self
+                .app
+                .on_acknowledgement_packet_validate(packet, acknowledgement, relayer);
+        }
+
+        let incentivized_ack = decode_incentivized_ack(acknowledgement)?;
+
+        self.app.on_acknowledgement_packet_validate(
+            packet,
+            &incentivized_ack.app_acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+            return self
+                .app
+                .on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+        }
+
+        let incentivized_ack = match decode_incentivized_ack(acknowledgement) {
+            Ok(ack) => ack,
+            Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+        };
+
+        let fee_events = match self.distribute_fees_on_acknowledgement(
+            packet,
+            &incentivized_ack.forward_relayer_address,
+            relayer,
+        ) {
+            Ok(events) => events,
+            Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+        };
+
+        let (extras, result) = self.app.on_acknowledgement_packet_execute(
+            packet,
+            &incentivized_ack.app_acknowledgement,
+            relayer,
+        );
+
+        (
+            merge_extras(
+                ModuleExtras {
+                    events: fee_events,
+                    log: Vec::new(),
+                },
+                extras,
+            ),
+            result,
+        )
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+            return self.app.on_timeout_packet_execute(packet, relayer);
+        }
+
+        let fee_events = match self.distribute_fees_on_timeout(packet, relayer) {
+            Ok(events) => events,
+            Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+        };
+
+        let (extras, result) = self.app.on_timeout_packet_execute(packet, relayer);
+
+        (
+            merge_extras(
+                ModuleExtras {
+                    events: fee_events,
+                    log: Vec::new(),
+                },
+                extras,
+            ),
+            result,
+        )
+    }
+}
+
+impl<M, Ctx> FeeMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: FeeExecutionContext + Debug,
+{
+    /// Returns the payee registered by the relayer on the source channel of
+    /// the packet, defaulting to the relayer itself.
+    fn payee(&self, packet: &Packet, relayer: &Signer) -> Result<Signer, FeeError> {
+        Ok(self
+            .ctx
+            .payee(&packet.chan_id_on_a, relayer)?
+            .unwrap_or_else(|| relayer.clone()))
+    }
+
+    fn distribute_fees_on_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        forward_relayer: &Signer,
+        relayer: &Signer,
+    ) -> Result<Vec<ModuleEvent>, FeeError> {
+        let packet_id = PacketId::from(packet);
+        let packet_fees = self.ctx.fees_in_escrow(&packet_id)?;
+
+        if packet_fees.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let payee = self.payee(packet, relayer)?;
+
+        let events = distribute_fees_on_acknowledgement(
+            &mut self.ctx,
+            forward_relayer,
+            &payee,
+            &packet_fees,
+        )?;
+
+        self.ctx.delete_fees_in_escrow(&packet_id)?;
+
+        Ok(events)
+    }
+
+    fn distribute_fees_on_timeout(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<Vec<ModuleEvent>, FeeError> {
+        let packet_id = PacketId::from(packet);
+        let packet_fees = self.ctx.fees_in_escrow(&packet_id)?;
+
+        if packet_fees.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let payee = self.payee(packet, relayer)?;
+
+        let events = distribute_fees_on_timeout(&mut self.ctx, &payee, &packet_fees)?;
+
+        self.ctx.delete_fees_in_escrow(&packet_id)?;
+
+        Ok(events)
+    }
+}
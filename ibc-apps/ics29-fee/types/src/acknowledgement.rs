@@ -0,0 +1,121 @@
+//! Defines the acknowledgement written by the fee middleware on fee enabled channels.
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::apps::fee::v1::IncentivizedAcknowledgement as RawIncentivizedAcknowledgement;
+use ibc_proto::Protobuf;
+
+/// Wraps the acknowledgement of the underlying application along with the
+/// address which should receive the fee for relaying the `MsgRecvPacket`.
+///
+/// It is JSON encoded, with the application acknowledgement encoded in base64.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncentivizedAcknowledgement {
+    /// The acknowledgement of the underlying application
+    #[cfg_attr(feature = "serde", serde(with = "base64_ack"))]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    pub app_acknowledgement: Acknowledgement,
+    /// The counterparty payee address of the relayer which relayed the
+    /// `MsgRecvPacket`. Empty if the relayer has not registered one.
+    pub forward_relayer_address: Signer,
+    /// Whether the underlying application acknowledged the packet successfully
+    pub underlying_app_success: bool,
+}
+
+impl IncentivizedAcknowledgement {
+    pub fn new(
+        app_acknowledgement: Acknowledgement,
+        forward_relayer_address: Signer,
+        underlying_app_success: bool,
+    ) -> Self {
+        Self {
+            app_acknowledgement,
+            forward_relayer_address,
+            underlying_app_success,
+        }
+    }
+}
+
+impl TryFrom<RawIncentivizedAcknowledgement> for IncentivizedAcknowledgement {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawIncentivizedAcknowledgement) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app_acknowledgement: raw.app_acknowledgement.try_into()?,
+            forward_relayer_address: raw.forward_relayer_address.into(),
+            underlying_app_success: raw.underlying_app_success,
+        })
+    }
+}
+
+impl From<IncentivizedAcknowledgement> for RawIncentivizedAcknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        Self {
+            app_acknowledgement: ack.app_acknowledgement.into(),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        }
+    }
+}
+
+impl Protobuf<RawIncentivizedAcknowledgement> for IncentivizedAcknowledgement {}
+
+#[cfg(feature = "serde")]
+mod base64_ack {
+    use base64::prelude::BASE64_STANDARD;
+    use base64::Engine;
+    use ibc_core::channel::types::acknowledgement::Acknowledgement;
+    use ibc_core::primitives::prelude::*;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(ack: &Acknowledgement, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&BASE64_STANDARD.encode(ack.as_bytes()))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Acknowledgement, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        let decoded = BASE64_STANDARD.decode(encoded).map_err(D::Error::custom)?;
+
+        decoded.try_into().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incentivized_ack_json_encoding() {
+        let ack = IncentivizedAcknowledgement::new(
+            br#"{"result":"AQ=="}"#.to_vec().try_into().unwrap(),
+            "cosmos1relayer".to_string().into(),
+            true,
+        );
+        let json = r#"{"app_acknowledgement":"eyJyZXN1bHQiOiJBUT09In0=","forward_relayer_address":"cosmos1relayer","underlying_app_success":true}"#;
+
+        assert_eq!(serde_json::to_string(&ack).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<IncentivizedAcknowledgement>(json).unwrap(),
+            ack
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Defines the Fee Middleware (ICS-29) error types.
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::error::{DecodingError, HostError};
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// host error: {0}
+    Host(HostError),
+    /// channel error: {0}
+    Channel(ChannelError),
+    /// decoding error: {0}
+    Decoding(DecodingError),
+    /// fee module is not enabled on channel `{channel_id}` on port `{port_id}`
+    FeeNotEnabled {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// invalid fee: {description}
+    InvalidFee { description: String },
+    /// invalid fee version: expected `{expected}`, actual `{actual}`
+    InvalidFeeVersion { expected: String, actual: String },
+    /// invalid payee: {description}
+    InvalidPayee { description: String },
+    /// relayer restrictions are not supported
+    UnsupportedRelayers,
+    /// packet `{packet_id}` was not sent, or has already been acknowledged or timed out
+    PacketNotInFlight { packet_id: PacketId },
+    /// packet sequence `{sequence}` has not been sent yet; next send sequence is `{next_sequence}`
+    PacketNotSent {
+        sequence: Sequence,
+        next_sequence: Sequence,
+    },
+    /// failed to deserialize incentivized acknowledgement
+    FailedToDeserializeAck,
+    /// invalid fee version metadata: `{version}`
+    InvalidVersionMetadata { version: String },
+    /// fee amount overflow
+    FeeAmountOverflow,
+}
+
+impl From<HostError> for FeeError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+impl From<ChannelError> for FeeError {
+    fn from(e: ChannelError) -> Self {
+        Self::Channel(e)
+    }
+}
+
+impl From<DecodingError> for FeeError {
+    fn from(e: DecodingError) -> Self {
+        Self::Decoding(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Channel(e) => Some(e),
+            Self::Host(e) => Some(e),
+            Self::Decoding(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<FeeError> for ChannelError {
+    fn from(err: FeeError) -> Self {
+        match err {
+            FeeError::Channel(e) => e,
+            e => ChannelError::AppSpecific {
+                description: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<FeeError> for StatusValue {
+    fn from(e: FeeError) -> Self {
+        StatusValue::new(e.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,146 @@
+//! Defines all fee middleware event types
+use ibc_app_transfer_types::BaseCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::MODULE_ID_STR;
+
+const EVENT_TYPE_INCENTIVIZED_PACKET: &str = "incentivized_ibc_packet";
+const EVENT_TYPE_REGISTER_PAYEE: &str = "register_payee";
+const EVENT_TYPE_REGISTER_COUNTERPARTY_PAYEE: &str = "register_counterparty_payee";
+const EVENT_TYPE_DISTRIBUTE_FEE: &str = "distribute_fee";
+
+/// Contains all events variants that can be emitted from the fee middleware
+pub enum Event {
+    IncentivizedPacket(IncentivizedPacketEvent),
+    RegisterPayee(RegisterPayeeEvent),
+    RegisterCounterpartyPayee(RegisterCounterpartyPayeeEvent),
+    DistributeFee(DistributeFeeEvent),
+}
+
+fn coins_to_string(coins: &[BaseCoin]) -> String {
+    coins
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Event emitted whenever a fee is escrowed for a packet, with the total of
+/// all the fees escrowed for that packet so far
+pub struct IncentivizedPacketEvent {
+    pub packet_id: PacketId,
+    pub total_recv_fee: Vec<BaseCoin>,
+    pub total_ack_fee: Vec<BaseCoin>,
+    pub total_timeout_fee: Vec<BaseCoin>,
+}
+
+impl From<IncentivizedPacketEvent> for ModuleEvent {
+    fn from(ev: IncentivizedPacketEvent) -> Self {
+        let IncentivizedPacketEvent {
+            packet_id,
+            total_recv_fee,
+            total_ack_fee,
+            total_timeout_fee,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_INCENTIVIZED_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("port_id", packet_id.port_id).into(),
+                ("channel_id", packet_id.chan_id).into(),
+                ("packet_sequence", packet_id.seq).into(),
+                ("recv_fee", coins_to_string(&total_recv_fee)).into(),
+                ("ack_fee", coins_to_string(&total_ack_fee)).into(),
+                ("timeout_fee", coins_to_string(&total_timeout_fee)).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted when a relayer registers a payee address
+pub struct RegisterPayeeEvent {
+    pub relayer: Signer,
+    pub payee: Signer,
+    pub channel_id: ChannelId,
+}
+
+impl From<RegisterPayeeEvent> for ModuleEvent {
+    fn from(ev: RegisterPayeeEvent) -> Self {
+        let RegisterPayeeEvent {
+            relayer,
+            payee,
+            channel_id,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_REGISTER_PAYEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("relayer", relayer).into(),
+                ("payee", payee).into(),
+                ("channel_id", channel_id).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted when a relayer registers a counterparty payee address
+pub struct RegisterCounterpartyPayeeEvent {
+    pub relayer: Signer,
+    pub counterparty_payee: Signer,
+    pub channel_id: ChannelId,
+}
+
+impl From<RegisterCounterpartyPayeeEvent> for ModuleEvent {
+    fn from(ev: RegisterCounterpartyPayeeEvent) -> Self {
+        let RegisterCounterpartyPayeeEvent {
+            relayer,
+            counterparty_payee,
+            channel_id,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_REGISTER_COUNTERPARTY_PAYEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("relayer", relayer).into(),
+                ("counterparty_payee", counterparty_payee).into(),
+                ("channel_id", channel_id).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted for each fee paid out of escrow, either to a relayer or as a
+/// refund
+pub struct DistributeFeeEvent {
+    pub receiver: Signer,
+    pub fee: Vec<BaseCoin>,
+}
+
+impl From<DistributeFeeEvent> for ModuleEvent {
+    fn from(ev: DistributeFeeEvent) -> Self {
+        let DistributeFeeEvent { receiver, fee } = ev;
+        Self {
+            kind: EVENT_TYPE_DISTRIBUTE_FEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("receiver", receiver).into(),
+                ("fee", coins_to_string(&fee)).into(),
+            ],
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::IncentivizedPacket(ev) => ev.into(),
+            Event::RegisterPayee(ev) => ev.into(),
+            Event::RegisterCounterpartyPayee(ev) => ev.into(),
+            Event::DistributeFee(ev) => ev.into(),
+        }
+    }
+}
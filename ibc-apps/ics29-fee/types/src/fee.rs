@@ -0,0 +1,352 @@
+//! Defines the fee types paid to relayers for relaying packets.
+use ibc_app_transfer_types::{Amount, BaseCoin};
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::apps::fee::v1::{
+    Fee as RawFee, IdentifiedPacketFees as RawIdentifiedPacketFees, PacketFee as RawPacketFee,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// The fees to be paid to the relayers of a packet, for each step of the
+/// packet lifecycle.
+///
+/// The whole [`Fee::total`] is escrowed when the fee is paid. Once the packet
+/// is acknowledged, the `recv_fee` is paid to the forward relayer, the
+/// `ack_fee` to the reverse relayer and the `timeout_fee` is refunded. If the
+/// packet times out instead, the `timeout_fee` is paid to the relayer and the
+/// rest is refunded.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fee {
+    /// Fee paid to the relayer which relays the `MsgRecvPacket`
+    pub recv_fee: Vec<BaseCoin>,
+    /// Fee paid to the relayer which relays the `MsgAcknowledgement`
+    pub ack_fee: Vec<BaseCoin>,
+    /// Fee paid to the relayer which relays the `MsgTimeout`
+    pub timeout_fee: Vec<BaseCoin>,
+}
+
+impl Fee {
+    pub fn new(
+        recv_fee: Vec<BaseCoin>,
+        ack_fee: Vec<BaseCoin>,
+        timeout_fee: Vec<BaseCoin>,
+    ) -> Result<Self, FeeError> {
+        let fee = Self {
+            recv_fee,
+            ack_fee,
+            timeout_fee,
+        };
+
+        fee.validate_basic()?;
+
+        Ok(fee)
+    }
+
+    /// Checks that at least one of the fees is non-zero.
+    pub fn validate_basic(&self) -> Result<(), FeeError> {
+        let has_fee = self
+            .recv_fee
+            .iter()
+            .chain(&self.ack_fee)
+            .chain(&self.timeout_fee)
+            .any(|coin| coin.amount != Amount::from(0));
+
+        if !has_fee {
+            return Err(FeeError::InvalidFee {
+                description: "fee must contain at least one non-zero coin".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the total amount to be escrowed for this fee, with the coins
+    /// of the same denomination merged together.
+    pub fn total(&self) -> Result<Vec<BaseCoin>, FeeError> {
+        let mut total = Vec::new();
+
+        add_coins(&mut total, &self.recv_fee)?;
+        add_coins(&mut total, &self.ack_fee)?;
+        add_coins(&mut total, &self.timeout_fee)?;
+
+        Ok(total)
+    }
+}
+
+/// Adds the `coins` to `total`, merging the coins of the same denomination.
+pub fn add_coins(total: &mut Vec<BaseCoin>, coins: &[BaseCoin]) -> Result<(), FeeError> {
+    for coin in coins {
+        match total.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(c) => {
+                c.amount = c
+                    .amount
+                    .checked_add(coin.amount)
+                    .ok_or(FeeError::FeeAmountOverflow)?;
+            }
+            None => total.push(coin.clone()),
+        }
+    }
+
+    Ok(())
+}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = DecodingError;
+
+    fn try_from(raw_fee: RawFee) -> Result<Self, Self::Error> {
+        fn parse_coins(
+            raw_coins: Vec<ibc_proto::cosmos::base::v1beta1::Coin>,
+        ) -> Result<Vec<BaseCoin>, DecodingError> {
+            raw_coins.into_iter().map(TryInto::try_into).collect()
+        }
+
+        Ok(Self {
+            recv_fee: parse_coins(raw_fee.recv_fee)?,
+            ack_fee: parse_coins(raw_fee.ack_fee)?,
+            timeout_fee: parse_coins(raw_fee.timeout_fee)?,
+        })
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        Self {
+            recv_fee: fee.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+/// A [`Fee`] escrowed for a packet, along with the address to refund any
+/// unspent fee to.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    /// Optional list of relayers permitted to receive the fee. Only an empty
+    /// list is currently supported.
+    pub relayers: Vec<Signer>,
+}
+
+impl PacketFee {
+    pub fn new(fee: Fee, refund_address: Signer, relayers: Vec<Signer>) -> Self {
+        Self {
+            fee,
+            refund_address,
+            relayers,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), FeeError> {
+        if self.refund_address.as_ref().is_empty() {
+            return Err(FeeError::InvalidFee {
+                description: "refund address must not be empty".to_string(),
+            });
+        }
+
+        if !self.relayers.is_empty() {
+            return Err(FeeError::UnsupportedRelayers);
+        }
+
+        self.fee.validate_basic()
+    }
+}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = DecodingError;
+
+    fn try_from(raw_packet_fee: RawPacketFee) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fee: raw_packet_fee
+                .fee
+                .ok_or(DecodingError::missing_raw_data("packet fee"))?
+                .try_into()?,
+            refund_address: raw_packet_fee.refund_address.into(),
+            relayers: raw_packet_fee
+                .relayers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        Self {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee
+                .relayers
+                .into_iter()
+                .map(|r| r.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+/// All the fees escrowed for the packet identified by `packet_id`.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedPacketFees {
+    pub packet_id: PacketId,
+    pub packet_fees: Vec<PacketFee>,
+}
+
+impl IdentifiedPacketFees {
+    pub fn new(packet_id: PacketId, packet_fees: Vec<PacketFee>) -> Self {
+        Self {
+            packet_id,
+            packet_fees,
+        }
+    }
+}
+
+impl TryFrom<RawIdentifiedPacketFees> for IdentifiedPacketFees {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawIdentifiedPacketFees) -> Result<Self, Self::Error> {
+        Ok(Self {
+            packet_id: raw
+                .packet_id
+                .ok_or(DecodingError::missing_raw_data(
+                    "identified packet fees packet id",
+                ))?
+                .try_into()?,
+            packet_fees: raw
+                .packet_fees
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<IdentifiedPacketFees> for RawIdentifiedPacketFees {
+    fn from(fees: IdentifiedPacketFees) -> Self {
+        Self {
+            packet_id: Some(fees.packet_id.into()),
+            packet_fees: fees.packet_fees.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<RawIdentifiedPacketFees> for IdentifiedPacketFees {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn coins(coins: &str) -> Vec<BaseCoin> {
+        if coins.is_empty() {
+            return vec![];
+        }
+        BaseCoin::from_string_list(coins).unwrap()
+    }
+
+    #[rstest]
+    #[case::all("10stake", "20stake", "30stake", true)]
+    #[case::only_recv("10stake", "", "", true)]
+    #[case::only_timeout("", "", "1stake", true)]
+    #[case::empty("", "", "", false)]
+    #[case::zero("0stake", "0stake", "0stake", false)]
+    fn test_fee_validate_basic(
+        #[case] recv_fee: &str,
+        #[case] ack_fee: &str,
+        #[case] timeout_fee: &str,
+        #[case] is_valid: bool,
+    ) {
+        let fee = Fee::new(coins(recv_fee), coins(ack_fee), coins(timeout_fee));
+
+        assert_eq!(fee.is_ok(), is_valid);
+    }
+
+    #[test]
+    fn test_fee_total_merges_denoms() {
+        let fee = Fee::new(
+            coins("10stake,5atom"),
+            coins("20stake"),
+            coins("30stake,1osmo"),
+        )
+        .unwrap();
+
+        assert_eq!(fee.total().unwrap(), coins("60stake,5atom,1osmo"));
+    }
+
+    #[test]
+    fn test_packet_fee_rejects_relayers() {
+        let packet_fee = PacketFee::new(
+            Fee::new(coins("10stake"), vec![], vec![]).unwrap(),
+            "refund".to_string().into(),
+            vec!["relayer".to_string().into()],
+        );
+
+        assert!(matches!(
+            packet_fee.validate_basic(),
+            Err(FeeError::UnsupportedRelayers)
+        ));
+    }
+
+    #[test]
+    fn test_identified_packet_fees_proto_roundtrip() {
+        let fees = IdentifiedPacketFees::new(
+            PacketId::new(
+                "transfer".parse().unwrap(),
+                "channel-0".parse().unwrap(),
+                1u64.into(),
+            ),
+            vec![PacketFee::new(
+                Fee::new(coins("10stake"), coins("20stake"), coins("30stake")).unwrap(),
+                "refund".to_string().into(),
+                vec![],
+            )],
+        );
+
+        let raw: RawIdentifiedPacketFees = fees.clone().into();
+
+        assert_eq!(IdentifiedPacketFees::try_from(raw).unwrap(), fees);
+    }
+}
@@ -0,0 +1,72 @@
+//! Defines the version metadata negotiated on fee enabled channels.
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::apps::fee::v1::Metadata as RawMetadata;
+use ibc_proto::Protobuf;
+
+use crate::VERSION;
+
+/// The channel version of a fee enabled channel, which wraps the version of
+/// the underlying application.
+///
+/// It is JSON encoded in the channel version as, for example,
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub fee_version: String,
+    pub app_version: String,
+}
+
+impl Metadata {
+    /// Wraps the given application version with the current fee version.
+    pub fn new(app_version: String) -> Self {
+        Self {
+            fee_version: VERSION.to_string(),
+            app_version,
+        }
+    }
+}
+
+impl From<RawMetadata> for Metadata {
+    fn from(raw: RawMetadata) -> Self {
+        Self {
+            fee_version: raw.fee_version,
+            app_version: raw.app_version,
+        }
+    }
+}
+
+impl From<Metadata> for RawMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            fee_version: metadata.fee_version,
+            app_version: metadata.app_version,
+        }
+    }
+}
+
+impl Protobuf<RawMetadata> for Metadata {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_json_encoding() {
+        let metadata = Metadata::new("ics20-1".to_string());
+        let json = r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#;
+
+        assert_eq!(serde_json::to_string(&metadata).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Metadata>(json).unwrap(), metadata);
+    }
+}
@@ -0,0 +1,10 @@
+//! Defines the fee middleware (ICS-29) message types.
+pub mod pay_packet_fee;
+pub mod pay_packet_fee_async;
+pub mod register_counterparty_payee;
+pub mod register_payee;
+
+pub use pay_packet_fee::*;
+pub use pay_packet_fee_async::*;
+pub use register_counterparty_payee::*;
+pub use register_payee::*;
@@ -0,0 +1,91 @@
+//! Defines the `MsgPayPacketFee` message type
+
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::MsgPayPacketFee as RawMsgPayPacketFee;
+use ibc_proto::Protobuf;
+
+use crate::Fee;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFee";
+
+/// Message used to escrow a fee for the next packet sent on the given
+/// channel. It is expected to be submitted in the same transaction as the
+/// message which sends the packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFee {
+    /// the fee to escrow for the packet
+    pub fee: Fee,
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// the account paying the fee, and receiving any refund
+    pub signer: Signer,
+    /// optional list of relayers permitted to receive the fee
+    pub relayers: Vec<Signer>,
+}
+
+impl TryFrom<RawMsgPayPacketFee> for MsgPayPacketFee {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFee) -> Result<Self, Self::Error> {
+        Ok(MsgPayPacketFee {
+            fee: raw_msg
+                .fee
+                .ok_or(DecodingError::missing_raw_data("msg pay packet fee fee"))?
+                .try_into()?,
+            port_id_on_a: raw_msg.source_port_id.parse()?,
+            chan_id_on_a: raw_msg.source_channel_id.parse()?,
+            signer: raw_msg.signer.into(),
+            relayers: raw_msg.relayers.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl From<MsgPayPacketFee> for RawMsgPayPacketFee {
+    fn from(domain_msg: MsgPayPacketFee) -> Self {
+        RawMsgPayPacketFee {
+            fee: Some(domain_msg.fee.into()),
+            source_port_id: domain_msg.port_id_on_a.to_string(),
+            source_channel_id: domain_msg.chan_id_on_a.to_string(),
+            signer: domain_msg.signer.to_string(),
+            relayers: domain_msg
+                .relayers
+                .into_iter()
+                .map(|r| r.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFee> for MsgPayPacketFee {}
+
+impl TryFrom<Any> for MsgPayPacketFee {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let TYPE_URL = raw.type_url.as_str() {
+            MsgPayPacketFee::decode_vec(&raw.value).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Defines the `MsgPayPacketFeeAsync` message type
+
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::MsgPayPacketFeeAsync as RawMsgPayPacketFeeAsync;
+use ibc_proto::Protobuf;
+
+use crate::PacketFee;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFeeAsync";
+
+/// Message used to escrow a fee for a packet which has already been sent,
+/// but not yet acknowledged or timed out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFeeAsync {
+    /// identifies the packet to escrow the fee for
+    pub packet_id: PacketId,
+    /// the fee to escrow, along with its refund address
+    pub packet_fee: PacketFee,
+}
+
+impl TryFrom<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFeeAsync) -> Result<Self, Self::Error> {
+        Ok(MsgPayPacketFeeAsync {
+            packet_id: raw_msg
+                .packet_id
+                .ok_or(DecodingError::missing_raw_data(
+                    "msg pay packet fee async packet id",
+                ))?
+                .try_into()?,
+            packet_fee: raw_msg
+                .packet_fee
+                .ok_or(DecodingError::missing_raw_data(
+                    "msg pay packet fee async packet fee",
+                ))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<MsgPayPacketFeeAsync> for RawMsgPayPacketFeeAsync {
+    fn from(domain_msg: MsgPayPacketFeeAsync) -> Self {
+        RawMsgPayPacketFeeAsync {
+            packet_id: Some(domain_msg.packet_id.into()),
+            packet_fee: Some(domain_msg.packet_fee.into()),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {}
+
+impl TryFrom<Any> for MsgPayPacketFeeAsync {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let TYPE_URL = raw.type_url.as_str() {
+            MsgPayPacketFeeAsync::decode_vec(&raw.value).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
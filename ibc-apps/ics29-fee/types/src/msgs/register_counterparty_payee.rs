@@ -0,0 +1,76 @@
+//! Defines the `MsgRegisterCounterpartyPayee` message type
+
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::MsgRegisterCounterpartyPayee as RawMsgRegisterCounterpartyPayee;
+use ibc_proto::Protobuf;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
+/// Message used by a relayer to register the address on the counterparty
+/// chain which receives the fees for the packets it relays to this chain on
+/// the given channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterCounterpartyPayee {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// the relayer address, which must sign the message
+    pub relayer: Signer,
+    /// the counterparty chain address receiving the fees on behalf of the relayer
+    pub counterparty_payee: Signer,
+}
+
+impl TryFrom<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgRegisterCounterpartyPayee) -> Result<Self, Self::Error> {
+        Ok(MsgRegisterCounterpartyPayee {
+            port_id: raw_msg.port_id.parse()?,
+            channel_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            counterparty_payee: raw_msg.counterparty_payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterpartyPayee> for RawMsgRegisterCounterpartyPayee {
+    fn from(domain_msg: MsgRegisterCounterpartyPayee) -> Self {
+        RawMsgRegisterCounterpartyPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            counterparty_payee: domain_msg.counterparty_payee.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {}
+
+impl TryFrom<Any> for MsgRegisterCounterpartyPayee {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let TYPE_URL = raw.type_url.as_str() {
+            MsgRegisterCounterpartyPayee::decode_vec(&raw.value).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
@@ -136,6 +136,12 @@ pub trait NftTransferValidationContext {
         None
     }
 
+    /// Returns a hash of the prefixed class ID.
+    /// Implement only if the host chain supports hashed class IDs.
+    fn class_hash_string(&self, _class_id: &PrefixedClassId) -> Option<String> {
+        None
+    }
+
     /// Returns the NFT
     fn get_nft(
         &self,
@@ -147,8 +153,28 @@ pub trait NftTransferValidationContext {
     fn get_nft_class(&self, class_id: &PrefixedClassId) -> Result<Self::NftClass, HostError>;
 }
 
+/// Methods required to keep track of the classes of the NFT vouchers minted
+/// by the NFT transfer application, to be implemented by the host.
+///
+/// The traces let the hashed class IDs, as returned by
+/// [`NftTransferValidationContext::class_hash_string`], be resolved back to
+/// their full path. Hosts which do not hash class IDs never get any trace to
+/// store.
+pub trait ClassTraceContext {
+    /// Returns the class ID whose hash is the given one, if its trace was
+    /// stored.
+    fn class_trace_by_hash(&self, hash: &str) -> Result<Option<PrefixedClassId>, HostError>;
+
+    /// Stores the trace of the given class ID under its hash.
+    fn store_class_trace(
+        &mut self,
+        hash: String,
+        class_id: PrefixedClassId,
+    ) -> Result<(), HostError>;
+}
+
 /// Read-write methods required in NFT transfer execution context.
-pub trait NftTransferExecutionContext: NftTransferValidationContext {
+pub trait NftTransferExecutionContext: NftTransferValidationContext + ClassTraceContext {
     /// Creates a new NFT Class identified by classId. If the class ID already exists, it updates the class metadata.
     fn create_or_update_class_execute(
         &self,
@@ -1,4 +1,5 @@
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::error::HostError;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
@@ -6,7 +7,7 @@ use crate::context::NftTransferExecutionContext;
 use crate::types::error::NftTransferError;
 use crate::types::events::TokenTraceEvent;
 use crate::types::packet::PacketData;
-use crate::types::{is_receiver_chain_source, TracePrefix};
+use crate::types::{is_receiver_chain_source, PrefixedClassId, TracePrefix};
 
 /// This function handles the transfer receiving logic.
 ///
@@ -128,8 +129,27 @@ where
                 .map_err(|err| (extras.clone(), err.into()))?;
         }
 
+        store_class_trace(ctx_b, &class_id).map_err(|err| (extras.clone(), err.into()))?;
+
         extras
     };
 
     Ok(extras)
 }
+
+/// Stores the trace of a voucher class, unless the host does not hash class
+/// IDs or already knows it.
+fn store_class_trace<Ctx: NftTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    class_id: &PrefixedClassId,
+) -> Result<(), HostError> {
+    let Some(hash) = ctx_b.class_hash_string(class_id) else {
+        return Ok(());
+    };
+
+    if ctx_b.class_trace_by_hash(&hash)?.is_none() {
+        ctx_b.store_class_trace(hash, class_id.clone())?;
+    }
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
-use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::handler::{send_module_packet, send_packet_validate};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::handler::types::events::MessageEvent;
 use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
+use ibc_core::router::module::Module;
 use ibc_core::router::types::event::ModuleEvent;
 
 use crate::context::{
@@ -18,6 +19,7 @@ use crate::types::{is_sender_chain_source, MODULE_ID_STR};
 pub fn send_nft_transfer<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     transfer_ctx: &mut TransferCtx,
+    module: &mut dyn Module,
     msg: MsgTransfer,
 ) -> Result<(), NftTransferError>
 where
@@ -25,7 +27,7 @@ where
     TransferCtx: NftTransferExecutionContext,
 {
     send_nft_transfer_validate(send_packet_ctx_a, transfer_ctx, msg.clone())?;
-    send_nft_transfer_execute(send_packet_ctx_a, transfer_ctx, msg)
+    send_nft_transfer_execute(send_packet_ctx_a, transfer_ctx, module, msg)
 }
 
 /// Validates the NFT transfer
@@ -128,9 +130,14 @@ where
 }
 
 /// Executes the token transfer. A prior call to [`send_nft_transfer_validate`] MUST have succeeded.
+///
+/// The packet is sent through `module`, the module stack bound to the NFT
+/// transfer port, so that the middleware wrapping the NFT transfer module
+/// gets to process it.
 pub fn send_nft_transfer_execute<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     transfer_ctx: &mut TransferCtx,
+    module: &mut dyn Module,
     msg: MsgTransfer,
 ) -> Result<(), NftTransferError>
 where
@@ -221,7 +228,7 @@ where
         }
     };
 
-    send_packet_execute(send_packet_ctx_a, packet)?;
+    send_module_packet(send_packet_ctx_a, module, packet)?;
 
     {
         send_packet_ctx_a.log_message(format!(
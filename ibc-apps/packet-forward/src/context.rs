@@ -0,0 +1,72 @@
+//! Defines the main context traits of the packet-forward middleware
+
+use ibc_app_packet_forward_types::InFlightPacket;
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, Sequence};
+use ibc_core::primitives::{Signer, Timestamp};
+
+/// Methods required in packet-forward middleware validation, to be
+/// implemented by the host
+pub trait PacketForwardValidationContext: TokenTransferValidationContext {
+    /// Returns the account which receives the tokens sent by
+    /// `original_sender` over the given channel, before they are forwarded.
+    ///
+    /// The account must not be controlled by anyone, as it holds the tokens
+    /// while they are in flight. It is usually derived from the channel and
+    /// the original sender.
+    fn forward_receiver(
+        &self,
+        channel_id: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, HostError>;
+
+    /// Returns the received packet forwarded as the given packet, if it has
+    /// not been acknowledged or timed out yet.
+    fn in_flight_packet(&self, packet_id: &PacketId) -> Result<Option<InFlightPacket>, HostError>;
+
+    /// Returns the current timestamp of the host, from which the timeout of
+    /// the forwarded packets is computed.
+    fn host_timestamp(&self) -> Result<Timestamp, HostError>;
+}
+
+/// Methods required in packet-forward middleware execution, to be implemented
+/// by the host.
+pub trait PacketForwardExecutionContext:
+    PacketForwardValidationContext + TokenTransferExecutionContext
+{
+    /// Stores the received packet forwarded as the given packet.
+    fn store_in_flight_packet(
+        &mut self,
+        packet_id: PacketId,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the received packet forwarded as the given packet, once the
+    /// latter is acknowledged or timed out.
+    fn delete_in_flight_packet(&mut self, packet_id: &PacketId) -> Result<(), HostError>;
+
+    /// Sends the given token transfer, and returns the sequence of its
+    /// packet.
+    ///
+    /// This is usually done with the `send_transfer` handler of the token
+    /// transfer application, passing it the module stack bound to the
+    /// transfer port.
+    fn send_transfer(&mut self, msg: MsgTransfer) -> Result<Sequence, TokenTransferError>;
+
+    /// Writes the acknowledgement of a received packet, which was held while
+    /// the packet was being forwarded.
+    ///
+    /// The acknowledgement must go through the `on_write_acknowledgement` of
+    /// the middleware wrapping the packet-forward middleware, if any, before
+    /// being committed.
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), HostError>;
+}
@@ -0,0 +1,29 @@
+//! Implementation of the packet-forward middleware application logic, which
+//! forwards the ICS-20 packets received by a chain on to another chain based
+//! on their memo.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod module;
+
+/// Re-exports the packet-forward middleware data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_packet_forward_types::*;
+}
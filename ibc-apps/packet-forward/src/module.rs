@@ -0,0 +1,423 @@
+//! Provides the [`PacketForwardMiddleware`], which forwards the ICS-20 packets
+//! received by the wrapped token transfer application on to another chain.
+use core::fmt::Debug;
+use core::str::FromStr;
+
+use ibc_app_packet_forward_types::error::PacketForwardError;
+use ibc_app_packet_forward_types::{ForwardMetadata, InFlightPacket, NextMemo, PacketMetadata};
+use ibc_app_transfer::handler::{
+    decode_recv_packet_data, encode_packet_data, escrow_execute, is_ack_successful, received_coin,
+};
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{is_sender_chain_source, Memo, Token};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::PacketForwardExecutionContext;
+
+/// Wraps the token transfer application to forward the packets whose memo
+/// carries a [`ForwardMetadata`] on to the next chain.
+///
+/// The tokens of such a packet are received by an intermediate account, from
+/// which they are sent to the next chain. The acknowledgement of the received
+/// packet is held until the forwarded packet is acknowledged, in which case
+/// the same acknowledgement is written. If the forwarded packet times out, it
+/// is sent again as many times as allowed by the metadata, before an error
+/// acknowledgement is written.
+///
+/// Whenever forwarding fails, the tokens are returned to the intermediate
+/// account and sent back to their source through the error acknowledgement.
+#[derive(Debug)]
+pub struct PacketForwardMiddleware<M, Ctx> {
+    app: M,
+    ctx: Ctx,
+}
+
+impl<M, Ctx> PacketForwardMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: PacketForwardExecutionContext + Debug,
+{
+    pub fn new(app: M, ctx: Ctx) -> Self {
+        Self { app, ctx }
+    }
+
+    /// Returns the packet-forward context of the middleware.
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    /// Receives the tokens of the packet into the intermediate account, and
+    /// forwards them to the next chain.
+    ///
+    /// Returns no acknowledgement if the packet was forwarded, as it is
+    /// written once the forwarded packet is acknowledged or timed out.
+    fn receive_and_forward(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+        data: PacketDataV2,
+        metadata: ForwardMetadata,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let forward_sender = match self
+            .ctx
+            .forward_receiver(&packet.chan_id_on_b, &data.sender)
+        {
+            Ok(forward_sender) => forward_sender,
+            Err(e) => return (ModuleExtras::empty(), Some(error_ack(e.into()))),
+        };
+
+        let tokens = data
+            .tokens
+            .iter()
+            .map(|token| received_coin(packet, token.clone()).0)
+            .collect();
+
+        // The memo is meant for the middleware only, so it is not passed on
+        // to the application.
+        let recv_data = PacketDataV2 {
+            receiver: forward_sender.clone(),
+            memo: Memo::from(""),
+            ..data
+        };
+
+        // The data is encoded again for the version of the channel, which the
+        // application decodes it with.
+        let recv_packet = match self
+            .ctx
+            .channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)
+            .map_err(Into::into)
+            .and_then(|version| encode_packet_data(&version, recv_data))
+        {
+            Ok(data) => Packet {
+                data,
+                ..packet.clone()
+            },
+            Err(e) => return (ModuleExtras::empty(), Some(error_ack(e.into()))),
+        };
+
+        let (mut extras, ack) = self.app.on_recv_packet_execute(&recv_packet, relayer);
+
+        if let Some(ack) = ack.filter(|ack| !is_ack_successful(ack)) {
+            return (extras, Some(ack));
+        }
+
+        let in_flight_packet = InFlightPacket {
+            original_packet: packet.clone(),
+            forward_sender,
+            tokens,
+            retries_remaining: metadata.retries(),
+            metadata,
+        };
+
+        if let Err(e) = self.forward(in_flight_packet.clone()) {
+            if let Err(e) = self.return_received_tokens(&in_flight_packet) {
+                extras.log.push(format!(
+                    "IBC packet-forward middleware: failed to refund the forwarded tokens: {e}"
+                ));
+            }
+
+            return (extras, Some(error_ack(e)));
+        }
+
+        (extras, None)
+    }
+
+    /// Sends the tokens of the in-flight packet to the next chain, and stores
+    /// it under the id of the forwarded packet.
+    fn forward(&mut self, in_flight_packet: InFlightPacket) -> Result<(), PacketForwardError> {
+        let metadata = &in_flight_packet.metadata;
+
+        let timeout_timestamp = (self.ctx.host_timestamp()? + metadata.timeout())
+            .map_err(|_| PacketForwardError::InvalidForwardTimeout)?;
+
+        let memo = match &metadata.next {
+            None => Memo::from(""),
+            Some(NextMemo::Memo(memo)) => Memo::from(memo.clone()),
+            Some(NextMemo::Forward(next)) => serde_json::to_string(next)
+                .expect("PacketMetadata's infallible Serialize impl failed")
+                .into(),
+        };
+
+        let msg = MsgTransfer {
+            port_id_on_a: metadata.port.clone(),
+            chan_id_on_a: metadata.channel.clone(),
+            packet_data: PacketDataV2 {
+                tokens: in_flight_packet
+                    .tokens
+                    .iter()
+                    .cloned()
+                    .map(Token::from)
+                    .collect(),
+                sender: in_flight_packet.forward_sender.clone(),
+                receiver: metadata.receiver.clone(),
+                memo,
+                forwarding: Default::default(),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: TimeoutTimestamp::At(timeout_timestamp),
+            forwarding: Default::default(),
+        };
+
+        let sequence = self.ctx.send_transfer(msg)?;
+
+        let packet_id = PacketId::new(metadata.port.clone(), metadata.channel.clone(), sequence);
+
+        self.ctx
+            .store_in_flight_packet(packet_id, in_flight_packet)?;
+
+        Ok(())
+    }
+
+    /// Returns the tokens of the in-flight packet, which were refunded to the
+    /// intermediate account, to where they were received from.
+    ///
+    /// The tokens unescrowed on receipt are escrowed back, and the vouchers
+    /// minted are burned, so that the error acknowledgement refunds them on
+    /// the sender chain.
+    fn return_received_tokens(
+        &mut self,
+        in_flight_packet: &InFlightPacket,
+    ) -> Result<(), PacketForwardError> {
+        let packet = &in_flight_packet.original_packet;
+        let account = self.ctx.sender_account(&in_flight_packet.forward_sender)?;
+        let memo = Memo::from("");
+
+        for coin in &in_flight_packet.tokens {
+            if is_sender_chain_source(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                &coin.denom,
+            ) {
+                escrow_execute(
+                    &mut self.ctx,
+                    &account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    coin,
+                    &memo,
+                )?;
+            } else {
+                self.ctx.burn_coins_execute(&account, coin, &memo)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the acknowledgement of the forwarded packet as the
+    /// acknowledgement of the received packet.
+    fn acknowledge_forwarded(
+        &mut self,
+        packet_id: &PacketId,
+        in_flight_packet: InFlightPacket,
+        acknowledgement: &Acknowledgement,
+    ) -> Result<(), PacketForwardError> {
+        self.ctx.delete_in_flight_packet(packet_id)?;
+
+        if !is_ack_successful(acknowledgement) {
+            self.return_received_tokens(&in_flight_packet)?;
+        }
+
+        self.ctx
+            .write_acknowledgement(&in_flight_packet.original_packet, acknowledgement.clone())?;
+
+        Ok(())
+    }
+
+    /// Sends the timed out forwarded packet again if any retries remain, and
+    /// writes an error acknowledgement for the received packet otherwise.
+    fn retry_or_fail_forwarded(
+        &mut self,
+        packet_id: &PacketId,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), PacketForwardError> {
+        self.ctx.delete_in_flight_packet(packet_id)?;
+
+        if let Some(retries_remaining) = in_flight_packet.retries_remaining.checked_sub(1) {
+            let retry = InFlightPacket {
+                retries_remaining,
+                ..in_flight_packet.clone()
+            };
+
+            if self.forward(retry).is_ok() {
+                return Ok(());
+            }
+        }
+
+        self.return_received_tokens(&in_flight_packet)?;
+
+        let acknowledgement = error_ack(PacketForwardError::ForwardTimedOut {
+            packet_id: packet_id.clone(),
+        });
+
+        self.ctx
+            .write_acknowledgement(&in_flight_packet.original_packet, acknowledgement)?;
+
+        Ok(())
+    }
+}
+
+/// Parses the forward metadata out of the memo of a packet.
+///
+/// Returns `None` if the memo is not meant for the middleware, that is if it
+/// is not a JSON object with a `forward` key.
+fn parse_forward_metadata(memo: &Memo) -> Result<Option<ForwardMetadata>, PacketForwardError> {
+    #[derive(serde::Deserialize)]
+    struct ForwardKey {
+        forward: Option<serde::de::IgnoredAny>,
+    }
+
+    let metadata = match serde_json::from_str::<PacketMetadata>(memo.as_ref()) {
+        Ok(metadata) => metadata.forward,
+        Err(e) => {
+            return match serde_json::from_str::<ForwardKey>(memo.as_ref()) {
+                Ok(ForwardKey { forward: Some(_) }) => {
+                    Err(PacketForwardError::InvalidForwardMetadata {
+                        description: e.to_string(),
+                    })
+                }
+                _ => Ok(None),
+            };
+        }
+    };
+
+    metadata
+        .port
+        .validate()
+        .map_err(|e| PacketForwardError::InvalidForwardMetadata {
+            description: e.to_string(),
+        })?;
+
+    ChannelId::from_str(metadata.channel.as_str()).map_err(|e| {
+        PacketForwardError::InvalidForwardMetadata {
+            description: e.to_string(),
+        }
+    })?;
+
+    if metadata.receiver.as_ref().is_empty() {
+        return Err(PacketForwardError::InvalidForwardMetadata {
+            description: "empty receiver".to_string(),
+        });
+    }
+
+    Ok(Some(metadata))
+}
+
+fn error_ack(e: PacketForwardError) -> Acknowledgement {
+    AcknowledgementStatus::error(e.into()).into()
+}
+
+impl<M, Ctx> Middleware for PacketForwardMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: PacketForwardExecutionContext + Debug,
+{
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.app
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.app
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Ok(data) = decode_recv_packet_data(&self.ctx, packet) else {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        };
+
+        match parse_forward_metadata(&data.memo) {
+            Ok(Some(metadata)) => self.receive_and_forward(packet, relayer, data, metadata),
+            Ok(None) => self.app.on_recv_packet_execute(packet, relayer),
+            Err(e) => (ModuleExtras::empty(), Some(error_ack(e))),
+        }
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+
+        let in_flight_packet = match self.ctx.in_flight_packet(&packet_id) {
+            Ok(Some(in_flight_packet)) => in_flight_packet,
+            Ok(None) => {
+                return self
+                    .app
+                    .on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+            }
+            Err(e) => return (ModuleExtras::empty(), Err(ChannelError::from(e))),
+        };
+
+        // The application refunds the intermediate account on error.
+        let (extras, result) =
+            self.app
+                .on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let result = self
+            .acknowledge_forwarded(&packet_id, in_flight_packet, acknowledgement)
+            .map_err(ChannelError::from);
+
+        (extras, result)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+
+        let in_flight_packet = match self.ctx.in_flight_packet(&packet_id) {
+            Ok(Some(in_flight_packet)) => in_flight_packet,
+            Ok(None) => return self.app.on_timeout_packet_execute(packet, relayer),
+            Err(e) => return (ModuleExtras::empty(), Err(ChannelError::from(e))),
+        };
+
+        // The application refunds the intermediate account.
+        let (extras, result) = self.app.on_timeout_packet_execute(packet, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let result = self
+            .retry_or_fail_forwarded(&packet_id, in_flight_packet)
+            .map_err(ChannelError::from);
+
+        (extras, result)
+    }
+}
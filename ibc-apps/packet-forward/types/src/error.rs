@@ -0,0 +1,71 @@
+//! Defines the packet-forward middleware error types.
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::error::HostError;
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum PacketForwardError {
+    /// host error: {0}
+    Host(HostError),
+    /// channel error: {0}
+    Channel(ChannelError),
+    /// token transfer error: {0}
+    TokenTransfer(TokenTransferError),
+    /// invalid forward metadata: {description}
+    InvalidForwardMetadata { description: String },
+    /// failed to compute the timeout of the forwarded packet
+    InvalidForwardTimeout,
+    /// forwarded packet `{packet_id}` timed out
+    ForwardTimedOut { packet_id: PacketId },
+}
+
+impl From<HostError> for PacketForwardError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+impl From<ChannelError> for PacketForwardError {
+    fn from(e: ChannelError) -> Self {
+        Self::Channel(e)
+    }
+}
+
+impl From<TokenTransferError> for PacketForwardError {
+    fn from(e: TokenTransferError) -> Self {
+        Self::TokenTransfer(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PacketForwardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Host(e) => Some(e),
+            Self::Channel(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<PacketForwardError> for ChannelError {
+    fn from(err: PacketForwardError) -> Self {
+        match err {
+            PacketForwardError::Channel(e) => e,
+            e => ChannelError::AppSpecific {
+                description: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<PacketForwardError> for StatusValue {
+    fn from(e: PacketForwardError) -> Self {
+        StatusValue::new(e.to_string()).expect("error message must not be empty")
+    }
+}
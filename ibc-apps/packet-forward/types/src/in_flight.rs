@@ -0,0 +1,40 @@
+//! Defines the state kept for a packet while it is being forwarded.
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::ForwardMetadata;
+
+/// A received packet which has been forwarded to the next chain, and whose
+/// acknowledgement is held until the forwarded packet is acknowledged or
+/// times out.
+///
+/// It is stored under the id of the forwarded packet.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InFlightPacket {
+    /// The received packet, which is acknowledged once the forwarded packet
+    /// is.
+    pub original_packet: Packet,
+    /// The account which received the tokens on this chain, and sent them
+    /// on to the next chain.
+    pub forward_sender: Signer,
+    /// The tokens forwarded, with their denomination on this chain.
+    pub tokens: Vec<PrefixedCoin>,
+    /// The next hop of the packet.
+    pub metadata: ForwardMetadata,
+    /// The number of times the forwarded packet may still be sent again if
+    /// it times out.
+    pub retries_remaining: u8,
+}
@@ -0,0 +1,36 @@
+//! Implementation of the packet-forward middleware data structures, which
+//! allow an ICS-20 token transfer to be forwarded through several chains
+//! based on the memo of its packet.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod in_flight;
+mod metadata;
+
+pub use in_flight::*;
+pub use metadata::*;
+pub mod error;
+
+/// Module identifier for the packet-forward middleware.
+pub const MODULE_ID_STR: &str = "packetforward";
+
+/// Number of times a forwarded packet is sent again after it timed out, when
+/// the memo does not specify it.
+pub const DEFAULT_FORWARD_RETRIES: u8 = 1;
+
+/// Timeout of a forwarded packet relative to the host timestamp, in
+/// nanoseconds, when the memo does not specify it.
+pub const DEFAULT_FORWARD_TIMEOUT_NANOS: u64 = 600_000_000_000;
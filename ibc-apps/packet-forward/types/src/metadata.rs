@@ -0,0 +1,117 @@
+//! Defines the forward metadata carried by the memo of an ICS-20 packet.
+use core::time::Duration;
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::{DEFAULT_FORWARD_RETRIES, DEFAULT_FORWARD_TIMEOUT_NANOS};
+
+/// The memo of an ICS-20 packet to be forwarded to another chain, e.g.
+///
+/// ```json
+/// {"forward":{"receiver":"cosmos1..","port":"transfer","channel":"channel-1"}}
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketMetadata {
+    pub forward: ForwardMetadata,
+}
+
+/// Describes the next hop of a forwarded packet.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The receiver of the tokens on the next chain.
+    pub receiver: Signer,
+    /// The port on which the tokens are forwarded.
+    pub port: PortId,
+    /// The channel on which the tokens are forwarded.
+    pub channel: ChannelId,
+    /// The timeout of the forwarded packet relative to the host timestamp,
+    /// in nanoseconds.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub timeout: Option<u64>,
+    /// The number of times the forwarded packet is sent again if it times
+    /// out.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub retries: Option<u8>,
+    /// The memo of the forwarded packet.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub next: Option<NextMemo>,
+}
+
+impl ForwardMetadata {
+    /// Returns the timeout of the forwarded packet, relative to the host
+    /// timestamp.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_nanos(self.timeout.unwrap_or(DEFAULT_FORWARD_TIMEOUT_NANOS))
+    }
+
+    /// Returns the number of times the forwarded packet is sent again if it
+    /// times out.
+    pub fn retries(&self) -> u8 {
+        self.retries.unwrap_or(DEFAULT_FORWARD_RETRIES)
+    }
+}
+
+/// The memo of a forwarded packet, which is either the forward metadata of
+/// the next hop, or any memo given as a JSON string.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NextMemo {
+    Forward(Box<PacketMetadata>),
+    Memo(String),
+}
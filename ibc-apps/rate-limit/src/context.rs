@@ -0,0 +1,59 @@
+//! Defines the main context traits of the rate-limit middleware
+
+use ibc_app_rate_limit_types::{PendingOutflow, RateLimit, RatePath};
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+/// Methods required in rate-limit middleware validation, to be implemented by
+/// the host
+pub trait RateLimitValidationContext {
+    /// Returns the rate limit on the given path, if any.
+    ///
+    /// The tokens flowing over paths without a rate limit are not limited.
+    fn rate_limit(&self, path: &RatePath) -> Result<Option<RateLimit>, HostError>;
+
+    /// Returns the value the quotas on the given denomination are a
+    /// percentage of, which is usually its total supply on this chain.
+    fn channel_value(&self, denom: &PrefixedDenom) -> Result<Amount, HostError>;
+
+    /// Returns the outflows recorded for the given sent packet, which is
+    /// empty if the packet was not rate limited or has been settled.
+    fn pending_outflows(&self, packet_id: &PacketId) -> Result<Vec<PendingOutflow>, HostError>;
+
+    /// Returns the current timestamp of the host, against which the time
+    /// windows of the rate limits are checked.
+    fn host_timestamp(&self) -> Result<Timestamp, HostError>;
+
+    /// Returns the ICS-20 version negotiated on the given channel, without
+    /// the metadata of the middleware wrapping the token transfer
+    /// application, if any. The data of the packets sent over the channel
+    /// is decoded according to this version.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, HostError>;
+}
+
+/// Methods required in rate-limit middleware execution, to be implemented by
+/// the host.
+pub trait RateLimitExecutionContext: RateLimitValidationContext {
+    /// Stores the given rate limit, along with its current flow.
+    fn store_rate_limit(&mut self, rate_limit: RateLimit) -> Result<(), HostError>;
+
+    /// Stores the outflows recorded for the given sent packet.
+    fn store_pending_outflows(
+        &mut self,
+        packet_id: &PacketId,
+        outflows: Vec<PendingOutflow>,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the outflows recorded for the given sent packet, once it has
+    /// been acknowledged or timed out.
+    fn delete_pending_outflows(&mut self, packet_id: &PacketId) -> Result<(), HostError>;
+}
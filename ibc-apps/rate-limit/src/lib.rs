@@ -0,0 +1,28 @@
+//! Implementation of the rate-limit middleware application logic, which caps
+//! the net flow of ICS-20 tokens over each channel.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod module;
+
+/// Re-exports the rate-limit middleware data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_rate_limit_types::*;
+}
@@ -0,0 +1,309 @@
+//! Provides the [`RateLimitMiddleware`], which enforces the rate limits on the
+//! packets sent and received by the wrapped token transfer application.
+use core::fmt::Debug;
+
+use ibc_app_rate_limit_types::error::RateLimitError;
+use ibc_app_rate_limit_types::{PendingOutflow, RateLimit, RatePath};
+use ibc_app_transfer::handler::{decode_packet_data, is_ack_successful, received_coin};
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::RateLimitExecutionContext;
+
+/// Wraps the token transfer application to cap the net flow of tokens over
+/// each channel within a time window.
+///
+/// Outgoing transfers are checked as their packet goes down the stack through
+/// `send_packet`, which fails if a quota is exceeded, while the incoming
+/// packets exceeding a quota, or whose inflow cannot be recorded, get an
+/// error acknowledgement without reaching the application. The outflow of a
+/// sent packet is reverted if it is refunded, that is if it times out or gets
+/// an error acknowledgement, within the same window.
+#[derive(Debug)]
+pub struct RateLimitMiddleware<M, Ctx> {
+    app: M,
+    ctx: Ctx,
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Send,
+    Recv,
+}
+
+impl<M, Ctx> RateLimitMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: RateLimitExecutionContext + Debug,
+{
+    pub fn new(app: M, ctx: Ctx) -> Self {
+        Self { app, ctx }
+    }
+
+    /// Returns the rate-limit context of the middleware.
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    /// Returns the rate limit on the given path, starting a new window if
+    /// the current one is over.
+    fn current_rate_limit(&self, path: &RatePath) -> Result<Option<RateLimit>, RateLimitError> {
+        let Some(mut rate_limit) = self.ctx.rate_limit(path)? else {
+            return Ok(None);
+        };
+
+        let now = self.ctx.host_timestamp()?;
+
+        if rate_limit.is_window_expired(now) {
+            rate_limit.reset(self.ctx.channel_value(&path.denom)?, now);
+        }
+
+        Ok(Some(rate_limit))
+    }
+
+    /// Records the flow of the given coins over the channel against their
+    /// rate limits, failing if any of them is exceeded.
+    ///
+    /// Returns the updated rate limits, which are yet to be stored, along
+    /// with the outflows recorded.
+    fn record_flows(
+        &self,
+        channel_id: &ChannelId,
+        coins: Vec<PrefixedCoin>,
+        direction: Direction,
+    ) -> Result<(Vec<RateLimit>, Vec<PendingOutflow>), RateLimitError> {
+        let mut rate_limits: Vec<RateLimit> = Vec::new();
+        let mut outflows = Vec::new();
+
+        for coin in coins {
+            let path = RatePath::new(coin.denom, channel_id.clone());
+
+            // A packet may carry the same denomination several times, in
+            // which case its flows add up.
+            let rate_limit = match rate_limits.iter().position(|r| r.path == path) {
+                Some(index) => &mut rate_limits[index],
+                None => {
+                    let Some(rate_limit) = self.current_rate_limit(&path)? else {
+                        continue;
+                    };
+                    rate_limits.push(rate_limit);
+                    rate_limits
+                        .last_mut()
+                        .expect("a rate limit was just pushed")
+                }
+            };
+
+            match direction {
+                Direction::Send => {
+                    rate_limit.send(coin.amount)?;
+                    outflows.push(PendingOutflow {
+                        path,
+                        amount: coin.amount,
+                        window_start: rate_limit.flow.window_start,
+                    });
+                }
+                Direction::Recv => rate_limit.receive(coin.amount)?,
+            }
+        }
+
+        Ok((rate_limits, outflows))
+    }
+
+    fn store_rate_limits(&mut self, rate_limits: Vec<RateLimit>) -> Result<(), RateLimitError> {
+        for rate_limit in rate_limits {
+            self.ctx.store_rate_limit(rate_limit)?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores the given rate limits, and returns the ones they replace.
+    fn replace_rate_limits(
+        &mut self,
+        rate_limits: Vec<RateLimit>,
+    ) -> Result<Vec<RateLimit>, RateLimitError> {
+        let mut previous_rate_limits = Vec::with_capacity(rate_limits.len());
+
+        for rate_limit in rate_limits {
+            previous_rate_limits.extend(self.ctx.rate_limit(&rate_limit.path)?);
+            self.ctx.store_rate_limit(rate_limit)?;
+        }
+
+        Ok(previous_rate_limits)
+    }
+
+    /// Records the outflow of a packet sent by the application.
+    fn send_packet_rate_limited(&mut self, packet: &Packet) -> Result<(), RateLimitError> {
+        let version = self
+            .ctx
+            .channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+        // Packets which do not carry tokens are not rate limited.
+        let Ok(data) = decode_packet_data(&version, &packet.data) else {
+            return Ok(());
+        };
+
+        let coins = data.tokens.into_iter().map(PrefixedCoin::from).collect();
+
+        let (rate_limits, outflows) =
+            self.record_flows(&packet.chan_id_on_a, coins, Direction::Send)?;
+
+        self.store_rate_limits(rate_limits)?;
+
+        if !outflows.is_empty() {
+            let packet_id = PacketId::new(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                packet.seq_on_a,
+            );
+
+            self.ctx.store_pending_outflows(&packet_id, outflows)?;
+        }
+
+        Ok(())
+    }
+
+    /// Settles the outflows recorded for a sent packet, which are reverted if
+    /// the packet was refunded within the window they were recorded in.
+    fn settle_outflows(&mut self, packet: &Packet, refunded: bool) -> Result<(), RateLimitError> {
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+
+        let outflows = self.ctx.pending_outflows(&packet_id)?;
+
+        if outflows.is_empty() {
+            return Ok(());
+        }
+
+        if refunded {
+            for outflow in outflows {
+                let Some(mut rate_limit) = self.ctx.rate_limit(&outflow.path)? else {
+                    continue;
+                };
+
+                if rate_limit.flow.window_start == outflow.window_start {
+                    rate_limit.undo_send(outflow.amount);
+                    self.ctx.store_rate_limit(rate_limit)?;
+                }
+            }
+        }
+
+        self.ctx.delete_pending_outflows(&packet_id)?;
+
+        Ok(())
+    }
+}
+
+impl<M, Ctx> Middleware for RateLimitMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: RateLimitExecutionContext + Debug,
+{
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.app
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.app
+    }
+
+    fn on_send_packet(&mut self, packet: Packet) -> Result<Packet, ChannelError> {
+        self.send_packet_rate_limited(&packet)?;
+
+        Ok(packet)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Ok(data) = self
+            .ctx
+            .channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)
+            .map_err(TokenTransferError::from)
+            .and_then(|version| decode_packet_data(&version, &packet.data))
+        else {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        };
+
+        let coins = data
+            .tokens
+            .into_iter()
+            .map(|token| received_coin(packet, token).0)
+            .collect();
+
+        // The inflow is stored before the application receives the tokens,
+        // so that the packet gets an error acknowledgement without reaching
+        // the application if it cannot be recorded.
+        let previous_rate_limits = match self
+            .record_flows(&packet.chan_id_on_b, coins, Direction::Recv)
+            .and_then(|(rate_limits, _)| self.replace_rate_limits(rate_limits))
+        {
+            Ok(previous_rate_limits) => previous_rate_limits,
+            Err(e) => {
+                return (
+                    ModuleExtras::empty(),
+                    Some(AcknowledgementStatus::error(e.into()).into()),
+                )
+            }
+        };
+
+        let (mut extras, ack) = self.app.on_recv_packet_execute(packet, relayer);
+
+        // The inflow only counts if the tokens were received.
+        if !ack.as_ref().map_or(true, is_ack_successful) {
+            if let Err(e) = self.store_rate_limits(previous_rate_limits) {
+                extras.log.push(format!(
+                    "IBC rate-limit middleware: failed to revert the inflow: {e}"
+                ));
+            }
+        }
+
+        (extras, ack)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        if let Err(e) = self.settle_outflows(packet, !is_ack_successful(acknowledgement)) {
+            return (ModuleExtras::empty(), Err(e.into()));
+        }
+
+        self.app
+            .on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        if let Err(e) = self.settle_outflows(packet, true) {
+            return (ModuleExtras::empty(), Err(e.into()));
+        }
+
+        self.app.on_timeout_packet_execute(packet, relayer)
+    }
+}
@@ -0,0 +1,58 @@
+//! Defines the rate-limit middleware error types.
+use displaydoc::Display;
+use ibc_app_transfer_types::PrefixedDenom;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum RateLimitError {
+    /// host error: {0}
+    Host(HostError),
+    /// outflow quota exceeded for denom `{denom}` on channel `{channel_id}`
+    SendQuotaExceeded {
+        denom: PrefixedDenom,
+        channel_id: ChannelId,
+    },
+    /// inflow quota exceeded for denom `{denom}` on channel `{channel_id}`
+    RecvQuotaExceeded {
+        denom: PrefixedDenom,
+        channel_id: ChannelId,
+    },
+    /// invalid quota: {description}
+    InvalidQuota { description: String },
+    /// flow amount overflow
+    FlowAmountOverflow,
+}
+
+impl From<HostError> for RateLimitError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RateLimitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Host(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<RateLimitError> for ChannelError {
+    fn from(err: RateLimitError) -> Self {
+        ChannelError::AppSpecific {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<RateLimitError> for StatusValue {
+    fn from(e: RateLimitError) -> Self {
+        StatusValue::new(e.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,25 @@
+//! Implementation of the rate-limit middleware data structures, which cap the
+//! net flow of ICS-20 tokens over a channel within a time window.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod rate_limit;
+
+pub use rate_limit::*;
+pub mod error;
+
+/// Module identifier for the rate-limit middleware.
+pub const MODULE_ID_STR: &str = "ratelimit";
@@ -0,0 +1,334 @@
+//! Defines the rate limits applied to the flow of tokens over a channel.
+use core::time::Duration;
+
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::error::RateLimitError;
+
+/// Identifies a rate limit by the denomination on this chain of the tokens it
+/// applies to, and the channel they flow over.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RatePath {
+    pub denom: PrefixedDenom,
+    pub channel_id: ChannelId,
+}
+
+impl RatePath {
+    pub fn new(denom: PrefixedDenom, channel_id: ChannelId) -> Self {
+        Self { denom, channel_id }
+    }
+}
+
+/// The maximum net flow of tokens allowed in each direction within a time
+/// window, as a percentage of the channel value.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Quota {
+    /// Maximum net outflow, in percent of the channel value.
+    pub max_percent_send: u64,
+    /// Maximum net inflow, in percent of the channel value.
+    pub max_percent_recv: u64,
+    /// Length of the time window, in hours.
+    pub duration_hours: u64,
+}
+
+impl Quota {
+    pub fn new(
+        max_percent_send: u64,
+        max_percent_recv: u64,
+        duration_hours: u64,
+    ) -> Result<Self, RateLimitError> {
+        let quota = Self {
+            max_percent_send,
+            max_percent_recv,
+            duration_hours,
+        };
+
+        quota.validate_basic()?;
+
+        Ok(quota)
+    }
+
+    /// Checks that the percentages do not exceed 100, that they do not both
+    /// block all the transfers, and that the window is not empty.
+    pub fn validate_basic(&self) -> Result<(), RateLimitError> {
+        if self.max_percent_send > 100 || self.max_percent_recv > 100 {
+            return Err(RateLimitError::InvalidQuota {
+                description: "percentages must not exceed 100".to_string(),
+            });
+        }
+
+        if self.max_percent_send == 0 && self.max_percent_recv == 0 {
+            return Err(RateLimitError::InvalidQuota {
+                description: "send and receive percentages must not both be zero".to_string(),
+            });
+        }
+
+        if self.duration_hours == 0 {
+            return Err(RateLimitError::InvalidQuota {
+                description: "duration must not be zero".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.duration_hours.saturating_mul(3600))
+    }
+}
+
+/// The tokens which flowed in each direction since the start of the current
+/// time window.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Flow {
+    pub inflow: Amount,
+    pub outflow: Amount,
+    /// The value the quota percentages apply to, which is snapshotted at the
+    /// start of the window.
+    pub channel_value: Amount,
+    pub window_start: Timestamp,
+}
+
+impl Flow {
+    pub fn new(channel_value: Amount, window_start: Timestamp) -> Self {
+        Self {
+            inflow: Amount::from(0u64),
+            outflow: Amount::from(0u64),
+            channel_value,
+            window_start,
+        }
+    }
+}
+
+/// A rate limit, along with the flow recorded against it in the current time
+/// window.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    pub path: RatePath,
+    pub quota: Quota,
+    pub flow: Flow,
+}
+
+impl RateLimit {
+    /// Creates a rate limit whose first window starts on its first use.
+    pub fn new(path: RatePath, quota: Quota) -> Self {
+        Self {
+            path,
+            quota,
+            flow: Flow::new(Amount::from(0u64), Timestamp::from_nanoseconds(0)),
+        }
+    }
+
+    /// Returns true if the current window is over at the given time, or if
+    /// no window was started yet.
+    pub fn is_window_expired(&self, now: Timestamp) -> bool {
+        if self.flow.window_start.nanoseconds() == 0 {
+            return true;
+        }
+
+        now.duration_since(&self.flow.window_start)
+            .is_some_and(|elapsed| elapsed >= self.quota.duration())
+    }
+
+    /// Starts a new window at the given time, with the given channel value.
+    pub fn reset(&mut self, channel_value: Amount, now: Timestamp) {
+        self.flow = Flow::new(channel_value, now);
+    }
+
+    /// Records an outflow of the given amount, unless it would bring the net
+    /// outflow of the window over the quota.
+    pub fn send(&mut self, amount: Amount) -> Result<(), RateLimitError> {
+        let outflow = self
+            .flow
+            .outflow
+            .checked_add(amount)
+            .ok_or(RateLimitError::FlowAmountOverflow)?;
+
+        if outflow > self.capacity(self.flow.inflow, self.quota.max_percent_send)? {
+            return Err(RateLimitError::SendQuotaExceeded {
+                denom: self.path.denom.clone(),
+                channel_id: self.path.channel_id.clone(),
+            });
+        }
+
+        self.flow.outflow = outflow;
+
+        Ok(())
+    }
+
+    /// Records an inflow of the given amount, unless it would bring the net
+    /// inflow of the window over the quota.
+    pub fn receive(&mut self, amount: Amount) -> Result<(), RateLimitError> {
+        let inflow = self
+            .flow
+            .inflow
+            .checked_add(amount)
+            .ok_or(RateLimitError::FlowAmountOverflow)?;
+
+        if inflow > self.capacity(self.flow.outflow, self.quota.max_percent_recv)? {
+            return Err(RateLimitError::RecvQuotaExceeded {
+                denom: self.path.denom.clone(),
+                channel_id: self.path.channel_id.clone(),
+            });
+        }
+
+        self.flow.inflow = inflow;
+
+        Ok(())
+    }
+
+    /// Reverts an outflow recorded in the current window, once the packet
+    /// which carried it is refunded.
+    pub fn undo_send(&mut self, amount: Amount) {
+        self.flow.outflow = self
+            .flow
+            .outflow
+            .checked_sub(amount)
+            .unwrap_or(Amount::from(0u64));
+    }
+
+    /// Returns the maximum flow in one direction, given the flow in the other
+    /// direction, which offsets it.
+    fn capacity(&self, counterflow: Amount, max_percent: u64) -> Result<Amount, RateLimitError> {
+        self.flow
+            .channel_value
+            .checked_mul(Amount::from(max_percent))
+            .and_then(|value| value.checked_div(Amount::from(100u64)))
+            .and_then(|quota| counterflow.checked_add(quota))
+            .ok_or(RateLimitError::FlowAmountOverflow)
+    }
+}
+
+/// An outflow recorded for a packet sent but not acknowledged yet, which is
+/// reverted if the packet is refunded within the same window.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingOutflow {
+    pub path: RatePath,
+    pub amount: Amount,
+    /// The start of the window the outflow was recorded in.
+    pub window_start: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(max_percent_send: u64, max_percent_recv: u64) -> RateLimit {
+        let mut rate_limit = RateLimit::new(
+            RatePath::new("uatom".parse().unwrap(), ChannelId::zero()),
+            Quota::new(max_percent_send, max_percent_recv, 24).unwrap(),
+        );
+        rate_limit.reset(Amount::from(1000u64), Timestamp::from_nanoseconds(1));
+        rate_limit
+    }
+
+    #[test]
+    fn test_send_within_quota() {
+        let mut rate_limit = rate_limit(10, 10);
+
+        rate_limit.send(Amount::from(60u64)).unwrap();
+        rate_limit.send(Amount::from(40u64)).unwrap();
+        assert!(matches!(
+            rate_limit.send(Amount::from(1u64)),
+            Err(RateLimitError::SendQuotaExceeded { .. })
+        ));
+        assert_eq!(rate_limit.flow.outflow, Amount::from(100u64));
+    }
+
+    #[test]
+    fn test_inflow_offsets_outflow() {
+        let mut rate_limit = rate_limit(10, 10);
+
+        rate_limit.receive(Amount::from(50u64)).unwrap();
+        rate_limit.send(Amount::from(150u64)).unwrap();
+        rate_limit.receive(Amount::from(200u64)).unwrap();
+        assert!(rate_limit.receive(Amount::from(1u64)).is_err());
+    }
+
+    #[test]
+    fn test_undo_send_frees_quota() {
+        let mut rate_limit = rate_limit(10, 10);
+
+        rate_limit.send(Amount::from(100u64)).unwrap();
+        rate_limit.undo_send(Amount::from(100u64));
+        rate_limit.send(Amount::from(100u64)).unwrap();
+    }
+
+    #[test]
+    fn test_window_expiry() {
+        let rate_limit = rate_limit(10, 10);
+        let hour = 3_600_000_000_000;
+
+        assert!(!rate_limit.is_window_expired(Timestamp::from_nanoseconds(23 * hour)));
+        assert!(rate_limit.is_window_expired(Timestamp::from_nanoseconds(24 * hour + 1)));
+    }
+
+    #[test]
+    fn test_invalid_quota() {
+        assert!(Quota::new(101, 10, 1).is_err());
+        assert!(Quota::new(0, 0, 1).is_err());
+        assert!(Quota::new(10, 10, 0).is_err());
+    }
+}
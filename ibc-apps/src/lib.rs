@@ -27,3 +27,40 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the implementation of the IBC [Relayer Incentivization
+/// Middleware](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) application logic.
+pub mod fee {
+    #[doc(inline)]
+    pub use ibc_app_fee::*;
+}
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) application logic.
+pub mod interchain_accounts {
+    #[doc(inline)]
+    pub use ibc_app_interchain_accounts::*;
+}
+
+/// Re-exports the implementation of the packet-forward middleware application
+/// logic, which forwards ICS-20 packets through several chains.
+pub mod packet_forward {
+    #[doc(inline)]
+    pub use ibc_app_packet_forward::*;
+}
+
+/// Re-exports the implementation of the rate-limit middleware application
+/// logic, which caps the flow of ICS-20 tokens over each channel.
+pub mod rate_limit {
+    #[doc(inline)]
+    pub use ibc_app_rate_limit::*;
+}
+
+/// Re-exports the implementation of the transfer hooks middleware application
+/// logic, which calls contracts through the memo of ICS-20 packets.
+pub mod transfer_hooks {
+    #[doc(inline)]
+    pub use ibc_app_transfer_hooks::*;
+}
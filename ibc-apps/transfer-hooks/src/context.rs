@@ -0,0 +1,47 @@
+//! Defines the main context trait of the transfer hooks middleware
+
+use ibc_app_transfer::context::TokenTransferExecutionContext;
+use ibc_app_transfer_hooks_types::HookCall;
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::error::HostError;
+use ibc_core::primitives::Signer;
+
+/// Executes the contract calls of the transfer hooks middleware, to be
+/// implemented by the host.
+///
+/// The token transfer context is used to take the tokens back from the
+/// called contract if its call fails.
+pub trait HookExecutor: TokenTransferExecutionContext {
+    /// Calls a contract with the tokens it received through the given packet.
+    ///
+    /// The `sender` is the sender of the packet on the counterparty chain,
+    /// which the host is expected to turn into a local account the contract
+    /// can trust, e.g. by deriving it from the channel and the sender. The
+    /// host must revert any change done by a failed call.
+    fn execute_hook(
+        &mut self,
+        packet: &Packet,
+        sender: &Signer,
+        call: &HookCall,
+        funds: &[PrefixedCoin],
+    ) -> Result<(), HostError>;
+
+    /// Calls back the contract which sent the given packet, once it is
+    /// acknowledged.
+    fn execute_ack_callback(
+        &mut self,
+        contract: &Signer,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+    ) -> Result<(), HostError>;
+
+    /// Calls back the contract which sent the given packet, once it timed
+    /// out.
+    fn execute_timeout_callback(
+        &mut self,
+        contract: &Signer,
+        packet: &Packet,
+    ) -> Result<(), HostError>;
+}
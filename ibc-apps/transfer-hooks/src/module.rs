@@ -0,0 +1,342 @@
+//! Provides the [`TransferHooksMiddleware`], which calls the contracts named
+//! in the memo of the ICS-20 packets handled by the wrapped token transfer
+//! application.
+use core::fmt::Debug;
+
+use ibc_app_transfer::handler::{
+    decode_recv_packet_data, decode_sent_packet_data, escrow_execute, is_ack_successful,
+    received_coin,
+};
+use ibc_app_transfer_hooks_types::error::TransferHooksError;
+use ibc_app_transfer_hooks_types::{CallbackMetadata, HookCall, HookMetadata};
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{is_sender_chain_source, Memo, PrefixedCoin};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::HookExecutor;
+
+/// Wraps the token transfer application to call the contracts named in the
+/// memo of the packets it handles.
+///
+/// When a received packet carries a [`HookMetadata`], its tokens are received
+/// by the called contract, which is then called with them. If the call fails,
+/// the tokens are taken back from the contract, and an error acknowledgement
+/// sends them back to their source. Tokens which cannot all be taken back are
+/// left with the contract, and the packet is acknowledged as received.
+///
+/// When a sent packet carries a [`CallbackMetadata`], the contract which sent
+/// it is called back once it is acknowledged or timed out.
+#[derive(Debug)]
+pub struct TransferHooksMiddleware<M, Ctx> {
+    app: M,
+    ctx: Ctx,
+}
+
+impl<M, Ctx> TransferHooksMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: HookExecutor + Debug,
+{
+    pub fn new(app: M, ctx: Ctx) -> Self {
+        Self { app, ctx }
+    }
+
+    /// Returns the transfer hooks context of the middleware.
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    /// Receives the tokens of the packet with the application, then calls
+    /// the contract with them.
+    fn receive_and_call(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+        data: PacketDataV2,
+        call: HookCall,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        if data.receiver != call.contract {
+            let e = TransferHooksError::ReceiverNotContract {
+                receiver: data.receiver,
+                contract: call.contract,
+            };
+            return (ModuleExtras::empty(), Some(error_ack(e)));
+        }
+
+        let (mut extras, ack) = self.app.on_recv_packet_execute(packet, relayer);
+
+        if !ack.as_ref().map_or(true, is_ack_successful) {
+            return (extras, ack);
+        }
+
+        let funds: Vec<PrefixedCoin> = data
+            .tokens
+            .into_iter()
+            .map(|token| received_coin(packet, token).0)
+            .collect();
+
+        let Err(error) = self.ctx.execute_hook(packet, &data.sender, &call, &funds) else {
+            return (extras, ack);
+        };
+
+        let hook_error = TransferHooksError::HookFailed {
+            contract: call.contract,
+            error,
+        };
+
+        // The tokens are only refunded through the error acknowledgement if
+        // they can all be taken back from the contract. Otherwise, the
+        // contract keeps them, and the packet is acknowledged as received.
+        let account = match self.validate_return_received_tokens(packet, &data.receiver, &funds) {
+            Ok(account) => account,
+            Err(e) => {
+                extras.log.push(format!(
+                    "IBC transfer hooks middleware: {hook_error}, and cannot take back the tokens: {e}"
+                ));
+                return (extras, ack);
+            }
+        };
+
+        if let Err(e) = self.return_received_tokens(packet, &account, &funds) {
+            extras.log.push(format!(
+                "IBC transfer hooks middleware: {hook_error}, and failed to take back the tokens: {e}"
+            ));
+            return (extras, Some(error_ack(e)));
+        }
+
+        (extras, Some(error_ack(hook_error)))
+    }
+
+    /// Validates that the receipt of the tokens of a packet can be undone,
+    /// and returns the account of their receiver.
+    fn validate_return_received_tokens(
+        &self,
+        packet: &Packet,
+        receiver: &Signer,
+        coins: &[PrefixedCoin],
+    ) -> Result<Ctx::AccountId, TransferHooksError> {
+        let account = self.ctx.receiver_account(receiver)?;
+        let memo = Memo::from("");
+        let mut escrowed = BTreeMap::new();
+
+        for coin in coins {
+            if is_sender_chain_source(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                &coin.denom,
+            ) {
+                self.ctx.escrow_coins_validate(
+                    &account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    coin,
+                    &memo,
+                )?;
+
+                let total_escrow = match escrowed.remove(&coin.denom) {
+                    Some(total_escrow) => total_escrow,
+                    None => self.ctx.total_escrow_for_denom(&coin.denom)?,
+                };
+                let total_escrow = total_escrow
+                    .checked_add(coin.amount)
+                    .ok_or_else(|| TokenTransferError::TotalEscrowOverflow(coin.denom.clone()))?;
+                escrowed.insert(coin.denom.clone(), total_escrow);
+            } else {
+                self.ctx.burn_coins_validate(&account, coin, &memo)?;
+            }
+        }
+
+        Ok(account)
+    }
+
+    /// Undoes the receipt of the tokens of a packet, by escrowing the ones
+    /// which were unescrowed and burning the ones which were minted.
+    fn return_received_tokens(
+        &mut self,
+        packet: &Packet,
+        account: &Ctx::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), TransferHooksError> {
+        let memo = Memo::from("");
+
+        for coin in coins {
+            if is_sender_chain_source(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                &coin.denom,
+            ) {
+                escrow_execute(
+                    &mut self.ctx,
+                    account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    coin,
+                    &memo,
+                )?;
+            } else {
+                self.ctx.burn_coins_execute(account, coin, &memo)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the hook call out of the memo of a received packet.
+///
+/// Returns `None` if the memo is not meant for the middleware, that is if it
+/// is not a JSON object with a `wasm` or `call` key.
+fn parse_hook_call(memo: &Memo) -> Result<Option<HookCall>, TransferHooksError> {
+    #[derive(serde::Deserialize)]
+    struct HookKeys {
+        wasm: Option<serde::de::IgnoredAny>,
+        call: Option<serde::de::IgnoredAny>,
+    }
+
+    match serde_json::from_str::<HookMetadata>(memo.as_ref()) {
+        Ok(metadata) => metadata.into_call(),
+        Err(e) => match serde_json::from_str::<HookKeys>(memo.as_ref()) {
+            Ok(HookKeys {
+                wasm: None,
+                call: None,
+            })
+            | Err(_) => Ok(None),
+            Ok(_) => Err(TransferHooksError::InvalidHookMetadata {
+                description: e.to_string(),
+            }),
+        },
+    }
+}
+
+/// Returns the contract to call back for the given sent packet, if its memo
+/// asks for it.
+fn callback_contract(data: &PacketDataV2) -> Result<Option<Signer>, TransferHooksError> {
+    let Ok(metadata) = serde_json::from_str::<CallbackMetadata>(data.memo.as_ref()) else {
+        return Ok(None);
+    };
+
+    if metadata.ibc_callback != data.sender {
+        return Err(TransferHooksError::CallbackNotSender {
+            contract: metadata.ibc_callback,
+            sender: data.sender.clone(),
+        });
+    }
+
+    Ok(Some(metadata.ibc_callback))
+}
+
+fn error_ack(e: TransferHooksError) -> Acknowledgement {
+    AcknowledgementStatus::error(e.into()).into()
+}
+
+impl<M, Ctx> Middleware for TransferHooksMiddleware<M, Ctx>
+where
+    M: Module,
+    Ctx: HookExecutor + Debug,
+{
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.app
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.app
+    }
+
+    /// Rejects the packets asking to call back a contract other than their
+    /// sender.
+    fn on_send_packet(&mut self, packet: Packet) -> Result<Packet, ChannelError> {
+        if let Ok(data) = decode_sent_packet_data(&self.ctx, &packet) {
+            callback_contract(&data)?;
+        }
+
+        Ok(packet)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Ok(data) = decode_recv_packet_data(&self.ctx, packet) else {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        };
+
+        match parse_hook_call(&data.memo) {
+            Ok(Some(call)) => self.receive_and_call(packet, relayer, data, call),
+            Ok(None) => self.app.on_recv_packet_execute(packet, relayer),
+            Err(e) => (ModuleExtras::empty(), Some(error_ack(e))),
+        }
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let (mut extras, result) =
+            self.app
+                .on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        if let Ok(Some(contract)) = decode_sent_packet_data(&self.ctx, packet)
+            .map_err(TransferHooksError::from)
+            .and_then(|data| callback_contract(&data))
+        {
+            // A failing callback does not prevent the packet from being
+            // acknowledged.
+            if let Err(e) = self
+                .ctx
+                .execute_ack_callback(&contract, packet, acknowledgement)
+            {
+                extras.log.push(format!(
+                    "IBC transfer hooks middleware: acknowledgement callback to `{contract}` failed: {e}"
+                ));
+            }
+        }
+
+        (extras, result)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let (mut extras, result) = self.app.on_timeout_packet_execute(packet, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        if let Ok(Some(contract)) = decode_sent_packet_data(&self.ctx, packet)
+            .map_err(TransferHooksError::from)
+            .and_then(|data| callback_contract(&data))
+        {
+            if let Err(e) = self.ctx.execute_timeout_callback(&contract, packet) {
+                extras.log.push(format!(
+                    "IBC transfer hooks middleware: timeout callback to `{contract}` failed: {e}"
+                ));
+            }
+        }
+
+        (extras, result)
+    }
+}
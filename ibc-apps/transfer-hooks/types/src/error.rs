@@ -0,0 +1,62 @@
+//! Defines the transfer hooks middleware error types.
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::host::types::error::HostError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+#[derive(Display, Debug)]
+pub enum TransferHooksError {
+    /// host error: {0}
+    Host(HostError),
+    /// token transfer error: {0}
+    TokenTransfer(TokenTransferError),
+    /// invalid hook metadata: {description}
+    InvalidHookMetadata { description: String },
+    /// receiver `{receiver}` is not the called contract `{contract}`
+    ReceiverNotContract { receiver: Signer, contract: Signer },
+    /// callback contract `{contract}` is not the sender `{sender}`
+    CallbackNotSender { contract: Signer, sender: Signer },
+    /// hook call to `{contract}` failed: {error}
+    HookFailed { contract: Signer, error: HostError },
+}
+
+impl From<HostError> for TransferHooksError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+impl From<TokenTransferError> for TransferHooksError {
+    fn from(e: TokenTransferError) -> Self {
+        Self::TokenTransfer(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransferHooksError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Host(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            Self::HookFailed { error: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransferHooksError> for ChannelError {
+    fn from(err: TransferHooksError) -> Self {
+        ChannelError::AppSpecific {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<TransferHooksError> for StatusValue {
+    fn from(e: TransferHooksError) -> Self {
+        StatusValue::new(e.to_string()).expect("error message must not be empty")
+    }
+}
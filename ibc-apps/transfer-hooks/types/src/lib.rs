@@ -0,0 +1,28 @@
+//! Implementation of the transfer hooks middleware data structures, which let
+//! the memo of an ICS-20 packet carry a call to a contract of the receiving
+//! chain.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod metadata;
+mod msg;
+
+pub use metadata::*;
+pub use msg::*;
+pub mod error;
+
+/// Module identifier for the transfer hooks middleware.
+pub const MODULE_ID_STR: &str = "transferhooks";
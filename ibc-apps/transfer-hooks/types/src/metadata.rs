@@ -0,0 +1,59 @@
+//! Defines the hook metadata carried by the memo of an ICS-20 packet.
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::TransferHooksError;
+use crate::HookMsg;
+
+/// The memo of a received ICS-20 packet whose tokens are to be handed to a
+/// contract along with a call, e.g.
+///
+/// ```json
+/// {"wasm":{"contract":"cosmos1..","msg":{"swap":{"min_out":"100"}}}}
+/// ```
+///
+/// The `call` key is an alias of `wasm` for hosts whose contracts are not
+/// CosmWasm ones. Exactly one of the two must be set.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookMetadata {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub wasm: Option<HookCall>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub call: Option<HookCall>,
+}
+
+impl HookMetadata {
+    /// Returns the call to execute, failing if both keys are set.
+    pub fn into_call(self) -> Result<Option<HookCall>, TransferHooksError> {
+        match (self.wasm, self.call) {
+            (Some(_), Some(_)) => Err(TransferHooksError::InvalidHookMetadata {
+                description: "only one of `wasm` and `call` may be set".to_string(),
+            }),
+            (wasm, call) => Ok(wasm.or(call)),
+        }
+    }
+}
+
+/// A call to a contract, which receives the tokens of the packet.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookCall {
+    /// The contract to call, which must also be the receiver of the packet.
+    pub contract: Signer,
+    /// The message the contract is called with.
+    pub msg: HookMsg,
+}
+
+/// The memo of a sent ICS-20 packet whose sender is a contract to be called
+/// back once the packet is acknowledged or timed out, e.g.
+///
+/// ```json
+/// {"ibc_callback":"cosmos1.."}
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallbackMetadata {
+    /// The contract to call back, which must be the sender of the packet.
+    pub ibc_callback: Signer,
+}
@@ -0,0 +1,138 @@
+//! Defines the message of a hook call.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::primitives::prelude::*;
+
+/// The message a contract is called with, kept as the JSON text found in the
+/// memo so that the host can decode it into the contract's own types.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookMsg(String);
+
+impl HookMsg {
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl AsRef<str> for HookMsg {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for HookMsg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use core::fmt::{Formatter, Result as FmtResult};
+
+    use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+    use super::*;
+
+    impl<'de> Deserialize<'de> for HookMsg {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(JsonTextVisitor).map(Self)
+        }
+    }
+
+    /// Writes any JSON value back as JSON text. Floating-point numbers are
+    /// not supported, as they are not by contracts either.
+    struct JsonTextVisitor;
+
+    impl<'de> Visitor<'de> for JsonTextVisitor {
+        type Value = String;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("a JSON value")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<String, E> {
+            Ok(v.to_string())
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<String, E> {
+            Ok(v.to_string())
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<String, E> {
+            Ok(v.to_string())
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<String, E> {
+            Ok(quote(v))
+        }
+
+        fn visit_unit<E>(self) -> Result<String, E> {
+            Ok("null".to_string())
+        }
+
+        fn visit_none<E>(self) -> Result<String, E> {
+            Ok("null".to_string())
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<String, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<String, A::Error> {
+            let mut elements = Vec::new();
+
+            while let Some(element) = seq.next_element::<HookMsg>()? {
+                elements.push(element.0);
+            }
+
+            Ok(format!("[{}]", elements.join(",")))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<String, A::Error> {
+            let mut entries = Vec::new();
+
+            while let Some(key) = map.next_key::<String>()? {
+                let value = map.next_value::<HookMsg>()?;
+                entries.push(format!("{}:{}", quote(&key), value.0));
+            }
+
+            Ok(format!("{{{}}}", entries.join(",")))
+        }
+    }
+
+    /// Returns the given string as a JSON string literal.
+    fn quote(s: &str) -> String {
+        let mut quoted = String::with_capacity(s.len() + 2);
+        quoted.push('"');
+
+        for c in s.chars() {
+            match c {
+                '"' => quoted.push_str("\\\""),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+                c => quoted.push(c),
+            }
+        }
+
+        quoted.push('"');
+        quoted
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_msg_keeps_json_text() {
+        let json = r#"{"swap":{"min_out":"100","route":[1,2],"exact":true,"referrer":null,"note":"a \"b\"\n"}}"#;
+
+        let msg: HookMsg = serde_json::from_str(json).unwrap();
+
+        assert_eq!(msg.as_ref(), json);
+    }
+}
@@ -0,0 +1,33 @@
+//! Defines the trait through which a light client implementation is plugged
+//! into the CosmWasm [`Context`].
+
+use ibc_core_client::context::client_state::ClientStateExecution;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_host::types::error::DecodingError;
+use ibc_primitives::proto::Any;
+
+use crate::context::Context;
+
+/// Selects the client and consensus state types of the light client run by
+/// the contract.
+///
+/// Both types are stored in the contract's client store as `Any`-encoded
+/// values wrapped into the ICS-08 Wasm client and consensus states, so that
+/// the host can read them back.
+///
+/// # Example
+///
+/// ```ignore
+/// pub struct TendermintClient;
+///
+/// impl<'a> ClientType<'a> for TendermintClient {
+///     type ClientState = ClientState;
+///     type ConsensusState = ConsensusState;
+/// }
+/// ```
+pub trait ClientType<'a>: Sized {
+    type ClientState: ClientStateExecution<Context<'a, Self>>
+        + TryFrom<Any, Error = DecodingError>
+        + Clone;
+    type ConsensusState: ConsensusStateTrait + TryFrom<Any, Error = DecodingError>;
+}
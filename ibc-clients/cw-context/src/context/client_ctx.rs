@@ -0,0 +1,255 @@
+//! Implements the ICS-02 client contexts for [`Context`], on top of the
+//! contract's client store.
+
+use cosmwasm_std::Order;
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::error::HostError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::{Timestamp, ToVec};
+
+use super::{decode_any, Context};
+use crate::api::ClientType;
+use crate::utils::{
+    consensus_state_key, height_from_iteration_key, iteration_key, processed_height_key,
+    processed_time_key, CLIENT_STATE_KEY, ITERATE_CONSENSUS_STATE_PREFIX,
+};
+
+impl<'a, C: ClientType<'a>> ClientValidationContext for Context<'a, C> {
+    type ClientStateRef = C::ClientState;
+    type ConsensusStateRef = C::ConsensusState;
+
+    /// The client store only holds the state of the client served by the
+    /// contract, so the client identifier is not needed.
+    fn client_state(&self, _client_id: &ClientId) -> Result<Self::ClientStateRef, HostError> {
+        let wasm_client_state = self.wasm_client_state()?;
+        let any = decode_any(&wasm_client_state.data).map_err(HostError::invalid_state)?;
+
+        C::ClientState::try_from(any).map_err(HostError::invalid_state)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, HostError> {
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )
+        .map_err(HostError::invalid_state)?;
+
+        let bytes = self
+            .retrieve(consensus_state_key(&height))
+            .ok_or(HostError::missing_state(format!(
+                "consensus state for client `{}` at height `{height}`",
+                client_cons_state_path.client_id
+            )))?;
+        let wasm_consensus_state: WasmConsensusState =
+            Protobuf::<Any>::decode_vec(&bytes).map_err(HostError::invalid_state)?;
+        let any = decode_any(&wasm_consensus_state.data).map_err(HostError::invalid_state)?;
+
+        C::ConsensusState::try_from(any).map_err(HostError::invalid_state)
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), HostError> {
+        let missing_meta = || {
+            HostError::missing_state(format!(
+                "update metadata for client `{client_id}` at height `{height}`"
+            ))
+        };
+
+        let time_bytes = self
+            .retrieve(processed_time_key(height))
+            .ok_or_else(missing_meta)?;
+        let time_bytes: [u8; 8] = time_bytes
+            .try_into()
+            .map_err(|_| HostError::invalid_state("processed time must be 8 bytes long"))?;
+
+        let height_bytes = self
+            .retrieve(processed_height_key(height))
+            .ok_or_else(missing_meta)?;
+        let processed_height = String::from_utf8(height_bytes)
+            .map_err(HostError::invalid_state)?
+            .parse()
+            .map_err(HostError::invalid_state)?;
+
+        Ok((
+            Timestamp::from_nanoseconds(u64::from_be_bytes(time_bytes)),
+            processed_height,
+        ))
+    }
+}
+
+impl<'a, C: ClientType<'a>> ClientExecutionContext for Context<'a, C> {
+    type ClientStateMut = C::ClientState;
+
+    /// Wraps the client state into a Wasm client state, so that the host can
+    /// read its latest height and the checksum of the contract's code.
+    fn store_client_state(
+        &mut self,
+        _client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), HostError> {
+        let wasm_client_state = WasmClientState {
+            checksum: self.obtain_checksum()?,
+            latest_height: client_state.latest_height(),
+            data: Into::<Any>::into(client_state).to_vec(),
+        };
+
+        self.insert(CLIENT_STATE_KEY, Any::from(wasm_client_state).to_vec())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), HostError> {
+        let height = Height::new(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        )
+        .map_err(HostError::invalid_state)?;
+
+        let wasm_consensus_state =
+            WasmConsensusState::new(Into::<Any>::into(consensus_state).to_vec());
+
+        self.insert(
+            consensus_state_key(&height),
+            Any::from(wasm_consensus_state).to_vec(),
+        )?;
+        self.insert(iteration_key(&height), consensus_state_key(&height))
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), HostError> {
+        let height = Height::new(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        )
+        .map_err(HostError::invalid_state)?;
+
+        self.remove(consensus_state_key(&height))?;
+        self.remove(iteration_key(&height))
+    }
+
+    fn store_update_meta(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), HostError> {
+        self.insert(
+            processed_time_key(&height),
+            host_timestamp.nanoseconds().to_be_bytes(),
+        )?;
+        self.insert(processed_height_key(&height), host_height.to_string())
+    }
+
+    fn delete_update_meta(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+    ) -> Result<(), HostError> {
+        self.remove(processed_time_key(&height))?;
+        self.remove(processed_height_key(&height))
+    }
+}
+
+impl<'a, C: ClientType<'a>> ExtClientValidationContext for Context<'a, C> {
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        Ok(Timestamp::from_nanoseconds(self.env().block.time.nanos()))
+    }
+
+    /// Contracts are not told the revision number of the host chain, so host
+    /// heights are always reported within revision `0`.
+    fn host_height(&self) -> Result<Height, HostError> {
+        Height::new(0, self.env().block.height).map_err(HostError::invalid_state)
+    }
+
+    fn consensus_state_heights(&self, _client_id: &ClientId) -> Result<Vec<Height>, HostError> {
+        self.range(
+            ITERATE_CONSENSUS_STATE_PREFIX,
+            &iteration_prefix_end(),
+            Order::Ascending,
+        )
+        .map(|(key, _)| {
+            height_from_iteration_key(&key).ok_or(HostError::invalid_state(
+                "malformed consensus state iteration key",
+            ))
+        })
+        .collect()
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        // Iteration keys have a fixed length, so any key strictly above the
+        // one of `height` is at least `iteration_key(height) || 0x00`.
+        let mut start = iteration_key(height);
+        start.push(0);
+
+        let next_height = self
+            .range(&start, &iteration_prefix_end(), Order::Ascending)
+            .next()
+            .and_then(|(key, _)| height_from_iteration_key(&key));
+
+        self.consensus_state_at(client_id, next_height)
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        let prev_height = self
+            .range(
+                ITERATE_CONSENSUS_STATE_PREFIX,
+                &iteration_key(height),
+                Order::Descending,
+            )
+            .next()
+            .and_then(|(key, _)| height_from_iteration_key(&key));
+
+        self.consensus_state_at(client_id, prev_height)
+    }
+}
+
+impl<'a, C: ClientType<'a>> Context<'a, C> {
+    fn consensus_state_at(
+        &self,
+        client_id: &ClientId,
+        height: Option<Height>,
+    ) -> Result<Option<C::ConsensusState>, HostError> {
+        height
+            .map(|height| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    height.revision_number(),
+                    height.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+}
+
+/// Returns the smallest key above all iteration keys.
+fn iteration_prefix_end() -> Vec<u8> {
+    let mut end = ITERATE_CONSENSUS_STATE_PREFIX.to_vec();
+    if let Some(last) = end.last_mut() {
+        *last += 1;
+    }
+    end
+}
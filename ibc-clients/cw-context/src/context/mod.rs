@@ -0,0 +1,193 @@
+//! Defines the [`Context`] through which light client implementations access
+//! the contract's client store.
+
+mod client_ctx;
+
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use cosmwasm_std::{Deps, DepsMut, Env, Order, Record, Storage};
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+use ibc_core_host::types::error::{DecodingError, HostError};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::proto::{Any, Protobuf};
+use prost::Message;
+
+use crate::api::ClientType;
+use crate::error::ContractError;
+use crate::utils::CLIENT_STATE_KEY;
+
+/// The part of a recovery store that the context reads from and writes to.
+///
+/// When migrating a client store, the host hands the contract a store
+/// combining the subject and substitute client stores, in which each key is
+/// prefixed with the client it belongs to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MigrationPrefix {
+    #[default]
+    None,
+    Subject,
+    Substitute,
+}
+
+impl MigrationPrefix {
+    pub fn key(&self) -> &'static [u8] {
+        match self {
+            Self::None => b"",
+            Self::Subject => SUBJECT_PREFIX,
+            Self::Substitute => SUBSTITUTE_PREFIX,
+        }
+    }
+}
+
+enum ContractStorage<'a> {
+    Ref(&'a dyn Storage),
+    Mut(&'a mut dyn Storage),
+}
+
+/// Context handed to the light client implementation selected by `C`, which
+/// reads and writes client and consensus states from the contract's client
+/// store.
+///
+/// Contracts cannot learn the identifier of the client they serve other than
+/// through their address, which ICS-08 hosts set to the client identifier.
+pub struct Context<'a, C> {
+    storage: ContractStorage<'a>,
+    env: Env,
+    client_id: ClientId,
+    checksum: Option<Vec<u8>>,
+    migration_prefix: MigrationPrefix,
+    client_type: PhantomData<C>,
+}
+
+impl<'a, C: ClientType<'a>> Context<'a, C> {
+    /// Creates a read-only context, as used by queries.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Result<Self, ContractError> {
+        Self::new(ContractStorage::Ref(deps.storage), env)
+    }
+
+    /// Creates a context that can write to the client store, as used by
+    /// `instantiate` and `sudo` calls.
+    pub fn new_mut(deps: DepsMut<'a>, env: Env) -> Result<Self, ContractError> {
+        Self::new(ContractStorage::Mut(deps.storage), env)
+    }
+
+    fn new(storage: ContractStorage<'a>, env: Env) -> Result<Self, ContractError> {
+        let client_id = ClientId::from_str(env.contract.address.as_str())?;
+
+        Ok(Self {
+            storage,
+            env,
+            client_id,
+            checksum: None,
+            migration_prefix: MigrationPrefix::None,
+            client_type: PhantomData,
+        })
+    }
+
+    pub fn client_id(&self) -> ClientId {
+        self.client_id.clone()
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Sets the checksum of the contract's code, which is only known to the
+    /// contract when it is instantiated. Afterwards, it is read from the
+    /// stored client state.
+    pub fn set_checksum(&mut self, checksum: Vec<u8>) {
+        self.checksum = Some(checksum);
+    }
+
+    pub fn set_migration_prefix(&mut self, migration_prefix: MigrationPrefix) {
+        self.migration_prefix = migration_prefix;
+    }
+
+    fn prefixed_key(&self, key: impl AsRef<[u8]>) -> Vec<u8> {
+        [self.migration_prefix.key(), key.as_ref()].concat()
+    }
+
+    fn storage_ref(&self) -> &dyn Storage {
+        match &self.storage {
+            ContractStorage::Ref(storage) => *storage,
+            ContractStorage::Mut(storage) => &**storage,
+        }
+    }
+
+    fn storage_mut(&mut self) -> Result<&mut dyn Storage, HostError> {
+        match &mut self.storage {
+            ContractStorage::Ref(_) => Err(HostError::failed_to_store(
+                "client store is read-only within queries",
+            )),
+            ContractStorage::Mut(storage) => Ok(&mut **storage),
+        }
+    }
+
+    /// Reads the value stored under `key`, within the current migration
+    /// prefix.
+    pub fn retrieve(&self, key: impl AsRef<[u8]>) -> Option<Vec<u8>> {
+        self.storage_ref().get(&self.prefixed_key(key))
+    }
+
+    /// Writes `value` under `key`, within the current migration prefix.
+    pub fn insert(
+        &mut self,
+        key: impl AsRef<[u8]>,
+        value: impl AsRef<[u8]>,
+    ) -> Result<(), HostError> {
+        let key = self.prefixed_key(key);
+        self.storage_mut()?.set(&key, value.as_ref());
+        Ok(())
+    }
+
+    /// Removes the value stored under `key`, within the current migration
+    /// prefix.
+    pub fn remove(&mut self, key: impl AsRef<[u8]>) -> Result<(), HostError> {
+        let key = self.prefixed_key(key);
+        self.storage_mut()?.remove(&key);
+        Ok(())
+    }
+
+    /// Iterates over the records within `[start, end)`, within the current
+    /// migration prefix. The returned keys are stripped from the prefix.
+    pub fn range(
+        &self,
+        start: &[u8],
+        end: &[u8],
+        order: Order,
+    ) -> impl Iterator<Item = Record> + '_ {
+        let prefix = self.migration_prefix.key();
+
+        self.storage_ref()
+            .range(
+                Some(&self.prefixed_key(start)),
+                Some(&self.prefixed_key(end)),
+                order,
+            )
+            .map(move |(key, value)| (key[prefix.len()..].to_vec(), value))
+    }
+
+    /// Returns the Wasm-wrapped client state, as seen by the host.
+    pub fn wasm_client_state(&self) -> Result<WasmClientState, HostError> {
+        let bytes = self
+            .retrieve(CLIENT_STATE_KEY)
+            .ok_or(HostError::missing_state("Wasm client state"))?;
+
+        Protobuf::<Any>::decode_vec(&bytes).map_err(HostError::invalid_state)
+    }
+
+    /// Returns the checksum of the contract's code.
+    pub fn obtain_checksum(&self) -> Result<Vec<u8>, HostError> {
+        match &self.checksum {
+            Some(checksum) => Ok(checksum.clone()),
+            None => Ok(self.wasm_client_state()?.checksum),
+        }
+    }
+}
+
+/// Decodes an `Any` from the bytes of a Wasm-wrapped state or message.
+pub fn decode_any(bytes: &[u8]) -> Result<Any, DecodingError> {
+    Ok(Any::decode(bytes)?)
+}
@@ -0,0 +1,76 @@
+//! Defines the error type returned by the contract entry point handlers.
+
+use cosmwasm_std::StdError;
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::error::{DecodingError, HostError, IdentifierError};
+use ibc_primitives::Timestamp;
+
+#[derive(Debug, Display)]
+pub enum ContractError {
+    /// client error: {0}
+    Client(ClientError),
+    /// host error: {0}
+    Host(HostError),
+    /// decoding error: {0}
+    Decoding(DecodingError),
+    /// identifier error: {0}
+    Identifier(IdentifierError),
+    /// CosmWasm error: {0}
+    Std(StdError),
+    /// invalid merkle path: expected a prefix and a path, got `{0}` keys
+    InvalidMerklePath(usize),
+    /// insufficient time elapsed: current timestamp `{current_timestamp}` is before the earliest valid timestamp `{earliest_valid_timestamp}`
+    InsufficientTimeElapsed {
+        current_timestamp: Timestamp,
+        earliest_valid_timestamp: Timestamp,
+    },
+    /// insufficient blocks elapsed: current height `{current_height}` is before the earliest valid height `{earliest_valid_height}`
+    InsufficientBlocksElapsed {
+        current_height: Height,
+        earliest_valid_height: Height,
+    },
+}
+
+impl From<ClientError> for ContractError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+impl From<HostError> for ContractError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+impl From<DecodingError> for ContractError {
+    fn from(e: DecodingError) -> Self {
+        Self::Decoding(e)
+    }
+}
+
+impl From<IdentifierError> for ContractError {
+    fn from(e: IdentifierError) -> Self {
+        Self::Identifier(e)
+    }
+}
+
+impl From<StdError> for ContractError {
+    fn from(e: StdError) -> Self {
+        Self::Std(e)
+    }
+}
+
+impl std::error::Error for ContractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Client(e) => Some(e),
+            Self::Host(e) => Some(e),
+            Self::Decoding(e) => Some(e),
+            Self::Identifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Implements the handlers of the contract entry points, which decode the
+//! messages sent by the ICS-08 host and dispatch them to the light client.
+
+use cosmwasm_std::{to_json_binary, Binary};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, PathBytes};
+use ibc_primitives::Timestamp;
+
+use crate::api::ClientType;
+use crate::context::{decode_any, Context, MigrationPrefix};
+use crate::error::ContractError;
+use crate::types::{
+    CheckForMisbehaviourResult, EmptyResult, InstantiateMsg, MerklePath, QueryMsg, StatusResult,
+    SudoMsg, TimestampAtHeightResult, UpdateStateResult,
+};
+
+impl<'a, C: ClientType<'a>> Context<'a, C> {
+    /// Initialises the client from the client and consensus states passed
+    /// by the host on client creation.
+    pub fn instantiate(&mut self, msg: InstantiateMsg) -> Result<Binary, ContractError> {
+        let client_id = self.client_id();
+
+        let client_state = C::ClientState::try_from(decode_any(&msg.client_state)?)?;
+        let consensus_state = decode_any(&msg.consensus_state)?;
+
+        client_state.verify_consensus_state(consensus_state.clone(), &self.host_timestamp()?)?;
+
+        self.set_checksum(msg.checksum);
+
+        client_state.initialise(self, &client_id, consensus_state)?;
+
+        Ok(to_json_binary(&EmptyResult {})?)
+    }
+
+    /// Handles the calls through which the host lets the client update or
+    /// verify against its store.
+    pub fn sudo(&mut self, msg: SudoMsg) -> Result<Binary, ContractError> {
+        let client_id = self.client_id();
+
+        // Recovery stores hold no state under the subject's unprefixed keys,
+        // so the client state is only read within each of the other calls.
+        let result = match msg {
+            SudoMsg::MigrateClientStore(_) => self.migrate_client_store(&client_id)?,
+            SudoMsg::UpdateState(msg) => {
+                let client_state = self.client_state(&client_id)?;
+                let heights = client_state.update_state(
+                    self,
+                    &client_id,
+                    decode_any(&msg.client_message)?,
+                )?;
+
+                to_json_binary(&UpdateStateResult { heights })?
+            }
+            SudoMsg::UpdateStateOnMisbehaviour(msg) => {
+                let client_state = self.client_state(&client_id)?;
+                client_state.update_state_on_misbehaviour(
+                    self,
+                    &client_id,
+                    decode_any(&msg.client_message)?,
+                )?;
+
+                to_json_binary(&EmptyResult {})?
+            }
+            SudoMsg::VerifyUpgradeAndUpdateState(msg) => {
+                let client_state = self.client_state(&client_id)?;
+                let upgraded_client_state = decode_any(&msg.upgrade_client_state)?;
+                let upgraded_consensus_state = decode_any(&msg.upgrade_consensus_state)?;
+
+                let latest_height = client_state.latest_height();
+                let latest_consensus_state =
+                    self.consensus_state(&consensus_state_path(&client_id, &latest_height))?;
+
+                client_state.verify_upgrade_client(
+                    upgraded_client_state.clone(),
+                    upgraded_consensus_state.clone(),
+                    CommitmentProofBytes::try_from(msg.proof_upgrade_client)?,
+                    CommitmentProofBytes::try_from(msg.proof_upgrade_consensus_state)?,
+                    latest_consensus_state.root(),
+                )?;
+                client_state.update_state_on_upgrade(
+                    self,
+                    &client_id,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                )?;
+
+                to_json_binary(&EmptyResult {})?
+            }
+            SudoMsg::VerifyMembership(msg) => {
+                let client_state = self.client_state(&client_id)?;
+                let (prefix, path) = split_merkle_path(msg.merkle_path)?;
+
+                self.verify_delay_passed(
+                    &client_id,
+                    &msg.height,
+                    msg.delay_time_period,
+                    msg.delay_block_period,
+                )?;
+                let consensus_state =
+                    self.consensus_state(&consensus_state_path(&client_id, &msg.height))?;
+
+                client_state.verify_membership_raw(
+                    &prefix,
+                    &CommitmentProofBytes::try_from(msg.proof)?,
+                    consensus_state.root(),
+                    path,
+                    msg.value,
+                )?;
+
+                to_json_binary(&EmptyResult {})?
+            }
+            SudoMsg::VerifyNonMembership(msg) => {
+                let client_state = self.client_state(&client_id)?;
+                let (prefix, path) = split_merkle_path(msg.merkle_path)?;
+
+                self.verify_delay_passed(
+                    &client_id,
+                    &msg.height,
+                    msg.delay_time_period,
+                    msg.delay_block_period,
+                )?;
+                let consensus_state =
+                    self.consensus_state(&consensus_state_path(&client_id, &msg.height))?;
+
+                client_state.verify_non_membership_raw(
+                    &prefix,
+                    &CommitmentProofBytes::try_from(msg.proof)?,
+                    consensus_state.root(),
+                    path,
+                )?;
+
+                to_json_binary(&EmptyResult {})?
+            }
+        };
+
+        Ok(result)
+    }
+
+    /// Handles the read-only calls of the host.
+    pub fn query(&self, msg: QueryMsg) -> Result<Binary, ContractError> {
+        let client_id = self.client_id();
+        let client_state = self.client_state(&client_id)?;
+
+        let result = match msg {
+            QueryMsg::Status(_) => to_json_binary(&StatusResult {
+                status: client_state.status(self, &client_id)?,
+            })?,
+            QueryMsg::TimestampAtHeight(msg) => {
+                let timestamp = client_state
+                    .timestamp_at_height(self, &consensus_state_path(&client_id, &msg.height))?;
+
+                to_json_binary(&TimestampAtHeightResult {
+                    timestamp: timestamp.nanoseconds(),
+                })?
+            }
+            QueryMsg::VerifyClientMessage(msg) => {
+                client_state.verify_client_message(
+                    self,
+                    &client_id,
+                    decode_any(&msg.client_message)?,
+                )?;
+
+                to_json_binary(&EmptyResult {})?
+            }
+            QueryMsg::CheckForMisbehaviour(msg) => {
+                let found_misbehaviour = client_state.check_for_misbehaviour(
+                    self,
+                    &client_id,
+                    decode_any(&msg.client_message)?,
+                )?;
+
+                to_json_binary(&CheckForMisbehaviourResult { found_misbehaviour })?
+            }
+        };
+
+        Ok(result)
+    }
+
+    /// Recovers the subject client with the state of the substitute client,
+    /// both of which are found in the combined store handed by the host.
+    ///
+    /// The contract only knows the identifier of the subject client, which
+    /// is therefore also passed as the substitute's identifier.
+    fn migrate_client_store(&mut self, client_id: &ClientId) -> Result<Binary, ContractError> {
+        self.set_migration_prefix(MigrationPrefix::Substitute);
+        let substitute_client_state = self.client_state(client_id)?;
+        let substitute_consensus_state = self.consensus_state(&consensus_state_path(
+            client_id,
+            &substitute_client_state.latest_height(),
+        ))?;
+
+        self.set_migration_prefix(MigrationPrefix::Subject);
+        let subject_client_state = self.client_state(client_id)?;
+
+        subject_client_state.check_substitute(self, substitute_client_state.clone().into())?;
+        subject_client_state.update_on_recovery(
+            self,
+            client_id,
+            client_id,
+            substitute_client_state.into(),
+            substitute_consensus_state.into(),
+        )?;
+
+        Ok(to_json_binary(&EmptyResult {})?)
+    }
+
+    /// Checks that the delay periods requested by the host have passed since
+    /// the consensus state at `height` was processed.
+    fn verify_delay_passed(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+        delay_time_period: u64,
+        delay_block_period: u64,
+    ) -> Result<(), ContractError> {
+        if delay_time_period == 0 && delay_block_period == 0 {
+            return Ok(());
+        }
+
+        let (processed_time, processed_height) = self.client_update_meta(client_id, height)?;
+
+        let current_timestamp = self.host_timestamp()?;
+        let earliest_valid_timestamp = Timestamp::from_nanoseconds(
+            processed_time
+                .nanoseconds()
+                .saturating_add(delay_time_period),
+        );
+        if current_timestamp < earliest_valid_timestamp {
+            return Err(ContractError::InsufficientTimeElapsed {
+                current_timestamp,
+                earliest_valid_timestamp,
+            });
+        }
+
+        let current_height = self.host_height()?;
+        let earliest_valid_height = processed_height.add(delay_block_period);
+        if current_height < earliest_valid_height {
+            return Err(ContractError::InsufficientBlocksElapsed {
+                current_height,
+                earliest_valid_height,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits the key path sent by the host into the counterparty's commitment
+/// prefix and the serialized path.
+fn split_merkle_path(
+    merkle_path: MerklePath,
+) -> Result<(CommitmentPrefix, PathBytes), ContractError> {
+    let [prefix, path]: [Vec<u8>; 2] = merkle_path
+        .key_path
+        .try_into()
+        .map_err(|key_path: Vec<Vec<u8>>| ContractError::InvalidMerklePath(key_path.len()))?;
+
+    Ok((
+        CommitmentPrefix::from_bytes(prefix),
+        PathBytes::from_bytes(path),
+    ))
+}
+
+fn consensus_state_path(client_id: &ClientId, height: &Height) -> ClientConsensusStatePath {
+    ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    )
+}
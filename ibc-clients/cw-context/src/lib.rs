@@ -0,0 +1,31 @@
+//! Provides the CosmWasm context and handlers needed to run any `ibc-rs` light
+//! client implementation as an ICS-08 Wasm light client contract.
+//!
+//! A light client contract only has to pick its client and consensus state
+//! types through the [`ClientType`](crate::api::ClientType) trait and forward
+//! the `instantiate`, `sudo` and `query` entry points to the corresponding
+//! [`Context`](crate::context::Context) handlers.
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+pub mod api;
+pub mod context;
+pub mod error;
+pub mod handlers;
+pub mod utils;
+
+/// Re-exports the messages exchanged between ICS-08 hosts and light client
+/// contracts from the `ibc-client-wasm-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::contract::*;
+}
@@ -0,0 +1,82 @@
+//! Defines the keys under which the contract writes to its client store.
+//!
+//! The layout follows the one used by `ibc-go`'s light clients, so that the
+//! client and consensus states written by the contract can be read back by an
+//! `ibc-go` host.
+
+use ibc_core_client::types::Height;
+
+/// Key of the Wasm-wrapped client state.
+pub const CLIENT_STATE_KEY: &[u8] = b"clientState";
+
+/// Prefix of the keys of Wasm-wrapped consensus states.
+pub const CONSENSUS_STATE_PREFIX: &str = "consensusStates";
+
+/// Prefix of the keys used to iterate over consensus states in height order.
+pub const ITERATE_CONSENSUS_STATE_PREFIX: &[u8] = b"iterateConsensusStates";
+
+const PROCESSED_TIME_SUFFIX: &str = "processedTime";
+const PROCESSED_HEIGHT_SUFFIX: &str = "processedHeight";
+
+/// Returns the key of the consensus state at the given height.
+pub fn consensus_state_key(height: &Height) -> Vec<u8> {
+    format!("{CONSENSUS_STATE_PREFIX}/{height}").into_bytes()
+}
+
+/// Returns the key of the host timestamp at which the consensus state at the
+/// given height was processed.
+pub fn processed_time_key(height: &Height) -> Vec<u8> {
+    format!("{CONSENSUS_STATE_PREFIX}/{height}/{PROCESSED_TIME_SUFFIX}").into_bytes()
+}
+
+/// Returns the key of the host height at which the consensus state at the
+/// given height was processed.
+pub fn processed_height_key(height: &Height) -> Vec<u8> {
+    format!("{CONSENSUS_STATE_PREFIX}/{height}/{PROCESSED_HEIGHT_SUFFIX}").into_bytes()
+}
+
+/// Returns the iteration key of the consensus state at the given height.
+///
+/// Heights are encoded in big-endian, so that iteration keys sort in the
+/// same order as heights.
+pub fn iteration_key(height: &Height) -> Vec<u8> {
+    let mut key = ITERATE_CONSENSUS_STATE_PREFIX.to_vec();
+    key.extend_from_slice(&height.revision_number().to_be_bytes());
+    key.extend_from_slice(&height.revision_height().to_be_bytes());
+    key
+}
+
+/// Decodes the height from an iteration key, returning `None` if the key is
+/// not a well-formed iteration key.
+pub fn height_from_iteration_key(key: &[u8]) -> Option<Height> {
+    let encoded = key.strip_prefix(ITERATE_CONSENSUS_STATE_PREFIX)?;
+    if encoded.len() != 16 {
+        return None;
+    }
+    let (revision_number, revision_height) = encoded.split_at(8);
+
+    Height::new(
+        u64::from_be_bytes(revision_number.try_into().ok()?),
+        u64::from_be_bytes(revision_height.try_into().ok()?),
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iteration_key_roundtrip() {
+        let low = Height::new(1, 255).unwrap();
+        let high = Height::new(1, 256).unwrap();
+
+        assert!(iteration_key(&low) < iteration_key(&high));
+        assert_eq!(height_from_iteration_key(&iteration_key(&low)), Some(low));
+        assert_eq!(height_from_iteration_key(b"iterateConsensusStates"), None);
+        assert_eq!(
+            consensus_state_key(&high),
+            b"consensusStates/1-256".to_vec()
+        );
+    }
+}
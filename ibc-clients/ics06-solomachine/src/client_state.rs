@@ -0,0 +1,68 @@
+//! This module includes trait implementations for the
+//! `ibc_client_solomachine_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_solomachine_types::ClientState` type in order to enable
+//! implementing a foreign trait on a foreign type (i.e. the orphan rule in
+//! Rust). As such, this module also includes some trait implementations that
+//! serve to pass through traits implemented on the wrapped `ClientState` type.
+
+use ibc_client_solomachine_types::proto::v3::ClientState as RawSmClientState;
+use ibc_client_solomachine_types::ClientState as ClientStateType;
+use ibc_core_host::types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+/// Newtype wrapper around the `ClientState` type, imported from the
+/// `ibc-client-solomachine-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ClientState` type.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, derive_more::From)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+}
+
+impl Protobuf<RawSmClientState> for ClientState {}
+
+impl TryFrom<RawSmClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawSmClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawSmClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
@@ -0,0 +1,144 @@
+use ibc_client_solomachine_types::error::SolomachineClientError;
+use ibc_client_solomachine_types::{
+    client_type as sm_client_type, encode_merkle_path, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, SignBytes, TimestampedSignatureData,
+};
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::error::DecodingError;
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::{Path, PathBytes};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::ClientState;
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        _host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let sm_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+
+        sm_consensus_state.validate()?;
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        sm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    /// Proofs are signed at the current sequence of the solo machine, so
+    /// they can only be checked at the latest height of the client.
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        let latest_height = self.latest_height();
+
+        if proof_height != latest_height {
+            return Err(SolomachineClientError::MismatchedProofHeight {
+                expected: latest_height,
+                actual: proof_height,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(SolomachineClientError::UpgradeNotSupported.into())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// The solo machine has no commitment root, so `root` is ignored: the
+    /// proof is a signature over `value` by the key of the client state.
+    fn verify_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_signature(self.inner(), prefix, proof, path, value)
+    }
+
+    /// Non-membership is proven by a signature over an empty value.
+    fn verify_non_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        verify_signature(self.inner(), prefix, proof, path, Vec::new())
+    }
+}
+
+/// Verifies that `proof`, a `TimestampedSignatureData`, holds a signature by
+/// the solo machine over `value` under the given path, at its current
+/// sequence.
+///
+/// Unlike the client of ibc-go, which increments the sequence after every
+/// verified proof, the sequence only advances with headers here, since
+/// proofs are verified without write access to the client store. A solo
+/// machine therefore signs all its proofs at the sequence of its latest
+/// header.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_signature(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: PathBytes,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let proof =
+        TimestampedSignatureData::decode_vec(proof.as_ref()).map_err(DecodingError::from)?;
+
+    let consensus_state = &client_state.consensus_state;
+
+    if proof.timestamp < consensus_state.timestamp {
+        return Err(SolomachineClientError::InsufficientProofTimestamp {
+            proof_timestamp: proof.timestamp,
+            consensus_timestamp: consensus_state.timestamp,
+        }
+        .into());
+    }
+
+    let sign_bytes = SignBytes {
+        sequence: client_state.sequence,
+        timestamp: proof.timestamp,
+        diversifier: consensus_state.diversifier.clone(),
+        path: encode_merkle_path(&[prefix.as_bytes(), path.as_ref()]),
+        data: value,
+    };
+
+    consensus_state
+        .public_key
+        .verify_signature(&sign_bytes.encode_vec(), &proof.signature_data)?;
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use ibc_client_solomachine_types::error::SolomachineClientError;
+use ibc_client_solomachine_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as SmHeader,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    /// The consensus state must be the one embedded in the client state.
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let sm_consensus_state: ConsensusStateType = consensus_state.try_into()?;
+
+        if sm_consensus_state != self.inner().consensus_state {
+            return Err(SolomachineClientError::MismatchedInitialConsensusState.into());
+        }
+
+        store_states(ctx, client_id, self.inner().clone())
+    }
+
+    /// Rotates the key of the solo machine to the one set by the header, and
+    /// advances its sequence.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = SmHeader::try_from(header)?;
+
+        let new_client_state = ClientStateType::new(
+            self.inner().sequence + 1,
+            false,
+            ConsensusStateType::new(
+                header.new_public_key,
+                header.new_diversifier,
+                header.timestamp,
+            ),
+        );
+        let new_height = new_client_state.latest_height();
+
+        store_states(ctx, client_id, new_client_state)?;
+
+        Ok(vec![new_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = self.inner().clone().with_frozen();
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            frozen_client_state.into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(SolomachineClientError::UpgradeNotSupported.into())
+    }
+
+    /// Replaces the subject client with the substitute client, unfrozen.
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
+        substitute_client_state: Any,
+        _substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+        let recovered_client_state = ClientStateType {
+            is_frozen: false,
+            ..substitute_client_state
+        };
+
+        store_states(ctx, subject_client_id, recovered_client_state)
+    }
+}
+
+/// Stores the client state along with its embedded consensus state at its
+/// latest height, and the update metadata at that height, so that the
+/// client can be used like any other.
+fn store_states<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    client_state: ClientStateType,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    let latest_height = client_state.latest_height();
+    let consensus_state = client_state.consensus_state.clone();
+
+    ctx.store_client_state(ClientStatePath::new(client_id.clone()), client_state.into())?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        consensus_state.into(),
+    )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        latest_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(())
+}
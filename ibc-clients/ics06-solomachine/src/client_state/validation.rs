@@ -0,0 +1,130 @@
+use ibc_client_solomachine_types::error::SolomachineClientError;
+use ibc_client_solomachine_types::{
+    ClientState as ClientStateType, Header as SmHeader, Misbehaviour as SmMisbehaviour,
+    SignatureAndData, SOLOMACHINE_HEADER_TYPE_URL, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::ClientState;
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext,
+{
+    /// Verifies a header or a misbehaviour, both of which must be signed by
+    /// the current key of the solo machine.
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => {
+                let header = SmHeader::try_from(client_message)?;
+                verify_header(self.inner(), &header)
+            }
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = SmMisbehaviour::try_from(client_message)?;
+                verify_misbehaviour(self.inner(), &misbehaviour)
+            }
+            _ => Err(ClientError::InvalidUpdateClientMessage),
+        }
+    }
+
+    /// Any verified misbehaviour is evidence of the solo machine having
+    /// signed conflicting data.
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => Ok(false),
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(true),
+            _ => Err(ClientError::InvalidUpdateClientMessage),
+        }
+    }
+
+    /// A solo machine client does not expire, so it is active until frozen.
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.inner().is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        Ok(Status::Active)
+    }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+        if self.inner().consensus_state.public_key
+            == substitute_client_state.consensus_state.public_key
+        {
+            return Err(SolomachineClientError::IdenticalSubstitutePublicKey.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Verifies that the header is signed by the current key of the solo machine,
+/// at its current sequence.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_header(client_state: &ClientStateType, header: &SmHeader) -> Result<(), ClientError> {
+    let consensus_state = &client_state.consensus_state;
+
+    if header.timestamp < consensus_state.timestamp {
+        return Err(SolomachineClientError::InsufficientProofTimestamp {
+            proof_timestamp: header.timestamp,
+            consensus_timestamp: consensus_state.timestamp,
+        }
+        .into());
+    }
+
+    let sign_bytes = header.sign_bytes(client_state.sequence, consensus_state.diversifier.clone());
+
+    consensus_state
+        .public_key
+        .verify_signature(&sign_bytes.encode_vec(), &header.signature)?;
+
+    Ok(())
+}
+
+/// Verifies that both signatures of the misbehaviour are by the current key
+/// of the solo machine, at the sequence of the misbehaviour.
+///
+/// Timestamps are not checked against the consensus state, so that past
+/// misbehaviour can still be submitted.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &SmMisbehaviour,
+) -> Result<(), ClientError> {
+    let verify = |signature_and_data: &SignatureAndData| -> Result<(), ClientError> {
+        let consensus_state = &client_state.consensus_state;
+        let sign_bytes = signature_and_data
+            .sign_bytes(misbehaviour.sequence, consensus_state.diversifier.clone());
+
+        consensus_state
+            .public_key
+            .verify_signature(&sign_bytes.encode_vec(), &signature_and_data.signature)?;
+
+        Ok(())
+    };
+
+    verify(&misbehaviour.signature_one)?;
+    verify(&misbehaviour.signature_two)
+}
@@ -0,0 +1,123 @@
+//! Defines Solo Machine's `ClientState` type
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+use crate::consensus_state::ConsensusState;
+use crate::error::SolomachineClientError;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+/// Contains the data used to verify the proofs of a Solo Machine.
+///
+/// The solo machine does not have a notion of block height: its `sequence`,
+/// which increases with every header it signs, is exposed as the revision
+/// height of the client's latest height, within revision `0`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, is_frozen: bool, consensus_state: ConsensusState) -> Self {
+        Self {
+            sequence,
+            is_frozen,
+            consensus_state,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), SolomachineClientError> {
+        if self.sequence == 0 {
+            return Err(SolomachineClientError::InvalidSequence);
+        }
+
+        self.consensus_state.validate()
+    }
+
+    /// Returns the height at which the solo machine is, i.e. its sequence.
+    ///
+    /// Panics if the sequence is zero, which [`ClientState::validate`] rules
+    /// out for decoded client states.
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("sequence is validated to be non-zero")
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_frozen
+    }
+
+    /// Freezes the client, after misbehaviour was detected.
+    pub fn with_frozen(self) -> Self {
+        Self {
+            is_frozen: true,
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or(DecodingError::missing_raw_data(
+                "client state consensus state",
+            ))?
+            .try_into()?;
+
+        let client_state = Self::new(raw.sequence, raw.is_frozen, consensus_state);
+
+        client_state
+            .validate()
+            .map_err(DecodingError::invalid_raw_data)?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOLOMACHINE_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
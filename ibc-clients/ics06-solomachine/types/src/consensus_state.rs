@@ -0,0 +1,111 @@
+//! Defines Solo Machine's `ConsensusState` type
+
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ConsensusState as RawConsensusState;
+use ibc_proto::Protobuf;
+
+use crate::error::SolomachineClientError;
+use crate::public_key::PublicKey;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+/// Defines the Solo Machine light client's consensus state, i.e. the key it
+/// currently signs with.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    /// Arbitrary string included in the signed bytes, so that signatures
+    /// produced for one solo machine client cannot be replayed on another
+    /// client sharing the same key.
+    pub diversifier: String,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: Timestamp) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), SolomachineClientError> {
+        if self.timestamp.nanoseconds() == 0 {
+            return Err(SolomachineClientError::InvalidTimestamp);
+        }
+
+        validate_diversifier(&self.diversifier)
+    }
+}
+
+/// A diversifier may be empty, but not made only of whitespaces.
+pub(crate) fn validate_diversifier(diversifier: &str) -> Result<(), SolomachineClientError> {
+    if !diversifier.is_empty() && diversifier.trim().is_empty() {
+        return Err(SolomachineClientError::InvalidDiversifier);
+    }
+
+    Ok(())
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw
+            .public_key
+            .ok_or(DecodingError::missing_raw_data(
+                "consensus state public key",
+            ))?
+            .try_into()?;
+
+        Ok(Self {
+            public_key,
+            diversifier: raw.diversifier,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOLOMACHINE_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Defines the Solo Machine light client's error type
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+/// The main error type for the Solo Machine light client
+#[derive(Debug, Display)]
+pub enum SolomachineClientError {
+    /// decoding error: {0}
+    Decoding(DecodingError),
+    /// invalid sequence; must be greater than 0
+    InvalidSequence,
+    /// invalid timestamp; must be greater than 0
+    InvalidTimestamp,
+    /// invalid diversifier; cannot contain only spaces
+    InvalidDiversifier,
+    /// missing signature
+    MissingSignature,
+    /// invalid signature length `{0}`
+    InvalidSignatureLength(usize),
+    /// unsupported signature: only single signatures are supported
+    UnsupportedSignature,
+    /// failed to verify signature
+    FailedToVerifySignature,
+    /// insufficient proof timestamp `{proof_timestamp}`; must be >= consensus state timestamp `{consensus_timestamp}`
+    InsufficientProofTimestamp {
+        proof_timestamp: Timestamp,
+        consensus_timestamp: Timestamp,
+    },
+    /// mismatched proof height: expected `{expected}`, actual `{actual}`
+    MismatchedProofHeight { expected: Height, actual: Height },
+    /// misbehaviour signatures cannot be equal
+    IdenticalMisbehaviourSignatures,
+    /// misbehaviour signed data cannot be equal
+    IdenticalMisbehaviourData,
+    /// mismatched initial consensus state: it must be the one embedded in the client state
+    MismatchedInitialConsensusState,
+    /// subject and substitute clients cannot share the same public key
+    IdenticalSubstitutePublicKey,
+    /// solo machine clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SolomachineClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Decoding(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<SolomachineClientError> for ClientError {
+    fn from(e: SolomachineClientError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodingError> for SolomachineClientError {
+    fn from(e: DecodingError) -> Self {
+        Self::Decoding(e)
+    }
+}
@@ -0,0 +1,124 @@
+//! Defines the header type with which the Solo Machine rotates its key
+
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    Header as RawHeader, HeaderData as RawHeaderData,
+};
+use ibc_proto::Protobuf;
+use prost::Message;
+
+use crate::consensus_state::validate_diversifier;
+use crate::error::SolomachineClientError;
+use crate::public_key::PublicKey;
+use crate::signature::{SignBytes, SENTINEL_HEADER_PATH};
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// A header signed by the solo machine with its current key, setting the
+/// public key and diversifier it signs with from then on.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub timestamp: Timestamp,
+    /// Encoded `SignatureDescriptor.Data` of the signature.
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    pub fn validate_basic(&self) -> Result<(), SolomachineClientError> {
+        if self.timestamp.nanoseconds() == 0 {
+            return Err(SolomachineClientError::InvalidTimestamp);
+        }
+
+        if self.signature.is_empty() {
+            return Err(SolomachineClientError::MissingSignature);
+        }
+
+        validate_diversifier(&self.new_diversifier)
+    }
+
+    /// Returns the bytes signed by the solo machine for this header, at the
+    /// given sequence and with its current diversifier.
+    pub fn sign_bytes(&self, sequence: u64, diversifier: String) -> SignBytes {
+        let header_data = RawHeaderData {
+            new_pub_key: Some(self.new_public_key.clone().into()),
+            new_diversifier: self.new_diversifier.clone(),
+        };
+
+        SignBytes {
+            sequence,
+            timestamp: self.timestamp,
+            diversifier,
+            path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+            data: header_data.encode_to_vec(),
+        }
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        let new_public_key = raw
+            .new_public_key
+            .ok_or(DecodingError::missing_raw_data("header new public key"))?
+            .try_into()?;
+
+        let header = Self {
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            signature: raw.signature,
+            new_public_key,
+            new_diversifier: raw.new_diversifier,
+        };
+
+        header
+            .validate_basic()
+            .map_err(DecodingError::invalid_raw_data)?;
+
+        Ok(header)
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOLOMACHINE_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! ICS-06: Solo Machine Client implements a client verification algorithm for
+//! standalone machines, such as phones or off-chain services, which hold a key
+//! and sign the state they commit to.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::ClientType;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+mod public_key;
+mod signature;
+
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+pub use public_key::*;
+pub use signature::*;
+
+pub mod error;
+
+/// Re-exports ICS-06 Solo Machine light client from `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::lightclients::solomachine::*;
+}
+
+pub const SOLOMACHINE_CLIENT_TYPE: &str = "06-solomachine";
+
+/// Returns the solo machine `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(SOLOMACHINE_CLIENT_TYPE).expect("Never fails because it's valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the solo machine client type
+    #[test]
+    pub fn test_solomachine_client_type() {
+        let _ = ClientType::from_str(SOLOMACHINE_CLIENT_TYPE).unwrap();
+    }
+}
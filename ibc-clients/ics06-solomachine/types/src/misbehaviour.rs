@@ -0,0 +1,109 @@
+//! Defines the misbehaviour type for the Solo Machine light client
+
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::Misbehaviour as RawMisbehaviour;
+use ibc_proto::Protobuf;
+
+use crate::error::SolomachineClientError;
+use crate::signature::SignatureAndData;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// Evidence that the solo machine signed two different pieces of data at the
+/// same sequence.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl Misbehaviour {
+    pub fn validate_basic(&self) -> Result<(), SolomachineClientError> {
+        if self.sequence == 0 {
+            return Err(SolomachineClientError::InvalidSequence);
+        }
+
+        if self.signature_one.signature == self.signature_two.signature {
+            return Err(SolomachineClientError::IdenticalMisbehaviourSignatures);
+        }
+
+        if self.signature_one.data == self.signature_two.data {
+            return Err(SolomachineClientError::IdenticalMisbehaviourData);
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        let signature_one = raw
+            .signature_one
+            .ok_or(DecodingError::missing_raw_data(
+                "misbehaviour signature one",
+            ))?
+            .try_into()?;
+        let signature_two = raw
+            .signature_two
+            .ok_or(DecodingError::missing_raw_data(
+                "misbehaviour signature two",
+            ))?
+            .try_into()?;
+
+        let misbehaviour = Self {
+            sequence: raw.sequence,
+            signature_one,
+            signature_two,
+        };
+
+        misbehaviour
+            .validate_basic()
+            .map_err(DecodingError::invalid_raw_data)?;
+
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOLOMACHINE_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
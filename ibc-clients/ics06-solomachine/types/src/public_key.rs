@@ -0,0 +1,128 @@
+//! Defines the public key type with which the Solo Machine signs its proofs
+
+use ed25519_consensus::{Signature as Ed25519Signature, VerificationKey as Ed25519PublicKey};
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_proto::cosmos::crypto::ed25519::PubKey as RawEd25519PubKey;
+use ibc_proto::cosmos::crypto::secp256k1::PubKey as RawSecp256k1PubKey;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::Sum;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use k256::ecdsa::signature::Verifier;
+use k256::ecdsa::{Signature as Secp256k1Signature, VerifyingKey as Secp256k1PublicKey};
+use prost::Message;
+
+use crate::error::SolomachineClientError;
+
+pub const SECP256K1_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+pub const ED25519_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+/// Public key of the Solo Machine, encoded on the wire as the `Any` of a
+/// Cosmos SDK public key.
+///
+/// Multisig public keys are not supported.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Any", into = "Any")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublicKey {
+    Secp256k1(Secp256k1PublicKey),
+    Ed25519(Ed25519PublicKey),
+}
+
+impl PublicKey {
+    /// Verifies the signature encoded in `signature_data`, a protobuf
+    /// `SignatureDescriptor.Data`, over `msg`.
+    ///
+    /// As in the Cosmos SDK, secp256k1 signatures are ECDSA signatures over
+    /// the SHA-256 digest of the message, serialized as `r || s` with a low
+    /// `s`, while ed25519 signatures are over the message itself.
+    pub fn verify_signature(
+        &self,
+        msg: &[u8],
+        signature_data: &[u8],
+    ) -> Result<(), SolomachineClientError> {
+        let signature = single_signature(signature_data)?;
+
+        match self {
+            Self::Secp256k1(public_key) => {
+                let signature = Secp256k1Signature::from_slice(&signature)
+                    .map_err(|_| SolomachineClientError::InvalidSignatureLength(signature.len()))?;
+
+                public_key
+                    .verify(msg, &signature)
+                    .map_err(|_| SolomachineClientError::FailedToVerifySignature)
+            }
+            Self::Ed25519(public_key) => {
+                let signature = Ed25519Signature::try_from(signature.as_slice())
+                    .map_err(|_| SolomachineClientError::InvalidSignatureLength(signature.len()))?;
+
+                public_key
+                    .verify(&signature, msg)
+                    .map_err(|_| SolomachineClientError::FailedToVerifySignature)
+            }
+        }
+    }
+}
+
+/// Extracts the signature out of an encoded `SignatureDescriptor.Data`,
+/// rejecting multi-signatures.
+fn single_signature(signature_data: &[u8]) -> Result<Vec<u8>, SolomachineClientError> {
+    let raw = RawSignatureData::decode(signature_data).map_err(DecodingError::Prost)?;
+
+    match raw.sum {
+        Some(Sum::Single(single)) => Ok(single.signature),
+        Some(Sum::Multi(_)) => Err(SolomachineClientError::UnsupportedSignature),
+        None => Err(SolomachineClientError::MissingSignature),
+    }
+}
+
+impl Protobuf<Any> for PublicKey {}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SECP256K1_PUBLIC_KEY_TYPE_URL => {
+                let raw_key = RawSecp256k1PubKey::decode(raw.value.as_ref())?;
+
+                Secp256k1PublicKey::from_sec1_bytes(&raw_key.key)
+                    .map(Self::Secp256k1)
+                    .map_err(|_| DecodingError::invalid_raw_data("secp256k1 public key"))
+            }
+            ED25519_PUBLIC_KEY_TYPE_URL => {
+                let raw_key = RawEd25519PubKey::decode(raw.value.as_ref())?;
+
+                Ed25519PublicKey::try_from(raw_key.key.as_slice())
+                    .map(Self::Ed25519)
+                    .map_err(|_| DecodingError::invalid_raw_data("ed25519 public key"))
+            }
+            _ => Err(DecodingError::UnknownTypeUrl(raw.type_url)),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(public_key: PublicKey) -> Self {
+        match public_key {
+            PublicKey::Secp256k1(public_key) => Any {
+                type_url: SECP256K1_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: RawSecp256k1PubKey {
+                    key: public_key.to_encoded_point(true).as_bytes().to_vec(),
+                }
+                .encode_to_vec(),
+            },
+            PublicKey::Ed25519(public_key) => Any {
+                type_url: ED25519_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: RawEd25519PubKey {
+                    key: public_key.to_bytes().to_vec(),
+                }
+                .encode_to_vec(),
+            },
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Defines the data signed by the Solo Machine, and the formats in which its
+//! signatures are submitted.
+
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    SignBytes as RawSignBytes, SignatureAndData as RawSignatureAndData,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use ibc_proto::Protobuf;
+use prost::encoding::{encode_key, encode_varint, WireType};
+
+/// Path under which the solo machine signs the data of a new header.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// The bytes a solo machine signs, whether to prove a value under a path of
+/// its store, to update its key, or to commit to a statement used as
+/// evidence of misbehaviour.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignBytes {
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    pub diversifier: String,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl Protobuf<RawSignBytes> for SignBytes {}
+
+impl TryFrom<RawSignBytes> for SignBytes {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawSignBytes) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            diversifier: raw.diversifier,
+            path: raw.path,
+            data: raw.data,
+        })
+    }
+}
+
+impl From<SignBytes> for RawSignBytes {
+    fn from(value: SignBytes) -> Self {
+        Self {
+            sequence: value.sequence,
+            timestamp: value.timestamp.nanoseconds(),
+            diversifier: value.diversifier,
+            path: value.path,
+            data: value.data,
+        }
+    }
+}
+
+/// The proof format of the solo machine: a signature over the `SignBytes`
+/// of a value, along with the timestamp it was signed at.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    /// Encoded `SignatureDescriptor.Data` of the signature.
+    pub signature_data: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl Protobuf<RawTimestampedSignatureData> for TimestampedSignatureData {}
+
+impl TryFrom<RawTimestampedSignatureData> for TimestampedSignatureData {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawTimestampedSignatureData) -> Result<Self, Self::Error> {
+        if raw.signature_data.is_empty() {
+            return Err(DecodingError::missing_raw_data("proof signature data"));
+        }
+
+        if raw.timestamp == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "proof timestamp must be greater than 0",
+            ));
+        }
+
+        Ok(Self {
+            signature_data: raw.signature_data,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+        })
+    }
+}
+
+impl From<TimestampedSignatureData> for RawTimestampedSignatureData {
+    fn from(value: TimestampedSignatureData) -> Self {
+        Self {
+            signature_data: value.signature_data,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// A signature over some data under a path, as found in a misbehaviour.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureAndData {
+    /// Encoded `SignatureDescriptor.Data` of the signature.
+    pub signature: Vec<u8>,
+    /// Encoded `MerklePath` of the signed data.
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl SignatureAndData {
+    /// Returns the bytes signed by the solo machine, at the given sequence
+    /// and with the given diversifier.
+    pub fn sign_bytes(&self, sequence: u64, diversifier: String) -> SignBytes {
+        SignBytes {
+            sequence,
+            timestamp: self.timestamp,
+            diversifier,
+            path: self.path.clone(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl Protobuf<RawSignatureAndData> for SignatureAndData {}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        if raw.signature.is_empty() {
+            return Err(DecodingError::missing_raw_data("signature"));
+        }
+
+        if raw.path.is_empty() {
+            return Err(DecodingError::missing_raw_data("signature path"));
+        }
+
+        if raw.data.is_empty() {
+            return Err(DecodingError::missing_raw_data("signature data"));
+        }
+
+        if raw.timestamp == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "signature timestamp must be greater than 0",
+            ));
+        }
+
+        Ok(Self {
+            signature: raw.signature,
+            path: raw.path,
+            data: raw.data,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+        })
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        Self {
+            signature: value.signature,
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// Encodes the key path of a `MerklePath`, as included in `SignBytes`.
+///
+/// Keys are encoded as raw bytes, which is wire-compatible with the `string`
+/// keys of the `ibc.core.commitment.v1.MerklePath` message while accepting
+/// non-UTF-8 commitment prefixes.
+pub fn encode_merkle_path(key_path: &[&[u8]]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for key in key_path {
+        encode_key(1, WireType::LengthDelimited, &mut buf);
+        encode_varint(key.len() as u64, &mut buf);
+        buf.extend_from_slice(key);
+    }
+    buf
+}
@@ -0,0 +1,18 @@
+//! Plugs the Tendermint light client into the CosmWasm context.
+
+use ibc_client_cw::api::ClientType;
+use ibc_client_cw::context::Context;
+use ibc_client_tendermint::client_state::ClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState;
+
+/// Selects the Tendermint client and consensus state types.
+#[derive(Clone, Debug)]
+pub struct TendermintClient;
+
+impl<'a> ClientType<'a> for TendermintClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
+
+/// Context of the Tendermint light client contract.
+pub type TendermintContext<'a> = Context<'a, TendermintClient>;
@@ -0,0 +1,39 @@
+//! Defines the entry points of the Tendermint light client contract.
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use ibc_client_cw::error::ContractError;
+use ibc_client_cw::types::{InstantiateMsg, QueryMsg, SudoMsg};
+
+use crate::client_type::TendermintContext;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<'_>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = TendermintContext::new_mut(deps, env)?;
+
+    let data = ctx.instantiate(msg)?;
+
+    Ok(Response::default().set_data(data))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    let mut ctx = TendermintContext::new_mut(deps, env)?;
+
+    let data = ctx.sudo(msg)?;
+
+    Ok(Response::default().set_data(data))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let ctx = TendermintContext::new_ref(deps, env)?;
+
+    ctx.query(msg)
+}
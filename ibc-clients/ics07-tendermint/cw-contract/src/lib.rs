@@ -0,0 +1,18 @@
+//! ICS-07 Tendermint light client packaged as a CosmWasm contract, to be run
+//! by hosts supporting ICS-08 Wasm light clients.
+//!
+//! Unless the `library` feature is enabled, the contract entry points are
+//! exported when compiling to Wasm.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+pub mod client_type;
+pub mod entrypoint;
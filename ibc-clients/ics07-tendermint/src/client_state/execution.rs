@@ -66,6 +66,7 @@ where
         &self,
         ctx: &mut E,
         subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
         substitute_client_state: Any,
         substitute_consensus_state: Any,
     ) -> Result<(), ClientError> {
@@ -77,6 +77,15 @@ where
     fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
         check_substitute::<V>(self.inner(), substitute_client_state)
     }
+
+    fn is_redundant_update(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        is_redundant_update(ctx, client_id, header)
+    }
 }
 
 /// Verify the client message as part of the client state validation process.
@@ -184,6 +193,48 @@ where
     }
 }
 
+/// Check whether a header would be a no-op update of the client.
+///
+/// This mirrors the no-op case of [`update_state`](crate::client_state::update_state):
+/// the update is redundant when a consensus state is already stored at the
+/// header's height. A stored consensus state that differs from the header is
+/// evidence of misbehaviour, so it is not reported as redundant.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn is_redundant_update<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    if header.type_url != TENDERMINT_HEADER_TYPE_URL {
+        return Ok(false);
+    }
+
+    let header = TmHeader::try_from(header)?;
+    let header_height = header.height();
+
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+
+    let existing_consensus_state: ConsensusStateType =
+        match ctx.consensus_state(&path_at_header_height) {
+            Ok(cs) => cs.try_into().map_err(Into::into)?,
+            Err(_) => return Ok(false),
+        };
+
+    Ok(existing_consensus_state == ConsensusStateType::from(header))
+}
+
 /// Query the status of the client state.
 ///
 /// Note that this function is typically implemented as part of the
@@ -0,0 +1,106 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Every light client operation is carried out by the contract identified by
+//! the client state's checksum, through the host's
+//! [`WasmEngine`](crate::engine::WasmEngine). As with the Tendermint client,
+//! this crate defines a newtype wrapper around the client state type in order
+//! to implement foreign traits on it.
+
+use core::fmt::Display;
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::proto::v1::ClientState as RawClientState;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::context::WasmClientValidationContext;
+use crate::engine::{ClientStore, WasmEngine};
+
+mod common;
+mod execution;
+mod validation;
+
+/// Newtype wrapper around the `ClientState` type, imported from the
+/// `ibc-client-wasm-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ClientState` type.
+#[derive(Clone, Debug, PartialEq, derive_more::From)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+
+    pub fn checksum(&self) -> &[u8] {
+        &self.0.checksum
+    }
+
+    /// Sends a [`QueryMsg`](crate::types::contract::QueryMsg) to the client's
+    /// contract and decodes its response.
+    fn query<V, M, R>(&self, ctx: &V, store: ClientStore<'_>, msg: &M) -> Result<R, ClientError>
+    where
+        V: WasmClientValidationContext,
+        M: Serialize,
+        R: DeserializeOwned,
+    {
+        let msg = serde_json::to_vec(msg).map_err(contract_error)?;
+        let response = ctx.wasm_engine().query(self.checksum(), store, &msg)?;
+        serde_json::from_slice(&response).map_err(contract_error)
+    }
+
+    /// Sends a [`SudoMsg`](crate::types::contract::SudoMsg) to the client's
+    /// contract and returns its raw response.
+    fn sudo<V, M>(&self, ctx: &V, store: ClientStore<'_>, msg: &M) -> Result<Vec<u8>, ClientError>
+    where
+        V: WasmClientValidationContext,
+        M: Serialize,
+    {
+        let msg = serde_json::to_vec(msg).map_err(contract_error)?;
+        Ok(ctx.wasm_engine().sudo(self.checksum(), store, &msg)?)
+    }
+}
+
+fn contract_error<E: Display>(e: E) -> ClientError {
+    ClientError::ClientSpecific {
+        description: format!("Wasm contract call failed: {e}"),
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
@@ -0,0 +1,105 @@
+use ibc_client_wasm_types::client_type as wasm_client_type;
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::{ClientError, UpgradeClientError};
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::error::DecodingError;
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::{Path, PathBytes};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+
+use super::ClientState;
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        _host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusStateType::try_from(consensus_state)?;
+
+        if consensus_state.data.is_empty() {
+            return Err(DecodingError::missing_raw_data("Wasm consensus state data").into());
+        }
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        wasm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        let latest_height = self.latest_height();
+
+        if latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Upgrades are not supported: contracts verify and apply an upgrade in a
+    /// single call, which does not fit the split between
+    /// `verify_upgrade_client` and `update_state_on_upgrade`.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(UpgradeClientError::InvalidUpgradeProposal {
+            description: "Wasm clients do not support upgrades".to_string(),
+        }
+        .into())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Proofs can only be verified by the contract, which needs to know the
+    /// client and proof height. See
+    /// [`ClientStateValidation::verify_membership_at_height`](ibc_core_client::context::client_state::ClientStateValidation::verify_membership_at_height).
+    fn verify_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(unsupported_root_verification())
+    }
+
+    /// See [`ClientState::verify_membership_raw`].
+    fn verify_non_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+    ) -> Result<(), ClientError> {
+        Err(unsupported_root_verification())
+    }
+}
+
+fn unsupported_root_verification() -> ClientError {
+    ClientError::ClientSpecific {
+        description: "Wasm clients cannot verify proofs against a commitment root".to_string(),
+    }
+}
@@ -0,0 +1,161 @@
+use ibc_client_wasm_types::client_message::ClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::contract::{
+    InstantiateMsg, MigrateClientStoreMsg, SudoMsg, UpdateStateMsg, UpdateStateOnMisbehaviourMsg,
+    UpdateStateResult,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::{ClientError, UpgradeClientError};
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::{contract_error, ClientState};
+use crate::context::WasmClientExecutionContext;
+use crate::engine::{ClientStore, WasmEngine};
+
+/// The contract is responsible for writing client and consensus states to
+/// its client store. The host only records when each consensus state was
+/// processed, which the core handlers need to enforce connection delays.
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext + WasmClientExecutionContext,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusStateType::try_from(consensus_state)?;
+
+        if !ctx.checksum_exists(self.checksum())? {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "missing Wasm code with checksum `{}`",
+                    hex::encode(self.checksum())
+                ),
+            });
+        }
+
+        let msg = serde_json::to_vec(&InstantiateMsg {
+            client_state: self.0.data.clone(),
+            consensus_state: consensus_state.data,
+            checksum: self.0.checksum.clone(),
+        })
+        .map_err(contract_error)?;
+
+        ctx.wasm_engine()
+            .instantiate(self.checksum(), ClientStore::Client(client_id), &msg)?;
+
+        store_update_meta(ctx, client_id, self.latest_height())
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let client_message = ClientMessage::try_from(header)?;
+
+        let response = self.sudo(
+            ctx,
+            ClientStore::Client(client_id),
+            &SudoMsg::UpdateState(UpdateStateMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+        let result: UpdateStateResult =
+            serde_json::from_slice(&response).map_err(contract_error)?;
+
+        if result.heights.is_empty() {
+            return Err(contract_error("no consensus height was updated"));
+        }
+
+        for height in &result.heights {
+            store_update_meta(ctx, client_id, *height)?;
+        }
+
+        Ok(result.heights)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        let client_message = ClientMessage::try_from(client_message)?;
+
+        self.sudo(
+            ctx,
+            ClientStore::Client(client_id),
+            &SudoMsg::UpdateStateOnMisbehaviour(UpdateStateOnMisbehaviourMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(UpgradeClientError::InvalidUpgradeProposal {
+            description: "Wasm clients do not support upgrades".to_string(),
+        }
+        .into())
+    }
+
+    /// Lets the contract migrate the substitute's state into the subject's
+    /// store, through a store combining both under the subject and
+    /// substitute prefixes.
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Any,
+        _substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+        self.sudo(
+            ctx,
+            ClientStore::Recovery {
+                subject: subject_client_id,
+                substitute: substitute_client_id,
+            },
+            &SudoMsg::MigrateClientStore(MigrateClientStoreMsg {}),
+        )?;
+
+        store_update_meta(
+            ctx,
+            subject_client_id,
+            substitute_client_state.latest_height,
+        )
+    }
+}
+
+fn store_update_meta<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+{
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    ctx.store_update_meta(client_id.clone(), height, host_timestamp, host_height)?;
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use ibc_client_wasm_types::client_message::ClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::contract::{
+    CheckForMisbehaviourMsg, CheckForMisbehaviourResult, MerklePath, QueryMsg, StatusMsg,
+    StatusResult, SudoMsg, TimestampAtHeightMsg, TimestampAtHeightResult, VerifyClientMessageMsg,
+    VerifyMembershipMsg, VerifyNonMembershipMsg,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status};
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, Path, PathBytes};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+use serde::de::IgnoredAny;
+
+use super::ClientState;
+use crate::context::WasmClientValidationContext;
+use crate::engine::ClientStore;
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext + WasmClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        let client_message = ClientMessage::try_from(client_message)?;
+
+        let _: IgnoredAny = self.query(
+            ctx,
+            ClientStore::Client(client_id),
+            &QueryMsg::VerifyClientMessage(VerifyClientMessageMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        let client_message = ClientMessage::try_from(client_message)?;
+
+        let result: CheckForMisbehaviourResult = self.query(
+            ctx,
+            ClientStore::Client(client_id),
+            &QueryMsg::CheckForMisbehaviour(CheckForMisbehaviourMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        Ok(result.found_misbehaviour)
+    }
+
+    /// Clients whose contract code has been removed from the checksum
+    /// registry are reported as unauthorized.
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if !ctx.checksum_exists(self.checksum())? {
+            return Ok(Status::Unauthorized);
+        }
+
+        let result: StatusResult = self.query(
+            ctx,
+            ClientStore::Client(client_id),
+            &QueryMsg::Status(StatusMsg {}),
+        )?;
+
+        Ok(result.status)
+    }
+
+    /// The substitute must run the same contract as the subject. Any further
+    /// checks are left to the contract when migrating the client store.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+        (self.checksum() == substitute_client_state.checksum.as_slice())
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+
+    fn timestamp_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+    ) -> Result<Timestamp, ClientError> {
+        let result: TimestampAtHeightResult = self.query(
+            ctx,
+            ClientStore::Client(&consensus_state_path.client_id),
+            &QueryMsg::TimestampAtHeight(TimestampAtHeightMsg {
+                height: consensus_height(consensus_state_path)?,
+            }),
+        )?;
+
+        Ok(Timestamp::from_nanoseconds(result.timestamp))
+    }
+
+    fn verify_membership_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let path_bytes = self.serialize_path(path)?;
+        self.verify_membership_raw_at_height(
+            ctx,
+            consensus_state_path,
+            prefix,
+            proof,
+            path_bytes,
+            value,
+        )
+    }
+
+    fn verify_non_membership_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        let path_bytes = self.serialize_path(path)?;
+        self.verify_non_membership_raw_at_height(
+            ctx,
+            consensus_state_path,
+            prefix,
+            proof,
+            path_bytes,
+        )
+    }
+
+    /// Delay periods are passed as zero, since connection delays are
+    /// enforced by the core handlers.
+    fn verify_membership_raw_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let msg = SudoMsg::VerifyMembership(VerifyMembershipMsg {
+            height: consensus_height(consensus_state_path)?,
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: proof.clone().into(),
+            merkle_path: merkle_path(prefix, path),
+            value,
+        });
+
+        self.sudo(
+            ctx,
+            ClientStore::Client(&consensus_state_path.client_id),
+            &msg,
+        )?;
+
+        Ok(())
+    }
+
+    fn verify_non_membership_raw_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        let msg = SudoMsg::VerifyNonMembership(VerifyNonMembershipMsg {
+            height: consensus_height(consensus_state_path)?,
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: proof.clone().into(),
+            merkle_path: merkle_path(prefix, path),
+        });
+
+        self.sudo(
+            ctx,
+            ClientStore::Client(&consensus_state_path.client_id),
+            &msg,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Builds the key path handed to the contract: the counterparty's commitment
+/// prefix followed by the serialized path.
+fn merkle_path(prefix: &CommitmentPrefix, path: PathBytes) -> MerklePath {
+    MerklePath {
+        key_path: vec![prefix.as_bytes().to_vec(), path.into_vec()],
+    }
+}
+
+fn consensus_height(
+    consensus_state_path: &ClientConsensusStatePath,
+) -> Result<Height, ClientError> {
+    Height::new(
+        consensus_state_path.revision_number,
+        consensus_state_path.revision_height,
+    )
+}
@@ -0,0 +1,99 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::ConsensusState` type.
+//!
+//! The consensus state of a Wasm client wraps the opaque consensus state of
+//! the contract, which the host cannot interpret. Proofs are therefore
+//! verified, and consensus state timestamps resolved, by the contract through
+//! the client state (see
+//! [`ClientStateValidation`](ibc_core_client::context::client_state::ClientStateValidation)).
+//! Accordingly, [`root`](ConsensusStateTrait::root) is empty and
+//! [`timestamp`](ConsensusStateTrait::timestamp) returns an error.
+
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::proto::v1::ConsensusState as RawConsensusState;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_host::types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// Newtype wrapper around the `ConsensusState` type imported from the
+/// `ibc-client-wasm-types` crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    inner: ConsensusStateType,
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn inner(&self) -> &ConsensusStateType {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> ConsensusStateType {
+        self.inner
+    }
+}
+
+impl From<ConsensusStateType> for ConsensusState {
+    fn from(inner: ConsensusStateType) -> Self {
+        Self {
+            inner,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+}
+
+impl From<ConsensusState> for ConsensusStateType {
+    fn from(value: ConsensusState) -> Self {
+        value.inner
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        Ok(ConsensusStateType::try_from(raw)?.into())
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner.into()
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(ConsensusStateType::try_from(raw)?.into())
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner.into()
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description:
+                "the timestamp of a Wasm consensus state must be queried from its contract"
+                    .to_string(),
+        })
+    }
+}
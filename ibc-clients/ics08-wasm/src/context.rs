@@ -0,0 +1,35 @@
+//! Defines the host context required by the Wasm client.
+
+use ibc_core_host::types::error::HostError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::engine::WasmEngine;
+
+/// Methods the host must provide for the Wasm client to run contracts and
+/// validate governance messages.
+pub trait WasmClientValidationContext {
+    type Engine: WasmEngine;
+
+    /// Returns the engine contracts are run with.
+    fn wasm_engine(&self) -> &Self::Engine;
+
+    /// Returns whether the checksum registry contains `checksum`.
+    fn checksum_exists(&self, checksum: &[u8]) -> Result<bool, HostError>;
+
+    /// Returns all the checksums in the registry.
+    fn checksums(&self) -> Result<Vec<Vec<u8>>, HostError>;
+
+    /// Returns the signer allowed to store and remove Wasm code and to
+    /// migrate contracts, typically the governance module account.
+    fn wasm_authority(&self) -> Result<Signer, HostError>;
+}
+
+/// Methods the host must provide to update the checksum registry.
+pub trait WasmClientExecutionContext: WasmClientValidationContext {
+    /// Adds `checksum` to the registry.
+    fn store_checksum(&mut self, checksum: Vec<u8>) -> Result<(), HostError>;
+
+    /// Removes `checksum` from the registry.
+    fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), HostError>;
+}
@@ -0,0 +1,98 @@
+//! Defines the interface through which the Wasm client runs light client
+//! contracts.
+
+use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+use ibc_core_host::types::error::HostError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+
+/// The client store a contract call is given access to.
+///
+/// A contract reads and writes its state through a key-value store that the
+/// engine maps onto the host store, under the `clients/{client_id}/` prefix of
+/// the relevant client. This is also where the contract is expected to write
+/// the (Wasm-wrapped) client and consensus states, so that they can be read
+/// back through the host's client context.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClientStore<'a> {
+    /// The store of a single client.
+    Client(&'a ClientId),
+    /// The combined store used when recovering a client: keys prefixed with
+    /// [`SUBJECT_PREFIX`] belong to the subject client's store, and keys
+    /// prefixed with [`SUBSTITUTE_PREFIX`] to the substitute client's store.
+    Recovery {
+        subject: &'a ClientId,
+        substitute: &'a ClientId,
+    },
+}
+
+impl<'a> ClientStore<'a> {
+    /// Resolves a key used by the contract to the client whose store it
+    /// belongs to, along with the key within that store.
+    ///
+    /// Returns `None` for keys of a recovery store that carry neither the
+    /// subject nor the substitute prefix, which contracts must not access.
+    pub fn resolve<'k>(&self, key: &'k [u8]) -> Option<(&'a ClientId, &'k [u8])> {
+        match *self {
+            Self::Client(client_id) => Some((client_id, key)),
+            Self::Recovery {
+                subject,
+                substitute,
+            } => {
+                if let Some(key) = key.strip_prefix(SUBJECT_PREFIX) {
+                    Some((subject, key))
+                } else {
+                    key.strip_prefix(SUBSTITUTE_PREFIX)
+                        .map(|key| (substitute, key))
+                }
+            }
+        }
+    }
+}
+
+/// A Wasm virtual machine, as seen by the Wasm client.
+///
+/// Contracts are identified by the checksum of their code, and messages are
+/// the JSON-encoded types found under [`contract`](crate::types::contract).
+/// Read-only calls go through [`WasmEngine::query`], while calls allowed to
+/// modify the client store go through [`WasmEngine::sudo`].
+///
+/// All methods take `&self`: engines are typically handles to a VM that shares
+/// the host store, and are invoked from both validation and execution
+/// contexts.
+pub trait WasmEngine {
+    /// Compiles and stores the given code under `checksum`.
+    fn store_code(&self, checksum: &[u8], code: &[u8]) -> Result<(), HostError>;
+
+    /// Removes the code stored under `checksum`.
+    fn remove_code(&self, checksum: &[u8]) -> Result<(), HostError>;
+
+    /// Instantiates the contract for a newly created client.
+    fn instantiate(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<(), HostError>;
+
+    /// Runs a read-only call into the contract and returns its response.
+    fn query(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, HostError>;
+
+    /// Runs a call into the contract that may write to the client store, and
+    /// returns its response.
+    fn sudo(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, HostError>;
+
+    /// Migrates the client's contract to the code stored under `checksum`.
+    fn migrate(&self, checksum: &[u8], store: ClientStore<'_>, msg: &[u8])
+        -> Result<(), HostError>;
+}
@@ -0,0 +1,62 @@
+//! Defines the error type of the Wasm client's governance handlers.
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::error::{DecodingError, HostError};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+/// Errors raised while handling the Wasm client's governance messages.
+#[derive(Debug, Display)]
+pub enum WasmClientError {
+    /// client error: {0}
+    Client(ClientError),
+    /// host error: {0}
+    Host(HostError),
+    /// decoding error: {0}
+    Decoding(DecodingError),
+    /// unauthorized signer `{signer}`; expected authority `{authority}`
+    UnauthorizedSigner { signer: Signer, authority: Signer },
+    /// empty Wasm byte code
+    EmptyWasmCode,
+    /// Wasm code with checksum `{0}` is already stored
+    DuplicateChecksum(String),
+    /// missing Wasm code with checksum `{0}`
+    MissingChecksum(String),
+    /// client `{client_id}` already uses the contract with checksum `{checksum}`
+    UnchangedChecksum {
+        client_id: ClientId,
+        checksum: String,
+    },
+}
+
+impl From<ClientError> for WasmClientError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+impl From<HostError> for WasmClientError {
+    fn from(e: HostError) -> Self {
+        Self::Host(e)
+    }
+}
+
+impl From<DecodingError> for WasmClientError {
+    fn from(e: DecodingError) -> Self {
+        Self::Decoding(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WasmClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Client(e) => Some(e),
+            Self::Host(e) => Some(e),
+            Self::Decoding(e) => Some(e),
+            _ => None,
+        }
+    }
+}
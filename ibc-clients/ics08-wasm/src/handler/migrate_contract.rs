@@ -0,0 +1,72 @@
+//! Protocol logic specific to processing a `MsgMigrateContract` message.
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::msgs::migrate_contract::MsgMigrateContract;
+use ibc_core_client::context::prelude::*;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ensure_authority;
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use crate::engine::{ClientStore, WasmEngine};
+use crate::error::WasmClientError;
+
+pub fn migrate_contract_validate<Ctx>(
+    ctx: &Ctx,
+    msg: &MsgMigrateContract,
+) -> Result<(), WasmClientError>
+where
+    Ctx: ClientValidationContext + WasmClientValidationContext,
+{
+    ensure_authority(ctx, &msg.signer)?;
+
+    if !ctx.checksum_exists(&msg.checksum)? {
+        return Err(WasmClientError::MissingChecksum(hex::encode(&msg.checksum)));
+    }
+
+    let client_state = wasm_client_state(ctx, &msg.client_id)?;
+
+    if client_state.checksum == msg.checksum {
+        return Err(WasmClientError::UnchangedChecksum {
+            client_id: msg.client_id.clone(),
+            checksum: hex::encode(&msg.checksum),
+        });
+    }
+
+    Ok(())
+}
+
+/// Migrates the client's contract to the new code, then points the client
+/// state, as possibly rewritten by the migration, to the new checksum.
+pub fn migrate_contract_execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgMigrateContract,
+) -> Result<(), WasmClientError>
+where
+    Ctx: ClientExecutionContext + WasmClientExecutionContext,
+    Ctx::ClientStateRef: From<ClientStateType>,
+{
+    ctx.wasm_engine()
+        .migrate(&msg.checksum, ClientStore::Client(&msg.client_id), &msg.msg)?;
+
+    let mut client_state = wasm_client_state(ctx, &msg.client_id)?;
+    client_state.checksum = msg.checksum;
+
+    ctx.store_client_state(ClientStatePath::new(msg.client_id), client_state.into())?;
+
+    Ok(())
+}
+
+fn wasm_client_state<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+) -> Result<ClientStateType, WasmClientError>
+where
+    Ctx: ClientValidationContext,
+{
+    let client_state: Any = ctx.client_state(client_id)?.into();
+
+    Ok(ClientStateType::try_from(client_state)?)
+}
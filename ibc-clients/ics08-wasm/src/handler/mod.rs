@@ -0,0 +1,27 @@
+//! Implements the handlers of the Wasm client's governance messages, which
+//! manage the checksum registry and the contracts run by Wasm clients.
+
+pub mod migrate_contract;
+pub mod remove_checksum;
+pub mod store_code;
+
+use ibc_primitives::Signer;
+
+use crate::context::WasmClientValidationContext;
+use crate::error::WasmClientError;
+
+pub(crate) fn ensure_authority<Ctx>(ctx: &Ctx, signer: &Signer) -> Result<(), WasmClientError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    let authority = ctx.wasm_authority()?;
+
+    if signer != &authority {
+        return Err(WasmClientError::UnauthorizedSigner {
+            signer: signer.clone(),
+            authority,
+        });
+    }
+
+    Ok(())
+}
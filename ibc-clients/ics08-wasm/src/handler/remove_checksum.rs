@@ -0,0 +1,39 @@
+//! Protocol logic specific to processing a `MsgRemoveChecksum` message.
+
+use ibc_client_wasm_types::msgs::remove_checksum::MsgRemoveChecksum;
+
+use super::ensure_authority;
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use crate::engine::WasmEngine;
+use crate::error::WasmClientError;
+
+pub fn remove_checksum_validate<Ctx>(
+    ctx: &Ctx,
+    msg: &MsgRemoveChecksum,
+) -> Result<(), WasmClientError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    ensure_authority(ctx, &msg.signer)?;
+
+    if !ctx.checksum_exists(&msg.checksum)? {
+        return Err(WasmClientError::MissingChecksum(hex::encode(&msg.checksum)));
+    }
+
+    Ok(())
+}
+
+/// Removes the code from the engine and its checksum from the registry.
+/// Clients still running the removed code become unauthorized.
+pub fn remove_checksum_execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgRemoveChecksum,
+) -> Result<(), WasmClientError>
+where
+    Ctx: WasmClientExecutionContext,
+{
+    ctx.wasm_engine().remove_code(&msg.checksum)?;
+    ctx.delete_checksum(&msg.checksum)?;
+
+    Ok(())
+}
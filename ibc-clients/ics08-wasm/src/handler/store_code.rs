@@ -0,0 +1,50 @@
+//! Protocol logic specific to processing a `MsgStoreCode` message.
+
+use ibc_client_wasm_types::msgs::store_code::MsgStoreCode;
+use ibc_primitives::prelude::*;
+use sha2::{Digest, Sha256};
+
+use super::ensure_authority;
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use crate::engine::WasmEngine;
+use crate::error::WasmClientError;
+
+/// Returns the checksum identifying the given Wasm code, i.e. its SHA-256
+/// hash.
+pub fn code_checksum(code: &[u8]) -> Vec<u8> {
+    Sha256::digest(code).to_vec()
+}
+
+pub fn store_code_validate<Ctx>(ctx: &Ctx, msg: &MsgStoreCode) -> Result<(), WasmClientError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    ensure_authority(ctx, &msg.signer)?;
+
+    if msg.wasm_byte_code.is_empty() {
+        return Err(WasmClientError::EmptyWasmCode);
+    }
+
+    let checksum = code_checksum(&msg.wasm_byte_code);
+
+    if ctx.checksum_exists(&checksum)? {
+        return Err(WasmClientError::DuplicateChecksum(hex::encode(checksum)));
+    }
+
+    Ok(())
+}
+
+/// Stores the code with the engine and registers its checksum, which is
+/// returned.
+pub fn store_code_execute<Ctx>(ctx: &mut Ctx, msg: MsgStoreCode) -> Result<Vec<u8>, WasmClientError>
+where
+    Ctx: WasmClientExecutionContext,
+{
+    let checksum = code_checksum(&msg.wasm_byte_code);
+
+    ctx.wasm_engine()
+        .store_code(&checksum, &msg.wasm_byte_code)?;
+    ctx.store_checksum(checksum.clone())?;
+
+    Ok(checksum)
+}
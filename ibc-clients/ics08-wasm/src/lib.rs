@@ -0,0 +1,35 @@
+//! ICS 08: Wasm light client implementation along with re-exporting the
+//! necessary types from `ibc-client-wasm-types` crate.
+//!
+//! This crate implements the host side of the Wasm client: client states and
+//! consensus states hold opaque bytes, and every light client operation is
+//! forwarded to a contract through the host's [`WasmEngine`](engine::WasmEngine).
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+pub mod engine;
+pub mod error;
+pub mod handler;
+
+/// Re-exports Wasm light client data structures from the
+/// `ibc-client-wasm-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::*;
+}
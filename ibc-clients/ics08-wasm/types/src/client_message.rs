@@ -1,6 +1,8 @@
 //! Defines the client message type for the ICS-08 Wasm light client.
 
-use ibc_primitives::proto::Protobuf;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
 use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
 
 use crate::Bytes;
@@ -27,6 +29,32 @@ impl From<ClientMessage> for RawClientMessage {
     }
 }
 
+impl Protobuf<Any> for ClientMessage {}
+
+impl From<ClientMessage> for Any {
+    fn from(value: ClientMessage) -> Self {
+        Self {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(value),
+        }
+    }
+}
+
+impl TryFrom<Any> for ClientMessage {
+    type Error = DecodingError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if let WASM_CLIENT_MESSAGE_TYPE_URL = any.type_url.as_str() {
+            Protobuf::<RawClientMessage>::decode(any.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+                actual: any.type_url,
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -0,0 +1,214 @@
+//! Defines the JSON messages exchanged between a host and an ICS-08 light
+//! client contract, following the contract API of `ibc-go`'s `08-wasm`
+//! module.
+//!
+//! Read-only calls are sent as [`QueryMsg`]s, while calls that may write to
+//! the client store are sent as [`SudoMsg`]s.
+
+use ibc_core_client::types::{Height, Status};
+use ibc_primitives::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::serializer::{Base64, Base64List};
+use crate::Bytes;
+
+/// Message passed to the contract when a Wasm client is created.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InstantiateMsg {
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub client_state: Bytes,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub consensus_state: Bytes,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub checksum: Bytes,
+}
+
+/// Read-only calls into the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Status(StatusMsg),
+    TimestampAtHeight(TimestampAtHeightMsg),
+    VerifyClientMessage(VerifyClientMessageMsg),
+    CheckForMisbehaviour(CheckForMisbehaviourMsg),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StatusMsg {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TimestampAtHeightMsg {
+    pub height: Height,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifyClientMessageMsg {
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub client_message: Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CheckForMisbehaviourMsg {
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub client_message: Bytes,
+}
+
+/// Calls into the contract that are allowed to modify the client store.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    UpdateState(UpdateStateMsg),
+    UpdateStateOnMisbehaviour(UpdateStateOnMisbehaviourMsg),
+    VerifyUpgradeAndUpdateState(VerifyUpgradeAndUpdateStateMsg),
+    VerifyMembership(VerifyMembershipMsg),
+    VerifyNonMembership(VerifyNonMembershipMsg),
+    MigrateClientStore(MigrateClientStoreMsg),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateStateMsg {
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub client_message: Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateStateOnMisbehaviourMsg {
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub client_message: Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifyUpgradeAndUpdateStateMsg {
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub upgrade_client_state: Bytes,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub upgrade_consensus_state: Bytes,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub proof_upgrade_client: Bytes,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub proof_upgrade_consensus_state: Bytes,
+}
+
+/// The key path of a value in the counterparty store, as expected by the
+/// contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MerklePath {
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<String>"))]
+    #[serde(with = "Base64List")]
+    pub key_path: Vec<Bytes>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifyMembershipMsg {
+    pub height: Height,
+    pub delay_time_period: u64,
+    pub delay_block_period: u64,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub proof: Bytes,
+    pub merkle_path: MerklePath,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub value: Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifyNonMembershipMsg {
+    pub height: Height,
+    pub delay_time_period: u64,
+    pub delay_block_period: u64,
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    #[serde(with = "Base64")]
+    pub proof: Bytes,
+    pub merkle_path: MerklePath,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MigrateClientStoreMsg {}
+
+/// Response to calls that carry no result besides their success, such as
+/// [`QueryMsg::VerifyClientMessage`] or [`SudoMsg::VerifyMembership`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EmptyResult {}
+
+/// Response to [`QueryMsg::Status`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StatusResult {
+    pub status: Status,
+}
+
+/// Response to [`QueryMsg::TimestampAtHeight`], in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TimestampAtHeightResult {
+    pub timestamp: u64,
+}
+
+/// Response to [`QueryMsg::CheckForMisbehaviour`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CheckForMisbehaviourResult {
+    pub found_misbehaviour: bool,
+}
+
+/// Response to [`SudoMsg::UpdateState`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateStateResult {
+    pub heights: Vec<Height>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures the messages serialize to the JSON layout expected by contracts
+    #[test]
+    fn test_contract_msg_json() {
+        let query = QueryMsg::Status(StatusMsg {});
+        assert_eq!(serde_json::to_string(&query).unwrap(), r#"{"status":{}}"#);
+
+        let sudo = SudoMsg::VerifyNonMembership(VerifyNonMembershipMsg {
+            height: Height::new(0, 5).unwrap(),
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: vec![1],
+            merkle_path: MerklePath {
+                key_path: vec![b"ibc".to_vec()],
+            },
+        });
+        let json = serde_json::to_string(&sudo).unwrap();
+        assert_eq!(
+            json,
+            r#"{"verify_non_membership":{"height":{"revision_number":0,"revision_height":5},"delay_time_period":0,"delay_block_period":0,"proof":"AQ==","merkle_path":{"key_path":["aWJj"]}}}"#
+        );
+        assert_eq!(serde_json::from_str::<SudoMsg>(&json).unwrap(), sudo);
+    }
+}
@@ -15,6 +15,8 @@
 pub mod client_message;
 pub mod client_state;
 pub mod consensus_state;
+#[cfg(feature = "serde")]
+pub mod contract;
 pub mod msgs;
 
 #[cfg(feature = "serde")]
@@ -22,6 +22,32 @@ impl Base64 {
     }
 }
 
+/// Serializes a list of byte vectors as a list of base64-encoded strings.
+pub struct Base64List;
+
+impl Base64List {
+    pub fn serialize<S: Serializer>(list: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let encoded: Vec<String> = list
+            .iter()
+            .map(|bytes| BASE64_STANDARD.encode(bytes))
+            .collect();
+        encoded.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|base64| {
+                BASE64_STANDARD
+                    .decode(base64.as_bytes())
+                    .map_err(Error::custom)
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
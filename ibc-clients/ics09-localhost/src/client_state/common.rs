@@ -0,0 +1,81 @@
+use ibc_client_localhost_types::client_type as localhost_client_type;
+use ibc_client_localhost_types::error::LocalhostClientError;
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::{Path, PathBytes};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+
+use super::ClientState;
+
+impl ClientStateCommon for ClientState {
+    /// Localhost clients are never created through `MsgCreateClient`, which
+    /// is rejected here since it carries a consensus state.
+    fn verify_consensus_state(
+        &self,
+        _consensus_state: Any,
+        _host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::CreationNotSupported.into())
+    }
+
+    fn client_type(&self) -> ClientType {
+        localhost_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    /// The latest height of the client may lag behind the host until it is
+    /// refreshed, so proof heights are instead checked against the height of
+    /// the host when verifying proofs.
+    fn validate_proof_height(&self, _proof_height: Height) -> Result<(), ClientError> {
+        Ok(())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::UpgradeNotSupported.into())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Membership is checked against the host state by
+    /// [`ClientStateValidation::verify_membership_at_height`](ibc_core_client::context::client_state::ClientStateValidation::verify_membership_at_height),
+    /// as there is no commitment root to verify a proof against.
+    fn verify_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::RootVerificationNotSupported.into())
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::RootVerificationNotSupported.into())
+    }
+}
@@ -0,0 +1,86 @@
+use ibc_client_localhost_types::error::LocalhostClientError;
+use ibc_client_localhost_types::ClientState as ClientStateType;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientStatePath;
+use ibc_core_host::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext + ValidationContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    /// Stores the client state. The localhost client has no consensus state,
+    /// so `consensus_state` is ignored.
+    ///
+    /// Hosts call this once, typically at genesis, with
+    /// [`ClientId::localhost`] as the client identifier, and store the
+    /// sentinel localhost connection along with it.
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.0.into())?;
+
+        Ok(())
+    }
+
+    /// Refreshes the latest height of the client to the height of the host.
+    ///
+    /// Client messages are rejected upon verification, so this is only
+    /// reached when called by the host, typically at the beginning of every
+    /// block, in which case `header` is ignored.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            ClientStateType::new(host_height).into(),
+        )?;
+
+        Ok(vec![host_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::UpdateNotSupported.into())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(LocalhostClientError::UpgradeNotSupported.into())
+    }
+
+    fn update_on_recovery(
+        &self,
+        _ctx: &mut E,
+        _subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
+        _substitute_client_state: Any,
+        _substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::RecoveryNotSupported.into())
+    }
+}
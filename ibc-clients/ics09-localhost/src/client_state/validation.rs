@@ -0,0 +1,170 @@
+use ibc_client_localhost_types::error::LocalhostClientError;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status};
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, Path};
+use ibc_core_host::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::ClientState;
+
+/// The localhost client reads the state it verifies from the host, so its
+/// validation context must also be the host's [`ValidationContext`].
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext + ValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        Err(LocalhostClientError::UpdateNotSupported.into())
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    /// A localhost client tracks the host itself, so it is always active.
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(Status::Active)
+    }
+
+    fn check_substitute(&self, _ctx: &V, _substitute_client_state: Any) -> Result<(), ClientError> {
+        Err(LocalhostClientError::RecoveryNotSupported.into())
+    }
+
+    /// Returns the timestamp of the host block at the given height.
+    fn timestamp_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+    ) -> Result<Timestamp, ClientError> {
+        let height = verify_proof_height(ctx, consensus_state_path)?;
+
+        ValidationContext::host_consensus_state(ctx, &height)?.timestamp()
+    }
+
+    /// Checks that `value` is stored at `path` in the current state of the
+    /// host. The proof is ignored.
+    fn verify_membership_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_proof_height(ctx, consensus_state_path)?;
+        verify_prefix(ctx, prefix)?;
+
+        match host_value(ctx, &path)? {
+            Some(stored_value) if stored_value == value => Ok(()),
+            Some(_) => Err(LocalhostClientError::MismatchedValue(path).into()),
+            None => Err(LocalhostClientError::MissingValue(path).into()),
+        }
+    }
+
+    /// Checks that nothing is stored at `path` in the current state of the
+    /// host. The proof is ignored.
+    fn verify_non_membership_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_proof_height(ctx, consensus_state_path)?;
+        verify_prefix(ctx, prefix)?;
+
+        if host_value(ctx, &path)?.is_some() {
+            return Err(LocalhostClientError::UnexpectedValue(path).into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the encoded value stored at `path` by the host, or `None` if there
+/// is none, in the same encoding as the values core handlers verify.
+///
+/// Only the paths of connections, channels and packets are supported, as
+/// these are the only ones verified over the localhost connection. Values
+/// the host fails to return are deemed absent.
+pub fn host_value<V>(ctx: &V, path: &Path) -> Result<Option<Vec<u8>>, ClientError>
+where
+    V: ValidationContext,
+{
+    let value = match path {
+        Path::Connection(path) => ctx.connection_end(&path.0).ok().map(|c| c.encode_vec()),
+        Path::ChannelEnd(path) => ctx.channel_end(path).ok().map(|c| c.encode_vec()),
+        Path::SeqSend(path) => ctx.get_next_sequence_send(path).ok().map(|s| s.to_vec()),
+        Path::SeqRecv(path) => ctx.get_next_sequence_recv(path).ok().map(|s| s.to_vec()),
+        Path::SeqAck(path) => ctx.get_next_sequence_ack(path).ok().map(|s| s.to_vec()),
+        Path::Commitment(path) => ctx.get_packet_commitment(path).ok().map(|c| c.into_vec()),
+        Path::Ack(path) => ctx
+            .get_packet_acknowledgement(path)
+            .ok()
+            .map(|a| a.into_vec()),
+        // Receipts are stored as a single byte, as in ibc-go.
+        Path::Receipt(path) => ctx.get_packet_receipt(path)?.is_ok().then(|| vec![1]),
+        Path::ChannelUpgrade(path) => ctx.channel_upgrade(path)?.map(|u| u.encode_vec()),
+        Path::CounterpartyChannelUpgrade(path) => ctx
+            .counterparty_channel_upgrade(path)?
+            .map(|u| u.encode_vec()),
+        Path::ChannelUpgradeError(path) => ctx.channel_upgrade_error(path)?.map(|e| e.encode_vec()),
+        _ => return Err(LocalhostClientError::UnsupportedPath(path.clone()).into()),
+    };
+
+    Ok(value)
+}
+
+/// Proofs are checked against the current state of the host, so they cannot
+/// be claimed at a height the host has not reached yet.
+fn verify_proof_height<V>(
+    ctx: &V,
+    consensus_state_path: &ClientConsensusStatePath,
+) -> Result<Height, ClientError>
+where
+    V: ValidationContext,
+{
+    let proof_height = Height::new(
+        consensus_state_path.revision_number,
+        consensus_state_path.revision_height,
+    )?;
+    let host_height = ValidationContext::host_height(ctx)?;
+
+    if proof_height > host_height {
+        return Err(LocalhostClientError::InvalidProofHeight {
+            proof_height,
+            host_height,
+        }
+        .into());
+    }
+
+    Ok(proof_height)
+}
+
+fn verify_prefix<V>(ctx: &V, prefix: &CommitmentPrefix) -> Result<(), ClientError>
+where
+    V: ValidationContext,
+{
+    if *prefix != ctx.commitment_prefix() {
+        return Err(LocalhostClientError::MismatchedPrefix.into());
+    }
+
+    Ok(())
+}
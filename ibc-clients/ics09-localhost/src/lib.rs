@@ -0,0 +1,28 @@
+//! ICS 09: Localhost light client implementation along with re-exporting the
+//! necessary types from `ibc-client-localhost-types` crate.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+
+pub const LOCALHOST_CLIENT_TYPE: &str = "09-localhost";
+
+/// Re-exports Localhost light client data structures from the
+/// `ibc-client-localhost-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_localhost_types::*;
+}
@@ -0,0 +1,105 @@
+//! Defines Localhost's `ClientState` type
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::localhost::v2::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v2.ClientState";
+
+/// Contains the latest height of the host, which the host refreshes as it
+/// produces blocks.
+///
+/// The localhost client has no consensus state: proofs are checked against
+/// the current state of the host.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(latest_height: Height) -> Self {
+        Self { latest_height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or(DecodingError::missing_raw_data(
+                "client state latest height",
+            ))?
+            .try_into()?;
+
+        Ok(Self::new(latest_height))
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let LOCALHOST_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = ClientState::new(Height::new(1, 10).unwrap());
+
+        let any = Any::from(client_state);
+        assert_eq!(any.type_url, LOCALHOST_CLIENT_STATE_TYPE_URL);
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+
+    #[test]
+    fn client_state_missing_height() {
+        let raw = RawClientState {
+            latest_height: None,
+        };
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+//! Defines the Localhost light client's error type
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_host_types::path::Path;
+use ibc_primitives::prelude::*;
+
+/// The main error type for the Localhost light client
+#[derive(Debug, Display)]
+pub enum LocalhostClientError {
+    /// missing value at path `{0}`
+    MissingValue(Path),
+    /// mismatched value at path `{0}`
+    MismatchedValue(Path),
+    /// unexpected value at path `{0}`; expected none
+    UnexpectedValue(Path),
+    /// unsupported path `{0}`
+    UnsupportedPath(Path),
+    /// localhost proofs are checked against the host state, not a commitment root
+    RootVerificationNotSupported,
+    /// mismatched commitment prefix; expected the prefix of the host
+    MismatchedPrefix,
+    /// invalid proof height `{proof_height}`; must not exceed host height `{host_height}`
+    InvalidProofHeight {
+        proof_height: Height,
+        host_height: Height,
+    },
+    /// localhost clients can only be created by the host
+    CreationNotSupported,
+    /// localhost clients can only be updated by the host
+    UpdateNotSupported,
+    /// localhost clients cannot be upgraded
+    UpgradeNotSupported,
+    /// localhost clients cannot be recovered
+    RecoveryNotSupported,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LocalhostClientError {}
+
+impl From<LocalhostClientError> for ClientError {
+    fn from(e: LocalhostClientError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
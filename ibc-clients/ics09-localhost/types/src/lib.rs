@@ -0,0 +1,66 @@
+//! ICS-09: Localhost Client implements a client through which a chain connects
+//! to itself, verifying proofs directly against its own state.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::{ClientId, ClientType};
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod client_state;
+
+pub use client_state::*;
+
+pub mod error;
+
+/// Re-exports ICS-09 Localhost light client from `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::lightclients::localhost::*;
+}
+
+pub const LOCALHOST_CLIENT_TYPE: &str = "09-localhost";
+
+/// Proof bytes relayers submit along with messages over the localhost
+/// connection. Proofs are not checked, since the localhost client reads the
+/// host state directly, but messages require them to be non-empty.
+pub const SENTINEL_PROOF: &[u8] = &[0x01];
+
+/// Returns the localhost `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(LOCALHOST_CLIENT_TYPE).expect("Never fails because it's valid")
+}
+
+/// Returns the `ClientId` of the localhost client, which is the same as its
+/// client type since a host has at most one localhost client.
+pub fn client_id() -> ClientId {
+    ClientId::localhost()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the localhost client type
+    #[test]
+    pub fn test_localhost_client_type() {
+        let _ = ClientType::from_str(LOCALHOST_CLIENT_TYPE).unwrap();
+    }
+
+    #[test]
+    pub fn test_localhost_client_id() {
+        assert_eq!(client_id().as_str(), LOCALHOST_CLIENT_TYPE);
+    }
+}
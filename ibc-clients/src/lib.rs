@@ -11,14 +11,32 @@
     rust_2018_idioms
 )]
 
+/// Re-exports implementations of ICS-06 Solo Machine light client.
+pub mod solomachine {
+    #[doc(inline)]
+    pub use ibc_client_solomachine::*;
+}
+
 /// Re-exports implementations of ICS-07 Tendermint light client.
 pub mod tendermint {
     #[doc(inline)]
     pub use ibc_client_tendermint::*;
 }
 
+/// Re-exports implementations of ICS-08 Wasm light client.
+pub mod wasm {
+    #[doc(inline)]
+    pub use ibc_client_wasm::*;
+}
+
 /// Re-exports implementations of ICS-08 Wasm light client types.
 pub mod wasm_types {
     #[doc(inline)]
     pub use ibc_client_wasm_types::*;
 }
+
+/// Re-exports implementations of ICS-09 Localhost light client.
+pub mod localhost {
+    #[doc(inline)]
+    pub use ibc_client_localhost::*;
+}
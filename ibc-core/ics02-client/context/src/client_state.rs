@@ -6,11 +6,12 @@ use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
-use ibc_core_host_types::path::{Path, PathBytes};
+use ibc_core_host_types::path::{ClientConsensusStatePath, Path, PathBytes};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 use ibc_primitives::Timestamp;
 
+use crate::consensus_state::ConsensusState;
 use crate::context::{ClientExecutionContext, ClientValidationContext};
 use crate::Convertible;
 
@@ -171,6 +172,107 @@ where
     ///
     /// Returns `Ok` if the subject and substitute client states match, `Err` otherwise.
     fn check_substitute(&self, ctx: &V, substitute_client_state: Any) -> Result<(), ClientError>;
+
+    /// Returns whether applying `header` through `update_state` would leave
+    /// the client unchanged, e.g. because another relayer already installed
+    /// a consensus state at the header's height.
+    ///
+    /// Hosts use this to drop redundant relay transactions before they get
+    /// executed. The default implementation never reports a redundant update.
+    fn is_redundant_update(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _header: Any,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    /// Returns the timestamp of the consensus state stored under
+    /// `consensus_state_path`.
+    ///
+    /// The default implementation reads it off the stored consensus state.
+    /// Clients whose consensus states are opaque to the host override it.
+    fn timestamp_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+    ) -> Result<Timestamp, ClientError> {
+        ctx.consensus_state(consensus_state_path)?.timestamp()
+    }
+
+    /// Verifies a proof of the existence of a value at a given path object,
+    /// against the consensus state stored under `consensus_state_path`.
+    ///
+    /// This is the entrypoint used by the core handlers. The default
+    /// implementation looks up the consensus state and delegates to
+    /// [`ClientStateCommon::verify_membership`]. Clients that cannot verify a
+    /// proof from the commitment root alone (e.g. because verification happens
+    /// outside of the host, as with ICS-08 Wasm clients) can override it to
+    /// make use of the client identifier and proof height.
+    fn verify_membership_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(consensus_state_path)?;
+        self.verify_membership(prefix, proof, consensus_state.root(), path, value)
+    }
+
+    /// Verifies the absence of a given proof at a given path object, against
+    /// the consensus state stored under `consensus_state_path`.
+    ///
+    /// See [`ClientStateValidation::verify_membership_at_height`] for when to
+    /// override this method.
+    fn verify_non_membership_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(consensus_state_path)?;
+        self.verify_non_membership(prefix, proof, consensus_state.root(), path)
+    }
+
+    /// Verifies a proof of the existence of a value at a given raw path
+    /// bytes, against the consensus state stored under `consensus_state_path`.
+    ///
+    /// Used for paths that are not part of the [`Path`] enum, such as the
+    /// IBC v2 packet paths. See
+    /// [`ClientStateValidation::verify_membership_at_height`] for when to
+    /// override this method.
+    fn verify_membership_raw_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(consensus_state_path)?;
+        self.verify_membership_raw(prefix, proof, consensus_state.root(), path, value)
+    }
+
+    /// Verifies the absence of a given proof at a given raw path bytes,
+    /// against the consensus state stored under `consensus_state_path`.
+    fn verify_non_membership_raw_at_height(
+        &self,
+        ctx: &V,
+        consensus_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(consensus_state_path)?;
+        self.verify_non_membership_raw(prefix, proof, consensus_state.root(), path)
+    }
 }
 
 /// `ClientState` methods which require access to the client's
@@ -230,10 +332,14 @@ where
 
     /// Update the subject client using the `substitute_client_state` in response
     /// to a successful client recovery.
+    ///
+    /// The `substitute_client_id` is provided for clients that need access to
+    /// the substitute's store during recovery, as ICS-08 Wasm clients do.
     fn update_on_recovery(
         &self,
         ctx: &mut E,
         subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
         substitute_client_state: Any,
         substitute_consensus_state: Any,
     ) -> Result<(), ClientError>;
@@ -2,5 +2,6 @@
 
 pub mod create_client;
 pub mod recover_client;
+pub mod register_counterparty;
 pub mod update_client;
 pub mod upgrade_client;
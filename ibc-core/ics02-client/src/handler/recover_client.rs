@@ -81,6 +81,7 @@ where
     subject_client_state.update_on_recovery(
         ctx.get_client_execution_context(),
         &subject_client_id,
+        &substitute_client_id,
         substitute_client_state.into(),
         substitute_consensus_state.into(),
     )?;
@@ -0,0 +1,45 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgRegisterCounterparty`.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::msgs::MsgRegisterCounterparty;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::v2::SeqSendPath;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRegisterCounterparty) -> Result<(), ClientError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_message_signer(&msg.signer)?;
+
+    // Make sure the client exists.
+    ctx.get_client_validation_context()
+        .client_state(&msg.client_id)?;
+
+    if ctx.client_counterparty(&msg.client_id)?.is_some() {
+        return Err(ClientError::DuplicateCounterparty(msg.client_id));
+    }
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRegisterCounterparty) -> Result<(), ClientError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.store_client_counterparty(&msg.client_id, msg.counterparty_info())?;
+
+    // IBC v2 packet sequences start at 1.
+    ctx.store_next_sequence_send_v2(&SeqSendPath::new(&msg.client_id), Sequence::from(1))?;
+
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.log_message(format!(
+        "success: registered counterparty client {} for client {}",
+        msg.counterparty_client_id, msg.client_id
+    ))?;
+
+    Ok(())
+}
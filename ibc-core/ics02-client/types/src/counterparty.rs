@@ -0,0 +1,106 @@
+//! Defines the counterparty information of IBC v2 clients.
+
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use crate::proto::v2::CounterpartyInfo as RawCounterpartyInfo;
+
+/// Information about the counterparty of a client, registered once so that
+/// the client can be used to send and receive IBC v2 packets.
+///
+/// Packets sent over the client are addressed to `client_id`, and the
+/// proofs of the counterparty state are verified under `prefix`.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterpartyInfo {
+    /// Identifier of the client of this chain on the counterparty
+    pub client_id: ClientId,
+    /// Prefix under which the counterparty stores its IBC state
+    pub prefix: CommitmentPrefix,
+}
+
+impl CounterpartyInfo {
+    pub fn new(client_id: ClientId, prefix: CommitmentPrefix) -> Self {
+        Self { client_id, prefix }
+    }
+}
+
+impl Protobuf<RawCounterpartyInfo> for CounterpartyInfo {}
+
+impl TryFrom<RawCounterpartyInfo> for CounterpartyInfo {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawCounterpartyInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: raw.client_id.parse()?,
+            prefix: prefix_from_merkle_prefix(raw.merkle_prefix)?,
+        })
+    }
+}
+
+impl From<CounterpartyInfo> for RawCounterpartyInfo {
+    fn from(value: CounterpartyInfo) -> Self {
+        Self {
+            merkle_prefix: prefix_to_merkle_prefix(value.prefix),
+            client_id: value.client_id.to_string(),
+        }
+    }
+}
+
+/// Converts a Merkle prefix into a commitment prefix.
+///
+/// In ibc-go, the path of a key is formed by appending the key to the last
+/// element of the Merkle prefix. Only prefixes of the form `[prefix, ""]` are
+/// supported, which are equivalent to the commitment prefix `prefix` used
+/// by IBC v1 connections.
+pub(crate) fn prefix_from_merkle_prefix(
+    merkle_prefix: Vec<Vec<u8>>,
+) -> Result<CommitmentPrefix, DecodingError> {
+    match merkle_prefix.as_slice() {
+        [prefix, key_prefix] if !prefix.is_empty() && key_prefix.is_empty() => {
+            Ok(CommitmentPrefix::from_bytes(prefix))
+        }
+        _ => Err(DecodingError::invalid_raw_data(format!(
+            "unsupported merkle prefix {merkle_prefix:?}: expected `[prefix, \"\"]`"
+        ))),
+    }
+}
+
+pub(crate) fn prefix_to_merkle_prefix(prefix: CommitmentPrefix) -> Vec<Vec<u8>> {
+    vec![prefix.into_vec(), Vec::new()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merkle_prefix_conversion() {
+        let prefix = CommitmentPrefix::from_bytes(b"ibc");
+
+        let merkle_prefix = prefix_to_merkle_prefix(prefix.clone());
+        assert_eq!(merkle_prefix, vec![b"ibc".to_vec(), vec![]]);
+        assert_eq!(prefix_from_merkle_prefix(merkle_prefix).unwrap(), prefix);
+
+        assert!(prefix_from_merkle_prefix(vec![b"ibc".to_vec()]).is_err());
+        assert!(prefix_from_merkle_prefix(vec![b"ibc".to_vec(), b"key".to_vec()]).is_err());
+        assert!(prefix_from_merkle_prefix(vec![vec![], vec![]]).is_err());
+    }
+}
@@ -48,6 +48,10 @@ pub enum ClientError {
     MissingLocalConsensusState(Height),
     /// duplicate client state `{0}`
     DuplicateClientState(ClientId),
+    /// missing counterparty of client `{0}`
+    MissingCounterparty(ClientId),
+    /// duplicate counterparty of client `{0}`
+    DuplicateCounterparty(ClientId),
     /// failed to verify client recovery states
     FailedToVerifyClientRecoveryStates,
     /// failed ICS23 verification: {0}
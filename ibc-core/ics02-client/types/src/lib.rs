@@ -14,16 +14,20 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+mod counterparty;
 pub mod error;
 pub mod events;
 mod height;
 pub mod msgs;
 mod status;
 
+pub use counterparty::*;
 pub use height::*;
 pub use status::*;
 
 /// Re-exports ICS-02 proto types from the `ibc-proto` crate for added convenience.
 pub mod proto {
     pub use ibc_proto::ibc::core::client::*;
+
+    pub mod v2;
 }
@@ -0,0 +1,71 @@
+//! Definition of domain type message `MsgRegisterCounterparty`.
+
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::counterparty::{prefix_from_merkle_prefix, prefix_to_merkle_prefix, CounterpartyInfo};
+use crate::proto::v2::MsgRegisterCounterparty as RawMsgRegisterCounterparty;
+
+pub const REGISTER_COUNTERPARTY_TYPE_URL: &str = "/ibc.core.client.v2.MsgRegisterCounterparty";
+
+/// Defines the message used to register the counterparty of a client, which
+/// is required before the client can be used to send or receive IBC v2
+/// packets.
+///
+/// The counterparty of a client can only be registered once.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterCounterparty {
+    /// Identifier of the client on this chain
+    pub client_id: ClientId,
+    /// Identifier of the client of this chain on the counterparty
+    pub counterparty_client_id: ClientId,
+    /// Prefix under which the counterparty stores its IBC state
+    pub counterparty_prefix: CommitmentPrefix,
+    /// The signer of the message
+    pub signer: Signer,
+}
+
+impl MsgRegisterCounterparty {
+    pub fn counterparty_info(&self) -> CounterpartyInfo {
+        CounterpartyInfo::new(
+            self.counterparty_client_id.clone(),
+            self.counterparty_prefix.clone(),
+        )
+    }
+}
+
+impl Protobuf<RawMsgRegisterCounterparty> for MsgRegisterCounterparty {}
+
+impl TryFrom<RawMsgRegisterCounterparty> for MsgRegisterCounterparty {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMsgRegisterCounterparty) -> Result<Self, Self::Error> {
+        Ok(MsgRegisterCounterparty {
+            client_id: raw.client_id.parse()?,
+            counterparty_client_id: raw.counterparty_client_id.parse()?,
+            counterparty_prefix: prefix_from_merkle_prefix(raw.counterparty_merkle_prefix)?,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterparty> for RawMsgRegisterCounterparty {
+    fn from(ics_msg: MsgRegisterCounterparty) -> Self {
+        RawMsgRegisterCounterparty {
+            client_id: ics_msg.client_id.to_string(),
+            counterparty_merkle_prefix: prefix_to_merkle_prefix(ics_msg.counterparty_prefix),
+            counterparty_client_id: ics_msg.counterparty_client_id.to_string(),
+            signer: ics_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Protobuf definitions of the `ibc.core.client.v2` package, which are not
+//! yet shipped by `ibc-proto`.
+
+use ibc_primitives::prelude::*;
+
+/// Information about the counterparty of an IBC v2 client.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CounterpartyInfo {
+    /// Merkle prefix under which the counterparty stores its IBC state
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub merkle_prefix: Vec<Vec<u8>>,
+    /// Identifier of the client of this chain on the counterparty
+    #[prost(string, tag = "2")]
+    pub client_id: String,
+}
+
+/// Registers the counterparty of a client, so that it can be used to send
+/// and receive IBC v2 packets.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgRegisterCounterparty {
+    /// Identifier of the client on this chain
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    /// Merkle prefix under which the counterparty stores its IBC state
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub counterparty_merkle_prefix: Vec<Vec<u8>>,
+    /// Identifier of the client of this chain on the counterparty
+    #[prost(string, tag = "3")]
+    pub counterparty_client_id: String,
+    /// Signer address
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl ::prost::Name for MsgRegisterCounterparty {
+    const NAME: &'static str = "MsgRegisterCounterparty";
+    const PACKAGE: &'static str = "ibc.core.client.v2";
+
+    fn full_name() -> String {
+        "ibc.core.client.v2.MsgRegisterCounterparty".into()
+    }
+
+    fn type_url() -> String {
+        "/ibc.core.client.v2.MsgRegisterCounterparty".into()
+    }
+}
@@ -1,7 +1,10 @@
+use core::time::Duration;
+
 use ibc_core_client::context::ClientValidationContext;
 use ibc_core_client::types::Height;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::ValidationContext;
 
 pub fn verify_conn_delay_passed<Ctx>(
@@ -12,18 +15,41 @@ pub fn verify_conn_delay_passed<Ctx>(
 where
     Ctx: ValidationContext,
 {
+    verify_delay_passed(
+        ctx,
+        packet_proof_height,
+        connection_end.client_id(),
+        connection_end.delay_period(),
+    )
+}
+
+/// Verifies that the given delay period has passed since the client updated
+/// its consensus state at `packet_proof_height`.
+pub fn verify_delay_passed<Ctx>(
+    ctx: &Ctx,
+    packet_proof_height: Height,
+    client_id: &ClientId,
+    conn_delay_time_period: Duration,
+) -> Result<(), ConnectionError>
+where
+    Ctx: ValidationContext,
+{
+    // The localhost connection has no delay, and its client has no update
+    // metadata since proofs are checked against the current host state.
+    if client_id.is_localhost() {
+        return Ok(());
+    }
+
     // Fetch the current host chain time and height.
     let current_host_time = ctx.host_timestamp()?;
     let current_host_height = ctx.host_height()?;
 
     // Fetch the latest time and height that the counterparty client was updated on the host chain.
-    let client_id = connection_end.client_id();
     let last_client_update = ctx
         .get_client_validation_context()
         .client_update_meta(client_id, &packet_proof_height)?;
 
-    // Fetch the connection delay time and height periods.
-    let conn_delay_time_period = connection_end.delay_period();
+    // Fetch the connection delay height period.
     let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period);
 
     // Verify that the current host chain time is later than the last client update time
@@ -75,9 +75,6 @@ where
             msg.proofs_height_on_b.revision_height(),
         );
 
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-
         let prefix_on_a = ctx_a.commitment_prefix();
         let prefix_on_b = vars.conn_end_on_a.counterparty().prefix();
 
@@ -94,19 +91,21 @@ where
                 vars.conn_end_on_a.delay_period(),
             )?;
 
-            client_state_of_b_on_a.verify_membership(
+            client_state_of_b_on_a.verify_membership_at_height(
+                client_val_ctx_a,
+                &client_cons_state_path_on_a,
                 prefix_on_b,
                 &msg.proof_conn_end_on_b,
-                consensus_state_of_b_on_a.root(),
                 Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
                 expected_conn_end_on_b.encode_vec(),
             )?;
         }
 
-        client_state_of_b_on_a.verify_membership(
+        client_state_of_b_on_a.verify_membership_at_height(
+            client_val_ctx_a,
+            &client_cons_state_path_on_a,
             prefix_on_b,
             &msg.proof_client_state_of_a_on_b,
-            consensus_state_of_b_on_a.root(),
             Path::ClientState(ClientStatePath::new(vars.client_id_on_b().clone())),
             msg.client_state_of_a_on_b.to_vec(),
         )?;
@@ -123,10 +122,11 @@ where
             msg.consensus_height_of_a_on_b.revision_height(),
         );
 
-        client_state_of_b_on_a.verify_membership(
+        client_state_of_b_on_a.verify_membership_at_height(
+            client_val_ctx_a,
+            &client_cons_state_path_on_a,
             prefix_on_b,
             &msg.proof_consensus_state_of_a_on_b,
-            consensus_state_of_b_on_a.root(),
             Path::ClientConsensusState(client_cons_state_path_on_b),
             stored_consensus_state_of_a_on_b.to_vec(),
         )?;
@@ -55,8 +55,6 @@ where
             msg.proof_height_on_a.revision_number(),
             msg.proof_height_on_a.revision_height(),
         );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
 
         let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let prefix_on_b = ctx_b.commitment_prefix();
@@ -73,10 +71,11 @@ where
             conn_end_on_b.delay_period(),
         )?;
 
-        client_state_of_a_on_b.verify_membership(
+        client_state_of_a_on_b.verify_membership_at_height(
+            client_val_ctx_b,
+            &client_cons_state_path_on_b,
             prefix_on_a,
             &msg.proof_conn_end_on_a,
-            consensus_state_of_a_on_b.root(),
             Path::Connection(ConnectionPath::new(conn_id_on_a)),
             expected_conn_end_on_a.encode_vec(),
         )?;
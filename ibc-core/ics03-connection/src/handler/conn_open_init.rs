@@ -16,6 +16,11 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    // The localhost client only serves the sentinel localhost connection.
+    if msg.client_id_on_a.is_localhost() {
+        return Err(ConnectionError::DisallowedLocalhostHandshake);
+    }
+
     let client_val_ctx_a = ctx_a.get_client_validation_context();
 
     // An IBC client running on the local (host) chain should exist.
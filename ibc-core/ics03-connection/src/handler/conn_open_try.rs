@@ -37,6 +37,11 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    // The localhost client only serves the sentinel localhost connection.
+    if msg.client_id_on_b.is_localhost() {
+        return Err(ConnectionError::DisallowedLocalhostHandshake);
+    }
+
     let client_val_ctx_b = ctx_b.get_client_validation_context();
 
     let client_state_of_b_on_a = unpack_host_client_state::<Ctx::HostClientState>(
@@ -75,9 +80,6 @@ where
             msg.proofs_height_on_a.revision_height(),
         );
 
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
         let prefix_on_a = vars.conn_end_on_b.counterparty().prefix();
         let prefix_on_b = ctx_b.commitment_prefix();
 
@@ -90,19 +92,21 @@ where
                 msg.delay_period,
             )?;
 
-            client_state_of_a_on_b.verify_membership(
+            client_state_of_a_on_b.verify_membership_at_height(
+                client_val_ctx_b,
+                &client_cons_state_path_on_b,
                 prefix_on_a,
                 &msg.proof_conn_end_on_a,
-                consensus_state_of_a_on_b.root(),
                 Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
                 expected_conn_end_on_a.encode_vec(),
             )?;
         }
 
-        client_state_of_a_on_b.verify_membership(
+        client_state_of_a_on_b.verify_membership_at_height(
+            client_val_ctx_b,
+            &client_cons_state_path_on_b,
             prefix_on_a,
             &msg.proof_client_state_of_b_on_a,
-            consensus_state_of_a_on_b.root(),
             Path::ClientState(ClientStatePath::new(client_id_on_a.clone())),
             msg.client_state_of_b_on_a.to_vec(),
         )?;
@@ -119,10 +123,11 @@ where
             msg.consensus_height_of_b_on_a.revision_height(),
         );
 
-        client_state_of_a_on_b.verify_membership(
+        client_state_of_a_on_b.verify_membership_at_height(
+            client_val_ctx_b,
+            &client_cons_state_path_on_b,
             prefix_on_a,
             &msg.proof_consensus_state_of_b_on_a,
-            consensus_state_of_a_on_b.root(),
             Path::ClientConsensusState(client_cons_state_path_on_a),
             stored_consensus_state_of_b_on_a.to_vec(),
         )?;
@@ -0,0 +1,53 @@
+//! Defines the sentinel `connection-localhost` connection, through which the
+//! host connects to itself using the `09-localhost` client.
+//!
+//! The connection is never negotiated through a handshake: a host supporting
+//! the localhost client stores it once, typically at genesis, along with the
+//! client state under [`ClientId::localhost`]. Channels can then be opened on
+//! top of it like on any other connection.
+use core::time::Duration;
+
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host::types::path::ConnectionPath;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Returns the sentinel connection end of the host to itself.
+///
+/// It is open from the start, has no delay period, and both of its ends use
+/// the localhost client and the commitment prefix of the host.
+pub fn localhost_connection_end<Ctx>(ctx: &Ctx) -> Result<ConnectionEnd, ConnectionError>
+where
+    Ctx: ValidationContext,
+{
+    let version = ctx.pick_version(&ctx.get_compatible_versions())?;
+
+    ConnectionEnd::new(
+        State::Open,
+        ClientId::localhost(),
+        Counterparty::new(
+            ClientId::localhost(),
+            Some(ConnectionId::localhost()),
+            ctx.commitment_prefix(),
+        ),
+        vec![version],
+        Duration::ZERO,
+    )
+}
+
+/// Stores the sentinel connection end under [`ConnectionId::localhost`].
+pub fn store_localhost_connection<Ctx>(ctx: &mut Ctx) -> Result<(), ConnectionError>
+where
+    Ctx: ExecutionContext,
+{
+    let connection_end = localhost_connection_end(ctx)?;
+
+    ctx.store_connection(
+        &ConnectionPath::new(&ConnectionId::localhost()),
+        connection_end,
+    )?;
+
+    Ok(())
+}
@@ -29,6 +29,8 @@ pub enum ConnectionError {
     MissingCommonVersion,
     /// missing counterparty
     MissingCounterparty,
+    /// localhost connection handshakes are disallowed
+    DisallowedLocalhostHandshake,
     /// insufficient consensus height `{current_height}` for host chain; needs to meet counterparty's height `{target_height}`
     InsufficientConsensusHeight {
         target_height: Height,
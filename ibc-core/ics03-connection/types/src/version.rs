@@ -65,7 +65,11 @@ impl Version {
     pub fn compatibles() -> Vec<Self> {
         vec![Self {
             identifier: "1".to_string(),
-            features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+            features: vec![
+                "ORDER_ORDERED".to_string(),
+                "ORDER_UNORDERED".to_string(),
+                "ORDER_ORDERED_ALLOW_TIMEOUT".to_string(),
+            ],
         }]
     }
 }
@@ -356,7 +360,11 @@ mod tests {
                 counterparty: Version::compatibles(),
                 picked: Ok(Version {
                     identifier: "1".to_string(),
-                    features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+                    features: vec![
+                        "ORDER_ORDERED".to_string(),
+                        "ORDER_UNORDERED".to_string(),
+                        "ORDER_ORDERED_ALLOW_TIMEOUT".to_string(),
+                    ],
                 }),
                 want_pass: true,
             },
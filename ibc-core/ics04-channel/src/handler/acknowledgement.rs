@@ -6,17 +6,18 @@ use ibc_core_channel_types::events::{AcknowledgePacket, WriteAcknowledgement};
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
 use ibc_core_channel_types::packet::{Packet, Receipt};
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
-    SeqAckPath, SeqRecvPath,
+    AckPath, ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqAckPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::multihop::{verify_counterparty_delay_passed, verify_counterparty_membership};
+use super::upgrade::handle_flush_state;
+
 pub fn commit_packet_sequence_number_with_chan_end<ExecCtx>(
     ctx_b: &mut ExecCtx,
     chan_end_on_b: &ChannelEnd,
@@ -36,7 +37,7 @@ where
 
             ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
         }
-        Order::Ordered => {
+        Order::Ordered | Order::OrderedAllowTimeout => {
             let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
             let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
             ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, next_seq_recv.increment())?;
@@ -173,13 +174,17 @@ where
     {
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
-        if let Order::Ordered = chan_end_on_a.ordering {
+        if chan_end_on_a.ordering.is_ordered() {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
             // (where `nextSeqRecv` is the value in the store)
             let seq_ack_path_on_a =
                 SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
             ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
         }
+
+        if chan_end_on_a.state == ChannelState::Flushing {
+            handle_flush_state(ctx_a, &msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)?;
+        }
     }
 
     // emit events and logs
@@ -210,7 +215,17 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+    // Acknowledgements are still processed while the channel end flushes
+    // in-flight packets during an upgrade.
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        });
+    }
 
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
@@ -249,7 +264,7 @@ where
         });
     }
 
-    if let Order::Ordered = chan_end_on_a.ordering {
+    if chan_end_on_a.ordering.is_ordered() {
         let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
         if packet.seq_on_a != next_seq_ack {
@@ -274,24 +289,25 @@ where
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
-        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
+        verify_counterparty_delay_passed(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
+            &msg.proof_acked_on_b,
+        )?;
 
         // Verify the proof for the packet against the chain store.
-        client_state_of_b_on_a.verify_membership(
-            conn_end_on_a.counterparty().prefix(),
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
             &msg.proof_acked_on_b,
-            consensus_state_of_b_on_a.root(),
             Path::Ack(ack_path_on_b),
             ack_commitment.into_vec(),
         )?;
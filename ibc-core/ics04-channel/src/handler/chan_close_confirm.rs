@@ -5,15 +5,16 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseConfirm;
 use ibc_core_channel_types::msgs::MsgChannelCloseConfirm;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::error::ConnectionError;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::multihop::{counterparty_connection_hops, verify_counterparty_membership};
+
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -118,39 +119,34 @@ where
 
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let port_id_on_a = &chan_end_on_b.counterparty().port_id;
         let chan_id_on_a = chan_end_on_b
             .counterparty()
             .channel_id()
             .ok_or(ChannelError::MissingCounterparty)?;
-        let conn_id_on_a = conn_end_on_b
-            .counterparty()
-            .connection_id()
-            .ok_or(ConnectionError::MissingCounterparty)?;
+        let conn_hops_on_a = counterparty_connection_hops(
+            &conn_end_on_b,
+            chan_end_on_b.connection_hops(),
+            &msg.proof_chan_end_on_a,
+        )?;
 
         let expected_chan_end_on_a = ChannelEnd::new(
             ChannelState::Closed,
             *chan_end_on_b.ordering(),
             Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
-            vec![conn_id_on_a.clone()],
+            conn_hops_on_a,
             chan_end_on_b.version().clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b.verify_membership(
-            prefix_on_a,
+        verify_counterparty_membership(
+            ctx_b,
+            &conn_end_on_b,
+            chan_end_on_b.connection_hops(),
+            msg.proof_height_on_a,
             &msg.proof_chan_end_on_a,
-            consensus_state_of_a_on_b.root(),
             Path::ChannelEnd(chan_end_path_on_a),
             expected_chan_end_on_a.encode_vec(),
         )?;
@@ -98,7 +98,7 @@ where
     chan_end_on_a.verify_not_closed()?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
-    chan_end_on_a.verify_connection_hops_length()?;
+    chan_end_on_a.verify_connection_hops_not_empty()?;
 
     let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
 
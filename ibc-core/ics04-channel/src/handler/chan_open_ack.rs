@@ -4,15 +4,16 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenAck;
 use ibc_core_channel_types::msgs::MsgChannelOpenAck;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::error::ConnectionError;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::multihop::{counterparty_connection_hops, verify_counterparty_membership};
+
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -99,7 +100,7 @@ where
     chan_end_on_a.verify_state_matches(&ChannelState::Init)?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
-    chan_end_on_a.verify_connection_hops_length()?;
+    chan_end_on_a.verify_connection_hops_not_empty()?;
 
     let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
 
@@ -117,19 +118,12 @@ where
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = &chan_end_on_a.counterparty().port_id;
-        let conn_id_on_b = conn_end_on_a
-            .counterparty()
-            .connection_id()
-            .ok_or(ConnectionError::MissingCounterparty)?;
+        let conn_hops_on_b = counterparty_connection_hops(
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            &msg.proof_chan_end_on_b,
+        )?;
 
         let expected_chan_end_on_b = ChannelEnd::new(
             ChannelState::TryOpen,
@@ -137,17 +131,19 @@ where
             // fine to use A's ordering here
             *chan_end_on_a.ordering(),
             Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
-            vec![conn_id_on_b.clone()],
+            conn_hops_on_b,
             msg.version_on_b.clone(),
         )?;
         let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, &msg.chan_id_on_b);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a.verify_membership(
-            prefix_on_b,
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
             &msg.proof_chan_end_on_b,
-            consensus_state_of_b_on_a.root(),
             Path::ChannelEnd(chan_end_path_on_b),
             expected_chan_end_on_b.encode_vec(),
         )?;
@@ -0,0 +1,240 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeAck`.
+use ibc_core_channel_types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeAck;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeAck;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, CounterpartyChannelUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, check_upgrade_compatibility, counterparty_connection_id, counterparty_ids,
+    current_fields, expected_counterparty_chan_end, has_elapsed_on_host, start_flushing,
+    verify_counterparty_membership,
+};
+
+pub fn chan_upgrade_ack_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    let chan_end_on_a =
+        ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+
+    // An aborted upgrade only writes an error receipt, which requires no
+    // further validation.
+    if abort_receipt(ctx_a, &chan_end_on_a, &msg)?.is_some() {
+        return Ok(());
+    }
+
+    module.on_chan_upgrade_ack_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.counterparty_upgrade.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_ack_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if let Some(error_receipt) = abort_receipt(ctx_a, &chan_end_on_a, &msg)? {
+        return abort_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, error_receipt);
+    }
+
+    let extras = module.on_chan_upgrade_ack_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.counterparty_upgrade.fields.version,
+    )?;
+
+    // state changes
+    let chan_end_on_a = {
+        let mut chan_end_on_a = chan_end_on_a;
+
+        // In the crossing hello case, this end may already be flushing.
+        if chan_end_on_a.is_open() {
+            let mut upgrade_on_a = ctx_a
+                .channel_upgrade(&ChannelUpgradePath::new(
+                    &msg.port_id_on_a,
+                    &msg.chan_id_on_a,
+                ))?
+                .ok_or(ChannelError::MissingUpgrade)?;
+
+            start_flushing(
+                ctx_a,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                &mut chan_end_on_a,
+                &mut upgrade_on_a,
+            )?;
+        }
+
+        if !ctx_a.has_inflight_packets(&chan_end_path_on_a)? {
+            chan_end_on_a.set_state(ChannelState::FlushComplete);
+            ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+        }
+
+        ctx_a.store_counterparty_channel_upgrade(
+            &CounterpartyChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+            msg.counterparty_upgrade,
+        )?;
+
+        chan_end_on_a
+    };
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade ack".to_string())?;
+
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+        let core_event = IbcEvent::UpgradeAckChannel(UpgradeAck::new(
+            msg.port_id_on_a,
+            msg.chan_id_on_a,
+            port_id_on_b,
+            chan_id_on_b,
+            chan_end_on_a.upgrade_sequence(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the error receipt to write if the upgrade must be aborted instead
+/// of proceeding with the flushing phase.
+fn abort_receipt<Ctx>(
+    ctx_a: &Ctx,
+    chan_end_on_a: &ChannelEnd,
+    msg: &MsgChannelUpgradeAck,
+) -> Result<Option<ErrorReceipt>, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let upgrade_on_a = ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    if let Err(e) = check_upgrade_compatibility(
+        ctx_a,
+        &upgrade_on_a.fields,
+        &msg.counterparty_upgrade.fields,
+    ) {
+        return Ok(Some(ErrorReceipt::new(
+            chan_end_on_a.upgrade_sequence(),
+            e.to_string(),
+        )));
+    }
+
+    let counterparty_timeout = msg.counterparty_upgrade.timeout;
+
+    if has_elapsed_on_host(ctx_a, &counterparty_timeout)? {
+        return Ok(Some(ErrorReceipt::new(
+            chan_end_on_a.upgrade_sequence(),
+            ChannelError::ElapsedUpgradeTimeout {
+                timeout: counterparty_timeout,
+            }
+            .to_string(),
+        )));
+    }
+
+    Ok(None)
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeAck) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        });
+    }
+
+    ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+        let expected_chan_end_on_b = expected_counterparty_chan_end(
+            ChannelState::Flushing,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            &current_fields(&chan_end_on_a),
+            counterparty_connection_id(&conn_end_on_a)?,
+            chan_end_on_a.upgrade_sequence(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            msg.proof_height_on_b,
+            &msg.proof_chan_end_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(&port_id_on_b, &chan_id_on_b)),
+            expected_chan_end_on_b.encode_vec(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            msg.proof_height_on_b,
+            &msg.proof_upgrade_on_b,
+            Path::ChannelUpgrade(ChannelUpgradePath::new(&port_id_on_b, &chan_id_on_b)),
+            msg.counterparty_upgrade.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeCancel`.
+use ibc_core_channel_types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeCancelled;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeCancel;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{abort_upgrade, counterparty_ids, verify_counterparty_membership};
+
+pub fn chan_upgrade_cancel_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_cancel_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    _module: &mut dyn Module,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_on_a =
+        ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+
+    let upgrade_sequence = if skips_proof(ctx_a, &chan_end_on_a, &msg)? {
+        chan_end_on_a.upgrade_sequence()
+    } else {
+        msg.error_receipt_on_b.sequence
+    };
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade cancel".to_string())?;
+
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+        let core_event = IbcEvent::UpgradeCancelChannel(UpgradeCancelled::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            upgrade_sequence,
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    // state changes
+    let error_receipt = ErrorReceipt::new(upgrade_sequence, "channel upgrade cancelled");
+
+    abort_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, error_receipt)
+}
+
+/// The upgrade authority may cancel an upgrade without proving an error
+/// receipt on the counterparty, as long as this end has not completed
+/// flushing yet.
+fn skips_proof<Ctx>(
+    ctx_a: &Ctx,
+    chan_end_on_a: &ChannelEnd,
+    msg: &MsgChannelUpgradeCancel,
+) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    Ok(ctx_a.is_channel_upgrade_authority(&msg.signer)?
+        && chan_end_on_a.state != ChannelState::FlushComplete)
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeCancel) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    if skips_proof(ctx_a, &chan_end_on_a, msg)? {
+        return Ok(());
+    }
+
+    let (Some(proof_error_receipt_on_b), Some(proof_height_on_b)) =
+        (&msg.proof_error_receipt_on_b, msg.proof_height_on_b)
+    else {
+        return Err(ChannelError::InvalidUpgrade {
+            description: "missing proof of the counterparty error receipt".to_string(),
+        });
+    };
+
+    let receipt_sequence = msg.error_receipt_on_b.sequence;
+    let upgrade_sequence = chan_end_on_a.upgrade_sequence();
+
+    // The error receipt must be for the current upgrade attempt, or a later
+    // one. Once flushing is complete, only an error receipt for the current
+    // attempt is accepted, since the counterparty may already have opened the
+    // upgraded channel.
+    if receipt_sequence < upgrade_sequence
+        || (chan_end_on_a.state == ChannelState::FlushComplete
+            && receipt_sequence != upgrade_sequence)
+    {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: upgrade_sequence,
+            actual: receipt_sequence,
+        });
+    }
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            proof_height_on_b,
+            proof_error_receipt_on_b,
+            Path::ChannelUpgradeError(ChannelUpgradeErrorPath::new(&port_id_on_b, &chan_id_on_b)),
+            msg.error_receipt_on_b.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeConfirm`.
+use ibc_core_channel_types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeConfirm;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeConfirm;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, CounterpartyChannelUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, counterparty_connection_id, counterparty_ids, current_fields,
+    expected_counterparty_chan_end, has_elapsed_on_host, open_upgraded_channel,
+    verify_counterparty_membership,
+};
+
+pub fn chan_upgrade_confirm_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)
+}
+
+pub fn chan_upgrade_confirm_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    if let Some(error_receipt) = abort_receipt(ctx_b, &chan_end_on_b, &msg)? {
+        return abort_upgrade(ctx_b, &msg.port_id_on_b, &msg.chan_id_on_b, error_receipt);
+    }
+
+    // state changes
+    let chan_end_on_b = {
+        let mut chan_end_on_b = chan_end_on_b;
+
+        if !ctx_b.has_inflight_packets(&chan_end_path_on_b)? {
+            chan_end_on_b.set_state(ChannelState::FlushComplete);
+            ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+        }
+
+        ctx_b.store_counterparty_channel_upgrade(
+            &CounterpartyChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b),
+            msg.counterparty_upgrade.clone(),
+        )?;
+
+        chan_end_on_b
+    };
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade confirm".to_string())?;
+
+        let (port_id_on_a, chan_id_on_a) = counterparty_ids(&chan_end_on_b)?;
+        let core_event = IbcEvent::UpgradeConfirmChannel(UpgradeConfirm::new(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            port_id_on_a,
+            chan_id_on_a,
+            chan_end_on_b.upgrade_sequence(),
+            chan_end_on_b.state,
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+    }
+
+    // If both ends are done flushing, the upgrade can be completed right away
+    // on this end.
+    if chan_end_on_b.state == ChannelState::FlushComplete
+        && msg.counterparty_channel_state == ChannelState::FlushComplete
+    {
+        open_upgraded_channel(ctx_b, module, &msg.port_id_on_b, &msg.chan_id_on_b)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the error receipt to write if the upgrade must be aborted because
+/// the counterparty timeout has elapsed.
+fn abort_receipt<Ctx>(
+    ctx_b: &Ctx,
+    chan_end_on_b: &ChannelEnd,
+    msg: &MsgChannelUpgradeConfirm,
+) -> Result<Option<ErrorReceipt>, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let counterparty_timeout = msg.counterparty_upgrade.timeout;
+
+    if has_elapsed_on_host(ctx_b, &counterparty_timeout)? {
+        return Ok(Some(ErrorReceipt::new(
+            chan_end_on_b.upgrade_sequence(),
+            ChannelError::ElapsedUpgradeTimeout {
+                timeout: counterparty_timeout,
+            }
+            .to_string(),
+        )));
+    }
+
+    Ok(None)
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeConfirm) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Flushing)?;
+
+    if !msg.counterparty_channel_state.is_flushing() {
+        return Err(ChannelError::InvalidState {
+            expected: "counterparty channel state to be FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: msg.counterparty_channel_state.to_string(),
+        });
+    }
+
+    ctx_b
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let (port_id_on_a, chan_id_on_a) = counterparty_ids(&chan_end_on_b)?;
+
+        let expected_chan_end_on_a = expected_counterparty_chan_end(
+            msg.counterparty_channel_state,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            &current_fields(&chan_end_on_b),
+            counterparty_connection_id(&conn_end_on_b)?,
+            chan_end_on_b.upgrade_sequence(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_b,
+            &conn_end_on_b,
+            msg.proof_height_on_a,
+            &msg.proof_chan_end_on_a,
+            Path::ChannelEnd(ChannelEndPath::new(&port_id_on_a, &chan_id_on_a)),
+            expected_chan_end_on_a.encode_vec(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_b,
+            &conn_end_on_b,
+            msg.proof_height_on_a,
+            &msg.proof_upgrade_on_a,
+            Path::ChannelUpgrade(ChannelUpgradePath::new(&port_id_on_a, &chan_id_on_a)),
+            msg.counterparty_upgrade.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
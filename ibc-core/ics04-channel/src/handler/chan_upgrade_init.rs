@@ -0,0 +1,146 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeInit`.
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeInit;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeInit;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeTimeout};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, CounterpartyChannelUpgradePath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+use super::upgrade::{counterparty_ids, current_fields, verify_single_hop};
+
+pub fn chan_upgrade_init_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    module.on_chan_upgrade_init_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_init_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let (extras, version) = module.on_chan_upgrade_init_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // state changes
+    let chan_end_on_a = {
+        let mut chan_end_on_a = chan_end_on_a;
+
+        // A previously initiated upgrade, if any, is replaced by this one.
+        ctx_a.delete_channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?;
+        ctx_a.delete_counterparty_channel_upgrade(&CounterpartyChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?;
+
+        chan_end_on_a.set_upgrade_sequence(chan_end_on_a.upgrade_sequence() + 1);
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+        let mut fields = msg.fields.clone();
+        fields.version = version;
+
+        ctx_a.store_channel_upgrade(
+            &ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+            Upgrade::new(fields, UpgradeTimeout::unset(), Sequence::from(0)),
+        )?;
+
+        chan_end_on_a
+    };
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade init".to_string())?;
+
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+        let core_event = IbcEvent::UpgradeInitChannel(UpgradeInit::new(
+            msg.port_id_on_a,
+            msg.chan_id_on_a,
+            port_id_on_b,
+            chan_id_on_b,
+            chan_end_on_a.upgrade_sequence(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeInit) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    // Only the upgrade authority of the host may initiate an upgrade.
+    if !ctx_a.is_channel_upgrade_authority(&msg.signer)? {
+        return Err(ChannelError::UnauthorizedUpgradeSigner(msg.signer.clone()));
+    }
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+
+    verify_single_hop(&chan_end_on_a)?;
+
+    msg.fields.validate_basic()?;
+
+    if msg.fields == current_fields(&chan_end_on_a) {
+        return Err(ChannelError::InvalidUpgrade {
+            description: "proposed upgrade fields are identical to the current channel end"
+                .to_string(),
+        });
+    }
+
+    // The connection the channel is upgraded to must exist and be OPEN.
+    let conn_end_on_a = ctx_a.connection_end(&msg.fields.connection_hops[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    Ok(())
+}
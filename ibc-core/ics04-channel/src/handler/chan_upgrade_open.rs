@@ -0,0 +1,117 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeOpen`.
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeOpen;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    counterparty_connection_id, counterparty_ids, current_fields, expected_counterparty_chan_end,
+    open_upgraded_channel, verify_counterparty_membership,
+};
+
+pub fn chan_upgrade_open_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_open_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    open_upgraded_channel(ctx_a, module, &msg.port_id_on_a, &msg.chan_id_on_a)
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeOpen) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a.verify_state_matches(&ChannelState::FlushComplete)?;
+
+    let upgrade_on_a = ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    let expected_chan_end_on_b = match msg.counterparty_channel_state {
+        // The counterparty has already completed the upgrade, so its channel
+        // end runs on the upgraded parameters.
+        ChannelState::Open => {
+            let upgrade_conn_end_on_a =
+                ctx_a.connection_end(&upgrade_on_a.fields.connection_hops[0])?;
+
+            upgrade_conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+            if msg.counterparty_upgrade_sequence < chan_end_on_a.upgrade_sequence() {
+                return Err(ChannelError::InvalidUpgradeSequence {
+                    expected: chan_end_on_a.upgrade_sequence(),
+                    actual: msg.counterparty_upgrade_sequence,
+                });
+            }
+
+            expected_counterparty_chan_end(
+                ChannelState::Open,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                &upgrade_on_a.fields,
+                counterparty_connection_id(&upgrade_conn_end_on_a)?,
+                msg.counterparty_upgrade_sequence,
+            )?
+        }
+        ChannelState::FlushComplete => expected_counterparty_chan_end(
+            ChannelState::FlushComplete,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            &current_fields(&chan_end_on_a),
+            counterparty_connection_id(&conn_end_on_a)?,
+            chan_end_on_a.upgrade_sequence(),
+        )?,
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "counterparty channel state to be OPEN or FLUSHCOMPLETE".to_string(),
+                actual: state.to_string(),
+            })
+        }
+    };
+
+    // Verify proofs
+    {
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            msg.proof_height_on_b,
+            &msg.proof_chan_end_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(&port_id_on_b, &chan_id_on_b)),
+            expected_chan_end_on_b.encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
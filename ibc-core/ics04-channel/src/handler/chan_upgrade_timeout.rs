@@ -0,0 +1,183 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTimeout`.
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTimedOut;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTimeout;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, counterparty_connection_id, counterparty_ids, verify_counterparty_membership,
+};
+
+pub fn chan_upgrade_timeout_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_timeout_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    _module: &mut dyn Module,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_on_a =
+        ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+    let upgrade_on_a = ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade timeout".to_string())?;
+
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+        let core_event = IbcEvent::UpgradeTimeoutChannel(UpgradeTimedOut::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            chan_end_on_a.upgrade_sequence(),
+            upgrade_on_a.timeout,
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    // state changes
+    let error_receipt = ErrorReceipt::new(
+        chan_end_on_a.upgrade_sequence(),
+        ChannelError::ElapsedUpgradeTimeout {
+            timeout: upgrade_on_a.timeout,
+        }
+        .to_string(),
+    );
+
+    abort_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, error_receipt)
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeTimeout) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !chan_end_on_a.is_flushing() {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to be FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        });
+    }
+
+    let upgrade_on_a = ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Check that the upgrade timeout has elapsed on the counterparty chain
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let timestamp_of_b = client_state_of_b_on_a
+            .timestamp_at_height(client_val_ctx_a, &client_cons_state_path_on_a)?;
+
+        if !upgrade_on_a
+            .timeout
+            .has_elapsed(msg.proof_height_on_b, &timestamp_of_b)
+        {
+            return Err(ChannelError::InvalidUpgrade {
+                description: format!(
+                    "upgrade timeout `{}` has not elapsed on the counterparty",
+                    upgrade_on_a.timeout
+                ),
+            });
+        }
+    }
+
+    let chan_end_on_b = &msg.chan_end_on_b;
+
+    match chan_end_on_b.state {
+        // The counterparty may not have completed flushing, otherwise it could
+        // already be moving to the upgraded channel.
+        ChannelState::FlushComplete => {
+            return Err(ChannelError::InvalidState {
+                expected: "counterparty channel state to not be FLUSHCOMPLETE".to_string(),
+                actual: chan_end_on_b.state.to_string(),
+            });
+        }
+        // A counterparty which already completed the upgrade cannot be timed out.
+        ChannelState::Open => {
+            let upgrade_conn_end_on_a =
+                ctx_a.connection_end(&upgrade_on_a.fields.connection_hops[0])?;
+            let upgraded_hops_on_b = vec![counterparty_connection_id(&upgrade_conn_end_on_a)?];
+
+            if chan_end_on_b.version == upgrade_on_a.fields.version
+                && chan_end_on_b.ordering == upgrade_on_a.fields.ordering
+                && chan_end_on_b.connection_hops == upgraded_hops_on_b
+            {
+                return Err(ChannelError::InvalidUpgrade {
+                    description: "counterparty channel end has already been upgraded".to_string(),
+                });
+            }
+        }
+        _ => {}
+    }
+
+    if chan_end_on_b.upgrade_sequence() < chan_end_on_a.upgrade_sequence() {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: chan_end_on_a.upgrade_sequence(),
+            actual: chan_end_on_b.upgrade_sequence(),
+        });
+    }
+
+    // Verify proofs
+    {
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            msg.proof_height_on_b,
+            &msg.proof_chan_end_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(&port_id_on_b, &chan_id_on_b)),
+            chan_end_on_b.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
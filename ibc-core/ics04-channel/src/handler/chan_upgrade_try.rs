@@ -0,0 +1,247 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTry`.
+use ibc_core_channel_types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTry;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTry;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, UpgradeTimeout};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, check_upgrade_compatibility, counterparty_connection_id, counterparty_ids,
+    current_fields, expected_counterparty_chan_end, start_flushing, verify_counterparty_membership,
+    verify_single_hop,
+};
+
+pub fn chan_upgrade_try_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)?;
+
+    let chan_end_on_b =
+        ctx_b.channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))?;
+
+    // An aborted upgrade only writes an error receipt, which requires no
+    // further validation.
+    if abort_receipt(ctx_b, &chan_end_on_b, &msg)?.is_some() {
+        return Ok(());
+    }
+
+    module.on_chan_upgrade_try_validate(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.counterparty_upgrade_fields.ordering,
+        &msg.proposed_upgrade_connection_hops,
+        &msg.counterparty_upgrade_fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_try_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    if let Some(error_receipt) = abort_receipt(ctx_b, &chan_end_on_b, &msg)? {
+        return abort_upgrade(ctx_b, &msg.port_id_on_b, &msg.chan_id_on_b, error_receipt);
+    }
+
+    let (extras, version) = module.on_chan_upgrade_try_execute(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.counterparty_upgrade_fields.ordering,
+        &msg.proposed_upgrade_connection_hops,
+        &msg.counterparty_upgrade_fields.version,
+    )?;
+
+    // state changes
+    let chan_end_on_b = {
+        let mut chan_end_on_b = chan_end_on_b;
+
+        let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        let mut upgrade_on_b = ctx_b
+            .channel_upgrade(&upgrade_path_on_b)?
+            .ok_or(ChannelError::MissingUpgrade)?;
+        upgrade_on_b.fields.version = version;
+
+        // Catch up with the counterparty if it is ahead of us.
+        if msg.counterparty_upgrade_sequence > chan_end_on_b.upgrade_sequence() {
+            chan_end_on_b.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+        }
+
+        start_flushing(
+            ctx_b,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            &mut chan_end_on_b,
+            &mut upgrade_on_b,
+        )?;
+
+        chan_end_on_b
+    };
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade try".to_string())?;
+
+        let (port_id_on_a, chan_id_on_a) = counterparty_ids(&chan_end_on_b)?;
+        let core_event = IbcEvent::UpgradeTryChannel(UpgradeTry::new(
+            msg.port_id_on_b,
+            msg.chan_id_on_b,
+            port_id_on_a,
+            chan_id_on_a,
+            chan_end_on_b.upgrade_sequence(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_b.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the error receipt to write if the upgrade must be aborted instead
+/// of proceeding to the flushing phase.
+fn abort_receipt<Ctx>(
+    ctx_b: &Ctx,
+    chan_end_on_b: &ChannelEnd,
+    msg: &MsgChannelUpgradeTry,
+) -> Result<Option<ErrorReceipt>, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let upgrade_sequence = chan_end_on_b.upgrade_sequence();
+
+    // The counterparty is behind: the error receipt is written at the
+    // sequence preceding ours, so that the counterparty can cancel its
+    // outdated upgrade and catch up with this end.
+    if msg.counterparty_upgrade_sequence < upgrade_sequence {
+        return Ok(Some(ErrorReceipt::new(
+            upgrade_sequence.saturating_sub(1),
+            ChannelError::InvalidUpgradeSequence {
+                expected: upgrade_sequence,
+                actual: msg.counterparty_upgrade_sequence,
+            }
+            .to_string(),
+        )));
+    }
+
+    let upgrade_on_b = ctx_b
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    if let Err(e) = check_upgrade_compatibility(
+        ctx_b,
+        &upgrade_on_b.fields,
+        &msg.counterparty_upgrade_fields,
+    ) {
+        return Ok(Some(ErrorReceipt::new(
+            upgrade_sequence.max(msg.counterparty_upgrade_sequence),
+            e.to_string(),
+        )));
+    }
+
+    Ok(None)
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeTry) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    msg.verify_connection_hops_length()?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+
+    verify_single_hop(&chan_end_on_b)?;
+
+    // The upgrade must have been initiated on this end as well.
+    let upgrade_on_b = ctx_b
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    if msg.proposed_upgrade_connection_hops != upgrade_on_b.fields.connection_hops {
+        return Err(ChannelError::InvalidUpgrade {
+            description: "proposed connection hops do not match the initiated upgrade".to_string(),
+        });
+    }
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let (port_id_on_a, chan_id_on_a) = counterparty_ids(&chan_end_on_b)?;
+
+        let expected_chan_end_on_a = expected_counterparty_chan_end(
+            ChannelState::Open,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            &current_fields(&chan_end_on_b),
+            counterparty_connection_id(&conn_end_on_b)?,
+            msg.counterparty_upgrade_sequence,
+        )?;
+
+        verify_counterparty_membership(
+            ctx_b,
+            &conn_end_on_b,
+            msg.proof_height_on_a,
+            &msg.proof_chan_end_on_a,
+            Path::ChannelEnd(ChannelEndPath::new(&port_id_on_a, &chan_id_on_a)),
+            expected_chan_end_on_a.encode_vec(),
+        )?;
+
+        let expected_upgrade_on_a = Upgrade::new(
+            msg.counterparty_upgrade_fields.clone(),
+            UpgradeTimeout::unset(),
+            Sequence::from(0),
+        );
+
+        verify_counterparty_membership(
+            ctx_b,
+            &conn_end_on_b,
+            msg.proof_height_on_a,
+            &msg.proof_upgrade_on_a,
+            Path::ChannelUpgrade(ChannelUpgradePath::new(&port_id_on_a, &chan_id_on_a)),
+            expected_upgrade_on_a.encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,365 @@
+//! Verification of the proofs of a channel end's counterparty, which may be
+//! reached through more than one connection hop (ICS-033).
+use core::time::Duration;
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::multihop::MultihopProof;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_connection::delay::{verify_conn_delay_passed, verify_delay_passed};
+use ibc_core_connection::types::error::ConnectionError;
+use ibc_core_connection::types::{ConnectionEnd, State as ConnectionState};
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
+use ibc_core_host::{ClientStateRef, ConsensusStateRef, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+use ibc_primitives::{Timestamp, ToVec};
+
+/// Verifies a proof of the existence of a value at a given path on the
+/// counterparty of a channel end, whose first connection hop is `conn_end`.
+///
+/// Single-hop channels verify the proof against the consensus state of the
+/// connection's client at `proof_height`. Multi-hop channels expect a
+/// [`MultihopProof`], whose intermediate proofs are verified hop by hop
+/// starting from that same consensus state.
+///
+/// All the proofs of a multi-hop channel are verified by the client of its
+/// first hop, so every chain along the route must share the commitment
+/// scheme of the first intermediate chain.
+pub fn verify_counterparty_membership<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+    connection_hops: &[ConnectionId],
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(conn_end.client_id())?;
+
+    if connection_hops.len() <= 1 {
+        client_state.verify_membership_at_height(
+            client_val_ctx,
+            &consensus_state_path(conn_end, proof_height),
+            conn_end.counterparty().prefix(),
+            proof,
+            path,
+            value,
+        )?;
+
+        return Ok(());
+    }
+
+    let multihop_proof = MultihopProof::try_from(proof)?;
+    let counterparty = verify_multihop_proof(
+        ctx,
+        &client_state,
+        conn_end,
+        connection_hops,
+        proof_height,
+        &multihop_proof,
+    )?;
+
+    client_state.verify_membership(
+        &counterparty.prefix,
+        &multihop_proof.key_proof,
+        counterparty.consensus_state.root(),
+        path,
+        value,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies the absence of a value at a given path on the counterparty of a
+/// channel end, whose first connection hop is `conn_end`.
+///
+/// See [`verify_counterparty_membership`] for the expected proofs.
+pub fn verify_counterparty_non_membership<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+    connection_hops: &[ConnectionId],
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+    path: Path,
+) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(conn_end.client_id())?;
+
+    if connection_hops.len() <= 1 {
+        client_state.verify_non_membership_at_height(
+            client_val_ctx,
+            &consensus_state_path(conn_end, proof_height),
+            conn_end.counterparty().prefix(),
+            proof,
+            path,
+        )?;
+
+        return Ok(());
+    }
+
+    let multihop_proof = MultihopProof::try_from(proof)?;
+    let counterparty = verify_multihop_proof(
+        ctx,
+        &client_state,
+        conn_end,
+        connection_hops,
+        proof_height,
+        &multihop_proof,
+    )?;
+
+    client_state.verify_non_membership(
+        &counterparty.prefix,
+        &multihop_proof.key_proof,
+        counterparty.consensus_state.root(),
+        path,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies that the delay period of the connection hops of a channel end,
+/// whose first hop is `conn_end`, has passed since the client of that hop
+/// stored its consensus state at `proof_height`.
+///
+/// The delay period of a multi-hop channel is the largest one among the
+/// connections along the route, as carried by the [`MultihopProof`]. It is
+/// enforced against the client of the first hop, since when the intermediate
+/// chains stored the consensus states of the next ones is not known.
+pub fn verify_counterparty_delay_passed<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+    connection_hops: &[ConnectionId],
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    if connection_hops.len() <= 1 {
+        verify_conn_delay_passed(ctx, proof_height, conn_end)?;
+
+        return Ok(());
+    }
+
+    let multihop_proof = MultihopProof::try_from(proof)?;
+    verify_connection_hops_len(connection_hops, &multihop_proof)?;
+
+    let delay_period = multihop_proof
+        .connection_proofs
+        .iter()
+        .map(|connection_proof| connection_proof.connection_end.delay_period())
+        .fold(conn_end.delay_period(), Duration::max);
+
+    verify_delay_passed(ctx, proof_height, conn_end.client_id(), delay_period)?;
+
+    Ok(())
+}
+
+/// Returns the height and timestamp of the counterparty chain at which the
+/// given proof was produced.
+///
+/// For a single-hop channel, this is the proof height and the timestamp of
+/// the consensus state stored at that height. For a multi-hop channel, it is
+/// the height and timestamp of the counterparty's consensus state proven by
+/// the last intermediate chain.
+pub fn counterparty_height_and_timestamp<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+    connection_hops: &[ConnectionId],
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+) -> Result<(Height, Timestamp), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(conn_end.client_id())?;
+
+    if connection_hops.len() <= 1 {
+        let timestamp = client_state.timestamp_at_height(
+            client_val_ctx,
+            &consensus_state_path(conn_end, proof_height),
+        )?;
+
+        return Ok((proof_height, timestamp));
+    }
+
+    let multihop_proof = MultihopProof::try_from(proof)?;
+    let counterparty = verify_multihop_proof(
+        ctx,
+        &client_state,
+        conn_end,
+        connection_hops,
+        proof_height,
+        &multihop_proof,
+    )?;
+
+    Ok((
+        counterparty.consensus_height,
+        counterparty.consensus_state.timestamp()?,
+    ))
+}
+
+/// Returns the connection hops of the counterparty channel end, from the
+/// counterparty's point of view.
+///
+/// The connection identifiers of a multi-hop channel's counterparty are
+/// taken from the connection ends carried by the [`MultihopProof`], which are
+/// verified along with it.
+pub fn counterparty_connection_hops(
+    conn_end: &ConnectionEnd,
+    connection_hops: &[ConnectionId],
+    proof: &CommitmentProofBytes,
+) -> Result<Vec<ConnectionId>, ChannelError> {
+    let first_counterparty_hop = conn_end
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::MissingCounterparty)?
+        .clone();
+
+    if connection_hops.len() <= 1 {
+        return Ok(vec![first_counterparty_hop]);
+    }
+
+    let multihop_proof = MultihopProof::try_from(proof)?;
+    verify_connection_hops_len(connection_hops, &multihop_proof)?;
+
+    let mut counterparty_hops = vec![first_counterparty_hop];
+    for connection_proof in &multihop_proof.connection_proofs {
+        counterparty_hops.push(
+            connection_proof
+                .connection_end
+                .counterparty()
+                .connection_id()
+                .ok_or(ConnectionError::MissingCounterparty)?
+                .clone(),
+        );
+    }
+    counterparty_hops.reverse();
+
+    Ok(counterparty_hops)
+}
+
+/// The state of the counterparty chain proven by a [`MultihopProof`].
+struct ProvenCounterparty<Ctx: ValidationContext> {
+    consensus_height: Height,
+    consensus_state: ConsensusStateRef<Ctx>,
+    prefix: CommitmentPrefix,
+}
+
+/// Verifies the connection and consensus proofs of a [`MultihopProof`], and
+/// returns the proven consensus state of the counterparty chain.
+///
+/// Starting from the consensus state of the first intermediate chain stored
+/// by the client of `conn_end` at `proof_height`, each intermediate chain
+/// proves its open connection end of the next hop, and the consensus state of
+/// the next chain that the client of that connection stores. The proofs are
+/// verified by the client of the first hop, which assumes that all the chains
+/// along the route share its commitment scheme.
+fn verify_multihop_proof<Ctx>(
+    ctx: &Ctx,
+    client_state: &ClientStateRef<Ctx>,
+    conn_end: &ConnectionEnd,
+    connection_hops: &[ConnectionId],
+    proof_height: Height,
+    multihop_proof: &MultihopProof,
+) -> Result<ProvenCounterparty<Ctx>, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    verify_connection_hops_len(connection_hops, multihop_proof)?;
+
+    let client_val_ctx = ctx.get_client_validation_context();
+
+    let mut counterparty = ProvenCounterparty::<Ctx> {
+        consensus_height: proof_height,
+        consensus_state: client_val_ctx
+            .consensus_state(&consensus_state_path(conn_end, proof_height))?,
+        prefix: conn_end.counterparty().prefix().clone(),
+    };
+
+    let hops = connection_hops
+        .iter()
+        .skip(1)
+        .zip(&multihop_proof.connection_proofs)
+        .zip(&multihop_proof.consensus_proofs);
+
+    for ((conn_id, connection_proof), consensus_proof) in hops {
+        let hop_conn_end = &connection_proof.connection_end;
+
+        hop_conn_end.verify_state_matches(&ConnectionState::Open)?;
+
+        client_state.verify_membership(
+            &counterparty.prefix,
+            &connection_proof.proof,
+            counterparty.consensus_state.root(),
+            Path::Connection(ConnectionPath::new(conn_id)),
+            hop_conn_end.clone().encode_vec(),
+        )?;
+
+        let hop_consensus_state_path = ClientConsensusStatePath::new(
+            hop_conn_end.client_id().clone(),
+            consensus_proof.consensus_height.revision_number(),
+            consensus_proof.consensus_height.revision_height(),
+        );
+
+        client_state.verify_membership(
+            &counterparty.prefix,
+            &consensus_proof.proof,
+            counterparty.consensus_state.root(),
+            Path::ClientConsensusState(hop_consensus_state_path),
+            consensus_proof.consensus_state.to_vec(),
+        )?;
+
+        counterparty = ProvenCounterparty {
+            consensus_height: consensus_proof.consensus_height,
+            consensus_state: ConsensusStateRef::<Ctx>::try_from(
+                consensus_proof.consensus_state.clone(),
+            )
+            .map_err(|_| ChannelError::InvalidMultihopProof {
+                description: format!(
+                    "unsupported consensus state `{}` on connection `{conn_id}`",
+                    consensus_proof.consensus_state.type_url
+                ),
+            })?,
+            prefix: hop_conn_end.counterparty().prefix().clone(),
+        };
+    }
+
+    Ok(counterparty)
+}
+
+fn verify_connection_hops_len(
+    connection_hops: &[ConnectionId],
+    multihop_proof: &MultihopProof,
+) -> Result<(), ChannelError> {
+    if multihop_proof.connection_hops_len() != connection_hops.len() {
+        return Err(ChannelError::InvalidMultihopProof {
+            description: format!(
+                "proof goes through {} connection hops, expected {}",
+                multihop_proof.connection_hops_len(),
+                connection_hops.len()
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+fn consensus_state_path(conn_end: &ConnectionEnd, height: Height) -> ClientConsensusStatePath {
+    ClientConsensusStatePath::new(
+        conn_end.client_id().clone(),
+        height.revision_number(),
+        height.revision_height(),
+    )
+}
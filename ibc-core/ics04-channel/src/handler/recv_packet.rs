@@ -1,14 +1,14 @@
-use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::channel::{Counterparty, Order};
 use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::ReceivePacket;
 use ibc_core_channel_types::msgs::MsgRecvPacket;
+use ibc_core_channel_types::packet::{Packet, Receipt};
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
+    AckPath, ChannelEndPath, CommitmentPath, CounterpartyChannelUpgradePath, Path, ReceiptPath,
     SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
@@ -19,6 +19,7 @@ use super::acknowledgement::{
     commit_packet_acknowledgment, commit_packet_sequence_number_with_chan_end,
     emit_packet_acknowledgement_event_with_chan_end,
 };
+use super::multihop::{verify_counterparty_delay_passed, verify_counterparty_membership};
 
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ChannelError>
 where
@@ -55,7 +56,7 @@ where
                     ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
                 ctx_b.get_packet_receipt(&receipt_path_on_b)?.is_ok()
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -71,13 +72,54 @@ where
         }
     }
 
+    // A late packet on an `ORDERED_ALLOW_TIMEOUT` channel is skipped: the
+    // application never sees it, and the timeout receipt lets the sending end
+    // prove the timeout.
+    if chan_end_on_b.ordering == Order::OrderedAllowTimeout && has_timed_out(ctx_b, &msg.packet)? {
+        let seq_recv_path_on_b =
+            SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+        ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, msg.packet.seq_on_a.increment())?;
+
+        let receipt_path_on_b = ReceiptPath::new(
+            &msg.packet.port_id_on_b,
+            &msg.packet.chan_id_on_b,
+            msg.packet.seq_on_a,
+        );
+        ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Timeout)?;
+
+        // The receive event lets relayers know that the packet was handled,
+        // so that they can prove its timeout to the sending end.
+        ctx_b.log_message("success: packet timed out on receipt".to_string())?;
+
+        let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
+            msg.packet,
+            chan_end_on_b.ordering,
+            conn_id_on_b.clone(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+
+        return Ok(());
+    }
+
     let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
 
     // state changes
     commit_packet_sequence_number_with_chan_end(ctx_b, &chan_end_on_b, &msg.packet)?;
 
-    if let Some(acknowledgement) = acknowledgement.as_ref() {
-        commit_packet_acknowledgment(ctx_b, &msg.packet, acknowledgement)?;
+    match acknowledgement.as_ref() {
+        Some(acknowledgement) => commit_packet_acknowledgment(ctx_b, &msg.packet, acknowledgement)?,
+        // The module is to write the acknowledgement later on, through
+        // `write_acknowledgement`.
+        None => {
+            let ack_path_on_b = AckPath::new(
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+                msg.packet.seq_on_a,
+            );
+            ctx_b.store_pending_acknowledgement(&ack_path_on_b)?;
+        }
     }
 
     // emit events and logs
@@ -127,7 +169,33 @@ where
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
-    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+    // Packets are still received while the channel end flushes in-flight
+    // packets during an upgrade.
+    if !chan_end_on_b.is_open() && !chan_end_on_b.is_flushing() {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to be OPEN, FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_b.state.to_string(),
+        });
+    }
+
+    if chan_end_on_b.is_flushing() {
+        let counterparty_upgrade_path_on_b =
+            CounterpartyChannelUpgradePath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+
+        // Only packets sent before the counterparty started flushing may be received.
+        if let Some(counterparty_upgrade) =
+            ctx_b.counterparty_channel_upgrade(&counterparty_upgrade_path_on_b)?
+        {
+            if msg.packet.seq_on_a >= counterparty_upgrade.next_sequence_send {
+                return Err(ChannelError::InvalidUpgrade {
+                    description: format!(
+                        "cannot receive packet `{}` sent after the counterparty started flushing at `{}`",
+                        msg.packet.seq_on_a, counterparty_upgrade.next_sequence_send
+                    ),
+                });
+            }
+        }
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_a.clone(),
@@ -141,21 +209,26 @@ where
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
-    let latest_height = ctx_b.host_height()?;
-    if msg.packet.timeout_height_on_b.has_expired(latest_height) {
-        return Err(ChannelError::InsufficientPacketHeight {
-            chain_height: latest_height,
-            timeout_height: msg.packet.timeout_height_on_b,
-        });
-    }
+    // Late packets are still received on an `ORDERED_ALLOW_TIMEOUT` channel,
+    // only to be skipped over.
+    let packet_timed_out = has_timed_out(ctx_b, &msg.packet)?;
+    if chan_end_on_b.ordering != Order::OrderedAllowTimeout {
+        let latest_height = ctx_b.host_height()?;
+        if msg.packet.timeout_height_on_b.has_expired(latest_height) {
+            return Err(ChannelError::InsufficientPacketHeight {
+                chain_height: latest_height,
+                timeout_height: msg.packet.timeout_height_on_b,
+            });
+        }
 
-    let latest_timestamp = ctx_b.host_timestamp()?;
-    if msg
-        .packet
-        .timeout_timestamp_on_b
-        .has_expired(&latest_timestamp)
-    {
-        return Err(ChannelError::ExpiredPacketTimestamp);
+        let latest_timestamp = ctx_b.host_timestamp()?;
+        if msg
+            .packet
+            .timeout_timestamp_on_b
+            .has_expired(&latest_timestamp)
+        {
+            return Err(ChannelError::ExpiredPacketTimestamp);
+        }
     }
 
     // Verify proofs
@@ -170,15 +243,6 @@ where
 
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
         let expected_commitment_on_a = compute_packet_commitment(
             &msg.packet.data,
             &msg.packet.timeout_height_on_b,
@@ -190,20 +254,28 @@ where
             msg.packet.seq_on_a,
         );
 
-        verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
+        verify_counterparty_delay_passed(
+            ctx_b,
+            &conn_end_on_b,
+            chan_end_on_b.connection_hops(),
+            msg.proof_height_on_a,
+            &msg.proof_commitment_on_a,
+        )?;
 
         // Verify the proof for the packet against the chain store.
-        client_state_of_a_on_b.verify_membership(
-            conn_end_on_b.counterparty().prefix(),
+        verify_counterparty_membership(
+            ctx_b,
+            &conn_end_on_b,
+            chan_end_on_b.connection_hops(),
+            msg.proof_height_on_a,
             &msg.proof_commitment_on_a,
-            consensus_state_of_a_on_b.root(),
             Path::Commitment(commitment_path_on_a),
             expected_commitment_on_a.into_vec(),
         )?;
     }
 
     match chan_end_on_b.ordering {
-        Order::Ordered => {
+        Order::Ordered | Order::OrderedAllowTimeout => {
             let seq_recv_path_on_b =
                 SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
             let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -214,7 +286,7 @@ where
                 });
             }
 
-            if msg.packet.seq_on_a == next_seq_recv {
+            if msg.packet.seq_on_a == next_seq_recv && !packet_timed_out {
                 // Case where the recvPacket is successful and an
                 // acknowledgement will be written (not a no-op)
                 validate_write_acknowledgement(ctx_b, msg)?;
@@ -253,3 +325,12 @@ where
 
     Ok(())
 }
+
+/// Checks whether the packet timed out according to the host's latest height
+/// and timestamp.
+fn has_timed_out<Ctx>(ctx_b: &Ctx, packet: &Packet) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    Ok(packet.timed_out(&ctx_b.host_timestamp()?, ctx_b.host_height()?))
+}
@@ -0,0 +1,151 @@
+//! Detection of redundant relay messages, i.e. messages that another relayer
+//! already got processed and that would execute as no-ops.
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::msgs::PacketMsg;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::v2::msgs::PacketMsg as PacketMsgV2;
+use ibc_core_channel_types::v2::packet::Packet as PacketV2;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_host::types::error::HostError;
+use ibc_core_host::types::path::{
+    v2 as path_v2, ChannelEndPath, CommitmentPath, ReceiptPath, SeqRecvPath,
+};
+use ibc_core_host::ValidationContext;
+
+/// Checks whether a transaction made of `msgs` only relays redundant
+/// messages, so that hosts can drop it before execution (e.g. in the
+/// mempool), in the same way as ibc-go's `RedundantRelayDecorator`.
+///
+/// Packet receipts, acknowledgements, timeouts and client updates, of both
+/// IBC v1 and v2, are the relay messages taken into account. Returns
+/// [`ChannelError::RedundantRelay`] if the transaction contains at least one
+/// of them and all of them are redundant. Other messages are skipped.
+pub fn check_redundancy<Ctx>(ctx: &Ctx, msgs: &[MsgEnvelope]) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let mut relays_any = false;
+
+    for msg in msgs {
+        match is_redundant(ctx, msg)? {
+            Some(true) => relays_any = true,
+            Some(false) => return Ok(()),
+            None => continue,
+        }
+    }
+
+    if relays_any {
+        return Err(ChannelError::RedundantRelay);
+    }
+
+    Ok(())
+}
+
+/// Returns whether the relay message `msg` would execute as a no-op, or
+/// `None` if `msg` is not a relay message.
+///
+/// A received packet is redundant if it was already received, while an
+/// acknowledged or timed out packet is redundant if its commitment was
+/// already deleted, i.e. if the host reports it as missing. A client update
+/// is redundant if the client reports it as such through
+/// [`ClientStateValidation::is_redundant_update`].
+pub fn is_redundant<Ctx>(ctx: &Ctx, msg: &MsgEnvelope) -> Result<Option<bool>, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let redundant = match msg {
+        MsgEnvelope::Packet(PacketMsg::Recv(msg)) => is_packet_received(ctx, &msg.packet)?,
+        MsgEnvelope::Packet(PacketMsg::Ack(msg)) => !has_packet_commitment(ctx, &msg.packet)?,
+        MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => !has_packet_commitment(ctx, &msg.packet)?,
+        MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(msg)) => {
+            !has_packet_commitment(ctx, &msg.packet)?
+        }
+        MsgEnvelope::PacketV2(PacketMsgV2::Recv(msg)) => is_packet_v2_received(ctx, &msg.packet)?,
+        MsgEnvelope::PacketV2(PacketMsgV2::Ack(msg)) => {
+            !has_packet_v2_commitment(ctx, &msg.packet)?
+        }
+        MsgEnvelope::PacketV2(PacketMsgV2::Timeout(msg)) => {
+            !has_packet_v2_commitment(ctx, &msg.packet)?
+        }
+        MsgEnvelope::Client(ClientMsg::UpdateClient(msg)) => is_redundant_update(ctx, msg)?,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(redundant))
+}
+
+fn is_packet_received<Ctx>(ctx_b: &Ctx, packet: &Packet) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    let received = match chan_end_on_b.ordering {
+        Order::None => false,
+        Order::Unordered => {
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+            ctx_b.get_packet_receipt(&receipt_path_on_b)?.is_ok()
+        }
+        Order::Ordered | Order::OrderedAllowTimeout => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            packet.seq_on_a < ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?
+        }
+    };
+
+    Ok(received)
+}
+
+fn has_packet_commitment<Ctx>(ctx_a: &Ctx, packet: &Packet) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let commitment_path_on_a =
+        CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+    match ctx_a.get_packet_commitment(&commitment_path_on_a) {
+        Ok(_) => Ok(true),
+        Err(HostError::MissingState { .. }) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn is_packet_v2_received<Ctx>(ctx_b: &Ctx, packet: &PacketV2) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let receipt_path_on_b = path_v2::ReceiptPath::new(&packet.destination_client, packet.sequence);
+
+    Ok(ctx_b.get_packet_receipt_v2(&receipt_path_on_b)?.is_ok())
+}
+
+fn has_packet_v2_commitment<Ctx>(ctx_a: &Ctx, packet: &PacketV2) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let commitment_path_on_a = path_v2::CommitmentPath::new(&packet.source_client, packet.sequence);
+
+    match ctx_a.get_packet_commitment_v2(&commitment_path_on_a) {
+        Ok(_) => Ok(true),
+        Err(HostError::MissingState { .. }) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn is_redundant_update<Ctx>(ctx: &Ctx, msg: &MsgUpdateClient) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(&msg.client_id)?;
+
+    Ok(client_state.is_redundant_update(
+        client_val_ctx,
+        &msg.client_id,
+        msg.client_message.clone(),
+    )?)
+}
@@ -8,6 +8,7 @@ use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
+use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
 use crate::context::{SendPacketExecutionContext, SendPacketValidationContext};
@@ -23,6 +24,22 @@ pub fn send_packet(
     send_packet_execute(ctx_a, packet)
 }
 
+/// Send a packet of an application through the module stack bound to its
+/// port, so that the middleware wrapping the application gets to process it
+/// before it is committed.
+///
+/// The packet returned by the stack is the one sent, and is validated again
+/// since middleware may modify it.
+pub fn send_module_packet(
+    ctx_a: &mut impl SendPacketExecutionContext,
+    module: &mut dyn Module,
+    packet: Packet,
+) -> Result<(), ChannelError> {
+    let packet = module.send_packet(packet)?;
+
+    send_packet(ctx_a, packet)
+}
+
 /// Validate that sending the given packet would succeed.
 pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
@@ -39,6 +56,15 @@ pub fn send_packet_validate(
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
 
+    // No new packets may be sent while in-flight packets are flushed during
+    // a channel upgrade.
+    if chan_end_on_a.is_flushing() {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to not be FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        });
+    }
+
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
         Some(packet.chan_id_on_b.clone()),
@@ -62,7 +88,11 @@ pub fn send_packet_validate(
 
     let latest_height_on_a = client_state_of_b_on_a.latest_height();
 
-    if packet.timeout_height_on_b.has_expired(latest_height_on_a) {
+    // The client of a multi-hop channel's first hop tracks an intermediate
+    // chain, whose heights say nothing of the destination's. The timeout
+    // height is then only checked against the destination's height proven on
+    // timeout, while timestamps are comparable across chains.
+    if !chan_end_on_a.is_multihop() && packet.timeout_height_on_b.has_expired(latest_height_on_a) {
         return Err(ChannelError::InsufficientPacketHeight {
             chain_height: latest_height_on_a,
             timeout_height: packet.timeout_height_on_b,
@@ -74,9 +104,8 @@ pub fn send_packet_validate(
         latest_height_on_a.revision_number(),
         latest_height_on_a.revision_height(),
     );
-    let consensus_state_of_b_on_a =
-        client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-    let latest_timestamp = consensus_state_of_b_on_a.timestamp()?;
+    let latest_timestamp = client_state_of_b_on_a
+        .timestamp_at_height(client_val_ctx_a, &client_cons_state_path_on_a)?;
     let packet_timestamp = packet.timeout_timestamp_on_b;
     if packet_timestamp.has_expired(&latest_timestamp) {
         return Err(ChannelError::ExpiredPacketTimestamp);
@@ -3,17 +3,23 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+    ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqAckPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::multihop::{
+    counterparty_height_and_timestamp, verify_counterparty_delay_passed,
+    verify_counterparty_membership, verify_counterparty_non_membership,
+};
 use super::timeout_on_close;
+use super::upgrade::{abort_upgrade, handle_flush_state};
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -81,13 +87,44 @@ where
     let chan_end_on_a = {
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
+        let is_flushing = chan_end_on_a.state == State::Flushing;
+
+        // Timeouts on a closed channel may be relayed in any order, so the
+        // acknowledgement sequence is only tracked while it is still open.
+        if chan_end_on_a.ordering == Order::OrderedAllowTimeout
+            && chan_end_on_a.state != State::Closed
+        {
+            // Note: in validation, we verified that `packet.seq_on_a == nextSeqAck`
+            let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+            ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, packet.seq_on_a.increment())?;
+        }
+
         if let Order::Ordered = chan_end_on_a.ordering {
-            let mut chan_end_on_a = chan_end_on_a;
+            // A timeout on an ordered channel closes it, which also aborts
+            // any upgrade in progress.
+            if is_flushing {
+                let error_receipt = ErrorReceipt::new(
+                    chan_end_on_a.upgrade_sequence(),
+                    "packet timed out on an ordered channel during an upgrade",
+                );
+                abort_upgrade(
+                    ctx_a,
+                    &packet.port_id_on_a,
+                    &packet.chan_id_on_a,
+                    error_receipt,
+                )?;
+            }
+
+            let mut chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
             chan_end_on_a.state = State::Closed;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
             chan_end_on_a
         } else {
+            if is_flushing {
+                handle_flush_state(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+            }
+
             chan_end_on_a
         }
     };
@@ -134,7 +171,14 @@ where
         &msg.packet.chan_id_on_a,
     ))?;
 
-    chan_end_on_a.verify_state_matches(&State::Open)?;
+    // Timeouts are still processed while the channel end flushes in-flight
+    // packets during an upgrade.
+    if !matches!(chan_end_on_a.state, State::Open | State::Flushing) {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        });
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_b.clone(),
@@ -173,6 +217,19 @@ where
         });
     }
 
+    // Timeouts on an `ORDERED_ALLOW_TIMEOUT` channel are processed in order,
+    // just like acknowledgements.
+    if let Order::OrderedAllowTimeout = chan_end_on_a.ordering {
+        let seq_ack_path_on_a = SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+        let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
+        if msg.packet.seq_on_a != next_seq_ack {
+            return Err(ChannelError::MismatchedPacketSequence {
+                actual: msg.packet.seq_on_a,
+                expected: next_seq_ack,
+            });
+        }
+    }
+
     // Verify proofs
     {
         let client_id_on_a = conn_end_on_a.client_id();
@@ -186,25 +243,30 @@ where
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
         // check that timeout height or timeout timestamp has passed on the other end
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let timestamp_of_b = consensus_state_of_b_on_a.timestamp()?;
-
-        if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
+        let (height_of_b, timestamp_of_b) = counterparty_height_and_timestamp(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
+            &msg.proof_unreceived_on_b,
+        )?;
+
+        if !msg.packet.timed_out(&timestamp_of_b, height_of_b) {
             return Err(ChannelError::InsufficientPacketTimeout {
                 timeout_height: msg.packet.timeout_height_on_b,
-                chain_height: msg.proof_height_on_b,
+                chain_height: height_of_b,
                 timeout_timestamp: msg.packet.timeout_timestamp_on_b,
                 chain_timestamp: timestamp_of_b,
             });
         }
 
-        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
+        verify_counterparty_delay_passed(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
+            &msg.proof_unreceived_on_b,
+        )?;
 
         let next_seq_recv_verification_result = match chan_end_on_a.ordering {
             Order::Ordered => {
@@ -217,14 +279,55 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
+                verify_counterparty_membership(
+                    ctx_a,
+                    &conn_end_on_a,
+                    chan_end_on_a.connection_hops(),
+                    msg.proof_height_on_b,
                     &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
                     msg.packet.seq_on_a.to_vec(),
                 )
             }
+            Order::OrderedAllowTimeout if msg.packet.seq_on_a >= msg.next_seq_recv_on_b => {
+                // The packet was not received yet, and never will be since
+                // it timed out.
+                let seq_recv_path_on_b =
+                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+
+                verify_counterparty_membership(
+                    ctx_a,
+                    &conn_end_on_a,
+                    chan_end_on_a.connection_hops(),
+                    msg.proof_height_on_b,
+                    &msg.proof_unreceived_on_b,
+                    Path::SeqRecv(seq_recv_path_on_b),
+                    msg.next_seq_recv_on_b.to_vec(),
+                )
+            }
+            Order::OrderedAllowTimeout => {
+                // The counterparty skipped over the packet, leaving a
+                // timeout receipt behind.
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+                let timeout_receipt = Receipt::Timeout
+                    .as_bytes()
+                    .map(<[u8]>::to_vec)
+                    .unwrap_or_default();
+
+                verify_counterparty_membership(
+                    ctx_a,
+                    &conn_end_on_a,
+                    chan_end_on_a.connection_hops(),
+                    msg.proof_height_on_b,
+                    &msg.proof_unreceived_on_b,
+                    Path::Receipt(receipt_path_on_b),
+                    timeout_receipt,
+                )
+            }
             Order::Unordered => {
                 let receipt_path_on_b = ReceiptPath::new(
                     &msg.packet.port_id_on_b,
@@ -232,10 +335,12 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
+                verify_counterparty_non_membership(
+                    ctx_a,
+                    &conn_end_on_a,
+                    chan_end_on_a.connection_hops(),
+                    msg.proof_height_on_b,
                     &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::Receipt(receipt_path_on_b),
                 )
             }
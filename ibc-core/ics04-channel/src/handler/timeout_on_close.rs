@@ -3,15 +3,16 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
-use ibc_core_connection::types::error::ConnectionError;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqRecvPath};
 use ibc_core_host::ValidationContext;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::multihop::{
+    counterparty_connection_hops, verify_counterparty_delay_passed, verify_counterparty_membership,
+    verify_counterparty_non_membership,
+};
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ChannelError>
 where
     Ctx: ValidationContext,
@@ -71,24 +72,16 @@ where
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
         let chan_id_on_b = chan_end_on_a
             .counterparty()
             .channel_id()
             .ok_or(ChannelError::MissingCounterparty)?;
-        let conn_id_on_b = conn_end_on_a
-            .counterparty()
-            .connection_id()
-            .ok_or(ConnectionError::MissingCounterparty)?;
-        let expected_conn_hops_on_b = vec![conn_id_on_b.clone()];
+        let expected_conn_hops_on_b = counterparty_connection_hops(
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            &msg.proof_close_on_b,
+        )?;
         let expected_counterparty = Counterparty::new(
             packet.port_id_on_a.clone(),
             Some(packet.chan_id_on_a.clone()),
@@ -105,18 +98,26 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a.verify_membership(
-            prefix_on_b,
+        verify_counterparty_membership(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
             &msg.proof_close_on_b,
-            consensus_state_of_b_on_a.root(),
             Path::ChannelEnd(chan_end_path_on_b),
             expected_chan_end_on_b.encode_vec(),
         )?;
 
-        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
+        verify_counterparty_delay_passed(
+            ctx_a,
+            &conn_end_on_a,
+            chan_end_on_a.connection_hops(),
+            msg.proof_height_on_b,
+            &msg.proof_unreceived_on_b,
+        )?;
 
         let next_seq_recv_verification_result = match chan_end_on_a.ordering {
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 if packet.seq_on_a < msg.next_seq_recv_on_b {
                     return Err(ChannelError::MismatchedPacketSequence {
                         actual: packet.seq_on_a,
@@ -126,10 +127,12 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
+                verify_counterparty_membership(
+                    ctx_a,
+                    &conn_end_on_a,
+                    chan_end_on_a.connection_hops(),
+                    msg.proof_height_on_b,
                     &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
                     packet.seq_on_a.to_vec(),
                 )
@@ -141,10 +144,12 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
+                verify_counterparty_non_membership(
+                    ctx_a,
+                    &conn_end_on_a,
+                    chan_end_on_a.connection_hops(),
+                    msg.proof_height_on_b,
                     &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::Receipt(receipt_path_on_b),
                 )
             }
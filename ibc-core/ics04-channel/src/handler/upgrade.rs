@@ -0,0 +1,395 @@
+//! Helper functions shared by the handlers of the channel upgrade handshake.
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{UpgradeError, UpgradeOpen};
+use ibc_core_channel_types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection::types::error::ConnectionError;
+use ibc_core_connection::types::{ConnectionEnd, State as ConnectionState};
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath,
+    CounterpartyChannelUpgradePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+/// Returns the identifiers of the counterparty channel end.
+pub(super) fn counterparty_ids(chan_end: &ChannelEnd) -> Result<(PortId, ChannelId), ChannelError> {
+    let counterparty = chan_end.counterparty();
+    let chan_id = counterparty
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    Ok((counterparty.port_id().clone(), chan_id.clone()))
+}
+
+/// Returns the identifier of the connection end on the counterparty chain.
+pub(super) fn counterparty_connection_id(
+    conn_end: &ConnectionEnd,
+) -> Result<ConnectionId, ChannelError> {
+    Ok(conn_end
+        .counterparty()
+        .connection_id()
+        .ok_or(ConnectionError::MissingCounterparty)?
+        .clone())
+}
+
+/// Returns the channel end the counterparty is expected to have stored,
+/// given the channel fields it has been configured with.
+pub(super) fn expected_counterparty_chan_end(
+    state: State,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    fields: &UpgradeFields,
+    counterparty_conn_id: ConnectionId,
+    upgrade_sequence: u64,
+) -> Result<ChannelEnd, ChannelError> {
+    Ok(ChannelEnd::new(
+        state,
+        fields.ordering,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![counterparty_conn_id],
+        fields.version.clone(),
+    )?
+    .with_upgrade_sequence(upgrade_sequence))
+}
+
+/// Checks that the channel end reaches its counterparty through a single
+/// connection hop, as multi-hop channels cannot be upgraded.
+pub(super) fn verify_single_hop(chan_end: &ChannelEnd) -> Result<(), ChannelError> {
+    if chan_end.is_multihop() {
+        return Err(ChannelError::InvalidUpgrade {
+            description: format!(
+                "multi-hop channels cannot be upgraded, channel goes through {} connection hops",
+                chan_end.connection_hops().len()
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the fields of the given channel end that an upgrade may change.
+pub(super) fn current_fields(chan_end: &ChannelEnd) -> UpgradeFields {
+    UpgradeFields::new(
+        *chan_end.ordering(),
+        chan_end.connection_hops().clone(),
+        chan_end.version().clone(),
+    )
+}
+
+/// Verifies that the given value is stored at `path` on the counterparty
+/// chain, as tracked by the client of `conn_end` at `proof_height`.
+///
+/// The counterparty is the chain at the other end of `conn_end`, since only
+/// single-hop channels can be upgraded, as checked by [`verify_single_hop`]
+/// when the upgrade starts.
+pub(super) fn verify_counterparty_membership<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let client_id = conn_end.client_id();
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(client_id)?;
+
+    client_state
+        .status(ctx.get_client_validation_context(), client_id)?
+        .verify_is_active()?;
+
+    client_state.validate_proof_height(proof_height)?;
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        proof_height.revision_number(),
+        proof_height.revision_height(),
+    );
+
+    client_state.verify_membership_at_height(
+        client_val_ctx,
+        &client_cons_state_path,
+        conn_end.counterparty().prefix(),
+        proof,
+        path,
+        value,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the upgrade proposed on this end is compatible with the
+/// upgrade proposed by the counterparty.
+pub(super) fn check_upgrade_compatibility<Ctx>(
+    ctx: &Ctx,
+    proposed_fields: &UpgradeFields,
+    counterparty_fields: &UpgradeFields,
+) -> Result<(), ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    if proposed_fields.ordering != counterparty_fields.ordering {
+        return Err(ChannelError::InvalidUpgrade {
+            description: format!(
+                "proposed ordering `{}` does not match counterparty ordering `{}`",
+                proposed_fields.ordering, counterparty_fields.ordering
+            ),
+        });
+    }
+
+    if proposed_fields.version != counterparty_fields.version {
+        return Err(ChannelError::InvalidUpgrade {
+            description: format!(
+                "proposed version `{}` does not match counterparty version `{}`",
+                proposed_fields.version, counterparty_fields.version
+            ),
+        });
+    }
+
+    let conn_end = ctx.connection_end(&proposed_fields.connection_hops[0])?;
+
+    conn_end.verify_state_matches(&ConnectionState::Open)?;
+
+    let counterparty_conn_id = counterparty_connection_id(&conn_end)?;
+
+    if counterparty_fields.connection_hops.first() != Some(&counterparty_conn_id) {
+        return Err(ChannelError::InvalidUpgrade {
+            description: format!(
+                "counterparty connection hops do not match the counterparty of the proposed connection `{counterparty_conn_id}`"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Computes the absolute upgrade timeout from the relative timeout
+/// configured on the host.
+///
+/// Note: Only the timestamp of the configured timeout is taken into account,
+/// as block heights are not predictable across chains.
+pub(super) fn absolute_upgrade_timeout<Ctx>(ctx: &Ctx) -> Result<UpgradeTimeout, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let relative_timeout = ctx.channel_upgrade_timeout()?;
+    let host_timestamp = ctx.host_timestamp()?;
+
+    let timestamp = (TimeoutTimestamp::At(host_timestamp)
+        + Duration::from_nanos(relative_timeout.timestamp.nanoseconds()))?;
+
+    Ok(UpgradeTimeout::new(TimeoutHeight::Never, timestamp))
+}
+
+/// Checks whether the given timeout has elapsed on the host chain.
+pub(super) fn has_elapsed_on_host<Ctx>(
+    ctx: &Ctx,
+    timeout: &UpgradeTimeout,
+) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    Ok(timeout.has_elapsed(ctx.host_height()?, &ctx.host_timestamp()?))
+}
+
+/// Moves the channel end into the `FLUSHING` state, and records the next send
+/// sequence and the absolute timeout in the upgrade.
+pub(super) fn start_flushing<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    chan_end: &mut ChannelEnd,
+    upgrade: &mut Upgrade,
+) -> Result<(), ChannelError>
+where
+    Ctx: ExecutionContext,
+{
+    chan_end.set_state(State::Flushing);
+    ctx.store_channel(&ChannelEndPath::new(port_id, chan_id), chan_end.clone())?;
+
+    upgrade.next_sequence_send = ctx.get_next_sequence_send(&SeqSendPath::new(port_id, chan_id))?;
+    upgrade.timeout = absolute_upgrade_timeout(ctx)?;
+    ctx.store_channel_upgrade(&ChannelUpgradePath::new(port_id, chan_id), upgrade.clone())?;
+
+    Ok(())
+}
+
+/// Aborts the upgrade in progress: the channel end is restored to the `OPEN`
+/// state with its original parameters, the upgrade information is deleted and
+/// the given error receipt is written, so that the counterparty can cancel
+/// its own side of the upgrade.
+pub(super) fn abort_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    error_receipt: ErrorReceipt,
+) -> Result<(), ChannelError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, chan_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+
+    chan_end.set_state(State::Open);
+    chan_end.set_upgrade_sequence(error_receipt.sequence);
+    ctx.store_channel(&chan_end_path, chan_end.clone())?;
+
+    ctx.delete_channel_upgrade(&ChannelUpgradePath::new(port_id, chan_id))?;
+    ctx.delete_counterparty_channel_upgrade(&CounterpartyChannelUpgradePath::new(
+        port_id, chan_id,
+    ))?;
+    ctx.store_channel_upgrade_error(
+        &ChannelUpgradeErrorPath::new(port_id, chan_id),
+        error_receipt.clone(),
+    )?;
+
+    ctx.log_message(format!(
+        "channel upgrade aborted: {}",
+        error_receipt.message
+    ))?;
+
+    let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end)?;
+    let event = IbcEvent::UpgradeErrorChannel(UpgradeError::new(
+        port_id.clone(),
+        chan_id.clone(),
+        port_id_on_b,
+        chan_id_on_b,
+        error_receipt,
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.emit_ibc_event(event)?;
+
+    Ok(())
+}
+
+/// Switches the channel end over to the upgraded parameters once both ends
+/// have flushed their in-flight packets.
+pub(super) fn open_upgraded_channel<Ctx>(
+    ctx: &mut Ctx,
+    module: &mut dyn Module,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), ChannelError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, chan_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+
+    let upgrade_path = ChannelUpgradePath::new(port_id, chan_id);
+    let upgrade = ctx
+        .channel_upgrade(&upgrade_path)?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    let counterparty_upgrade_path = CounterpartyChannelUpgradePath::new(port_id, chan_id);
+    let counterparty_upgrade = ctx
+        .counterparty_channel_upgrade(&counterparty_upgrade_path)?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    // When switching to an ordered channel, the packet sequences must carry on
+    // from the point at which each end started flushing.
+    if upgrade.fields.ordering.is_ordered() && *chan_end.ordering() == Order::Unordered {
+        ctx.store_next_sequence_recv(
+            &SeqRecvPath::new(port_id, chan_id),
+            counterparty_upgrade.next_sequence_send,
+        )?;
+        ctx.store_next_sequence_ack(
+            &SeqAckPath::new(port_id, chan_id),
+            upgrade.next_sequence_send,
+        )?;
+    }
+
+    chan_end.set_ordering(upgrade.fields.ordering);
+    chan_end.set_connection_hops(upgrade.fields.connection_hops.clone());
+    chan_end.set_version(upgrade.fields.version.clone());
+    chan_end.set_state(State::Open);
+    ctx.store_channel(&chan_end_path, chan_end.clone())?;
+
+    ctx.delete_channel_upgrade(&upgrade_path)?;
+    ctx.delete_counterparty_channel_upgrade(&counterparty_upgrade_path)?;
+
+    let extras = module.on_chan_upgrade_open_execute(
+        port_id,
+        chan_id,
+        upgrade.fields.ordering,
+        &upgrade.fields.connection_hops,
+        &upgrade.fields.version,
+    );
+
+    ctx.log_message("success: channel upgrade open".to_string())?;
+
+    let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end)?;
+    let core_event = IbcEvent::UpgradeOpenChannel(UpgradeOpen::new(
+        port_id.clone(),
+        chan_id.clone(),
+        port_id_on_b,
+        chan_id_on_b,
+        chan_end.upgrade_sequence(),
+        State::Open,
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.emit_ibc_event(core_event)?;
+
+    for module_event in extras.events {
+        ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx.log_message(log_message)?;
+    }
+
+    Ok(())
+}
+
+/// Called after a packet was acknowledged or timed out on a `FLUSHING`
+/// channel end. Aborts the upgrade if the counterparty timeout has elapsed,
+/// and otherwise moves the channel end to `FLUSHCOMPLETE` once no packets are
+/// left in flight.
+pub(super) fn handle_flush_state<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), ChannelError>
+where
+    Ctx: ExecutionContext,
+{
+    let Some(counterparty_upgrade) =
+        ctx.counterparty_channel_upgrade(&CounterpartyChannelUpgradePath::new(port_id, chan_id))?
+    else {
+        return Ok(());
+    };
+
+    let chan_end_path = ChannelEndPath::new(port_id, chan_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+
+    if has_elapsed_on_host(ctx, &counterparty_upgrade.timeout)? {
+        let error_receipt = ErrorReceipt::new(
+            chan_end.upgrade_sequence(),
+            ChannelError::ElapsedUpgradeTimeout {
+                timeout: counterparty_upgrade.timeout,
+            }
+            .to_string(),
+        );
+        return abort_upgrade(ctx, port_id, chan_id, error_receipt);
+    }
+
+    if !ctx.has_inflight_packets(&chan_end_path)? {
+        chan_end.set_state(State::FlushComplete);
+        ctx.store_channel(&chan_end_path, chan_end)?;
+    }
+
+    Ok(())
+}
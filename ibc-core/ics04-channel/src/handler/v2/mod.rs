@@ -0,0 +1,108 @@
+//! This module implements the processing logic for IBC v2 packet messages.
+//!
+//! IBC v2 packets are sent from a client to its registered counterparty
+//! client, and each of their payloads is routed by port to a [`ModuleV2`].
+mod acknowledgement;
+mod recv_packet;
+mod send_packet;
+mod timeout;
+
+pub use acknowledgement::*;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::v2::packet::Payload;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::CounterpartyInfo;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::identifiers::{ClientId, PortId};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_core_router::types::module::ModuleExtras;
+use ibc_core_router::v2::ModuleV2;
+use ibc_primitives::prelude::*;
+pub use recv_packet::*;
+pub use send_packet::*;
+pub use timeout::*;
+
+/// Returns the payload of a packet, which must carry exactly one of them as
+/// in ibc-go. Receiving several payloads atomically would require the
+/// applications to roll back the payloads received before a failing one.
+fn single_payload(payloads: &[Payload]) -> Result<&Payload, ChannelError> {
+    match payloads {
+        [payload] => Ok(payload),
+        _ => Err(ChannelError::InvalidPayload {
+            description: format!(
+                "packets must carry exactly one payload, got {}",
+                payloads.len()
+            ),
+        }),
+    }
+}
+
+/// Returns the counterparty registered for the given client.
+fn counterparty_of<Ctx>(ctx: &Ctx, client_id: &ClientId) -> Result<CounterpartyInfo, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.client_counterparty(client_id)?
+        .ok_or_else(|| ClientError::MissingCounterparty(client_id.clone()).into())
+}
+
+/// Checks that the counterparty registered for `client_id` is
+/// `counterparty_client_id`, and returns it.
+fn verify_counterparty<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+    counterparty_client_id: &ClientId,
+) -> Result<CounterpartyInfo, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let counterparty = counterparty_of(ctx, client_id)?;
+
+    if &counterparty.client_id != counterparty_client_id {
+        return Err(ChannelError::MismatchedCounterpartyClient {
+            expected: counterparty.client_id,
+            actual: counterparty_client_id.clone(),
+        });
+    }
+
+    Ok(counterparty)
+}
+
+fn module<'a>(router: &'a impl Router, port_id: &PortId) -> Result<&'a dyn ModuleV2, ChannelError> {
+    router
+        .lookup_module(port_id)
+        .and_then(|module_id| router.get_route_v2(&module_id))
+        .ok_or_else(|| ChannelError::MissingModule(port_id.clone()))
+}
+
+fn module_mut<'a>(
+    router: &'a mut impl Router,
+    port_id: &PortId,
+) -> Result<&'a mut dyn ModuleV2, ChannelError> {
+    let module_id = router
+        .lookup_module(port_id)
+        .ok_or_else(|| ChannelError::MissingModule(port_id.clone()))?;
+
+    router
+        .get_route_v2_mut(&module_id)
+        .ok_or_else(|| ChannelError::MissingModule(port_id.clone()))
+}
+
+/// Emits the events and logs returned by the applications.
+fn emit_module_extras<Ctx>(ctx: &mut Ctx, extras: Vec<ModuleExtras>) -> Result<(), ChannelError>
+where
+    Ctx: ExecutionContext,
+{
+    for extras in extras {
+        for module_event in extras.events {
+            ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::v2::acknowledgement::{Acknowledgement, PacketStatus};
+use ibc_core_channel_types::v2::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::v2::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::v2::msgs::MsgRecvPacket;
+use ibc_core_channel_types::v2::packet::Packet;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::v2::{AckPath, CommitmentPath, ReceiptPath};
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+
+use super::{emit_module_extras, module, module_mut, single_payload, verify_counterparty};
+
+pub fn recv_packet_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    router: &impl Router,
+    msg: &MsgRecvPacket,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty =
+        verify_counterparty(ctx_b, &packet.destination_client, &packet.source_client)?;
+
+    if packet.has_expired(&ctx_b.host_timestamp()?) {
+        return Err(ChannelError::ExpiredPacketTimestamp);
+    }
+
+    // Another relayer already relayed the packet, which is a no-op.
+    if packet_already_received(ctx_b, packet)? {
+        return Ok(());
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_b = &packet.destination_client;
+        let client_val_ctx_b = ctx_b.get_client_validation_context();
+        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+
+        client_state_of_a_on_b
+            .status(client_val_ctx_b, client_id_on_b)?
+            .verify_is_active()?;
+
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height.revision_number(),
+            msg.proof_height.revision_height(),
+        );
+
+        client_state_of_a_on_b.verify_membership_raw_at_height(
+            client_val_ctx_b,
+            &client_cons_state_path_on_b,
+            &counterparty.prefix,
+            &msg.proof_commitment,
+            CommitmentPath::new(&packet.source_client, packet.sequence).to_bytes(),
+            compute_packet_commitment(packet).into_vec(),
+        )?;
+    }
+
+    // `onRecvPacket` cannot fail, but the payload must be routed to an
+    // application.
+    module(router, &single_payload(&packet.payloads)?.destination_port)?;
+
+    Ok(())
+}
+
+pub fn recv_packet_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgRecvPacket,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let packet = msg.packet;
+
+    if packet_already_received(ctx_b, &packet)? {
+        return Ok(());
+    }
+
+    ctx_b.store_packet_receipt_v2(
+        &ReceiptPath::new(&packet.destination_client, packet.sequence),
+        Receipt::Ok,
+    )?;
+
+    let payload = single_payload(&packet.payloads)?;
+
+    let (extras, result) = module_mut(router, &payload.destination_port)?.on_recv_packet_execute(
+        &packet.source_client,
+        &packet.destination_client,
+        packet.sequence,
+        payload,
+        &msg.signer,
+    );
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: packet receive".to_string())?;
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(IbcEvent::ReceivePacketV2(ReceivePacket::new(
+            packet.clone(),
+        )))?;
+    }
+
+    // The acknowledgement of an asynchronous payload is written later on by
+    // the application, with `write_acknowledgement`.
+    let acknowledgement = match result.status {
+        PacketStatus::Success => Some(Acknowledgement::new(vec![result.acknowledgement])),
+        PacketStatus::Failure => Some(Acknowledgement::error()),
+        PacketStatus::Async => None,
+    };
+
+    if let Some(acknowledgement) = acknowledgement {
+        commit_acknowledgement(ctx_b, packet, acknowledgement)?;
+    }
+
+    emit_module_extras(ctx_b, vec![extras])?;
+
+    Ok(())
+}
+
+/// Writes the acknowledgement of a received packet, whose reception was
+/// acknowledged asynchronously by the application.
+///
+/// Fails if the packet was not received, or if it was already acknowledged.
+pub fn write_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    if !packet_already_received(ctx_b, &packet)? {
+        return Err(ChannelError::InvalidAcknowledgement {
+            description: format!("packet `{}` was not received", packet.sequence),
+        });
+    }
+
+    let ack_path_on_b = AckPath::new(&packet.destination_client, packet.sequence);
+    if ctx_b.get_packet_acknowledgement_v2(&ack_path_on_b).is_ok() {
+        return Err(ChannelError::DuplicateAcknowledgment(packet.sequence));
+    }
+
+    commit_acknowledgement(ctx_b, packet, acknowledgement)
+}
+
+fn commit_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    ctx_b.store_packet_acknowledgement_v2(
+        &AckPath::new(&packet.destination_client, packet.sequence),
+        compute_ack_commitment(&acknowledgement),
+    )?;
+
+    ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+    ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx_b.emit_ibc_event(IbcEvent::WriteAcknowledgementV2(WriteAcknowledgement::new(
+        packet,
+        acknowledgement,
+    )))?;
+
+    Ok(())
+}
+
+fn packet_already_received<Ctx>(ctx_b: &Ctx, packet: &Packet) -> Result<bool, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let receipt_path_on_b = ReceiptPath::new(&packet.destination_client, packet.sequence);
+
+    Ok(ctx_b.get_packet_receipt_v2(&receipt_path_on_b)?.is_ok())
+}
@@ -0,0 +1,143 @@
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::v2::commitment::compute_packet_commitment;
+use ibc_core_channel_types::v2::events::SendPacket;
+use ibc_core_channel_types::v2::msgs::MsgSendPacket;
+use ibc_core_channel_types::v2::packet::Packet;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::v2::{CommitmentPath, SeqSendPath};
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+
+use super::{counterparty_of, emit_module_extras, module, module_mut, single_payload};
+
+/// Maximum time, in seconds, by which the timeout of a sent packet may
+/// exceed the host timestamp, as set by ibc-go.
+const MAX_TIMEOUT_DELTA_SECS: u64 = 24 * 60 * 60;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+pub fn send_packet_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl Router,
+    msg: &MsgSendPacket,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let packet = build_packet(ctx_a, msg)?;
+
+    single_payload(&packet.payloads)?;
+
+    let client_val_ctx_a = ctx_a.get_client_validation_context();
+    let client_state_of_b_on_a = client_val_ctx_a.client_state(&packet.source_client)?;
+
+    client_state_of_b_on_a
+        .status(client_val_ctx_a, &packet.source_client)?
+        .verify_is_active()?;
+
+    let latest_height_on_a = client_state_of_b_on_a.latest_height();
+    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+        packet.source_client.clone(),
+        latest_height_on_a.revision_number(),
+        latest_height_on_a.revision_height(),
+    );
+    let latest_timestamp = client_state_of_b_on_a
+        .timestamp_at_height(client_val_ctx_a, &client_cons_state_path_on_a)?;
+
+    if packet.has_expired(&latest_timestamp) {
+        return Err(ChannelError::ExpiredPacketTimestamp);
+    }
+
+    let host_timestamp_secs = ctx_a.host_timestamp()?.nanoseconds() / NANOS_PER_SECOND;
+    let max_timeout_timestamp = host_timestamp_secs.saturating_add(MAX_TIMEOUT_DELTA_SECS);
+
+    if packet.timeout_timestamp > max_timeout_timestamp {
+        return Err(ChannelError::ExcessivePacketTimeout {
+            timeout_timestamp: packet.timeout_timestamp,
+            max_timeout_timestamp,
+        });
+    }
+
+    for payload in &packet.payloads {
+        module(router, &payload.source_port)?.on_send_packet_validate(
+            &packet.source_client,
+            &packet.destination_client,
+            packet.sequence,
+            payload,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn send_packet_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgSendPacket,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let packet = build_packet(ctx_a, &msg)?;
+
+    ctx_a.store_next_sequence_send_v2(
+        &SeqSendPath::new(&packet.source_client),
+        packet.sequence.increment(),
+    )?;
+
+    ctx_a.store_packet_commitment_v2(
+        &CommitmentPath::new(&packet.source_client, packet.sequence),
+        compute_packet_commitment(&packet),
+    )?;
+
+    let mut all_extras = Vec::with_capacity(packet.payloads.len());
+
+    for payload in &packet.payloads {
+        let (extras, cb_result) = module_mut(router, &payload.source_port)?.on_send_packet_execute(
+            &packet.source_client,
+            &packet.destination_client,
+            packet.sequence,
+            payload,
+            &msg.signer,
+        );
+
+        cb_result?;
+
+        all_extras.push(extras);
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet send".to_string())?;
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(IbcEvent::SendPacketV2(SendPacket::new(packet)))?;
+
+        emit_module_extras(ctx_a, all_extras)?;
+    }
+
+    Ok(())
+}
+
+/// Builds the packet sent by the message, addressed to the counterparty of
+/// the source client and numbered with its next sequence to send.
+fn build_packet<Ctx>(ctx_a: &Ctx, msg: &MsgSendPacket) -> Result<Packet, ChannelError>
+where
+    Ctx: ValidationContext,
+{
+    let counterparty = counterparty_of(ctx_a, &msg.source_client)?;
+    let sequence = ctx_a.get_next_sequence_send_v2(&SeqSendPath::new(&msg.source_client))?;
+
+    Ok(Packet {
+        sequence,
+        source_client: msg.source_client.clone(),
+        destination_client: counterparty.client_id,
+        timeout_timestamp: msg.timeout_timestamp,
+        payloads: msg.payloads.clone(),
+    })
+}
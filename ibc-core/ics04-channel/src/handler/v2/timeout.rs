@@ -0,0 +1,142 @@
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::v2::commitment::compute_packet_commitment;
+use ibc_core_channel_types::v2::events::TimeoutPacket;
+use ibc_core_channel_types::v2::msgs::MsgTimeout;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::v2::{CommitmentPath, ReceiptPath};
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+
+use super::{emit_module_extras, module, module_mut, verify_counterparty};
+
+pub fn timeout_packet_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl Router,
+    msg: &MsgTimeout,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty =
+        verify_counterparty(ctx_a, &packet.source_client, &packet.destination_client)?;
+
+    let commitment_path_on_a = CommitmentPath::new(&packet.source_client, packet.sequence);
+    let Ok(commitment_on_a) = ctx_a.get_packet_commitment_v2(&commitment_path_on_a) else {
+        // The timeout was already relayed, which is a no-op.
+        return Ok(());
+    };
+
+    let expected_commitment_on_a = compute_packet_commitment(packet);
+    if commitment_on_a != expected_commitment_on_a {
+        return Err(ChannelError::MismatchedPacketCommitment {
+            expected: expected_commitment_on_a,
+            actual: commitment_on_a,
+        });
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_a = &packet.source_client;
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(client_val_ctx_a, client_id_on_a)?
+            .verify_is_active()?;
+
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height)?;
+
+        // check that the timeout timestamp has passed on the other end
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height.revision_number(),
+            msg.proof_height.revision_height(),
+        );
+        let timestamp_of_b = client_state_of_b_on_a
+            .timestamp_at_height(client_val_ctx_a, &client_cons_state_path_on_a)?;
+
+        if !packet.has_expired(&timestamp_of_b) {
+            return Err(ChannelError::UnexpiredPacket {
+                timeout_timestamp: packet.timeout_timestamp,
+                chain_timestamp: timestamp_of_b,
+            });
+        }
+
+        client_state_of_b_on_a.verify_non_membership_raw_at_height(
+            client_val_ctx_a,
+            &client_cons_state_path_on_a,
+            &counterparty.prefix,
+            &msg.proof_unreceived,
+            ReceiptPath::new(&packet.destination_client, packet.sequence).to_bytes(),
+        )?;
+    }
+
+    for payload in &packet.payloads {
+        module(router, &payload.source_port)?.on_timeout_packet_validate(
+            &packet.source_client,
+            &packet.destination_client,
+            packet.sequence,
+            payload,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn timeout_packet_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgTimeout,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let packet = &msg.packet;
+
+    let commitment_path_on_a = CommitmentPath::new(&packet.source_client, packet.sequence);
+    if ctx_a
+        .get_packet_commitment_v2(&commitment_path_on_a)
+        .is_err()
+    {
+        // The timeout was already relayed, which is a no-op.
+        return Ok(());
+    }
+
+    ctx_a.delete_packet_commitment_v2(&commitment_path_on_a)?;
+
+    let mut all_extras = Vec::with_capacity(packet.payloads.len());
+
+    for payload in &packet.payloads {
+        let (extras, cb_result) = module_mut(router, &payload.source_port)?
+            .on_timeout_packet_execute(
+                &packet.source_client,
+                &packet.destination_client,
+                packet.sequence,
+                payload,
+                &msg.signer,
+            );
+
+        cb_result?;
+
+        all_extras.push(extras);
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet timeout".to_string())?;
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(IbcEvent::TimeoutPacketV2(TimeoutPacket::new(msg.packet)))?;
+
+        emit_module_extras(ctx_a, all_extras)?;
+    }
+
+    Ok(())
+}
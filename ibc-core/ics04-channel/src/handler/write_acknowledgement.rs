@@ -0,0 +1,95 @@
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_host::types::path::{AckPath, ChannelEndPath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+use super::acknowledgement::{
+    commit_packet_acknowledgment, emit_packet_acknowledgement_event_with_chan_end,
+};
+
+/// Writes the acknowledgement of a packet received earlier, for which the
+/// module returned no acknowledgement on receipt, and which is therefore
+/// pending.
+///
+/// Lets modules which process packets asynchronously acknowledge them in a
+/// later block. The acknowledgement must first go through the
+/// `write_acknowledgement` of the module stack the packet was routed to, so
+/// that the middleware wrapping the module gets to process it.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`].
+pub fn write_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    write_acknowledgement_validate(ctx_b, &packet)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Validates that the acknowledgement of the packet was deferred by the
+/// module on receipt, and was not written yet.
+pub fn write_acknowledgement_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    packet: &Packet,
+) -> Result<(), ChannelError>
+where
+    ValCtx: ValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    if !chan_end_on_b.is_open() && !chan_end_on_b.is_flushing() {
+        return Err(ChannelError::InvalidState {
+            expected: "channel state to be OPEN, FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_b.state.to_string(),
+        });
+    }
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(ChannelError::DuplicateAcknowledgment(packet.seq_on_a));
+    }
+
+    // Only the acknowledgements which the module deferred on receipt are
+    // written here.
+    if !ctx_b
+        .pending_acknowledgements(&chan_end_path_on_b)?
+        .contains(&packet.seq_on_a)
+    {
+        return Err(ChannelError::InvalidAcknowledgement {
+            description: format!(
+                "packet `{}` has no pending acknowledgement",
+                packet.seq_on_a
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Commits the acknowledgement of the packet, which is no longer pending, and
+/// emits the `WriteAcknowledgement` event.
+pub fn write_acknowledgement_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ChannelError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    commit_packet_acknowledgment(ctx_b, &packet, &acknowledgement)?;
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    ctx_b.delete_pending_acknowledgement(&ack_path_on_b)?;
+
+    emit_packet_acknowledgement_event_with_chan_end(ctx_b, &chan_end_on_b, packet, acknowledgement)
+}
@@ -54,12 +54,6 @@ impl TryFrom<RawIdentifiedChannel> for IdentifiedChannelEnd {
     type Error = DecodingError;
 
     fn try_from(value: RawIdentifiedChannel) -> Result<Self, Self::Error> {
-        if value.upgrade_sequence != 0 {
-            return Err(DecodingError::invalid_raw_data(
-                "channel upgrade sequence expected to be 0",
-            ));
-        }
-
         let raw_channel_end = RawChannel {
             state: value.state,
             ordering: value.ordering,
@@ -92,12 +86,13 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
             version: value.channel_end.version.to_string(),
             port_id: value.port_id.to_string(),
             channel_id: value.channel_id.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.channel_end.upgrade_sequence,
         }
     }
 }
 
 /// One end of a channel
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -119,14 +114,18 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The latest upgrade sequence of the channel. It is incremented every time
+    /// a new upgrade handshake is initiated and is `0` for channels that have
+    /// never been upgraded.
+    pub upgrade_sequence: u64,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version, self.upgrade_sequence
         )
     }
 }
@@ -158,7 +157,8 @@ impl TryFrom<RawChannel> for ChannelEnd {
         let version = value.version.into();
 
         let channel = ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)
-            .map_err(|e| DecodingError::invalid_raw_data(format!("channel end: {e}")))?;
+            .map_err(|e| DecodingError::invalid_raw_data(format!("channel end: {e}")))?
+            .with_upgrade_sequence(value.upgrade_sequence);
 
         Ok(channel)
     }
@@ -176,7 +176,7 @@ impl From<ChannelEnd> for RawChannel {
                 .map(|v| v.as_str().to_string())
                 .collect(),
             version: value.version.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.upgrade_sequence,
         }
     }
 }
@@ -200,6 +200,7 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: 0,
         }
     }
 
@@ -217,6 +218,12 @@ impl ChannelEnd {
         Ok(channel_end)
     }
 
+    /// Sets the upgrade sequence of the `ChannelEnd`, consuming and returning it.
+    pub fn with_upgrade_sequence(mut self, upgrade_sequence: u64) -> Self {
+        self.upgrade_sequence = upgrade_sequence;
+        self
+    }
+
     /// Updates the ChannelEnd to assume a new State 's'.
     pub fn set_state(&mut self, s: State) {
         self.state = s;
@@ -230,6 +237,18 @@ impl ChannelEnd {
         self.remote.channel_id = Some(c);
     }
 
+    pub fn set_ordering(&mut self, o: Order) {
+        self.ordering = o;
+    }
+
+    pub fn set_connection_hops(&mut self, hops: Vec<ConnectionId>) {
+        self.connection_hops = hops;
+    }
+
+    pub fn set_upgrade_sequence(&mut self, upgrade_sequence: u64) {
+        self.upgrade_sequence = upgrade_sequence;
+    }
+
     /// Returns `true` if this `ChannelEnd` is in state [`State::Open`].
     pub fn is_open(&self) -> bool {
         self.state == State::Open
@@ -255,6 +274,16 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+
+    /// Returns `true` if this `ChannelEnd` is in one of the flushing states
+    /// of a channel upgrade, i.e. [`State::Flushing`] or [`State::FlushComplete`].
+    pub fn is_flushing(&self) -> bool {
+        matches!(self.state, State::Flushing | State::FlushComplete)
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -319,11 +348,18 @@ impl ChannelEnd {
         Ok(())
     }
 
-    /// Checks if the `connection_hops` has a length of `expected`.
+    /// Checks that the channel end has at least one connection hop.
     ///
-    /// Note: The current IBC version only supports one connection hop.
-    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
-        verify_connection_hops_length(&self.connection_hops, 1)
+    /// Channels with more than one connection hop are ICS-033 multi-hop
+    /// channels, which reach their counterparty through intermediate chains.
+    pub fn verify_connection_hops_not_empty(&self) -> Result<(), ChannelError> {
+        verify_connection_hops_not_empty(&self.connection_hops)
+    }
+
+    /// Returns true if the channel end reaches its counterparty through more
+    /// than one connection hop.
+    pub fn is_multihop(&self) -> bool {
+        self.connection_hops.len() > 1
     }
 
     pub fn version_matches(&self, other: &Version) -> bool {
@@ -331,6 +367,16 @@ impl ChannelEnd {
     }
 }
 
+/// Checks that the `connection_hops` is not empty.
+pub(crate) fn verify_connection_hops_not_empty(
+    connection_hops: &[ConnectionId],
+) -> Result<(), ChannelError> {
+    if connection_hops.is_empty() {
+        return Err(ChannelError::MissingConnectionHops);
+    }
+    Ok(())
+}
+
 /// Checks if the `connection_hops` has a length of `expected`.
 pub(crate) fn verify_connection_hops_length(
     connection_hops: &[ConnectionId],
@@ -345,6 +391,7 @@ pub(crate) fn verify_connection_hops_length(
     Ok(())
 }
 
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -451,6 +498,10 @@ pub enum Order {
     None = 0isize,
     Unordered = 1isize,
     Ordered = 2isize,
+    /// Delivers the packets in the order they were sent, like `Ordered`,
+    /// except that the packets which timed out are skipped instead of closing
+    /// the channel.
+    OrderedAllowTimeout = 3isize,
 }
 
 impl Display for Order {
@@ -467,17 +518,24 @@ impl Order {
             Self::None => "ORDER_NONE_UNSPECIFIED",
             Self::Unordered => "ORDER_UNORDERED",
             Self::Ordered => "ORDER_ORDERED",
+            Self::OrderedAllowTimeout => "ORDER_ORDERED_ALLOW_TIMEOUT",
         }
     }
 
+    /// Returns true if the packets are delivered in the order they were sent.
+    pub fn is_ordered(&self) -> bool {
+        matches!(self, Self::Ordered | Self::OrderedAllowTimeout)
+    }
+
     // Parses the Order out from a i32.
     pub fn from_i32(nr: i32) -> Result<Self, ChannelError> {
         match nr {
             0 => Ok(Self::None),
             1 => Ok(Self::Unordered),
             2 => Ok(Self::Ordered),
+            3 => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidState {
-                expected: "to be one of 0, 1, 2".to_string(),
+                expected: "to be one of 0, 1, 2, 3".to_string(),
                 actual: nr.to_string(),
             }),
         }
@@ -492,8 +550,11 @@ impl FromStr for Order {
             "uninitialized" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
+            "ordered_allow_timeout" => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidState {
-                expected: "to be one of 'uninitialized', 'unordered', 'ordered'".to_string(),
+                expected:
+                    "to be one of 'uninitialized', 'unordered', 'ordered', 'ordered_allow_timeout'"
+                        .to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -501,6 +562,7 @@ impl FromStr for Order {
 }
 
 /// Represents the state of a [`ChannelEnd`]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -523,6 +585,8 @@ pub enum State {
     TryOpen = 2isize,
     Open = 3isize,
     Closed = 4isize,
+    Flushing = 5isize,
+    FlushComplete = 6isize,
 }
 
 impl State {
@@ -534,6 +598,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -545,8 +611,10 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(ChannelError::InvalidState {
-                expected: "to be one of: 0, 1, 2, 3, 4".to_string(),
+                expected: "to be one of: 0, 1, 2, 3, 4, 5, 6".to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -557,6 +625,11 @@ impl State {
         self == State::Open
     }
 
+    /// Returns if this channel state is one of the upgrade flushing states.
+    pub fn is_flushing(self) -> bool {
+        matches!(self, State::Flushing | State::FlushComplete)
+    }
+
     /// Returns if the channel with this state
     /// has progressed less than or the same as the argument.
     ///
@@ -5,14 +5,15 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_host_types::error::{DecodingError, HostError, IdentifierError};
-use ibc_core_host_types::identifiers::Sequence;
+use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Timestamp, TimestampError};
+use ibc_primitives::{Signer, Timestamp, TimestampError};
 
 use super::channel::Counterparty;
 use super::timeout::TimeoutHeight;
 use crate::commitment::PacketCommitment;
 use crate::timeout::TimeoutTimestamp;
+use crate::upgrade::UpgradeTimeout;
 use crate::Version;
 
 /// Errors that arise from the ICS04 Channel module
@@ -30,6 +31,8 @@ pub enum ChannelError {
     Timestamp(TimestampError),
     /// packet acknowledgment for sequence `{0}` already exists
     DuplicateAcknowledgment(Sequence),
+    /// redundant relay: all the relayed messages have already been processed
+    RedundantRelay,
     /// insufficient packet timeout height: should have `{timeout_height}` > `{chain_height}`
     InsufficientPacketHeight {
         chain_height: Height,
@@ -48,6 +51,11 @@ pub enum ChannelError {
     InvalidState { expected: String, actual: String },
     /// invalid connection hops length: expected `{expected}`, actual `{actual}`
     InvalidConnectionHopsLength { expected: u64, actual: u64 },
+    /// missing connection hops
+    MissingConnectionHops,
+    /// invalid multi-hop proof: `{description}`
+    #[from(skip)]
+    InvalidMultihopProof { description: String },
     /// missing acknowledgment status
     MissingAcknowledgmentStatus,
     /// missing counterparty
@@ -69,10 +77,49 @@ pub enum ChannelError {
         expected: PacketCommitment,
         actual: PacketCommitment,
     },
+    /// invalid channel upgrade: `{description}`
+    #[from(skip)]
+    InvalidUpgrade { description: String },
+    /// invalid channel upgrade sequence: expected `{expected}`, actual `{actual}`
+    #[from(skip)]
+    InvalidUpgradeSequence { expected: u64, actual: u64 },
+    /// missing channel upgrade
+    MissingUpgrade,
+    /// upgrade timeout `{timeout}` has elapsed
+    ElapsedUpgradeTimeout { timeout: UpgradeTimeout },
+    /// signer `{0}` is not authorized to initiate or cancel channel upgrades
+    UnauthorizedUpgradeSigner(Signer),
     /// unsupported version: expected `{expected}`, actual `{actual}`
     UnsupportedVersion { expected: Version, actual: Version },
     /// application specific error: `{description}`
     AppSpecific { description: String },
+    /// invalid packet payload: `{description}`
+    #[from(skip)]
+    InvalidPayload { description: String },
+    /// invalid packet acknowledgement: `{description}`
+    #[from(skip)]
+    InvalidAcknowledgement { description: String },
+    /// mismatched counterparty client: expected `{expected}`, actual `{actual}`
+    #[from(skip)]
+    MismatchedCounterpartyClient {
+        expected: ClientId,
+        actual: ClientId,
+    },
+    /// missing module bound to port `{0}`
+    #[from(skip)]
+    MissingModule(PortId),
+    /// packet with timeout timestamp `{timeout_timestamp}` has not expired at chain timestamp `{chain_timestamp}`
+    #[from(skip)]
+    UnexpiredPacket {
+        timeout_timestamp: u64,
+        chain_timestamp: Timestamp,
+    },
+    /// excessive packet timeout timestamp: should have `{timeout_timestamp}` <= `{max_timeout_timestamp}`
+    #[from(skip)]
+    ExcessivePacketTimeout {
+        timeout_timestamp: u64,
+        max_timeout_timestamp: u64,
+    },
 }
 
 impl From<IdentifierError> for ChannelError {
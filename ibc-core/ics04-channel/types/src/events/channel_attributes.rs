@@ -2,8 +2,11 @@
 //! during the channel handshake.
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
 use tendermint::abci;
 
+use crate::channel::State;
+use crate::timeout::{TimeoutHeight, TimeoutTimestamp};
 use crate::Version;
 
 const CONNECTION_ID_ATTRIBUTE_KEY: &str = "connection_id";
@@ -14,6 +17,11 @@ const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub(super) const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
 const VERSION_ATTRIBUTE_KEY: &str = "version";
+const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+const CHANNEL_STATE_ATTRIBUTE_KEY: &str = "channel_state";
+const UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "upgrade_timeout_height";
+const UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "upgrade_timeout_timestamp";
+const UPGRADE_ERROR_RECEIPT_ATTRIBUTE_KEY: &str = "upgrade_error_receipt";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -170,3 +178,135 @@ impl From<VersionAttribute> for abci::EventAttribute {
         (VERSION_ATTRIBUTE_KEY, attr.version.as_str()).into()
     }
 }
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeSequenceAttribute {
+    pub upgrade_sequence: u64,
+}
+
+impl From<UpgradeSequenceAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeSequenceAttribute) -> Self {
+        (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            attr.upgrade_sequence.to_string(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ChannelStateAttribute {
+    pub channel_state: State,
+}
+
+impl From<ChannelStateAttribute> for abci::EventAttribute {
+    fn from(attr: ChannelStateAttribute) -> Self {
+        (CHANNEL_STATE_ATTRIBUTE_KEY, attr.channel_state.as_string()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutHeightAttribute {
+    pub upgrade_timeout_height: TimeoutHeight,
+}
+
+impl From<UpgradeTimeoutHeightAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeTimeoutHeightAttribute) -> Self {
+        (
+            UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY,
+            attr.upgrade_timeout_height.to_event_attribute_value(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutTimestampAttribute {
+    pub upgrade_timeout_timestamp: TimeoutTimestamp,
+}
+
+impl From<UpgradeTimeoutTimestampAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeTimeoutTimestampAttribute) -> Self {
+        (
+            UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            attr.upgrade_timeout_timestamp.nanoseconds().to_string(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeErrorReceiptAttribute {
+    pub error_receipt: String,
+}
+
+impl From<UpgradeErrorReceiptAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeErrorReceiptAttribute) -> Self {
+        (UPGRADE_ERROR_RECEIPT_ATTRIBUTE_KEY, attr.error_receipt).into()
+    }
+}
@@ -9,9 +9,10 @@ use ibc_primitives::prelude::*;
 use tendermint::abci;
 
 use self::channel_attributes::{
-    ChannelIdAttribute, ConnectionIdAttribute, CounterpartyChannelIdAttribute,
-    CounterpartyPortIdAttribute, PortIdAttribute, VersionAttribute,
-    COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
+    ChannelIdAttribute, ChannelStateAttribute, ConnectionIdAttribute,
+    CounterpartyChannelIdAttribute, CounterpartyPortIdAttribute, PortIdAttribute,
+    UpgradeErrorReceiptAttribute, UpgradeSequenceAttribute, UpgradeTimeoutHeightAttribute,
+    UpgradeTimeoutTimestampAttribute, VersionAttribute, COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
 };
 use self::packet_attributes::{
     AcknowledgementAttribute, ChannelOrderingAttribute, DstChannelIdAttribute, DstPortIdAttribute,
@@ -19,11 +20,12 @@ use self::packet_attributes::{
     SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
 };
 use super::acknowledgement::Acknowledgement;
-use super::channel::Order;
+use super::channel::{Order, State};
 use super::timeout::TimeoutHeight;
 use super::Version;
 use crate::packet::Packet;
 use crate::timeout::TimeoutTimestamp;
+use crate::upgrade::{ErrorReceipt, UpgradeTimeout};
 
 /// Channel event types corresponding to ibc-go's channel events:
 /// https://github.com/cosmos/ibc-go/blob/c4413c5877f9ef883494da1721cb18caaba7f7f5/modules/core/04-channel/types/events.go#L52-L72
@@ -35,12 +37,22 @@ const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
 const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
 const CHANNEL_CLOSED_EVENT: &str = "channel_close";
 
+/// Channel upgrade event types corresponding to ibc-go's channel upgrade events
+const CHANNEL_UPGRADE_INIT_EVENT: &str = "channel_upgrade_init";
+const CHANNEL_UPGRADE_TRY_EVENT: &str = "channel_upgrade_try";
+const CHANNEL_UPGRADE_ACK_EVENT: &str = "channel_upgrade_ack";
+const CHANNEL_UPGRADE_CONFIRM_EVENT: &str = "channel_upgrade_confirm";
+const CHANNEL_UPGRADE_OPEN_EVENT: &str = "channel_upgrade_open";
+const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
+const CHANNEL_UPGRADE_CANCELLED_EVENT: &str = "channel_upgrade_cancelled";
+const CHANNEL_UPGRADE_ERROR_EVENT: &str = "channel_upgrade_error";
+
 /// Packet event types
-const SEND_PACKET_EVENT: &str = "send_packet";
-const RECEIVE_PACKET_EVENT: &str = "recv_packet";
-const WRITE_ACK_EVENT: &str = "write_acknowledgement";
-const ACK_PACKET_EVENT: &str = "acknowledge_packet";
-const TIMEOUT_EVENT: &str = "timeout_packet";
+pub(crate) const SEND_PACKET_EVENT: &str = "send_packet";
+pub(crate) const RECEIVE_PACKET_EVENT: &str = "recv_packet";
+pub(crate) const WRITE_ACK_EVENT: &str = "write_acknowledgement";
+pub(crate) const ACK_PACKET_EVENT: &str = "acknowledge_packet";
+pub(crate) const TIMEOUT_EVENT: &str = "timeout_packet";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -1118,6 +1130,632 @@ impl TryFrom<TimeoutPacket> for abci::Event {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeInit {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeInit {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_INIT_EVENT
+    }
+}
+
+impl From<UpgradeInit> for abci::Event {
+    fn from(u: UpgradeInit) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_INIT_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTry {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeTry {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TRY_EVENT
+    }
+}
+
+impl From<UpgradeTry> for abci::Event {
+    fn from(u: UpgradeTry) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TRY_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeAck {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeAck {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ACK_EVENT
+    }
+}
+
+impl From<UpgradeAck> for abci::Event {
+    fn from(u: UpgradeAck) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ACK_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeConfirm {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    channel_state_attr_on_b: ChannelStateAttribute,
+}
+
+impl UpgradeConfirm {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        upgrade_sequence: u64,
+        channel_state_on_b: State,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            channel_state_attr_on_b: channel_state_on_b.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn channel_state_on_b(&self) -> State {
+        self.channel_state_attr_on_b.channel_state
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CONFIRM_EVENT
+    }
+}
+
+impl From<UpgradeConfirm> for abci::Event {
+    fn from(u: UpgradeConfirm) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CONFIRM_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.upgrade_sequence_attr.into(),
+                u.channel_state_attr_on_b.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeOpen {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    channel_state_attr_on_a: ChannelStateAttribute,
+}
+
+impl UpgradeOpen {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        channel_state_on_a: State,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            channel_state_attr_on_a: channel_state_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn channel_state_on_a(&self) -> State {
+        self.channel_state_attr_on_a.channel_state
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_OPEN_EVENT
+    }
+}
+
+impl From<UpgradeOpen> for abci::Event {
+    fn from(u: UpgradeOpen) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_OPEN_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.channel_state_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimedOut {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_timeout_height_attr: UpgradeTimeoutHeightAttribute,
+    upgrade_timeout_timestamp_attr: UpgradeTimeoutTimestampAttribute,
+}
+
+impl UpgradeTimedOut {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_timeout: UpgradeTimeout,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_timeout_height_attr: upgrade_timeout.height.into(),
+            upgrade_timeout_timestamp_attr: upgrade_timeout.timestamp.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_timeout_height(&self) -> TimeoutHeight {
+        self.upgrade_timeout_height_attr.upgrade_timeout_height
+    }
+    pub fn upgrade_timeout_timestamp(&self) -> TimeoutTimestamp {
+        self.upgrade_timeout_timestamp_attr
+            .upgrade_timeout_timestamp
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TIMEOUT_EVENT
+    }
+}
+
+impl From<UpgradeTimedOut> for abci::Event {
+    fn from(u: UpgradeTimedOut) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TIMEOUT_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_timeout_height_attr.into(),
+                u.upgrade_timeout_timestamp_attr.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeCancelled {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeCancelled {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CANCELLED_EVENT
+    }
+}
+
+impl From<UpgradeCancelled> for abci::Event {
+    fn from(u: UpgradeCancelled) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CANCELLED_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeError {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    error_receipt_attr: UpgradeErrorReceiptAttribute,
+}
+
+impl UpgradeError {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        error_receipt: ErrorReceipt,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: error_receipt.sequence.into(),
+            error_receipt_attr: error_receipt.message.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn error_receipt_message(&self) -> &str {
+        &self.error_receipt_attr.error_receipt
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ERROR_EVENT
+    }
+}
+
+impl From<UpgradeError> for abci::Event {
+    fn from(u: UpgradeError) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ERROR_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.error_receipt_attr.into(),
+            ],
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tendermint::abci::Event as AbciEvent;
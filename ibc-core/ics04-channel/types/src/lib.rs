@@ -21,8 +21,11 @@ pub mod error;
 pub mod events;
 
 pub mod msgs;
+pub mod multihop;
 pub mod packet;
 pub mod timeout;
+pub mod upgrade;
+pub mod v2;
 
 pub mod acknowledgement;
 pub mod commitment;
@@ -32,4 +35,7 @@ pub use version::Version;
 /// Re-exports ICS-04 proto types from the `ibc-proto` crate
 pub mod proto {
     pub use ibc_proto::ibc::core::channel::*;
+
+    pub mod multihop;
+    pub mod v2;
 }
@@ -5,7 +5,7 @@ use ibc_primitives::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
 use ibc_proto::Protobuf;
 
-use crate::channel::{verify_connection_hops_length, ChannelEnd, Counterparty, Order, State};
+use crate::channel::{verify_connection_hops_not_empty, ChannelEnd, Counterparty, Order, State};
 use crate::error::ChannelError;
 use crate::Version;
 
@@ -33,11 +33,10 @@ pub struct MsgChannelOpenInit {
 }
 
 impl MsgChannelOpenInit {
-    /// Checks if the `connection_hops` has a length of `expected`.
-    ///
-    /// Note: Current IBC version only supports one connection hop.
-    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
-        verify_connection_hops_length(&self.connection_hops_on_a, 1)
+    /// Checks that at least one connection hop is given. More than one makes
+    /// for a multi-hop channel.
+    pub fn verify_connection_hops_not_empty(&self) -> Result<(), ChannelError> {
+        verify_connection_hops_not_empty(&self.connection_hops_on_a)
     }
 }
 
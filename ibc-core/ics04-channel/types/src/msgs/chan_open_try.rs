@@ -7,7 +7,7 @@ use ibc_primitives::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
 use ibc_proto::Protobuf;
 
-use crate::channel::{verify_connection_hops_length, ChannelEnd, Counterparty, Order, State};
+use crate::channel::{verify_connection_hops_not_empty, ChannelEnd, Counterparty, Order, State};
 use crate::error::ChannelError;
 use crate::Version;
 
@@ -41,11 +41,10 @@ pub struct MsgChannelOpenTry {
 }
 
 impl MsgChannelOpenTry {
-    /// Checks if the `connection_hops` has a length of `expected`.
-    ///
-    /// Note: The current IBC version only supports one connection hop.
-    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
-        verify_connection_hops_length(&self.connection_hops_on_b, 1)
+    /// Checks that at least one connection hop is given. More than one makes
+    /// for a multi-hop channel.
+    pub fn verify_connection_hops_not_empty(&self) -> Result<(), ChannelError> {
+        verify_connection_hops_not_empty(&self.connection_hops_on_b)
     }
 }
 
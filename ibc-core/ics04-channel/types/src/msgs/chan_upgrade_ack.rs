@@ -0,0 +1,71 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeAck as RawMsgChannelUpgradeAck;
+use ibc_proto::Protobuf;
+
+use crate::upgrade::Upgrade;
+
+pub const CHAN_UPGRADE_ACK_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeAck";
+
+/// Message definition for the third step in the channel upgrade handshake
+/// (`ChanUpgradeAck` datagram).
+///
+/// Per our convention, this message is sent to chain A.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeAck {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub counterparty_upgrade: Upgrade,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_upgrade_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeAck> for MsgChannelUpgradeAck {}
+
+impl TryFrom<RawMsgChannelUpgradeAck> for MsgChannelUpgradeAck {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeAck) -> Result<Self, Self::Error> {
+        Ok(MsgChannelUpgradeAck {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            counterparty_upgrade: raw_msg
+                .counterparty_upgrade
+                .ok_or(DecodingError::missing_raw_data("counterparty upgrade"))?
+                .try_into()?,
+            proof_chan_end_on_b: raw_msg.proof_channel.try_into()?,
+            proof_upgrade_on_b: raw_msg.proof_upgrade.try_into()?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::missing_raw_data("proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeAck> for RawMsgChannelUpgradeAck {
+    fn from(domain_msg: MsgChannelUpgradeAck) -> Self {
+        RawMsgChannelUpgradeAck {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            counterparty_upgrade: Some(domain_msg.counterparty_upgrade.into()),
+            proof_channel: domain_msg.proof_chan_end_on_b.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel;
+use ibc_proto::Protobuf;
+
+use crate::upgrade::ErrorReceipt;
+
+pub const CHAN_UPGRADE_CANCEL_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeCancel";
+
+/// Message definition for cancelling an ongoing channel upgrade
+/// (`ChanUpgradeCancel` datagram).
+///
+/// This message may be sent to either end of the channel; per our convention,
+/// the receiving chain is denoted as chain A. The proof of the counterparty
+/// error receipt may only be omitted when the signer is the upgrade authority
+/// of chain A.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeCancel {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub error_receipt_on_b: ErrorReceipt,
+    pub proof_error_receipt_on_b: Option<CommitmentProofBytes>,
+    pub proof_height_on_b: Option<Height>,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeCancel> for MsgChannelUpgradeCancel {}
+
+impl TryFrom<RawMsgChannelUpgradeCancel> for MsgChannelUpgradeCancel {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeCancel) -> Result<Self, Self::Error> {
+        let proof_error_receipt_on_b = if raw_msg.proof_error_receipt.is_empty() {
+            None
+        } else {
+            Some(raw_msg.proof_error_receipt.try_into()?)
+        };
+
+        let proof_height_on_b = raw_msg
+            .proof_height
+            .and_then(|raw_height| raw_height.try_into().ok());
+
+        if proof_error_receipt_on_b.is_some() && proof_height_on_b.is_none() {
+            return Err(DecodingError::missing_raw_data("proof height"));
+        }
+
+        Ok(MsgChannelUpgradeCancel {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            error_receipt_on_b: raw_msg
+                .error_receipt
+                .ok_or(DecodingError::missing_raw_data("error receipt"))?
+                .try_into()?,
+            proof_error_receipt_on_b,
+            proof_height_on_b,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeCancel> for RawMsgChannelUpgradeCancel {
+    fn from(domain_msg: MsgChannelUpgradeCancel) -> Self {
+        RawMsgChannelUpgradeCancel {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            error_receipt: Some(domain_msg.error_receipt_on_b.into()),
+            proof_error_receipt: domain_msg
+                .proof_error_receipt_on_b
+                .map(Into::into)
+                .unwrap_or_default(),
+            proof_height: domain_msg.proof_height_on_b.map(Into::into),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
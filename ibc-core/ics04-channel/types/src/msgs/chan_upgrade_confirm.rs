@@ -0,0 +1,86 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+use ibc_proto::Protobuf;
+
+use crate::channel::State;
+use crate::upgrade::Upgrade;
+
+pub const CHAN_UPGRADE_CONFIRM_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeConfirm";
+
+/// Message definition for the fourth step in the channel upgrade handshake
+/// (`ChanUpgradeConfirm` datagram).
+///
+/// Per our convention, this message is sent to chain B.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeConfirm {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    pub counterparty_channel_state: State,
+    pub counterparty_upgrade: Upgrade,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_upgrade_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeConfirm> for MsgChannelUpgradeConfirm {}
+
+impl TryFrom<RawMsgChannelUpgradeConfirm> for MsgChannelUpgradeConfirm {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeConfirm) -> Result<Self, Self::Error> {
+        let counterparty_channel_state = State::from_i32(raw_msg.counterparty_channel_state)
+            .map_err(|e| {
+                DecodingError::invalid_raw_data(format!("counterparty channel state: {e}"))
+            })?;
+
+        if !counterparty_channel_state.is_flushing() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "counterparty channel state expected to be `FLUSHING` or `FLUSHCOMPLETE`, actual `{counterparty_channel_state}`"
+            )));
+        }
+
+        Ok(MsgChannelUpgradeConfirm {
+            port_id_on_b: raw_msg.port_id.parse()?,
+            chan_id_on_b: raw_msg.channel_id.parse()?,
+            counterparty_channel_state,
+            counterparty_upgrade: raw_msg
+                .counterparty_upgrade
+                .ok_or(DecodingError::missing_raw_data("counterparty upgrade"))?
+                .try_into()?,
+            proof_chan_end_on_a: raw_msg.proof_channel.try_into()?,
+            proof_upgrade_on_a: raw_msg.proof_upgrade.try_into()?,
+            proof_height_on_a: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::missing_raw_data("proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeConfirm> for RawMsgChannelUpgradeConfirm {
+    fn from(domain_msg: MsgChannelUpgradeConfirm) -> Self {
+        RawMsgChannelUpgradeConfirm {
+            port_id: domain_msg.port_id_on_b.to_string(),
+            channel_id: domain_msg.chan_id_on_b.to_string(),
+            counterparty_channel_state: domain_msg.counterparty_channel_state as i32,
+            counterparty_upgrade: Some(domain_msg.counterparty_upgrade.into()),
+            proof_channel: domain_msg.proof_chan_end_on_a.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_a.into(),
+            proof_height: Some(domain_msg.proof_height_on_a.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
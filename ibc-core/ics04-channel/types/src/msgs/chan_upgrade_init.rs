@@ -0,0 +1,64 @@
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeInit as RawMsgChannelUpgradeInit;
+use ibc_proto::Protobuf;
+
+use crate::upgrade::UpgradeFields;
+
+pub const CHAN_UPGRADE_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeInit";
+
+/// Message definition for the first step in the channel upgrade handshake
+/// (`ChanUpgradeInit` datagram).
+///
+/// Per our convention, this message is sent to chain A. Only the authority
+/// of chain A may initiate a channel upgrade.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeInit {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub fields: UpgradeFields,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeInit> for MsgChannelUpgradeInit {}
+
+impl TryFrom<RawMsgChannelUpgradeInit> for MsgChannelUpgradeInit {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeInit) -> Result<Self, Self::Error> {
+        let fields: UpgradeFields = raw_msg
+            .fields
+            .ok_or(DecodingError::missing_raw_data("upgrade fields"))?
+            .try_into()?;
+
+        fields
+            .validate_basic()
+            .map_err(|e| DecodingError::invalid_raw_data(format!("upgrade fields: {e}")))?;
+
+        Ok(MsgChannelUpgradeInit {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            fields,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeInit> for RawMsgChannelUpgradeInit {
+    fn from(domain_msg: MsgChannelUpgradeInit) -> Self {
+        RawMsgChannelUpgradeInit {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            fields: Some(domain_msg.fields.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+use ibc_proto::Protobuf;
+
+use crate::channel::State;
+
+pub const CHAN_UPGRADE_OPEN_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeOpen";
+
+/// Message definition for the last step in the channel upgrade handshake
+/// (`ChanUpgradeOpen` datagram).
+///
+/// This message may be sent to either end of the channel; per our convention,
+/// the receiving chain is denoted as chain A.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeOpen {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub counterparty_channel_state: State,
+    pub counterparty_upgrade_sequence: u64,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeOpen> for MsgChannelUpgradeOpen {}
+
+impl TryFrom<RawMsgChannelUpgradeOpen> for MsgChannelUpgradeOpen {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeOpen) -> Result<Self, Self::Error> {
+        let counterparty_channel_state = State::from_i32(raw_msg.counterparty_channel_state)
+            .map_err(|e| {
+                DecodingError::invalid_raw_data(format!("counterparty channel state: {e}"))
+            })?;
+
+        if !matches!(
+            counterparty_channel_state,
+            State::Open | State::FlushComplete
+        ) {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "counterparty channel state expected to be `OPEN` or `FLUSHCOMPLETE`, actual `{counterparty_channel_state}`"
+            )));
+        }
+
+        Ok(MsgChannelUpgradeOpen {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            counterparty_channel_state,
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence,
+            proof_chan_end_on_b: raw_msg.proof_channel.try_into()?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::missing_raw_data("proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeOpen> for RawMsgChannelUpgradeOpen {
+    fn from(domain_msg: MsgChannelUpgradeOpen) -> Self {
+        RawMsgChannelUpgradeOpen {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            counterparty_channel_state: domain_msg.counterparty_channel_state as i32,
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence,
+            proof_channel: domain_msg.proof_chan_end_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout;
+use ibc_proto::Protobuf;
+
+use crate::channel::ChannelEnd;
+
+pub const CHAN_UPGRADE_TIMEOUT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTimeout";
+
+/// Message definition for aborting a channel upgrade whose timeout has
+/// elapsed on the counterparty (`ChanUpgradeTimeout` datagram).
+///
+/// This message may be sent to either end of the channel; per our convention,
+/// the receiving chain is denoted as chain A.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTimeout {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub chan_end_on_b: ChannelEnd,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeTimeout> for MsgChannelUpgradeTimeout {}
+
+impl TryFrom<RawMsgChannelUpgradeTimeout> for MsgChannelUpgradeTimeout {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTimeout) -> Result<Self, Self::Error> {
+        Ok(MsgChannelUpgradeTimeout {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            chan_end_on_b: raw_msg
+                .counterparty_channel
+                .ok_or(DecodingError::missing_raw_data("counterparty channel"))?
+                .try_into()?,
+            proof_chan_end_on_b: raw_msg.proof_channel.try_into()?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::missing_raw_data("proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTimeout> for RawMsgChannelUpgradeTimeout {
+    fn from(domain_msg: MsgChannelUpgradeTimeout) -> Self {
+        RawMsgChannelUpgradeTimeout {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            counterparty_channel: Some(domain_msg.chan_end_on_b.into()),
+            proof_channel: domain_msg.proof_chan_end_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use ibc_proto::Protobuf;
+
+use crate::channel::verify_connection_hops_length;
+use crate::error::ChannelError;
+use crate::upgrade::UpgradeFields;
+
+pub const CHAN_UPGRADE_TRY_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTry";
+
+/// Message definition for the second step in the channel upgrade handshake
+/// (`ChanUpgradeTry` datagram).
+///
+/// Per our convention, this message is sent to chain B.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTry {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    pub proposed_upgrade_connection_hops: Vec<ConnectionId>,
+    pub counterparty_upgrade_fields: UpgradeFields,
+    pub counterparty_upgrade_sequence: u64,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_upgrade_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl MsgChannelUpgradeTry {
+    /// Checks if the proposed `connection_hops` has a length of `expected`.
+    ///
+    /// Note: The current IBC version only supports one connection hop.
+    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
+        verify_connection_hops_length(&self.proposed_upgrade_connection_hops, 1)
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {}
+
+impl TryFrom<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTry) -> Result<Self, Self::Error> {
+        if raw_msg.counterparty_upgrade_sequence == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "counterparty upgrade sequence cannot be 0",
+            ));
+        }
+
+        let counterparty_upgrade_fields: UpgradeFields = raw_msg
+            .counterparty_upgrade_fields
+            .ok_or(DecodingError::missing_raw_data(
+                "counterparty upgrade fields",
+            ))?
+            .try_into()?;
+
+        counterparty_upgrade_fields.validate_basic().map_err(|e| {
+            DecodingError::invalid_raw_data(format!("counterparty upgrade fields: {e}"))
+        })?;
+
+        let proposed_upgrade_connection_hops = raw_msg
+            .proposed_upgrade_connection_hops
+            .into_iter()
+            .map(|conn_id| conn_id.parse())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(MsgChannelUpgradeTry {
+            port_id_on_b: raw_msg.port_id.parse()?,
+            chan_id_on_b: raw_msg.channel_id.parse()?,
+            proposed_upgrade_connection_hops,
+            counterparty_upgrade_fields,
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence,
+            proof_chan_end_on_a: raw_msg.proof_channel.try_into()?,
+            proof_upgrade_on_a: raw_msg.proof_upgrade.try_into()?,
+            proof_height_on_a: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::missing_raw_data("proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTry> for RawMsgChannelUpgradeTry {
+    fn from(domain_msg: MsgChannelUpgradeTry) -> Self {
+        RawMsgChannelUpgradeTry {
+            port_id: domain_msg.port_id_on_b.to_string(),
+            channel_id: domain_msg.chan_id_on_b.to_string(),
+            proposed_upgrade_connection_hops: domain_msg
+                .proposed_upgrade_connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            counterparty_upgrade_fields: Some(domain_msg.counterparty_upgrade_fields.into()),
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence,
+            proof_channel: domain_msg.proof_chan_end_on_a.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_a.into(),
+            proof_height: Some(domain_msg.proof_height_on_a.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -8,6 +8,13 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
 mod recv_packet;
 mod timeout;
 mod timeout_on_close;
@@ -22,6 +29,14 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+// Upgrade handshake messages.
+pub use chan_upgrade_ack::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_confirm::*;
+pub use chan_upgrade_init::*;
+pub use chan_upgrade_open::*;
+pub use chan_upgrade_timeout::*;
+pub use chan_upgrade_try::*;
 use ibc_core_host_types::identifiers::*;
 use ibc_primitives::prelude::*;
 pub use recv_packet::*;
@@ -43,6 +58,13 @@ pub enum ChannelMsg {
     OpenConfirm(MsgChannelOpenConfirm),
     CloseInit(MsgChannelCloseInit),
     CloseConfirm(MsgChannelCloseConfirm),
+    UpgradeInit(MsgChannelUpgradeInit),
+    UpgradeTry(MsgChannelUpgradeTry),
+    UpgradeAck(MsgChannelUpgradeAck),
+    UpgradeConfirm(MsgChannelUpgradeConfirm),
+    UpgradeOpen(MsgChannelUpgradeOpen),
+    UpgradeTimeout(MsgChannelUpgradeTimeout),
+    UpgradeCancel(MsgChannelUpgradeCancel),
 }
 
 /// All packet messages
@@ -68,6 +90,13 @@ pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
         ChannelMsg::OpenConfirm(msg) => &msg.port_id_on_b,
         ChannelMsg::CloseInit(msg) => &msg.port_id_on_a,
         ChannelMsg::CloseConfirm(msg) => &msg.port_id_on_b,
+        ChannelMsg::UpgradeInit(msg) => &msg.port_id_on_a,
+        ChannelMsg::UpgradeTry(msg) => &msg.port_id_on_b,
+        ChannelMsg::UpgradeAck(msg) => &msg.port_id_on_a,
+        ChannelMsg::UpgradeConfirm(msg) => &msg.port_id_on_b,
+        ChannelMsg::UpgradeOpen(msg) => &msg.port_id_on_a,
+        ChannelMsg::UpgradeTimeout(msg) => &msg.port_id_on_a,
+        ChannelMsg::UpgradeCancel(msg) => &msg.port_id_on_a,
     }
 }
 
@@ -0,0 +1,237 @@
+//! Defines the proofs of ICS-033 multi-hop channels, whose ends are connected
+//! through one or more intermediate chains.
+
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::proto::multihop::{
+    MultihopConnectionProof as RawMultihopConnectionProof,
+    MultihopConsensusProof as RawMultihopConsensusProof, MultihopProof as RawMultihopProof,
+};
+
+/// Proof of a connection end stored on an intermediate chain of a multi-hop
+/// channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultihopConnectionProof {
+    pub connection_end: ConnectionEnd,
+    pub proof: CommitmentProofBytes,
+}
+
+impl Protobuf<RawMultihopConnectionProof> for MultihopConnectionProof {}
+
+impl TryFrom<RawMultihopConnectionProof> for MultihopConnectionProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMultihopConnectionProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            connection_end: raw
+                .connection_end
+                .ok_or(DecodingError::missing_raw_data(
+                    "multi-hop connection proof connection end",
+                ))?
+                .try_into()?,
+            proof: raw.proof.try_into()?,
+        })
+    }
+}
+
+impl From<MultihopConnectionProof> for RawMultihopConnectionProof {
+    fn from(value: MultihopConnectionProof) -> Self {
+        Self {
+            connection_end: Some(value.connection_end.into()),
+            proof: value.proof.into(),
+        }
+    }
+}
+
+/// Proof of the consensus state of the next chain along the route, stored on
+/// an intermediate chain of a multi-hop channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultihopConsensusProof {
+    pub consensus_height: Height,
+    pub consensus_state: Any,
+    pub proof: CommitmentProofBytes,
+}
+
+impl Protobuf<RawMultihopConsensusProof> for MultihopConsensusProof {}
+
+impl TryFrom<RawMultihopConsensusProof> for MultihopConsensusProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMultihopConsensusProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            consensus_height: raw
+                .consensus_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::invalid_raw_data(
+                    "multi-hop consensus proof consensus height",
+                ))?,
+            consensus_state: raw.consensus_state.ok_or(DecodingError::missing_raw_data(
+                "multi-hop consensus proof consensus state",
+            ))?,
+            proof: raw.proof.try_into()?,
+        })
+    }
+}
+
+impl From<MultihopConsensusProof> for RawMultihopConsensusProof {
+    fn from(value: MultihopConsensusProof) -> Self {
+        Self {
+            consensus_height: Some(value.consensus_height.into()),
+            consensus_state: Some(value.consensus_state),
+            proof: value.proof.into(),
+        }
+    }
+}
+
+/// Proof of a value stored on the counterparty of a multi-hop channel.
+///
+/// The route from the verifying chain to the counterparty goes through the
+/// connection hops of the verifying channel end. The verifying chain only
+/// tracks the first intermediate chain, so each intermediate chain proves both
+/// its connection end of the next hop and the consensus state it stores for
+/// the next chain along the route. The root of the last proven consensus
+/// state, that of the counterparty, is then used to verify the `key_proof`.
+///
+/// The connection and consensus proofs are ordered from the first
+/// intermediate chain towards the counterparty, so a channel with `n`
+/// connection hops expects `n - 1` of each.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultihopProof {
+    pub key_proof: CommitmentProofBytes,
+    pub connection_proofs: Vec<MultihopConnectionProof>,
+    pub consensus_proofs: Vec<MultihopConsensusProof>,
+}
+
+impl MultihopProof {
+    /// Returns the number of connection hops this proof goes through.
+    pub fn connection_hops_len(&self) -> usize {
+        self.connection_proofs.len() + 1
+    }
+}
+
+impl Protobuf<RawMultihopProof> for MultihopProof {}
+
+impl TryFrom<RawMultihopProof> for MultihopProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMultihopProof) -> Result<Self, Self::Error> {
+        if raw.connection_proofs.len() != raw.consensus_proofs.len() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "multi-hop proof has {} connection proofs but {} consensus proofs",
+                raw.connection_proofs.len(),
+                raw.consensus_proofs.len()
+            )));
+        }
+
+        Ok(Self {
+            key_proof: raw.key_proof.try_into()?,
+            connection_proofs: raw
+                .connection_proofs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            consensus_proofs: raw
+                .consensus_proofs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<MultihopProof> for RawMultihopProof {
+    fn from(value: MultihopProof) -> Self {
+        Self {
+            key_proof: value.key_proof.into(),
+            connection_proofs: value
+                .connection_proofs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            consensus_proofs: value.consensus_proofs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<MultihopProof> for CommitmentProofBytes {
+    type Error = DecodingError;
+
+    fn try_from(value: MultihopProof) -> Result<Self, Self::Error> {
+        Protobuf::<RawMultihopProof>::encode_vec(value).try_into()
+    }
+}
+
+impl<'a> TryFrom<&'a CommitmentProofBytes> for MultihopProof {
+    type Error = DecodingError;
+
+    fn try_from(value: &'a CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Ok(Protobuf::<RawMultihopProof>::decode(value.as_ref())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_commitment_types::commitment::CommitmentPrefix;
+    use ibc_core_connection_types::version::Version as ConnectionVersion;
+    use ibc_core_connection_types::{Counterparty, State};
+    use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
+    use ibc_primitives::ZERO_DURATION;
+
+    use super::*;
+
+    #[test]
+    fn multihop_proof_round_trip() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+        let connection_end = ConnectionEnd::new(
+            State::Open,
+            client_id.clone(),
+            Counterparty::new(
+                client_id,
+                Some(ConnectionId::zero()),
+                CommitmentPrefix::empty(),
+            ),
+            ConnectionVersion::compatibles(),
+            ZERO_DURATION,
+        )
+        .expect("no error");
+
+        let proof = MultihopProof {
+            key_proof: vec![1].try_into().expect("no error"),
+            connection_proofs: vec![MultihopConnectionProof {
+                connection_end,
+                proof: vec![2].try_into().expect("no error"),
+            }],
+            consensus_proofs: vec![MultihopConsensusProof {
+                consensus_height: Height::new(0, 10).expect("no error"),
+                consensus_state: Any {
+                    type_url: "/ibc.mock.ConsensusState".to_string(),
+                    value: vec![3],
+                },
+                proof: vec![4].try_into().expect("no error"),
+            }],
+        };
+
+        let proof_bytes = CommitmentProofBytes::try_from(proof.clone()).expect("no error");
+        let decoded = MultihopProof::try_from(&proof_bytes).expect("no error");
+
+        assert_eq!(decoded, proof);
+        assert_eq!(decoded.connection_hops_len(), 2);
+    }
+
+    #[test]
+    fn multihop_proof_mismatched_proofs() {
+        let raw = RawMultihopProof {
+            key_proof: vec![1],
+            connection_proofs: vec![],
+            consensus_proofs: vec![RawMultihopConsensusProof::default()],
+        };
+
+        assert!(MultihopProof::try_from(raw).is_err());
+    }
+}
@@ -4,7 +4,9 @@ use ibc_core_host_types::error::DecodingError;
 use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
-use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
+use ibc_proto::ibc::core::channel::v1::{
+    Packet as RawPacket, PacketId as RawPacketId, PacketState as RawPacketState,
+};
 
 use super::timeout::TimeoutHeight;
 use crate::timeout::TimeoutTimestamp;
@@ -41,6 +43,9 @@ pub enum PacketMsgType {
 pub enum Receipt {
     Ok,
     None,
+    /// The packet timed out on an `ORDERED_ALLOW_TIMEOUT` channel and was
+    /// skipped by the receiving end.
+    Timeout,
 }
 
 impl Receipt {
@@ -48,6 +53,20 @@ impl Receipt {
         matches!(self, Receipt::Ok)
     }
 
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Receipt::Timeout)
+    }
+
+    /// Returns the bytes committed to the host's store for this receipt, or
+    /// `None` if nothing is stored.
+    pub fn as_bytes(&self) -> Option<&'static [u8]> {
+        match self {
+            Receipt::Ok => Some(&[1]),
+            Receipt::Timeout => Some(&[2]),
+            Receipt::None => None,
+        }
+    }
+
     pub fn is_none(&self) -> bool {
         matches!(self, Receipt::None)
     }
@@ -326,3 +345,74 @@ impl From<PacketState> for RawPacketState {
         }
     }
 }
+
+/// Uniquely identifies a packet by the source port, channel and sequence.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    pub seq: Sequence,
+}
+
+impl PacketId {
+    pub fn new(port_id: PortId, chan_id: ChannelId, seq: Sequence) -> Self {
+        Self {
+            port_id,
+            chan_id,
+            seq,
+        }
+    }
+}
+
+impl From<&Packet> for PacketId {
+    fn from(packet: &Packet) -> Self {
+        Self::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        )
+    }
+}
+
+impl core::fmt::Display for PacketId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}/{}/{}", self.port_id, self.chan_id, self.seq)
+    }
+}
+
+impl TryFrom<RawPacketId> for PacketId {
+    type Error = DecodingError;
+
+    fn try_from(raw_packet_id: RawPacketId) -> Result<Self, Self::Error> {
+        Ok(PacketId {
+            port_id: raw_packet_id.port_id.parse()?,
+            chan_id: raw_packet_id.channel_id.parse()?,
+            seq: Sequence::from(raw_packet_id.sequence),
+        })
+    }
+}
+
+impl From<PacketId> for RawPacketId {
+    fn from(packet_id: PacketId) -> Self {
+        Self {
+            port_id: packet_id.port_id.to_string(),
+            channel_id: packet_id.chan_id.to_string(),
+            sequence: packet_id.seq.value(),
+        }
+    }
+}
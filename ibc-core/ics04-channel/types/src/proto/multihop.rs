@@ -0,0 +1,49 @@
+//! Protobuf definitions of the ICS-033 multi-hop channel proofs, which are not
+//! yet shipped by `ibc-proto`.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::Height;
+use ibc_proto::ibc::core::connection::v1::ConnectionEnd;
+
+/// Proof of a connection end stored on an intermediate chain.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultihopConnectionProof {
+    /// The proven connection end
+    #[prost(message, optional, tag = "1")]
+    pub connection_end: Option<ConnectionEnd>,
+    /// Proof of the connection end on the intermediate chain
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof: Vec<u8>,
+}
+
+/// Proof of the consensus state of the next chain along the route, stored on
+/// an intermediate chain.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultihopConsensusProof {
+    /// Height of the next chain at which the consensus state was stored
+    #[prost(message, optional, tag = "1")]
+    pub consensus_height: Option<Height>,
+    /// The proven consensus state
+    #[prost(message, optional, tag = "2")]
+    pub consensus_state: Option<Any>,
+    /// Proof of the consensus state on the intermediate chain
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof: Vec<u8>,
+}
+
+/// Proof of a value stored on the counterparty of a multi-hop channel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultihopProof {
+    /// Proof of the value on the counterparty chain
+    #[prost(bytes = "vec", tag = "1")]
+    pub key_proof: Vec<u8>,
+    /// Proofs of the connection ends along the route, starting from the
+    /// first intermediate chain
+    #[prost(message, repeated, tag = "2")]
+    pub connection_proofs: Vec<MultihopConnectionProof>,
+    /// Proofs of the consensus states along the route, starting from the
+    /// first intermediate chain
+    #[prost(message, repeated, tag = "3")]
+    pub consensus_proofs: Vec<MultihopConsensusProof>,
+}
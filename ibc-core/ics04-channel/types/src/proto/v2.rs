@@ -0,0 +1,129 @@
+//! Protobuf definitions of the `ibc.core.channel.v2` package, which are not
+//! yet shipped by `ibc-proto`.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// Packet sent from a source client to a destination client, carrying one
+/// or more application payloads.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Packet {
+    /// Sequence number of the packet on the source client
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// Identifier of the client on the sending chain
+    #[prost(string, tag = "2")]
+    pub source_client: String,
+    /// Identifier of the client on the receiving chain
+    #[prost(string, tag = "3")]
+    pub destination_client: String,
+    /// Timeout timestamp, in seconds since the Unix epoch, after which the
+    /// packet can no longer be received
+    #[prost(uint64, tag = "4")]
+    pub timeout_timestamp: u64,
+    /// Application payloads
+    #[prost(message, repeated, tag = "5")]
+    pub payloads: Vec<Payload>,
+}
+
+/// Application data carried by a packet, routed by port.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Payload {
+    /// Port of the sending application
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    /// Port of the receiving application
+    #[prost(string, tag = "2")]
+    pub destination_port: String,
+    /// Version of the application
+    #[prost(string, tag = "3")]
+    pub version: String,
+    /// Encoding of the value
+    #[prost(string, tag = "4")]
+    pub encoding: String,
+    /// Application-specific value
+    #[prost(bytes = "vec", tag = "5")]
+    pub value: Vec<u8>,
+}
+
+/// Acknowledgement of a packet, with one acknowledgement per payload.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Acknowledgement {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub app_acknowledgements: Vec<Vec<u8>>,
+}
+
+/// Sends a packet from the given source client.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSendPacket {
+    #[prost(string, tag = "1")]
+    pub source_client: String,
+    #[prost(uint64, tag = "2")]
+    pub timeout_timestamp: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub payloads: Vec<Payload>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+/// Receives a packet sent by the counterparty.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgRecvPacket {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_commitment: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+/// Times out a packet that was not received by the counterparty.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgTimeout {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_unreceived: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+/// Acknowledges a packet received by the counterparty.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgAcknowledgement {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(message, optional, tag = "2")]
+    pub acknowledgement: Option<Acknowledgement>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof_acked: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+macro_rules! impl_name {
+    ($($msg:ident),*) => {
+        $(
+            impl ::prost::Name for $msg {
+                const NAME: &'static str = stringify!($msg);
+                const PACKAGE: &'static str = "ibc.core.channel.v2";
+
+                fn full_name() -> String {
+                    format!("{}.{}", Self::PACKAGE, Self::NAME)
+                }
+
+                fn type_url() -> String {
+                    format!("/{}", Self::full_name())
+                }
+            }
+        )*
+    };
+}
+
+impl_name!(MsgSendPacket, MsgRecvPacket, MsgTimeout, MsgAcknowledgement);
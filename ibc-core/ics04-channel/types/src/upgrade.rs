@@ -0,0 +1,387 @@
+//! Defines the domain types used by the channel upgrade handshake.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::channel::v1::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, Order};
+use crate::error::ChannelError;
+use crate::timeout::{TimeoutHeight, TimeoutTimestamp};
+use crate::Version;
+
+/// The fields of a [`ChannelEnd`](crate::channel::ChannelEnd) that may be
+/// changed during a channel upgrade.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Order, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+
+    /// Checks that the proposed fields are well-formed.
+    ///
+    /// Note: The current IBC version only supports one connection hop.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidState {
+                expected: "upgrade ordering to not be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+
+        verify_connection_hops_length(&self.connection_hops, 1)?;
+
+        if self.version.as_str().trim().is_empty() {
+            return Err(ChannelError::InvalidUpgrade {
+                description: "upgrade version cannot be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = DecodingError;
+
+    fn try_from(raw_fields: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = Order::from_i32(raw_fields.ordering)
+            .map_err(|e| DecodingError::invalid_raw_data(format!("upgrade ordering: {e}")))?;
+
+        let connection_hops = raw_fields
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(UpgradeFields {
+            ordering,
+            connection_hops,
+            version: raw_fields.version.into(),
+        })
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(domain_fields: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: domain_fields.ordering as i32,
+            connection_hops: domain_fields
+                .connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            version: domain_fields.version.to_string(),
+        }
+    }
+}
+
+/// An execution deadline for the channel upgrade handshake, expressed either
+/// as a block height, a block timestamp, or both.
+///
+/// When stored in the host's channel parameters, it is interpreted as a
+/// timeout relative to the block at which flushing begins.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: TimeoutTimestamp,
+}
+
+impl UpgradeTimeout {
+    pub fn new(height: TimeoutHeight, timestamp: TimeoutTimestamp) -> Self {
+        Self { height, timestamp }
+    }
+
+    /// Returns an upgrade timeout that is set at neither a height nor a
+    /// timestamp, which is what the initiating chain stores before flushing.
+    pub fn unset() -> Self {
+        Self::new(TimeoutHeight::Never, TimeoutTimestamp::Never)
+    }
+
+    /// Returns `true` if either the timeout height or the timeout timestamp
+    /// is set.
+    pub fn is_set(&self) -> bool {
+        self.height.is_set() || self.timestamp.is_set()
+    }
+
+    /// Checks if the upgrade timeout has elapsed at the given height or
+    /// timestamp.
+    ///
+    /// Note: Unlike packet timeouts, an upgrade timeout is considered elapsed
+    /// once the given height or timestamp reaches it.
+    pub fn has_elapsed(&self, height: Height, timestamp: &Timestamp) -> bool {
+        let height_elapsed = match self.height {
+            TimeoutHeight::At(timeout_height) => height >= timeout_height,
+            TimeoutHeight::Never => false,
+        };
+        let timestamp_elapsed = match self.timestamp {
+            TimeoutTimestamp::At(timeout_timestamp) => *timestamp >= timeout_timestamp,
+            TimeoutTimestamp::Never => false,
+        };
+
+        height_elapsed || timestamp_elapsed
+    }
+}
+
+impl Display for UpgradeTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeTimeout {{ height: {}, timestamp: {} }}",
+            self.height, self.timestamp
+        )
+    }
+}
+
+impl Protobuf<RawTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = DecodingError;
+
+    fn try_from(raw_timeout: RawTimeout) -> Result<Self, Self::Error> {
+        Ok(UpgradeTimeout {
+            height: raw_timeout.height.try_into()?,
+            timestamp: raw_timeout.timestamp.into(),
+        })
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(domain_timeout: UpgradeTimeout) -> Self {
+        RawTimeout {
+            height: domain_timeout.height.into(),
+            timestamp: domain_timeout.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// A verifiable record of an attempted channel upgrade. It contains the
+/// proposed changes to the channel end, the timeout for the upgrade attempt
+/// and the next send sequence at the time flushing began.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Upgrade {
+    pub fn new(
+        fields: UpgradeFields,
+        timeout: UpgradeTimeout,
+        next_sequence_send: Sequence,
+    ) -> Self {
+        Self {
+            fields,
+            timeout,
+            next_sequence_send,
+        }
+    }
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = DecodingError;
+
+    fn try_from(raw_upgrade: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Upgrade {
+            fields: raw_upgrade
+                .fields
+                .ok_or(DecodingError::missing_raw_data("upgrade fields"))?
+                .try_into()?,
+            timeout: raw_upgrade
+                .timeout
+                .ok_or(DecodingError::missing_raw_data("upgrade timeout"))?
+                .try_into()?,
+            next_sequence_send: raw_upgrade.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(domain_upgrade: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(domain_upgrade.fields.into()),
+            timeout: Some(domain_upgrade.timeout.into()),
+            next_sequence_send: domain_upgrade.next_sequence_send.into(),
+        }
+    }
+}
+
+/// Records the upgrade sequence and the cause of an aborted upgrade
+/// handshake, so that the counterparty can prove the abort and cancel its
+/// own side of the upgrade.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReceipt {
+    pub sequence: u64,
+    pub message: String,
+}
+
+impl ErrorReceipt {
+    pub fn new(sequence: u64, message: impl Into<String>) -> Self {
+        Self {
+            sequence,
+            message: message.into(),
+        }
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl TryFrom<RawErrorReceipt> for ErrorReceipt {
+    type Error = DecodingError;
+
+    fn try_from(raw_receipt: RawErrorReceipt) -> Result<Self, Self::Error> {
+        Ok(ErrorReceipt {
+            sequence: raw_receipt.sequence,
+            message: raw_receipt.message,
+        })
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(domain_receipt: ErrorReceipt) -> Self {
+        RawErrorReceipt {
+            sequence: domain_receipt.sequence,
+            message: domain_receipt.message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use super::*;
+
+    fn raw_upgrade() -> RawUpgrade {
+        RawUpgrade {
+            fields: Some(RawUpgradeFields {
+                ordering: Order::Unordered as i32,
+                connection_hops: vec!["connection-0".to_string()],
+                version: "ics20-1".to_string(),
+            }),
+            timeout: Some(RawTimeout {
+                height: Some(RawHeight {
+                    revision_number: 0,
+                    revision_height: 10,
+                }),
+                timestamp: 0,
+            }),
+            next_sequence_send: 1,
+        }
+    }
+
+    #[test]
+    fn upgrade_raw_roundtrip() {
+        let raw = raw_upgrade();
+        let upgrade = Upgrade::try_from(raw.clone()).expect("valid raw upgrade");
+        assert!(upgrade.fields.validate_basic().is_ok());
+        assert!(upgrade.timeout.is_set());
+        assert_eq!(RawUpgrade::from(upgrade), raw);
+    }
+
+    #[test]
+    fn upgrade_missing_fields_fails() {
+        let raw = RawUpgrade {
+            fields: None,
+            ..raw_upgrade()
+        };
+        assert!(Upgrade::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn upgrade_timeout_elapsed() {
+        let timeout = UpgradeTimeout::new(
+            TimeoutHeight::At(Height::new(0, 10).expect("valid height")),
+            TimeoutTimestamp::Never,
+        );
+        let now = Timestamp::from_nanoseconds(1);
+
+        assert!(!timeout.has_elapsed(Height::new(0, 9).expect("valid height"), &now));
+        assert!(timeout.has_elapsed(Height::new(0, 10).expect("valid height"), &now));
+        assert!(!UpgradeTimeout::unset().is_set());
+    }
+}
@@ -0,0 +1,192 @@
+//! Defines the IBC v2 acknowledgement type and the result of receiving a
+//! payload.
+
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+use sha2::Digest;
+
+use crate::proto::v2::Acknowledgement as RawAcknowledgement;
+
+/// The preimage of the acknowledgement written when the reception of any of
+/// the payloads of a packet failed.
+const UNIVERSAL_ERROR_ACKNOWLEDGEMENT: &[u8] = b"UNIVERSAL_ERROR_ACKNOWLEDGEMENT";
+
+/// Returns the sentinel application acknowledgement of a failed packet,
+/// which is `sha256("UNIVERSAL_ERROR_ACKNOWLEDGEMENT")` as defined by ibc-go.
+pub fn error_app_acknowledgement() -> Vec<u8> {
+    sha2::Sha256::digest(UNIVERSAL_ERROR_ACKNOWLEDGEMENT).to_vec()
+}
+
+/// Acknowledgement of an IBC v2 packet.
+///
+/// A successful acknowledgement holds one application acknowledgement per
+/// payload, in the order of the payloads. If the reception of any payload
+/// failed, the acknowledgement instead holds a single sentinel error
+/// acknowledgement, see [`Acknowledgement::error`].
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acknowledgement {
+    pub app_acknowledgements: Vec<Vec<u8>>,
+}
+
+impl Acknowledgement {
+    pub fn new(app_acknowledgements: Vec<Vec<u8>>) -> Self {
+        Self {
+            app_acknowledgements,
+        }
+    }
+
+    /// The acknowledgement written when the reception of a payload failed.
+    pub fn error() -> Self {
+        Self::new(vec![error_app_acknowledgement()])
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self.app_acknowledgements.as_slice(),
+            [ack] if *ack == error_app_acknowledgement()
+        )
+    }
+}
+
+impl Protobuf<RawAcknowledgement> for Acknowledgement {}
+
+impl TryFrom<RawAcknowledgement> for Acknowledgement {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAcknowledgement) -> Result<Self, Self::Error> {
+        if raw.app_acknowledgements.is_empty() {
+            return Err(DecodingError::missing_raw_data("app acknowledgements"));
+        }
+
+        if raw.app_acknowledgements.iter().any(Vec::is_empty) {
+            return Err(DecodingError::invalid_raw_data(
+                "app acknowledgements cannot be empty",
+            ));
+        }
+
+        Ok(Self::new(raw.app_acknowledgements))
+    }
+}
+
+impl From<Acknowledgement> for RawAcknowledgement {
+    fn from(ack: Acknowledgement) -> Self {
+        Self {
+            app_acknowledgements: ack.app_acknowledgements,
+        }
+    }
+}
+
+/// Status of the reception of a payload by an application.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketStatus {
+    /// The payload was processed, and its acknowledgement is written right
+    /// away.
+    Success,
+    /// The payload could not be processed. The packet is acknowledged with
+    /// the error acknowledgement.
+    Failure,
+    /// The payload acknowledgement will be written later by the application.
+    Async,
+}
+
+/// Result of the reception of a payload by an application.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecvPacketResult {
+    pub status: PacketStatus,
+    /// The application acknowledgement. Ignored unless the status is
+    /// [`PacketStatus::Success`].
+    pub acknowledgement: Vec<u8>,
+}
+
+impl RecvPacketResult {
+    pub fn success(acknowledgement: Vec<u8>) -> Self {
+        Self {
+            status: PacketStatus::Success,
+            acknowledgement,
+        }
+    }
+
+    pub fn failure() -> Self {
+        Self {
+            status: PacketStatus::Failure,
+            acknowledgement: Vec::new(),
+        }
+    }
+
+    pub fn async_ack() -> Self {
+        Self {
+            status: PacketStatus::Async,
+            acknowledgement: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_acknowledgement() {
+        assert!(Acknowledgement::error().is_error());
+        assert!(!Acknowledgement::new(vec![b"ok".to_vec()]).is_error());
+        assert!(!Acknowledgement::new(vec![
+            error_app_acknowledgement(),
+            error_app_acknowledgement()
+        ])
+        .is_error());
+    }
+
+    #[test]
+    fn test_acknowledgement_conversion() {
+        assert!(Acknowledgement::try_from(RawAcknowledgement {
+            app_acknowledgements: vec![]
+        })
+        .is_err());
+        assert!(Acknowledgement::try_from(RawAcknowledgement {
+            app_acknowledgements: vec![b"ok".to_vec(), vec![]]
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! Commitments of IBC v2 packets and acknowledgements, in the format defined
+//! by ibc-go.
+
+use ibc_primitives::prelude::*;
+
+use super::acknowledgement::Acknowledgement;
+use super::packet::{Packet, Payload};
+use crate::commitment::{hash, AcknowledgementCommitment, PacketCommitment};
+
+/// The version byte prepended to IBC v2 commitments.
+const COMMITMENT_VERSION: u8 = 2;
+
+/// Computes the commitment of a packet:
+///
+/// `sha256(0x02 || sha256(destination_client) || sha256(timeout) || sha256(payload hashes))`
+///
+/// where the timeout is encoded as a big-endian `u64`.
+pub fn compute_packet_commitment(packet: &Packet) -> PacketCommitment {
+    let mut app_bytes = Vec::with_capacity(32 * packet.payloads.len());
+    for payload in &packet.payloads {
+        app_bytes.extend_from_slice(&hash_payload(payload));
+    }
+
+    let mut hash_input = Vec::with_capacity(1 + 32 * 3);
+    hash_input.push(COMMITMENT_VERSION);
+    hash_input.extend_from_slice(&hash(packet.destination_client.as_bytes()));
+    hash_input.extend_from_slice(&hash(&packet.timeout_timestamp.to_be_bytes()));
+    hash_input.extend_from_slice(&hash(&app_bytes));
+
+    hash(&hash_input).to_vec().into()
+}
+
+/// Computes the commitment of an acknowledgement:
+///
+/// `sha256(0x02 || sha256(app_ack_1) || ... || sha256(app_ack_n))`
+pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
+    let mut hash_input = Vec::with_capacity(1 + 32 * ack.app_acknowledgements.len());
+    hash_input.push(COMMITMENT_VERSION);
+    for app_ack in &ack.app_acknowledgements {
+        hash_input.extend_from_slice(&hash(app_ack));
+    }
+
+    hash(&hash_input).to_vec().into()
+}
+
+fn hash_payload(payload: &Payload) -> [u8; 32] {
+    let mut hash_input = Vec::with_capacity(32 * 5);
+    hash_input.extend_from_slice(&hash(payload.source_port.as_bytes()));
+    hash_input.extend_from_slice(&hash(payload.destination_port.as_bytes()));
+    hash_input.extend_from_slice(&hash(payload.version.as_bytes()));
+    hash_input.extend_from_slice(&hash(payload.encoding.as_bytes()));
+    hash_input.extend_from_slice(&hash(&payload.value));
+
+    hash(&hash_input)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+
+    use super::*;
+
+    fn packet(destination_client: &str, timeout_timestamp: u64) -> Packet {
+        Packet {
+            sequence: Sequence::from(1),
+            source_client: "07-tendermint-0".parse().unwrap(),
+            destination_client: destination_client.parse::<ClientId>().unwrap(),
+            timeout_timestamp,
+            payloads: vec![Payload {
+                source_port: PortId::transfer(),
+                destination_port: PortId::transfer(),
+                version: "ics20-1".to_string(),
+                encoding: "application/json".to_string(),
+                value: b"value".to_vec(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_packet_commitment_binds_routing_fields() {
+        let commitment = compute_packet_commitment(&packet("07-tendermint-1", 10));
+        assert_eq!(commitment.as_ref().len(), 32);
+
+        // The source client and sequence are part of the path, not of the
+        // commitment.
+        let mut other_source = packet("07-tendermint-1", 10);
+        other_source.source_client = "07-tendermint-2".parse().unwrap();
+        assert_eq!(compute_packet_commitment(&other_source), commitment);
+
+        assert_ne!(
+            compute_packet_commitment(&packet("07-tendermint-2", 10)),
+            commitment
+        );
+        assert_ne!(
+            compute_packet_commitment(&packet("07-tendermint-1", 11)),
+            commitment
+        );
+    }
+
+    #[test]
+    fn test_ack_commitment() {
+        let ack = Acknowledgement::new(vec![b"ok".to_vec()]);
+
+        let mut hash_input = vec![COMMITMENT_VERSION];
+        hash_input.extend_from_slice(&hash(b"ok"));
+        assert_eq!(
+            compute_ack_commitment(&ack).as_ref(),
+            hash(&hash_input).as_slice()
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Types for the IBC v2 packet events.
+//!
+//! The events share their types with the IBC v1 packet events, but carry
+//! the attributes defined by ibc-go for IBC v2, including the hex encoded
+//! protobuf packet.
+
+use core::str;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use super::acknowledgement::Acknowledgement;
+use super::packet::Packet;
+use crate::events::{
+    ACK_PACKET_EVENT, RECEIVE_PACKET_EVENT, SEND_PACKET_EVENT, TIMEOUT_EVENT, WRITE_ACK_EVENT,
+};
+use crate::proto::v2::{Acknowledgement as RawAcknowledgement, Packet as RawPacket};
+
+const PKT_SRC_CLIENT_ATTRIBUTE_KEY: &str = "packet_source_client";
+const PKT_DST_CLIENT_ATTRIBUTE_KEY: &str = "packet_dest_client";
+const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
+const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
+const PKT_HEX_ATTRIBUTE_KEY: &str = "encoded_packet_hex";
+const ACK_HEX_ATTRIBUTE_KEY: &str = "encoded_acknowledgement_hex";
+
+fn encode_hex(bytes: &[u8]) -> String {
+    str::from_utf8(&hex::encode(bytes))
+        .expect("Never fails because hexadecimal is valid UTF8")
+        .to_string()
+}
+
+fn packet_attributes(packet: Packet) -> Vec<abci::EventAttribute> {
+    vec![
+        (
+            PKT_SRC_CLIENT_ATTRIBUTE_KEY,
+            packet.source_client.to_string(),
+        )
+            .into(),
+        (
+            PKT_DST_CLIENT_ATTRIBUTE_KEY,
+            packet.destination_client.to_string(),
+        )
+            .into(),
+        (PKT_SEQ_ATTRIBUTE_KEY, packet.sequence.to_string()).into(),
+        (
+            PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            packet.timeout_timestamp.to_string(),
+        )
+            .into(),
+        (
+            PKT_HEX_ATTRIBUTE_KEY,
+            encode_hex(&Protobuf::<RawPacket>::encode_vec(packet)),
+        )
+            .into(),
+    ]
+}
+
+macro_rules! packet_event {
+    ($(#[$doc:meta])* $name:ident, $event_type:expr) => {
+        $(#[$doc])*
+        #[cfg_attr(
+            feature = "parity-scale-codec",
+            derive(
+                parity_scale_codec::Encode,
+                parity_scale_codec::Decode,
+                scale_info::TypeInfo
+            )
+        )]
+        #[cfg_attr(
+            feature = "borsh",
+            derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+        )]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            packet: Packet,
+        }
+
+        impl $name {
+            pub fn new(packet: Packet) -> Self {
+                Self { packet }
+            }
+
+            pub fn packet(&self) -> &Packet {
+                &self.packet
+            }
+
+            pub fn event_type(&self) -> &str {
+                $event_type
+            }
+        }
+
+        impl From<$name> for abci::Event {
+            fn from(event: $name) -> Self {
+                abci::Event {
+                    kind: $event_type.to_string(),
+                    attributes: packet_attributes(event.packet),
+                }
+            }
+        }
+    };
+}
+
+packet_event!(
+    /// Emitted when an IBC v2 packet is sent.
+    SendPacket,
+    SEND_PACKET_EVENT
+);
+
+packet_event!(
+    /// Emitted when an IBC v2 packet is received.
+    ReceivePacket,
+    RECEIVE_PACKET_EVENT
+);
+
+packet_event!(
+    /// Emitted when an IBC v2 packet is acknowledged on the sending chain.
+    AcknowledgePacket,
+    ACK_PACKET_EVENT
+);
+
+packet_event!(
+    /// Emitted when an IBC v2 packet times out on the sending chain.
+    TimeoutPacket,
+    TIMEOUT_EVENT
+);
+
+/// Emitted when the acknowledgement of an IBC v2 packet is written on the
+/// receiving chain.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WriteAcknowledgement {
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+}
+
+impl WriteAcknowledgement {
+    pub fn new(packet: Packet, acknowledgement: Acknowledgement) -> Self {
+        Self {
+            packet,
+            acknowledgement,
+        }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn acknowledgement(&self) -> &Acknowledgement {
+        &self.acknowledgement
+    }
+
+    pub fn event_type(&self) -> &str {
+        WRITE_ACK_EVENT
+    }
+}
+
+impl From<WriteAcknowledgement> for abci::Event {
+    fn from(event: WriteAcknowledgement) -> Self {
+        let mut attributes = packet_attributes(event.packet);
+        attributes.push(
+            (
+                ACK_HEX_ATTRIBUTE_KEY,
+                encode_hex(&Protobuf::<RawAcknowledgement>::encode_vec(
+                    event.acknowledgement,
+                )),
+            )
+                .into(),
+        );
+
+        abci::Event {
+            kind: WRITE_ACK_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Data structures of IBC v2 (also known as IBC Eureka), in which packets are
+//! sent directly between a pair of counterparty clients, without connection
+//! or channel handshakes.
+
+pub mod acknowledgement;
+pub mod commitment;
+pub mod events;
+pub mod msgs;
+pub mod packet;
@@ -0,0 +1,73 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::proto::v2::MsgAcknowledgement as RawMsgAcknowledgement;
+use crate::v2::acknowledgement::Acknowledgement;
+use crate::v2::packet::Packet;
+
+pub const ACKNOWLEDGEMENT_TYPE_URL: &str = "/ibc.core.channel.v2.MsgAcknowledgement";
+
+/// Message definition for acknowledging an IBC v2 packet.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgAcknowledgement {
+    pub packet: Packet,
+    pub acknowledgement: Acknowledgement,
+    /// Proof of the acknowledgement commitment on the receiving chain
+    pub proof_acked: CommitmentProofBytes,
+    /// Height at which the acknowledgement proof was taken
+    pub proof_height: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgAcknowledgement> for MsgAcknowledgement {}
+
+impl TryFrom<RawMsgAcknowledgement> for MsgAcknowledgement {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgAcknowledgement) -> Result<Self, Self::Error> {
+        Ok(MsgAcknowledgement {
+            packet: raw_msg
+                .packet
+                .ok_or(DecodingError::missing_raw_data(
+                    "msg acknowledgement packet data",
+                ))?
+                .try_into()?,
+            acknowledgement: raw_msg
+                .acknowledgement
+                .ok_or(DecodingError::missing_raw_data(
+                    "msg acknowledgement acknowledgement",
+                ))?
+                .try_into()?,
+            proof_acked: raw_msg.proof_acked.try_into()?,
+            proof_height: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::invalid_raw_data(
+                    "msg acknowledgement proof height",
+                ))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgAcknowledgement> for RawMsgAcknowledgement {
+    fn from(domain_msg: MsgAcknowledgement) -> Self {
+        RawMsgAcknowledgement {
+            packet: Some(domain_msg.packet.into()),
+            acknowledgement: Some(domain_msg.acknowledgement.into()),
+            proof_acked: domain_msg.proof_acked.into(),
+            proof_height: Some(domain_msg.proof_height.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Message definitions of the IBC v2 packet datagrams.
+
+mod acknowledgement;
+mod recv_packet;
+mod send_packet;
+mod timeout;
+
+pub use acknowledgement::*;
+use ibc_primitives::prelude::*;
+pub use recv_packet::*;
+pub use send_packet::*;
+pub use timeout::*;
+
+/// All IBC v2 packet messages
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub enum PacketMsg {
+    Send(MsgSendPacket),
+    Recv(MsgRecvPacket),
+    Ack(MsgAcknowledgement),
+    Timeout(MsgTimeout),
+}
@@ -0,0 +1,60 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::proto::v2::MsgRecvPacket as RawMsgRecvPacket;
+use crate::v2::packet::Packet;
+
+pub const RECV_PACKET_TYPE_URL: &str = "/ibc.core.channel.v2.MsgRecvPacket";
+
+/// Message definition for receiving an IBC v2 packet.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRecvPacket {
+    pub packet: Packet,
+    /// Proof of the packet commitment on the sending chain
+    pub proof_commitment: CommitmentProofBytes,
+    /// Height at which the commitment proof was taken
+    pub proof_height: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgRecvPacket> for MsgRecvPacket {}
+
+impl TryFrom<RawMsgRecvPacket> for MsgRecvPacket {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgRecvPacket) -> Result<Self, Self::Error> {
+        Ok(MsgRecvPacket {
+            packet: raw_msg
+                .packet
+                .ok_or(DecodingError::missing_raw_data("msg recv packet data"))?
+                .try_into()?,
+            proof_commitment: raw_msg.proof_commitment.try_into()?,
+            proof_height: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::invalid_raw_data("msg recv proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgRecvPacket> for RawMsgRecvPacket {
+    fn from(domain_msg: MsgRecvPacket) -> Self {
+        RawMsgRecvPacket {
+            packet: Some(domain_msg.packet.into()),
+            proof_commitment: domain_msg.proof_commitment.into(),
+            proof_height: Some(domain_msg.proof_height.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::proto::v2::MsgSendPacket as RawMsgSendPacket;
+use crate::v2::packet::Payload;
+
+pub const SEND_PACKET_TYPE_URL: &str = "/ibc.core.channel.v2.MsgSendPacket";
+
+/// Message definition for sending an IBC v2 packet.
+///
+/// The destination client and the packet sequence are not part of the
+/// message: the former is the registered counterparty of the source client,
+/// and the latter is assigned by the host.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSendPacket {
+    pub source_client: ClientId,
+    /// Timeout timestamp, in seconds since the Unix epoch
+    pub timeout_timestamp: u64,
+    pub payloads: Vec<Payload>,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgSendPacket> for MsgSendPacket {}
+
+impl TryFrom<RawMsgSendPacket> for MsgSendPacket {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgSendPacket) -> Result<Self, Self::Error> {
+        if raw_msg.timeout_timestamp == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "msg send packet timeout timestamp cannot be 0",
+            ));
+        }
+
+        if raw_msg.payloads.is_empty() {
+            return Err(DecodingError::missing_raw_data("msg send packet payloads"));
+        }
+
+        // As in ibc-go, packets carry a single payload for now.
+        if raw_msg.payloads.len() > 1 {
+            return Err(DecodingError::invalid_raw_data(
+                "msg send packet cannot carry more than one payload",
+            ));
+        }
+
+        Ok(MsgSendPacket {
+            source_client: raw_msg.source_client.parse()?,
+            timeout_timestamp: raw_msg.timeout_timestamp,
+            payloads: raw_msg
+                .payloads
+                .into_iter()
+                .map(Payload::try_from)
+                .collect::<Result<_, _>>()?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgSendPacket> for RawMsgSendPacket {
+    fn from(domain_msg: MsgSendPacket) -> Self {
+        RawMsgSendPacket {
+            source_client: domain_msg.source_client.to_string(),
+            timeout_timestamp: domain_msg.timeout_timestamp,
+            payloads: domain_msg.payloads.into_iter().map(Into::into).collect(),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::error::DecodingError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::proto::v2::MsgTimeout as RawMsgTimeout;
+use crate::v2::packet::Packet;
+
+pub const TIMEOUT_TYPE_URL: &str = "/ibc.core.channel.v2.MsgTimeout";
+
+/// Message definition for timing out an IBC v2 packet, which proves that
+/// the packet was not received by the counterparty before its timeout.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgTimeout {
+    pub packet: Packet,
+    /// Proof of the absence of the packet receipt on the receiving chain
+    pub proof_unreceived: CommitmentProofBytes,
+    /// Height at which the absence proof was taken
+    pub proof_height: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgTimeout> for MsgTimeout {}
+
+impl TryFrom<RawMsgTimeout> for MsgTimeout {
+    type Error = DecodingError;
+
+    fn try_from(raw_msg: RawMsgTimeout) -> Result<Self, Self::Error> {
+        Ok(MsgTimeout {
+            packet: raw_msg
+                .packet
+                .ok_or(DecodingError::missing_raw_data("msg timeout packet data"))?
+                .try_into()?,
+            proof_unreceived: raw_msg.proof_unreceived.try_into()?,
+            proof_height: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(DecodingError::missing_raw_data("msg timeout proof height"))?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgTimeout> for RawMsgTimeout {
+    fn from(domain_msg: MsgTimeout) -> Self {
+        RawMsgTimeout {
+            packet: Some(domain_msg.packet.into()),
+            proof_unreceived: domain_msg.proof_unreceived.into(),
+            proof_height: Some(domain_msg.proof_height.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Defines the IBC v2 packet type.
+
+use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::Protobuf;
+
+use crate::proto::v2::{Packet as RawPacket, Payload as RawPayload};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Application data carried by an IBC v2 packet.
+///
+/// Payloads are routed to the application bound to `destination_port` on
+/// the receiving chain, and back to the one bound to `source_port` on the
+/// sending chain for acknowledgements and timeouts.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Payload {
+    pub source_port: PortId,
+    pub destination_port: PortId,
+    pub version: String,
+    pub encoding: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
+    )]
+    pub value: Vec<u8>,
+}
+
+impl Protobuf<RawPayload> for Payload {}
+
+impl TryFrom<RawPayload> for Payload {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawPayload) -> Result<Self, Self::Error> {
+        if raw.version.is_empty() {
+            return Err(DecodingError::missing_raw_data("payload version"));
+        }
+
+        if raw.encoding.is_empty() {
+            return Err(DecodingError::missing_raw_data("payload encoding"));
+        }
+
+        if raw.value.is_empty() {
+            return Err(DecodingError::missing_raw_data("payload value"));
+        }
+
+        Ok(Self {
+            source_port: raw.source_port.parse()?,
+            destination_port: raw.destination_port.parse()?,
+            version: raw.version,
+            encoding: raw.encoding,
+            value: raw.value,
+        })
+    }
+}
+
+impl From<Payload> for RawPayload {
+    fn from(payload: Payload) -> Self {
+        Self {
+            source_port: payload.source_port.to_string(),
+            destination_port: payload.destination_port.to_string(),
+            version: payload.version,
+            encoding: payload.encoding,
+            value: payload.value,
+        }
+    }
+}
+
+/// The IBC v2 packet type.
+///
+/// Unlike IBC v1 packets, which travel over a channel, IBC v2 packets are
+/// sent directly from a client on the sending chain to its counterparty
+/// client on the receiving chain. The protocol allows several payloads per
+/// packet, but as in ibc-go, packets carry exactly one payload for now.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Packet {
+    pub sequence: Sequence,
+    pub source_client: ClientId,
+    pub destination_client: ClientId,
+    /// Timeout timestamp, in seconds since the Unix epoch
+    pub timeout_timestamp: u64,
+    pub payloads: Vec<Payload>,
+}
+
+impl Packet {
+    /// Checks whether the packet has timed out at the given timestamp of the
+    /// receiving chain.
+    pub fn has_expired(&self, timestamp: &Timestamp) -> bool {
+        timestamp.nanoseconds() >= self.timeout_timestamp_nanos()
+    }
+
+    /// Returns the timeout timestamp in nanoseconds, saturating at the
+    /// largest representable timestamp.
+    pub fn timeout_timestamp_nanos(&self) -> u64 {
+        self.timeout_timestamp.saturating_mul(NANOS_PER_SECOND)
+    }
+}
+
+impl Protobuf<RawPacket> for Packet {}
+
+impl TryFrom<RawPacket> for Packet {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawPacket) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "packet sequence cannot be 0",
+            ));
+        }
+
+        if raw.timeout_timestamp == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "packet timeout timestamp cannot be 0",
+            ));
+        }
+
+        if raw.payloads.is_empty() {
+            return Err(DecodingError::missing_raw_data("packet payloads"));
+        }
+
+        // As in ibc-go, packets carry a single payload for now.
+        if raw.payloads.len() > 1 {
+            return Err(DecodingError::invalid_raw_data(
+                "packet cannot carry more than one payload",
+            ));
+        }
+
+        Ok(Self {
+            sequence: raw.sequence.into(),
+            source_client: raw.source_client.parse()?,
+            destination_client: raw.destination_client.parse()?,
+            timeout_timestamp: raw.timeout_timestamp,
+            payloads: raw
+                .payloads
+                .into_iter()
+                .map(Payload::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Packet> for RawPacket {
+    fn from(packet: Packet) -> Self {
+        Self {
+            sequence: packet.sequence.into(),
+            source_client: packet.source_client.to_string(),
+            destination_client: packet.destination_client.to_string(),
+            timeout_timestamp: packet.timeout_timestamp,
+            payloads: packet.payloads.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_payload() -> RawPayload {
+        RawPayload {
+            source_port: "transfer".to_string(),
+            destination_port: "transfer".to_string(),
+            version: "ics20-1".to_string(),
+            encoding: "application/json".to_string(),
+            value: b"value".to_vec(),
+        }
+    }
+
+    fn raw_packet() -> RawPacket {
+        RawPacket {
+            sequence: 1,
+            source_client: "07-tendermint-0".to_string(),
+            destination_client: "07-tendermint-1".to_string(),
+            timeout_timestamp: 10,
+            payloads: vec![raw_payload()],
+        }
+    }
+
+    #[test]
+    fn test_packet_conversion() {
+        let packet = Packet::try_from(raw_packet()).unwrap();
+        assert_eq!(RawPacket::from(packet), raw_packet());
+
+        for raw in [
+            RawPacket {
+                sequence: 0,
+                ..raw_packet()
+            },
+            RawPacket {
+                timeout_timestamp: 0,
+                ..raw_packet()
+            },
+            RawPacket {
+                payloads: vec![],
+                ..raw_packet()
+            },
+            RawPacket {
+                payloads: vec![raw_payload(), raw_payload()],
+                ..raw_packet()
+            },
+            RawPacket {
+                destination_client: "".to_string(),
+                ..raw_packet()
+            },
+            RawPacket {
+                payloads: vec![RawPayload {
+                    encoding: "".to_string(),
+                    ..raw_payload()
+                }],
+                ..raw_packet()
+            },
+        ] {
+            assert!(Packet::try_from(raw).is_err());
+        }
+    }
+
+    #[test]
+    fn test_packet_expiry() {
+        let packet = Packet::try_from(raw_packet()).unwrap();
+
+        assert!(!packet.has_expired(&Timestamp::from_nanoseconds(9_999_999_999)));
+        assert!(packet.has_expired(&Timestamp::from_nanoseconds(10_000_000_000)));
+    }
+}
@@ -1,4 +1,4 @@
-use ibc_app_transfer_types::VERSION;
+use ibc_app_transfer_types::{PrefixedDenom, VERSION};
 use ibc_core_host_types::identifiers::{ChannelId, PortId};
 use ibc_primitives::prelude::*;
 use sha2::{Digest, Sha256};
@@ -19,6 +19,29 @@ pub fn cosmos_adr028_escrow_address(port_id: &PortId, channel_id: &ChannelId) ->
     hash
 }
 
+/// Helper function to compute the hash of a denomination, as done by the Cosmos
+/// SDK to name vouchers `ibc/<hash>`.
+///
+/// The hash is the upper-case hex encoding of the SHA-256 digest of the full
+/// denomination path, without the `ibc/` prefix.
+pub fn cosmos_denom_hash(denom: &PrefixedDenom) -> String {
+    upper_hex_sha256(denom.to_string().as_bytes())
+}
+
+/// Helper function to compute the hash of an NFT class, given as its full
+/// path, as done by the Cosmos SDK NFT transfer module to name the classes of
+/// vouchers `ibc/<hash>`.
+pub fn cosmos_class_hash(class_path: &str) -> String {
+    upper_hex_sha256(class_path.as_bytes())
+}
+
+fn upper_hex_sha256(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use subtle_encoding::bech32;
@@ -54,4 +77,23 @@ mod tests {
             "cosmos177x69sver58mcfs74x6dg0tv6ls4s3xmmcaw53",
         );
     }
+
+    #[test]
+    fn test_cosmos_denom_hash() {
+        // ATOM on Osmosis
+        let denom = "transfer/channel-0/uatom".parse().unwrap();
+
+        assert_eq!(
+            cosmos_denom_hash(&denom),
+            "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn test_cosmos_class_hash() {
+        assert_eq!(
+            cosmos_class_hash("transfer/channel-0/uatom"),
+            cosmos_denom_hash(&"transfer/channel-0/uatom".parse().unwrap())
+        );
+    }
 }
@@ -3,17 +3,19 @@ use core::time::Duration;
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, UpgradeTimeout};
 use ibc_core_client_context::prelude::*;
-use ibc_core_client_types::Height;
+use ibc_core_client_types::{CounterpartyInfo, Height};
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::events::IbcEvent;
 use ibc_core_host_types::error::HostError;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    v2 as path_v2, AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, CommitmentPath, ConnectionPath, CounterpartyChannelUpgradePath,
+    ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc_primitives::prelude::*;
 use ibc_primitives::{Signer, Timestamp};
@@ -101,7 +103,8 @@ pub trait ValidationContext {
     /// Returns the sequence number for the next packet to be acknowledged for the given store path
     fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, HostError>;
 
-    /// Returns the packet commitment for the given store path
+    /// Returns the packet commitment for the given store path, or
+    /// [`HostError::MissingState`] if there is none.
     fn get_packet_commitment(
         &self,
         commitment_path: &CommitmentPath,
@@ -114,7 +117,8 @@ pub trait ValidationContext {
     /// If the receipt is present in the host's state, return `Receipt::Ok`,
     /// indicating the packet has already been processed. If the receipt is
     /// absent, return `Receipt::None`, indicating the packet has not been
-    /// received.
+    /// received. A packet skipped over by an `ORDERED_ALLOW_TIMEOUT` channel
+    /// because it timed out has a `Receipt::Timeout`.
     fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, HostError>;
 
     /// Returns the packet acknowledgement for the given store path
@@ -123,6 +127,93 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, HostError>;
 
+    /// Returns the sequences of the packets received on the given channel
+    /// whose acknowledgement is to be written asynchronously, and was not
+    /// written yet.
+    fn pending_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, HostError>;
+
+    /// Returns the upgrade proposed for the channel at the given store path,
+    /// or `None` if no upgrade is in progress.
+    fn channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, HostError>;
+
+    /// Returns the upgrade proposed by the counterparty, which is stored while
+    /// the channel is flushing its in-flight packets, or `None` if absent.
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, HostError>;
+
+    /// Returns the error receipt of the last aborted upgrade of the channel
+    /// at the given store path, or `None` if no upgrade was ever aborted.
+    fn channel_upgrade_error(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<Option<ErrorReceipt>, HostError>;
+
+    /// Returns the sequences of the packets sent on the given channel whose
+    /// commitment is still stored, i.e. for which no acknowledgement or
+    /// timeout was processed yet.
+    fn packet_commitment_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, HostError>;
+
+    /// Returns `true` if the channel at the given store path still has packet
+    /// commitments for which no acknowledgement or timeout was processed.
+    fn has_inflight_packets(&self, channel_end_path: &ChannelEndPath) -> Result<bool, HostError> {
+        Ok(!self
+            .packet_commitment_sequences(channel_end_path)?
+            .is_empty())
+    }
+
+    /// Returns the timeout, relative to the block at which flushing starts,
+    /// after which channel upgrades will time out.
+    fn channel_upgrade_timeout(&self) -> Result<UpgradeTimeout, HostError>;
+
+    /// Returns `true` if the given `signer` is allowed to initiate channel
+    /// upgrades and to cancel them without a proof of an error receipt.
+    fn is_channel_upgrade_authority(&self, signer: &Signer) -> Result<bool, HostError>;
+
+    /// Returns the counterparty registered for the given client, or `None`
+    /// if the client cannot be used to send or receive IBC v2 packets yet.
+    fn client_counterparty(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<CounterpartyInfo>, HostError>;
+
+    /// Returns the sequence number of the next IBC v2 packet to be sent over
+    /// the given client.
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &path_v2::SeqSendPath,
+    ) -> Result<Sequence, HostError>;
+
+    /// Returns the IBC v2 packet commitment for the given store path, or
+    /// [`HostError::MissingState`] if there is none.
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &path_v2::CommitmentPath,
+    ) -> Result<PacketCommitment, HostError>;
+
+    /// Returns the IBC v2 packet receipt for the given store path, with the
+    /// same semantics as [`ValidationContext::get_packet_receipt`].
+    fn get_packet_receipt_v2(
+        &self,
+        receipt_path: &path_v2::ReceiptPath,
+    ) -> Result<Receipt, HostError>;
+
+    /// Returns the IBC v2 packet acknowledgement for the given store path
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &path_v2::AckPath,
+    ) -> Result<AcknowledgementCommitment, HostError>;
+
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
     /// `ExecutionContext::increase_channel_counter`.
@@ -186,7 +277,9 @@ pub trait ExecutionContext: ValidationContext {
         commitment_path: &CommitmentPath,
     ) -> Result<(), HostError>;
 
-    /// Stores the given packet receipt at the given store path
+    /// Stores the given packet receipt at the given store path. The stored
+    /// value must be [`Receipt::as_bytes`], against which the timeout receipts
+    /// of `ORDERED_ALLOW_TIMEOUT` channels are proven.
     fn store_packet_receipt(
         &mut self,
         receipt_path: &ReceiptPath,
@@ -203,6 +296,15 @@ pub trait ExecutionContext: ValidationContext {
     /// Deletes the packet acknowledgement at the given store path
     fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError>;
 
+    /// Records that the acknowledgement to be stored at the given path is to
+    /// be written asynchronously, once the module is done processing the
+    /// packet.
+    fn store_pending_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError>;
+
+    /// Deletes the record of the pending acknowledgement at the given path,
+    /// once written.
+    fn delete_pending_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError>;
+
     /// Stores the given channel_end at a path associated with the port_id and channel_id.
     fn store_channel(
         &mut self,
@@ -231,6 +333,82 @@ pub trait ExecutionContext: ValidationContext {
         seq: Sequence,
     ) -> Result<(), HostError>;
 
+    /// Stores the given upgrade at a path associated with the port_id and channel_id.
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the upgrade stored at the given path.
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), HostError>;
+
+    /// Stores the upgrade proposed by the counterparty at the given path.
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the counterparty upgrade stored at the given path.
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+    ) -> Result<(), HostError>;
+
+    /// Stores the given error receipt at the given path, overwriting any
+    /// previous one.
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), HostError>;
+
+    /// Stores the counterparty registered for the given client.
+    fn store_client_counterparty(
+        &mut self,
+        client_id: &ClientId,
+        counterparty: CounterpartyInfo,
+    ) -> Result<(), HostError>;
+
+    /// Stores the sequence number of the next IBC v2 packet to be sent over
+    /// the given client.
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &path_v2::SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), HostError>;
+
+    /// Stores the given IBC v2 packet commitment at the given store path
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &path_v2::CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), HostError>;
+
+    /// Deletes the IBC v2 packet commitment at the given store path
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &path_v2::CommitmentPath,
+    ) -> Result<(), HostError>;
+
+    /// Stores the given IBC v2 packet receipt at the given store path
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &path_v2::ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), HostError>;
+
+    /// Stores the given IBC v2 packet acknowledgement at the given store path
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &path_v2::AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), HostError>;
+
     /// Called upon channel identifier creation (Init or Try message processing).
     /// Increases the counter, that keeps track of how many channels have been created.
     fn increase_channel_counter(&mut self) -> Result<(), HostError>;
@@ -242,6 +420,29 @@ pub trait ExecutionContext: ValidationContext {
     fn log_message(&mut self, message: String) -> Result<(), HostError>;
 }
 
+/// Context of hosts which can stage the state transitions written to them, so
+/// that those of a batch of messages are either all committed or all
+/// discarded.
+///
+/// Trait used for the `dispatch_batch` entrypoint in the `ibc-core` crate.
+pub trait TransactionalExecutionContext: ExecutionContext {
+    /// Starts staging the state transitions, events and logs written to the
+    /// context, which are observed by the context until committed or
+    /// discarded.
+    fn begin_staging(&mut self);
+
+    /// Commits the staged state transitions, events and logs all at once, and
+    /// stops staging.
+    ///
+    /// Must not fail, as the staged state transitions of the modules are
+    /// committed right after.
+    fn commit_staged(&mut self);
+
+    /// Discards the staged state transitions, events and logs, and stops
+    /// staging.
+    fn discard_staged(&mut self);
+}
+
 /// Convenient type alias for `ClientStateRef`, providing access to client
 /// validation methods within the context.
 pub type ClientStateRef<Ctx> =
@@ -6,6 +6,9 @@ use ibc_primitives::prelude::*;
 use crate::error::IdentifierError;
 use crate::validate::{validate_client_identifier, validate_client_type};
 
+/// The identifier of the localhost client, which is also its client type.
+const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -68,6 +71,24 @@ impl ClientId {
         self.0.as_bytes()
     }
 
+    /// Returns the identifier of the `09-localhost` client, through which the
+    /// host connects to itself. It is not formed from a counter, since a host
+    /// has at most one such client.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ClientId;
+    /// let client_id = ClientId::localhost();
+    /// assert_eq!(client_id.as_str(), "09-localhost");
+    /// ```
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CLIENT_ID.to_string())
+    }
+
+    /// Check if the client identifier is the one of the localhost client.
+    pub fn is_localhost(&self) -> bool {
+        self.0 == LOCALHOST_CLIENT_ID
+    }
+
     /// Check if the client identifier is for 08-wasm light client.
     pub fn is_wasm_client_id(&self) -> bool {
         const WASM_CLIENT_PREFIX: &str = "08-wasm-";
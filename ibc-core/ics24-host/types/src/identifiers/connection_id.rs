@@ -9,6 +9,10 @@ use crate::validate::validate_connection_identifier;
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 
+/// The identifier of the sentinel connection of the host to itself, which is
+/// not numbered since there is only ever one such connection.
+pub(crate) const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -62,6 +66,23 @@ impl ConnectionId {
     pub fn zero() -> Self {
         Self::new(0)
     }
+
+    /// Returns the identifier of the sentinel connection through which the
+    /// host connects to itself using the `09-localhost` client.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ConnectionId;
+    /// let conn_id = ConnectionId::localhost();
+    /// assert_eq!(&conn_id, "connection-localhost");
+    /// ```
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CONNECTION_ID.to_string())
+    }
+
+    /// Check if the identifier is the one of the localhost connection.
+    pub fn is_localhost(&self) -> bool {
+        self.0 == LOCALHOST_CONNECTION_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
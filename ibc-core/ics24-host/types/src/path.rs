@@ -11,6 +11,8 @@ use ibc_primitives::prelude::*;
 
 use crate::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 
+pub mod v2;
+
 pub const NEXT_CLIENT_SEQUENCE: &str = "nextClientSequence";
 pub const NEXT_CONNECTION_SEQUENCE: &str = "nextConnectionSequence";
 pub const NEXT_CHANNEL_SEQUENCE: &str = "nextChannelSequence";
@@ -29,6 +31,10 @@ pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
 pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
 pub const PACKET_ACK_PREFIX: &str = "acks";
 pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
+pub const CHANNEL_UPGRADE_PREFIX: &str = "channelUpgrades";
+pub const UPGRADE_PREFIX: &str = "upgrades";
+pub const UPGRADE_ERROR_PREFIX: &str = "upgradeError";
+pub const COUNTERPARTY_UPGRADE_PREFIX: &str = "counterpartyUpgrade";
 
 pub const ITERATE_CONSENSUS_STATE_PREFIX: &str = "iterateConsensusStates";
 pub const PROCESSED_TIME: &str = "processedTime";
@@ -99,6 +105,9 @@ pub enum Path {
     Commitment(CommitmentPath),
     Ack(AckPath),
     Receipt(ReceiptPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
+    CounterpartyChannelUpgrade(CounterpartyChannelUpgradePath),
     UpgradeClientState(UpgradeClientStatePath),
     UpgradeConsensusState(UpgradeConsensusStatePath),
 }
@@ -741,6 +750,82 @@ impl UpgradeConsensusStatePath {
     }
 }
 
+/// Path at which the proposed upgrade of a channel is stored.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
+pub struct ChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradePath {
+        ChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// Path at which the error receipt of the last aborted upgrade of a channel
+/// is stored.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_ERROR_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradeErrorPath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradeErrorPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradeErrorPath {
+        ChannelUpgradeErrorPath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// Path at which the upgrade proposed by the counterparty of a channel is
+/// stored while in-flight packets are being flushed.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{CHANNEL_UPGRADE_PREFIX}/{COUNTERPARTY_UPGRADE_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
+pub struct CounterpartyChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl CounterpartyChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> CounterpartyChannelUpgradePath {
+        CounterpartyChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -799,6 +884,7 @@ impl FromStr for Path {
             .or_else(|| parse_commitments(&components))
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .or_else(|| parse_upgrade_client_state(&components))
             .or_else(|| parse_upgrade_consensus_state(&components))
             .ok_or(PathError::ParseFailure {
@@ -1124,6 +1210,38 @@ fn parse_receipts(components: &[&str]) -> Option<Path> {
     )
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    let first = *components.first()?;
+
+    if first != CHANNEL_UPGRADE_PREFIX {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let Some(Path::Ports(PortPath(port_id))) = port else {
+        return None;
+    };
+
+    let Some(SubPath::Channels(channel_id)) = channel else {
+        return None;
+    };
+
+    match components[1] {
+        UPGRADE_PREFIX => Some(ChannelUpgradePath(port_id, channel_id).into()),
+        UPGRADE_ERROR_PREFIX => Some(ChannelUpgradeErrorPath(port_id, channel_id).into()),
+        COUNTERPARTY_UPGRADE_PREFIX => {
+            Some(CounterpartyChannelUpgradePath(port_id, channel_id).into())
+        }
+        _ => None,
+    }
+}
+
 fn parse_upgrade_client_state(components: &[&str]) -> Option<Path> {
     if components.len() != 3 {
         return None;
@@ -1269,6 +1387,21 @@ mod tests {
             sequence: Sequence::from(0),
         })
     )]
+    #[case(
+        "channelUpgrades/upgrades/ports/transfer/channels/channel-0",
+        Path::ChannelUpgrade(ChannelUpgradePath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/upgradeError/ports/transfer/channels/channel-0",
+        Path::ChannelUpgradeError(ChannelUpgradeErrorPath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/counterpartyUpgrade/ports/transfer/channels/channel-0",
+        Path::CounterpartyChannelUpgrade(CounterpartyChannelUpgradePath(
+            PortId::transfer(),
+            ChannelId::zero()
+        ))
+    )]
     #[case(
         "upgradedIBCState/0/upgradedClient",
         Path::UpgradeClientState(UpgradeClientStatePath {
@@ -1294,6 +1427,8 @@ mod tests {
     #[case("clients/clientType")]
     #[case("channels/channel-0")]
     #[case("sequences/0")]
+    #[case("channelUpgrades/upgrades/ports/transfer")]
+    #[case("channelUpgrades/unknown/ports/transfer/channels/channel-0")]
     fn test_failure_parsing(#[case] path_str: &str) {
         // cannot be parsed into Path
         assert!(Path::from_str(path_str).is_err());
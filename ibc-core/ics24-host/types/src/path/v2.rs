@@ -0,0 +1,204 @@
+//! Defines the store paths used by IBC v2.
+//!
+//! In IBC v2, packets are addressed by client identifiers rather than by
+//! port and channel identifiers. Packet commitments, receipts and
+//! acknowledgements are stored under byte keys made of the client identifier,
+//! a one byte discriminant and the big-endian encoded packet sequence, as
+//! defined by ibc-go. As such, these paths are not part of the [`Path`](super::Path)
+//! enum and are only ever handed to light clients as [`PathBytes`].
+
+use derive_more::Display;
+use ibc_primitives::prelude::*;
+
+use super::{PathBytes, NEXT_SEQ_SEND_PREFIX};
+use crate::identifiers::{ClientId, Sequence};
+
+const PACKET_COMMITMENT_DISCRIMINANT: u8 = 1;
+const PACKET_RECEIPT_DISCRIMINANT: u8 = 2;
+const PACKET_ACK_DISCRIMINANT: u8 = 3;
+
+fn packet_key(client_id: &ClientId, discriminant: u8, sequence: Sequence) -> PathBytes {
+    let mut key = client_id.as_bytes().to_vec();
+    key.push(discriminant);
+    key.extend_from_slice(&sequence.value().to_be_bytes());
+    key.into()
+}
+
+/// Path of the next sequence to send on the given client.
+///
+/// This path is not provable, and is only used by the host.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{NEXT_SEQ_SEND_PREFIX}/{_0}")]
+pub struct SeqSendPath(pub ClientId);
+
+impl SeqSendPath {
+    pub fn new(client_id: &ClientId) -> Self {
+        Self(client_id.clone())
+    }
+
+    pub fn to_bytes(&self) -> PathBytes {
+        self.to_string().into_bytes().into()
+    }
+}
+
+/// Path of the commitment of the packet sent with the given sequence from
+/// the given source client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CommitmentPath {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl CommitmentPath {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> Self {
+        Self {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+
+    /// Returns the store key: `client_id || 0x01 || big_endian(sequence)`.
+    pub fn to_bytes(&self) -> PathBytes {
+        packet_key(
+            &self.client_id,
+            PACKET_COMMITMENT_DISCRIMINANT,
+            self.sequence,
+        )
+    }
+}
+
+/// Path of the receipt of the packet received with the given sequence on the
+/// given destination client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReceiptPath {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl ReceiptPath {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> Self {
+        Self {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+
+    /// Returns the store key: `client_id || 0x02 || big_endian(sequence)`.
+    pub fn to_bytes(&self) -> PathBytes {
+        packet_key(&self.client_id, PACKET_RECEIPT_DISCRIMINANT, self.sequence)
+    }
+}
+
+/// Path of the acknowledgement of the packet received with the given
+/// sequence on the given destination client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AckPath {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl AckPath {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> Self {
+        Self {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+
+    /// Returns the store key: `client_id || 0x03 || big_endian(sequence)`.
+    pub fn to_bytes(&self) -> PathBytes {
+        packet_key(&self.client_id, PACKET_ACK_DISCRIMINANT, self.sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_keys() {
+        let client_id = ClientId::new("07-tendermint", 0).unwrap();
+        let sequence = Sequence::from(258);
+
+        let mut expected = b"07-tendermint-0".to_vec();
+        expected.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 1, 2]);
+        assert_eq!(
+            CommitmentPath::new(&client_id, sequence).to_bytes(),
+            expected.clone().into()
+        );
+
+        expected[15] = 2;
+        assert_eq!(
+            ReceiptPath::new(&client_id, sequence).to_bytes(),
+            expected.clone().into()
+        );
+
+        expected[15] = 3;
+        assert_eq!(
+            AckPath::new(&client_id, sequence).to_bytes(),
+            expected.into()
+        );
+    }
+
+    #[test]
+    fn test_seq_send_path() {
+        let client_id = ClientId::new("07-tendermint", 0).unwrap();
+
+        assert_eq!(
+            SeqSendPath::new(&client_id).to_string(),
+            "nextSequenceSend/07-tendermint-0"
+        );
+    }
+}
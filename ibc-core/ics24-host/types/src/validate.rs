@@ -1,7 +1,7 @@
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError as Error;
-use crate::identifiers::{ChannelId, ConnectionId};
+use crate::identifiers::{ChannelId, ConnectionId, LOCALHOST_CONNECTION_ID};
 
 const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
@@ -100,8 +100,13 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// Default validator function for Connection identifiers.
 ///
 /// A valid connection identifier must be between 10-64 characters, as specified
-/// in the ICS-24 spec.
+/// in the ICS-24 spec. Besides numbered identifiers, the sentinel identifier of
+/// the localhost connection is accepted.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
+    if id == LOCALHOST_CONNECTION_ID {
+        return Ok(());
+    }
+
     validate_identifier_chars(id)?;
     validate_identifier_length(id, 10, 64)?;
     validate_named_u64_index(id, ConnectionId::prefix())?;
@@ -172,6 +177,7 @@ mod tests {
         validate_connection_identifier("connection-0").expect("success");
         validate_connection_identifier("connection-123").expect("success");
         validate_connection_identifier("connection-18446744073709551615").expect("success");
+        validate_connection_identifier("connection-localhost").expect("success");
     }
 
     #[test]
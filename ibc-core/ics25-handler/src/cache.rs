@@ -0,0 +1,1250 @@
+//! Defines [`CachedContext`], a caching overlay over an [`ExecutionContext`]
+//! which lets several messages be processed before deciding whether their
+//! state transitions are committed.
+use core::time::Duration;
+
+use ibc_core_channel::types::channel::ChannelEnd;
+use ibc_core_channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel::types::packet::Receipt;
+use ibc_core_channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeTimeout};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::{CounterpartyInfo, Height};
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection::types::version::Version as ConnectionVersion;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::error::HostError;
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc_core_host::types::path::{
+    v2 as path_v2, AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath,
+    ClientUpdateTimePath, CommitmentPath, ConnectionPath, CounterpartyChannelUpgradePath,
+    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, PathBytes,
+    ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
+use ibc_core_router::types::module::ModuleId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+/// A store key written to by a [`CachedContext`], either by storing or by
+/// deleting the value under it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StoreKey {
+    /// A path of the provable store, as defined by ICS-24.
+    Path(Path),
+    /// A path of the provable store under which IBC v2 packets are tracked.
+    PathV2(PathBytes),
+    /// The marker of a packet whose acknowledgement is pending, whose
+    /// location is up to the host.
+    PendingAcknowledgement(AckPath),
+    /// The counterparty registered for a client, whose location is up to the
+    /// host.
+    ClientCounterparty(ClientId),
+    /// A key of the store of a module, as reported by a
+    /// [`TransactionalRouter`](ibc_core_router::router::TransactionalRouter).
+    Module { module_id: ModuleId, key: Vec<u8> },
+}
+
+/// The state transitions recorded by a [`CachedContext`], along with the
+/// events and logs emitted while processing messages against it.
+///
+/// Deletions are recorded as `None` entries, so that they shadow the values
+/// stored by the underlying context.
+pub struct StoreCache<Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    client_counter_increments: u64,
+    connection_counter_increments: u64,
+    channel_counter_increments: u64,
+    client_states: BTreeMap<ClientStatePath, ClientStateRef<Ctx>>,
+    consensus_states: BTreeMap<ClientConsensusStatePath, Option<ConsensusStateRef<Ctx>>>,
+    update_meta: BTreeMap<(ClientId, Height), Option<(Timestamp, Height)>>,
+    connections: BTreeMap<ConnectionPath, ConnectionEnd>,
+    client_connections: BTreeMap<ClientConnectionPath, ConnectionId>,
+    channels: BTreeMap<ChannelEndPath, ChannelEnd>,
+    next_sequence_send: BTreeMap<SeqSendPath, Sequence>,
+    next_sequence_recv: BTreeMap<SeqRecvPath, Sequence>,
+    next_sequence_ack: BTreeMap<SeqAckPath, Sequence>,
+    packet_commitments: BTreeMap<CommitmentPath, Option<PacketCommitment>>,
+    packet_receipts: BTreeMap<ReceiptPath, Receipt>,
+    packet_acknowledgements: BTreeMap<AckPath, Option<AcknowledgementCommitment>>,
+    pending_acknowledgements: BTreeMap<AckPath, bool>,
+    channel_upgrades: BTreeMap<ChannelUpgradePath, Option<Upgrade>>,
+    counterparty_channel_upgrades: BTreeMap<CounterpartyChannelUpgradePath, Option<Upgrade>>,
+    channel_upgrade_errors: BTreeMap<ChannelUpgradeErrorPath, ErrorReceipt>,
+    client_counterparties: BTreeMap<ClientId, CounterpartyInfo>,
+    next_sequence_send_v2: BTreeMap<path_v2::SeqSendPath, Sequence>,
+    packet_commitments_v2: BTreeMap<path_v2::CommitmentPath, Option<PacketCommitment>>,
+    packet_receipts_v2: BTreeMap<path_v2::ReceiptPath, Receipt>,
+    packet_acknowledgements_v2: BTreeMap<path_v2::AckPath, AcknowledgementCommitment>,
+    module_keys: Vec<(ModuleId, Vec<u8>)>,
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+}
+
+impl<Ctx> StoreCache<Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+{
+    fn new() -> Self {
+        Self {
+            client_counter_increments: 0,
+            connection_counter_increments: 0,
+            channel_counter_increments: 0,
+            client_states: BTreeMap::new(),
+            consensus_states: BTreeMap::new(),
+            update_meta: BTreeMap::new(),
+            connections: BTreeMap::new(),
+            client_connections: BTreeMap::new(),
+            channels: BTreeMap::new(),
+            next_sequence_send: BTreeMap::new(),
+            next_sequence_recv: BTreeMap::new(),
+            next_sequence_ack: BTreeMap::new(),
+            packet_commitments: BTreeMap::new(),
+            packet_receipts: BTreeMap::new(),
+            packet_acknowledgements: BTreeMap::new(),
+            pending_acknowledgements: BTreeMap::new(),
+            channel_upgrades: BTreeMap::new(),
+            counterparty_channel_upgrades: BTreeMap::new(),
+            channel_upgrade_errors: BTreeMap::new(),
+            client_counterparties: BTreeMap::new(),
+            next_sequence_send_v2: BTreeMap::new(),
+            packet_commitments_v2: BTreeMap::new(),
+            packet_receipts_v2: BTreeMap::new(),
+            packet_acknowledgements_v2: BTreeMap::new(),
+            module_keys: Vec::new(),
+            events: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// Returns the events emitted while processing messages.
+    pub fn events(&self) -> &[IbcEvent] {
+        &self.events
+    }
+
+    /// Returns the messages logged while processing messages.
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+
+    /// Returns the store keys written to while processing messages, in
+    /// ascending order.
+    ///
+    /// Those of the module stores are only included in the caches returned by
+    /// [`dispatch_uncommitted`](crate::entrypoint::dispatch_uncommitted), as
+    /// reported by the router the messages were dispatched through.
+    pub fn written_keys(&self) -> Vec<StoreKey> {
+        let counter_paths = [
+            (
+                self.client_counter_increments,
+                Path::from(NextClientSequencePath),
+            ),
+            (
+                self.connection_counter_increments,
+                NextConnectionSequencePath.into(),
+            ),
+            (
+                self.channel_counter_increments,
+                NextChannelSequencePath.into(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(increments, _)| *increments > 0)
+        .map(|(_, path)| path);
+
+        let update_meta_paths = self.update_meta.keys().flat_map(|(client_id, height)| {
+            let (revision_number, revision_height) =
+                (height.revision_number(), height.revision_height());
+
+            [
+                ClientUpdateTimePath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+                ClientUpdateHeightPath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            ]
+        });
+
+        let paths = counter_paths
+            .chain(self.client_states.keys().cloned().map(Path::from))
+            .chain(self.consensus_states.keys().cloned().map(Path::from))
+            .chain(update_meta_paths)
+            .chain(self.connections.keys().cloned().map(Path::from))
+            .chain(self.client_connections.keys().cloned().map(Path::from))
+            .chain(self.channels.keys().cloned().map(Path::from))
+            .chain(self.next_sequence_send.keys().cloned().map(Path::from))
+            .chain(self.next_sequence_recv.keys().cloned().map(Path::from))
+            .chain(self.next_sequence_ack.keys().cloned().map(Path::from))
+            .chain(self.packet_commitments.keys().cloned().map(Path::from))
+            .chain(self.packet_receipts.keys().cloned().map(Path::from))
+            .chain(self.packet_acknowledgements.keys().cloned().map(Path::from))
+            .chain(self.channel_upgrades.keys().cloned().map(Path::from))
+            .chain(
+                self.counterparty_channel_upgrades
+                    .keys()
+                    .cloned()
+                    .map(Path::from),
+            )
+            .chain(self.channel_upgrade_errors.keys().cloned().map(Path::from))
+            .map(StoreKey::Path);
+
+        let paths_v2 = self
+            .next_sequence_send_v2
+            .keys()
+            .map(path_v2::SeqSendPath::to_bytes)
+            .chain(
+                self.packet_commitments_v2
+                    .keys()
+                    .map(path_v2::CommitmentPath::to_bytes),
+            )
+            .chain(
+                self.packet_receipts_v2
+                    .keys()
+                    .map(path_v2::ReceiptPath::to_bytes),
+            )
+            .chain(
+                self.packet_acknowledgements_v2
+                    .keys()
+                    .map(path_v2::AckPath::to_bytes),
+            )
+            .map(StoreKey::PathV2);
+
+        let mut keys: Vec<StoreKey> = paths
+            .chain(paths_v2)
+            .chain(
+                self.pending_acknowledgements
+                    .keys()
+                    .cloned()
+                    .map(StoreKey::PendingAcknowledgement),
+            )
+            .chain(
+                self.client_counterparties
+                    .keys()
+                    .cloned()
+                    .map(StoreKey::ClientCounterparty),
+            )
+            .chain(
+                self.module_keys
+                    .iter()
+                    .cloned()
+                    .map(|(module_id, key)| StoreKey::Module { module_id, key }),
+            )
+            .collect();
+
+        keys.sort();
+        keys
+    }
+
+    /// Records the keys of the module stores written to while processing
+    /// messages.
+    pub(crate) fn with_module_keys(mut self, module_keys: Vec<(ModuleId, Vec<u8>)>) -> Self {
+        self.module_keys = module_keys;
+        self
+    }
+
+    /// Writes the recorded state transitions into `ctx`, and emits the
+    /// recorded events and logs through it.
+    ///
+    /// If an error is returned, `ctx` may have been partially written to, and
+    /// the host is expected to discard the state modifications made to it.
+    pub fn commit(self, ctx: &mut Ctx) -> Result<(), HostError> {
+        for _ in 0..self.client_counter_increments {
+            ctx.increase_client_counter()?;
+        }
+        for _ in 0..self.connection_counter_increments {
+            ctx.increase_connection_counter()?;
+        }
+        for _ in 0..self.channel_counter_increments {
+            ctx.increase_channel_counter()?;
+        }
+
+        let client_exec_ctx = ctx.get_client_execution_context();
+        for (path, client_state) in self.client_states {
+            client_exec_ctx.store_client_state(path, client_state)?;
+        }
+        for (path, consensus_state) in self.consensus_states {
+            match consensus_state {
+                Some(consensus_state) => {
+                    client_exec_ctx.store_consensus_state(path, consensus_state)?
+                }
+                None => client_exec_ctx.delete_consensus_state(path)?,
+            }
+        }
+        for ((client_id, height), meta) in self.update_meta {
+            match meta {
+                Some((host_timestamp, host_height)) => client_exec_ctx.store_update_meta(
+                    client_id,
+                    height,
+                    host_timestamp,
+                    host_height,
+                )?,
+                None => client_exec_ctx.delete_update_meta(client_id, height)?,
+            }
+        }
+
+        for (path, connection_end) in self.connections {
+            ctx.store_connection(&path, connection_end)?;
+        }
+        for (path, conn_id) in self.client_connections {
+            ctx.store_connection_to_client(&path, conn_id)?;
+        }
+        for (path, channel_end) in self.channels {
+            ctx.store_channel(&path, channel_end)?;
+        }
+        for (path, seq) in self.next_sequence_send {
+            ctx.store_next_sequence_send(&path, seq)?;
+        }
+        for (path, seq) in self.next_sequence_recv {
+            ctx.store_next_sequence_recv(&path, seq)?;
+        }
+        for (path, seq) in self.next_sequence_ack {
+            ctx.store_next_sequence_ack(&path, seq)?;
+        }
+        for (path, commitment) in self.packet_commitments {
+            match commitment {
+                Some(commitment) => ctx.store_packet_commitment(&path, commitment)?,
+                None => ctx.delete_packet_commitment(&path)?,
+            }
+        }
+        for (path, receipt) in self.packet_receipts {
+            ctx.store_packet_receipt(&path, receipt)?;
+        }
+        for (path, ack_commitment) in self.packet_acknowledgements {
+            match ack_commitment {
+                Some(ack_commitment) => ctx.store_packet_acknowledgement(&path, ack_commitment)?,
+                None => ctx.delete_packet_acknowledgement(&path)?,
+            }
+        }
+        for (path, pending) in self.pending_acknowledgements {
+            if pending {
+                ctx.store_pending_acknowledgement(&path)?;
+            } else {
+                ctx.delete_pending_acknowledgement(&path)?;
+            }
+        }
+        for (path, upgrade) in self.channel_upgrades {
+            match upgrade {
+                Some(upgrade) => ctx.store_channel_upgrade(&path, upgrade)?,
+                None => ctx.delete_channel_upgrade(&path)?,
+            }
+        }
+        for (path, upgrade) in self.counterparty_channel_upgrades {
+            match upgrade {
+                Some(upgrade) => ctx.store_counterparty_channel_upgrade(&path, upgrade)?,
+                None => ctx.delete_counterparty_channel_upgrade(&path)?,
+            }
+        }
+        for (path, error_receipt) in self.channel_upgrade_errors {
+            ctx.store_channel_upgrade_error(&path, error_receipt)?;
+        }
+        for (client_id, counterparty) in self.client_counterparties {
+            ctx.store_client_counterparty(&client_id, counterparty)?;
+        }
+        for (path, seq) in self.next_sequence_send_v2 {
+            ctx.store_next_sequence_send_v2(&path, seq)?;
+        }
+        for (path, commitment) in self.packet_commitments_v2 {
+            match commitment {
+                Some(commitment) => ctx.store_packet_commitment_v2(&path, commitment)?,
+                None => ctx.delete_packet_commitment_v2(&path)?,
+            }
+        }
+        for (path, receipt) in self.packet_receipts_v2 {
+            ctx.store_packet_receipt_v2(&path, receipt)?;
+        }
+        for (path, ack_commitment) in self.packet_acknowledgements_v2 {
+            ctx.store_packet_acknowledgement_v2(&path, ack_commitment)?;
+        }
+
+        for event in self.events {
+            ctx.emit_ibc_event(event)?;
+        }
+        for message in self.logs {
+            ctx.log_message(message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A caching overlay over an [`ExecutionContext`].
+///
+/// Reads go through the overlay first, and fall back to the underlying
+/// context. Writes, events and logs are only recorded by the overlay, and
+/// reach the underlying context once [`StoreCache::commit`] is called on the
+/// cache returned by [`CachedContext::into_cache`]. Dropping the overlay
+/// instead discards them.
+///
+/// The overlay is its own client validation and execution context, so the
+/// client states of the underlying context must support it as such. Note that
+/// the state of IBC applications is not covered: modules write to their own
+/// storage while processing packets and channel handshakes, which the
+/// entrypoints stage through a
+/// [`TransactionalRouter`](ibc_core_router::router::TransactionalRouter).
+pub struct CachedContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    inner: &'a Ctx,
+    cache: StoreCache<Ctx>,
+}
+
+impl<'a, Ctx> CachedContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+{
+    /// Creates an empty overlay over the given context.
+    pub fn new(inner: &'a Ctx) -> Self {
+        Self {
+            inner,
+            cache: StoreCache::new(),
+        }
+    }
+
+    /// Returns the events emitted so far.
+    pub fn events(&self) -> &[IbcEvent] {
+        self.cache.events()
+    }
+
+    /// Returns the messages logged so far.
+    pub fn logs(&self) -> &[String] {
+        self.cache.logs()
+    }
+
+    /// Releases the underlying context, returning the recorded state
+    /// transitions, events and logs.
+    pub fn into_cache(self) -> StoreCache<Ctx> {
+        self.cache
+    }
+
+    /// Returns the heights at which the given client has a consensus state,
+    /// taking the recorded writes into account.
+    fn merged_consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, HostError>
+    where
+        Ctx::V: ExtClientValidationContext,
+    {
+        let mut heights = self
+            .inner
+            .get_client_validation_context()
+            .consensus_state_heights(client_id)?;
+
+        for (path, consensus_state) in &self.cache.consensus_states {
+            if &path.client_id != client_id {
+                continue;
+            }
+
+            let height = Height::new(path.revision_number, path.revision_height)
+                .map_err(HostError::invalid_state)?;
+
+            heights.retain(|h| h != &height);
+            if consensus_state.is_some() {
+                heights.push(height);
+            }
+        }
+        heights.sort();
+
+        Ok(heights)
+    }
+}
+
+/// Returns the value recorded for `key`, falling back to `inner` if the key
+/// was never written, and failing if the value was deleted.
+fn cached_or_else<K, T>(
+    cache: &BTreeMap<K, Option<T>>,
+    key: &K,
+    description: &str,
+    inner: impl FnOnce() -> Result<T, HostError>,
+) -> Result<T, HostError>
+where
+    K: Ord,
+    T: Clone,
+{
+    match cache.get(key) {
+        Some(Some(value)) => Ok(value.clone()),
+        Some(None) => Err(HostError::missing_state(format!(
+            "{description} was deleted"
+        ))),
+        None => inner(),
+    }
+}
+
+impl<Ctx> ClientValidationContext for CachedContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    ClientStateRef<Ctx>: ClientStateExecution<Self> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+{
+    type ClientStateRef = ClientStateRef<Ctx>;
+    type ConsensusStateRef = ConsensusStateRef<Ctx>;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, HostError> {
+        match self
+            .cache
+            .client_states
+            .get(&ClientStatePath::new(client_id.clone()))
+        {
+            Some(client_state) => Ok(client_state.clone()),
+            None => self
+                .inner
+                .get_client_validation_context()
+                .client_state(client_id),
+        }
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, HostError> {
+        cached_or_else(
+            &self.cache.consensus_states,
+            client_cons_state_path,
+            "consensus state",
+            || {
+                self.inner
+                    .get_client_validation_context()
+                    .consensus_state(client_cons_state_path)
+            },
+        )
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), HostError> {
+        cached_or_else(
+            &self.cache.update_meta,
+            &(client_id.clone(), *height),
+            "client update meta",
+            || {
+                self.inner
+                    .get_client_validation_context()
+                    .client_update_meta(client_id, height)
+            },
+        )
+    }
+}
+
+impl<Ctx> ClientExecutionContext for CachedContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    ClientStateRef<Ctx>: ClientStateExecution<Self> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+{
+    type ClientStateMut = ClientStateRef<Ctx>;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), HostError> {
+        self.cache
+            .client_states
+            .insert(client_state_path, client_state);
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), HostError> {
+        self.cache
+            .consensus_states
+            .insert(consensus_state_path, Some(consensus_state));
+        Ok(())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), HostError> {
+        self.cache
+            .consensus_states
+            .insert(consensus_state_path, None);
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), HostError> {
+        self.cache
+            .update_meta
+            .insert((client_id, height), Some((host_timestamp, host_height)));
+        Ok(())
+    }
+
+    fn delete_update_meta(&mut self, client_id: ClientId, height: Height) -> Result<(), HostError> {
+        self.cache.update_meta.insert((client_id, height), None);
+        Ok(())
+    }
+}
+
+impl<Ctx> ExtClientValidationContext for CachedContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::V: ExtClientValidationContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    ClientStateRef<Ctx>: ClientStateExecution<Self> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+{
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        ExtClientValidationContext::host_timestamp(self.inner.get_client_validation_context())
+    }
+
+    fn host_height(&self) -> Result<Height, HostError> {
+        ExtClientValidationContext::host_height(self.inner.get_client_validation_context())
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, HostError> {
+        self.merged_consensus_state_heights(client_id)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        self.merged_consensus_state_heights(client_id)?
+            .into_iter()
+            .find(|h| h > height)
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        self.merged_consensus_state_heights(client_id)?
+            .into_iter()
+            .rev()
+            .find(|h| h < height)
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+}
+
+impl<Ctx> ValidationContext for CachedContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    ClientStateRef<Ctx>: ClientStateExecution<Self> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+    Ctx::HostClientState: ClientStateValidation<Self>,
+{
+    type V = Self;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+
+    fn host_height(&self) -> Result<Height, HostError> {
+        self.inner.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        self.inner.host_timestamp()
+    }
+
+    fn host_consensus_state(&self, height: &Height) -> Result<Self::HostConsensusState, HostError> {
+        self.inner.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, HostError> {
+        Ok(self.inner.client_counter()? + self.cache.client_counter_increments)
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, HostError> {
+        match self.cache.connections.get(&ConnectionPath::new(conn_id)) {
+            Some(connection_end) => Ok(connection_end.clone()),
+            None => self.inner.connection_end(conn_id),
+        }
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), HostError> {
+        self.inner
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.inner.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, HostError> {
+        Ok(self.inner.connection_counter()? + self.cache.connection_counter_increments)
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.inner.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, HostError> {
+        self.inner.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, HostError> {
+        match self.cache.channels.get(channel_end_path) {
+            Some(channel_end) => Ok(channel_end.clone()),
+            None => self.inner.channel_end(channel_end_path),
+        }
+    }
+
+    fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath) -> Result<Sequence, HostError> {
+        match self.cache.next_sequence_send.get(seq_send_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_send(seq_send_path),
+        }
+    }
+
+    fn get_next_sequence_recv(&self, seq_recv_path: &SeqRecvPath) -> Result<Sequence, HostError> {
+        match self.cache.next_sequence_recv.get(seq_recv_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_recv(seq_recv_path),
+        }
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, HostError> {
+        match self.cache.next_sequence_ack.get(seq_ack_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_ack(seq_ack_path),
+        }
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, HostError> {
+        cached_or_else(
+            &self.cache.packet_commitments,
+            commitment_path,
+            "packet commitment",
+            || self.inner.get_packet_commitment(commitment_path),
+        )
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, HostError> {
+        match self.cache.packet_receipts.get(receipt_path) {
+            Some(receipt) => Ok(receipt.clone()),
+            None => self.inner.get_packet_receipt(receipt_path),
+        }
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, HostError> {
+        cached_or_else(
+            &self.cache.packet_acknowledgements,
+            ack_path,
+            "packet acknowledgement",
+            || self.inner.get_packet_acknowledgement(ack_path),
+        )
+    }
+
+    fn pending_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, HostError> {
+        let mut sequences = self.inner.pending_acknowledgements(channel_end_path)?;
+
+        for (ack_path, pending) in &self.cache.pending_acknowledgements {
+            if ack_path.port_id != channel_end_path.0 || ack_path.channel_id != channel_end_path.1 {
+                continue;
+            }
+
+            sequences.retain(|seq| seq != &ack_path.sequence);
+            if *pending {
+                sequences.push(ack_path.sequence);
+            }
+        }
+        sequences.sort();
+
+        Ok(sequences)
+    }
+
+    fn channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, HostError> {
+        match self.cache.channel_upgrades.get(upgrade_path) {
+            Some(upgrade) => Ok(upgrade.clone()),
+            None => self.inner.channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, HostError> {
+        match self.cache.counterparty_channel_upgrades.get(upgrade_path) {
+            Some(upgrade) => Ok(upgrade.clone()),
+            None => self.inner.counterparty_channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn channel_upgrade_error(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<Option<ErrorReceipt>, HostError> {
+        match self.cache.channel_upgrade_errors.get(upgrade_error_path) {
+            Some(error_receipt) => Ok(Some(error_receipt.clone())),
+            None => self.inner.channel_upgrade_error(upgrade_error_path),
+        }
+    }
+
+    fn packet_commitment_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, HostError> {
+        let mut sequences = self.inner.packet_commitment_sequences(channel_end_path)?;
+
+        for (commitment_path, commitment) in &self.cache.packet_commitments {
+            if commitment_path.port_id != channel_end_path.0
+                || commitment_path.channel_id != channel_end_path.1
+            {
+                continue;
+            }
+
+            sequences.retain(|seq| seq != &commitment_path.sequence);
+            if commitment.is_some() {
+                sequences.push(commitment_path.sequence);
+            }
+        }
+        sequences.sort();
+
+        Ok(sequences)
+    }
+
+    fn has_inflight_packets(&self, channel_end_path: &ChannelEndPath) -> Result<bool, HostError> {
+        let has_cached_commitments = self.cache.packet_commitments.keys().any(|path| {
+            path.port_id == channel_end_path.0 && path.channel_id == channel_end_path.1
+        });
+
+        // The underlying context may answer this more efficiently than by
+        // listing the sequences, as long as the overlay does not shadow it.
+        if has_cached_commitments {
+            Ok(!self
+                .packet_commitment_sequences(channel_end_path)?
+                .is_empty())
+        } else {
+            self.inner.has_inflight_packets(channel_end_path)
+        }
+    }
+
+    fn channel_upgrade_timeout(&self) -> Result<UpgradeTimeout, HostError> {
+        self.inner.channel_upgrade_timeout()
+    }
+
+    fn is_channel_upgrade_authority(&self, signer: &Signer) -> Result<bool, HostError> {
+        self.inner.is_channel_upgrade_authority(signer)
+    }
+
+    fn client_counterparty(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<CounterpartyInfo>, HostError> {
+        match self.cache.client_counterparties.get(client_id) {
+            Some(counterparty) => Ok(Some(counterparty.clone())),
+            None => self.inner.client_counterparty(client_id),
+        }
+    }
+
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &path_v2::SeqSendPath,
+    ) -> Result<Sequence, HostError> {
+        match self.cache.next_sequence_send_v2.get(seq_send_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_send_v2(seq_send_path),
+        }
+    }
+
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &path_v2::CommitmentPath,
+    ) -> Result<PacketCommitment, HostError> {
+        cached_or_else(
+            &self.cache.packet_commitments_v2,
+            commitment_path,
+            "packet commitment",
+            || self.inner.get_packet_commitment_v2(commitment_path),
+        )
+    }
+
+    fn get_packet_receipt_v2(
+        &self,
+        receipt_path: &path_v2::ReceiptPath,
+    ) -> Result<Receipt, HostError> {
+        match self.cache.packet_receipts_v2.get(receipt_path) {
+            Some(receipt) => Ok(receipt.clone()),
+            None => self.inner.get_packet_receipt_v2(receipt_path),
+        }
+    }
+
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &path_v2::AckPath,
+    ) -> Result<AcknowledgementCommitment, HostError> {
+        match self.cache.packet_acknowledgements_v2.get(ack_path) {
+            Some(ack_commitment) => Ok(ack_commitment.clone()),
+            None => self.inner.get_packet_acknowledgement_v2(ack_path),
+        }
+    }
+
+    fn channel_counter(&self) -> Result<u64, HostError> {
+        Ok(self.inner.channel_counter()? + self.cache.channel_counter_increments)
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.inner.max_expected_time_per_block()
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.inner.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), HostError> {
+        self.inner.validate_message_signer(signer)
+    }
+}
+
+impl<Ctx> ExecutionContext for CachedContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    ClientStateRef<Ctx>: ClientStateExecution<Self> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+    Ctx::HostClientState: ClientStateValidation<Self>,
+{
+    type E = Self;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), HostError> {
+        self.cache.client_counter_increments += 1;
+        Ok(())
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), HostError> {
+        self.cache
+            .connections
+            .insert(connection_path.clone(), connection_end);
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), HostError> {
+        self.cache
+            .client_connections
+            .insert(client_connection_path.clone(), conn_id);
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), HostError> {
+        self.cache.connection_counter_increments += 1;
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_commitments
+            .insert(commitment_path.clone(), Some(commitment));
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_commitments
+            .insert(commitment_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_receipts
+            .insert(receipt_path.clone(), receipt);
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_acknowledgements
+            .insert(ack_path.clone(), Some(ack_commitment));
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError> {
+        self.cache
+            .packet_acknowledgements
+            .insert(ack_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_pending_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError> {
+        self.cache
+            .pending_acknowledgements
+            .insert(ack_path.clone(), true);
+        Ok(())
+    }
+
+    fn delete_pending_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError> {
+        self.cache
+            .pending_acknowledgements
+            .insert(ack_path.clone(), false);
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), HostError> {
+        self.cache
+            .channels
+            .insert(channel_end_path.clone(), channel_end);
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.cache
+            .next_sequence_send
+            .insert(seq_send_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.cache
+            .next_sequence_recv
+            .insert(seq_recv_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.cache
+            .next_sequence_ack
+            .insert(seq_ack_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), HostError> {
+        self.cache
+            .channel_upgrades
+            .insert(upgrade_path.clone(), Some(upgrade));
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), HostError> {
+        self.cache
+            .channel_upgrades
+            .insert(upgrade_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), HostError> {
+        self.cache
+            .counterparty_channel_upgrades
+            .insert(upgrade_path.clone(), Some(upgrade));
+        Ok(())
+    }
+
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+    ) -> Result<(), HostError> {
+        self.cache
+            .counterparty_channel_upgrades
+            .insert(upgrade_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), HostError> {
+        self.cache
+            .channel_upgrade_errors
+            .insert(upgrade_error_path.clone(), error_receipt);
+        Ok(())
+    }
+
+    fn store_client_counterparty(
+        &mut self,
+        client_id: &ClientId,
+        counterparty: CounterpartyInfo,
+    ) -> Result<(), HostError> {
+        self.cache
+            .client_counterparties
+            .insert(client_id.clone(), counterparty);
+        Ok(())
+    }
+
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &path_v2::SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.cache
+            .next_sequence_send_v2
+            .insert(seq_send_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &path_v2::CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_commitments_v2
+            .insert(commitment_path.clone(), Some(commitment));
+        Ok(())
+    }
+
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &path_v2::CommitmentPath,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_commitments_v2
+            .insert(commitment_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &path_v2::ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_receipts_v2
+            .insert(receipt_path.clone(), receipt);
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &path_v2::AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), HostError> {
+        self.cache
+            .packet_acknowledgements_v2
+            .insert(ack_path.clone(), ack_commitment);
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), HostError> {
+        self.cache.channel_counter_increments += 1;
+        Ok(())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), HostError> {
+        self.cache.events.push(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), HostError> {
+        self.cache.logs.push(message);
+        Ok(())
+    }
+}
@@ -3,29 +3,44 @@ use ibc_core_channel::handler::{
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
-    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    chan_open_try_execute, chan_open_try_validate, chan_upgrade_ack_execute,
+    chan_upgrade_ack_validate, chan_upgrade_cancel_execute, chan_upgrade_cancel_validate,
+    chan_upgrade_confirm_execute, chan_upgrade_confirm_validate, chan_upgrade_init_execute,
+    chan_upgrade_init_validate, chan_upgrade_open_execute, chan_upgrade_open_validate,
+    chan_upgrade_timeout_execute, chan_upgrade_timeout_validate, chan_upgrade_try_execute,
+    chan_upgrade_try_validate, recv_packet_execute, recv_packet_validate, timeout_packet_execute,
+    timeout_packet_validate, v2 as handler_v2, TimeoutMsgType,
 };
 use ibc_core_channel::types::msgs::{
     channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
 };
+use ibc_core_channel::types::v2::msgs::PacketMsg as PacketMsgV2;
+use ibc_core_client::context::client_state::{ClientStateExecution, ClientStateValidation};
 use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
-use ibc_core_client::handler::{create_client, update_client, upgrade_client};
+use ibc_core_client::handler::{
+    create_client, register_counterparty, update_client, upgrade_client,
+};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
 };
 use ibc_core_connection::types::msgs::ConnectionMsg;
-use ibc_core_handler_types::error::HandlerError;
+use ibc_core_handler_types::error::{BatchError, HandlerError, UncommittedBatchError};
+use ibc_core_handler_types::events::IbcEvent;
 use ibc_core_handler_types::msgs::MsgEnvelope;
 use ibc_core_host::types::error::HostError;
-use ibc_core_host::{ExecutionContext, ValidationContext};
-use ibc_core_router::router::Router;
+use ibc_core_host::{
+    ClientStateRef, ConsensusStateRef, ExecutionContext, TransactionalExecutionContext,
+    ValidationContext,
+};
+use ibc_core_router::router::{Router, TransactionalRouter};
 use ibc_core_router::types::error::RouterError;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
+use crate::cache::{CachedContext, StoreCache};
+
 /// Entrypoint which performs both validation and message execution
 pub fn dispatch<Ctx>(
     ctx: &mut Ctx,
@@ -42,6 +57,121 @@ where
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which performs both validation and execution of a batch of
+/// messages, such as those of a single transaction.
+///
+/// The messages are dispatched in order against a [`CachedContext`] over
+/// `ctx`, so that each message observes the state transitions of the previous
+/// ones, while the modules they are routed to through `router` stage their
+/// own. If every message succeeds, the state transitions, events and logs of
+/// the batch are committed all at once to `ctx`, along with those staged by
+/// the modules, and the events emitted by each message are returned.
+/// Otherwise, both `ctx` and the modules are left untouched, and the index of
+/// the failing message is returned along with its error.
+pub fn dispatch_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl TransactionalRouter,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<Vec<Vec<IbcEvent>>, BatchError>
+where
+    Ctx: TransactionalExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    for<'a> ClientStateRef<Ctx>: ClientStateExecution<CachedContext<'a, Ctx>> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+    for<'a> Ctx::HostClientState: ClientStateValidation<CachedContext<'a, Ctx>>,
+    <ClientStateRef<Ctx> as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    router.begin_staging();
+
+    let mut cached_ctx = CachedContext::new(ctx);
+    let mut events = Vec::with_capacity(msgs.len());
+
+    for (index, msg) in msgs.into_iter().enumerate() {
+        let emitted = cached_ctx.events().len();
+
+        if let Err(error) = dispatch(&mut cached_ctx, router, msg) {
+            router.discard_staged();
+            return Err(BatchError::Message { index, error });
+        }
+
+        events.push(cached_ctx.events()[emitted..].to_vec());
+    }
+
+    let cache = cached_ctx.into_cache();
+
+    ctx.begin_staging();
+
+    if let Err(e) = cache.commit(ctx) {
+        ctx.discard_staged();
+        router.discard_staged();
+        return Err(BatchError::Commit(e));
+    }
+
+    ctx.commit_staged();
+    router.commit_staged();
+
+    Ok(events)
+}
+
+/// Entrypoint which performs both validation and execution of a batch of
+/// messages, without committing their state transitions.
+///
+/// The messages are dispatched against a [`CachedContext`] over `ctx` as by
+/// [`dispatch_batch`], while the modules they are routed to through `router`
+/// stage their state transitions, which are discarded afterwards. The cache
+/// of the [`CachedContext`] is returned. It holds the events and logs the
+/// batch would emit, as well as the store keys it would write to, including
+/// those of the module stores (see [`StoreCache::written_keys`]), which lets
+/// wallets and relayers estimate fees and preview outcomes before
+/// broadcasting a transaction. If a message fails, the events and logs of the
+/// messages preceding it are returned along with its error.
+#[allow(clippy::result_large_err)]
+pub fn dispatch_uncommitted<Ctx>(
+    ctx: &Ctx,
+    router: &mut impl TransactionalRouter,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<StoreCache<Ctx>, UncommittedBatchError>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientExecutionContext<
+        ClientStateMut = ClientStateRef<Ctx>,
+        ConsensusStateRef = ConsensusStateRef<Ctx>,
+    >,
+    for<'a> ClientStateRef<Ctx>: ClientStateExecution<CachedContext<'a, Ctx>> + Clone,
+    ConsensusStateRef<Ctx>: Clone,
+    for<'a> Ctx::HostClientState: ClientStateValidation<CachedContext<'a, Ctx>>,
+    <ClientStateRef<Ctx> as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    router.begin_staging();
+
+    let mut cached_ctx = CachedContext::new(ctx);
+
+    for (index, msg) in msgs.into_iter().enumerate() {
+        let emitted = cached_ctx.events().len();
+        let logged = cached_ctx.logs().len();
+
+        if let Err(error) = dispatch(&mut cached_ctx, router, msg) {
+            router.discard_staged();
+            return Err(UncommittedBatchError {
+                error: BatchError::Message { index, error },
+                events: cached_ctx.events()[..emitted].to_vec(),
+                logs: cached_ctx.logs()[..logged].to_vec(),
+            });
+        }
+    }
+
+    let module_keys = router.staged_keys();
+
+    router.discard_staged();
+
+    Ok(cached_ctx.into_cache().with_module_keys(module_keys))
+}
+
 /// Entrypoint which only performs message validation
 ///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
@@ -49,7 +179,7 @@ where
 ///     validate(m_1), execute(m_1), ..., validate(m_n), execute(m_n)
 /// That is, the state transition of message `i` must be applied before
 /// message `i+1` is validated. This is equivalent to calling
-/// `dispatch()` on each successively.
+/// `dispatch()` on each successively, or `dispatch_batch()` on all of them.
 pub fn validate<Ctx>(ctx: &Ctx, router: &impl Router, msg: MsgEnvelope) -> Result<(), HandlerError>
 where
     Ctx: ValidationContext,
@@ -66,6 +196,7 @@ where
                 update_client::validate(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))?
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::validate(ctx, msg)?,
+            ClientMsg::RegisterCounterparty(msg) => register_counterparty::validate(ctx, msg)?,
             ClientMsg::RecoverClient(_msg) => {
                 // Recover client messages are not dispatched by ibc-rs as they can only be
                 // authorized via a passing governance proposal
@@ -93,6 +224,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg)?,
                 ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg)?,
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_validate(ctx, module, msg)?,
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_validate(ctx, module, msg)?,
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -115,6 +253,16 @@ where
                 }
             }
         }
+        // IBC v2 payloads are routed to their modules by the handlers, as
+        // packets are not bound to a port.
+        MsgEnvelope::PacketV2(msg) => match msg {
+            PacketMsgV2::Send(msg) => handler_v2::send_packet_validate(ctx, router, &msg)?,
+            PacketMsgV2::Recv(msg) => handler_v2::recv_packet_validate(ctx, router, &msg)?,
+            PacketMsgV2::Ack(msg) => {
+                handler_v2::acknowledgement_packet_validate(ctx, router, &msg)?
+            }
+            PacketMsgV2::Timeout(msg) => handler_v2::timeout_packet_validate(ctx, router, &msg)?,
+        },
     };
 
     Ok(())
@@ -140,6 +288,7 @@ where
                 update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))?
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::execute(ctx, msg)?,
+            ClientMsg::RegisterCounterparty(msg) => register_counterparty::execute(ctx, msg)?,
             ClientMsg::RecoverClient(_msg) => {
                 // Recover client messages are not dispatched by ibc-rs as they can only be
                 // authorized via a passing governance proposal
@@ -167,6 +316,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg)?,
                 ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg)?,
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_execute(ctx, module, msg)?,
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_execute(ctx, module, msg)?,
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -189,6 +345,12 @@ where
                 }
             }
         }
+        MsgEnvelope::PacketV2(msg) => match msg {
+            PacketMsgV2::Send(msg) => handler_v2::send_packet_execute(ctx, router, msg)?,
+            PacketMsgV2::Recv(msg) => handler_v2::recv_packet_execute(ctx, router, msg)?,
+            PacketMsgV2::Ack(msg) => handler_v2::acknowledgement_packet_execute(ctx, router, msg)?,
+            PacketMsgV2::Timeout(msg) => handler_v2::timeout_packet_execute(ctx, router, msg)?,
+        },
     }
 
     Ok(())
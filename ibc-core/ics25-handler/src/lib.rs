@@ -8,7 +8,13 @@
 //! to the context (e.g. [`ExecutionContext`](ibc_core_host::ExecutionContext))
 //! while processing `M`. If the transaction containing `M` consists of multiple
 //! messages, then typically the state modifications from all messages are
-//! expected to be rolled back as well.
+//! expected to be rolled back as well. The
+//! [`dispatch_batch`](entrypoint::dispatch_batch) entrypoint does so on behalf
+//! of the host, by processing them against a
+//! [`CachedContext`](cache::CachedContext) while the modules stage their state
+//! transitions, whereas the
+//! [`dispatch_uncommitted`](entrypoint::dispatch_uncommitted) entrypoint
+//! processes messages the same way, but discards their state transitions.
 #![no_std]
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
@@ -24,6 +30,7 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+pub mod cache;
 pub mod entrypoint;
 
 /// Re-export IBC handler types from `ibc-core-handler-types` crate.
@@ -5,9 +5,12 @@ use displaydoc::Display;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_host_types::error::HostError;
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
 
+use crate::events::IbcEvent;
+
 /// Top-level type that surfaces errors from the core ibc-rs crates.
 #[derive(Debug, Display, From)]
 pub enum HandlerError {
@@ -32,3 +35,46 @@ impl std::error::Error for HandlerError {
         }
     }
 }
+
+/// Error raised while dispatching a batch of messages.
+#[derive(Debug, Display)]
+pub enum BatchError {
+    /// message `{index}` of the batch failed: {error}
+    Message { index: usize, error: HandlerError },
+    /// failed to commit the batch: {0}
+    Commit(HostError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Message { error, .. } => Some(error),
+            Self::Commit(e) => Some(e),
+        }
+    }
+}
+
+/// Error raised while dispatching a batch of messages without committing it.
+///
+/// Carries the events and logs emitted by the messages preceding the failing
+/// one, for hosts to report along with the error.
+#[derive(Debug)]
+pub struct UncommittedBatchError {
+    pub error: BatchError,
+    pub events: Vec<IbcEvent>,
+    pub logs: Vec<String>,
+}
+
+impl core::fmt::Display for UncommittedBatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UncommittedBatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -1,6 +1,7 @@
 //! Defines events emitted during handling of IBC messages
 
 use ibc_core_channel_types::events as ChannelEvents;
+use ibc_core_channel_types::v2::events as ChannelEventsV2;
 use ibc_core_client_types::events::{self as ClientEvents};
 use ibc_core_connection_types::events as ConnectionEvents;
 use ibc_core_host_types::error::DecodingError;
@@ -43,6 +44,15 @@ pub enum IbcEvent {
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
 
+    UpgradeInitChannel(ChannelEvents::UpgradeInit),
+    UpgradeTryChannel(ChannelEvents::UpgradeTry),
+    UpgradeAckChannel(ChannelEvents::UpgradeAck),
+    UpgradeConfirmChannel(ChannelEvents::UpgradeConfirm),
+    UpgradeOpenChannel(ChannelEvents::UpgradeOpen),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimedOut),
+    UpgradeCancelChannel(ChannelEvents::UpgradeCancelled),
+    UpgradeErrorChannel(ChannelEvents::UpgradeError),
+
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
     WriteAcknowledgement(ChannelEvents::WriteAcknowledgement),
@@ -50,6 +60,12 @@ pub enum IbcEvent {
     TimeoutPacket(ChannelEvents::TimeoutPacket),
     ChannelClosed(ChannelEvents::ChannelClosed),
 
+    SendPacketV2(ChannelEventsV2::SendPacket),
+    ReceivePacketV2(ChannelEventsV2::ReceivePacket),
+    WriteAcknowledgementV2(ChannelEventsV2::WriteAcknowledgement),
+    AcknowledgePacketV2(ChannelEventsV2::AcknowledgePacket),
+    TimeoutPacketV2(ChannelEventsV2::TimeoutPacket),
+
     Module(ModuleEvent),
     Message(MessageEvent),
 }
@@ -73,12 +89,25 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeInitChannel(event) => event.into(),
+            IbcEvent::UpgradeTryChannel(event) => event.into(),
+            IbcEvent::UpgradeAckChannel(event) => event.into(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeOpenChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelChannel(event) => event.into(),
+            IbcEvent::UpgradeErrorChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into()?,
             IbcEvent::ReceivePacket(event) => event.try_into()?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into()?,
             IbcEvent::AcknowledgePacket(event) => event.try_into()?,
             IbcEvent::TimeoutPacket(event) => event.try_into()?,
             IbcEvent::ChannelClosed(event) => event.into(),
+            IbcEvent::SendPacketV2(event) => event.into(),
+            IbcEvent::ReceivePacketV2(event) => event.into(),
+            IbcEvent::WriteAcknowledgementV2(event) => event.into(),
+            IbcEvent::AcknowledgePacketV2(event) => event.into(),
+            IbcEvent::TimeoutPacketV2(event) => event.into(),
             IbcEvent::Module(event) => event.into(),
             IbcEvent::Message(event) => abci::Event {
                 kind: MESSAGE_EVENT.to_string(),
@@ -105,12 +134,25 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeInitChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTryChannel(event) => event.event_type(),
+            IbcEvent::UpgradeAckChannel(event) => event.event_type(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeOpenChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.event_type(),
+            IbcEvent::UpgradeCancelChannel(event) => event.event_type(),
+            IbcEvent::UpgradeErrorChannel(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
             IbcEvent::AcknowledgePacket(event) => event.event_type(),
             IbcEvent::TimeoutPacket(event) => event.event_type(),
             IbcEvent::ChannelClosed(event) => event.event_type(),
+            IbcEvent::SendPacketV2(event) => event.event_type(),
+            IbcEvent::ReceivePacketV2(event) => event.event_type(),
+            IbcEvent::WriteAcknowledgementV2(event) => event.event_type(),
+            IbcEvent::AcknowledgePacketV2(event) => event.event_type(),
+            IbcEvent::TimeoutPacketV2(event) => event.event_type(),
             IbcEvent::Module(module_event) => module_event.kind.as_str(),
             IbcEvent::Message(_) => MESSAGE_EVENT,
         }
@@ -0,0 +1,718 @@
+//! Defines the `Middleware` trait, and the `StackBuilder` which composes
+//! middleware on top of an application.
+//!
+//! A middleware wraps the next module of its stack, which is either the
+//! application or another middleware. The callbacks of the ICS-26 router go
+//! up the stack, from the outermost middleware to the application, while the
+//! packets and acknowledgements written by the application go down the stack.
+use core::fmt::Debug;
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::Version;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::Signer;
+
+use crate::module::Module;
+
+/// A module wrapping the next module of a stack, which may intercept any of
+/// its callbacks.
+///
+/// All the callbacks forward to the next module by default, so that a
+/// middleware only needs to implement the ones it is interested in. Every
+/// middleware is also a [`Module`], and can therefore be registered with the
+/// router or wrapped by another middleware.
+///
+/// As the callbacks of both traits share the same names, they need to be
+/// called as `Module::on_recv_packet_execute(&mut middleware, ..)` when both
+/// traits are in scope.
+pub trait Middleware: Debug {
+    /// The module wrapped by this middleware.
+    type Next: Module;
+
+    fn next(&self) -> &Self::Next;
+
+    fn next_mut(&mut self) -> &mut Self::Next;
+
+    /// Intercepts a packet sent by the wrapped module, before it goes further
+    /// down the stack.
+    fn on_send_packet(&mut self, packet: Packet) -> Result<Packet, ChannelError> {
+        Ok(packet)
+    }
+
+    /// Intercepts an acknowledgement written asynchronously by the wrapped
+    /// module, before it goes further down the stack.
+    fn on_write_acknowledgement(
+        &mut self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, ChannelError> {
+        Ok(acknowledgement)
+    }
+
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.next().on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.next_mut().on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.next().on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.next_mut().on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.next()
+            .on_chan_open_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.next_mut()
+            .on_chan_open_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.next()
+            .on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.next_mut()
+            .on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.next().on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.next_mut()
+            .on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.next()
+            .on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.next_mut()
+            .on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.next().on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.next_mut().on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.next().on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.next_mut().on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.next()
+            .on_chan_upgrade_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.next_mut()
+            .on_chan_upgrade_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        self.next_mut().on_chan_upgrade_open_execute(
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        self.next_mut().on_recv_packet_execute(packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        self.next()
+            .on_acknowledgement_packet_validate(packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        self.next_mut()
+            .on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        self.next().on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        self.next_mut().on_timeout_packet_execute(packet, relayer)
+    }
+}
+
+impl<M: Middleware> Module for M {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_open_init_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_open_init_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_open_try_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_open_try_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_open_ack_validate(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_open_ack_execute(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_open_confirm_validate(self, port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_open_confirm_execute(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_close_init_validate(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_close_init_execute(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_close_confirm_validate(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_close_confirm_execute(self, port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_upgrade_init_validate(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_upgrade_init_execute(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_upgrade_try_validate(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_upgrade_try_execute(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_upgrade_ack_validate(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_upgrade_ack_execute(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        Middleware::on_chan_upgrade_open_execute(
+            self,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        Middleware::on_recv_packet_execute(self, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_acknowledgement_packet_validate(self, packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        Middleware::on_acknowledgement_packet_execute(self, packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_timeout_packet_validate(self, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        Middleware::on_timeout_packet_execute(self, packet, relayer)
+    }
+
+    /// The packet goes through the wrapped module first, as packets are sent
+    /// from the bottom of the stack.
+    fn send_packet(&mut self, packet: Packet) -> Result<Packet, ChannelError> {
+        let packet = self.next_mut().send_packet(packet)?;
+
+        self.on_send_packet(packet)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, ChannelError> {
+        let acknowledgement = self
+            .next_mut()
+            .write_acknowledgement(packet, acknowledgement)?;
+
+        self.on_write_acknowledgement(packet, acknowledgement)
+    }
+}
+
+/// Composes a stack of middleware on top of an application, to be registered
+/// with the router under the given port.
+///
+/// ```ignore
+/// let (port_id, stack) = StackBuilder::new(PortId::transfer(), transfer_app)
+///     .wrap(|app| FeeMiddleware::new(app, fee_ctx))
+///     .wrap(|app| RateLimitMiddleware::new(app, rate_limit_ctx))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct StackBuilder<M> {
+    port_id: PortId,
+    module: M,
+}
+
+impl<M: Module> StackBuilder<M> {
+    /// Starts a stack with the application at its bottom.
+    pub fn new(port_id: PortId, app: M) -> Self {
+        Self {
+            port_id,
+            module: app,
+        }
+    }
+
+    /// Wraps the current stack with the middleware returned by `wrap`, which
+    /// becomes the top of the stack.
+    pub fn wrap<W, F>(self, wrap: F) -> StackBuilder<W>
+    where
+        W: Middleware<Next = M>,
+        F: FnOnce(M) -> W,
+    {
+        StackBuilder {
+            port_id: self.port_id,
+            module: wrap(self.module),
+        }
+    }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    /// Returns the port to bind the stack to, along with the top of the stack.
+    pub fn build(self) -> (PortId, M) {
+        (self.port_id, self.module)
+    }
+}
@@ -118,6 +118,99 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Called when a channel upgrade is initiated on this end of the channel.
+    ///
+    /// Returns the version the module wants to upgrade to. Modules that do not
+    /// support channel upgrades should keep the default implementation, which
+    /// rejects the upgrade.
+    fn on_chan_upgrade_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            description: "module does not support channel upgrades".to_string(),
+        })
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            description: "module does not support channel upgrades".to_string(),
+        })
+    }
+
+    /// Called when the counterparty has initiated a channel upgrade.
+    ///
+    /// Returns the version the module agrees to upgrade to, which must match
+    /// the counterparty's proposed version for the upgrade to proceed.
+    fn on_chan_upgrade_try_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            description: "module does not support channel upgrades".to_string(),
+        })
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            description: "module does not support channel upgrades".to_string(),
+        })
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called once the channel upgrade has completed and the channel end has
+    /// been switched to the upgraded parameters. This callback cannot fail,
+    /// as the counterparty may have already moved to the upgraded channel.
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> ModuleExtras {
+        ModuleExtras::empty()
+    }
+
     // Note: no `on_recv_packet_validate()`
     // the `onRecvPacket` callback always succeeds
     // if any error occurs, than an "error acknowledgement"
@@ -139,12 +232,11 @@ pub trait Module: Debug {
     ///
     /// ## Committing a packet asynchronously
     ///
-    /// Event emission and state updates for packet acknowledgements
-    /// can be performed asynchronously using [`emit_packet_acknowledgement_event`]
-    /// and [`commit_packet_acknowledgment`], respectively.
+    /// The packet is then recorded as pending an acknowledgement, which
+    /// can be written in a later block with [`write_acknowledgement`],
+    /// once it went through [`Module::write_acknowledgement`].
     ///
-    /// [`commit_packet_acknowledgment`]: ../../channel/handler/fn.commit_packet_acknowledgment.html
-    /// [`emit_packet_acknowledgement_event`]: ../../channel/handler/fn.emit_packet_acknowledgement_event.html
+    /// [`write_acknowledgement`]: ../../channel/handler/fn.write_acknowledgement.html
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
@@ -178,4 +270,30 @@ pub trait Module: Debug {
         packet: &Packet,
         relayer: &Signer,
     ) -> (ModuleExtras, Result<(), ChannelError>);
+
+    /// Passes a packet sent by the application down through the middleware
+    /// wrapping it, before the packet gets committed.
+    ///
+    /// Returns the packet to be committed. Applications keep the default
+    /// implementation, while middleware stacks get it from [`Middleware`].
+    ///
+    /// [`Middleware`]: crate::middleware::Middleware
+    fn send_packet(&mut self, packet: Packet) -> Result<Packet, ChannelError> {
+        Ok(packet)
+    }
+
+    /// Passes an acknowledgement written by the application for the given
+    /// received packet down through the middleware wrapping it, before the
+    /// acknowledgement gets committed.
+    ///
+    /// This only needs to be called for acknowledgements written
+    /// asynchronously, as the ones returned from [`Module::on_recv_packet_execute`]
+    /// already went through the middleware.
+    fn write_acknowledgement(
+        &mut self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, ChannelError> {
+        Ok(acknowledgement)
+    }
 }
@@ -2,8 +2,10 @@
 
 use ibc_core_host_types::identifiers::PortId;
 use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
+use crate::v2::ModuleV2;
 
 /// Router as defined in ICS-26, which binds modules to ports.
 pub trait Router {
@@ -15,4 +17,42 @@ pub trait Router {
 
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Returns a reference to a `ModuleV2` registered against the specified
+    /// `ModuleId`, if any.
+    ///
+    /// Defaults to `None`, for routers that do not handle IBC v2 packets.
+    fn get_route_v2(&self, _module_id: &ModuleId) -> Option<&dyn ModuleV2> {
+        None
+    }
+
+    /// Returns a mutable reference to a `ModuleV2` registered against the
+    /// specified `ModuleId`, if any.
+    fn get_route_v2_mut(&mut self, _module_id: &ModuleId) -> Option<&mut dyn ModuleV2> {
+        None
+    }
+}
+
+/// A [`Router`] whose modules can stage the state transitions they make while
+/// processing messages, so that those of a batch of messages are either all
+/// committed or all discarded.
+pub trait TransactionalRouter: Router {
+    /// Starts staging the state transitions made by the modules, which are
+    /// observed by the modules until committed or discarded.
+    fn begin_staging(&mut self);
+
+    /// Returns the keys of the module stores written to since staging began,
+    /// along with the module each of them belongs to.
+    fn staged_keys(&self) -> Vec<(ModuleId, Vec<u8>)>;
+
+    /// Commits the staged state transitions of the modules, and stops
+    /// staging.
+    ///
+    /// Must not fail, as it is called once the state transitions of the IBC
+    /// handlers have been committed.
+    fn commit_staged(&mut self);
+
+    /// Discards the staged state transitions of the modules, and stops
+    /// staging.
+    fn discard_staged(&mut self);
 }
@@ -0,0 +1,89 @@
+//! Defines the `ModuleV2` trait, implemented by the applications that handle
+//! IBC v2 packets.
+use core::fmt::Debug;
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::v2::acknowledgement::RecvPacketResult;
+use ibc_core_channel_types::v2::packet::Payload;
+use ibc_core_host_types::identifiers::{ClientId, Sequence};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::Signer;
+
+/// An application handling IBC v2 packets.
+///
+/// The payload of an IBC v2 packet is routed by its port to the application
+/// bound to it. The callbacks are invoked with that payload, along with the
+/// identifiers of the packet it belongs to.
+pub trait ModuleV2: Debug {
+    fn on_send_packet_validate(
+        &self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        signer: &Signer,
+    ) -> Result<(), ChannelError>;
+
+    fn on_send_packet_execute(
+        &mut self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        signer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>);
+
+    /// Processes a received payload.
+    ///
+    /// This callback cannot fail: a payload that cannot be processed must be
+    /// reported with [`RecvPacketResult::failure`], which gets the whole
+    /// packet acknowledged with the error acknowledgement.
+    fn on_recv_packet_execute(
+        &mut self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult);
+
+    /// Validates the acknowledgement of a payload, which is the error
+    /// acknowledgement if the reception of any payload of the packet failed.
+    fn on_acknowledgement_packet_validate(
+        &self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> Result<(), ChannelError>;
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>);
+
+    fn on_timeout_packet_validate(
+        &self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError>;
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        source_client: &ClientId,
+        destination_client: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>);
+}
@@ -67,6 +67,12 @@ pub mod core {
 }
 
 pub mod clients {
+    /// Re-exports ICS-06 solo machine client data structures from the
+    /// `ibc-client-solomachine-types` crate
+    pub mod solomachine {
+        #[doc(inline)]
+        pub use ibc_client_solomachine_types::*;
+    }
     /// Re-exports ICS-07 tendermint client data structures from the
     /// `ibc-client-tendermint-types` crate
     pub mod tendermint {
@@ -79,6 +85,12 @@ pub mod clients {
         #[doc(inline)]
         pub use ibc_client_wasm_types::*;
     }
+    /// Re-exports ICS-09 localhost client data structures from the
+    /// `ibc-client-localhost-types` crate
+    pub mod localhost {
+        #[doc(inline)]
+        pub use ibc_client_localhost_types::*;
+    }
 }
 
 /// Re-exports data structures of various IBC applications
@@ -19,14 +19,21 @@ const INVALID_ARGS: &str = "invalid context argument";
 pub(crate) struct ClientCtx {
     ident: Ident,
     generics: Vec<GenericArgument>,
+    params: Vec<GenericArgument>,
     predicates: Vec<WherePredicate>,
 }
 
 impl ClientCtx {
-    fn new(ident: Ident, generics: Vec<GenericArgument>, predicates: Vec<WherePredicate>) -> Self {
+    fn new(
+        ident: Ident,
+        generics: Vec<GenericArgument>,
+        params: Vec<GenericArgument>,
+        predicates: Vec<WherePredicate>,
+    ) -> Self {
         Self {
             ident,
             generics,
+            params,
             predicates,
         }
     }
@@ -34,9 +41,9 @@ impl ClientCtx {
     /// Returns the `impl` quote block for the given context type, used for
     /// implementing ClientValidation/ExecutionContext on the given enum.
     fn impl_ts(&self) -> TokenStream {
-        let gens = self.generics.clone();
+        let params = self.params.clone();
 
-        quote! { impl<#(#gens),*> }
+        quote! { impl<#(#params),*> }
     }
 
     /// Returns the `where` clause quote block for the given context type, used
@@ -58,16 +65,19 @@ impl ToTokens for ClientCtx {
     }
 }
 
+/// The contexts to implement the client state traits for. Each of the
+/// `validation` and `execution` attributes may be given more than once, in
+/// which case the corresponding trait is implemented for every context.
 pub(crate) struct Opts {
-    client_validation_context: ClientCtx,
-    client_execution_context: ClientCtx,
+    client_validation_contexts: Vec<ClientCtx>,
+    client_execution_contexts: Vec<ClientCtx>,
 }
 
 impl Opts {
     /// Returns the `Opts` struct from the given `DeriveInput` AST.
     fn from_derive_input(ast: &DeriveInput) -> Result<Self, Error> {
-        let mut client_validation_context = None;
-        let mut client_execution_context = None;
+        let mut client_validation_contexts = vec![];
+        let mut client_execution_contexts = vec![];
 
         if ast.attrs.is_empty() {
             return Err(Error::new_spanned(ast, MISSING_ATTR));
@@ -87,48 +97,89 @@ impl Opts {
                     Err(e) => return Err(Error::new_spanned(attr, e)),
                 };
 
-                let (gens, ps) = split_for_impl(path_segment.arguments)?;
+                let mut params = vec![];
+                let mut predicates = vec![];
 
-                let ctx = ClientCtx::new(path_segment.ident.clone(), gens, ps);
+                let gens = split_for_impl(path_segment.arguments, &mut params, &mut predicates)?;
+
+                let ctx = ClientCtx::new(path_segment.ident.clone(), gens, params, predicates);
 
                 match meta_ident.as_str() {
-                    "validation" => client_validation_context = Some(ctx),
-                    "execution" => client_execution_context = Some(ctx),
+                    "validation" => client_validation_contexts.push(ctx),
+                    "execution" => client_execution_contexts.push(ctx),
                     _ => return Err(Error::new_spanned(&meta_list.path, INVALID_ATTR)),
                 };
             }
         }
 
-        let client_validation_context = client_validation_context
-            .ok_or_else(|| Error::new_spanned(ast, MISSING_VALIDATION_ATTR))?;
-        let client_execution_context = client_execution_context
-            .ok_or_else(|| Error::new_spanned(ast, MISSING_EXECUTION_ATTR))?;
+        if client_validation_contexts.is_empty() {
+            return Err(Error::new_spanned(ast, MISSING_VALIDATION_ATTR));
+        }
+        if client_execution_contexts.is_empty() {
+            return Err(Error::new_spanned(ast, MISSING_EXECUTION_ATTR));
+        }
 
         Ok(Self {
-            client_validation_context,
-            client_execution_context,
+            client_validation_contexts,
+            client_execution_contexts,
         })
     }
 }
 
+/// Splits the generic arguments of a context type into the arguments of the
+/// type itself, with their trait bounds stripped, and the generic parameters
+/// and where clause predicates of the `impl` block.
+///
+/// Generic arguments of nested types, such as `S` in
+/// `Context<'a, Store<S: Clone>>`, are also turned into generic parameters.
 fn split_for_impl(
     args: syn::PathArguments,
-) -> Result<(Vec<GenericArgument>, Vec<WherePredicate>), Error> {
+    params: &mut Vec<GenericArgument>,
+    predicates: &mut Vec<WherePredicate>,
+) -> Result<Vec<GenericArgument>, Error> {
     let mut generics = vec![];
-    let mut predicates = vec![];
 
     if let syn::PathArguments::AngleBracketed(gen) = args {
         generics.reserve_exact(gen.args.len());
         for arg in gen.args {
             match arg.clone() {
-                GenericArgument::Type(_) | GenericArgument::Lifetime(_) => {
+                GenericArgument::Lifetime(_) => {
+                    push_param(params, arg.clone());
+                    generics.push(arg);
+                }
+                GenericArgument::Type(syn::Type::Path(mut type_path))
+                    if type_path.qself.is_none() =>
+                {
+                    let is_param = type_path.path.get_ident().is_some();
+
+                    for segment in type_path.path.segments.iter_mut() {
+                        let arguments = core::mem::take(&mut segment.arguments);
+                        let nested = split_for_impl(arguments.clone(), params, predicates)?;
+
+                        if let syn::PathArguments::AngleBracketed(mut nested_args) = arguments {
+                            nested_args.args = nested.into_iter().collect();
+                            segment.arguments = syn::PathArguments::AngleBracketed(nested_args);
+                        }
+                    }
+
+                    let arg = GenericArgument::Type(syn::Type::Path(type_path));
+
+                    if is_param {
+                        push_param(params, arg.clone());
+                    }
+
+                    generics.push(arg);
+                }
+                GenericArgument::Type(_) => {
+                    push_param(params, arg.clone());
                     generics.push(arg);
                 }
                 GenericArgument::Constraint(c) => {
                     let ident = c.ident.into_token_stream();
 
-                    let gen = syn::parse2(ident.into_token_stream())?;
+                    let gen: GenericArgument = syn::parse2(ident.into_token_stream())?;
 
+                    push_param(params, gen.clone());
                     generics.push(gen);
 
                     let gen_type_param: syn::TypeParam =
@@ -141,7 +192,20 @@ fn split_for_impl(
         }
     }
 
-    Ok((generics, predicates))
+    Ok(generics)
+}
+
+/// Adds `param` to the generic parameters of the `impl` block, unless it is
+/// already declared.
+fn push_param(params: &mut Vec<GenericArgument>, param: GenericArgument) {
+    let declared = param.to_token_stream().to_string();
+
+    if !params
+        .iter()
+        .any(|p| p.to_token_stream().to_string() == declared)
+    {
+        params.push(param);
+    }
 }
 
 pub fn client_state_derive_impl(ast: DeriveInput, imports: &Imports) -> TokenStream {
@@ -157,14 +221,18 @@ pub fn client_state_derive_impl(ast: DeriveInput, imports: &Imports) -> TokenStr
     };
 
     let ClientStateCommon_impl_block = impl_ClientStateCommon(enum_name, enum_variants, imports);
-    let ClientStateValidation_impl_block =
-        impl_ClientStateValidation(enum_name, enum_variants, &opts, imports);
-    let ClientStateExecution_impl_block =
-        impl_ClientStateExecution(enum_name, enum_variants, &opts, imports);
+    let ClientStateValidation_impl_blocks = opts
+        .client_validation_contexts
+        .iter()
+        .map(|ctx| impl_ClientStateValidation(enum_name, enum_variants, ctx, imports));
+    let ClientStateExecution_impl_blocks = opts
+        .client_execution_contexts
+        .iter()
+        .map(|ctx| impl_ClientStateExecution(enum_name, enum_variants, ctx, imports));
 
     quote! {
         #ClientStateCommon_impl_block
-        #ClientStateValidation_impl_block
-        #ClientStateExecution_impl_block
+        #(#ClientStateValidation_impl_blocks)*
+        #(#ClientStateExecution_impl_blocks)*
     }
 }
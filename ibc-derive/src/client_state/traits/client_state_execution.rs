@@ -4,33 +4,33 @@ use syn::punctuated::{Iter, Punctuated};
 use syn::token::Comma;
 use syn::Variant;
 
-use crate::client_state::Opts;
+use crate::client_state::ClientCtx;
 use crate::utils::{get_enum_variant_type_path, Imports};
 
 pub(crate) fn impl_ClientStateExecution(
     client_state_enum_name: &Ident,
     enum_variants: &Punctuated<Variant, Comma>,
-    opts: &Opts,
+    client_ctx: &ClientCtx,
     imports: &Imports,
 ) -> TokenStream {
     let initialise_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { initialise(cs, ctx, client_id, consensus_state) },
         imports,
     );
     let update_state_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { update_state(cs, ctx, client_id, header) },
         imports,
     );
     let update_state_on_misbehaviour_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { update_state_on_misbehaviour(cs, ctx, client_id, client_message) },
         imports,
     );
@@ -38,7 +38,7 @@ pub(crate) fn impl_ClientStateExecution(
     let update_state_with_upgrade_client_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { update_state_on_upgrade(cs, ctx, client_id, upgraded_client_state, upgraded_consensus_state) },
         imports,
     );
@@ -46,8 +46,8 @@ pub(crate) fn impl_ClientStateExecution(
     let update_on_recovery_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
-        quote! { update_on_recovery(cs, ctx, client_id, substitute_client_state, substitute_consensus_state) },
+        client_ctx,
+        quote! { update_on_recovery(cs, ctx, client_id, substitute_client_id, substitute_client_state, substitute_consensus_state) },
         imports,
     );
 
@@ -60,14 +60,14 @@ pub(crate) fn impl_ClientStateExecution(
 
     // The types we need for the generated code.
     let HostClientState = client_state_enum_name;
-    let E = &opts.client_execution_context.clone().into_token_stream();
+    let E = &client_ctx.clone().into_token_stream();
 
     // The `impl` block quote based on whether the context includes generics.
-    let Impl = opts.client_execution_context.impl_ts();
+    let Impl = client_ctx.impl_ts();
 
     // The `Where` clause quote based on whether the generics within the context
     // include trait bounds
-    let Where = opts.client_execution_context.where_clause_ts();
+    let Where = client_ctx.where_clause_ts();
 
     quote! {
         #Impl #ClientStateExecution<#E> for #HostClientState #Where {
@@ -120,6 +120,7 @@ pub(crate) fn impl_ClientStateExecution(
                 &self,
                 ctx: &mut #E,
                 client_id: &#ClientId,
+                substitute_client_id: &#ClientId,
                 substitute_client_state: #Any,
                 substitute_consensus_state: #Any,
             ) -> core::result::Result<(), #ClientError> {
@@ -135,7 +136,7 @@ pub(crate) fn impl_ClientStateExecution(
 fn delegate_call_in_match(
     enum_name: &Ident,
     enum_variants: Iter<'_, Variant>,
-    opts: &Opts,
+    client_ctx: &ClientCtx,
     fn_call: TokenStream,
     imports: &Imports,
 ) -> Vec<TokenStream> {
@@ -146,7 +147,7 @@ fn delegate_call_in_match(
             let HostClientState = enum_name;
             let Tendermint = &variant.ident;
             let TmClientState = get_enum_variant_type_path(variant);
-            let ClientExecutionContext = &opts.client_execution_context;
+            let ClientExecutionContext = client_ctx;
 
             // Note: We use `HostClientState` and `Tendermint`, etc as *variable names*. They're
             // only meant to improve readability of the `quote`; it's not literally what's generated!
@@ -4,19 +4,19 @@ use syn::punctuated::{Iter, Punctuated};
 use syn::token::Comma;
 use syn::Variant;
 
-use crate::client_state::Opts;
+use crate::client_state::ClientCtx;
 use crate::utils::{get_enum_variant_type_path, Imports};
 
 pub(crate) fn impl_ClientStateValidation(
     client_state_enum_name: &Ident,
     enum_variants: &Punctuated<Variant, Comma>,
-    opts: &Opts,
+    client_ctx: &ClientCtx,
     imports: &Imports,
 ) -> TokenStream {
     let verify_client_message_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { verify_client_message(cs, ctx, client_id, client_message) },
         imports,
     );
@@ -24,7 +24,7 @@ pub(crate) fn impl_ClientStateValidation(
     let check_for_misbehaviour_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { check_for_misbehaviour(cs, ctx, client_id, client_message) },
         imports,
     );
@@ -32,7 +32,7 @@ pub(crate) fn impl_ClientStateValidation(
     let status_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { status(cs, ctx, client_id) },
         imports,
     );
@@ -40,28 +40,82 @@ pub(crate) fn impl_ClientStateValidation(
     let check_substitute_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
-        opts,
+        client_ctx,
         quote! { check_substitute(cs, ctx, substitute_client_state) },
         imports,
     );
 
+    let is_redundant_update_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        client_ctx,
+        quote! { is_redundant_update(cs, ctx, client_id, header) },
+        imports,
+    );
+
+    let timestamp_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        client_ctx,
+        quote! { timestamp_at_height(cs, ctx, consensus_state_path) },
+        imports,
+    );
+
+    let verify_membership_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        client_ctx,
+        quote! { verify_membership_at_height(cs, ctx, consensus_state_path, prefix, proof, path, value) },
+        imports,
+    );
+
+    let verify_non_membership_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        client_ctx,
+        quote! { verify_non_membership_at_height(cs, ctx, consensus_state_path, prefix, proof, path) },
+        imports,
+    );
+
+    let verify_membership_raw_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        client_ctx,
+        quote! { verify_membership_raw_at_height(cs, ctx, consensus_state_path, prefix, proof, path, value) },
+        imports,
+    );
+
+    let verify_non_membership_raw_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        client_ctx,
+        quote! { verify_non_membership_raw_at_height(cs, ctx, consensus_state_path, prefix, proof, path) },
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
     let ClientId = imports.client_id();
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
     let Status = imports.status();
+    let ClientConsensusStatePath = imports.client_consensus_state_path();
+    let CommitmentPrefix = imports.commitment_prefix();
+    let CommitmentProofBytes = imports.commitment_proof_bytes();
+    let Path = imports.path();
+    let PathBytes = imports.path_bytes();
+    let Timestamp = imports.timestamp();
 
     // The types we need for the generated code.
     let HostClientState = client_state_enum_name;
-    let V = opts.client_validation_context.clone().into_token_stream();
+    let V = client_ctx.clone().into_token_stream();
 
     // The `impl` block quote based on whether the context includes generics.
-    let Impl = opts.client_validation_context.impl_ts();
+    let Impl = client_ctx.impl_ts();
 
     // The `Where` clause quote based on whether the generics within the context
     // include trait bounds
-    let Where = opts.client_validation_context.where_clause_ts();
+    let Where = client_ctx.where_clause_ts();
 
     quote! {
         #Impl #ClientStateValidation<#V> for #HostClientState #Where {
@@ -106,6 +160,81 @@ pub(crate) fn impl_ClientStateValidation(
                     #(#check_substitute_impl),*
                 }
             }
+
+            fn is_redundant_update(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+                header: #Any,
+            ) -> core::result::Result<bool, #ClientError> {
+                match self {
+                    #(#is_redundant_update_impl),*
+                }
+            }
+
+            fn timestamp_at_height(
+                &self,
+                ctx: &#V,
+                consensus_state_path: &#ClientConsensusStatePath,
+            ) -> core::result::Result<#Timestamp, #ClientError> {
+                match self {
+                    #(#timestamp_at_height_impl),*
+                }
+            }
+
+            fn verify_membership_at_height(
+                &self,
+                ctx: &#V,
+                consensus_state_path: &#ClientConsensusStatePath,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                path: #Path,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_membership_at_height_impl),*
+                }
+            }
+
+            fn verify_non_membership_at_height(
+                &self,
+                ctx: &#V,
+                consensus_state_path: &#ClientConsensusStatePath,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                path: #Path,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_non_membership_at_height_impl),*
+                }
+            }
+
+            fn verify_membership_raw_at_height(
+                &self,
+                ctx: &#V,
+                consensus_state_path: &#ClientConsensusStatePath,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                path: #PathBytes,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_membership_raw_at_height_impl),*
+                }
+            }
+
+            fn verify_non_membership_raw_at_height(
+                &self,
+                ctx: &#V,
+                consensus_state_path: &#ClientConsensusStatePath,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                path: #PathBytes,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_non_membership_raw_at_height_impl),*
+                }
+            }
         }
 
     }
@@ -114,7 +243,7 @@ pub(crate) fn impl_ClientStateValidation(
 fn delegate_call_in_match(
     enum_name: &Ident,
     enum_variants: Iter<'_, Variant>,
-    opts: &Opts,
+    client_ctx: &ClientCtx,
     fn_call: TokenStream,
     imports: &Imports,
 ) -> Vec<TokenStream> {
@@ -125,7 +254,7 @@ fn delegate_call_in_match(
             let HostClientState = enum_name;
             let Tendermint = &variant.ident;
             let TmClientState = get_enum_variant_type_path(variant);
-            let ClientValidationContext = &opts.client_validation_context;
+            let ClientValidationContext = client_ctx;
 
             // Note: We use `HostClientState` and `Tendermint`, etc as *variable names*. They're
             // only meant to improve readability of the `quote`; it's not literally what's generated!
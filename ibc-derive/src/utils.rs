@@ -50,6 +50,11 @@ impl Imports {
         quote! {#Prefix::host::types::path::Path}
     }
 
+    pub fn client_consensus_state_path(&self) -> TokenStream {
+        let Prefix = self.prefix();
+        quote! {#Prefix::host::types::path::ClientConsensusStatePath}
+    }
+
     pub fn path_bytes(&self) -> TokenStream {
         let Prefix = self.prefix();
         quote! {#Prefix::host::types::path::PathBytes}
@@ -0,0 +1,24 @@
+//! Required traits for blanket implementations of the NFT transfer
+//! [`gRPC query service`](crate::apps::nft_transfer).
+
+use ibc::apps::nft_transfer::context::{ClassTraceContext, NftTransferValidationContext};
+use ibc::apps::nft_transfer::types::{PrefixedClassId, TokenId};
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// Context to be implemented by the host that provides the NFT transfer gRPC
+/// query service.
+pub trait NftTransferQueryContext: NftTransferValidationContext + ClassTraceContext {
+    /// Returns the list of all the stored class traces.
+    fn class_traces(&self) -> Result<Vec<PrefixedClassId>, HostError>;
+
+    /// Returns the address of the account escrowing the NFTs sent over the
+    /// given channel.
+    fn escrow_address(&self, port_id: &PortId, channel_id: &ChannelId)
+        -> Result<Signer, HostError>;
+
+    /// Returns the NFTs owned by the given account, along with their class.
+    fn nfts_by_owner(&self, owner: &Signer) -> Result<Vec<(PrefixedClassId, TokenId)>, HostError>;
+}
@@ -0,0 +1,106 @@
+//! Provides utility functions for querying the NFT transfer states.
+
+use ibc::apps::nft_transfer::types::PrefixedClassId;
+use ibc::core::host::types::error::HostError;
+use ibc::primitives::prelude::*;
+
+use super::{
+    NftTransferQueryContext, QueryClassHashRequest, QueryClassHashResponse, QueryClassTraceRequest,
+    QueryClassTraceResponse, QueryClassTracesRequest, QueryClassTracesResponse,
+    QueryEscrowAddressRequest, QueryEscrowAddressResponse, QueryNftsByOwnerRequest,
+    QueryNftsByOwnerResponse, QueryParamsRequest, QueryParamsResponse,
+};
+use crate::error::QueryError;
+
+/// Prefix of the class IDs named after their hash.
+const HASHED_CLASS_ID_PREFIX: &str = "ibc/";
+
+/// Queries for the class trace with the given hash
+pub fn query_class_trace<I>(
+    ibc_ctx: &I,
+    request: &QueryClassTraceRequest,
+) -> Result<QueryClassTraceResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let hash = request
+        .hash
+        .strip_prefix(HASHED_CLASS_ID_PREFIX)
+        .unwrap_or(&request.hash);
+
+    let class_trace = ibc_ctx
+        .class_trace_by_hash(hash)?
+        .ok_or_else(|| HostError::missing_state(format!("class trace for hash {hash}")))?;
+
+    Ok(QueryClassTraceResponse::new(class_trace))
+}
+
+/// Queries for all the class traces
+pub fn query_class_traces<I>(
+    ibc_ctx: &I,
+    _request: &QueryClassTracesRequest,
+) -> Result<QueryClassTracesResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let class_traces = ibc_ctx.class_traces()?;
+
+    Ok(QueryClassTracesResponse::new(class_traces, None))
+}
+
+/// Queries for the hash of the given class
+pub fn query_class_hash<I>(
+    ibc_ctx: &I,
+    request: &QueryClassHashRequest,
+) -> Result<QueryClassHashResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let class_id: PrefixedClassId = request.trace.parse()?;
+
+    let hash = ibc_ctx
+        .class_hash_string(&class_id)
+        .ok_or_else(|| HostError::missing_state("support for hashed class IDs"))?;
+
+    Ok(QueryClassHashResponse::new(hash))
+}
+
+/// Queries for the escrow address of the given channel
+pub fn query_escrow_address<I>(
+    ibc_ctx: &I,
+    request: &QueryEscrowAddressRequest,
+) -> Result<QueryEscrowAddressResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let escrow_address = ibc_ctx.escrow_address(&request.port_id, &request.channel_id)?;
+
+    Ok(QueryEscrowAddressResponse::new(escrow_address))
+}
+
+/// Queries for the parameters of the NFT transfer module
+pub fn query_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryParamsRequest,
+) -> Result<QueryParamsResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    Ok(QueryParamsResponse::new(
+        ibc_ctx.can_send_nft().is_ok(),
+        ibc_ctx.can_receive_nft().is_ok(),
+    ))
+}
+
+/// Queries for the NFTs owned by the given account
+pub fn query_nfts_by_owner<I>(
+    ibc_ctx: &I,
+    request: &QueryNftsByOwnerRequest,
+) -> Result<QueryNftsByOwnerResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let nfts = ibc_ctx.nfts_by_owner(&request.owner)?;
+
+    Ok(QueryNftsByOwnerResponse::new(nfts))
+}
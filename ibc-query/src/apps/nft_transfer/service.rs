@@ -0,0 +1,83 @@
+//! [`NftTransferQueryService`](NftTransferQueryService) takes a generic `I` to store `ibc_context` that implements [`NftTransferQueryContext`](NftTransferQueryContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+//!
+//! The NFTs owned by an account are not exposed by the service, as the NFT
+//! transfer gRPC API has no such query. They are available through
+//! [`query_nfts_by_owner`](super::query_nfts_by_owner).
+
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::applications::nft_transfer::v1::query_server::Query as NftTransferQuery;
+use ibc_proto::ibc::applications::nft_transfer::v1::{
+    QueryClassHashRequest, QueryClassHashResponse, QueryClassTraceRequest, QueryClassTraceResponse,
+    QueryClassTracesRequest, QueryClassTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_class_hash, query_class_trace, query_class_traces, query_escrow_address, query_params,
+    NftTransferQueryContext,
+};
+use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct NftTransferQueryService<I>
+where
+    I: NftTransferQueryContext + Send + Sync + 'static,
+{
+    ibc_context: I,
+}
+
+impl<I> NftTransferQueryService<I>
+where
+    I: NftTransferQueryContext + Send + Sync + 'static,
+{
+    /// The parameter `ibc_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ibc_context: I) -> Self {
+        Self { ibc_context }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> NftTransferQuery for NftTransferQueryService<I>
+where
+    I: NftTransferQueryContext + Send + Sync + 'static,
+{
+    async fn class_trace(
+        &self,
+        request: Request<QueryClassTraceRequest>,
+    ) -> Result<Response<QueryClassTraceResponse>, Status> {
+        query_class_trace(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn class_traces(
+        &self,
+        request: Request<QueryClassTracesRequest>,
+    ) -> Result<Response<QueryClassTracesResponse>, Status> {
+        query_class_traces(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn class_hash(
+        &self,
+        request: Request<QueryClassHashRequest>,
+    ) -> Result<Response<QueryClassHashResponse>, Status> {
+        query_class_hash(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn escrow_address(
+        &self,
+        request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        query_escrow_address(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+    }
+
+    async fn params(
+        &self,
+        request: Request<QueryParamsRequest>,
+    ) -> Result<Response<QueryParamsResponse>, Status> {
+        query_params(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+}
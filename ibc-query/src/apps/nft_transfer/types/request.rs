@@ -0,0 +1,107 @@
+//! Contains all the RPC method request domain types and their conversions to
+//! and from the corresponding gRPC proto types for the NFT transfer module.
+
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::Signer;
+use ibc::primitives::prelude::*;
+use ibc_proto::ibc::applications::nft_transfer::v1::{
+    QueryClassHashRequest as RawQueryClassHashRequest,
+    QueryClassTraceRequest as RawQueryClassTraceRequest,
+    QueryClassTracesRequest as RawQueryClassTracesRequest,
+    QueryEscrowAddressRequest as RawQueryEscrowAddressRequest,
+    QueryParamsRequest as RawQueryParamsRequest,
+};
+
+use crate::error::QueryError;
+use crate::types::PageRequest;
+
+/// Defines the RPC method request type for querying a class trace by its
+/// hash, with or without the `ibc/` prefix
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassTraceRequest {
+    pub hash: String,
+}
+
+impl From<RawQueryClassTraceRequest> for QueryClassTraceRequest {
+    fn from(request: RawQueryClassTraceRequest) -> Self {
+        Self { hash: request.hash }
+    }
+}
+
+/// Defines the RPC method request type for querying all class traces
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassTracesRequest {
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<RawQueryClassTracesRequest> for QueryClassTracesRequest {
+    fn from(request: RawQueryClassTracesRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the hash of a class,
+/// given as its full path
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassHashRequest {
+    pub trace: String,
+}
+
+impl From<RawQueryClassHashRequest> for QueryClassHashRequest {
+    fn from(request: RawQueryClassHashRequest) -> Self {
+        Self {
+            trace: request.trace,
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the escrow address of a
+/// channel
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryEscrowAddressRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl TryFrom<RawQueryEscrowAddressRequest> for QueryEscrowAddressRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryEscrowAddressRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying the NFT transfer
+/// parameters
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryParamsRequest;
+
+impl From<RawQueryParamsRequest> for QueryParamsRequest {
+    fn from(_request: RawQueryParamsRequest) -> Self {
+        Self
+    }
+}
+
+/// Defines the request type for querying the NFTs owned by an account, which
+/// has no gRPC counterpart
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryNftsByOwnerRequest {
+    pub owner: Signer,
+}
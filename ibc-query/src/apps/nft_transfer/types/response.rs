@@ -0,0 +1,225 @@
+//! Contains all the RPC method response domain types and their conversions to
+//! and from the corresponding gRPC proto types for the NFT transfer module.
+
+use ibc::apps::nft_transfer::types::{PrefixedClassId, TokenId};
+use ibc::core::primitives::Signer;
+use ibc::primitives::prelude::*;
+use ibc::primitives::proto::Protobuf;
+use ibc_proto::ibc::applications::nft_transfer::v1::{
+    Params as RawParams, QueryClassHashResponse as RawQueryClassHashResponse,
+    QueryClassTraceResponse as RawQueryClassTraceResponse,
+    QueryClassTracesResponse as RawQueryClassTracesResponse,
+    QueryEscrowAddressResponse as RawQueryEscrowAddressResponse,
+    QueryParamsResponse as RawQueryParamsResponse,
+};
+
+use crate::error::QueryError;
+use crate::types::PageResponse;
+
+/// Defines the RPC method response type when querying a class trace.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassTraceResponse {
+    pub class_trace: PrefixedClassId,
+}
+
+impl QueryClassTraceResponse {
+    pub fn new(class_trace: PrefixedClassId) -> Self {
+        Self { class_trace }
+    }
+}
+
+impl Protobuf<RawQueryClassTraceResponse> for QueryClassTraceResponse {}
+
+impl TryFrom<RawQueryClassTraceResponse> for QueryClassTraceResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryClassTraceResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            class_trace: value
+                .class_trace
+                .ok_or_else(|| QueryError::missing_field("class_trace"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<QueryClassTraceResponse> for RawQueryClassTraceResponse {
+    fn from(response: QueryClassTraceResponse) -> Self {
+        Self {
+            class_trace: Some(response.class_trace.into()),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying all class traces.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassTracesResponse {
+    pub class_traces: Vec<PrefixedClassId>,
+    pub pagination: Option<PageResponse>,
+}
+
+impl QueryClassTracesResponse {
+    pub fn new(class_traces: Vec<PrefixedClassId>, pagination: Option<PageResponse>) -> Self {
+        Self {
+            class_traces,
+            pagination,
+        }
+    }
+}
+
+impl Protobuf<RawQueryClassTracesResponse> for QueryClassTracesResponse {}
+
+impl TryFrom<RawQueryClassTracesResponse> for QueryClassTracesResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryClassTracesResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            class_traces: value
+                .class_traces
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            pagination: value.pagination.map(Into::into),
+        })
+    }
+}
+
+impl From<QueryClassTracesResponse> for RawQueryClassTracesResponse {
+    fn from(response: QueryClassTracesResponse) -> Self {
+        Self {
+            class_traces: response.class_traces.into_iter().map(Into::into).collect(),
+            pagination: response.pagination.map(Into::into),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the hash of a class.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassHashResponse {
+    pub hash: String,
+}
+
+impl QueryClassHashResponse {
+    pub fn new(hash: String) -> Self {
+        Self { hash }
+    }
+}
+
+impl Protobuf<RawQueryClassHashResponse> for QueryClassHashResponse {}
+
+impl From<RawQueryClassHashResponse> for QueryClassHashResponse {
+    fn from(value: RawQueryClassHashResponse) -> Self {
+        Self { hash: value.hash }
+    }
+}
+
+impl From<QueryClassHashResponse> for RawQueryClassHashResponse {
+    fn from(response: QueryClassHashResponse) -> Self {
+        Self {
+            hash: response.hash,
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the escrow address of a
+/// channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryEscrowAddressResponse {
+    pub escrow_address: Signer,
+}
+
+impl QueryEscrowAddressResponse {
+    pub fn new(escrow_address: Signer) -> Self {
+        Self { escrow_address }
+    }
+}
+
+impl Protobuf<RawQueryEscrowAddressResponse> for QueryEscrowAddressResponse {}
+
+impl From<RawQueryEscrowAddressResponse> for QueryEscrowAddressResponse {
+    fn from(value: RawQueryEscrowAddressResponse) -> Self {
+        Self {
+            escrow_address: value.escrow_address.into(),
+        }
+    }
+}
+
+impl From<QueryEscrowAddressResponse> for RawQueryEscrowAddressResponse {
+    fn from(response: QueryEscrowAddressResponse) -> Self {
+        Self {
+            escrow_address: response.escrow_address.to_string(),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the NFT transfer
+/// parameters.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryParamsResponse {
+    /// Whether NFTs can be sent from this chain.
+    pub send_enabled: bool,
+    /// Whether NFTs can be received by this chain.
+    pub receive_enabled: bool,
+}
+
+impl QueryParamsResponse {
+    pub fn new(send_enabled: bool, receive_enabled: bool) -> Self {
+        Self {
+            send_enabled,
+            receive_enabled,
+        }
+    }
+}
+
+impl Protobuf<RawQueryParamsResponse> for QueryParamsResponse {}
+
+impl TryFrom<RawQueryParamsResponse> for QueryParamsResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryParamsResponse) -> Result<Self, Self::Error> {
+        let params = value
+            .params
+            .ok_or_else(|| QueryError::missing_field("params"))?;
+
+        Ok(Self {
+            send_enabled: params.send_enabled,
+            receive_enabled: params.receive_enabled,
+        })
+    }
+}
+
+impl From<QueryParamsResponse> for RawQueryParamsResponse {
+    fn from(response: QueryParamsResponse) -> Self {
+        Self {
+            params: Some(RawParams {
+                send_enabled: response.send_enabled,
+                receive_enabled: response.receive_enabled,
+            }),
+        }
+    }
+}
+
+/// Defines the response type when querying the NFTs owned by an account,
+/// each given along with its class.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryNftsByOwnerResponse {
+    pub nfts: Vec<(PrefixedClassId, TokenId)>,
+}
+
+impl QueryNftsByOwnerResponse {
+    pub fn new(nfts: Vec<(PrefixedClassId, TokenId)>) -> Self {
+        Self { nfts }
+    }
+}
@@ -0,0 +1,21 @@
+//! Required traits for blanket implementations of the token transfer
+//! [`gRPC query service`](crate::apps::transfer).
+
+use ibc::apps::transfer::context::{DenomTraceContext, TokenTransferValidationContext};
+use ibc::apps::transfer::types::PrefixedDenom;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// Context to be implemented by the host that provides the token transfer
+/// gRPC query service.
+pub trait TransferQueryContext: TokenTransferValidationContext + DenomTraceContext {
+    /// Returns the list of all the stored denomination traces.
+    fn denom_traces(&self) -> Result<Vec<PrefixedDenom>, HostError>;
+
+    /// Returns the address of the account escrowing the tokens sent over the
+    /// given channel.
+    fn escrow_address(&self, port_id: &PortId, channel_id: &ChannelId)
+        -> Result<Signer, HostError>;
+}
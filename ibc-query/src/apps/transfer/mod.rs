@@ -0,0 +1,9 @@
+mod context;
+mod query;
+mod service;
+mod types;
+
+pub use context::*;
+pub use query::*;
+pub use service::*;
+pub use types::*;
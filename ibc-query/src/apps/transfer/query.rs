@@ -0,0 +1,117 @@
+//! Provides utility functions for querying the token transfer states.
+
+use ibc::apps::transfer::types::{PrefixedCoin, PrefixedDenom};
+use ibc::core::host::types::error::HostError;
+use ibc::primitives::prelude::*;
+
+use super::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse, TransferQueryContext,
+};
+use crate::error::QueryError;
+
+/// Prefix of the denominations named after their hash.
+const HASHED_DENOM_PREFIX: &str = "ibc/";
+
+/// Queries for the denomination trace with the given hash
+pub fn query_denom_trace<I>(
+    ibc_ctx: &I,
+    request: &QueryDenomTraceRequest,
+) -> Result<QueryDenomTraceResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let hash = request
+        .hash
+        .strip_prefix(HASHED_DENOM_PREFIX)
+        .unwrap_or(&request.hash);
+
+    let denom_trace = ibc_ctx
+        .denom_trace_by_hash(hash)?
+        .ok_or_else(|| HostError::missing_state(format!("denom trace for hash {hash}")))?;
+
+    Ok(QueryDenomTraceResponse::new(denom_trace))
+}
+
+/// Queries for all the denomination traces
+pub fn query_denom_traces<I>(
+    ibc_ctx: &I,
+    _request: &QueryDenomTracesRequest,
+) -> Result<QueryDenomTracesResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let denom_traces = ibc_ctx.denom_traces()?;
+
+    Ok(QueryDenomTracesResponse::new(denom_traces, None))
+}
+
+/// Queries for the parameters of the token transfer module
+pub fn query_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryParamsRequest,
+) -> Result<QueryParamsResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    Ok(QueryParamsResponse::new(
+        ibc_ctx.can_send_coins().is_ok(),
+        ibc_ctx.can_receive_coins().is_ok(),
+    ))
+}
+
+/// Queries for the hash of the given denomination
+pub fn query_denom_hash<I>(
+    ibc_ctx: &I,
+    request: &QueryDenomHashRequest,
+) -> Result<QueryDenomHashResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let denom: PrefixedDenom = request.trace.parse()?;
+
+    let hash = ibc_ctx
+        .denom_hash_string(&denom)
+        .ok_or_else(|| HostError::missing_state("support for hashed denominations"))?;
+
+    Ok(QueryDenomHashResponse::new(hash))
+}
+
+/// Queries for the escrow address of the given channel
+pub fn query_escrow_address<I>(
+    ibc_ctx: &I,
+    request: &QueryEscrowAddressRequest,
+) -> Result<QueryEscrowAddressResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let escrow_address = ibc_ctx.escrow_address(&request.port_id, &request.channel_id)?;
+
+    Ok(QueryEscrowAddressResponse::new(escrow_address))
+}
+
+/// Queries for the total amount of tokens of the given denomination held in
+/// escrow
+pub fn query_total_escrow_for_denom<I>(
+    ibc_ctx: &I,
+    request: &QueryTotalEscrowForDenomRequest,
+) -> Result<QueryTotalEscrowForDenomResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let denom = match request.denom.strip_prefix(HASHED_DENOM_PREFIX) {
+        Some(hash) => ibc_ctx
+            .denom_trace_by_hash(hash)?
+            .ok_or_else(|| HostError::missing_state(format!("denom trace for hash {hash}")))?,
+        None => request.denom.parse()?,
+    };
+
+    let amount = ibc_ctx.total_escrow_for_denom(&denom)?;
+
+    Ok(QueryTotalEscrowForDenomResponse::new(PrefixedCoin {
+        denom,
+        amount,
+    }))
+}
@@ -0,0 +1,87 @@
+//! [`TransferQueryService`](TransferQueryService) takes a generic `I` to store `ibc_context` that implements [`TransferQueryContext`](TransferQueryContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::applications::transfer::v1::query_server::Query as TransferQuery;
+use ibc_proto::ibc::applications::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_denom_hash, query_denom_trace, query_denom_traces, query_escrow_address, query_params,
+    query_total_escrow_for_denom, TransferQueryContext,
+};
+use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct TransferQueryService<I>
+where
+    I: TransferQueryContext + Send + Sync + 'static,
+{
+    ibc_context: I,
+}
+
+impl<I> TransferQueryService<I>
+where
+    I: TransferQueryContext + Send + Sync + 'static,
+{
+    /// The parameter `ibc_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ibc_context: I) -> Self {
+        Self { ibc_context }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> TransferQuery for TransferQueryService<I>
+where
+    I: TransferQueryContext + Send + Sync + 'static,
+{
+    async fn denom_traces(
+        &self,
+        request: Request<QueryDenomTracesRequest>,
+    ) -> Result<Response<QueryDenomTracesResponse>, Status> {
+        query_denom_traces(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn denom_trace(
+        &self,
+        request: Request<QueryDenomTraceRequest>,
+    ) -> Result<Response<QueryDenomTraceResponse>, Status> {
+        query_denom_trace(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn params(
+        &self,
+        request: Request<QueryParamsRequest>,
+    ) -> Result<Response<QueryParamsResponse>, Status> {
+        query_params(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn denom_hash(
+        &self,
+        request: Request<QueryDenomHashRequest>,
+    ) -> Result<Response<QueryDenomHashResponse>, Status> {
+        query_denom_hash(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+
+    async fn escrow_address(
+        &self,
+        request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        query_escrow_address(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+    }
+
+    async fn total_escrow_for_denom(
+        &self,
+        request: Request<QueryTotalEscrowForDenomRequest>,
+    ) -> Result<Response<QueryTotalEscrowForDenomResponse>, Status> {
+        query_total_escrow_for_denom(&self.ibc_context, &request.into_domain())?.into_response()
+    }
+}
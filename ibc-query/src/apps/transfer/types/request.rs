@@ -0,0 +1,116 @@
+//! Contains all the RPC method request domain types and their conversions to
+//! and from the corresponding gRPC proto types for the token transfer module.
+
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::primitives::prelude::*;
+use ibc_proto::ibc::applications::transfer::v1::{
+    QueryDenomHashRequest as RawQueryDenomHashRequest,
+    QueryDenomTraceRequest as RawQueryDenomTraceRequest,
+    QueryDenomTracesRequest as RawQueryDenomTracesRequest,
+    QueryEscrowAddressRequest as RawQueryEscrowAddressRequest,
+    QueryParamsRequest as RawQueryParamsRequest,
+    QueryTotalEscrowForDenomRequest as RawQueryTotalEscrowForDenomRequest,
+};
+
+use crate::error::QueryError;
+use crate::types::PageRequest;
+
+/// Defines the RPC method request type for querying a denomination trace by
+/// its hash, with or without the `ibc/` prefix
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTraceRequest {
+    pub hash: String,
+}
+
+impl From<RawQueryDenomTraceRequest> for QueryDenomTraceRequest {
+    fn from(request: RawQueryDenomTraceRequest) -> Self {
+        Self { hash: request.hash }
+    }
+}
+
+/// Defines the RPC method request type for querying all denomination traces
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTracesRequest {
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<RawQueryDenomTracesRequest> for QueryDenomTracesRequest {
+    fn from(request: RawQueryDenomTracesRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the token transfer
+/// parameters
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryParamsRequest;
+
+impl From<RawQueryParamsRequest> for QueryParamsRequest {
+    fn from(_request: RawQueryParamsRequest) -> Self {
+        Self
+    }
+}
+
+/// Defines the RPC method request type for querying the hash of a
+/// denomination, given as its full path
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomHashRequest {
+    pub trace: String,
+}
+
+impl From<RawQueryDenomHashRequest> for QueryDenomHashRequest {
+    fn from(request: RawQueryDenomHashRequest) -> Self {
+        Self {
+            trace: request.trace,
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the escrow address of a
+/// channel
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryEscrowAddressRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl TryFrom<RawQueryEscrowAddressRequest> for QueryEscrowAddressRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryEscrowAddressRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying the total amount of
+/// tokens of a denomination held in escrow, given as its full path or its
+/// `ibc/<hash>` form
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryTotalEscrowForDenomRequest {
+    pub denom: String,
+}
+
+impl From<RawQueryTotalEscrowForDenomRequest> for QueryTotalEscrowForDenomRequest {
+    fn from(request: RawQueryTotalEscrowForDenomRequest) -> Self {
+        Self {
+            denom: request.denom,
+        }
+    }
+}
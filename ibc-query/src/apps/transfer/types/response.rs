@@ -0,0 +1,251 @@
+//! Contains all the RPC method response domain types and their conversions to
+//! and from the corresponding gRPC proto types for the token transfer module.
+
+use ibc::apps::transfer::types::{PrefixedCoin, PrefixedDenom};
+use ibc::core::primitives::Signer;
+use ibc::primitives::prelude::*;
+use ibc::primitives::proto::Protobuf;
+use ibc_proto::ibc::applications::transfer::v1::{
+    Params as RawParams, QueryDenomHashResponse as RawQueryDenomHashResponse,
+    QueryDenomTraceResponse as RawQueryDenomTraceResponse,
+    QueryDenomTracesResponse as RawQueryDenomTracesResponse,
+    QueryEscrowAddressResponse as RawQueryEscrowAddressResponse,
+    QueryParamsResponse as RawQueryParamsResponse,
+    QueryTotalEscrowForDenomResponse as RawQueryTotalEscrowForDenomResponse,
+};
+
+use crate::error::QueryError;
+use crate::types::PageResponse;
+
+/// Defines the RPC method response type when querying a denomination trace.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTraceResponse {
+    pub denom_trace: PrefixedDenom,
+}
+
+impl QueryDenomTraceResponse {
+    pub fn new(denom_trace: PrefixedDenom) -> Self {
+        Self { denom_trace }
+    }
+}
+
+impl Protobuf<RawQueryDenomTraceResponse> for QueryDenomTraceResponse {}
+
+impl TryFrom<RawQueryDenomTraceResponse> for QueryDenomTraceResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryDenomTraceResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom_trace: value
+                .denom_trace
+                .ok_or_else(|| QueryError::missing_field("denom_trace"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<QueryDenomTraceResponse> for RawQueryDenomTraceResponse {
+    fn from(response: QueryDenomTraceResponse) -> Self {
+        Self {
+            denom_trace: Some(response.denom_trace.into()),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying all denomination
+/// traces.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTracesResponse {
+    pub denom_traces: Vec<PrefixedDenom>,
+    pub pagination: Option<PageResponse>,
+}
+
+impl QueryDenomTracesResponse {
+    pub fn new(denom_traces: Vec<PrefixedDenom>, pagination: Option<PageResponse>) -> Self {
+        Self {
+            denom_traces,
+            pagination,
+        }
+    }
+}
+
+impl Protobuf<RawQueryDenomTracesResponse> for QueryDenomTracesResponse {}
+
+impl TryFrom<RawQueryDenomTracesResponse> for QueryDenomTracesResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryDenomTracesResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom_traces: value
+                .denom_traces
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            pagination: value.pagination.map(Into::into),
+        })
+    }
+}
+
+impl From<QueryDenomTracesResponse> for RawQueryDenomTracesResponse {
+    fn from(response: QueryDenomTracesResponse) -> Self {
+        Self {
+            denom_traces: response.denom_traces.into_iter().map(Into::into).collect(),
+            pagination: response.pagination.map(Into::into),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the token transfer
+/// parameters.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryParamsResponse {
+    /// Whether tokens can be sent from this chain.
+    pub send_enabled: bool,
+    /// Whether tokens can be received by this chain.
+    pub receive_enabled: bool,
+}
+
+impl QueryParamsResponse {
+    pub fn new(send_enabled: bool, receive_enabled: bool) -> Self {
+        Self {
+            send_enabled,
+            receive_enabled,
+        }
+    }
+}
+
+impl Protobuf<RawQueryParamsResponse> for QueryParamsResponse {}
+
+impl TryFrom<RawQueryParamsResponse> for QueryParamsResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryParamsResponse) -> Result<Self, Self::Error> {
+        let params = value
+            .params
+            .ok_or_else(|| QueryError::missing_field("params"))?;
+
+        Ok(Self {
+            send_enabled: params.send_enabled,
+            receive_enabled: params.receive_enabled,
+        })
+    }
+}
+
+impl From<QueryParamsResponse> for RawQueryParamsResponse {
+    fn from(response: QueryParamsResponse) -> Self {
+        Self {
+            params: Some(RawParams {
+                send_enabled: response.send_enabled,
+                receive_enabled: response.receive_enabled,
+            }),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the hash of a
+/// denomination.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomHashResponse {
+    pub hash: String,
+}
+
+impl QueryDenomHashResponse {
+    pub fn new(hash: String) -> Self {
+        Self { hash }
+    }
+}
+
+impl Protobuf<RawQueryDenomHashResponse> for QueryDenomHashResponse {}
+
+impl From<RawQueryDenomHashResponse> for QueryDenomHashResponse {
+    fn from(value: RawQueryDenomHashResponse) -> Self {
+        Self { hash: value.hash }
+    }
+}
+
+impl From<QueryDenomHashResponse> for RawQueryDenomHashResponse {
+    fn from(response: QueryDenomHashResponse) -> Self {
+        Self {
+            hash: response.hash,
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the escrow address of a
+/// channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryEscrowAddressResponse {
+    pub escrow_address: Signer,
+}
+
+impl QueryEscrowAddressResponse {
+    pub fn new(escrow_address: Signer) -> Self {
+        Self { escrow_address }
+    }
+}
+
+impl Protobuf<RawQueryEscrowAddressResponse> for QueryEscrowAddressResponse {}
+
+impl From<RawQueryEscrowAddressResponse> for QueryEscrowAddressResponse {
+    fn from(value: RawQueryEscrowAddressResponse) -> Self {
+        Self {
+            escrow_address: value.escrow_address.into(),
+        }
+    }
+}
+
+impl From<QueryEscrowAddressResponse> for RawQueryEscrowAddressResponse {
+    fn from(response: QueryEscrowAddressResponse) -> Self {
+        Self {
+            escrow_address: response.escrow_address.to_string(),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the total amount of
+/// tokens of a denomination held in escrow.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryTotalEscrowForDenomResponse {
+    pub amount: PrefixedCoin,
+}
+
+impl QueryTotalEscrowForDenomResponse {
+    pub fn new(amount: PrefixedCoin) -> Self {
+        Self { amount }
+    }
+}
+
+impl Protobuf<RawQueryTotalEscrowForDenomResponse> for QueryTotalEscrowForDenomResponse {}
+
+impl TryFrom<RawQueryTotalEscrowForDenomResponse> for QueryTotalEscrowForDenomResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryTotalEscrowForDenomResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: value
+                .amount
+                .ok_or_else(|| QueryError::missing_field("amount"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<QueryTotalEscrowForDenomResponse> for RawQueryTotalEscrowForDenomResponse {
+    fn from(response: QueryTotalEscrowForDenomResponse) -> Self {
+        Self {
+            amount: Some(response.amount.into()),
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! Provides utility functions for querying IBC channel states.
 
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::host::types::error::HostError;
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath,
+    ClientStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::primitives::prelude::format;
@@ -11,7 +12,8 @@ use ibc_proto::google::protobuf::Any;
 
 use super::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
-    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelParamsRequest, QueryChannelParamsResponse, QueryChannelRequest,
     QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
     QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
     QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
@@ -21,6 +23,7 @@ use super::{
     QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
     QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryUpgradeErrorRequest, QueryUpgradeErrorResponse, QueryUpgradeRequest, QueryUpgradeResponse,
 };
 use crate::core::client::IdentifiedClientState;
 use crate::core::context::{ProvableContext, QueryContext};
@@ -91,7 +94,6 @@ where
                 .iter()
                 .any(|connection_hop| connection_hop == &request.connection_id)
         })
-        .map(Into::into)
         .collect();
 
     Ok(QueryConnectionChannelsResponse::new(
@@ -258,7 +260,6 @@ where
     let commitments = ibc_ctx
         .packet_commitments(&channel_end_path)?
         .into_iter()
-        .map(Into::into)
         .collect();
 
     Ok(QueryPacketCommitmentsResponse::new(
@@ -344,18 +345,13 @@ pub fn query_packet_acknowledgements<I>(
 where
     I: QueryContext,
 {
-    let commitment_sequences = request
-        .packet_commitment_sequences
-        .iter()
-        .copied()
-        .map(Into::into);
+    let commitment_sequences = request.packet_commitment_sequences.iter().copied();
 
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
     let acknowledgements = ibc_ctx
         .packet_acknowledgements(&channel_end_path, commitment_sequences)?
         .into_iter()
-        .map(Into::into)
         .collect();
 
     Ok(QueryPacketAcknowledgementsResponse::new(
@@ -373,11 +369,7 @@ pub fn query_unreceived_packets<I>(
 where
     I: QueryContext,
 {
-    let sequences = request
-        .packet_commitment_sequences
-        .iter()
-        .copied()
-        .map(Into::into);
+    let sequences = request.packet_commitment_sequences.iter().copied();
 
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
@@ -397,7 +389,7 @@ pub fn query_unreceived_acks<I>(
 where
     I: QueryContext,
 {
-    let sequences = request.packet_ack_sequences.iter().copied().map(Into::into);
+    let sequences = request.packet_ack_sequences.iter().copied();
 
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
@@ -475,3 +467,90 @@ where
         proof_height,
     ))
 }
+
+/// Queries for the upgrade error receipt of a channel
+pub fn query_upgrade_error<I>(
+    ibc_ctx: &I,
+    request: &QueryUpgradeErrorRequest,
+) -> Result<QueryUpgradeErrorResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let upgrade_error_path = ChannelUpgradeErrorPath::new(&request.port_id, &request.channel_id);
+
+    let error_receipt = ibc_ctx
+        .channel_upgrade_error(&upgrade_error_path)?
+        .ok_or_else(|| {
+            HostError::missing_state(format!(
+                "upgrade error receipt not found for channel {}",
+                request.channel_id
+            ))
+        })?;
+
+    let proof_height = match request.query_height {
+        Some(height) => height,
+        None => ibc_ctx.host_height()?,
+    };
+
+    let proof = ibc_ctx
+        .get_proof(proof_height, &Path::ChannelUpgradeError(upgrade_error_path))
+        .ok_or_else(|| {
+            QueryError::missing_proof(format!(
+                "Upgrade error receipt proof not found for channel {}",
+                request.channel_id
+            ))
+        })?;
+
+    Ok(QueryUpgradeErrorResponse::new(
+        error_receipt,
+        proof,
+        proof_height,
+    ))
+}
+
+/// Queries for the upgrade in progress on a channel
+pub fn query_upgrade<I>(
+    ibc_ctx: &I,
+    request: &QueryUpgradeRequest,
+) -> Result<QueryUpgradeResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let upgrade_path = ChannelUpgradePath::new(&request.port_id, &request.channel_id);
+
+    let upgrade = ibc_ctx.channel_upgrade(&upgrade_path)?.ok_or_else(|| {
+        HostError::missing_state(format!(
+            "upgrade not found for channel {}",
+            request.channel_id
+        ))
+    })?;
+
+    let proof_height = match request.query_height {
+        Some(height) => height,
+        None => ibc_ctx.host_height()?,
+    };
+
+    let proof = ibc_ctx
+        .get_proof(proof_height, &Path::ChannelUpgrade(upgrade_path))
+        .ok_or_else(|| {
+            QueryError::missing_proof(format!(
+                "Upgrade proof not found for channel {}",
+                request.channel_id
+            ))
+        })?;
+
+    Ok(QueryUpgradeResponse::new(upgrade, proof, proof_height))
+}
+
+/// Queries for the parameters of the channel module
+pub fn query_channel_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryChannelParamsRequest,
+) -> Result<QueryChannelParamsResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let upgrade_timeout = ibc_ctx.channel_upgrade_timeout()?;
+
+    Ok(QueryChannelParamsResponse::new(upgrade_timeout))
+}
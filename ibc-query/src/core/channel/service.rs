@@ -24,11 +24,11 @@ use ibc_proto::ibc::core::channel::v1::{
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_channel, query_channel_client_state, query_channel_consensus_state, query_channels,
-    query_connection_channels, query_next_sequence_receive, query_next_sequence_send,
-    query_packet_acknowledgement, query_packet_acknowledgements, query_packet_commitment,
-    query_packet_commitments, query_packet_receipt, query_unreceived_acks,
-    query_unreceived_packets,
+    query_channel, query_channel_client_state, query_channel_consensus_state, query_channel_params,
+    query_channels, query_connection_channels, query_next_sequence_receive,
+    query_next_sequence_send, query_packet_acknowledgement, query_packet_acknowledgements,
+    query_packet_commitment, query_packet_commitments, query_packet_receipt, query_unreceived_acks,
+    query_unreceived_packets, query_upgrade, query_upgrade_error,
 };
 use crate::core::context::QueryContext;
 use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
@@ -169,28 +169,22 @@ where
 
     async fn upgrade_error(
         &self,
-        _request: Request<QueryUpgradeErrorRequest>,
+        request: Request<QueryUpgradeErrorRequest>,
     ) -> Result<Response<QueryUpgradeErrorResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying UpgradeError is not supported yet",
-        ))
+        query_upgrade_error(&self.ibc_context, &request.try_into_domain()?)?.into_response()
     }
 
     async fn upgrade(
         &self,
-        _request: Request<QueryUpgradeRequest>,
+        request: Request<QueryUpgradeRequest>,
     ) -> Result<Response<QueryUpgradeResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying Upgrade is not supported yet",
-        ))
+        query_upgrade(&self.ibc_context, &request.try_into_domain()?)?.into_response()
     }
 
     async fn channel_params(
         &self,
-        _request: Request<QueryChannelParamsRequest>,
+        request: Request<QueryChannelParamsRequest>,
     ) -> Result<Response<QueryChannelParamsResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying ChannelParams is not supported yet",
-        ))
+        query_channel_params(&self.ibc_context, &request.into_domain())?.into_response()
     }
 }
@@ -7,6 +7,7 @@ use ibc::primitives::prelude::*;
 use ibc_proto::ibc::core::channel::v1::{
     QueryChannelClientStateRequest as RawQueryChannelClientStateRequest,
     QueryChannelConsensusStateRequest as RawQueryChannelConsensusStateRequest,
+    QueryChannelParamsRequest as RawQueryChannelParamsRequest,
     QueryChannelRequest as RawQueryChannelRequest, QueryChannelsRequest as RawQueryChannelsRequest,
     QueryConnectionChannelsRequest as RawQueryConnectionChannelsRequest,
     QueryNextSequenceReceiveRequest as RawQueryNextSequenceReceiveRequest,
@@ -18,6 +19,8 @@ use ibc_proto::ibc::core::channel::v1::{
     QueryPacketReceiptRequest as RawQueryPacketReceiptRequest,
     QueryUnreceivedAcksRequest as RawQueryUnreceivedAcksRequest,
     QueryUnreceivedPacketsRequest as RawQueryUnreceivedPacketsRequest,
+    QueryUpgradeErrorRequest as RawQueryUpgradeErrorRequest,
+    QueryUpgradeRequest as RawQueryUpgradeRequest,
 };
 
 use crate::error::QueryError;
@@ -408,3 +411,63 @@ impl TryFrom<RawQueryNextSequenceSendRequest> for QueryNextSequenceSendRequest {
         })
     }
 }
+
+/// Defines the RPC method request type for querying the upgrade error receipt
+/// of a channel
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryUpgradeErrorRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub query_height: Option<Height>,
+}
+
+impl TryFrom<RawQueryUpgradeErrorRequest> for QueryUpgradeErrorRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryUpgradeErrorRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+            query_height: None,
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying the upgrade in progress
+/// on a channel
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryUpgradeRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub query_height: Option<Height>,
+}
+
+impl TryFrom<RawQueryUpgradeRequest> for QueryUpgradeRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryUpgradeRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: request.port_id.parse()?,
+            channel_id: request.channel_id.parse()?,
+            query_height: None,
+        })
+    }
+}
+
+/// Defines the RPC method request type for querying the channel parameters
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelParamsRequest {
+    pub query_height: Option<Height>,
+}
+
+impl From<RawQueryChannelParamsRequest> for QueryChannelParamsRequest {
+    fn from(_request: RawQueryChannelParamsRequest) -> Self {
+        Self { query_height: None }
+    }
+}
@@ -4,14 +4,16 @@
 use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::PacketState;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeTimeout};
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ClientId, Sequence};
 use ibc::core::primitives::proto::Any;
 use ibc::primitives::prelude::*;
 use ibc::primitives::proto::Protobuf;
 use ibc_proto::ibc::core::channel::v1::{
-    QueryChannelClientStateResponse as RawQueryChannelClientStateResponse,
+    Params as RawParams, QueryChannelClientStateResponse as RawQueryChannelClientStateResponse,
     QueryChannelConsensusStateResponse as RawQueryChannelConsensusStateResponse,
+    QueryChannelParamsResponse as RawQueryChannelParamsResponse,
     QueryChannelResponse as RawQueryChannelResponse,
     QueryChannelsResponse as RawQueryChannelsResponse,
     QueryConnectionChannelsResponse as RawQueryConnectionChannelsResponse,
@@ -24,6 +26,8 @@ use ibc_proto::ibc::core::channel::v1::{
     QueryPacketReceiptResponse as RawQueryPacketReceiptResponse,
     QueryUnreceivedAcksResponse as RawQueryUnreceivedAcksResponse,
     QueryUnreceivedPacketsResponse as RawQueryUnreceivedPacketsResponse,
+    QueryUpgradeErrorResponse as RawQueryUpgradeErrorResponse,
+    QueryUpgradeResponse as RawQueryUpgradeResponse,
 };
 
 use crate::core::client::IdentifiedClientState;
@@ -736,3 +740,147 @@ impl From<QueryNextSequenceSendResponse> for RawQueryNextSequenceSendResponse {
         }
     }
 }
+
+/// Defines the RPC method response type when querying the upgrade error
+/// receipt of a channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryUpgradeErrorResponse {
+    pub error_receipt: ErrorReceipt,
+    pub proof: Proof,
+    pub proof_height: Height,
+}
+
+impl QueryUpgradeErrorResponse {
+    pub fn new(error_receipt: ErrorReceipt, proof: Proof, proof_height: Height) -> Self {
+        Self {
+            error_receipt,
+            proof,
+            proof_height,
+        }
+    }
+}
+
+impl Protobuf<RawQueryUpgradeErrorResponse> for QueryUpgradeErrorResponse {}
+
+impl TryFrom<RawQueryUpgradeErrorResponse> for QueryUpgradeErrorResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryUpgradeErrorResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            error_receipt: value
+                .error_receipt
+                .ok_or_else(|| QueryError::missing_field("error_receipt"))?
+                .try_into()?,
+            proof: value.proof,
+            proof_height: value
+                .proof_height
+                .ok_or_else(|| QueryError::missing_field("proof_height"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<QueryUpgradeErrorResponse> for RawQueryUpgradeErrorResponse {
+    fn from(response: QueryUpgradeErrorResponse) -> Self {
+        Self {
+            error_receipt: Some(response.error_receipt.into()),
+            proof: response.proof,
+            proof_height: Some(response.proof_height.into()),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the upgrade in progress
+/// on a channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryUpgradeResponse {
+    pub upgrade: Upgrade,
+    pub proof: Proof,
+    pub proof_height: Height,
+}
+
+impl QueryUpgradeResponse {
+    pub fn new(upgrade: Upgrade, proof: Proof, proof_height: Height) -> Self {
+        Self {
+            upgrade,
+            proof,
+            proof_height,
+        }
+    }
+}
+
+impl Protobuf<RawQueryUpgradeResponse> for QueryUpgradeResponse {}
+
+impl TryFrom<RawQueryUpgradeResponse> for QueryUpgradeResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryUpgradeResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            upgrade: value
+                .upgrade
+                .ok_or_else(|| QueryError::missing_field("upgrade"))?
+                .try_into()?,
+            proof: value.proof,
+            proof_height: value
+                .proof_height
+                .ok_or_else(|| QueryError::missing_field("proof_height"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<QueryUpgradeResponse> for RawQueryUpgradeResponse {
+    fn from(response: QueryUpgradeResponse) -> Self {
+        Self {
+            upgrade: Some(response.upgrade.into()),
+            proof: response.proof,
+            proof_height: Some(response.proof_height.into()),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the channel parameters.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelParamsResponse {
+    /// The relative timeout applied to channel upgrades.
+    pub upgrade_timeout: UpgradeTimeout,
+}
+
+impl QueryChannelParamsResponse {
+    pub fn new(upgrade_timeout: UpgradeTimeout) -> Self {
+        Self { upgrade_timeout }
+    }
+}
+
+impl Protobuf<RawQueryChannelParamsResponse> for QueryChannelParamsResponse {}
+
+impl TryFrom<RawQueryChannelParamsResponse> for QueryChannelParamsResponse {
+    type Error = QueryError;
+
+    fn try_from(value: RawQueryChannelParamsResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            upgrade_timeout: value
+                .params
+                .ok_or_else(|| QueryError::missing_field("params"))?
+                .upgrade_timeout
+                .ok_or_else(|| QueryError::missing_field("upgrade_timeout"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<QueryChannelParamsResponse> for RawQueryChannelParamsResponse {
+    fn from(response: QueryChannelParamsResponse) -> Self {
+        Self {
+            params: Some(RawParams {
+                upgrade_timeout: Some(response.upgrade_timeout.into()),
+            }),
+        }
+    }
+}
@@ -13,11 +13,12 @@ use ibc::primitives::prelude::{format, ToString};
 use ibc::primitives::proto::Any;
 
 use super::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientStateResponse,
-    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
-    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
-    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
-    QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
+    ConsensusStateWithHeight, IdentifiedClientState, QueryClientParamsRequest,
+    QueryClientParamsResponse, QueryClientStateResponse, QueryClientStatesRequest,
+    QueryClientStatesResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+    QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
     QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
     QueryUpgradedConsensusStateResponse,
 };
@@ -196,6 +197,19 @@ where
     Ok(QueryClientStatusResponse::new(client_status))
 }
 
+/// Queries for the parameters of the client module.
+pub fn query_client_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryClientParamsRequest,
+) -> Result<QueryClientParamsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let allowed_clients = ibc_ctx.allowed_clients()?;
+
+    Ok(QueryClientParamsResponse::new(allowed_clients))
+}
+
 /// Queries for the upgraded client state.
 pub fn query_upgraded_client_state<I, U>(
     ibc_ctx: &I,
@@ -2,7 +2,7 @@
 //! and from the corresponding gRPC proto types for the client module.
 
 use ibc::core::client::types::{Height, Status};
-use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::identifiers::{ClientId, ClientType};
 use ibc::core::primitives::proto::Any;
 use ibc::primitives::prelude::*;
 use ibc::primitives::proto::Protobuf;
@@ -390,11 +390,11 @@ impl From<QueryClientStatusResponse> for RawQueryClientStatusResponse {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct QueryClientParamsResponse {
-    pub allowed_clients: Vec<ClientId>,
+    pub allowed_clients: Vec<ClientType>,
 }
 
 impl QueryClientParamsResponse {
-    pub fn new(allowed_clients: Vec<ClientId>) -> Self {
+    pub fn new(allowed_clients: Vec<ClientType>) -> Self {
         Self { allowed_clients }
     }
 }
@@ -411,7 +411,7 @@ impl TryFrom<RawQueryClientParamsResponse> for QueryClientParamsResponse {
                 .ok_or_else(|| QueryError::missing_field("params"))?
                 .allowed_clients
                 .into_iter()
-                .map(|id| id.parse())
+                .map(|client_type| client_type.parse())
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -424,7 +424,7 @@ impl From<QueryClientParamsResponse> for RawQueryClientParamsResponse {
                 allowed_clients: response
                     .allowed_clients
                     .into_iter()
-                    .map(|id| id.to_string())
+                    .map(|client_type| client_type.to_string())
                     .collect(),
             }),
         }
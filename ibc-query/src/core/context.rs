@@ -5,7 +5,7 @@ use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::IdentifiedConnectionEnd;
 use ibc::core::host::types::error::HostError;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ClientId, ClientType, ConnectionId, Sequence};
 use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
 use ibc::core::primitives::prelude::*;
@@ -33,6 +33,9 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all heights at which consensus states for the given client are.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, HostError>;
 
+    /// Returns the list of client types that are allowed to be created on the host.
+    fn allowed_clients(&self) -> Result<Vec<ClientType>, HostError>;
+
     // Connection queries
 
     /// Returns the list of all connection ends.
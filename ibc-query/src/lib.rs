@@ -9,6 +9,11 @@
 //! - [`ValidationContext`](ibc::core::host::ValidationContext)
 //! - [`ProvableContext`](crate::core::context::ProvableContext)
 //! - [`QueryContext`](crate::core::context::QueryContext)
+//! - [`TransferQueryContext`](crate::apps::transfer::TransferQueryContext)
+//!   - Only for [`TransferQueryService`](crate::apps::transfer::TransferQueryService)
+//! - [`NftTransferQueryContext`](crate::apps::nft_transfer::NftTransferQueryContext)
+//!   - Only for [`NftTransferQueryService`](crate::apps::nft_transfer::NftTransferQueryService),
+//!     with the `std` feature
 //! - [`UpgradeValidationContext`](ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext)
 //!   - Only for
 //!     [`ClientQuery::upgraded_client_state`](ibc_proto::ibc::core::client::v1::query_server::Query::upgraded_client_state)
@@ -69,6 +74,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod apps;
 pub mod core;
 pub mod error;
 pub mod types;
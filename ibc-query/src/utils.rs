@@ -3,6 +3,8 @@ use tonic::{Request, Response, Status};
 use crate::error::QueryError;
 
 pub trait TryIntoDomain<T> {
+    // `Status` is what the gRPC services return, so it cannot be boxed here.
+    #[allow(clippy::result_large_err)]
     fn try_into_domain(self) -> Result<T, Status>;
 }
 
@@ -32,6 +34,7 @@ pub trait IntoResponse<Raw>: Sized
 where
     Self: Into<Raw>,
 {
+    #[allow(clippy::result_large_err)]
     fn into_response(self) -> Result<Response<Raw>, Status> {
         Ok(Response::new(self.into()))
     }
@@ -3,11 +3,14 @@ use core::time::Duration;
 
 use basecoin_store::context::ProvableStore;
 use basecoin_store::impls::InMemoryStore;
+use ibc::clients::localhost::types::ClientState as LocalhostClientState;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::PacketCommitment;
-use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::channel::types::upgrade::Upgrade;
+use ibc::core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::Height;
+use ibc::core::connection::localhost::store_localhost_connection;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{dispatch, execute, validate};
 use ibc::core::handler::types::error::HandlerError;
@@ -15,11 +18,12 @@ use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
+    ConnectionPath, CounterpartyChannelUpgradePath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::primitives::prelude::*;
+use ibc::primitives::proto::Any;
 use ibc::primitives::Timestamp;
 
 use super::testapp::ibc::core::types::{LightClientState, MockIbcStore};
@@ -194,6 +198,16 @@ where
             consensus_state,
             ibc_commitment_proof,
         );
+
+        // Refresh the localhost client, if any, to the new host height.
+        let localhost_client_id = ClientId::localhost();
+        if let Ok(client_state @ AnyClientState::Localhost(_)) =
+            self.ibc_store.client_state(&localhost_client_id)
+        {
+            client_state
+                .update_state(&mut self.ibc_store, &localhost_client_id, Any::default())
+                .expect("no error");
+        }
     }
 
     /// End the current block on the context.
@@ -365,6 +379,18 @@ where
         self
     }
 
+    /// Bootstraps the localhost client at the current height of the host,
+    /// along with the sentinel localhost connection, through which this
+    /// context connects to itself.
+    pub fn with_localhost_client(mut self) -> Self {
+        let client_state = LocalhostClientState::new(self.latest_height());
+        self = self.with_client_state(&ClientId::localhost(), client_state.into());
+
+        store_localhost_connection(&mut self.ibc_store).expect("error writing to store");
+
+        self
+    }
+
     /// Bootstraps an IBC connection to this context.
     ///
     /// This does not bootstrap any light client.
@@ -461,6 +487,38 @@ where
         self
     }
 
+    /// Bootstraps a channel upgrade proposed on this end to this context.
+    ///
+    /// This does not bootstrap any corresponding IBC channel, connection or light client.
+    pub fn with_channel_upgrade(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        let upgrade_path = ChannelUpgradePath::new(&port_id, &chan_id);
+        self.ibc_store
+            .store_channel_upgrade(&upgrade_path, upgrade)
+            .expect("error writing to store");
+        self
+    }
+
+    /// Bootstraps a channel upgrade proposed by the counterparty to this context.
+    ///
+    /// This does not bootstrap any corresponding IBC channel, connection or light client.
+    pub fn with_counterparty_channel_upgrade(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        let upgrade_path = CounterpartyChannelUpgradePath::new(&port_id, &chan_id);
+        self.ibc_store
+            .store_counterparty_channel_upgrade(&upgrade_path, upgrade)
+            .expect("error writing to store");
+        self
+    }
+
     /// Calls [`validate`] function on [`MsgEnvelope`] using the context's IBC store and router.
     pub fn validate(&mut self, msg: MsgEnvelope) -> Result<(), HandlerError> {
         validate(&self.ibc_store, &self.ibc_router, msg)
@@ -1,22 +1,28 @@
 use bon::builder;
+use ibc::apps::transfer::handler::encode_packet_data;
 use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
-use ibc::apps::transfer::types::packet::PacketData;
-use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::apps::transfer::types::packet::{PacketData, PacketDataV2};
+use ibc::apps::transfer::types::{Forwarding, Memo, PrefixedCoin, VERSION};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
-use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 
+use crate::context::MockContext;
 use crate::fixtures::core::signer::dummy_account_id;
 
 /// Returns a dummy [`MsgTransfer`], for testing purposes only!
 #[builder]
 pub fn dummy_msg_transfer(
-    #[builder(start_fn)] packet_data: PacketData,
+    #[builder(start_fn, into)] packet_data: PacketDataV2,
     #[builder(default = PortId::transfer())] port_id_on_a: PortId,
     #[builder(default = ChannelId::zero())] chan_id_on_a: ChannelId,
     #[builder(default = TimeoutHeight::Never)] timeout_height_on_b: TimeoutHeight,
     #[builder(default = TimeoutTimestamp::Never)] timeout_timestamp_on_b: TimeoutTimestamp,
+    #[builder(default)] forwarding: Forwarding,
 ) -> MsgTransfer {
     MsgTransfer {
         port_id_on_a,
@@ -24,12 +30,33 @@ pub fn dummy_msg_transfer(
         packet_data,
         timeout_height_on_b,
         timeout_timestamp_on_b,
+        forwarding,
     }
 }
 
+/// Bootstraps an open `channel-0` on the transfer port over the localhost
+/// connection, with `channel-1` as its counterparty and the given version.
+pub fn dummy_transfer_channel_ctx(version: &str) -> MockContext {
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![ConnectionId::localhost()],
+        Version::new(version.to_string()),
+    )
+    .expect("valid channel end");
+
+    MockContext::default()
+        .with_localhost_client()
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), Sequence::from(1))
+}
+
+/// Returns the packet sent by the given [`MsgTransfer`] over an ICS-20 v1
+/// channel.
 pub fn extract_transfer_packet(msg: &MsgTransfer, sequence: Sequence) -> Packet {
-    let data = serde_json::to_vec(&msg.packet_data)
-        .expect("PacketData's infallible Serialize impl failed");
+    let data = encode_packet_data(&Version::new(VERSION.to_string()), msg.packet_data.clone())
+        .expect("ICS-20 v1 packet data carries a single token");
 
     Packet {
         seq_on_a: sequence,
@@ -1,7 +1,24 @@
+use ibc::core::client::types::msgs::MsgCreateClient;
 use ibc::core::client::types::Height;
+use ibc::core::primitives::Timestamp;
 
+use crate::fixtures::core::signer::dummy_account_id;
+use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::mock::header::MockHeader;
 /// Returns a dummy `MockHeader` with the given revision height.
 pub fn dummy_new_mock_header(revision_height: u64) -> MockHeader {
     MockHeader::new(Height::new(0, revision_height).expect("Never fails"))
 }
+
+/// Returns a dummy `MsgCreateClient` creating a mock client, whose latest
+/// consensus state has the given timestamp.
+pub fn dummy_msg_create_mock_client(timestamp: Timestamp) -> MsgCreateClient {
+    let header = dummy_new_mock_header(42).with_timestamp(timestamp);
+
+    MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    )
+}
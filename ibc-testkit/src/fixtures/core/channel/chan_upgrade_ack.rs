@@ -0,0 +1,112 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeAck as RawMsgChannelUpgradeAck;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeAck`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_ack(
+    timeout_timestamp: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeAck {
+    RawMsgChannelUpgradeAck {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_upgrade: Some(dummy_raw_upgrade(timeout_timestamp, 1)),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeAck;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_ack_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeAck,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_ack(u64::MAX, 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Bad port, name too short".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    port_id: "p".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing counterparty upgrade".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    counterparty_upgrade: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof of channel".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    proof_channel: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad proof height, height = 0".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    proof_height: Some(Height {
+                        revision_number: 0,
+                        revision_height: 0,
+                    }),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeAck::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeAck::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_ack(u64::MAX, 10);
+        let msg = MsgChannelUpgradeAck::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeAck::from(msg.clone());
+        let msg_back = MsgChannelUpgradeAck::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
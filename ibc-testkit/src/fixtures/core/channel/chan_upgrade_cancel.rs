@@ -0,0 +1,107 @@
+use ibc::core::channel::types::proto::v1::{
+    ErrorReceipt as RawErrorReceipt, MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeCancel` with a proof of the given
+/// error receipt, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_cancel(
+    error_receipt_sequence: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeCancel {
+    RawMsgChannelUpgradeCancel {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        error_receipt: Some(RawErrorReceipt {
+            sequence: error_receipt_sequence,
+            message: "upgrade aborted".to_string(),
+        }),
+        proof_error_receipt: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeCancel;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_cancel_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeCancel,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_cancel(1, 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "No proof, as sent by the upgrade authority".to_string(),
+                raw: RawMsgChannelUpgradeCancel {
+                    proof_error_receipt: Vec::new(),
+                    proof_height: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Proof without a proof height".to_string(),
+                raw: RawMsgChannelUpgradeCancel {
+                    proof_height: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing error receipt".to_string(),
+                raw: RawMsgChannelUpgradeCancel {
+                    error_receipt: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeCancel::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeCancel::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_cancel(1, 10);
+        let msg = MsgChannelUpgradeCancel::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeCancel::from(msg.clone());
+        let msg_back = MsgChannelUpgradeCancel::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
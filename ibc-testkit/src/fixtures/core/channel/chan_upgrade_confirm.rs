@@ -0,0 +1,111 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeConfirm`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_confirm(
+    counterparty_channel_state: i32,
+    timeout_timestamp: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeConfirm {
+    RawMsgChannelUpgradeConfirm {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_channel_state,
+        counterparty_upgrade: Some(dummy_raw_upgrade(timeout_timestamp, 1)),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeConfirm;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_confirm_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeConfirm,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_confirm(5, u64::MAX, 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Counterparty done flushing".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    counterparty_channel_state: 6,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Counterparty not flushing".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    counterparty_channel_state: 3,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing counterparty upgrade".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    counterparty_upgrade: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgChannelUpgradeConfirm {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeConfirm::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeConfirm::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_confirm(5, u64::MAX, 10);
+        let msg = MsgChannelUpgradeConfirm::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeConfirm::from(msg.clone());
+        let msg_back = MsgChannelUpgradeConfirm::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
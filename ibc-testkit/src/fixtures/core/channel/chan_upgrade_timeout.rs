@@ -0,0 +1,104 @@
+use ibc::core::channel::types::proto::v1::{
+    Channel as RawChannel, MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeTimeout`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_timeout(
+    counterparty_channel: RawChannel,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeTimeout {
+    RawMsgChannelUpgradeTimeout {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_channel: Some(counterparty_channel),
+        proof_channel: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeTimeout;
+
+    use super::*;
+    use crate::fixtures::core::channel::dummy_raw_channel_end;
+
+    #[test]
+    fn parse_channel_upgrade_timeout_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeTimeout,
+            want_pass: bool,
+        }
+
+        let default_raw_msg =
+            dummy_raw_msg_chan_upgrade_timeout(dummy_raw_channel_end(3, Some(0)), 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Missing counterparty channel".to_string(),
+                raw: RawMsgChannelUpgradeTimeout {
+                    counterparty_channel: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad counterparty channel state".to_string(),
+                raw: RawMsgChannelUpgradeTimeout {
+                    counterparty_channel: Some(dummy_raw_channel_end(-1, Some(0))),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgChannelUpgradeTimeout {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeTimeout::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeTimeout::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_timeout(dummy_raw_channel_end(3, Some(0)), 10);
+        let msg = MsgChannelUpgradeTimeout::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeTimeout::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTimeout::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
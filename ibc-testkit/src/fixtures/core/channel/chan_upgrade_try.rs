@@ -0,0 +1,127 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade_fields};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeTry`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_try(
+    counterparty_upgrade_sequence: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeTry {
+    RawMsgChannelUpgradeTry {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        proposed_upgrade_connection_hops: vec![ConnectionId::zero().to_string()],
+        counterparty_upgrade_fields: Some(dummy_raw_upgrade_fields()),
+        counterparty_upgrade_sequence,
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeTry;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_try_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeTry,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_try(1, 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Bad channel, name too short".to_string(),
+                raw: RawMsgChannelUpgradeTry {
+                    channel_id: "chshort".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad proposed connection hops".to_string(),
+                raw: RawMsgChannelUpgradeTry {
+                    proposed_upgrade_connection_hops: vec!["con nection".to_string()],
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing counterparty upgrade fields".to_string(),
+                raw: RawMsgChannelUpgradeTry {
+                    counterparty_upgrade_fields: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Zero counterparty upgrade sequence".to_string(),
+                raw: RawMsgChannelUpgradeTry {
+                    counterparty_upgrade_sequence: 0,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof of upgrade".to_string(),
+                raw: RawMsgChannelUpgradeTry {
+                    proof_upgrade: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgChannelUpgradeTry {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeTry::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeTry::try_from raw failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_try(1, 10);
+        let msg = MsgChannelUpgradeTry::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeTry::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTry::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
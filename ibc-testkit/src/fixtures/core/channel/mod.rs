@@ -5,14 +5,23 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
 mod packet;
 mod recv_packet;
 mod timeout;
 mod timeout_on_close;
 
 use ibc::core::channel::types::proto::v1::{
-    Channel as RawChannel, Counterparty as RawCounterparty,
+    Channel as RawChannel, Counterparty as RawCounterparty, Timeout as RawTimeout,
+    Upgrade as RawUpgrade, UpgradeFields as RawUpgradeFields,
 };
+use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::primitives::prelude::*;
 
@@ -23,6 +32,13 @@ pub use self::chan_open_ack::*;
 pub use self::chan_open_confirm::*;
 pub use self::chan_open_init::*;
 pub use self::chan_open_try::*;
+pub use self::chan_upgrade_ack::*;
+pub use self::chan_upgrade_cancel::*;
+pub use self::chan_upgrade_confirm::*;
+pub use self::chan_upgrade_init::*;
+pub use self::chan_upgrade_open::*;
+pub use self::chan_upgrade_timeout::*;
+pub use self::chan_upgrade_try::*;
 pub use self::packet::*;
 pub use self::recv_packet::*;
 pub use self::timeout::*;
@@ -53,6 +69,31 @@ pub fn dummy_raw_channel_end(state: i32, channel_id: Option<u64>) -> RawChannel
     }
 }
 
+/// Returns dummy `RawUpgradeFields` proposing to upgrade an ICS-20 channel
+/// to version 2, for testing purposes only!
+pub fn dummy_raw_upgrade_fields() -> RawUpgradeFields {
+    RawUpgradeFields {
+        ordering: 1,
+        connection_hops: vec![ConnectionId::zero().to_string()],
+        version: "ics20-2".to_string(),
+    }
+}
+
+/// Returns a dummy `RawUpgrade`, for testing purposes only!
+pub fn dummy_raw_upgrade(timeout_timestamp: u64, next_sequence_send: u64) -> RawUpgrade {
+    RawUpgrade {
+        fields: Some(dummy_raw_upgrade_fields()),
+        timeout: Some(RawTimeout {
+            height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 0,
+            }),
+            timestamp: timeout_timestamp,
+        }),
+        next_sequence_send,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::str::FromStr;
@@ -174,6 +215,11 @@ mod tests {
                 want_res: Order::Ordered,
                 want_err: false,
             },
+            Test {
+                ordering: "ORDERED_ALLOW_TIMEOUT",
+                want_res: Order::OrderedAllowTimeout,
+                want_err: false,
+            },
             Test {
                 ordering: "UNKNOWN_ORDER",
                 want_res: Order::None,
@@ -497,17 +497,19 @@ where
         let msg = MsgTransfer {
             port_id_on_a: PortId::transfer(),
             chan_id_on_a: chan_id_on_a.clone(),
-            packet_data,
+            packet_data: packet_data.into(),
             // setting timeout height to 10 blocks from B's current height.
             timeout_height_on_b: self.get_ctx_b().latest_height().add(10).into(),
             // not setting timeout timestamp.
             timeout_timestamp_on_b: TimeoutTimestamp::Never,
+            forwarding: Default::default(),
         };
 
         // module creates the send_packet
         send_transfer(
             self.get_ctx_a_mut().ibc_store_mut(),
-            &mut DummyTransferModule,
+            &mut DummyTransferModule::new(),
+            &mut DummyTransferModule::new(),
             msg,
         )
         .expect("successfully created send_packet");
@@ -0,0 +1,170 @@
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::types::{add_coins, Fee, IdentifiedPacketFees, PacketFee};
+use ibc::apps::transfer::types::BaseCoin;
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use super::types::DummyFeeContext;
+
+impl FeeValidationContext for DummyFeeContext {
+    type AccountId = Signer;
+
+    fn fee_account(&self, signer: &Signer) -> Result<Self::AccountId, HostError> {
+        if signer.as_ref().is_empty() {
+            return Err(HostError::invalid_state("empty fee account"));
+        }
+
+        Ok(signer.clone())
+    }
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<bool, HostError> {
+        Ok(self
+            .fee_enabled
+            .contains_key(&(port_id.clone(), channel_id.clone())))
+    }
+
+    fn payee(&self, channel_id: &ChannelId, relayer: &Signer) -> Result<Option<Signer>, HostError> {
+        Ok(self
+            .payees
+            .get(&(channel_id.clone(), relayer.clone()))
+            .cloned())
+    }
+
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, HostError> {
+        Ok(self
+            .counterparty_payees
+            .get(&(channel_id.clone(), relayer.clone()))
+            .cloned())
+    }
+
+    fn fees_in_escrow(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, HostError> {
+        Ok(self
+            .fees_in_escrow
+            .get(packet_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn fees_in_escrow_on_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, HostError> {
+        Ok(self
+            .fees_in_escrow
+            .iter()
+            .filter(|(packet_id, _)| {
+                &packet_id.port_id == port_id && &packet_id.chan_id == channel_id
+            })
+            .map(|(packet_id, packet_fees)| IdentifiedPacketFees {
+                packet_id: packet_id.clone(),
+                packet_fees: packet_fees.clone(),
+            })
+            .collect())
+    }
+
+    fn relayer_address_for_async_ack(
+        &self,
+        packet_id: &PacketId,
+    ) -> Result<Option<Signer>, HostError> {
+        Ok(self.async_ack_relayers.get(packet_id).cloned())
+    }
+
+    fn escrow_fee_validate(&self, _payer: &Self::AccountId, _fee: &Fee) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+impl FeeExecutionContext for DummyFeeContext {
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), HostError> {
+        self.fee_enabled
+            .insert((port_id.clone(), channel_id.clone()), ());
+        Ok(())
+    }
+
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), HostError> {
+        self.fee_enabled
+            .remove(&(port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn store_payee(
+        &mut self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        payee: &Signer,
+    ) -> Result<(), HostError> {
+        self.payees
+            .insert((channel_id.clone(), relayer.clone()), payee.clone());
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+        counterparty_payee: &Signer,
+    ) -> Result<(), HostError> {
+        self.counterparty_payees.insert(
+            (channel_id.clone(), relayer.clone()),
+            counterparty_payee.clone(),
+        );
+        Ok(())
+    }
+
+    fn store_fees_in_escrow(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), HostError> {
+        self.fees_in_escrow.insert(packet_id.clone(), packet_fees);
+        Ok(())
+    }
+
+    fn delete_fees_in_escrow(&mut self, packet_id: &PacketId) -> Result<(), HostError> {
+        self.fees_in_escrow.remove(packet_id);
+        Ok(())
+    }
+
+    fn store_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+        relayer: &Signer,
+    ) -> Result<(), HostError> {
+        self.async_ack_relayers
+            .insert(packet_id.clone(), relayer.clone());
+        Ok(())
+    }
+
+    fn delete_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+    ) -> Result<(), HostError> {
+        self.async_ack_relayers.remove(packet_id);
+        Ok(())
+    }
+
+    fn escrow_fee_execute(&mut self, _payer: &Self::AccountId, fee: &Fee) -> Result<(), HostError> {
+        let total = fee
+            .total()
+            .map_err(|e| HostError::invalid_state(e.to_string()))?;
+
+        add_coins(&mut self.escrowed, &total).map_err(|e| HostError::invalid_state(e.to_string()))
+    }
+
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[BaseCoin],
+    ) -> Result<(), HostError> {
+        add_coins(self.distributed.entry(receiver.clone()).or_default(), coins)
+            .map_err(|e| HostError::invalid_state(e.to_string()))
+    }
+}
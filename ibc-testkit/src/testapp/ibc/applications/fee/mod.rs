@@ -0,0 +1,3 @@
+#[cfg(feature = "serde")]
+pub mod context;
+pub mod types;
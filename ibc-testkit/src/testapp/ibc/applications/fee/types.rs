@@ -0,0 +1,35 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::fee::types::PacketFee;
+use ibc::apps::transfer::types::BaseCoin;
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// An in-memory fee middleware context, which records the fees escrowed and
+/// the fees paid out to each account.
+#[derive(Debug, Default)]
+pub struct DummyFeeContext {
+    pub fee_enabled: BTreeMap<(PortId, ChannelId), ()>,
+    pub payees: BTreeMap<(ChannelId, Signer), Signer>,
+    pub counterparty_payees: BTreeMap<(ChannelId, Signer), Signer>,
+    pub fees_in_escrow: BTreeMap<PacketId, Vec<PacketFee>>,
+    pub async_ack_relayers: BTreeMap<PacketId, Signer>,
+    /// The total of all the fees escrowed so far.
+    pub escrowed: Vec<BaseCoin>,
+    /// The coins paid out of escrow to each account.
+    pub distributed: BTreeMap<Signer, Vec<BaseCoin>>,
+}
+
+impl DummyFeeContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables fees on the given channel.
+    pub fn with_fee_enabled(mut self, port_id: PortId, channel_id: ChannelId) -> Self {
+        self.fee_enabled.insert((port_id, channel_id), ());
+        self
+    }
+}
@@ -0,0 +1,170 @@
+use ibc::apps::interchain_accounts::controller::context::{
+    IcaControllerExecutionContext, IcaControllerValidationContext,
+};
+use ibc::apps::interchain_accounts::host::context::{
+    IcaHostExecutionContext, IcaHostValidationContext,
+};
+use ibc::apps::interchain_accounts::types::{ControllerParams, HostParams};
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::Signer;
+
+use super::types::{DummyIcaControllerModule, DummyIcaHostModule};
+
+impl IcaControllerValidationContext for DummyIcaControllerModule {
+    fn controller_params(&self) -> Result<ControllerParams, HostError> {
+        Ok(self.params.clone())
+    }
+
+    fn active_channel(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Option<ChannelId>, HostError> {
+        Ok(self
+            .active_channels
+            .get(&(connection_id.clone(), port_id.clone()))
+            .cloned())
+    }
+
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, HostError> {
+        self.channel_connections
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .ok_or_else(|| {
+                HostError::missing_state(format!(
+                    "missing connection for channel {channel_id} on port {port_id}"
+                ))
+            })
+    }
+
+    fn interchain_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Option<String>, HostError> {
+        Ok(self
+            .addresses
+            .get(&(connection_id.clone(), port_id.clone()))
+            .cloned())
+    }
+}
+
+impl IcaControllerExecutionContext for DummyIcaControllerModule {
+    fn store_active_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), HostError> {
+        self.active_channels
+            .insert((connection_id.clone(), port_id.clone()), channel_id.clone());
+        Ok(())
+    }
+
+    fn store_interchain_account_address(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        address: String,
+    ) -> Result<(), HostError> {
+        self.addresses
+            .insert((connection_id.clone(), port_id.clone()), address);
+        Ok(())
+    }
+}
+
+impl IcaHostValidationContext for DummyIcaHostModule {
+    fn host_params(&self) -> Result<HostParams, HostError> {
+        Ok(self.params.clone())
+    }
+
+    fn interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<Signer>, HostError> {
+        Ok(self
+            .accounts
+            .get(&(connection_id.clone(), controller_port_id.clone()))
+            .cloned())
+    }
+
+    fn active_channel(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<ChannelId>, HostError> {
+        Ok(self
+            .active_channels
+            .get(&(connection_id.clone(), controller_port_id.clone()))
+            .cloned())
+    }
+
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, HostError> {
+        self.channel_connections
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .ok_or_else(|| {
+                HostError::missing_state(format!(
+                    "missing connection for channel {channel_id} on port {port_id}"
+                ))
+            })
+    }
+}
+
+impl IcaHostExecutionContext for DummyIcaHostModule {
+    fn new_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Signer, HostError> {
+        let account = Signer::from(format!("ica{}", self.accounts.len()));
+
+        self.accounts.insert(
+            (connection_id.clone(), controller_port_id.clone()),
+            account.clone(),
+        );
+
+        Ok(account)
+    }
+
+    fn store_active_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), HostError> {
+        self.active_channels.insert(
+            (connection_id.clone(), controller_port_id.clone()),
+            channel_id.clone(),
+        );
+        Ok(())
+    }
+
+    /// Executes nothing, but responds to each message with an empty response
+    /// of the matching type.
+    fn execute_tx(&mut self, account: &Signer, messages: Vec<Any>) -> Result<Vec<Any>, HostError> {
+        let responses = messages
+            .iter()
+            .map(|msg| Any {
+                type_url: format!("{}Response", msg.type_url),
+                value: Vec::new(),
+            })
+            .collect();
+
+        self.executed.push((account.clone(), messages));
+
+        Ok(responses)
+    }
+}
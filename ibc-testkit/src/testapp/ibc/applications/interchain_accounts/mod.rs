@@ -0,0 +1,5 @@
+#[cfg(feature = "serde")]
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod module;
+pub mod types;
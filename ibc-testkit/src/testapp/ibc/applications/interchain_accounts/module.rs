@@ -0,0 +1,487 @@
+use ibc::apps::interchain_accounts::{controller, host};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::ModuleExtras;
+
+use super::types::{DummyIcaControllerModule, DummyIcaHostModule};
+
+impl Module for DummyIcaControllerModule {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        controller::module::on_chan_open_init_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?;
+
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.channel_connections.insert(
+            (port_id.clone(), channel_id.clone()),
+            connection_hops[0].clone(),
+        );
+
+        Ok(controller::module::on_chan_open_init_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?)
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        controller::module::on_chan_open_try_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?;
+
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(controller::module::on_chan_open_try_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(controller::module::on_chan_open_ack_validate(
+            self,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(controller::module::on_chan_open_ack_execute(
+            self,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(controller::module::on_chan_open_confirm_validate(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(controller::module::on_chan_open_confirm_execute(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(controller::module::on_chan_close_init_validate(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(controller::module::on_chan_close_init_execute(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(controller::module::on_chan_close_confirm_validate(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(controller::module::on_chan_close_confirm_execute(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = controller::module::on_recv_packet_execute(self, packet);
+
+        (extras, Some(ack))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(controller::module::on_acknowledgement_packet_validate(
+            self,
+            packet,
+            acknowledgement,
+            relayer,
+        )?)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let (extras, result) = controller::module::on_acknowledgement_packet_execute(
+            self,
+            packet,
+            acknowledgement,
+            relayer,
+        );
+
+        (extras, result.map_err(Into::into))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(controller::module::on_timeout_packet_validate(
+            self, packet, relayer,
+        )?)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let (extras, result) = controller::module::on_timeout_packet_execute(self, packet, relayer);
+
+        (extras, result.map_err(Into::into))
+    }
+}
+
+impl Module for DummyIcaHostModule {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        host::module::on_chan_open_init_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?;
+
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(host::module::on_chan_open_init_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?)
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        host::module::on_chan_open_try_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?;
+
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.channel_connections.insert(
+            (port_id.clone(), channel_id.clone()),
+            connection_hops[0].clone(),
+        );
+
+        Ok(host::module::on_chan_open_try_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(host::module::on_chan_open_ack_validate(
+            self,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(host::module::on_chan_open_ack_execute(
+            self,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(host::module::on_chan_open_confirm_validate(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(host::module::on_chan_open_confirm_execute(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(host::module::on_chan_close_init_validate(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(host::module::on_chan_close_init_execute(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(host::module::on_chan_close_confirm_validate(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(host::module::on_chan_close_confirm_execute(
+            self, port_id, channel_id,
+        )?)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = host::module::on_recv_packet_execute(self, packet);
+
+        (extras, Some(ack))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(host::module::on_acknowledgement_packet_validate(
+            self,
+            packet,
+            acknowledgement,
+            relayer,
+        )?)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let (extras, result) =
+            host::module::on_acknowledgement_packet_execute(self, packet, acknowledgement, relayer);
+
+        (extras, result.map_err(Into::into))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(host::module::on_timeout_packet_validate(
+            self, packet, relayer,
+        )?)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        let (extras, result) = host::module::on_timeout_packet_execute(self, packet, relayer);
+
+        (extras, result.map_err(Into::into))
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::interchain_accounts::types::{ControllerParams, HostParams};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::Signer;
+
+/// An in-memory interchain accounts controller module, which records the
+/// accounts registered by each owner.
+#[derive(Debug, Default)]
+pub struct DummyIcaControllerModule {
+    pub params: ControllerParams,
+    pub active_channels: BTreeMap<(ConnectionId, PortId), ChannelId>,
+    pub addresses: BTreeMap<(ConnectionId, PortId), String>,
+    /// The connection of each channel opened by the module.
+    pub channel_connections: BTreeMap<(PortId, ChannelId), ConnectionId>,
+}
+
+impl DummyIcaControllerModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// An in-memory interchain accounts host module, which records the accounts
+/// it created and the messages they executed.
+#[derive(Debug, Default)]
+pub struct DummyIcaHostModule {
+    pub params: HostParams,
+    pub accounts: BTreeMap<(ConnectionId, PortId), Signer>,
+    pub active_channels: BTreeMap<(ConnectionId, PortId), ChannelId>,
+    /// The connection of each channel opened by the module.
+    pub channel_connections: BTreeMap<(PortId, ChannelId), ConnectionId>,
+    /// The messages executed by each account, in order.
+    pub executed: Vec<(Signer, Vec<Any>)>,
+}
+
+impl DummyIcaHostModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the messages the host allows interchain accounts to execute.
+    pub fn with_allow_messages(mut self, allow_messages: Vec<String>) -> Self {
+        self.params.allow_messages = allow_messages;
+        self
+    }
+}
@@ -1,2 +1,7 @@
+pub mod fee;
+pub mod interchain_accounts;
 pub mod nft_transfer;
+pub mod packet_forward;
+pub mod rate_limit;
 pub mod transfer;
+pub mod transfer_hooks;
@@ -1,5 +1,6 @@
 use ibc::apps::nft_transfer::context::{
-    NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
+    ClassTraceContext, NftClassContext, NftContext, NftTransferExecutionContext,
+    NftTransferValidationContext,
 };
 use ibc::apps::nft_transfer::types::{
     ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
@@ -8,6 +9,9 @@ use ibc::core::host::types::error::HostError;
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
+use ibc::cosmos_host::utils::{cosmos_adr028_escrow_address, cosmos_class_hash};
+use ibc_query::apps::nft_transfer::NftTransferQueryContext;
+use subtle_encoding::bech32;
 
 use super::types::{DummyNft, DummyNftClass, DummyNftTransferModule};
 
@@ -121,6 +125,10 @@ impl NftTransferValidationContext for DummyNftTransferModule {
         Ok(())
     }
 
+    fn class_hash_string(&self, class_id: &PrefixedClassId) -> Option<String> {
+        Some(cosmos_class_hash(&class_id.to_string()))
+    }
+
     fn get_nft(
         &self,
         _class_id: &PrefixedClassId,
@@ -147,44 +155,92 @@ impl NftTransferExecutionContext for DummyNftTransferModule {
     fn escrow_nft_execute(
         &mut self,
         _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), HostError> {
+        let escrow_address = self.escrow_address(port_id, channel_id)?;
+        self.nft_owners
+            .insert((class_id.clone(), token_id.clone()), escrow_address);
         Ok(())
     }
 
     fn unescrow_nft_execute(
         &mut self,
-        _to_account: &Self::AccountId,
+        to_account: &Self::AccountId,
         _port_id: &PortId,
         _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
     ) -> Result<(), HostError> {
+        self.nft_owners
+            .insert((class_id.clone(), token_id.clone()), to_account.clone());
         Ok(())
     }
 
     fn mint_nft_execute(
         &mut self,
-        _account: &Self::AccountId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _token_uri: Option<&TokenUri>,
         _token_data: Option<&TokenData>,
     ) -> Result<(), HostError> {
+        self.nft_owners
+            .insert((class_id.clone(), token_id.clone()), account.clone());
         Ok(())
     }
 
     fn burn_nft_execute(
         &mut self,
         _account: &Self::AccountId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), HostError> {
+        self.nft_owners
+            .remove(&(class_id.clone(), token_id.clone()));
         Ok(())
     }
 }
+
+impl ClassTraceContext for DummyNftTransferModule {
+    fn class_trace_by_hash(&self, hash: &str) -> Result<Option<PrefixedClassId>, HostError> {
+        Ok(self.class_traces.get(hash).cloned())
+    }
+
+    fn store_class_trace(
+        &mut self,
+        hash: String,
+        class_id: PrefixedClassId,
+    ) -> Result<(), HostError> {
+        self.class_traces.insert(hash, class_id);
+        Ok(())
+    }
+}
+
+impl NftTransferQueryContext for DummyNftTransferModule {
+    fn class_traces(&self) -> Result<Vec<PrefixedClassId>, HostError> {
+        Ok(self.class_traces.values().cloned().collect())
+    }
+
+    fn escrow_address(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, HostError> {
+        let address = cosmos_adr028_escrow_address(port_id, channel_id);
+        Ok(bech32::encode("cosmos", address).into())
+    }
+
+    fn nfts_by_owner(&self, owner: &Signer) -> Result<Vec<(PrefixedClassId, TokenId)>, HostError> {
+        Ok(self
+            .nft_owners
+            .iter()
+            .filter(|(_, nft_owner)| *nft_owner == owner)
+            .map(|(nft, _)| nft.clone())
+            .collect())
+    }
+}
@@ -1,7 +1,21 @@
-use ibc::apps::nft_transfer::types::{ClassData, ClassId, ClassUri, TokenData, TokenId, TokenUri};
+use alloc::collections::BTreeMap;
 
+use ibc::apps::nft_transfer::types::{
+    ClassData, ClassId, ClassUri, PrefixedClassId, TokenData, TokenId, TokenUri,
+};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// An NFT transfer module which hashes class IDs the way Cosmos SDK chains
+/// do, and keeps track of the class traces and of the owners of the NFTs.
 #[derive(Debug)]
-pub struct DummyNftTransferModule;
+pub struct DummyNftTransferModule {
+    /// The traces of the classes of the minted vouchers, by class ID hash.
+    pub class_traces: BTreeMap<String, PrefixedClassId>,
+    /// The owners of the NFTs, escrowed NFTs being owned by the escrow
+    /// account of their channel.
+    pub nft_owners: BTreeMap<(PrefixedClassId, TokenId), Signer>,
+}
 
 #[derive(Debug)]
 pub struct DummyNft {
@@ -50,7 +64,10 @@ impl Default for DummyNftClass {
 
 impl DummyNftTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            class_traces: BTreeMap::new(),
+            nft_owners: BTreeMap::new(),
+        }
     }
 }
 
@@ -0,0 +1,215 @@
+use ibc::apps::packet_forward::context::{
+    PacketForwardExecutionContext, PacketForwardValidationContext,
+};
+use ibc::apps::packet_forward::types::InFlightPacket;
+use ibc::apps::transfer::context::{
+    DenomTraceContext, TokenTransferExecutionContext, TokenTransferValidationContext,
+};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::{Amount, Memo, PrefixedCoin, PrefixedDenom, VERSION};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+
+use super::types::DummyPacketForwardContext;
+
+impl TokenTransferValidationContext for DummyPacketForwardContext {
+    type AccountId = Signer;
+
+    fn sender_account(&self, sender: &Signer) -> Result<Self::AccountId, HostError> {
+        Ok(sender.clone())
+    }
+
+    fn receiver_account(&self, receiver: &Signer) -> Result<Self::AccountId, HostError> {
+        Ok(receiver.clone())
+    }
+
+    fn get_port(&self) -> Result<PortId, HostError> {
+        Ok(PortId::transfer())
+    }
+
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, HostError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_else(|| Version::new(VERSION.to_string())))
+    }
+
+    fn can_send_coins(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    // Escrow totals are not tracked, as no tokens are ever unescrowed.
+    fn total_escrow_for_denom(&self, _denom: &PrefixedDenom) -> Result<Amount, HostError> {
+        Ok(Amount::from(0u64))
+    }
+}
+
+/// Denominations are not hashed, so no trace is ever stored.
+impl DenomTraceContext for DummyPacketForwardContext {
+    fn denom_trace_by_hash(&self, _hash: &str) -> Result<Option<PrefixedDenom>, HostError> {
+        Ok(None)
+    }
+
+    fn store_denom_trace(&mut self, _hash: String, _denom: PrefixedDenom) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for DummyPacketForwardContext {
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.escrowed.push((from_account.clone(), coin.clone()));
+        Ok(())
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.burned.push((account.clone(), coin.clone()));
+        Ok(())
+    }
+
+    fn store_total_escrow_for_denom(
+        &mut self,
+        _denom: &PrefixedDenom,
+        _amount: Amount,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+impl PacketForwardValidationContext for DummyPacketForwardContext {
+    fn forward_receiver(
+        &self,
+        channel_id: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, HostError> {
+        Ok(format!("pfm/{channel_id}/{original_sender}").into())
+    }
+
+    fn in_flight_packet(&self, packet_id: &PacketId) -> Result<Option<InFlightPacket>, HostError> {
+        Ok(self.in_flight_packets.get(packet_id).cloned())
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        Ok(self.host_timestamp)
+    }
+}
+
+impl PacketForwardExecutionContext for DummyPacketForwardContext {
+    fn store_in_flight_packet(
+        &mut self,
+        packet_id: PacketId,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), HostError> {
+        self.in_flight_packets.insert(packet_id, in_flight_packet);
+        Ok(())
+    }
+
+    fn delete_in_flight_packet(&mut self, packet_id: &PacketId) -> Result<(), HostError> {
+        self.in_flight_packets.remove(packet_id);
+        Ok(())
+    }
+
+    fn send_transfer(&mut self, msg: MsgTransfer) -> Result<Sequence, TokenTransferError> {
+        if self.fail_transfers {
+            return Err(HostError::invalid_state("transfers are disabled").into());
+        }
+
+        let sequence = self.next_sequence;
+        self.next_sequence = sequence.increment();
+        self.sent_transfers.push((sequence, msg));
+
+        Ok(sequence)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), HostError> {
+        self.written_acknowledgements
+            .push((packet.clone(), acknowledgement));
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::packet_forward::types::InFlightPacket;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+
+/// An in-memory packet-forward middleware context, which records the
+/// transfers it sends, the acknowledgements it writes, and the tokens it
+/// escrows or burns.
+#[derive(Debug)]
+pub struct DummyPacketForwardContext {
+    pub in_flight_packets: BTreeMap<PacketId, InFlightPacket>,
+    /// The transfers sent so far, along with the sequence of their packet.
+    pub sent_transfers: Vec<(Sequence, MsgTransfer)>,
+    pub written_acknowledgements: Vec<(Packet, Acknowledgement)>,
+    pub escrowed: Vec<(Signer, PrefixedCoin)>,
+    pub burned: Vec<(Signer, PrefixedCoin)>,
+    /// The ICS-20 version of each channel, which defaults to v1.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Version>,
+    pub next_sequence: Sequence,
+    pub host_timestamp: Timestamp,
+    /// Makes all the transfers fail when set.
+    pub fail_transfers: bool,
+}
+
+impl DummyPacketForwardContext {
+    pub fn new() -> Self {
+        Self {
+            in_flight_packets: BTreeMap::new(),
+            sent_transfers: Vec::new(),
+            written_acknowledgements: Vec::new(),
+            escrowed: Vec::new(),
+            burned: Vec::new(),
+            channel_versions: BTreeMap::new(),
+            next_sequence: Sequence::from(1),
+            host_timestamp: Timestamp::from_nanoseconds(1),
+            fail_transfers: false,
+        }
+    }
+}
+
+impl Default for DummyPacketForwardContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
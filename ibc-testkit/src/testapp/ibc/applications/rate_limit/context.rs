@@ -0,0 +1,74 @@
+use ibc::apps::rate_limit::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use ibc::apps::rate_limit::types::{PendingOutflow, RateLimit, RatePath};
+use ibc::apps::transfer::types::{Amount, PrefixedDenom, VERSION};
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+
+use super::types::DummyRateLimitContext;
+
+impl RateLimitValidationContext for DummyRateLimitContext {
+    fn rate_limit(&self, path: &RatePath) -> Result<Option<RateLimit>, HostError> {
+        Ok(self.rate_limits.get(path).cloned())
+    }
+
+    fn channel_value(&self, denom: &PrefixedDenom) -> Result<Amount, HostError> {
+        Ok(self
+            .channel_values
+            .get(denom)
+            .copied()
+            .unwrap_or(Amount::from(0u64)))
+    }
+
+    fn pending_outflows(&self, packet_id: &PacketId) -> Result<Vec<PendingOutflow>, HostError> {
+        Ok(self
+            .pending_outflows
+            .get(packet_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        Ok(self.host_timestamp)
+    }
+
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, HostError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_else(|| Version::new(VERSION.to_string())))
+    }
+}
+
+impl RateLimitExecutionContext for DummyRateLimitContext {
+    fn store_rate_limit(&mut self, rate_limit: RateLimit) -> Result<(), HostError> {
+        if self.fail_stores {
+            return Err(HostError::failed_to_store("rate limit"));
+        }
+
+        self.rate_limits.insert(rate_limit.path.clone(), rate_limit);
+        Ok(())
+    }
+
+    fn store_pending_outflows(
+        &mut self,
+        packet_id: &PacketId,
+        outflows: Vec<PendingOutflow>,
+    ) -> Result<(), HostError> {
+        self.pending_outflows.insert(packet_id.clone(), outflows);
+        Ok(())
+    }
+
+    fn delete_pending_outflows(&mut self, packet_id: &PacketId) -> Result<(), HostError> {
+        self.pending_outflows.remove(packet_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::rate_limit::types::{PendingOutflow, RateLimit, RatePath};
+use ibc::apps::transfer::types::{Amount, PrefixedDenom};
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+
+/// An in-memory rate-limit middleware context.
+#[derive(Debug)]
+pub struct DummyRateLimitContext {
+    pub rate_limits: BTreeMap<RatePath, RateLimit>,
+    /// The channel value of each denomination, which defaults to zero.
+    pub channel_values: BTreeMap<PrefixedDenom, Amount>,
+    pub pending_outflows: BTreeMap<PacketId, Vec<PendingOutflow>>,
+    /// The ICS-20 version of each channel, which defaults to v1.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Version>,
+    pub host_timestamp: Timestamp,
+    /// Makes storing the rate limits fail, as it would on a host running out
+    /// of gas.
+    pub fail_stores: bool,
+}
+
+impl DummyRateLimitContext {
+    pub fn new() -> Self {
+        Self {
+            rate_limits: BTreeMap::new(),
+            channel_values: BTreeMap::new(),
+            pending_outflows: BTreeMap::new(),
+            channel_versions: BTreeMap::new(),
+            host_timestamp: Timestamp::from_nanoseconds(1),
+            fail_stores: false,
+        }
+    }
+
+    /// Adds the given rate limit, and sets the channel value of its
+    /// denomination.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit, channel_value: Amount) -> Self {
+        self.channel_values
+            .insert(rate_limit.path.denom.clone(), channel_value);
+        self.rate_limits.insert(rate_limit.path.clone(), rate_limit);
+        self
+    }
+}
+
+impl Default for DummyRateLimitContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,25 @@
-use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
-use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::apps::transfer::context::{
+    DenomTraceContext, TokenForwardingExecutionContext, TokenTransferExecutionContext,
+    TokenTransferValidationContext, TransferAuthorizationContext,
+};
+use ibc::apps::transfer::handler::{escrow_execute, resolve_forwarding};
+use ibc::apps::transfer::types::authorization::TransferAuthorization;
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::policy::DenomPolicy;
+use ibc::apps::transfer::types::{
+    is_sender_chain_source, Amount, Memo, PrefixedCoin, PrefixedDenom, VERSION,
+};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::channel::types::Version;
 use ibc::core::host::types::error::HostError;
-use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
+use ibc::cosmos_host::utils::{cosmos_adr028_escrow_address, cosmos_denom_hash};
+use ibc_query::apps::transfer::TransferQueryContext;
+use subtle_encoding::bech32;
 
 use super::types::DummyTransferModule;
 
@@ -21,6 +38,18 @@ impl TokenTransferValidationContext for DummyTransferModule {
         Ok(PortId::transfer())
     }
 
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, HostError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_else(|| Version::new(VERSION.to_string())))
+    }
+
     fn can_send_coins(&self) -> Result<(), HostError> {
         Ok(())
     }
@@ -28,6 +57,18 @@ impl TokenTransferValidationContext for DummyTransferModule {
     fn can_receive_coins(&self) -> Result<(), HostError> {
         Ok(())
     }
+
+    fn denom_policy(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Option<DenomPolicy>, HostError> {
+        Ok(self
+            .denom_policies
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned())
+    }
+
     fn escrow_coins_validate(
         &self,
         _from_account: &Self::AccountId,
@@ -65,6 +106,18 @@ impl TokenTransferValidationContext for DummyTransferModule {
     ) -> Result<(), HostError> {
         Ok(())
     }
+
+    fn total_escrow_for_denom(&self, denom: &PrefixedDenom) -> Result<Amount, HostError> {
+        Ok(self
+            .total_escrow
+            .get(denom)
+            .copied()
+            .unwrap_or(Amount::from(0u64)))
+    }
+
+    fn denom_hash_string(&self, denom: &PrefixedDenom) -> Option<String> {
+        Some(cosmos_denom_hash(denom))
+    }
 }
 
 impl TokenTransferExecutionContext for DummyTransferModule {
@@ -92,17 +145,175 @@ impl TokenTransferExecutionContext for DummyTransferModule {
     fn mint_coins_execute(
         &mut self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
     ) -> Result<(), HostError> {
+        let supply = self
+            .voucher_supply
+            .entry(coin.denom.clone())
+            .or_insert(Amount::from(0u64));
+        *supply = supply
+            .checked_add(coin.amount)
+            .ok_or_else(|| HostError::invalid_state("voucher supply overflow"))?;
         Ok(())
     }
 
     fn burn_coins_execute(
         &mut self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), HostError> {
+        let supply = self
+            .voucher_supply
+            .entry(coin.denom.clone())
+            .or_insert(Amount::from(0u64));
+        *supply = supply
+            .checked_sub(coin.amount)
+            .ok_or_else(|| HostError::invalid_state("not enough vouchers to burn"))?;
+        Ok(())
+    }
+
+    fn store_total_escrow_for_denom(
+        &mut self,
+        denom: &PrefixedDenom,
+        amount: Amount,
+    ) -> Result<(), HostError> {
+        self.total_escrow.insert(denom.clone(), amount);
+        Ok(())
+    }
+}
+
+impl DenomTraceContext for DummyTransferModule {
+    fn denom_trace_by_hash(&self, hash: &str) -> Result<Option<PrefixedDenom>, HostError> {
+        Ok(self.denom_traces.get(hash).cloned())
+    }
+
+    fn store_denom_trace(&mut self, hash: String, denom: PrefixedDenom) -> Result<(), HostError> {
+        self.denom_traces.insert(hash, denom);
+        Ok(())
+    }
+}
+
+impl TransferAuthorizationContext for DummyTransferModule {
+    fn transfer_authorization(
+        &self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<Option<TransferAuthorization>, HostError> {
+        Ok(self
+            .transfer_authorizations
+            .get(&(granter.clone(), grantee.clone()))
+            .cloned())
+    }
+
+    fn store_transfer_authorization(
+        &mut self,
+        granter: Signer,
+        grantee: Signer,
+        authorization: TransferAuthorization,
+    ) -> Result<(), HostError> {
+        self.transfer_authorizations
+            .insert((granter, grantee), authorization);
+        Ok(())
+    }
+
+    fn delete_transfer_authorization(
+        &mut self,
+        granter: &Signer,
+        grantee: &Signer,
+    ) -> Result<(), HostError> {
+        self.transfer_authorizations
+            .remove(&(granter.clone(), grantee.clone()));
+        Ok(())
+    }
+}
+
+impl TokenForwardingExecutionContext for DummyTransferModule {
+    fn forwarding_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, HostError> {
+        Ok(format!("forward/{port_id}/{channel_id}").into())
+    }
+
+    fn forwarded_packet(&self, packet_id: &PacketId) -> Result<Option<Packet>, HostError> {
+        Ok(self.forwarded_packets.get(packet_id).cloned())
+    }
+
+    fn store_forwarded_packet(
+        &mut self,
+        packet_id: PacketId,
+        packet: Packet,
+    ) -> Result<(), HostError> {
+        self.forwarded_packets.insert(packet_id, packet);
         Ok(())
     }
+
+    fn delete_forwarded_packet(&mut self, packet_id: &PacketId) -> Result<(), HostError> {
+        self.forwarded_packets.remove(packet_id);
+        Ok(())
+    }
+
+    /// Takes the tokens from the sender the way the `send_transfer` handler
+    /// does, without sending any packet.
+    fn send_transfer(&mut self, msg: MsgTransfer) -> Result<Sequence, TokenTransferError> {
+        if self.fail_transfers {
+            return Err(HostError::invalid_state("transfers are disabled").into());
+        }
+
+        let msg = resolve_forwarding(msg)?;
+
+        for token in &msg.packet_data.tokens {
+            let coin = PrefixedCoin::from(token.clone());
+
+            if is_sender_chain_source(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                &coin.denom,
+            ) {
+                escrow_execute(
+                    self,
+                    &msg.packet_data.sender,
+                    &msg.port_id_on_a,
+                    &msg.chan_id_on_a,
+                    &coin,
+                    &msg.packet_data.memo,
+                )?;
+            } else {
+                self.burn_coins_execute(&msg.packet_data.sender, &coin, &msg.packet_data.memo)?;
+            }
+        }
+
+        let sequence = self.next_sequence;
+        self.next_sequence = sequence.increment();
+        self.sent_transfers.push((sequence, msg));
+
+        Ok(sequence)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), HostError> {
+        self.written_acknowledgements
+            .push((packet.clone(), acknowledgement));
+        Ok(())
+    }
+}
+
+impl TransferQueryContext for DummyTransferModule {
+    fn denom_traces(&self) -> Result<Vec<PrefixedDenom>, HostError> {
+        Ok(self.denom_traces.values().cloned().collect())
+    }
+
+    fn escrow_address(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, HostError> {
+        let address = cosmos_adr028_escrow_address(port_id, channel_id);
+        Ok(bech32::encode("cosmos", address).into())
+    }
 }
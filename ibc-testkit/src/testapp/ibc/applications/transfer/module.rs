@@ -1,15 +1,20 @@
+use ibc::apps::transfer::module;
 use ibc::core::channel::types::acknowledgement::Acknowledgement;
 use ibc::core::channel::types::channel::{Counterparty, Order};
 use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::v2::acknowledgement::RecvPacketResult;
+use ibc::core::channel::types::v2::packet::Payload;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 use ibc::core::router::module::Module;
 use ibc::core::router::types::module::ModuleExtras;
+use ibc::core::router::v2::ModuleV2;
 
 use super::types::DummyTransferModule;
+use crate::testapp::ibc::core::router::ModuleState;
 
 impl Module for DummyTransferModule {
     fn on_chan_open_init_validate(
@@ -60,6 +65,106 @@ impl Module for DummyTransferModule {
         Ok((ModuleExtras::empty(), counterparty_version.clone()))
     }
 
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(module::on_chan_upgrade_init_validate(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )?)
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(module::on_chan_upgrade_init_execute(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )?)
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(module::on_chan_upgrade_try_validate(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(module::on_chan_upgrade_try_execute(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(module::on_chan_upgrade_ack_validate(
+            self,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(module::on_chan_upgrade_ack_execute(
+            self,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
     fn on_recv_packet_execute(
         &mut self,
         _packet: &Packet,
@@ -105,3 +210,166 @@ impl Module for DummyTransferModule {
         (ModuleExtras::empty(), Ok(()))
     }
 }
+
+impl ModuleV2 for DummyTransferModule {
+    fn on_send_packet_validate(
+        &self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_send_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        (ModuleExtras::empty(), RecvPacketResult::success(vec![1u8]))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+impl ModuleState for DummyTransferModule {
+    fn state(&self) -> BTreeMap<String, String> {
+        let denom_traces = self
+            .denom_traces
+            .iter()
+            .map(|(hash, trace)| (format!("denomTraces/{hash}"), format!("{trace}")));
+        let total_escrow = self
+            .total_escrow
+            .iter()
+            .map(|(denom, amount)| (format!("totalEscrow/{denom}"), format!("{amount}")));
+        let voucher_supply = self
+            .voucher_supply
+            .iter()
+            .map(|(denom, amount)| (format!("voucherSupply/{denom}"), format!("{amount}")));
+        let transfer_authorizations =
+            self.transfer_authorizations
+                .iter()
+                .map(|((granter, grantee), authorization)| {
+                    (
+                        format!("transferAuthorizations/{granter}/{grantee}"),
+                        format!("{authorization:?}"),
+                    )
+                });
+        let denom_policies = self
+            .denom_policies
+            .iter()
+            .map(|((port_id, chan_id), policy)| {
+                (
+                    format!("denomPolicies/{port_id}/{chan_id}"),
+                    format!("{policy:?}"),
+                )
+            });
+        let channel_versions = self
+            .channel_versions
+            .iter()
+            .map(|((port_id, chan_id), version)| {
+                (
+                    format!("channelVersions/{port_id}/{chan_id}"),
+                    format!("{version}"),
+                )
+            });
+        let forwarded_packets = self.forwarded_packets.iter().map(|(packet_id, packet)| {
+            (
+                format!(
+                    "forwardedPackets/{}/{}/{}",
+                    packet_id.port_id, packet_id.chan_id, packet_id.seq
+                ),
+                format!("{packet:?}"),
+            )
+        });
+        let sent_transfers = self
+            .sent_transfers
+            .iter()
+            .map(|(seq, msg)| (format!("sentTransfers/{seq}"), format!("{msg:?}")));
+        let written_acknowledgements =
+            self.written_acknowledgements
+                .iter()
+                .enumerate()
+                .map(|(index, (packet, ack))| {
+                    (
+                        format!("writtenAcknowledgements/{index}"),
+                        format!("{packet:?}/{ack:?}"),
+                    )
+                });
+
+        denom_traces
+            .chain(total_escrow)
+            .chain(voucher_supply)
+            .chain(transfer_authorizations)
+            .chain(denom_policies)
+            .chain(channel_versions)
+            .chain(forwarded_packets)
+            .chain(sent_transfers)
+            .chain(written_acknowledgements)
+            .chain([(
+                "nextSequence".to_string(),
+                format!("{}", self.next_sequence),
+            )])
+            .collect()
+    }
+}
@@ -1,9 +1,132 @@
-#[derive(Debug)]
-pub struct DummyTransferModule;
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc::apps::transfer::types::authorization::TransferAuthorization;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::policy::DenomPolicy;
+use ibc::apps::transfer::types::{Amount, PrefixedDenom, TracePrefix};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// A token transfer module which hashes denominations the way Cosmos SDK
+/// chains do, and keeps track of the denomination traces, the escrowed tokens,
+/// the minted vouchers, the transfer authorizations and the denomination
+/// policies.
+///
+/// It also supports forwarding, recording the transfers it sends and the
+/// acknowledgements it writes instead of sending packets.
+#[derive(Clone, Debug)]
+pub struct DummyTransferModule {
+    /// The traces of the minted vouchers, by denomination hash.
+    pub denom_traces: BTreeMap<String, PrefixedDenom>,
+    /// The amount of tokens held in escrow, by denomination.
+    pub total_escrow: BTreeMap<PrefixedDenom, Amount>,
+    /// The amount of vouchers minted and not burned yet, by denomination.
+    pub voucher_supply: BTreeMap<PrefixedDenom, Amount>,
+    /// The transfer authorizations, by granter and grantee.
+    pub transfer_authorizations: BTreeMap<(Signer, Signer), TransferAuthorization>,
+    /// The denomination policies, by channel.
+    pub denom_policies: BTreeMap<(PortId, ChannelId), DenomPolicy>,
+    /// The ICS-20 version of each channel, which defaults to v1.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Version>,
+    /// The received packets whose tokens are being forwarded, by id of the
+    /// forwarded packet.
+    pub forwarded_packets: BTreeMap<PacketId, Packet>,
+    /// The transfers sent so far, as resolved by the `send_transfer` handler,
+    /// along with the sequence of their packet.
+    pub sent_transfers: Vec<(Sequence, MsgTransfer)>,
+    pub written_acknowledgements: Vec<(Packet, Acknowledgement)>,
+    pub next_sequence: Sequence,
+    /// Makes all the transfers fail when set.
+    pub fail_transfers: bool,
+}
+
+/// A denomination whose total escrow on a chain differs from the supply of
+/// the vouchers minted for it on the counterparty chains.
+#[derive(Debug, PartialEq, Eq)]
+pub struct EscrowMismatch {
+    pub denom: PrefixedDenom,
+    pub total_escrow: Amount,
+    pub voucher_supply: Amount,
+}
 
 impl DummyTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            denom_traces: BTreeMap::new(),
+            total_escrow: BTreeMap::new(),
+            voucher_supply: BTreeMap::new(),
+            transfer_authorizations: BTreeMap::new(),
+            denom_policies: BTreeMap::new(),
+            channel_versions: BTreeMap::new(),
+            forwarded_packets: BTreeMap::new(),
+            sent_transfers: Vec::new(),
+            written_acknowledgements: Vec::new(),
+            next_sequence: Sequence::from(1),
+            fail_transfers: false,
+        }
+    }
+
+    /// Checks that the tokens held in escrow by this chain match, for every
+    /// denomination, the vouchers minted for them on its counterparties.
+    ///
+    /// Each counterparty is given along with the trace prefix of its end of
+    /// the channel to this chain, which its vouchers are prefixed with. The
+    /// check only holds if the counterparties of all the channels the tokens
+    /// were sent over are given, and no packet is in flight.
+    pub fn check_escrow_invariant(
+        &self,
+        counterparties: &[(TracePrefix, &DummyTransferModule)],
+    ) -> Result<(), Vec<EscrowMismatch>> {
+        let zero = Amount::from(0u64);
+        let mut supplies: BTreeMap<PrefixedDenom, Amount> = BTreeMap::new();
+
+        for (prefix, counterparty) in counterparties {
+            for (voucher, supply) in &counterparty.voucher_supply {
+                if !voucher.trace_path.starts_with(prefix) {
+                    continue;
+                }
+
+                let mut denom = voucher.clone();
+                denom.remove_trace_prefix(prefix);
+
+                let total = supplies.entry(denom).or_insert(zero);
+                *total = total
+                    .checked_add(*supply)
+                    .expect("voucher supply must not overflow");
+            }
+        }
+
+        let denoms: BTreeSet<_> = self
+            .total_escrow
+            .keys()
+            .chain(supplies.keys())
+            .cloned()
+            .collect();
+
+        let mut mismatches: Vec<EscrowMismatch> = Vec::new();
+
+        for denom in denoms {
+            let total_escrow = self.total_escrow.get(&denom).copied().unwrap_or(zero);
+            let voucher_supply = supplies.get(&denom).copied().unwrap_or(zero);
+
+            if total_escrow != voucher_supply {
+                mismatches.push(EscrowMismatch {
+                    denom,
+                    total_escrow,
+                    voucher_supply,
+                });
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
     }
 }
 
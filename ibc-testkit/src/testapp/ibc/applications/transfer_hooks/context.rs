@@ -0,0 +1,210 @@
+use ibc::apps::transfer::context::{
+    DenomTraceContext, TokenTransferExecutionContext, TokenTransferValidationContext,
+};
+use ibc::apps::transfer::types::{Amount, Memo, PrefixedCoin, PrefixedDenom, VERSION};
+use ibc::apps::transfer_hooks::context::HookExecutor;
+use ibc::apps::transfer_hooks::types::HookCall;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use super::types::DummyHookExecutor;
+
+impl TokenTransferValidationContext for DummyHookExecutor {
+    type AccountId = Signer;
+
+    fn sender_account(&self, sender: &Signer) -> Result<Self::AccountId, HostError> {
+        Ok(sender.clone())
+    }
+
+    fn receiver_account(&self, receiver: &Signer) -> Result<Self::AccountId, HostError> {
+        Ok(receiver.clone())
+    }
+
+    fn get_port(&self) -> Result<PortId, HostError> {
+        Ok(PortId::transfer())
+    }
+
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, HostError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_else(|| Version::new(VERSION.to_string())))
+    }
+
+    fn can_send_coins(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.check_funds()
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.check_funds()
+    }
+
+    // Escrow totals are not tracked, as no tokens are ever unescrowed.
+    fn total_escrow_for_denom(&self, _denom: &PrefixedDenom) -> Result<Amount, HostError> {
+        Ok(Amount::from(0u64))
+    }
+}
+
+/// Denominations are not hashed, so no trace is ever stored.
+impl DenomTraceContext for DummyHookExecutor {
+    fn denom_trace_by_hash(&self, _hash: &str) -> Result<Option<PrefixedDenom>, HostError> {
+        Ok(None)
+    }
+
+    fn store_denom_trace(&mut self, _hash: String, _denom: PrefixedDenom) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for DummyHookExecutor {
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.escrowed.push((from_account.clone(), coin.clone()));
+        Ok(())
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.burned.push((account.clone(), coin.clone()));
+        Ok(())
+    }
+
+    fn store_total_escrow_for_denom(
+        &mut self,
+        _denom: &PrefixedDenom,
+        _amount: Amount,
+    ) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+impl DummyHookExecutor {
+    fn check_calls(&self) -> Result<(), HostError> {
+        if self.fail_calls {
+            return Err(HostError::invalid_state("contract calls are disabled"));
+        }
+
+        Ok(())
+    }
+
+    fn check_funds(&self) -> Result<(), HostError> {
+        if self.lock_funds {
+            return Err(HostError::invalid_state("the funds were spent"));
+        }
+
+        Ok(())
+    }
+}
+
+impl HookExecutor for DummyHookExecutor {
+    fn execute_hook(
+        &mut self,
+        _packet: &Packet,
+        sender: &Signer,
+        call: &HookCall,
+        funds: &[PrefixedCoin],
+    ) -> Result<(), HostError> {
+        self.check_calls()?;
+        self.executed_hooks
+            .push((sender.clone(), call.clone(), funds.to_vec()));
+        Ok(())
+    }
+
+    fn execute_ack_callback(
+        &mut self,
+        contract: &Signer,
+        _packet: &Packet,
+        acknowledgement: &Acknowledgement,
+    ) -> Result<(), HostError> {
+        self.check_calls()?;
+        self.ack_callbacks
+            .push((contract.clone(), acknowledgement.clone()));
+        Ok(())
+    }
+
+    fn execute_timeout_callback(
+        &mut self,
+        contract: &Signer,
+        _packet: &Packet,
+    ) -> Result<(), HostError> {
+        self.check_calls()?;
+        self.timeout_callbacks.push(contract.clone());
+        Ok(())
+    }
+}
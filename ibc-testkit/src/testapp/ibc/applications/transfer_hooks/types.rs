@@ -0,0 +1,50 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::apps::transfer_hooks::types::HookCall;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// An in-memory transfer hooks middleware context, which records the calls it
+/// executes, and the tokens it escrows or burns.
+#[derive(Debug)]
+pub struct DummyHookExecutor {
+    /// The hooks executed so far, along with the sender of their packet and
+    /// the funds they were called with.
+    pub executed_hooks: Vec<(Signer, HookCall, Vec<PrefixedCoin>)>,
+    pub ack_callbacks: Vec<(Signer, Acknowledgement)>,
+    pub timeout_callbacks: Vec<Signer>,
+    pub escrowed: Vec<(Signer, PrefixedCoin)>,
+    pub burned: Vec<(Signer, PrefixedCoin)>,
+    /// The ICS-20 version of each channel, which defaults to v1.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Version>,
+    /// Makes all the hooks and callbacks fail when set.
+    pub fail_calls: bool,
+    /// Prevents the tokens received by the contracts from being escrowed or
+    /// burned when set, as if the contracts had spent them.
+    pub lock_funds: bool,
+}
+
+impl DummyHookExecutor {
+    pub fn new() -> Self {
+        Self {
+            executed_hooks: Vec::new(),
+            ack_callbacks: Vec::new(),
+            timeout_callbacks: Vec::new(),
+            escrowed: Vec::new(),
+            burned: Vec::new(),
+            channel_versions: BTreeMap::new(),
+            fail_calls: false,
+            lock_funds: false,
+        }
+    }
+}
+
+impl Default for DummyHookExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -315,6 +315,27 @@ where
     fn check_substitute(&self, _ctx: &V, _substitute_client_state: Any) -> Result<(), ClientError> {
         Ok(())
     }
+
+    fn is_redundant_update(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        if header.type_url != MOCK_HEADER_TYPE_URL {
+            return Ok(false);
+        }
+
+        let header = MockHeader::try_from(header)?;
+
+        Ok(ctx
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                header.height().revision_number(),
+                header.height().revision_height(),
+            ))
+            .is_ok())
+    }
 }
 
 impl<E> ClientStateExecution<E> for MockClientState
@@ -443,6 +464,7 @@ where
         &self,
         ctx: &mut E,
         subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
         substitute_client_state: Any,
         substitute_consensus_state: Any,
     ) -> Result<(), ClientError> {
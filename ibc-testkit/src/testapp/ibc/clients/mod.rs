@@ -1,9 +1,20 @@
 pub mod mock;
+pub mod wasm;
 
 use alloc::fmt::Debug;
 
 use basecoin_store::context::ProvableStore;
 use derive_more::From;
+use ibc::clients::localhost::client_state::ClientState as LocalhostClientState;
+use ibc::clients::localhost::types::{
+    ClientState as LocalhostClientStateType, LOCALHOST_CLIENT_STATE_TYPE_URL,
+};
+use ibc::clients::solomachine::client_state::ClientState as SmClientState;
+use ibc::clients::solomachine::consensus_state::ConsensusState as SmConsensusState;
+use ibc::clients::solomachine::types::{
+    ClientState as SmClientStateType, ConsensusState as SmConsensusStateType,
+    SOLOMACHINE_CLIENT_STATE_TYPE_URL, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc::clients::tendermint::types::{
@@ -11,6 +22,7 @@ use ibc::clients::tendermint::types::{
     TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
 use ibc::core::client::types::Height;
+use ibc::core::handler::cache::CachedContext;
 use ibc::core::host::types::error::DecodingError;
 use ibc::core::primitives::prelude::*;
 use ibc::derive::{ClientState, ConsensusState};
@@ -27,8 +39,12 @@ use crate::testapp::ibc::clients::mock::consensus_state::{
 #[derive(Debug, Clone, From, PartialEq, ClientState)]
 #[validation(MockIbcStore<S: ProvableStore + Debug>)]
 #[execution(MockIbcStore<S: ProvableStore + Debug>)]
+#[validation(CachedContext<'a, MockIbcStore<S: ProvableStore + Debug>>)]
+#[execution(CachedContext<'a, MockIbcStore<S: ProvableStore + Debug>>)]
 pub enum AnyClientState {
     Tendermint(TmClientState),
+    Solomachine(SmClientState),
+    Localhost(LocalhostClientState),
     Mock(MockClientState),
 }
 
@@ -36,6 +52,8 @@ impl AnyClientState {
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(cs) => cs.inner().latest_height,
+            Self::Solomachine(cs) => cs.inner().latest_height(),
+            Self::Localhost(cs) => cs.inner().latest_height(),
             Self::Mock(cs) => cs.latest_height(),
         }
     }
@@ -43,6 +61,8 @@ impl AnyClientState {
     pub fn is_frozen(&self) -> bool {
         match self {
             Self::Tendermint(cs) => cs.inner().is_frozen(),
+            Self::Solomachine(cs) => cs.inner().is_frozen(),
+            Self::Localhost(_) => false,
             Self::Mock(cs) => cs.is_frozen(),
         }
     }
@@ -56,6 +76,10 @@ impl TryFrom<Any> for AnyClientState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         if raw.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL {
             Ok(TmClientState::try_from(raw)?.into())
+        } else if raw.type_url == SOLOMACHINE_CLIENT_STATE_TYPE_URL {
+            Ok(SmClientState::try_from(raw)?.into())
+        } else if raw.type_url == LOCALHOST_CLIENT_STATE_TYPE_URL {
+            Ok(LocalhostClientState::try_from(raw)?.into())
         } else if raw.type_url == MOCK_CLIENT_STATE_TYPE_URL {
             MockClientState::try_from(raw).map(Into::into)
         } else {
@@ -68,6 +92,8 @@ impl From<AnyClientState> for Any {
     fn from(host_client_state: AnyClientState) -> Self {
         match host_client_state {
             AnyClientState::Tendermint(cs) => cs.into(),
+            AnyClientState::Solomachine(cs) => cs.into(),
+            AnyClientState::Localhost(cs) => cs.into(),
             AnyClientState::Mock(cs) => cs.into(),
         }
     }
@@ -85,9 +111,28 @@ impl From<ConsensusStateType> for AnyConsensusState {
     }
 }
 
+impl From<SmClientStateType> for AnyClientState {
+    fn from(client_state: SmClientStateType) -> Self {
+        Self::Solomachine(client_state.into())
+    }
+}
+
+impl From<LocalhostClientStateType> for AnyClientState {
+    fn from(client_state: LocalhostClientStateType) -> Self {
+        Self::Localhost(client_state.into())
+    }
+}
+
+impl From<SmConsensusStateType> for AnyConsensusState {
+    fn from(consensus_state: SmConsensusStateType) -> Self {
+        Self::Solomachine(consensus_state.into())
+    }
+}
+
 #[derive(Debug, Clone, From, PartialEq, Eq, ConsensusState)]
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
+    Solomachine(SmConsensusState),
     Mock(MockConsensusState),
 }
 
@@ -97,6 +142,8 @@ impl TryFrom<Any> for AnyConsensusState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         if raw.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL {
             Ok(TmConsensusState::try_from(raw)?.into())
+        } else if raw.type_url == SOLOMACHINE_CONSENSUS_STATE_TYPE_URL {
+            Ok(SmConsensusState::try_from(raw)?.into())
         } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
             MockConsensusState::try_from(raw).map(Into::into)
         } else {
@@ -109,6 +156,7 @@ impl From<AnyConsensusState> for Any {
     fn from(host_consensus_state: AnyConsensusState) -> Self {
         match host_consensus_state {
             AnyConsensusState::Tendermint(cs) => cs.into(),
+            AnyConsensusState::Solomachine(cs) => cs.into(),
             AnyConsensusState::Mock(cs) => cs.into(),
         }
     }
@@ -127,6 +175,19 @@ impl TryFrom<AnyConsensusState> for ConsensusStateType {
     }
 }
 
+impl TryFrom<AnyConsensusState> for SmConsensusStateType {
+    type Error = DecodingError;
+
+    fn try_from(value: AnyConsensusState) -> Result<Self, Self::Error> {
+        match value {
+            AnyConsensusState::Solomachine(cs) => Ok(cs.into_inner()),
+            _ => Err(DecodingError::invalid_raw_data(
+                "AnyConsensusState could not be converted to SmConsensusState",
+            )),
+        }
+    }
+}
+
 impl TryFrom<AnyConsensusState> for MockConsensusState {
     type Error = DecodingError;
 
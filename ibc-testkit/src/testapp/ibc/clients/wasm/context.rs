@@ -0,0 +1,218 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc::clients::wasm::client_state::ClientState as WasmClientState;
+use ibc::clients::wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc::clients::wasm::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use ibc::clients::wasm::types::client_state::ClientState as WasmClientStateType;
+use ibc::core::client::context::prelude::*;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+
+use super::MockWasmEngine;
+use crate::fixtures::core::signer::dummy_account_id;
+
+/// A host that only knows about Wasm clients. Client and consensus states,
+/// which a real contract would write through the engine, are set directly.
+#[derive(Debug)]
+pub struct MockWasmContext {
+    pub engine: MockWasmEngine,
+    pub checksums: BTreeSet<Vec<u8>>,
+    pub authority: Signer,
+    pub client_states: BTreeMap<ClientId, WasmClientState>,
+    pub consensus_states: BTreeMap<ClientConsensusStatePath, WasmConsensusState>,
+    pub update_meta: BTreeMap<(ClientId, Height), (Timestamp, Height)>,
+    pub host_timestamp: Timestamp,
+    pub host_height: Height,
+}
+
+impl Default for MockWasmContext {
+    fn default() -> Self {
+        Self {
+            engine: MockWasmEngine::default(),
+            checksums: BTreeSet::new(),
+            authority: dummy_account_id(),
+            client_states: BTreeMap::new(),
+            consensus_states: BTreeMap::new(),
+            update_meta: BTreeMap::new(),
+            host_timestamp: Timestamp::from_nanoseconds(1_000_000_000),
+            host_height: Height::new(0, 10).expect("Never fails"),
+        }
+    }
+}
+
+impl MockWasmContext {
+    /// Stores the given code and registers its checksum, bypassing the
+    /// governance handlers.
+    pub fn with_code(mut self, checksum: Vec<u8>, code: Vec<u8>) -> Self {
+        self.engine.codes.lock().insert(checksum.clone(), code);
+        self.checksums.insert(checksum);
+        self
+    }
+
+    pub fn with_client_state(
+        mut self,
+        client_id: ClientId,
+        client_state: WasmClientStateType,
+    ) -> Self {
+        self.client_states.insert(client_id, client_state.into());
+        self
+    }
+}
+
+impl ClientValidationContext for MockWasmContext {
+    type ClientStateRef = WasmClientState;
+    type ConsensusStateRef = WasmConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, HostError> {
+        self.client_states
+            .get(client_id)
+            .cloned()
+            .ok_or_else(|| HostError::missing_state(format!("client state for {client_id}")))
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, HostError> {
+        self.consensus_states
+            .get(client_cons_state_path)
+            .cloned()
+            .ok_or_else(|| {
+                HostError::missing_state(format!("consensus state at {client_cons_state_path}"))
+            })
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), HostError> {
+        self.update_meta
+            .get(&(client_id.clone(), *height))
+            .copied()
+            .ok_or_else(|| {
+                HostError::missing_state(format!("update meta for {client_id} at {height}"))
+            })
+    }
+}
+
+impl ClientExecutionContext for MockWasmContext {
+    type ClientStateMut = WasmClientState;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), HostError> {
+        self.client_states.insert(client_state_path.0, client_state);
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), HostError> {
+        self.consensus_states
+            .insert(consensus_state_path, consensus_state);
+        Ok(())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), HostError> {
+        self.consensus_states.remove(&consensus_state_path);
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), HostError> {
+        self.update_meta
+            .insert((client_id, height), (host_timestamp, host_height));
+        Ok(())
+    }
+
+    fn delete_update_meta(&mut self, client_id: ClientId, height: Height) -> Result<(), HostError> {
+        self.update_meta.remove(&(client_id, height));
+        Ok(())
+    }
+}
+
+impl ExtClientValidationContext for MockWasmContext {
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        Ok(self.host_timestamp)
+    }
+
+    fn host_height(&self) -> Result<Height, HostError> {
+        Ok(self.host_height)
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, HostError> {
+        self.consensus_states
+            .keys()
+            .filter(|path| &path.client_id == client_id)
+            .map(|path| {
+                Height::new(path.revision_number, path.revision_height)
+                    .map_err(HostError::invalid_state)
+            })
+            .collect()
+    }
+
+    fn next_consensus_state(
+        &self,
+        _client_id: &ClientId,
+        _height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        Ok(None)
+    }
+
+    fn prev_consensus_state(
+        &self,
+        _client_id: &ClientId,
+        _height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        Ok(None)
+    }
+}
+
+impl WasmClientValidationContext for MockWasmContext {
+    type Engine = MockWasmEngine;
+
+    fn wasm_engine(&self) -> &Self::Engine {
+        &self.engine
+    }
+
+    fn checksum_exists(&self, checksum: &[u8]) -> Result<bool, HostError> {
+        Ok(self.checksums.contains(checksum))
+    }
+
+    fn checksums(&self) -> Result<Vec<Vec<u8>>, HostError> {
+        Ok(self.checksums.iter().cloned().collect())
+    }
+
+    fn wasm_authority(&self) -> Result<Signer, HostError> {
+        Ok(self.authority.clone())
+    }
+}
+
+impl WasmClientExecutionContext for MockWasmContext {
+    fn store_checksum(&mut self, checksum: Vec<u8>) -> Result<(), HostError> {
+        self.checksums.insert(checksum);
+        Ok(())
+    }
+
+    fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), HostError> {
+        self.checksums.remove(checksum);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::collections::BTreeMap;
+
+use ibc::clients::wasm::engine::{ClientStore, WasmEngine};
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::primitives::prelude::*;
+use parking_lot::Mutex;
+
+/// An owned counterpart of [`ClientStore`], recorded with each contract call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockClientStore {
+    Client(ClientId),
+    Recovery {
+        subject: ClientId,
+        substitute: ClientId,
+    },
+}
+
+impl From<ClientStore<'_>> for MockClientStore {
+    fn from(store: ClientStore<'_>) -> Self {
+        match store {
+            ClientStore::Client(client_id) => Self::Client(client_id.clone()),
+            ClientStore::Recovery {
+                subject,
+                substitute,
+            } => Self::Recovery {
+                subject: subject.clone(),
+                substitute: substitute.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmEntrypoint {
+    Instantiate,
+    Query,
+    Sudo,
+    Migrate,
+}
+
+/// A contract call received by the [`MockWasmEngine`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmCall {
+    pub entrypoint: WasmEntrypoint,
+    pub checksum: Vec<u8>,
+    pub store: MockClientStore,
+    pub msg: Vec<u8>,
+}
+
+/// A [`WasmEngine`] that doesn't run any code. It keeps track of the stored
+/// code, records every contract call, and answers queries and sudo calls
+/// with the response registered for the message's variant (e.g. `status`).
+#[derive(Debug)]
+pub struct MockWasmEngine {
+    pub codes: Mutex<BTreeMap<Vec<u8>, Vec<u8>>>,
+    pub calls: Mutex<Vec<WasmCall>>,
+    pub responses: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl Default for MockWasmEngine {
+    fn default() -> Self {
+        let responses = [
+            ("status", r#"{"status":"Active"}"#),
+            ("timestamp_at_height", r#"{"timestamp":0}"#),
+            ("verify_client_message", "{}"),
+            ("check_for_misbehaviour", r#"{"found_misbehaviour":false}"#),
+            ("update_state_on_misbehaviour", "{}"),
+            ("verify_membership", "{}"),
+            ("verify_non_membership", "{}"),
+            ("migrate_client_store", "{}"),
+        ]
+        .into_iter()
+        .map(|(variant, response)| (variant.to_string(), response.as_bytes().to_vec()))
+        .collect();
+
+        Self {
+            codes: Mutex::new(BTreeMap::new()),
+            calls: Mutex::new(Vec::new()),
+            responses: Mutex::new(responses),
+        }
+    }
+}
+
+impl MockWasmEngine {
+    /// Sets the response returned to messages of the given variant.
+    pub fn set_response(&self, variant: &str, response: impl Into<Vec<u8>>) {
+        self.responses
+            .lock()
+            .insert(variant.to_string(), response.into());
+    }
+
+    /// Makes messages of the given variant fail.
+    pub fn remove_response(&self, variant: &str) {
+        self.responses.lock().remove(variant);
+    }
+
+    pub fn calls(&self) -> Vec<WasmCall> {
+        self.calls.lock().clone()
+    }
+
+    fn record(
+        &self,
+        entrypoint: WasmEntrypoint,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<(), HostError> {
+        if !self.codes.lock().contains_key(checksum) {
+            return Err(HostError::missing_state(format!(
+                "no code stored under checksum {checksum:?}"
+            )));
+        }
+
+        self.calls.lock().push(WasmCall {
+            entrypoint,
+            checksum: checksum.to_vec(),
+            store: store.into(),
+            msg: msg.to_vec(),
+        });
+
+        Ok(())
+    }
+
+    fn respond(&self, msg: &[u8]) -> Result<Vec<u8>, HostError> {
+        // Contract messages are JSON objects with a single key, the variant.
+        let variant = msg
+            .strip_prefix(b"{\"")
+            .and_then(|rest| rest.split(|b| *b == b'"').next())
+            .map(String::from_utf8_lossy)
+            .ok_or_else(|| HostError::invalid_state("malformed contract message"))?;
+
+        self.responses
+            .lock()
+            .get(variant.as_ref())
+            .cloned()
+            .ok_or_else(|| HostError::Other {
+                description: format!("contract rejected `{variant}`"),
+            })
+    }
+}
+
+impl WasmEngine for MockWasmEngine {
+    fn store_code(&self, checksum: &[u8], code: &[u8]) -> Result<(), HostError> {
+        self.codes.lock().insert(checksum.to_vec(), code.to_vec());
+        Ok(())
+    }
+
+    fn remove_code(&self, checksum: &[u8]) -> Result<(), HostError> {
+        self.codes
+            .lock()
+            .remove(checksum)
+            .map(|_| ())
+            .ok_or_else(|| HostError::missing_state("no code to remove"))
+    }
+
+    fn instantiate(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<(), HostError> {
+        self.record(WasmEntrypoint::Instantiate, checksum, store, msg)
+    }
+
+    fn query(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, HostError> {
+        self.record(WasmEntrypoint::Query, checksum, store, msg)?;
+        self.respond(msg)
+    }
+
+    fn sudo(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, HostError> {
+        self.record(WasmEntrypoint::Sudo, checksum, store, msg)?;
+        self.respond(msg)
+    }
+
+    fn migrate(
+        &self,
+        checksum: &[u8],
+        store: ClientStore<'_>,
+        msg: &[u8],
+    ) -> Result<(), HostError> {
+        self.record(WasmEntrypoint::Migrate, checksum, store, msg)
+    }
+}
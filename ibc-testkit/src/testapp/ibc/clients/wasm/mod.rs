@@ -0,0 +1,8 @@
+//! A mock host for the ICS-08 Wasm client, running against a
+//! [`MockWasmEngine`] that records contract calls and answers them with
+//! canned responses.
+mod context;
+mod engine;
+
+pub use context::*;
+pub use engine::*;
@@ -6,6 +6,7 @@ use ibc::core::client::context::{
     ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
 };
 use ibc::core::client::types::Height;
+use ibc::core::handler::cache::CachedContext;
 use ibc::core::host::types::error::HostError;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
 use ibc::core::host::types::path::{
@@ -318,3 +319,16 @@ where
         Ok(())
     }
 }
+
+impl<S> MockClientContext for CachedContext<'_, MockIbcStore<S>>
+where
+    S: ProvableStore + Debug,
+{
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, HostError> {
+        ValidationContext::host_height(self)
+    }
+}
@@ -5,31 +5,40 @@ use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::types::Height as StoreHeight;
+use ibc::clients::tendermint::types::client_type as tm_client_type;
 use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::{PacketState, Receipt};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeTimeout};
 use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{CounterpartyInfo, Height};
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::commitment_types::merkle::MerkleProof;
 use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
 use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::host::types::error::HostError;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ClientId, ClientType, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
+    v2 as path_v2, AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, CommitmentPath, ConnectionPath, CounterpartyChannelUpgradePath,
     NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
-use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
+use ibc::core::host::{
+    ClientStateRef, ConsensusStateRef, ExecutionContext, TransactionalExecutionContext,
+    ValidationContext,
+};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
 use ibc::primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
 
-use super::types::{MockIbcStore, DEFAULT_BLOCK_TIME_SECS};
+use super::types::{
+    ClientCounterpartyPath, MockIbcStore, PacketPathV2, StagedState, DEFAULT_BLOCK_TIME_SECS,
+};
+use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 
 impl<S> ValidationContext for MockIbcStore<S>
@@ -165,17 +174,18 @@ where
     ) -> Result<PacketCommitment, HostError> {
         self.packet_commitment_store
             .get(StoreHeight::Pending, commitment_path)
-            .ok_or(HostError::failed_to_retrieve("packet commitment"))
+            .ok_or(HostError::missing_state("packet commitment"))
     }
 
     fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, HostError> {
-        if self
+        let receipt = self
             .packet_receipt_store
-            .is_path_set(StoreHeight::Pending, receipt_path)
-        {
-            Ok(Receipt::Ok)
-        } else {
-            Ok(Receipt::None)
+            .get(StoreHeight::Pending, receipt_path);
+
+        match receipt.as_deref() {
+            None => Ok(Receipt::None),
+            Some(bytes) if Some(bytes) == Receipt::Timeout.as_bytes() => Ok(Receipt::Timeout),
+            Some(_) => Ok(Receipt::Ok),
         }
     }
 
@@ -191,6 +201,124 @@ where
             )))
     }
 
+    fn pending_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, HostError> {
+        Ok(self
+            .pending_acknowledgements
+            .lock()
+            .iter()
+            .filter(|path| {
+                path.port_id == channel_end_path.0 && path.channel_id == channel_end_path.1
+            })
+            .map(|path| path.sequence)
+            .collect())
+    }
+
+    fn client_counterparty(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<CounterpartyInfo>, HostError> {
+        Ok(self.client_counterparty_store.get(
+            StoreHeight::Pending,
+            &ClientCounterpartyPath(client_id.clone()),
+        ))
+    }
+
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &path_v2::SeqSendPath,
+    ) -> Result<Sequence, HostError> {
+        self.send_sequence_store_v2
+            .get(StoreHeight::Pending, seq_send_path)
+            .ok_or(HostError::failed_to_retrieve("send packet sequence"))
+    }
+
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &path_v2::CommitmentPath,
+    ) -> Result<PacketCommitment, HostError> {
+        self.packet_commitment_store_v2
+            .get(
+                StoreHeight::Pending,
+                &PacketPathV2(commitment_path.to_bytes()),
+            )
+            .ok_or(HostError::missing_state("packet commitment"))
+    }
+
+    fn get_packet_receipt_v2(
+        &self,
+        receipt_path: &path_v2::ReceiptPath,
+    ) -> Result<Receipt, HostError> {
+        if self
+            .packet_receipt_store_v2
+            .is_path_set(StoreHeight::Pending, &PacketPathV2(receipt_path.to_bytes()))
+        {
+            Ok(Receipt::Ok)
+        } else {
+            Ok(Receipt::None)
+        }
+    }
+
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &path_v2::AckPath,
+    ) -> Result<AcknowledgementCommitment, HostError> {
+        self.packet_ack_store_v2
+            .get(StoreHeight::Pending, &PacketPathV2(ack_path.to_bytes()))
+            .ok_or(HostError::failed_to_retrieve(format!(
+                "packet acknowledgment `{}`",
+                ack_path.sequence
+            )))
+    }
+
+    fn channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, HostError> {
+        Ok(self
+            .channel_upgrade_store
+            .get(StoreHeight::Pending, upgrade_path))
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, HostError> {
+        Ok(self
+            .counterparty_channel_upgrade_store
+            .get(StoreHeight::Pending, upgrade_path))
+    }
+
+    fn channel_upgrade_error(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<Option<ErrorReceipt>, HostError> {
+        Ok(self
+            .channel_upgrade_error_store
+            .get(StoreHeight::Pending, upgrade_error_path))
+    }
+
+    fn packet_commitment_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, HostError> {
+        Ok(self
+            .packet_commitments(channel_end_path)?
+            .into_iter()
+            .map(|packet_state| packet_state.seq)
+            .collect())
+    }
+
+    fn channel_upgrade_timeout(&self) -> Result<UpgradeTimeout, HostError> {
+        Ok(self.channel_upgrade_timeout)
+    }
+
+    fn is_channel_upgrade_authority(&self, signer: &Signer) -> Result<bool, HostError> {
+        Ok(signer == &self.channel_upgrade_authority)
+    }
+
     /// Returns a counter of the number of channel ids that have been created thus far.
     /// The value of this counter should increase only via the
     /// `ChannelKeeper::increase_channel_counter` method.
@@ -332,6 +460,11 @@ where
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Returns the client types supported by [`AnyClientState`].
+    fn allowed_clients(&self) -> Result<Vec<ClientType>, HostError> {
+        Ok(vec![tm_client_type(), mock_client_type()])
+    }
+
     /// Returns all the IBC connection ends of a chain.
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, HostError> {
         let path = "connections".to_owned().into();
@@ -662,10 +795,15 @@ where
     fn store_packet_receipt(
         &mut self,
         receipt_path: &ReceiptPath,
-        _receipt: Receipt,
+        receipt: Receipt,
     ) -> Result<(), HostError> {
+        let Some(receipt_bytes) = receipt.as_bytes() else {
+            return Err(HostError::invalid_state(
+                "cannot store an empty packet receipt",
+            ));
+        };
         self.packet_receipt_store
-            .set_path(receipt_path.clone())
+            .set(receipt_path.clone(), receipt_bytes.to_vec())
             .map_err(|e| HostError::failed_to_store(format!("packet receipt: {e:?}")))?;
         Ok(())
     }
@@ -686,6 +824,82 @@ where
         Ok(())
     }
 
+    fn store_pending_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError> {
+        self.pending_acknowledgements
+            .lock()
+            .insert(ack_path.clone());
+        Ok(())
+    }
+
+    fn delete_pending_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError> {
+        self.pending_acknowledgements.lock().remove(ack_path);
+        Ok(())
+    }
+
+    fn store_client_counterparty(
+        &mut self,
+        client_id: &ClientId,
+        counterparty: CounterpartyInfo,
+    ) -> Result<(), HostError> {
+        self.client_counterparty_store
+            .set(ClientCounterpartyPath(client_id.clone()), counterparty)
+            .map_err(|e| HostError::failed_to_store(format!("client counterparty: {e:?}")))?;
+        Ok(())
+    }
+
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &path_v2::SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.send_sequence_store_v2
+            .set(seq_send_path.clone(), seq)
+            .map_err(|e| HostError::failed_to_store(format!("next send sequence: {e:?}")))?;
+        Ok(())
+    }
+
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &path_v2::CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), HostError> {
+        self.packet_commitment_store_v2
+            .set(PacketPathV2(commitment_path.to_bytes()), commitment)
+            .map_err(|e| HostError::failed_to_store(format!("packet commitment: {e:?}")))?;
+        Ok(())
+    }
+
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &path_v2::CommitmentPath,
+    ) -> Result<(), HostError> {
+        self.packet_commitment_store_v2
+            .delete(PacketPathV2(commitment_path.to_bytes()));
+        Ok(())
+    }
+
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &path_v2::ReceiptPath,
+        _receipt: Receipt,
+    ) -> Result<(), HostError> {
+        self.packet_receipt_store_v2
+            .set_path(PacketPathV2(receipt_path.to_bytes()))
+            .map_err(|e| HostError::failed_to_store(format!("packet receipt: {e:?}")))?;
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &path_v2::AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), HostError> {
+        self.packet_ack_store_v2
+            .set(PacketPathV2(ack_path.to_bytes()), ack_commitment)
+            .map_err(|e| HostError::failed_to_store(format!("packet acknowledgment: {e:?}")))?;
+        Ok(())
+    }
+
     fn store_channel(
         &mut self,
         channel_end_path: &ChannelEndPath,
@@ -732,6 +946,58 @@ where
         Ok(())
     }
 
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), HostError> {
+        self.channel_upgrade_store
+            .set(upgrade_path.clone(), upgrade)
+            .map_err(|e| HostError::failed_to_store(format!("channel upgrade: {e:?}")))?;
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), HostError> {
+        self.channel_upgrade_store.delete(upgrade_path.clone());
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), HostError> {
+        self.counterparty_channel_upgrade_store
+            .set(upgrade_path.clone(), upgrade)
+            .map_err(|e| {
+                HostError::failed_to_store(format!("counterparty channel upgrade: {e:?}"))
+            })?;
+        Ok(())
+    }
+
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyChannelUpgradePath,
+    ) -> Result<(), HostError> {
+        self.counterparty_channel_upgrade_store
+            .delete(upgrade_path.clone());
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), HostError> {
+        self.channel_upgrade_error_store
+            .set(upgrade_error_path.clone(), error_receipt)
+            .map_err(|e| HostError::failed_to_store(format!("channel upgrade error: {e:?}")))?;
+        Ok(())
+    }
+
     fn increase_channel_counter(&mut self) -> Result<(), HostError> {
         let current_sequence = self
             .channel_counter
@@ -754,3 +1020,39 @@ where
         Ok(())
     }
 }
+
+/// Stages the state transitions by applying those written so far, which
+/// [`Store::reset`] reverts to, and keeps track of the state held outside of
+/// the store.
+impl<S> TransactionalExecutionContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn begin_staging(&mut self) {
+        self.store.apply().expect("no error");
+
+        *self.staged.lock() = Some(StagedState {
+            pending_acknowledgements: self.pending_acknowledgements.lock().clone(),
+            events: self.events.lock().len(),
+            logs: self.logs.lock().len(),
+        });
+    }
+
+    fn commit_staged(&mut self) {
+        self.store.apply().expect("no error");
+
+        *self.staged.lock() = None;
+    }
+
+    fn discard_staged(&mut self) {
+        let Some(staged) = self.staged.lock().take() else {
+            return;
+        };
+
+        self.store.reset();
+
+        *self.pending_acknowledgements.lock() = staged.pending_acknowledgements;
+        self.events.lock().truncate(staged.events);
+        self.logs.lock().truncate(staged.logs);
+    }
+}
@@ -1,15 +1,18 @@
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 
 use ibc::core::host::types::identifiers::PortId;
+use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
-use ibc::core::router::router::Router;
+use ibc::core::router::router::{Router, TransactionalRouter};
 use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::v2::ModuleV2;
 
-use super::types::MockRouter;
+use super::types::{MockRouter, StagedModules};
 
 impl Router for MockRouter {
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
-        self.router.get(module_id).map(Arc::as_ref)
+        self.router.get(module_id).map(|module| module.as_module())
     }
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
         // NOTE: The following:
@@ -20,7 +23,7 @@ impl Router for MockRouter {
 
         match self.router.get_mut(module_id) {
             Some(arc_mod) => match Arc::get_mut(arc_mod) {
-                Some(m) => Some(m),
+                Some(m) => Some(m.as_module_mut()),
                 None => None,
             },
             None => None,
@@ -30,4 +33,84 @@ impl Router for MockRouter {
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
         self.port_to_module.get(port_id).cloned()
     }
+
+    fn get_route_v2(&self, module_id: &ModuleId) -> Option<&dyn ModuleV2> {
+        self.router_v2
+            .get(module_id)
+            .map(|module| module.as_module())
+    }
+
+    fn get_route_v2_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn ModuleV2> {
+        match self.router_v2.get_mut(module_id) {
+            Some(arc_mod) => match Arc::get_mut(arc_mod) {
+                Some(m) => Some(m.as_module_mut()),
+                None => None,
+            },
+            None => None,
+        }
+    }
+}
+
+/// Stages the state transitions of the modules by keeping copies of them, to
+/// be restored if the state transitions are discarded.
+impl TransactionalRouter for MockRouter {
+    fn begin_staging(&mut self) {
+        self.staged = Some(StagedModules {
+            router: self
+                .router
+                .iter()
+                .map(|(module_id, module)| (module_id.clone(), module.staged_copy()))
+                .collect(),
+            router_v2: self
+                .router_v2
+                .iter()
+                .map(|(module_id, module)| (module_id.clone(), module.staged_copy()))
+                .collect(),
+        });
+    }
+
+    fn staged_keys(&self) -> Vec<(ModuleId, Vec<u8>)> {
+        let Some(staged) = &self.staged else {
+            return Vec::new();
+        };
+
+        let states = self
+            .router
+            .iter()
+            .map(|(module_id, module)| {
+                let staged_state = staged.router.get(module_id).map(|m| m.state());
+                (module_id, module.state(), staged_state)
+            })
+            .chain(self.router_v2.iter().map(|(module_id, module)| {
+                let staged_state = staged.router_v2.get(module_id).map(|m| m.state());
+                (module_id, module.state(), staged_state)
+            }));
+
+        let mut keys = BTreeSet::new();
+
+        for (module_id, state, staged_state) in states {
+            let staged_state = staged_state.unwrap_or_default();
+
+            keys.extend(
+                state
+                    .keys()
+                    .chain(staged_state.keys())
+                    .filter(|key| state.get(*key) != staged_state.get(*key))
+                    .map(|key| (module_id.clone(), key.as_bytes().to_vec())),
+            );
+        }
+
+        keys.into_iter().collect()
+    }
+
+    fn commit_staged(&mut self) {
+        self.staged = None;
+    }
+
+    fn discard_staged(&mut self) {
+        if let Some(staged) = self.staged.take() {
+            self.router = staged.router;
+            self.router_v2 = staged.router_v2;
+        }
+    }
 }
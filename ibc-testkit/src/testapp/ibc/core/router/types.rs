@@ -1,19 +1,104 @@
 use alloc::sync::Arc;
+use core::any::Any;
 
 use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::host::types::identifiers::PortId;
 use ibc::core::primitives::prelude::*;
+use ibc::core::router::middleware::StackBuilder;
 use ibc::core::router::module::Module;
 use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::v2::ModuleV2;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
+/// The state of a module registered with the [`MockRouter`], as the entries
+/// of a key-value store, from which the router tells the keys written to by
+/// the state transitions it stages.
+pub trait ModuleState {
+    /// Returns the entries of the state of the module.
+    ///
+    /// Defaults to no entries, for modules without state.
+    fn state(&self) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
+}
+
+/// A module which the [`MockRouter`] can copy, to stage the state transitions
+/// made by a batch of messages.
+pub trait StagedModule: Module + ModuleState {
+    /// Returns a copy of the module.
+    fn staged_copy(&self) -> Arc<dyn StagedModule>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_module(&self) -> &dyn Module;
+
+    fn as_module_mut(&mut self) -> &mut dyn Module;
+}
+
+impl<M: Module + ModuleState + Clone + 'static> StagedModule for M {
+    fn staged_copy(&self) -> Arc<dyn StagedModule> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_module(&self) -> &dyn Module {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn Module {
+        self
+    }
+}
+
+/// A module handling IBC v2 packets which the [`MockRouter`] can copy, to
+/// stage the state transitions made by a batch of messages.
+pub trait StagedModuleV2: ModuleV2 + ModuleState {
+    /// Returns a copy of the module.
+    fn staged_copy(&self) -> Arc<dyn StagedModuleV2>;
+
+    fn as_module(&self) -> &dyn ModuleV2;
+
+    fn as_module_mut(&mut self) -> &mut dyn ModuleV2;
+}
+
+impl<M: ModuleV2 + ModuleState + Clone + 'static> StagedModuleV2 for M {
+    fn staged_copy(&self) -> Arc<dyn StagedModuleV2> {
+        Arc::new(self.clone())
+    }
+
+    fn as_module(&self) -> &dyn ModuleV2 {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn ModuleV2 {
+        self
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct MockRouter {
-    pub router: BTreeMap<ModuleId, Arc<dyn Module>>,
+    pub router: BTreeMap<ModuleId, Arc<dyn StagedModule>>,
+
+    /// Modules handling IBC v2 packets
+    pub router_v2: BTreeMap<ModuleId, Arc<dyn StagedModuleV2>>,
 
     /// Maps ports to the module that owns it
     pub port_to_module: BTreeMap<PortId, ModuleId>,
+
+    /// Copies of the modules as of the start of staging, which are restored
+    /// if the staged state transitions are discarded
+    pub staged: Option<StagedModules>,
+}
+
+/// Copies of the modules registered with a [`MockRouter`].
+#[derive(Debug)]
+pub struct StagedModules {
+    pub router: BTreeMap<ModuleId, Arc<dyn StagedModule>>,
+    pub router_v2: BTreeMap<ModuleId, Arc<dyn StagedModuleV2>>,
 }
 
 impl MockRouter {
@@ -27,7 +112,11 @@ impl MockRouter {
         let transfer_mod = DummyTransferModule::new();
 
         router
-            .add_route(module_id, transfer_mod)
+            .add_route(module_id.clone(), transfer_mod)
+            .expect("Never fails");
+
+        router
+            .add_route_v2(module_id, DummyTransferModule::new())
             .expect("Never fails");
 
         router
@@ -36,7 +125,7 @@ impl MockRouter {
     pub fn add_route(
         &mut self,
         module_id: ModuleId,
-        module: impl Module + 'static,
+        module: impl StagedModule + 'static,
     ) -> Result<(), String> {
         match self.router.insert(module_id, Arc::new(module)) {
             None => Ok(()),
@@ -44,6 +133,37 @@ impl MockRouter {
         }
     }
 
+    pub fn add_route_v2(
+        &mut self,
+        module_id: ModuleId,
+        module: impl StagedModuleV2 + 'static,
+    ) -> Result<(), String> {
+        match self.router_v2.insert(module_id, Arc::new(module)) {
+            None => Ok(()),
+            Some(_) => Err("Duplicate module_id".to_owned()),
+        }
+    }
+
+    /// Returns the module registered against the given `ModuleId`, if it is
+    /// of type `M`.
+    pub fn module<M: Module + 'static>(&self, module_id: &ModuleId) -> Option<&M> {
+        self.router.get(module_id)?.as_any().downcast_ref()
+    }
+
+    /// Registers a middleware stack, and binds it to the port of the stack.
+    pub fn add_stack<M: StagedModule + 'static>(
+        &mut self,
+        module_id: ModuleId,
+        stack: StackBuilder<M>,
+    ) -> Result<(), String> {
+        let (port_id, module) = stack.build();
+
+        self.add_route(module_id.clone(), module)?;
+        self.scope_port_to_module(port_id, module_id);
+
+        Ok(())
+    }
+
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
         self.port_to_module.insert(port_id, module_id);
     }
@@ -1,7 +1,8 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display, Formatter};
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
@@ -9,21 +10,27 @@ use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedS
 use bon::builder;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeTimeout};
 use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::proto::v2::CounterpartyInfo as RawCounterpartyInfo;
+use ibc::core::client::types::{CounterpartyInfo, Height};
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
-    ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
-    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    v2 as path_v2, AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath,
+    ClientUpdateTimePath, CommitmentPath, ConnectionPath, CounterpartyChannelUpgradePath,
+    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, PathBytes,
+    ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::core::channel::v1::Channel as RawChannelEnd;
+use ibc_proto::ibc::core::channel::v1::{
+    Channel as RawChannelEnd, ErrorReceipt as RawErrorReceipt, Upgrade as RawUpgrade,
+};
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
 use ibc_proto::ics23::CommitmentProof;
@@ -31,13 +38,47 @@ use parking_lot::Mutex;
 
 use crate::context::{MockStore, TestContext};
 use crate::fixtures::core::context::dummy_store_generic_test_context;
+use crate::fixtures::core::signer::dummy_account_id;
 use crate::hosts::{HostClientState, TestBlock, TestHeader, TestHost};
 use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
+/// The default timeout after which channel upgrades time out, relative to
+/// the block at which flushing starts.
+pub const DEFAULT_CHANNEL_UPGRADE_TIMEOUT_SECS: u64 = 600;
+
 pub type DefaultIbcStore = MockIbcStore<MockStore>;
 
+/// Store key of the counterparty registered for a client.
+#[derive(Clone, Debug, derive_more::Display)]
+#[display("clients/{_0}/counterparty")]
+pub struct ClientCounterpartyPath(pub ClientId);
+
+/// Store key of the IBC v2 packet paths, which are made of raw bytes rather
+/// than strings, and are therefore hex encoded under the `v2` prefix.
+#[derive(Clone, Debug)]
+pub struct PacketPathV2(pub PathBytes);
+
+impl Display for PacketPathV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "v2/")?;
+        for byte in self.0.as_ref() {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The state of a [`MockIbcStore`] kept outside of its store, as of the start
+/// of staging.
+#[derive(Debug)]
+pub struct StagedState {
+    pub pending_acknowledgements: BTreeSet<AckPath>,
+    pub events: usize,
+    pub logs: usize,
+}
+
 /// An object that stores all IBC related data.
 #[derive(Debug)]
 pub struct MockIbcStore<S>
@@ -81,10 +122,40 @@ where
     pub ack_sequence_store: JsonStore<SharedStore<S>, SeqAckPath, Sequence>,
     /// A typed-store for packet commitments
     pub packet_commitment_store: BinStore<SharedStore<S>, CommitmentPath, PacketCommitment>,
-    /// A typed-store for packet receipts
-    pub packet_receipt_store: TypedSet<SharedStore<S>, ReceiptPath>,
+    /// A typed-store for packet receipts, keeping the bytes of each receipt
+    pub packet_receipt_store: BinStore<SharedStore<S>, ReceiptPath, Vec<u8>>,
     /// A typed-store for packet ack
     pub packet_ack_store: BinStore<SharedStore<S>, AckPath, AcknowledgementCommitment>,
+    /// A typed-store for the counterparties registered for IBC v2
+    pub client_counterparty_store: ProtobufStore<
+        SharedStore<S>,
+        ClientCounterpartyPath,
+        CounterpartyInfo,
+        RawCounterpartyInfo,
+    >,
+    /// A typed-store for IBC v2 send sequences
+    pub send_sequence_store_v2: JsonStore<SharedStore<S>, path_v2::SeqSendPath, Sequence>,
+    /// A typed-store for IBC v2 packet commitments
+    pub packet_commitment_store_v2: BinStore<SharedStore<S>, PacketPathV2, PacketCommitment>,
+    /// A typed-store for IBC v2 packet receipts
+    pub packet_receipt_store_v2: TypedSet<SharedStore<S>, PacketPathV2>,
+    /// A typed-store for IBC v2 packet acks
+    pub packet_ack_store_v2: BinStore<SharedStore<S>, PacketPathV2, AcknowledgementCommitment>,
+    /// A typed-store for channel upgrades
+    pub channel_upgrade_store:
+        ProtobufStore<SharedStore<S>, ChannelUpgradePath, Upgrade, RawUpgrade>,
+    /// A typed-store for the upgrades proposed by the counterparty
+    pub counterparty_channel_upgrade_store:
+        ProtobufStore<SharedStore<S>, CounterpartyChannelUpgradePath, Upgrade, RawUpgrade>,
+    /// A typed-store for channel upgrade error receipts
+    pub channel_upgrade_error_store:
+        ProtobufStore<SharedStore<S>, ChannelUpgradeErrorPath, ErrorReceipt, RawErrorReceipt>,
+    /// The acknowledgements to be written asynchronously
+    pub pending_acknowledgements: Arc<Mutex<BTreeSet<AckPath>>>,
+    /// The relative timeout of channel upgrades
+    pub channel_upgrade_timeout: UpgradeTimeout,
+    /// The signer allowed to initiate and cancel channel upgrades
+    pub channel_upgrade_authority: Signer,
     /// Map of host consensus states
     pub host_consensus_states: Arc<Mutex<BTreeMap<u64, AnyConsensusState>>>,
     /// Map of older ibc commitment proofs
@@ -93,6 +164,9 @@ where
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
     pub logs: Arc<Mutex<Vec<String>>>,
+    /// The state to restore if staged state transitions are discarded, while
+    /// staging
+    pub staged: Arc<Mutex<Option<StagedState>>>,
 }
 
 impl<S> MockIbcStore<S>
@@ -138,8 +212,25 @@ where
             packet_commitment_store: TypedStore::new(shared_store.clone()),
             packet_receipt_store: TypedStore::new(shared_store.clone()),
             packet_ack_store: TypedStore::new(shared_store.clone()),
+            client_counterparty_store: TypedStore::new(shared_store.clone()),
+            send_sequence_store_v2: TypedStore::new(shared_store.clone()),
+            packet_commitment_store_v2: TypedStore::new(shared_store.clone()),
+            packet_receipt_store_v2: TypedStore::new(shared_store.clone()),
+            packet_ack_store_v2: TypedStore::new(shared_store.clone()),
+            channel_upgrade_store: TypedStore::new(shared_store.clone()),
+            counterparty_channel_upgrade_store: TypedStore::new(shared_store.clone()),
+            channel_upgrade_error_store: TypedStore::new(shared_store.clone()),
+            pending_acknowledgements: Arc::new(Mutex::new(BTreeSet::new())),
+            channel_upgrade_timeout: UpgradeTimeout::new(
+                TimeoutHeight::Never,
+                TimeoutTimestamp::At(Timestamp::from_nanoseconds(
+                    DEFAULT_CHANNEL_UPGRADE_TIMEOUT_SECS * 1_000_000_000,
+                )),
+            ),
+            channel_upgrade_authority: dummy_account_id(),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            staged: Arc::new(Mutex::new(None)),
             store: shared_store,
         }
     }
@@ -217,15 +308,17 @@ mod tests {
     use super::*;
     use crate::fixtures::core::channel::dummy_packet;
     use crate::fixtures::core::signer::dummy_bech32_account;
-    use crate::testapp::ibc::core::router::MockRouter;
+    use crate::testapp::ibc::core::router::{MockRouter, ModuleState};
 
     #[test]
     fn test_router() {
-        #[derive(Debug, Default)]
+        #[derive(Clone, Debug, Default)]
         struct FooModule {
             counter: u64,
         }
 
+        impl ModuleState for FooModule {}
+
         impl Module for FooModule {
             fn on_chan_open_init_validate(
                 &self,
@@ -323,9 +416,11 @@ mod tests {
             }
         }
 
-        #[derive(Debug, Default)]
+        #[derive(Clone, Debug, Default)]
         struct BarModule;
 
+        impl ModuleState for BarModule {}
+
         impl Module for BarModule {
             fn on_chan_open_init_validate(
                 &self,
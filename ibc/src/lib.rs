@@ -85,6 +85,10 @@ pub mod derive {
     /// - `#[validation(Context<S>)]`
     /// - `#[validation(Context<'a, S>)]`
     /// - `#[validation(Context<'a, S: Clone>)]`
+    /// - `#[validation(Context<'a, Store<S: Clone>>)]`
+    ///
+    /// Each attribute may be given more than once, to implement the
+    /// corresponding trait for several contexts.
     pub use ibc_derive::IbcClientState as ClientState;
     /// A derive macro for implementing the
     /// [`ConsensusState`](crate::core::client::context::consensus_state::ConsensusState)
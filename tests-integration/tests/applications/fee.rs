@@ -0,0 +1,288 @@
+use ibc::apps::fee::module::FeeMiddleware;
+use ibc::apps::fee::types::{Fee, IncentivizedAcknowledgement, Metadata, PacketFee};
+use ibc::apps::transfer::types::{BaseCoin, VERSION as TRANSFER_VERSION};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::core::channel::dummy_packet;
+use ibc_testkit::testapp::ibc::applications::fee::types::DummyFeeContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+type FeeTransferModule = FeeMiddleware<DummyTransferModule, DummyFeeContext>;
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+fn coins(coins: &str) -> Vec<BaseCoin> {
+    BaseCoin::from_string_list(coins).expect("valid coins")
+}
+
+fn fee_version() -> Version {
+    Version::new(serde_json::to_string(&Metadata::new(TRANSFER_VERSION.to_string())).unwrap())
+}
+
+fn fee_enabled_module() -> FeeTransferModule {
+    FeeMiddleware::new(
+        DummyTransferModule::new(),
+        DummyFeeContext::new().with_fee_enabled(PortId::transfer(), ChannelId::zero()),
+    )
+}
+
+fn packet_fee() -> PacketFee {
+    PacketFee::new(
+        Fee::new(coins("10stake"), coins("5stake"), coins("3stake")).unwrap(),
+        signer("cosmos1refund"),
+        vec![],
+    )
+}
+
+#[test]
+fn test_on_chan_open_init_wraps_version() {
+    let mut module = FeeMiddleware::new(DummyTransferModule::new(), DummyFeeContext::new());
+
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(1);
+    let counterparty = Counterparty::new(port_id.clone(), None);
+
+    let (_, out_version) = module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &[ConnectionId::new(1)],
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &fee_version(),
+        )
+        .unwrap();
+
+    assert_eq!(out_version, fee_version());
+    assert!(module
+        .ctx()
+        .fee_enabled
+        .contains_key(&(port_id, channel_id)));
+}
+
+#[test]
+fn test_on_chan_open_init_without_fee() {
+    let mut module = FeeMiddleware::new(DummyTransferModule::new(), DummyFeeContext::new());
+
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(1);
+    let in_version = Version::new(TRANSFER_VERSION.to_string());
+
+    let (_, out_version) = module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &[ConnectionId::new(1)],
+            &port_id,
+            &channel_id,
+            &Counterparty::new(port_id.clone(), None),
+            &in_version,
+        )
+        .unwrap();
+
+    assert_eq!(out_version, in_version);
+    assert!(module.ctx().fee_enabled.is_empty());
+}
+
+#[test]
+fn test_on_chan_open_try_rejects_invalid_fee_version() {
+    let module = FeeMiddleware::new(DummyTransferModule::new(), DummyFeeContext::new());
+
+    let port_id = PortId::transfer();
+    let metadata = Metadata {
+        fee_version: "ics29-2".to_string(),
+        app_version: TRANSFER_VERSION.to_string(),
+    };
+
+    let res = module.on_chan_open_try_validate(
+        Order::Unordered,
+        &[ConnectionId::new(1)],
+        &port_id,
+        &ChannelId::new(1),
+        &Counterparty::new(port_id.clone(), Some(ChannelId::new(1))),
+        &Version::new(serde_json::to_string(&metadata).unwrap()),
+    );
+
+    assert!(res.is_err());
+}
+
+/// Fees are enabled on an existing transfer channel by upgrading it to a
+/// version wrapped with fee metadata.
+#[test]
+fn test_on_chan_upgrade_enables_fee_on_transfer_channel() {
+    let mut module = FeeMiddleware::new(DummyTransferModule::new(), DummyFeeContext::new());
+
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(1);
+    let connection_hops = [ConnectionId::new(1)];
+
+    let version = module
+        .on_chan_upgrade_init_validate(
+            &port_id,
+            &channel_id,
+            Order::Unordered,
+            &connection_hops,
+            &fee_version(),
+        )
+        .unwrap();
+
+    assert_eq!(version, fee_version());
+
+    module.on_chan_upgrade_open_execute(
+        &port_id,
+        &channel_id,
+        Order::Unordered,
+        &connection_hops,
+        &version,
+    );
+
+    assert!(module
+        .ctx()
+        .fee_enabled
+        .contains_key(&(port_id.clone(), channel_id.clone())));
+
+    // The transfer application still validates the wrapped version.
+    let metadata = Metadata::new("some-unsupported-version".to_string());
+
+    assert!(module
+        .on_chan_upgrade_init_validate(
+            &port_id,
+            &channel_id,
+            Order::Unordered,
+            &connection_hops,
+            &Version::new(serde_json::to_string(&metadata).unwrap()),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_on_chan_open_ack_requires_metadata_on_fee_enabled_channel() {
+    let module = fee_enabled_module();
+
+    let res = module.on_chan_open_ack_validate(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &Version::new(TRANSFER_VERSION.to_string()),
+    );
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_on_recv_packet_wraps_acknowledgement() {
+    let mut module = fee_enabled_module();
+
+    let relayer = signer("cosmos1relayer");
+    module
+        .ctx_mut()
+        .counterparty_payees
+        .insert((ChannelId::zero(), relayer.clone()), signer("cosmos1payee"));
+
+    let (_, ack) = module.on_recv_packet_execute(&dummy_packet().call(), &relayer);
+
+    let ack: IncentivizedAcknowledgement =
+        serde_json::from_slice(ack.expect("acknowledgement is written").as_bytes()).unwrap();
+
+    assert_eq!(ack.forward_relayer_address, signer("cosmos1payee"));
+    assert!(ack.underlying_app_success);
+    assert_eq!(
+        ack.app_acknowledgement,
+        Acknowledgement::try_from(vec![1u8]).unwrap()
+    );
+}
+
+#[test]
+fn test_on_acknowledgement_packet_distributes_fees() {
+    let mut module = fee_enabled_module();
+
+    let packet = dummy_packet().seq_on_a(Sequence::from(1)).call();
+    let packet_id = PacketId::from(&packet);
+    module
+        .ctx_mut()
+        .fees_in_escrow
+        .insert(packet_id.clone(), vec![packet_fee()]);
+
+    let ack = IncentivizedAcknowledgement::new(
+        Acknowledgement::try_from(vec![1u8]).unwrap(),
+        signer("cosmos1forward"),
+        true,
+    );
+    let ack = Acknowledgement::try_from(serde_json::to_vec(&ack).unwrap()).unwrap();
+
+    module
+        .on_acknowledgement_packet_validate(&packet, &ack, &signer("cosmos1reverse"))
+        .unwrap();
+
+    let (extras, res) =
+        module.on_acknowledgement_packet_execute(&packet, &ack, &signer("cosmos1reverse"));
+
+    assert!(res.is_ok());
+    assert_eq!(extras.events.len(), 3);
+
+    let distributed = &module.ctx().distributed;
+    assert_eq!(distributed[&signer("cosmos1forward")], coins("10stake"));
+    assert_eq!(distributed[&signer("cosmos1reverse")], coins("5stake"));
+    assert_eq!(distributed[&signer("cosmos1refund")], coins("3stake"));
+    assert!(!module.ctx().fees_in_escrow.contains_key(&packet_id));
+}
+
+#[test]
+fn test_on_timeout_packet_distributes_fees() {
+    let mut module = fee_enabled_module();
+
+    let packet = dummy_packet().seq_on_a(Sequence::from(1)).call();
+    module
+        .ctx_mut()
+        .fees_in_escrow
+        .insert(PacketId::from(&packet), vec![packet_fee()]);
+
+    let relayer = signer("cosmos1relayer");
+    module
+        .ctx_mut()
+        .payees
+        .insert((ChannelId::zero(), relayer.clone()), signer("cosmos1payee"));
+
+    let (_, res) = module.on_timeout_packet_execute(&packet, &relayer);
+
+    assert!(res.is_ok());
+
+    let distributed = &module.ctx().distributed;
+    assert_eq!(distributed[&signer("cosmos1payee")], coins("3stake"));
+    assert_eq!(distributed[&signer("cosmos1refund")], coins("15stake"));
+}
+
+#[test]
+fn test_async_acknowledgement_is_wrapped() {
+    let mut module = fee_enabled_module();
+
+    let packet = dummy_packet().seq_on_a(Sequence::from(1)).call();
+    let relayer = signer("cosmos1relayer");
+
+    // The relayer is recorded when the application acknowledges the packet
+    // asynchronously.
+    let packet_id = PacketId::new(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a,
+    );
+    module
+        .ctx_mut()
+        .async_ack_relayers
+        .insert(packet_id.clone(), relayer);
+
+    let ack = module
+        .wrap_async_acknowledgement(&packet, Acknowledgement::try_from(vec![1u8]).unwrap())
+        .unwrap();
+
+    let ack: IncentivizedAcknowledgement = serde_json::from_slice(ack.as_bytes()).unwrap();
+
+    assert!(ack.forward_relayer_address.as_ref().is_empty());
+    assert!(!module.ctx().async_ack_relayers.contains_key(&packet_id));
+}
@@ -0,0 +1,355 @@
+use ibc::apps::interchain_accounts::controller::handler::{
+    register_interchain_account_execute, register_interchain_account_validate, send_tx,
+};
+use ibc::apps::interchain_accounts::types::msgs::{MsgRegisterInterchainAccount, MsgSendTx};
+use ibc::apps::interchain_accounts::types::{
+    controller_port_id, host_port_id, CosmosTx, InterchainAccountPacketData, Metadata,
+};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::{Signer, ZERO_DURATION};
+use ibc::core::router::module::Module;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_packet;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::interchain_accounts::types::{
+    DummyIcaControllerModule, DummyIcaHostModule,
+};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+
+const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+fn owner() -> Signer {
+    Signer::from("cosmos1owner".to_string())
+}
+
+fn metadata() -> Metadata {
+    Metadata::new(ConnectionId::zero(), ConnectionId::zero())
+}
+
+fn version(metadata: &Metadata) -> Version {
+    Version::new(serde_json::to_string(metadata).unwrap())
+}
+
+fn cosmos_tx(type_url: &str) -> CosmosTx {
+    CosmosTx::new(vec![Any {
+        type_url: type_url.to_string(),
+        value: vec![1, 2, 3],
+    }])
+}
+
+/// A controller chain with an open connection to the host chain.
+fn controller_ctx() -> MockContext {
+    let client_id = mock_client_type().build_client_id(0);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end)
+}
+
+/// A controller chain where the owner's interchain account is registered and
+/// its channel is open.
+fn registered_controller() -> (MockContext, DummyIcaControllerModule) {
+    let port_id = controller_port_id(&owner()).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        Counterparty::new(host_port_id(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        version(&metadata()),
+    )
+    .unwrap();
+
+    let ctx = controller_ctx()
+        .with_channel(port_id.clone(), ChannelId::zero(), chan_end)
+        .with_send_sequence(port_id.clone(), ChannelId::zero(), Sequence::from(1));
+
+    let mut module = DummyIcaControllerModule::new();
+    module
+        .active_channels
+        .insert((ConnectionId::zero(), port_id), ChannelId::zero());
+
+    (ctx, module)
+}
+
+/// A host chain where the owner's interchain account was created over the
+/// channel `channel-0`.
+fn registered_host() -> DummyIcaHostModule {
+    let mut module = DummyIcaHostModule::new();
+
+    let (_, version) = module
+        .on_chan_open_try_execute(
+            Order::Ordered,
+            &[ConnectionId::zero()],
+            &host_port_id(),
+            &ChannelId::zero(),
+            &Counterparty::new(
+                controller_port_id(&owner()).unwrap(),
+                Some(ChannelId::zero()),
+            ),
+            &version(&metadata()),
+        )
+        .unwrap();
+
+    assert!(version.as_str().contains(r#""address":"ica0""#));
+
+    module
+}
+
+fn recv_packet(
+    module: &mut DummyIcaHostModule,
+    packet_data: &InterchainAccountPacketData,
+) -> AcknowledgementStatus {
+    let packet = dummy_packet()
+        .port_id_on_a(controller_port_id(&owner()).unwrap())
+        .port_id_on_b(host_port_id())
+        .data(serde_json::to_vec(packet_data).unwrap())
+        .call();
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &Signer::from("relayer".to_string()));
+
+    serde_json::from_slice(ack.unwrap().as_bytes()).unwrap()
+}
+
+#[test]
+fn test_register_interchain_account_opens_channel() {
+    let mut ctx = controller_ctx();
+    let mut module = DummyIcaControllerModule::new();
+
+    let msg = MsgRegisterInterchainAccount {
+        owner: owner(),
+        connection_id: ConnectionId::zero(),
+        version: Version::empty(),
+        ordering: Order::Ordered,
+    };
+
+    register_interchain_account_validate(&ctx.ibc_store, &module, &module, msg.clone()).unwrap();
+    let channel_id =
+        register_interchain_account_execute(&mut ctx.ibc_store, &mut module, msg).unwrap();
+
+    assert_eq!(channel_id, ChannelId::zero());
+
+    let port_id = controller_port_id(&owner()).unwrap();
+    let chan_end = ctx
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&port_id, &channel_id))
+        .unwrap();
+
+    assert_eq!(chan_end.state, State::Init);
+    assert_eq!(chan_end.counterparty().port_id(), &host_port_id());
+    assert_eq!(chan_end.version(), &version(&metadata()));
+}
+
+#[test]
+fn test_register_interchain_account_rejects_active_channel() {
+    let (ctx, module) = registered_controller();
+
+    let msg = MsgRegisterInterchainAccount {
+        owner: owner(),
+        connection_id: ConnectionId::zero(),
+        version: Version::empty(),
+        ordering: Order::Ordered,
+    };
+
+    let res = register_interchain_account_validate(&ctx.ibc_store, &module, &module, msg);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_channel_handshake_records_interchain_account() {
+    let host = registered_host();
+    let mut controller = DummyIcaControllerModule::new();
+
+    let port_id = controller_port_id(&owner()).unwrap();
+
+    controller
+        .on_chan_open_init_execute(
+            Order::Ordered,
+            &[ConnectionId::zero()],
+            &port_id,
+            &ChannelId::zero(),
+            &Counterparty::new(host_port_id(), None),
+            &version(&metadata()),
+        )
+        .unwrap();
+
+    let mut host_metadata = metadata();
+    host_metadata.address = "ica0".to_string();
+
+    controller
+        .on_chan_open_ack_validate(&port_id, &ChannelId::zero(), &version(&host_metadata))
+        .unwrap();
+    controller
+        .on_chan_open_ack_execute(&port_id, &ChannelId::zero(), &version(&host_metadata))
+        .unwrap();
+
+    let key = (ConnectionId::zero(), port_id);
+    assert_eq!(
+        controller.active_channels.get(&key),
+        Some(&ChannelId::zero())
+    );
+    assert_eq!(controller.addresses.get(&key), Some(&"ica0".to_string()));
+    assert_eq!(
+        host.accounts.get(&key).map(ToString::to_string),
+        Some("ica0".to_string())
+    );
+}
+
+#[test]
+fn test_on_chan_open_ack_requires_address() {
+    let mut controller = DummyIcaControllerModule::new();
+
+    let port_id = controller_port_id(&owner()).unwrap();
+    controller
+        .channel_connections
+        .insert((port_id.clone(), ChannelId::zero()), ConnectionId::zero());
+
+    let res =
+        controller.on_chan_open_ack_validate(&port_id, &ChannelId::zero(), &version(&metadata()));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_on_chan_open_try_rejects_non_controller_port() {
+    let host = DummyIcaHostModule::new();
+
+    let res = host.on_chan_open_try_validate(
+        Order::Ordered,
+        &[ConnectionId::zero()],
+        &host_port_id(),
+        &ChannelId::zero(),
+        &Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        &version(&metadata()),
+    );
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_on_chan_open_try_rejects_mismatched_address() {
+    let host = registered_host();
+
+    let mut metadata = metadata();
+    metadata.address = "ica1".to_string();
+
+    let res = host.on_chan_open_try_validate(
+        Order::Ordered,
+        &[ConnectionId::zero()],
+        &host_port_id(),
+        &ChannelId::new(1),
+        &Counterparty::new(
+            controller_port_id(&owner()).unwrap(),
+            Some(ChannelId::new(1)),
+        ),
+        &version(&metadata),
+    );
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_send_tx_commits_packet() {
+    let (mut ctx, module) = registered_controller();
+
+    let msg = MsgSendTx {
+        owner: owner(),
+        connection_id: ConnectionId::zero(),
+        packet_data: InterchainAccountPacketData::execute_tx(cosmos_tx(MSG_SEND), String::new()),
+        relative_timeout: 1_000_000_000,
+    };
+
+    let sequence = send_tx(
+        &mut ctx.ibc_store,
+        &module,
+        &mut DummyIcaControllerModule::new(),
+        msg,
+    )
+    .unwrap();
+
+    assert_eq!(sequence, Sequence::from(1));
+
+    let port_id = controller_port_id(&owner()).unwrap();
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&CommitmentPath::new(&port_id, &ChannelId::zero(), sequence))
+        .is_ok());
+}
+
+#[test]
+fn test_send_tx_requires_active_channel() {
+    let mut ctx = controller_ctx();
+    let module = DummyIcaControllerModule::new();
+
+    let msg = MsgSendTx {
+        owner: owner(),
+        connection_id: ConnectionId::zero(),
+        packet_data: InterchainAccountPacketData::execute_tx(cosmos_tx(MSG_SEND), String::new()),
+        relative_timeout: 1_000_000_000,
+    };
+
+    assert!(send_tx(
+        &mut ctx.ibc_store,
+        &module,
+        &mut DummyIcaControllerModule::new(),
+        msg
+    )
+    .is_err());
+}
+
+#[test]
+fn test_on_recv_packet_executes_tx() {
+    let mut host = registered_host();
+
+    let ack = recv_packet(
+        &mut host,
+        &InterchainAccountPacketData::execute_tx(cosmos_tx(MSG_SEND), String::new()),
+    );
+
+    assert!(ack.is_successful());
+    assert_eq!(host.executed.len(), 1);
+    assert_eq!(host.executed[0].0.as_ref(), "ica0");
+    assert_eq!(host.executed[0].1, cosmos_tx(MSG_SEND).messages);
+}
+
+#[test]
+fn test_on_recv_packet_rejects_disallowed_message() {
+    let mut host = registered_host().with_allow_messages(vec![MSG_SEND.to_string()]);
+
+    let ack = recv_packet(
+        &mut host,
+        &InterchainAccountPacketData::execute_tx(
+            cosmos_tx("/cosmos.staking.v1beta1.MsgDelegate"),
+            String::new(),
+        ),
+    );
+
+    assert!(!ack.is_successful());
+    assert!(host.executed.is_empty());
+}
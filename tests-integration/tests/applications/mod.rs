@@ -1,4 +1,14 @@
 #[cfg(feature = "serde")]
+pub mod fee;
+#[cfg(feature = "serde")]
+pub mod interchain_accounts;
+#[cfg(feature = "serde")]
 pub mod nft_transfer;
 #[cfg(feature = "serde")]
+pub mod packet_forward;
+#[cfg(feature = "serde")]
+pub mod rate_limit;
+#[cfg(feature = "serde")]
 pub mod transfer;
+#[cfg(feature = "serde")]
+pub mod transfer_hooks;
@@ -1,13 +1,26 @@
 use ibc::apps::nft_transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_recv_packet_execute,
 };
-use ibc::apps::nft_transfer::types::VERSION;
+use ibc::apps::nft_transfer::types::packet::PacketData;
+use ibc::apps::nft_transfer::types::{PrefixedClassId, TokenId, TokenIds, VERSION};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_query::apps::nft_transfer::{
+    query_class_hash, query_class_trace, query_class_traces, query_escrow_address,
+    query_nfts_by_owner, QueryClassHashRequest, QueryClassTraceRequest, QueryClassTracesRequest,
+    QueryEscrowAddressRequest, QueryNftsByOwnerRequest,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
+use subtle_encoding::bech32;
 
 fn get_defaults() -> (
     DummyNftTransferModule,
@@ -24,7 +37,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyNftTransferModule,
+        DummyNftTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -133,3 +146,141 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+fn recv_packet(class_id: &str, token_ids: &[&str], receiver: Signer) -> Packet {
+    let token_ids: Vec<String> = token_ids.iter().map(ToString::to_string).collect();
+    let data = PacketData::new(
+        class_id.parse().unwrap(),
+        None,
+        None,
+        TokenIds::try_from(token_ids).unwrap(),
+        vec![],
+        vec![],
+        dummy_account_id(),
+        receiver,
+        "".into(),
+    )
+    .unwrap();
+
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+#[test]
+fn test_query_class_traces() {
+    let mut nft_transfer = DummyNftTransferModule::new();
+
+    let (_, ack) = on_recv_packet_execute(
+        &mut nft_transfer,
+        &recv_packet("class_0", &["token_0"], dummy_account_id()),
+    );
+    assert!(
+        serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+            .unwrap()
+            .is_successful()
+    );
+
+    let voucher_class: PrefixedClassId = "transfer/channel-0/class_0".parse().unwrap();
+
+    let hash = query_class_hash(
+        &nft_transfer,
+        &QueryClassHashRequest {
+            trace: voucher_class.to_string(),
+        },
+    )
+    .unwrap()
+    .hash;
+
+    let class_trace = query_class_trace(
+        &nft_transfer,
+        &QueryClassTraceRequest {
+            hash: format!("ibc/{hash}"),
+        },
+    )
+    .unwrap()
+    .class_trace;
+    assert_eq!(class_trace, voucher_class);
+
+    let class_traces =
+        query_class_traces(&nft_transfer, &QueryClassTracesRequest { pagination: None })
+            .unwrap()
+            .class_traces;
+    assert_eq!(class_traces, vec![voucher_class]);
+
+    assert!(query_class_trace(
+        &nft_transfer,
+        &QueryClassTraceRequest {
+            hash: "ibc/0000".to_string(),
+        },
+    )
+    .is_err());
+}
+
+#[test]
+fn test_query_nfts_by_owner() {
+    let mut nft_transfer = DummyNftTransferModule::new();
+    let owner = dummy_account_id();
+
+    on_recv_packet_execute(
+        &mut nft_transfer,
+        &recv_packet("class_0", &["token_0", "token_1"], owner.clone()),
+    );
+
+    let voucher_class: PrefixedClassId = "transfer/channel-0/class_0".parse().unwrap();
+    let token_id = |id: &str| id.parse::<TokenId>().unwrap();
+
+    let nfts = query_nfts_by_owner(
+        &nft_transfer,
+        &QueryNftsByOwnerRequest {
+            owner: owner.clone(),
+        },
+    )
+    .unwrap()
+    .nfts;
+    assert_eq!(
+        nfts,
+        vec![
+            (voucher_class.clone(), token_id("token_0")),
+            (voucher_class, token_id("token_1")),
+        ]
+    );
+
+    let nfts = query_nfts_by_owner(
+        &nft_transfer,
+        &QueryNftsByOwnerRequest {
+            owner: "cosmos1other".to_string().into(),
+        },
+    )
+    .unwrap()
+    .nfts;
+    assert!(nfts.is_empty());
+}
+
+#[test]
+fn test_query_nft_escrow_address() {
+    let nft_transfer = DummyNftTransferModule::new();
+
+    let escrow_address = query_escrow_address(
+        &nft_transfer,
+        &QueryEscrowAddressRequest {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+        },
+    )
+    .unwrap()
+    .escrow_address;
+
+    let expected = bech32::encode(
+        "cosmos",
+        cosmos_adr028_escrow_address(&PortId::transfer(), &ChannelId::zero()),
+    );
+    assert_eq!(escrow_address.to_string(), expected);
+}
@@ -0,0 +1,300 @@
+use ibc::apps::packet_forward::module::PacketForwardMiddleware;
+use ibc::apps::packet_forward::types::{
+    ForwardMetadata, NextMemo, PacketMetadata, DEFAULT_FORWARD_TIMEOUT_NANOS,
+};
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{PrefixedCoin, Token};
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc_testkit::testapp::ibc::applications::packet_forward::types::DummyPacketForwardContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+type ForwardTransferModule =
+    PacketForwardMiddleware<DummyTransferModule, DummyPacketForwardContext>;
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+fn forward_module() -> ForwardTransferModule {
+    PacketForwardMiddleware::new(DummyTransferModule::new(), DummyPacketForwardContext::new())
+}
+
+fn forward_metadata() -> ForwardMetadata {
+    ForwardMetadata {
+        receiver: signer("cosmos1receiver"),
+        port: PortId::transfer(),
+        channel: ChannelId::new(1),
+        timeout: None,
+        retries: None,
+        next: None,
+    }
+}
+
+fn forward_memo(metadata: ForwardMetadata) -> String {
+    serde_json::to_string(&PacketMetadata { forward: metadata }).unwrap()
+}
+
+/// Returns a packet received on `channel-0` from `channel-5`, carrying the
+/// given coin and memo.
+fn recv_packet(coin: &str, memo: String) -> Packet {
+    let data = PacketData {
+        token: coin.parse().unwrap(),
+        sender: signer("cosmos1sender"),
+        receiver: signer("cosmos1intermediate"),
+        memo: memo.into(),
+    };
+
+    Packet {
+        seq_on_a: Sequence::from(7),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(5),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+/// Returns the forwarded packet sent with the given sequence.
+fn forwarded_packet(sequence: u64) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(sequence),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(9),
+        data: vec![1],
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+fn forwarded_packet_id(sequence: u64) -> PacketId {
+    PacketId::new(
+        PortId::transfer(),
+        ChannelId::new(1),
+        Sequence::from(sequence),
+    )
+}
+
+fn error_ack() -> Acknowledgement {
+    AcknowledgementStatus::error(StatusValue::new("failed").unwrap()).into()
+}
+
+fn is_error_ack(ack: &Acknowledgement) -> bool {
+    !serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+        .unwrap()
+        .is_successful()
+}
+
+fn forward_sender() -> Signer {
+    signer("pfm/channel-0/cosmos1sender")
+}
+
+#[test]
+fn test_recv_packet_without_forward_memo() {
+    let mut module = forward_module();
+
+    let packet = recv_packet("100uatom", r#"{"wasm":{}}"#.to_string());
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(ack.is_some());
+    assert!(module.ctx().sent_transfers.is_empty());
+}
+
+#[test]
+fn test_recv_packet_with_invalid_forward_memo() {
+    let mut module = forward_module();
+
+    let packet = recv_packet("100uatom", r#"{"forward":{"port":"transfer"}}"#.to_string());
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(is_error_ack(&ack.unwrap()));
+    assert!(module.ctx().sent_transfers.is_empty());
+}
+
+#[test]
+fn test_recv_packet_forwards_and_holds_ack() {
+    let mut module = forward_module();
+
+    let next = ForwardMetadata {
+        channel: ChannelId::new(2),
+        ..forward_metadata()
+    };
+    let metadata = ForwardMetadata {
+        next: Some(NextMemo::Forward(Box::new(PacketMetadata {
+            forward: next.clone(),
+        }))),
+        ..forward_metadata()
+    };
+
+    let packet = recv_packet("100uatom", forward_memo(metadata));
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(ack.is_none());
+
+    let (sequence, msg) = &module.ctx().sent_transfers[0];
+    assert_eq!(msg.port_id_on_a, PortId::transfer());
+    assert_eq!(msg.chan_id_on_a, ChannelId::new(1));
+    assert_eq!(msg.packet_data.sender, forward_sender());
+    assert_eq!(msg.packet_data.receiver, signer("cosmos1receiver"));
+    assert_eq!(
+        msg.packet_data.tokens,
+        vec![Token::from(
+            "100transfer/channel-0/uatom"
+                .parse::<PrefixedCoin>()
+                .unwrap()
+        )]
+    );
+    assert_eq!(msg.packet_data.memo.as_ref(), forward_memo(next));
+    assert_eq!(
+        msg.timeout_timestamp_on_b,
+        TimeoutTimestamp::At(Timestamp::from_nanoseconds(
+            1 + DEFAULT_FORWARD_TIMEOUT_NANOS
+        ))
+    );
+
+    let in_flight_packet = &module.ctx().in_flight_packets[&forwarded_packet_id(sequence.value())];
+    assert_eq!(in_flight_packet.original_packet, packet);
+}
+
+#[test]
+fn test_recv_packet_refunds_when_forwarding_fails() {
+    let mut module = forward_module();
+    module.ctx_mut().fail_transfers = true;
+
+    let packet = recv_packet("100uatom", forward_memo(forward_metadata()));
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(is_error_ack(&ack.unwrap()));
+    assert_eq!(
+        module.ctx().burned,
+        vec![(
+            forward_sender(),
+            "100transfer/channel-0/uatom".parse().unwrap()
+        )]
+    );
+}
+
+#[test]
+fn test_ack_of_forwarded_packet_is_written() {
+    let mut module = forward_module();
+
+    let packet = recv_packet("100uatom", forward_memo(forward_metadata()));
+    module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let ack: Acknowledgement =
+        AcknowledgementStatus::success(StatusValue::new("AQ==").unwrap()).into();
+    let (_, res) =
+        module.on_acknowledgement_packet_execute(&forwarded_packet(1), &ack, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(module.ctx().written_acknowledgements, vec![(packet, ack)]);
+    assert!(module.ctx().in_flight_packets.is_empty());
+    assert!(module.ctx().burned.is_empty());
+}
+
+#[test]
+fn test_error_ack_of_forwarded_packet_refunds() {
+    let mut module = forward_module();
+
+    // The tokens are native to this chain, so they were unescrowed on
+    // receipt.
+    let packet = recv_packet(
+        "100transfer/channel-5/uatom",
+        forward_memo(forward_metadata()),
+    );
+    module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let (_, res) = module.on_acknowledgement_packet_execute(
+        &forwarded_packet(1),
+        &error_ack(),
+        &signer("relayer"),
+    );
+    res.unwrap();
+
+    assert_eq!(
+        module.ctx().escrowed,
+        vec![(forward_sender(), "100uatom".parse().unwrap())]
+    );
+    assert_eq!(
+        module.ctx().written_acknowledgements,
+        vec![(packet, error_ack())]
+    );
+}
+
+#[test]
+fn test_timeout_of_forwarded_packet_retries_then_refunds() {
+    let mut module = forward_module();
+
+    let metadata = ForwardMetadata {
+        retries: Some(1),
+        ..forward_metadata()
+    };
+    let packet = recv_packet("100uatom", forward_memo(metadata));
+    module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let (_, res) = module.on_timeout_packet_execute(&forwarded_packet(1), &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(module.ctx().sent_transfers.len(), 2);
+    assert!(module.ctx().written_acknowledgements.is_empty());
+
+    let in_flight_packet = &module.ctx().in_flight_packets[&forwarded_packet_id(2)];
+    assert_eq!(in_flight_packet.retries_remaining, 0);
+
+    let (_, res) = module.on_timeout_packet_execute(&forwarded_packet(2), &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(module.ctx().sent_transfers.len(), 2);
+    assert!(module.ctx().in_flight_packets.is_empty());
+    assert_eq!(
+        module.ctx().burned,
+        vec![(
+            forward_sender(),
+            "100transfer/channel-0/uatom".parse().unwrap()
+        )]
+    );
+
+    let (original_packet, ack) = &module.ctx().written_acknowledgements[0];
+    assert_eq!(original_packet, &packet);
+    assert!(is_error_ack(ack));
+}
+
+#[test]
+fn test_ack_of_packet_not_forwarded_passes_through() {
+    let mut module = forward_module();
+
+    let (_, res) = module.on_acknowledgement_packet_execute(
+        &forwarded_packet(1),
+        &error_ack(),
+        &signer("relayer"),
+    );
+    res.unwrap();
+
+    assert!(module.ctx().written_acknowledgements.is_empty());
+    assert!(module.ctx().burned.is_empty());
+}
+
+#[test]
+fn test_recv_packet_forwards_string_next_memo() {
+    let mut module = forward_module();
+
+    let memo = r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","next":"{\"wasm\":{}}"}}"#;
+    let packet = recv_packet("100uatom", memo.to_string());
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(ack.is_none());
+
+    let (_, msg) = &module.ctx().sent_transfers[0];
+    assert_eq!(msg.packet_data.memo.as_ref(), r#"{"wasm":{}}"#);
+}
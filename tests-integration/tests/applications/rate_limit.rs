@@ -0,0 +1,249 @@
+use ibc::apps::rate_limit::module::RateLimitMiddleware;
+use ibc::apps::rate_limit::types::{Quota, RateLimit, RatePath};
+use ibc::apps::transfer::handler::send_transfer;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{Amount, VERSION};
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::applications::transfer::{
+    dummy_msg_transfer, dummy_packet_data, dummy_transfer_channel_ctx,
+};
+use ibc_testkit::testapp::ibc::applications::rate_limit::types::DummyRateLimitContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+type RateLimitedTransferModule = RateLimitMiddleware<DummyTransferModule, DummyRateLimitContext>;
+
+const HOUR_NANOS: u64 = 3_600_000_000_000;
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+/// Returns a module limiting the flow of `uatom` over `channel-0` to 10% of
+/// a channel value of 1000 in each direction.
+fn rate_limited_module() -> RateLimitedTransferModule {
+    let rate_limit = RateLimit::new(
+        RatePath::new("uatom".parse().unwrap(), ChannelId::zero()),
+        Quota::new(10, 10, 24).unwrap(),
+    );
+
+    RateLimitMiddleware::new(
+        DummyTransferModule::new(),
+        DummyRateLimitContext::new().with_rate_limit(rate_limit, Amount::from(1000u64)),
+    )
+}
+
+fn packet_data(coin: &str) -> Vec<u8> {
+    let data = PacketData {
+        token: coin.parse().unwrap(),
+        sender: signer("cosmos1sender"),
+        receiver: signer("cosmos1receiver"),
+        memo: "".into(),
+    };
+
+    serde_json::to_vec(&data).unwrap()
+}
+
+/// Returns a packet sent from `channel-0` to `channel-5`.
+fn sent_packet(sequence: u64, coin: &str) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(sequence),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(5),
+        data: packet_data(coin),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+/// Returns a packet received on `channel-0` from `channel-5`.
+fn recv_packet(coin: &str) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(5),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: packet_data(coin),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+fn is_error_ack(ack: &Acknowledgement) -> bool {
+    !serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+        .unwrap()
+        .is_successful()
+}
+
+fn flow(module: &RateLimitedTransferModule) -> (Amount, Amount) {
+    let flow = &module.ctx().rate_limits.values().next().unwrap().flow;
+    (flow.inflow, flow.outflow)
+}
+
+#[test]
+fn test_send_packet_exceeding_quota_fails() {
+    let mut module = rate_limited_module();
+
+    module.send_packet(sent_packet(1, "60uatom")).unwrap();
+    module.send_packet(sent_packet(2, "40uatom")).unwrap();
+    assert!(module.send_packet(sent_packet(3, "1uatom")).is_err());
+
+    assert_eq!(flow(&module), (0u64.into(), 100u64.into()));
+    assert_eq!(module.ctx().pending_outflows.len(), 2);
+}
+
+#[test]
+fn test_send_packet_without_rate_limit() {
+    let mut module = rate_limited_module();
+
+    module.send_packet(sent_packet(1, "5000uosmo")).unwrap();
+
+    assert!(module.ctx().pending_outflows.is_empty());
+}
+
+#[test]
+fn test_recv_packet_exceeding_quota_gets_error_ack() {
+    let mut module = rate_limited_module();
+
+    // The tokens are native to this chain, so they are received as `uatom`.
+    let (_, ack) = module.on_recv_packet_execute(
+        &recv_packet("100transfer/channel-5/uatom"),
+        &signer("relayer"),
+    );
+    assert_eq!(ack, Some(vec![1].try_into().unwrap()));
+
+    let (_, ack) = module.on_recv_packet_execute(
+        &recv_packet("1transfer/channel-5/uatom"),
+        &signer("relayer"),
+    );
+    assert!(is_error_ack(&ack.unwrap()));
+
+    assert_eq!(flow(&module), (100u64.into(), 0u64.into()));
+}
+
+#[test]
+fn test_recv_packet_with_failing_store_gets_error_ack() {
+    let mut module = rate_limited_module();
+    module.ctx_mut().fail_stores = true;
+
+    let (_, ack) = module.on_recv_packet_execute(
+        &recv_packet("100transfer/channel-5/uatom"),
+        &signer("relayer"),
+    );
+    assert!(is_error_ack(&ack.unwrap()));
+
+    assert_eq!(flow(&module), (0u64.into(), 0u64.into()));
+}
+
+#[test]
+fn test_send_transfer_exceeding_quota_fails() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+    let mut module = rate_limited_module();
+
+    let msg = |coin: &str| {
+        dummy_msg_transfer(dummy_packet_data(coin.parse().unwrap()).call())
+            .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+            .call()
+    };
+
+    let (first, second) = (msg("100uatom"), msg("1uatom"));
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut module,
+        first,
+    )
+    .unwrap();
+
+    assert!(send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut module,
+        second,
+    )
+    .is_err());
+
+    assert_eq!(flow(&module), (0u64.into(), 100u64.into()));
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(2),
+        ))
+        .is_err());
+}
+
+#[test]
+fn test_timeout_reverts_outflow() {
+    let mut module = rate_limited_module();
+
+    let packet = sent_packet(1, "100uatom");
+    module.send_packet(packet.clone()).unwrap();
+
+    let (_, res) = module.on_timeout_packet_execute(&packet, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(flow(&module), (0u64.into(), 0u64.into()));
+    assert!(module.ctx().pending_outflows.is_empty());
+}
+
+#[test]
+fn test_error_ack_reverts_outflow() {
+    let mut module = rate_limited_module();
+
+    let packet = sent_packet(1, "100uatom");
+    module.send_packet(packet.clone()).unwrap();
+
+    let ack = AcknowledgementStatus::error(StatusValue::new("failed").unwrap()).into();
+    let (_, res) = module.on_acknowledgement_packet_execute(&packet, &ack, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(flow(&module), (0u64.into(), 0u64.into()));
+}
+
+#[test]
+fn test_success_ack_keeps_outflow() {
+    let mut module = rate_limited_module();
+
+    let packet = sent_packet(1, "100uatom");
+    module.send_packet(packet.clone()).unwrap();
+
+    let ack = AcknowledgementStatus::success(StatusValue::new("AQ==").unwrap()).into();
+    let (_, res) = module.on_acknowledgement_packet_execute(&packet, &ack, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(flow(&module), (0u64.into(), 100u64.into()));
+    assert!(module.ctx().pending_outflows.is_empty());
+}
+
+#[test]
+fn test_window_reset_restores_quota() {
+    let mut module = rate_limited_module();
+
+    let packet = sent_packet(1, "100uatom");
+    module.send_packet(packet.clone()).unwrap();
+
+    module.ctx_mut().host_timestamp = Timestamp::from_nanoseconds(1 + 24 * HOUR_NANOS);
+    module.send_packet(sent_packet(2, "100uatom")).unwrap();
+
+    // The refund of a packet sent in the previous window does not affect the
+    // current one.
+    let (_, res) = module.on_timeout_packet_execute(&packet, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(flow(&module), (0u64.into(), 100u64.into()));
+}
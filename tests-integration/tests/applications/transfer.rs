@@ -1,14 +1,52 @@
+use ibc::apps::transfer::context::TokenTransferExecutionContext;
+use ibc::apps::transfer::forwarding;
+use ibc::apps::transfer::handler::{
+    decode_packet_data, encode_packet_data, escrow_execute, send_authorized_transfer,
+    send_transfer, unescrow_execute,
+};
 use ibc::apps::transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_chan_upgrade_ack_validate, on_chan_upgrade_init_validate,
+    on_chan_upgrade_try_execute, on_chan_upgrade_try_validate, on_recv_packet_execute,
+};
+use ibc::apps::transfer::types::authorization::{Allocation, TransferAuthorization};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::packet::{PacketData, PacketDataV2};
+use ibc::apps::transfer::types::policy::DenomPolicy;
+use ibc::apps::transfer::types::{
+    ack_success_b64, Amount, Forwarding, ForwardingPacketData, Hop, PrefixedCoin, PrefixedDenom,
+    TracePrefix, VERSION, VERSION_V2,
 };
-use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::middleware::{Middleware, StackBuilder};
+use ibc::core::router::module::Module;
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
-use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_query::apps::transfer::{
+    query_denom_hash, query_denom_trace, query_denom_traces, query_escrow_address,
+    query_total_escrow_for_denom, QueryDenomHashRequest, QueryDenomTraceRequest,
+    QueryDenomTracesRequest, QueryEscrowAddressRequest, QueryTotalEscrowForDenomRequest,
+};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::applications::transfer::{
+    dummy_msg_transfer, dummy_transfer_channel_ctx,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::{
+    DummyTransferModule, EscrowMismatch,
+};
 use subtle_encoding::bech32;
 
 fn get_defaults() -> (
@@ -26,7 +64,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyTransferModule,
+        DummyTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -147,14 +185,79 @@ fn test_on_chan_open_try_counterparty_correct_version() {
     assert_eq!(out_version, Version::new(VERSION.to_string()));
 }
 
-/// If the counterparty doesn't support ics20, then fail
+/// If the counterparty proposes an unsupported version, then answer with
+/// ICS-20 v1
 #[test]
 fn test_on_chan_open_try_counterparty_incorrect_version() {
-    let (ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
 
     let counterparty_version = Version::new("some-unsupported-version".to_string());
 
-    let res = on_chan_open_try_validate(
+    on_chan_open_try_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    let (_, out_version) = on_chan_open_try_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, Version::new(VERSION.to_string()));
+}
+
+/// If the relayer passed in ICS-20 v2, then return ICS-20 v2
+#[test]
+fn test_on_chan_open_init_ics20_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let in_version = Version::new(VERSION_V2.to_string());
+
+    on_chan_open_init_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .unwrap();
+
+    let (_, out_version) = on_chan_open_init_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, in_version);
+}
+
+/// If the counterparty supports ICS-20 v2, then return ICS-20 v2
+#[test]
+fn test_on_chan_open_try_counterparty_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let counterparty_version = Version::new(VERSION_V2.to_string());
+
+    on_chan_open_try_validate(
         &ctx,
         order,
         &connection_hops,
@@ -162,7 +265,1067 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
         &channel_id,
         &counterparty,
         &counterparty_version,
+    )
+    .unwrap();
+
+    let (_, out_version) = on_chan_open_try_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, counterparty_version);
+}
+
+/// Upgrading a channel to ICS-20 v2 is accepted on both ends.
+#[test]
+fn test_on_chan_upgrade_to_v2_version() {
+    let (mut ctx, _, connection_hops, port_id, channel_id, _) = get_defaults();
+
+    let proposed_version = Version::new(VERSION_V2.to_string());
+
+    let version = on_chan_upgrade_init_validate(
+        &ctx,
+        &port_id,
+        &channel_id,
+        Order::Unordered,
+        &connection_hops,
+        &proposed_version,
+    )
+    .unwrap();
+
+    assert_eq!(version, proposed_version);
+
+    let (_, version) = on_chan_upgrade_try_execute(
+        &mut ctx,
+        &port_id,
+        &channel_id,
+        Order::Unordered,
+        &connection_hops,
+        &proposed_version,
+    )
+    .unwrap();
+
+    assert_eq!(version, proposed_version);
+
+    on_chan_upgrade_ack_validate(&ctx, &port_id, &channel_id, &proposed_version).unwrap();
+}
+
+/// Channels cannot be upgraded to an unsupported version, nor to an ordered
+/// channel.
+#[test]
+fn test_on_chan_upgrade_invalid_parameters() {
+    let (ctx, _, connection_hops, port_id, channel_id, _) = get_defaults();
+
+    let unsupported_version = Version::new("some-unsupported-version".to_string());
+
+    assert!(on_chan_upgrade_init_validate(
+        &ctx,
+        &port_id,
+        &channel_id,
+        Order::Unordered,
+        &connection_hops,
+        &unsupported_version,
+    )
+    .is_err());
+    assert!(on_chan_upgrade_try_validate(
+        &ctx,
+        &port_id,
+        &channel_id,
+        Order::Unordered,
+        &connection_hops,
+        &unsupported_version,
+    )
+    .is_err());
+    assert!(
+        on_chan_upgrade_ack_validate(&ctx, &port_id, &channel_id, &unsupported_version).is_err()
     );
 
-    assert!(res.is_err());
+    assert!(on_chan_upgrade_init_validate(
+        &ctx,
+        &port_id,
+        &channel_id,
+        Order::Ordered,
+        &connection_hops,
+        &Version::new(VERSION.to_string()),
+    )
+    .is_err());
+}
+
+fn multi_token_packet_data() -> PacketDataV2 {
+    let tokens = ["10uatom", "20transfer/channel-2/uosmo"]
+        .into_iter()
+        .map(|coin| coin.parse::<PrefixedCoin>().unwrap().into())
+        .collect();
+
+    PacketDataV2 {
+        tokens,
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+        forwarding: Default::default(),
+    }
+}
+
+fn single_token_packet_data() -> PacketDataV2 {
+    PacketDataV2 {
+        tokens: vec!["10uatom".parse::<PrefixedCoin>().unwrap().into()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+        forwarding: Default::default(),
+    }
+}
+
+#[test]
+fn test_send_transfer_multiple_tokens() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION_V2);
+
+    let msg = dummy_msg_transfer(multi_token_packet_data())
+        .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+        .call();
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut DummyTransferModule::new(),
+        msg.clone(),
+    )
+    .unwrap();
+
+    let packet_data = ctx
+        .get_events()
+        .iter()
+        .find_map(|event| match event {
+            IbcEvent::SendPacket(e) => Some(e.packet_data().to_vec()),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        decode_packet_data(&Version::new(VERSION_V2.to_string()), &packet_data).unwrap(),
+        msg.packet_data
+    );
+
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: packet_data,
+        timeout_height_on_b: msg.timeout_height_on_b,
+        timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+    };
+
+    // The packet data is decoded according to the version of the channel it
+    // is received over, so an ICS-20 v1 channel rejects it.
+    let mut transfer = DummyTransferModule::new();
+    let (_, ack) = on_recv_packet_execute(&mut transfer, &packet);
+
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
+    assert!(transfer.voucher_supply.is_empty());
+
+    transfer.channel_versions.insert(
+        (PortId::transfer(), ChannelId::zero()),
+        Version::new(VERSION_V2.to_string()),
+    );
+    let (extras, ack) = on_recv_packet_execute(&mut transfer, &packet);
+
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(ack_status.is_successful());
+
+    // One denomination trace event per minted voucher, followed by one
+    // reception event per token
+    assert_eq!(extras.events.len(), 4);
+}
+
+#[test]
+fn test_recv_packet_exceeding_total_escrow() {
+    // The uatom tokens go back to this chain, so they are unescrowed, while
+    // vouchers are minted for the uosmo tokens.
+    let tokens = [
+        "10transfer/channel-1/uatom",
+        "10transfer/channel-1/uatom",
+        "20uosmo",
+    ]
+    .into_iter()
+    .map(|coin| coin.parse::<PrefixedCoin>().unwrap().into())
+    .collect();
+    let data = PacketDataV2 {
+        tokens,
+        ..multi_token_packet_data()
+    };
+
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: encode_packet_data(&Version::new(VERSION_V2.to_string()), data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    };
+
+    // Each token is covered by the total escrow, but not both of them.
+    let denom: PrefixedDenom = "uatom".parse().unwrap();
+    let mut transfer = DummyTransferModule::new();
+    transfer.channel_versions.insert(
+        (PortId::transfer(), ChannelId::zero()),
+        Version::new(VERSION_V2.to_string()),
+    );
+    transfer
+        .total_escrow
+        .insert(denom.clone(), Amount::from(15u64));
+
+    let (_, ack) = on_recv_packet_execute(&mut transfer, &packet);
+
+    // Nothing is received, as the sender is refunded all the tokens.
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
+    assert_eq!(transfer.total_escrow[&denom], Amount::from(15u64));
+    assert!(transfer.voucher_supply.is_empty());
+}
+
+#[test]
+fn test_send_transfer_multiple_tokens_over_v1_channel() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+
+    let msg = dummy_msg_transfer(multi_token_packet_data())
+        .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+        .call();
+
+    let err = send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut DummyTransferModule::new(),
+        msg,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            TokenTransferError::UnsupportedTokenCount { count: 2, .. }
+        ),
+        "{err}"
+    );
+}
+
+/// A middleware which records the packets sent through it, and rejects them
+/// all if `reject` is set.
+#[derive(Debug)]
+struct SendRecordingMiddleware<M> {
+    next: M,
+    sent: Vec<Packet>,
+    reject: bool,
+}
+
+impl<M: Module> SendRecordingMiddleware<M> {
+    fn new(next: M, reject: bool) -> Self {
+        Self {
+            next,
+            sent: Vec::new(),
+            reject,
+        }
+    }
+}
+
+impl<M: Module> Middleware for SendRecordingMiddleware<M> {
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.next
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.next
+    }
+
+    fn on_send_packet(&mut self, packet: Packet) -> Result<Packet, ChannelError> {
+        if self.reject {
+            return Err(ChannelError::AppSpecific {
+                description: "packet rejected by middleware".to_string(),
+            });
+        }
+
+        self.sent.push(packet.clone());
+        Ok(packet)
+    }
+}
+
+#[test]
+fn test_send_transfer_goes_through_middleware() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+
+    let (_, mut stack) = StackBuilder::new(PortId::transfer(), DummyTransferModule::new())
+        .wrap(|app| SendRecordingMiddleware::new(app, false))
+        .build();
+
+    let msg = dummy_msg_transfer(single_token_packet_data())
+        .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+        .call();
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut stack,
+        msg,
+    )
+    .unwrap();
+
+    let [packet] = stack.sent.as_slice() else {
+        core::panic!("expected a single packet, got {:?}", stack.sent);
+    };
+
+    assert_eq!(packet.seq_on_a, Sequence::from(1));
+
+    let commitment = ctx
+        .ibc_store
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ))
+        .unwrap();
+    assert_eq!(
+        commitment,
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b
+        )
+    );
+}
+
+#[test]
+fn test_send_transfer_rejected_by_middleware() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+
+    let (_, mut stack) = StackBuilder::new(PortId::transfer(), DummyTransferModule::new())
+        .wrap(|app| SendRecordingMiddleware::new(app, true))
+        .build();
+
+    let msg = dummy_msg_transfer(single_token_packet_data())
+        .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+        .call();
+
+    assert!(send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut stack,
+        msg,
+    )
+    .is_err());
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ))
+        .is_err());
+}
+
+/// Returns a packet received on `channel-0` from `channel-1`, carrying the
+/// given coin.
+fn recv_packet(coin: &str) -> Packet {
+    let data = PacketData {
+        token: coin.parse().unwrap(),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+// ATOM received over `channel-0`, as named on Osmosis
+const ATOM_VOUCHER_HASH: &str = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+#[test]
+fn test_recv_packet_stores_denom_trace() {
+    let mut transfer = DummyTransferModule::new();
+
+    on_recv_packet_execute(&mut transfer, &recv_packet("100uatom"));
+    on_recv_packet_execute(&mut transfer, &recv_packet("50uatom"));
+
+    let voucher = "transfer/channel-0/uatom".parse().unwrap();
+    assert_eq!(
+        transfer.denom_traces,
+        [(ATOM_VOUCHER_HASH.to_string(), voucher)].into()
+    );
+
+    // Unescrowed tokens are native to this chain, so they have no trace.
+    transfer
+        .total_escrow
+        .insert("uosmo".parse().unwrap(), Amount::from(100u64));
+    on_recv_packet_execute(&mut transfer, &recv_packet("10transfer/channel-1/uosmo"));
+
+    assert_eq!(transfer.denom_traces.len(), 1);
+}
+
+#[test]
+fn test_query_denom_traces() {
+    let mut transfer = DummyTransferModule::new();
+
+    on_recv_packet_execute(&mut transfer, &recv_packet("100uatom"));
+
+    let denom_trace = query_denom_trace(
+        &transfer,
+        &QueryDenomTraceRequest {
+            hash: format!("ibc/{ATOM_VOUCHER_HASH}"),
+        },
+    )
+    .unwrap()
+    .denom_trace;
+    assert_eq!(denom_trace.to_string(), "transfer/channel-0/uatom");
+
+    let denom_traces = query_denom_traces(&transfer, &QueryDenomTracesRequest { pagination: None })
+        .unwrap()
+        .denom_traces;
+    assert_eq!(denom_traces, vec![denom_trace]);
+
+    let hash = query_denom_hash(
+        &transfer,
+        &QueryDenomHashRequest {
+            trace: "transfer/channel-0/uatom".to_string(),
+        },
+    )
+    .unwrap()
+    .hash;
+    assert_eq!(hash, ATOM_VOUCHER_HASH);
+
+    assert!(query_denom_trace(
+        &transfer,
+        &QueryDenomTraceRequest {
+            hash: "ibc/0000".to_string(),
+        },
+    )
+    .is_err());
+}
+
+#[test]
+fn test_query_escrow() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+    let mut transfer = DummyTransferModule::new();
+
+    let packet_data = PacketDataV2 {
+        tokens: vec!["100uatom".parse::<PrefixedCoin>().unwrap().into()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+        forwarding: Default::default(),
+    };
+    let msg = dummy_msg_transfer(packet_data)
+        .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+        .call();
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        msg,
+    )
+    .unwrap();
+
+    let amount = query_total_escrow_for_denom(
+        &transfer,
+        &QueryTotalEscrowForDenomRequest {
+            denom: "uatom".to_string(),
+        },
+    )
+    .unwrap()
+    .amount;
+    assert_eq!(amount, "100uatom".parse().unwrap());
+
+    let escrow_address = query_escrow_address(
+        &transfer,
+        &QueryEscrowAddressRequest {
+            port_id: PortId::transfer(),
+            channel_id: "channel-141".parse().unwrap(),
+        },
+    )
+    .unwrap()
+    .escrow_address;
+    assert_eq!(
+        escrow_address.as_ref(),
+        "cosmos1x54ltnyg88k0ejmk8ytwrhd3ltm84xehrnlslf"
+    );
+}
+
+#[test]
+fn test_escrow_invariant() {
+    let mut transfer_a = DummyTransferModule::new();
+    let mut transfer_b = DummyTransferModule::new();
+    let counterparties = [(
+        TracePrefix::new(PortId::transfer(), ChannelId::zero()),
+        &transfer_b,
+    )];
+    assert!(transfer_a.check_escrow_invariant(&counterparties).is_ok());
+
+    let account = dummy_account_id();
+    let coin: PrefixedCoin = "100uatom".parse().unwrap();
+    escrow_execute(
+        &mut transfer_a,
+        &account,
+        &PortId::transfer(),
+        &ChannelId::new(1),
+        &coin,
+        &"".into(),
+    )
+    .unwrap();
+    on_recv_packet_execute(&mut transfer_b, &recv_packet("100uatom"));
+
+    // 40 vouchers are sent back, to be burned and unescrowed.
+    transfer_b
+        .burn_coins_execute(
+            &account,
+            &"40transfer/channel-0/uatom".parse().unwrap(),
+            &"".into(),
+        )
+        .unwrap();
+    unescrow_execute(
+        &mut transfer_a,
+        &account,
+        &PortId::transfer(),
+        &ChannelId::new(1),
+        &"40uatom".parse().unwrap(),
+    )
+    .unwrap();
+
+    let counterparties = [(
+        TracePrefix::new(PortId::transfer(), ChannelId::zero()),
+        &transfer_b,
+    )];
+    assert!(transfer_a.check_escrow_invariant(&counterparties).is_ok());
+    assert_eq!(transfer_a.total_escrow[&coin.denom], Amount::from(60u64));
+
+    // Vouchers minted without tokens being escrowed break the invariant.
+    on_recv_packet_execute(&mut transfer_b, &recv_packet("5uatom"));
+
+    let counterparties = [(
+        TracePrefix::new(PortId::transfer(), ChannelId::zero()),
+        &transfer_b,
+    )];
+    assert_eq!(
+        transfer_a.check_escrow_invariant(&counterparties),
+        Err(vec![EscrowMismatch {
+            denom: coin.denom,
+            total_escrow: Amount::from(60u64),
+            voucher_supply: Amount::from(65u64),
+        }])
+    );
+}
+
+#[test]
+fn test_recv_packet_with_insufficient_escrow() {
+    let mut transfer = DummyTransferModule::new();
+    transfer
+        .total_escrow
+        .insert("uosmo".parse().unwrap(), Amount::from(5u64));
+
+    let (_, ack) =
+        on_recv_packet_execute(&mut transfer, &recv_packet("10transfer/channel-1/uosmo"));
+
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
+    assert_eq!(
+        transfer.total_escrow[&"uosmo".parse().unwrap()],
+        Amount::from(5u64)
+    );
+}
+
+#[test]
+fn test_send_authorized_transfer() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+    let mut transfer = DummyTransferModule::new();
+
+    let granter = dummy_account_id();
+    let grantee: Signer = "cosmos1grantee".to_string().into();
+    let timeout_height_on_b = TimeoutHeight::At(ctx.latest_height().add(10));
+    let msg = |coin: &str| {
+        let packet_data = PacketDataV2 {
+            tokens: vec![coin.parse::<PrefixedCoin>().unwrap().into()],
+            sender: granter.clone(),
+            receiver: dummy_account_id(),
+            memo: "".into(),
+            forwarding: Default::default(),
+        };
+        dummy_msg_transfer(packet_data)
+            .timeout_height_on_b(timeout_height_on_b)
+            .call()
+    };
+
+    let err = send_authorized_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        &grantee,
+        msg("100uatom"),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, TokenTransferError::MissingTransferAuthorization { .. }),
+        "{err}"
+    );
+
+    let allocation = |spend_limit: &str| Allocation {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::zero(),
+        spend_limit: vec![spend_limit.parse().unwrap()],
+        allow_list: vec![],
+        allowed_packet_data: vec![],
+    };
+    let key = (granter.clone(), grantee.clone());
+    transfer.transfer_authorizations.insert(
+        key.clone(),
+        TransferAuthorization {
+            allocations: vec![allocation("150uatom")],
+        },
+    );
+
+    send_authorized_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        &grantee,
+        msg("100uatom"),
+    )
+    .unwrap();
+    assert_eq!(
+        transfer.transfer_authorizations[&key].allocations,
+        vec![allocation("50uatom")]
+    );
+
+    // Grantees cannot forward tokens, as the allocations do not cover the
+    // chains they would go through.
+    let mut forwarded_msg = msg("10uatom");
+    forwarded_msg.timeout_height_on_b = TimeoutHeight::Never;
+    forwarded_msg.forwarding =
+        Forwarding::new(false, vec![Hop::new(PortId::transfer(), ChannelId::new(5))]);
+    let err = send_authorized_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        &grantee,
+        forwarded_msg,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, TokenTransferError::UnauthorizedForwarding),
+        "{err}"
+    );
+
+    let err = send_authorized_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        &grantee,
+        msg("100uatom"),
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, TokenTransferError::SpendLimitExceeded(_)),
+        "{err}"
+    );
+
+    send_authorized_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        &grantee,
+        msg("50uatom"),
+    )
+    .unwrap();
+    assert!(transfer.transfer_authorizations.is_empty());
+
+    // The sender needs no authorization.
+    send_authorized_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        &granter,
+        msg("100uatom"),
+    )
+    .unwrap();
+    assert_eq!(
+        transfer.total_escrow[&"uatom".parse().unwrap()],
+        Amount::from(250u64)
+    );
+}
+
+#[test]
+fn test_denom_policy() {
+    let mut transfer = DummyTransferModule::new();
+    transfer.denom_policies.insert(
+        (PortId::transfer(), ChannelId::zero()),
+        DenomPolicy {
+            blocked_base_denoms: vec!["ujunk".parse().unwrap()],
+            max_trace_depth: Some(1),
+            ..DenomPolicy::default()
+        },
+    );
+
+    for (coin, allowed) in [
+        ("100uatom", true),
+        ("100ujunk", false),
+        ("100transfer/channel-7/uatom", false),
+    ] {
+        let (_, ack) = on_recv_packet_execute(&mut transfer, &recv_packet(coin));
+        let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+        assert_eq!(ack_status.is_successful(), allowed, "{coin}");
+    }
+
+    assert_eq!(
+        transfer.voucher_supply.keys().collect::<Vec<_>>(),
+        vec![&"transfer/channel-0/uatom".parse().unwrap()]
+    );
+
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+    let packet_data = PacketDataV2 {
+        tokens: vec!["100ujunk".parse::<PrefixedCoin>().unwrap().into()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+        forwarding: Default::default(),
+    };
+    let msg = dummy_msg_transfer(packet_data)
+        .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+        .call();
+
+    let err = send_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        msg,
+    )
+    .unwrap_err();
+    assert!(matches!(err, TokenTransferError::BlockedDenom(_)), "{err}");
+}
+
+fn forwarding_timeout() -> TimeoutTimestamp {
+    TimeoutTimestamp::At(Timestamp::from_nanoseconds(u64::MAX))
+}
+
+#[test]
+fn test_send_transfer_with_unwinding() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION_V2);
+
+    let voucher: PrefixedCoin = "10transfer/channel-0/transfer/channel-3/uatom"
+        .parse()
+        .unwrap();
+    let mut transfer = DummyTransferModule::new();
+    transfer
+        .voucher_supply
+        .insert(voucher.denom.clone(), voucher.amount);
+
+    let packet_data = PacketDataV2 {
+        tokens: vec![voucher.clone().into()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "final memo".into(),
+        forwarding: Default::default(),
+    };
+    let next_hop = Hop::new(PortId::transfer(), ChannelId::new(5));
+
+    // The tokens must be sent over the channel they were received over.
+    let msg = dummy_msg_transfer(packet_data.clone())
+        .chan_id_on_a(ChannelId::new(7))
+        .timeout_timestamp_on_b(forwarding_timeout())
+        .forwarding(Forwarding::new(true, vec![next_hop.clone()]))
+        .call();
+
+    let err = send_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        msg,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, TokenTransferError::MismatchedUnwindChannel { .. }),
+        "{err}"
+    );
+
+    let msg = dummy_msg_transfer(packet_data)
+        .timeout_timestamp_on_b(forwarding_timeout())
+        .forwarding(Forwarding::new(true, vec![next_hop.clone()]))
+        .call();
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &mut transfer,
+        &mut DummyTransferModule::new(),
+        msg,
+    )
+    .unwrap();
+
+    let send_packet_event = ctx
+        .get_events()
+        .into_iter()
+        .find_map(|event| match event {
+            IbcEvent::SendPacket(e) => Some(e),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(send_packet_event.chan_id_on_a(), &ChannelId::zero());
+
+    let data = decode_packet_data(
+        &Version::new(VERSION_V2.to_string()),
+        send_packet_event.packet_data(),
+    )
+    .unwrap();
+    assert_eq!(data.memo.as_ref(), "");
+    assert_eq!(
+        data.forwarding,
+        ForwardingPacketData::new(
+            "final memo".into(),
+            vec![Hop::new(PortId::transfer(), ChannelId::new(3)), next_hop],
+        )
+    );
+
+    // The vouchers are burned, as they go back towards their source.
+    assert_eq!(transfer.voucher_supply[&voucher.denom], Amount::from(0u64));
+}
+
+#[test]
+fn test_send_transfer_with_invalid_forwarding() {
+    let hop = Hop::new(PortId::transfer(), ChannelId::new(5));
+
+    for (coin, version, forwarding, timeout_height) in [
+        (
+            "10uatom",
+            VERSION_V2,
+            Forwarding::new(true, vec![]),
+            TimeoutHeight::Never,
+        ),
+        (
+            "10uatom",
+            VERSION_V2,
+            Forwarding::new(false, vec![hop.clone()]),
+            TimeoutHeight::At(MockContext::default().latest_height().add(10)),
+        ),
+        (
+            "10uatom",
+            VERSION,
+            Forwarding::new(false, vec![hop.clone()]),
+            TimeoutHeight::Never,
+        ),
+    ] {
+        let mut ctx = dummy_transfer_channel_ctx(version);
+
+        let packet_data = PacketDataV2 {
+            tokens: vec![coin.parse::<PrefixedCoin>().unwrap().into()],
+            sender: dummy_account_id(),
+            receiver: dummy_account_id(),
+            memo: "".into(),
+            forwarding: Default::default(),
+        };
+        let msg = dummy_msg_transfer(packet_data)
+            .timeout_height_on_b(timeout_height)
+            .timeout_timestamp_on_b(forwarding_timeout())
+            .forwarding(forwarding)
+            .call();
+
+        let err = send_transfer(
+            &mut ctx.ibc_store,
+            &mut DummyTransferModule::new(),
+            &mut DummyTransferModule::new(),
+            msg,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                TokenTransferError::CannotUnwindNativeDenom(_)
+                    | TokenTransferError::TimeoutHeightWithForwarding
+                    | TokenTransferError::UnsupportedForwarding(_)
+            ),
+            "{err}"
+        );
+    }
+}
+
+/// Returns a packet received on `channel-0` from `channel-1`, carrying the
+/// given coin to be forwarded over `channel-2`.
+fn forwarding_recv_packet(coin: &str) -> Packet {
+    let data = PacketDataV2 {
+        tokens: vec![coin.parse::<PrefixedCoin>().unwrap().into()],
+        sender: dummy_account_id(),
+        receiver: "cosmos1final".to_string().into(),
+        memo: "".into(),
+        forwarding: ForwardingPacketData::new(
+            "final memo".into(),
+            vec![Hop::new(PortId::transfer(), ChannelId::new(2))],
+        ),
+    };
+
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: encode_packet_data(&Version::new(VERSION_V2.to_string()), data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: forwarding_timeout(),
+    }
+}
+
+/// Returns the packet sent over `channel-2` by the given forwarded transfer.
+fn forwarded_packet(sequence: Sequence, msg: &MsgTransfer) -> Packet {
+    Packet {
+        seq_on_a: sequence,
+        port_id_on_a: msg.port_id_on_a.clone(),
+        chan_id_on_a: msg.chan_id_on_a.clone(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(3),
+        data: encode_packet_data(
+            &Version::new(VERSION_V2.to_string()),
+            msg.packet_data.clone(),
+        )
+        .unwrap(),
+        timeout_height_on_b: msg.timeout_height_on_b,
+        timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+    }
+}
+
+/// Returns a transfer module whose `channel-0`, over which the packets to be
+/// forwarded are received, and `channel-2`, over which they are forwarded,
+/// are ICS-20 v2 channels.
+fn forwarding_transfer_module() -> DummyTransferModule {
+    let mut transfer = DummyTransferModule::new();
+    for channel_id in [ChannelId::zero(), ChannelId::new(2)] {
+        transfer.channel_versions.insert(
+            (PortId::transfer(), channel_id),
+            Version::new(VERSION_V2.to_string()),
+        );
+    }
+    transfer
+}
+
+#[test]
+fn test_forward_packet() {
+    let mut transfer = forwarding_transfer_module();
+    let packet = forwarding_recv_packet("100uatom");
+
+    let (_, ack) = forwarding::on_recv_packet_execute(&mut transfer, &packet);
+    assert!(ack.is_none());
+
+    let voucher: PrefixedCoin = "100transfer/channel-0/uatom".parse().unwrap();
+    let (sequence, msg) = transfer.sent_transfers[0].clone();
+    assert_eq!(msg.chan_id_on_a, ChannelId::new(2));
+    assert_eq!(msg.packet_data.tokens, vec![voucher.clone().into()]);
+    assert_eq!(
+        msg.packet_data.sender.as_ref(),
+        "forward/transfer/channel-0"
+    );
+    assert_eq!(msg.packet_data.receiver.as_ref(), "cosmos1final");
+    assert_eq!(msg.packet_data.memo.as_ref(), "final memo");
+    assert!(msg.packet_data.forwarding.is_empty());
+
+    // The vouchers minted for the forwarding account are escrowed, as they
+    // are native to this chain from the next chain's point of view.
+    assert_eq!(transfer.voucher_supply[&voucher.denom], voucher.amount);
+    assert_eq!(transfer.total_escrow[&voucher.denom], voucher.amount);
+
+    let success_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    let (_, result) = forwarding::on_acknowledgement_packet_execute(
+        &mut transfer,
+        &forwarded_packet(sequence, &msg),
+        &success_ack,
+        &dummy_account_id(),
+    );
+    result.unwrap();
+
+    assert_eq!(
+        transfer.written_acknowledgements,
+        vec![(packet, success_ack)]
+    );
+    assert!(transfer.forwarded_packets.is_empty());
+}
+
+#[test]
+fn test_forward_packet_failure() {
+    let error_ack: Acknowledgement =
+        AcknowledgementStatus::error(TokenTransferError::MissingTokens.into()).into();
+
+    for timeout in [false, true] {
+        let mut transfer = forwarding_transfer_module();
+        let packet = forwarding_recv_packet("100uatom");
+
+        forwarding::on_recv_packet_execute(&mut transfer, &packet);
+        let (sequence, msg) = transfer.sent_transfers[0].clone();
+        let forwarded_packet = forwarded_packet(sequence, &msg);
+
+        let (_, result) = if timeout {
+            forwarding::on_timeout_packet_execute(
+                &mut transfer,
+                &forwarded_packet,
+                &dummy_account_id(),
+            )
+        } else {
+            forwarding::on_acknowledgement_packet_execute(
+                &mut transfer,
+                &forwarded_packet,
+                &error_ack,
+                &dummy_account_id(),
+            )
+        };
+        result.unwrap();
+
+        // The vouchers are refunded to the forwarding account, and then
+        // burned so that the origin can be refunded.
+        let voucher = "transfer/channel-0/uatom".parse().unwrap();
+        assert_eq!(transfer.voucher_supply[&voucher], Amount::from(0u64));
+        assert_eq!(transfer.total_escrow[&voucher], Amount::from(0u64));
+
+        let (acked_packet, ack) = &transfer.written_acknowledgements[0];
+        assert_eq!(acked_packet, &packet);
+
+        let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+        assert!(!ack_status.is_successful());
+        assert_eq!(ack == &error_ack, !timeout);
+    }
+}
+
+#[test]
+fn test_forward_packet_send_failure() {
+    let mut transfer = forwarding_transfer_module();
+    transfer.fail_transfers = true;
+
+    let (_, ack) =
+        forwarding::on_recv_packet_execute(&mut transfer, &forwarding_recv_packet("100uatom"));
+
+    let ack_status =
+        serde_json::from_slice::<AcknowledgementStatus>(ack.unwrap().as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
+    assert_eq!(
+        transfer.voucher_supply[&"transfer/channel-0/uatom".parse().unwrap()],
+        Amount::from(0u64)
+    );
+
+    // Hosts which do not support forwarding reject the packet.
+    let (_, ack) = on_recv_packet_execute(
+        &mut forwarding_transfer_module(),
+        &forwarding_recv_packet("100uatom"),
+    );
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
 }
@@ -0,0 +1,303 @@
+use ibc::apps::transfer::handler::send_transfer;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::VERSION;
+use ibc::apps::transfer_hooks::module::TransferHooksMiddleware;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::applications::transfer::{
+    dummy_msg_transfer, dummy_packet_data, dummy_transfer_channel_ctx,
+};
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::applications::transfer_hooks::types::DummyHookExecutor;
+
+type HookedTransferModule = TransferHooksMiddleware<DummyTransferModule, DummyHookExecutor>;
+
+const CONTRACT: &str = "cosmos1contract";
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+fn hooks_module() -> HookedTransferModule {
+    TransferHooksMiddleware::new(DummyTransferModule::new(), DummyHookExecutor::new())
+}
+
+fn wasm_memo(msg: &str) -> String {
+    format!(r#"{{"wasm":{{"contract":"{CONTRACT}","msg":{msg}}}}}"#)
+}
+
+fn packet_data(coin: &str, sender: &str, receiver: &str, memo: String) -> Vec<u8> {
+    let data = PacketData {
+        token: coin.parse().unwrap(),
+        sender: signer(sender),
+        receiver: signer(receiver),
+        memo: memo.into(),
+    };
+
+    serde_json::to_vec(&data).unwrap()
+}
+
+/// Returns a packet received on `channel-0` from `channel-5`, whose tokens
+/// go to the given receiver.
+fn recv_packet(coin: &str, receiver: &str, memo: String) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(5),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: packet_data(coin, "cosmos1sender", receiver, memo),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+/// Returns a packet sent by the contract from `channel-0` to `channel-5`.
+fn sent_packet(memo: String) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(5),
+        data: packet_data("100uatom", CONTRACT, "cosmos1receiver", memo),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+fn is_error_ack(ack: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+        .is_ok_and(|status| !status.is_successful())
+}
+
+#[test]
+fn test_recv_packet_without_hook_memo() {
+    let mut module = hooks_module();
+
+    let packet = recv_packet("100uatom", CONTRACT, r#"{"forward":{}}"#.to_string());
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(!is_error_ack(&ack.unwrap()));
+    assert!(module.ctx().executed_hooks.is_empty());
+}
+
+#[test]
+fn test_recv_packet_executes_hook_with_funds() {
+    let mut module = hooks_module();
+
+    let msg = r#"{"swap":{"min_out":"90","route":[1,7]}}"#;
+    let packet = recv_packet("100uatom", CONTRACT, wasm_memo(msg));
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(!is_error_ack(&ack.unwrap()));
+
+    let (sender, call, funds) = &module.ctx().executed_hooks[0];
+    assert_eq!(sender, &signer("cosmos1sender"));
+    assert_eq!(call.contract, signer(CONTRACT));
+    assert_eq!(call.msg.as_ref(), msg);
+    assert_eq!(funds, &vec!["100transfer/channel-0/uatom".parse().unwrap()]);
+}
+
+#[test]
+fn test_recv_packet_executes_generic_call() {
+    let mut module = hooks_module();
+
+    let memo = format!(r#"{{"call":{{"contract":"{CONTRACT}","msg":"swap"}}}}"#);
+    let packet = recv_packet("100uatom", CONTRACT, memo);
+    module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let (_, call, _) = &module.ctx().executed_hooks[0];
+    assert_eq!(call.msg.as_ref(), r#""swap""#);
+}
+
+#[test]
+fn test_recv_packet_with_invalid_hook_memo() {
+    let mut module = hooks_module();
+
+    for memo in [
+        r#"{"wasm":{"msg":{}}}"#.to_string(),
+        format!(
+            r#"{{"wasm":{{"contract":"{CONTRACT}","msg":{{}}}},"call":{{"contract":"{CONTRACT}","msg":{{}}}}}}"#
+        ),
+    ] {
+        let packet = recv_packet("100uatom", CONTRACT, memo);
+        let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+        assert!(is_error_ack(&ack.unwrap()));
+    }
+
+    assert!(module.ctx().executed_hooks.is_empty());
+}
+
+#[test]
+fn test_recv_packet_to_other_receiver_is_rejected() {
+    let mut module = hooks_module();
+
+    let packet = recv_packet("100uatom", "cosmos1receiver", wasm_memo("{}"));
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(is_error_ack(&ack.unwrap()));
+    assert!(module.ctx().executed_hooks.is_empty());
+}
+
+#[test]
+fn test_failed_hook_burns_minted_tokens() {
+    let mut module = hooks_module();
+    module.ctx_mut().fail_calls = true;
+
+    let packet = recv_packet("100uatom", CONTRACT, wasm_memo("{}"));
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(is_error_ack(&ack.unwrap()));
+    assert_eq!(
+        module.ctx().burned,
+        vec![(
+            signer(CONTRACT),
+            "100transfer/channel-0/uatom".parse().unwrap()
+        )]
+    );
+}
+
+#[test]
+fn test_failed_hook_escrows_unescrowed_tokens() {
+    let mut module = hooks_module();
+    module.ctx_mut().fail_calls = true;
+
+    // The tokens are native to this chain, so they were unescrowed on
+    // receipt.
+    let packet = recv_packet("100transfer/channel-5/uatom", CONTRACT, wasm_memo("{}"));
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(is_error_ack(&ack.unwrap()));
+    assert_eq!(
+        module.ctx().escrowed,
+        vec![(signer(CONTRACT), "100uatom".parse().unwrap())]
+    );
+}
+
+#[test]
+fn test_failed_hook_keeps_tokens_which_cannot_be_taken_back() {
+    let mut module = hooks_module();
+    module.ctx_mut().fail_calls = true;
+    module.ctx_mut().lock_funds = true;
+
+    // The packet is acknowledged as received, since its tokens stay with
+    // the contract and cannot be refunded to the sender.
+    let packet = recv_packet("100uatom", CONTRACT, wasm_memo("{}"));
+    let (extras, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    assert!(!is_error_ack(&ack.unwrap()));
+    assert!(module.ctx().burned.is_empty());
+    assert!(module.ctx().escrowed.is_empty());
+    assert!(extras
+        .log
+        .iter()
+        .any(|log| log.contains("cannot take back the tokens")));
+}
+
+#[test]
+fn test_send_packet_with_callback_to_other_contract_fails() {
+    let mut module = hooks_module();
+
+    let memo = r#"{"ibc_callback":"cosmos1other"}"#.to_string();
+    assert!(module.send_packet(sent_packet(memo)).is_err());
+
+    let memo = format!(r#"{{"ibc_callback":"{CONTRACT}"}}"#);
+    module.send_packet(sent_packet(memo)).unwrap();
+}
+
+#[test]
+fn test_send_transfer_with_callback_to_other_contract_fails() {
+    let mut ctx = dummy_transfer_channel_ctx(VERSION);
+    let mut module = hooks_module();
+
+    let msg = |memo: &str| {
+        let data = dummy_packet_data("100uatom".parse().unwrap())
+            .sender(signer(CONTRACT))
+            .memo(memo.into())
+            .call();
+
+        dummy_msg_transfer(data)
+            .timeout_height_on_b(TimeoutHeight::At(ctx.latest_height().add(10)))
+            .call()
+    };
+
+    let (to_other, to_sender) = (
+        msg(r#"{"ibc_callback":"cosmos1other"}"#),
+        msg(&format!(r#"{{"ibc_callback":"{CONTRACT}"}}"#)),
+    );
+
+    assert!(send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut module,
+        to_other,
+    )
+    .is_err());
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &mut DummyTransferModule::new(),
+        &mut module,
+        to_sender,
+    )
+    .unwrap();
+
+    // Only the packet calling back its sender was committed.
+    let commitment_path = |sequence: u64| {
+        CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(sequence),
+        )
+    };
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&commitment_path(1))
+        .is_ok());
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&commitment_path(2))
+        .is_err());
+}
+
+#[test]
+fn test_ack_and_timeout_call_back_sender() {
+    let mut module = hooks_module();
+
+    let packet = sent_packet(format!(r#"{{"ibc_callback":"{CONTRACT}"}}"#));
+    let ack: Acknowledgement =
+        AcknowledgementStatus::success(StatusValue::new("AQ==").unwrap()).into();
+
+    let (_, res) = module.on_acknowledgement_packet_execute(&packet, &ack, &signer("relayer"));
+    res.unwrap();
+    let (_, res) = module.on_timeout_packet_execute(&packet, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(module.ctx().ack_callbacks, vec![(signer(CONTRACT), ack)]);
+    assert_eq!(module.ctx().timeout_callbacks, vec![signer(CONTRACT)]);
+}
+
+#[test]
+fn test_failed_callback_does_not_fail_ack() {
+    let mut module = hooks_module();
+    module.ctx_mut().fail_calls = true;
+
+    let packet = sent_packet(format!(r#"{{"ibc_callback":"{CONTRACT}"}}"#));
+    let ack = AcknowledgementStatus::success(StatusValue::new("AQ==").unwrap()).into();
+
+    let (extras, res) = module.on_acknowledgement_packet_execute(&packet, &ack, &signer("relayer"));
+
+    res.unwrap();
+    assert_eq!(extras.log.len(), 1);
+}
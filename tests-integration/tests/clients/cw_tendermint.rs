@@ -0,0 +1,263 @@
+use core::time::Duration;
+
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockStorage};
+use cosmwasm_std::{from_json, Addr, Env, Order, Storage, Timestamp as CwTimestamp};
+use ibc::clients::tendermint::consensus_state::ConsensusState;
+use ibc::clients::wasm_types::client_state::ClientState as WasmClientState;
+use ibc::core::client::types::{Height, Status};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ToVec};
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc_client_cw::types::{
+    CheckForMisbehaviourMsg, CheckForMisbehaviourResult, EmptyResult, InstantiateMsg, MerklePath,
+    MigrateClientStoreMsg, QueryMsg, StatusMsg, StatusResult, SudoMsg, TimestampAtHeightMsg,
+    TimestampAtHeightResult, UpdateStateMsg, UpdateStateResult, VerifyClientMessageMsg,
+    VerifyMembershipMsg,
+};
+use ibc_client_cw::utils::CLIENT_STATE_KEY;
+use ibc_client_tendermint_cw::entrypoint::{instantiate, query, sudo};
+use ibc_testkit::hosts::tendermint::BlockParams;
+use ibc_testkit::hosts::{TendermintHost, TestBlock, TestHost};
+use serde::de::DeserializeOwned;
+
+const CHECKSUM: [u8; 32] = [7; 32];
+const GENESIS_TIMESTAMP_SECS: u64 = 1_700_000_000;
+const APP_HASH: [u8; 32] = [1; 32];
+
+fn tendermint_host(num_blocks: u64) -> TendermintHost {
+    let mut host = TendermintHost::default();
+    let params = BlockParams::default();
+
+    let genesis = host.generate_block(
+        APP_HASH.to_vec(),
+        1,
+        Timestamp::from_nanoseconds(GENESIS_TIMESTAMP_SECS * 1_000_000_000),
+        &params,
+    );
+    host.push_block(genesis);
+
+    for _ in 1..num_blocks {
+        host.commit_block(APP_HASH.to_vec(), Duration::from_secs(1), &params);
+    }
+
+    host
+}
+
+/// Returns the environment of a contract serving the `08-wasm-0` client,
+/// shortly after the host's latest block.
+fn contract_env(host: &TendermintHost, elapsed: Duration) -> Env {
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("08-wasm-0");
+    env.block.height = 100;
+    env.block.time = CwTimestamp::from_nanos(
+        host.latest_block().timestamp().nanoseconds() + elapsed.as_nanos() as u64,
+    );
+    env
+}
+
+fn instantiate_msg(host: &TendermintHost, height: &Height) -> InstantiateMsg {
+    let client_state = host.generate_client_state(height, &Default::default());
+    let consensus_state =
+        ConsensusState::from(host.get_block(height).expect("block exists").into_header());
+
+    InstantiateMsg {
+        client_state: Any::from(client_state).to_vec(),
+        consensus_state: Any::from(consensus_state).to_vec(),
+        checksum: CHECKSUM.to_vec(),
+    }
+}
+
+fn instantiated_storage(host: &TendermintHost, height: &Height) -> MockStorage {
+    let mut deps = mock_dependencies();
+    let env = contract_env(host, Duration::from_secs(5));
+    let info = message_info(&Addr::unchecked("creator"), &[]);
+
+    instantiate(deps.as_mut(), env, info, instantiate_msg(host, height)).expect("no error");
+
+    deps.storage
+}
+
+fn query_as<T: DeserializeOwned>(storage: &MockStorage, env: Env, msg: QueryMsg) -> T {
+    let mut deps = mock_dependencies();
+    deps.storage = clone_storage(storage);
+
+    from_json(query(deps.as_ref(), env, msg).expect("no error")).expect("no error")
+}
+
+fn clone_storage(storage: &MockStorage) -> MockStorage {
+    let mut cloned = MockStorage::new();
+    for (key, value) in storage.range(None, None, Order::Ascending) {
+        cloned.set(&key, &value);
+    }
+    cloned
+}
+
+fn stored_wasm_client_state(storage: &MockStorage, prefix: &[u8]) -> WasmClientState {
+    let bytes = storage
+        .get(&[prefix, CLIENT_STATE_KEY].concat())
+        .expect("client state exists");
+    Protobuf::<Any>::decode_vec(&bytes).expect("no error")
+}
+
+#[test]
+fn test_cw_instantiate_and_query() {
+    let host = tendermint_host(1);
+    let height = Height::new(0, 1).unwrap();
+    let storage = instantiated_storage(&host, &height);
+    let env = contract_env(&host, Duration::from_secs(5));
+
+    let wasm_client_state = stored_wasm_client_state(&storage, b"");
+    assert_eq!(wasm_client_state.checksum, CHECKSUM.to_vec());
+    assert_eq!(wasm_client_state.latest_height, height);
+
+    let status: StatusResult = query_as(&storage, env.clone(), QueryMsg::Status(StatusMsg {}));
+    assert_eq!(status.status, Status::Active);
+
+    let timestamp: TimestampAtHeightResult = query_as(
+        &storage,
+        env,
+        QueryMsg::TimestampAtHeight(TimestampAtHeightMsg { height }),
+    );
+    assert_eq!(
+        timestamp.timestamp,
+        host.latest_block().timestamp().nanoseconds()
+    );
+}
+
+#[test]
+fn test_cw_status_expired() {
+    let host = tendermint_host(1);
+    let height = Height::new(0, 1).unwrap();
+    let storage = instantiated_storage(&host, &height);
+
+    // Beyond the default trusting period of the client
+    let env = contract_env(&host, Duration::from_secs(64_001));
+
+    let status: StatusResult = query_as(&storage, env, QueryMsg::Status(StatusMsg {}));
+    assert_eq!(status.status, Status::Expired);
+}
+
+#[test]
+fn test_cw_update_state() {
+    let host = tendermint_host(3);
+    let trusted_height = Height::new(0, 1).unwrap();
+    let target_height = Height::new(0, 3).unwrap();
+    let storage = instantiated_storage(&host, &trusted_height);
+    let env = contract_env(&host, Duration::from_secs(5));
+
+    let header = host
+        .get_block(&target_height)
+        .unwrap()
+        .into_header_with_trusted(&host.get_block(&trusted_height).unwrap());
+    let client_message = Any::from(header).to_vec();
+
+    let _: EmptyResult = query_as(
+        &storage,
+        env.clone(),
+        QueryMsg::VerifyClientMessage(VerifyClientMessageMsg {
+            client_message: client_message.clone(),
+        }),
+    );
+    let misbehaviour: CheckForMisbehaviourResult = query_as(
+        &storage,
+        env.clone(),
+        QueryMsg::CheckForMisbehaviour(CheckForMisbehaviourMsg {
+            client_message: client_message.clone(),
+        }),
+    );
+    assert!(!misbehaviour.found_misbehaviour);
+
+    let mut deps = mock_dependencies();
+    deps.storage = storage;
+    let response = sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::UpdateState(UpdateStateMsg { client_message }),
+    )
+    .expect("no error");
+    let result: UpdateStateResult = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(result.heights, vec![target_height]);
+
+    let wasm_client_state = stored_wasm_client_state(&deps.storage, b"");
+    assert_eq!(wasm_client_state.latest_height, target_height);
+    assert_eq!(wasm_client_state.checksum, CHECKSUM.to_vec());
+
+    let timestamp: TimestampAtHeightResult = query_as(
+        &deps.storage,
+        env,
+        QueryMsg::TimestampAtHeight(TimestampAtHeightMsg {
+            height: target_height,
+        }),
+    );
+    assert_eq!(
+        timestamp.timestamp,
+        host.latest_block().timestamp().nanoseconds()
+    );
+}
+
+#[test]
+fn test_cw_verify_membership_failures() {
+    let host = tendermint_host(1);
+    let height = Height::new(0, 1).unwrap();
+    let env = contract_env(&host, Duration::from_secs(5));
+
+    let verify_membership = |key_path: Vec<Vec<u8>>, delay_time_period: u64| {
+        let mut deps = mock_dependencies();
+        deps.storage = instantiated_storage(&host, &height);
+
+        sudo(
+            deps.as_mut(),
+            env.clone(),
+            SudoMsg::VerifyMembership(VerifyMembershipMsg {
+                height,
+                delay_time_period,
+                delay_block_period: 0,
+                proof: vec![1, 2, 3],
+                merkle_path: MerklePath { key_path },
+                value: b"value".to_vec(),
+            }),
+        )
+        .unwrap_err()
+        .to_string()
+    };
+
+    // The key path must hold exactly a prefix and a path
+    let err = verify_membership(vec![b"ibc".to_vec()], 0);
+    assert!(err.contains("invalid merkle path"), "{err}");
+
+    // The delay period must have passed since the consensus state was stored
+    let err = verify_membership(vec![b"ibc".to_vec(), b"path".to_vec()], u64::MAX);
+    assert!(err.contains("insufficient time elapsed"), "{err}");
+
+    // The proof must be a valid merkle proof
+    let err = verify_membership(vec![b"ibc".to_vec(), b"path".to_vec()], 0);
+    assert!(err.contains("client error"), "{err}");
+}
+
+#[test]
+fn test_cw_migrate_client_store() {
+    let host = tendermint_host(2);
+    let subject_height = Height::new(0, 1).unwrap();
+    let substitute_height = Height::new(0, 2).unwrap();
+
+    let subject = instantiated_storage(&host, &subject_height);
+    let substitute = instantiated_storage(&host, &substitute_height);
+
+    let mut deps = mock_dependencies();
+    for (prefix, storage) in [(&b"subject/"[..], &subject), (b"substitute/", &substitute)] {
+        for (key, value) in storage.range(None, None, Order::Ascending) {
+            deps.storage.set(&[prefix, &key].concat(), &value);
+        }
+    }
+
+    sudo(
+        deps.as_mut(),
+        contract_env(&host, Duration::from_secs(5)),
+        SudoMsg::MigrateClientStore(MigrateClientStoreMsg {}),
+    )
+    .expect("no error");
+
+    let wasm_client_state = stored_wasm_client_state(&deps.storage, b"subject/");
+    assert_eq!(wasm_client_state.latest_height, substitute_height);
+    assert_eq!(wasm_client_state.checksum, CHECKSUM.to_vec());
+}
@@ -0,0 +1,390 @@
+use ibc::clients::solomachine::client_state::ClientState;
+use ibc::clients::solomachine::types::{
+    client_type as sm_client_type, encode_merkle_path, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, Header, Misbehaviour, PublicKey, SignBytes,
+    SignatureAndData, TimestampedSignatureData,
+};
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientStatePath, Path};
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::ToVec;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{Single, Sum};
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use k256::ecdsa::signature::Signer;
+use prost::Message;
+
+const DIVERSIFIER: &str = "diversifier";
+const GENESIS_TIMESTAMP_NANOS: u64 = 1_700_000_000_000_000_000;
+
+/// A key held by the solo machine in the tests.
+enum SigningKey {
+    Secp256k1(k256::ecdsa::SigningKey),
+    Ed25519(ed25519_consensus::SigningKey),
+}
+
+impl SigningKey {
+    fn secp256k1(seed: u8) -> Self {
+        Self::Secp256k1(k256::ecdsa::SigningKey::from_bytes(&[seed; 32].into()).unwrap())
+    }
+
+    fn ed25519(seed: u8) -> Self {
+        Self::Ed25519(ed25519_consensus::SigningKey::from([seed; 32]))
+    }
+
+    fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Secp256k1(key) => PublicKey::Secp256k1(*key.verifying_key()),
+            Self::Ed25519(key) => PublicKey::Ed25519(key.verification_key()),
+        }
+    }
+
+    /// Signs the bytes, returning the encoded `SignatureDescriptor.Data`.
+    fn sign(&self, sign_bytes: SignBytes) -> Vec<u8> {
+        let msg = sign_bytes.encode_vec();
+        let signature = match self {
+            Self::Secp256k1(key) => {
+                let signature: k256::ecdsa::Signature = key.sign(&msg);
+                signature.to_vec()
+            }
+            Self::Ed25519(key) => key.sign(&msg).to_bytes().to_vec(),
+        };
+
+        RawSignatureData {
+            sum: Some(Sum::Single(Single { mode: 1, signature })),
+        }
+        .encode_to_vec()
+    }
+}
+
+fn timestamp(offset_nanos: u64) -> Timestamp {
+    Timestamp::from_nanoseconds(GENESIS_TIMESTAMP_NANOS + offset_nanos)
+}
+
+fn client_state(key: &SigningKey, sequence: u64) -> ClientStateType {
+    ClientStateType::new(
+        sequence,
+        false,
+        ConsensusStateType::new(key.public_key(), DIVERSIFIER.to_string(), timestamp(0)),
+    )
+}
+
+fn client_id() -> ClientId {
+    sm_client_type().build_client_id(0)
+}
+
+fn prefix() -> CommitmentPrefix {
+    CommitmentPrefix::from_bytes(b"ibc")
+}
+
+fn path() -> Path {
+    ClientStatePath::new(ClientId::new("07-tendermint", 0).unwrap()).into()
+}
+
+/// Returns a proof of `value` under `path()`, signed at `sequence`.
+fn proof(
+    key: &SigningKey,
+    sequence: u64,
+    value: &[u8],
+    timestamp: Timestamp,
+) -> CommitmentProofBytes {
+    let signature_data = key.sign(SignBytes {
+        sequence,
+        timestamp,
+        diversifier: DIVERSIFIER.to_string(),
+        path: encode_merkle_path(&[prefix().as_bytes(), path().to_string().as_bytes()]),
+        data: value.to_vec(),
+    });
+
+    TimestampedSignatureData {
+        signature_data,
+        timestamp,
+    }
+    .encode_vec()
+    .try_into()
+    .unwrap()
+}
+
+fn signed_header(key: &SigningKey, sequence: u64, new_key: &SigningKey) -> Header {
+    let mut header = Header {
+        timestamp: timestamp(10),
+        signature: vec![],
+        new_public_key: new_key.public_key(),
+        new_diversifier: DIVERSIFIER.to_string(),
+    };
+    header.signature = key.sign(header.sign_bytes(sequence, DIVERSIFIER.to_string()));
+    header
+}
+
+fn signature_and_data(key: &SigningKey, sequence: u64, data: &[u8]) -> SignatureAndData {
+    let mut signature_and_data = SignatureAndData {
+        signature: vec![],
+        path: encode_merkle_path(&[b"ibc", b"path"]),
+        data: data.to_vec(),
+        timestamp: timestamp(10),
+    };
+    signature_and_data.signature =
+        key.sign(signature_and_data.sign_bytes(sequence, DIVERSIFIER.to_string()));
+    signature_and_data
+}
+
+/// A host with a solo machine client at sequence `1`, signing with `key`.
+fn ctx_with_client(key: &SigningKey) -> MockContext {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_state = client_state(key, 1);
+    let msg = MsgCreateClient::new(
+        client_state.clone().into(),
+        client_state.consensus_state.into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    validate(&ctx.ibc_store, &router, msg_envelope.clone()).expect("no error");
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope).expect("no error");
+
+    ctx
+}
+
+fn update_client(ctx: &mut MockContext, client_message: Any) -> Result<(), String> {
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id(),
+        client_message,
+        signer: dummy_account_id(),
+    }));
+
+    validate(&ctx.ibc_store, &router, msg_envelope.clone()).map_err(|e| e.to_string())?;
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope).map_err(|e| e.to_string())
+}
+
+fn stored_client_state(ctx: &MockContext) -> AnyClientState {
+    ctx.ibc_store.client_state(&client_id()).unwrap()
+}
+
+#[test]
+fn test_create_client_ok() {
+    let key = SigningKey::secp256k1(1);
+    let ctx = ctx_with_client(&key);
+
+    assert_eq!(stored_client_state(&ctx), client_state(&key, 1).into());
+}
+
+#[test]
+fn test_create_client_mismatched_consensus_state() {
+    let key = SigningKey::secp256k1(1);
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let other_consensus_state =
+        ConsensusStateType::new(key.public_key(), "other".to_string(), timestamp(0));
+    let msg = MsgCreateClient::new(
+        Any::from(client_state(&key, 1)),
+        other_consensus_state.into(),
+        dummy_account_id(),
+    );
+
+    let res = execute(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_update_client_rotates_key() {
+    let key = SigningKey::secp256k1(1);
+    let new_key = SigningKey::ed25519(2);
+    let mut ctx = ctx_with_client(&key);
+
+    update_client(&mut ctx, signed_header(&key, 1, &new_key).into()).expect("no error");
+
+    let AnyClientState::Solomachine(client_state) = stored_client_state(&ctx) else {
+        panic!("client state is not a solo machine client state")
+    };
+    assert_eq!(client_state.inner().sequence, 2);
+    assert_eq!(client_state.latest_height(), Height::new(0, 2).unwrap());
+    assert_eq!(
+        client_state.inner().consensus_state.public_key,
+        new_key.public_key()
+    );
+    assert_eq!(
+        client_state.inner().consensus_state.timestamp,
+        timestamp(10)
+    );
+
+    // The next header must be signed by the new key, at the new sequence
+    let err = update_client(&mut ctx, signed_header(&key, 2, &key).into()).unwrap_err();
+    assert!(err.contains("failed to verify signature"), "{err}");
+
+    update_client(&mut ctx, signed_header(&new_key, 2, &key).into()).expect("no error");
+}
+
+#[test]
+fn test_update_client_wrong_sequence() {
+    let key = SigningKey::secp256k1(1);
+    let mut ctx = ctx_with_client(&key);
+
+    let err = update_client(&mut ctx, signed_header(&key, 2, &key).into()).unwrap_err();
+    assert!(err.contains("failed to verify signature"), "{err}");
+}
+
+#[test]
+fn test_misbehaviour_freezes_client() {
+    let key = SigningKey::secp256k1(1);
+    let mut ctx = ctx_with_client(&key);
+
+    let misbehaviour = Misbehaviour {
+        sequence: 1,
+        signature_one: signature_and_data(&key, 1, b"one"),
+        signature_two: signature_and_data(&key, 1, b"two"),
+    };
+
+    update_client(&mut ctx, misbehaviour.into()).expect("no error");
+
+    let AnyClientState::Solomachine(client_state) = stored_client_state(&ctx) else {
+        panic!("client state is not a solo machine client state")
+    };
+    assert!(client_state.inner().is_frozen());
+    assert_eq!(
+        client_state.status(&ctx.ibc_store, &client_id()).unwrap(),
+        Status::Frozen
+    );
+}
+
+#[test]
+fn test_misbehaviour_signed_by_other_key() {
+    let key = SigningKey::secp256k1(1);
+    let other_key = SigningKey::secp256k1(2);
+    let mut ctx = ctx_with_client(&key);
+
+    let misbehaviour = Misbehaviour {
+        sequence: 1,
+        signature_one: signature_and_data(&key, 1, b"one"),
+        signature_two: signature_and_data(&other_key, 1, b"two"),
+    };
+
+    let err = update_client(&mut ctx, misbehaviour.into()).unwrap_err();
+    assert!(err.contains("failed to verify signature"), "{err}");
+}
+
+#[test]
+fn test_verify_membership() {
+    for key in [SigningKey::secp256k1(1), SigningKey::ed25519(1)] {
+        let client_state = ClientState::from(client_state(&key, 3));
+        let root = CommitmentRoot::from_bytes(&[]);
+        let value = b"value".to_vec();
+
+        client_state
+            .verify_membership(
+                &prefix(),
+                &proof(&key, 3, &value, timestamp(5)),
+                &root,
+                path(),
+                value.clone(),
+            )
+            .expect("no error");
+
+        // A signature over another value
+        assert!(client_state
+            .verify_membership(
+                &prefix(),
+                &proof(&key, 3, b"other", timestamp(5)),
+                &root,
+                path(),
+                value.clone(),
+            )
+            .is_err());
+
+        // A signature at another sequence
+        assert!(client_state
+            .verify_membership(
+                &prefix(),
+                &proof(&key, 2, &value, timestamp(5)),
+                &root,
+                path(),
+                value.clone(),
+            )
+            .is_err());
+
+        // A signature older than the consensus state
+        let err = client_state
+            .verify_membership(
+                &prefix(),
+                &proof(&key, 3, &value, Timestamp::from_nanoseconds(1)),
+                &root,
+                path(),
+                value,
+            )
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("insufficient proof timestamp"),
+            "{err}"
+        );
+    }
+}
+
+#[test]
+fn test_verify_non_membership() {
+    let key = SigningKey::secp256k1(1);
+    let client_state = ClientState::from(client_state(&key, 3));
+    let root = CommitmentRoot::from_bytes(&[]);
+
+    client_state
+        .verify_non_membership(&prefix(), &proof(&key, 3, &[], timestamp(5)), &root, path())
+        .expect("no error");
+
+    assert!(client_state
+        .verify_non_membership(
+            &prefix(),
+            &proof(&key, 3, b"value", timestamp(5)),
+            &root,
+            path()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_validate_proof_height() {
+    let key = SigningKey::secp256k1(1);
+    let client_state = ClientState::from(client_state(&key, 3));
+
+    client_state
+        .validate_proof_height(Height::new(0, 3).unwrap())
+        .expect("no error");
+    assert!(client_state
+        .validate_proof_height(Height::new(0, 2).unwrap())
+        .is_err());
+    assert!(client_state
+        .validate_proof_height(Height::new(0, 4).unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_client_state_encoding_roundtrip() {
+    for key in [SigningKey::secp256k1(1), SigningKey::ed25519(1)] {
+        let client_state = client_state(&key, 7);
+
+        let decoded = ClientStateType::try_from(Any::from(client_state.clone())).expect("no error");
+        assert_eq!(decoded, client_state);
+
+        let any = Any::from(client_state);
+        let decoded = <ClientState as Protobuf<Any>>::decode_vec(&any.to_vec()).expect("no error");
+        assert_eq!(decoded.inner().sequence, 7);
+    }
+}
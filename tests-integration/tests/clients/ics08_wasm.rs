@@ -0,0 +1,406 @@
+use ibc::clients::wasm::client_state::ClientState as WasmClientState;
+use ibc::clients::wasm::engine::ClientStore;
+use ibc::clients::wasm::error::WasmClientError;
+use ibc::clients::wasm::handler::migrate_contract::{
+    migrate_contract_execute, migrate_contract_validate,
+};
+use ibc::clients::wasm::handler::remove_checksum::{
+    remove_checksum_execute, remove_checksum_validate,
+};
+use ibc::clients::wasm::handler::store_code::{
+    code_checksum, store_code_execute, store_code_validate,
+};
+use ibc::clients::wasm::types::client_message::ClientMessage;
+use ibc::clients::wasm::types::client_state::ClientState as WasmClientStateType;
+use ibc::clients::wasm::types::consensus_state::ConsensusState as WasmConsensusStateType;
+use ibc::clients::wasm::types::contract::{
+    InstantiateMsg, QueryMsg, StatusMsg, SudoMsg, TimestampAtHeightMsg,
+};
+use ibc::clients::wasm::types::msgs::migrate_contract::MsgMigrateContract;
+use ibc::clients::wasm::types::msgs::remove_checksum::MsgRemoveChecksum;
+use ibc::clients::wasm::types::msgs::store_code::MsgStoreCode;
+use ibc::clients::wasm::types::{
+    client_type as wasm_client_type, SUBJECT_PREFIX, SUBSTITUTE_PREFIX,
+};
+use ibc::core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+use ibc::core::host::types::path::{ClientConsensusStatePath, Path, ReceiptPath};
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::wasm::{
+    MockClientStore, MockWasmContext, WasmCall, WasmEntrypoint,
+};
+
+const CODE: &[u8] = b"\0asm light client";
+const NEW_CODE: &[u8] = b"\0asm light client v2";
+
+fn client_id(counter: u64) -> ClientId {
+    wasm_client_type().build_client_id(counter)
+}
+
+fn height(revision_height: u64) -> Height {
+    Height::new(0, revision_height).unwrap()
+}
+
+fn wasm_client_state(checksum: Vec<u8>) -> WasmClientStateType {
+    WasmClientStateType {
+        data: b"client state".to_vec(),
+        checksum,
+        latest_height: height(5),
+    }
+}
+
+/// A host running a single Wasm client on the stored `CODE`.
+fn ctx_with_client() -> MockWasmContext {
+    let checksum = code_checksum(CODE);
+
+    MockWasmContext::default()
+        .with_code(checksum.clone(), CODE.to_vec())
+        .with_client_state(client_id(0), wasm_client_state(checksum))
+}
+
+fn last_call(ctx: &MockWasmContext) -> WasmCall {
+    ctx.engine.calls().last().cloned().unwrap()
+}
+
+#[test]
+fn test_store_code() {
+    let mut ctx = MockWasmContext::default();
+    let msg = MsgStoreCode {
+        signer: dummy_account_id(),
+        wasm_byte_code: CODE.to_vec(),
+    };
+
+    store_code_validate(&ctx, &msg).unwrap();
+    let checksum = store_code_execute(&mut ctx, msg.clone()).unwrap();
+
+    assert_eq!(checksum, code_checksum(CODE));
+    assert!(ctx.checksums.contains(&checksum));
+    assert_eq!(
+        ctx.engine.codes.lock().get(&checksum).map(Vec::as_slice),
+        Some(CODE)
+    );
+
+    assert!(matches!(
+        store_code_validate(&ctx, &msg),
+        Err(WasmClientError::DuplicateChecksum(_))
+    ));
+}
+
+#[test]
+fn test_store_code_rejects_invalid_msgs() {
+    let ctx = MockWasmContext::default();
+
+    let unauthorized = MsgStoreCode {
+        signer: Signer::from("cosmos1attacker".to_string()),
+        wasm_byte_code: CODE.to_vec(),
+    };
+    assert!(matches!(
+        store_code_validate(&ctx, &unauthorized),
+        Err(WasmClientError::UnauthorizedSigner { .. })
+    ));
+
+    let empty = MsgStoreCode {
+        signer: dummy_account_id(),
+        wasm_byte_code: vec![],
+    };
+    assert!(matches!(
+        store_code_validate(&ctx, &empty),
+        Err(WasmClientError::EmptyWasmCode)
+    ));
+}
+
+#[test]
+fn test_remove_checksum() {
+    let mut ctx = ctx_with_client();
+    let checksum = code_checksum(CODE);
+    let msg = MsgRemoveChecksum {
+        signer: dummy_account_id(),
+        checksum: checksum.clone(),
+    };
+
+    remove_checksum_validate(&ctx, &msg).unwrap();
+    remove_checksum_execute(&mut ctx, msg.clone()).unwrap();
+
+    assert!(!ctx.checksums.contains(&checksum));
+    assert!(ctx.engine.codes.lock().is_empty());
+
+    // Clients running the removed code are no longer authorized.
+    let client_state = ctx.client_state(&client_id(0)).unwrap();
+    assert_eq!(
+        client_state.status(&ctx, &client_id(0)).unwrap(),
+        Status::Unauthorized
+    );
+
+    assert!(matches!(
+        remove_checksum_validate(&ctx, &msg),
+        Err(WasmClientError::MissingChecksum(_))
+    ));
+}
+
+#[test]
+fn test_migrate_contract() {
+    let new_checksum = code_checksum(NEW_CODE);
+    let mut ctx = ctx_with_client().with_code(new_checksum.clone(), NEW_CODE.to_vec());
+    let msg = MsgMigrateContract {
+        signer: dummy_account_id(),
+        client_id: client_id(0),
+        checksum: new_checksum.clone(),
+        msg: b"{}".to_vec(),
+    };
+
+    migrate_contract_validate(&ctx, &msg).unwrap();
+    migrate_contract_execute(&mut ctx, msg).unwrap();
+
+    assert_eq!(
+        last_call(&ctx),
+        WasmCall {
+            entrypoint: WasmEntrypoint::Migrate,
+            checksum: new_checksum.clone(),
+            store: MockClientStore::Client(client_id(0)),
+            msg: b"{}".to_vec(),
+        }
+    );
+    assert_eq!(
+        ctx.client_state(&client_id(0)).unwrap().checksum(),
+        new_checksum.as_slice()
+    );
+}
+
+#[test]
+fn test_migrate_contract_rejects_invalid_checksums() {
+    let ctx = ctx_with_client();
+
+    let unknown = MsgMigrateContract {
+        signer: dummy_account_id(),
+        client_id: client_id(0),
+        checksum: code_checksum(NEW_CODE),
+        msg: vec![],
+    };
+    assert!(matches!(
+        migrate_contract_validate(&ctx, &unknown),
+        Err(WasmClientError::MissingChecksum(_))
+    ));
+
+    let unchanged = MsgMigrateContract {
+        checksum: code_checksum(CODE),
+        ..unknown
+    };
+    assert!(matches!(
+        migrate_contract_validate(&ctx, &unchanged),
+        Err(WasmClientError::UnchangedChecksum { .. })
+    ));
+}
+
+#[test]
+fn test_initialise_instantiates_contract() {
+    let mut ctx = ctx_with_client();
+    let client_state = ctx.client_state(&client_id(0)).unwrap();
+    let consensus_state = WasmConsensusStateType::new(b"consensus state".to_vec());
+
+    client_state
+        .initialise(&mut ctx, &client_id(0), consensus_state.into())
+        .unwrap();
+
+    let call = last_call(&ctx);
+    assert_eq!(call.entrypoint, WasmEntrypoint::Instantiate);
+    assert_eq!(call.store, MockClientStore::Client(client_id(0)));
+    assert_eq!(
+        serde_json::from_slice::<InstantiateMsg>(&call.msg).unwrap(),
+        InstantiateMsg {
+            client_state: b"client state".to_vec(),
+            consensus_state: b"consensus state".to_vec(),
+            checksum: code_checksum(CODE),
+        }
+    );
+    assert!(ctx.update_meta.contains_key(&(client_id(0), height(5))));
+}
+
+#[test]
+fn test_initialise_requires_stored_code() {
+    let mut ctx =
+        MockWasmContext::default().with_client_state(client_id(0), wasm_client_state(vec![1; 32]));
+    let client_state = ctx.client_state(&client_id(0)).unwrap();
+    let consensus_state = WasmConsensusStateType::new(b"consensus state".to_vec());
+
+    assert!(client_state
+        .initialise(&mut ctx, &client_id(0), consensus_state.into())
+        .is_err());
+    assert!(ctx.engine.calls().is_empty());
+}
+
+#[test]
+fn test_queries_are_forwarded_to_contract() {
+    let ctx = ctx_with_client();
+    let client_state = ctx.client_state(&client_id(0)).unwrap();
+
+    assert_eq!(
+        client_state.status(&ctx, &client_id(0)).unwrap(),
+        Status::Active
+    );
+    assert_eq!(
+        serde_json::from_slice::<QueryMsg>(&last_call(&ctx).msg).unwrap(),
+        QueryMsg::Status(StatusMsg {})
+    );
+
+    ctx.engine
+        .set_response("timestamp_at_height", r#"{"timestamp":42}"#);
+    let path = ClientConsensusStatePath::new(client_id(0), 0, 3);
+    assert_eq!(
+        client_state.timestamp_at_height(&ctx, &path).unwrap(),
+        Timestamp::from_nanoseconds(42)
+    );
+    assert_eq!(
+        serde_json::from_slice::<QueryMsg>(&last_call(&ctx).msg).unwrap(),
+        QueryMsg::TimestampAtHeight(TimestampAtHeightMsg { height: height(3) })
+    );
+
+    ctx.engine
+        .set_response("check_for_misbehaviour", r#"{"found_misbehaviour":true}"#);
+    let client_message = ClientMessage {
+        data: b"header".to_vec(),
+    };
+    assert!(client_state
+        .check_for_misbehaviour(&ctx, &client_id(0), client_message.into())
+        .unwrap());
+}
+
+#[test]
+fn test_verify_membership_at_height() {
+    let ctx = ctx_with_client();
+    let client_state = ctx.client_state(&client_id(0)).unwrap();
+    let path = ClientConsensusStatePath::new(client_id(0), 0, 4);
+    let receipt_path = ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1));
+    let proof = CommitmentProofBytes::try_from(vec![7]).unwrap();
+    let prefix = CommitmentPrefix::from_bytes(b"ibc");
+
+    client_state
+        .verify_non_membership_at_height(
+            &ctx,
+            &path,
+            &prefix,
+            &proof,
+            Path::Receipt(receipt_path.clone()),
+        )
+        .unwrap();
+
+    let call = last_call(&ctx);
+    assert_eq!(call.entrypoint, WasmEntrypoint::Sudo);
+    assert_eq!(call.store, MockClientStore::Client(client_id(0)));
+    let SudoMsg::VerifyNonMembership(msg) = serde_json::from_slice(&call.msg).unwrap() else {
+        panic!("expected a verify_non_membership message");
+    };
+    assert_eq!(msg.height, height(4));
+    assert_eq!(msg.proof, vec![7]);
+    assert_eq!(
+        msg.merkle_path.key_path,
+        vec![b"ibc".to_vec(), receipt_path.to_string().into_bytes()]
+    );
+
+    // Proofs rejected by the contract fail verification.
+    ctx.engine.remove_response("verify_membership");
+    assert!(client_state
+        .verify_membership_at_height(
+            &ctx,
+            &path,
+            &prefix,
+            &proof,
+            Path::Receipt(receipt_path),
+            vec![1],
+        )
+        .is_err());
+}
+
+#[test]
+fn test_update_state_records_update_meta() {
+    let mut ctx = ctx_with_client();
+    let client_state = ctx.client_state(&client_id(0)).unwrap();
+    ctx.engine.set_response(
+        "update_state",
+        r#"{"heights":[{"revision_number":0,"revision_height":6}]}"#,
+    );
+
+    let client_message = ClientMessage {
+        data: b"header".to_vec(),
+    };
+    let heights = client_state
+        .update_state(&mut ctx, &client_id(0), client_message.into())
+        .unwrap();
+
+    assert_eq!(heights, vec![height(6)]);
+    assert_eq!(
+        ctx.update_meta.get(&(client_id(0), height(6))),
+        Some(&(ctx.host_timestamp, ctx.host_height))
+    );
+}
+
+#[test]
+fn test_recovery_uses_subject_and_substitute_stores() {
+    let checksum = code_checksum(CODE);
+    let mut ctx = ctx_with_client().with_client_state(
+        client_id(1),
+        WasmClientStateType {
+            latest_height: height(9),
+            ..wasm_client_state(checksum.clone())
+        },
+    );
+    let subject: WasmClientState = ctx.client_state(&client_id(0)).unwrap();
+    let substitute = ctx.client_state(&client_id(1)).unwrap();
+
+    subject
+        .check_substitute(&ctx, substitute.clone().into())
+        .unwrap();
+    subject
+        .update_on_recovery(
+            &mut ctx,
+            &client_id(0),
+            &client_id(1),
+            substitute.into(),
+            WasmConsensusStateType::new(vec![1]).into(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        last_call(&ctx).store,
+        MockClientStore::Recovery {
+            subject: client_id(0),
+            substitute: client_id(1),
+        }
+    );
+    assert!(ctx.update_meta.contains_key(&(client_id(0), height(9))));
+
+    let different_code = wasm_client_state(vec![1; 32]);
+    assert!(subject
+        .check_substitute(&ctx, WasmClientState::from(different_code).into())
+        .is_err());
+}
+
+#[test]
+fn test_recovery_store_resolves_prefixed_keys() {
+    let subject = client_id(0);
+    let substitute = client_id(1);
+    let store = ClientStore::Recovery {
+        subject: &subject,
+        substitute: &substitute,
+    };
+
+    let subject_key = [SUBJECT_PREFIX, b"clientState"].concat();
+    let substitute_key = [SUBSTITUTE_PREFIX, b"clientState"].concat();
+
+    assert_eq!(
+        store.resolve(&subject_key),
+        Some((&subject, b"clientState".as_slice()))
+    );
+    assert_eq!(
+        store.resolve(&substitute_key),
+        Some((&substitute, b"clientState".as_slice()))
+    );
+    assert_eq!(store.resolve(b"clientState"), None);
+    assert_eq!(
+        ClientStore::Client(&subject).resolve(b"clientState"),
+        Some((&subject, b"clientState".as_slice()))
+    );
+}
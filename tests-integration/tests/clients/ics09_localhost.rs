@@ -0,0 +1,264 @@
+use ibc::clients::localhost::types::{
+    client_id as localhost_client_id, ClientState as LocalhostClientState, SENTINEL_PROOF,
+};
+use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::types::channel::{Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgAcknowledgement, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit,
+    MsgChannelOpenTry, MsgRecvPacket, MsgTimeout, PacketMsg,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::channel::types::Version;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
+use ibc::core::connection::types::{Counterparty as ConnectionCounterparty, State};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath, ReceiptPath};
+use ibc::core::host::ValidationContext;
+use ibc::primitives::proto::Any;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+
+fn version() -> Version {
+    Version::new("ics20-1".to_string())
+}
+
+fn proof() -> CommitmentProofBytes {
+    SENTINEL_PROOF.to_vec().try_into().expect("no error")
+}
+
+/// Opens a channel between the `transfer` port and itself over the localhost
+/// connection, with `channel-0` as the A side and `channel-1` as the B side.
+fn ctx_with_channel() -> MockContext {
+    let mut ctx = MockContext::default().with_localhost_client();
+
+    let msgs = [
+        ChannelMsg::OpenInit(MsgChannelOpenInit {
+            port_id_on_a: PortId::transfer(),
+            connection_hops_on_a: vec![ConnectionId::localhost()],
+            port_id_on_b: PortId::transfer(),
+            ordering: Order::Unordered,
+            signer: dummy_account_id(),
+            version_proposal: version(),
+        }),
+        #[allow(deprecated)]
+        ChannelMsg::OpenTry(MsgChannelOpenTry {
+            port_id_on_b: PortId::transfer(),
+            connection_hops_on_b: vec![ConnectionId::localhost()],
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            version_supported_on_a: version(),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: ctx.latest_height(),
+            ordering: Order::Unordered,
+            signer: dummy_account_id(),
+            version_proposal: Version::empty(),
+        }),
+        ChannelMsg::OpenAck(MsgChannelOpenAck {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            chan_id_on_b: ChannelId::new(1),
+            version_on_b: version(),
+            proof_chan_end_on_b: proof(),
+            proof_height_on_b: ctx.latest_height(),
+            signer: dummy_account_id(),
+        }),
+        ChannelMsg::OpenConfirm(MsgChannelOpenConfirm {
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: ctx.latest_height(),
+            signer: dummy_account_id(),
+        }),
+    ];
+
+    for msg in msgs {
+        ctx.dispatch(MsgEnvelope::from(msg)).expect("no error");
+    }
+
+    ctx
+}
+
+fn packet(timeout_height_on_b: TimeoutHeight) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: b"data".to_vec(),
+        timeout_height_on_b,
+        timeout_timestamp_on_b: TimeoutTimestamp::Never,
+    }
+}
+
+fn recv_packet_msg(packet: Packet, proof_height: Height) -> MsgEnvelope {
+    PacketMsg::Recv(MsgRecvPacket {
+        packet,
+        proof_commitment_on_a: proof(),
+        proof_height_on_a: proof_height,
+        signer: dummy_account_id(),
+    })
+    .into()
+}
+
+#[test]
+fn test_channel_handshake() {
+    let ctx = ctx_with_channel();
+
+    for chan_id in [ChannelId::new(0), ChannelId::new(1)] {
+        let chan_end = ctx
+            .ibc_store
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id))
+            .expect("no error");
+
+        assert_eq!(chan_end.state(), &ChannelState::Open);
+        assert_eq!(chan_end.connection_hops(), &[ConnectionId::localhost()]);
+    }
+}
+
+#[test]
+fn test_packet_flow() {
+    let mut ctx = ctx_with_channel();
+
+    let packet = packet(TimeoutHeight::At(ctx.latest_height().add(10)));
+    send_packet(&mut ctx.ibc_store, packet.clone()).expect("no error");
+
+    ctx.dispatch(recv_packet_msg(packet.clone(), ctx.latest_height()))
+        .expect("no error");
+
+    let receipt_path = ReceiptPath::new(&PortId::transfer(), &ChannelId::new(1), packet.seq_on_a);
+    assert!(ctx
+        .ibc_store
+        .get_packet_receipt(&receipt_path)
+        .expect("no error")
+        .is_ok());
+
+    let msg = PacketMsg::Ack(MsgAcknowledgement {
+        packet: packet.clone(),
+        acknowledgement: vec![1u8].try_into().expect("no error"),
+        proof_acked_on_b: proof(),
+        proof_height_on_b: ctx.latest_height(),
+        signer: dummy_account_id(),
+    });
+    ctx.dispatch(msg.into()).expect("no error");
+
+    let commitment_path =
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::new(0), packet.seq_on_a);
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&commitment_path)
+        .is_err());
+}
+
+#[test]
+fn test_timeout_packet() {
+    let mut ctx = ctx_with_channel();
+
+    let packet = packet(TimeoutHeight::At(ctx.latest_height().increment()));
+    send_packet(&mut ctx.ibc_store, packet.clone()).expect("no error");
+
+    ctx.advance_block_height();
+    ctx.advance_block_height();
+
+    // The packet can no longer be received
+    assert!(ctx
+        .dispatch(recv_packet_msg(packet.clone(), ctx.latest_height()))
+        .is_err());
+
+    let msg = PacketMsg::Timeout(MsgTimeout {
+        packet: packet.clone(),
+        next_seq_recv_on_b: Sequence::from(1),
+        proof_unreceived_on_b: proof(),
+        proof_height_on_b: ctx.latest_height(),
+        signer: dummy_account_id(),
+    });
+    ctx.dispatch(msg.into()).expect("no error");
+
+    let commitment_path =
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::new(0), packet.seq_on_a);
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&commitment_path)
+        .is_err());
+}
+
+#[test]
+fn test_recv_packet_not_sent() {
+    let mut ctx = ctx_with_channel();
+
+    let packet = packet(TimeoutHeight::At(ctx.latest_height().add(10)));
+
+    let err = ctx
+        .dispatch(recv_packet_msg(packet, ctx.latest_height()))
+        .unwrap_err();
+    assert!(err.to_string().contains("missing value"), "{err}");
+}
+
+#[test]
+fn test_recv_packet_proof_height_ahead_of_host() {
+    let mut ctx = ctx_with_channel();
+
+    let packet = packet(TimeoutHeight::At(ctx.latest_height().add(10)));
+    send_packet(&mut ctx.ibc_store, packet.clone()).expect("no error");
+
+    let err = ctx
+        .dispatch(recv_packet_msg(packet, ctx.latest_height().increment()))
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid proof height"), "{err}");
+}
+
+#[test]
+fn test_client_refreshed_every_block() {
+    let mut ctx = MockContext::default().with_localhost_client();
+
+    ctx.advance_block_height();
+
+    let client_state = ctx
+        .ibc_store
+        .client_state(&localhost_client_id())
+        .expect("no error");
+    assert_eq!(client_state.latest_height(), ctx.latest_height());
+}
+
+#[test]
+fn test_connection_handshake_disallowed() {
+    let mut ctx = MockContext::default().with_localhost_client();
+
+    let localhost_connection = ctx
+        .ibc_store
+        .connection_end(&ConnectionId::localhost())
+        .expect("no error");
+    assert_eq!(localhost_connection.state(), &State::Open);
+
+    let msg = ConnectionMsg::OpenInit(MsgConnectionOpenInit {
+        client_id_on_a: localhost_client_id(),
+        counterparty: ConnectionCounterparty::new(
+            localhost_client_id(),
+            None,
+            ctx.ibc_store.commitment_prefix(),
+        ),
+        version: None,
+        delay_period: Default::default(),
+        signer: dummy_account_id(),
+    });
+
+    let err = ctx.dispatch(msg.into()).unwrap_err();
+    assert!(err.to_string().contains("disallowed"), "{err}");
+}
+
+#[test]
+fn test_create_client_disallowed() {
+    let mut ctx = MockContext::default();
+
+    let client_state = AnyClientState::from(LocalhostClientState::new(ctx.latest_height()));
+    let msg = MsgCreateClient::new(client_state.into(), Any::default(), dummy_account_id());
+
+    assert!(ctx.dispatch(ClientMsg::from(msg).into()).is_err());
+}
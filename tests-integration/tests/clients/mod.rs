@@ -1 +1,7 @@
+#[cfg(feature = "serde")]
+pub mod cw_tendermint;
+pub mod ics06_solomachine;
 pub mod ics07_tendermint;
+#[cfg(feature = "serde")]
+pub mod ics08_wasm;
+pub mod ics09_localhost;
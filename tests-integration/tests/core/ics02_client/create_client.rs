@@ -1,3 +1,5 @@
+use ibc::clients::localhost::types::client_type as localhost_client_type;
+use ibc::clients::solomachine::types::client_type as sm_client_type;
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ConsensusState as TmConsensusState,
 };
@@ -150,6 +152,8 @@ fn create_client_execute(fxt: &mut Fixture<MsgCreateClient>, expect: Expect) {
     let client_type = match expected_client_state {
         AnyClientState::Mock(_) => mock_client_type(),
         AnyClientState::Tendermint(_) => tm_client_type(),
+        AnyClientState::Solomachine(_) => sm_client_type(),
+        AnyClientState::Localhost(_) => localhost_client_type(),
     };
     let client_id = client_type.build_client_id(fxt.ctx.client_counter().unwrap());
     let res = execute(&mut fxt.ctx, &mut router, msg_envelope);
@@ -0,0 +1,206 @@
+use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeAck};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, CounterpartyChannelUpgradePath,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_upgrade_ack, dummy_raw_upgrade_fields,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeAck,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+
+    let client_id_on_a = mock_client_type().build_client_id(0);
+    let conn_id_on_a = ConnectionId::zero();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    // The upgrade was initiated on chain A, which is still open.
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_a.clone()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+
+    let upgrade_on_a = Upgrade::new(
+        UpgradeFields::try_from(dummy_raw_upgrade_fields()).unwrap(),
+        UpgradeTimeout::unset(),
+        Sequence::from(0),
+    );
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), Sequence::from(1))
+        .with_channel_upgrade(PortId::transfer(), ChannelId::zero(), upgrade_on_a);
+
+    let msg =
+        MsgChannelUpgradeAck::try_from(dummy_raw_msg_chan_upgrade_ack(u64::MAX, proof_height))
+            .unwrap();
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_ack_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    // Without any in-flight packets, flushing completes right away.
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state, State::FlushComplete);
+
+    let counterparty_upgrade = context
+        .ibc_store
+        .counterparty_channel_upgrade(&CounterpartyChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+
+    assert_eq!(counterparty_upgrade, Some(msg.counterparty_upgrade));
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeAckChannel(_)));
+}
+
+#[rstest]
+fn chan_upgrade_ack_execute_with_inflight_packets(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+    } = fixture;
+
+    let mut context = context.with_packet_commitment(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        Sequence::from(1),
+        PacketCommitment::from(vec![0u8]),
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state, State::Flushing);
+}
+
+#[rstest]
+fn chan_upgrade_ack_execute_abort_elapsed_timeout(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        ..
+    } = fixture;
+
+    let msg = MsgChannelUpgradeAck::try_from(dummy_raw_msg_chan_upgrade_ack(1, 10)).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation succeeds for an aborted upgrade");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution succeeds for an aborted upgrade");
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state, State::Open);
+
+    let error_receipt = context
+        .ibc_store
+        .channel_upgrade_error(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap()
+        .expect("error receipt is stored");
+
+    assert_eq!(error_receipt.sequence, 1);
+}
@@ -0,0 +1,214 @@
+use ibc::apps::transfer::types::{VERSION, VERSION_V2};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeConfirm};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_upgrade_confirm, dummy_raw_upgrade_fields,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub proof_height: u64,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+
+    let client_id_on_b = mock_client_type().build_client_id(0);
+    let conn_id_on_b = ConnectionId::zero();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_b.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_b.clone()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+    chan_end_on_b.set_state(State::Flushing);
+
+    let upgrade_on_b = Upgrade::new(
+        dummy_raw_upgrade_fields().try_into().unwrap(),
+        UpgradeTimeout::unset(),
+        Sequence::from(1),
+    );
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_channel_upgrade(PortId::transfer(), ChannelId::zero(), upgrade_on_b);
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        proof_height,
+    }
+}
+
+/// Once both ends completed flushing, confirming the upgrade also opens the
+/// upgraded channel.
+#[rstest]
+fn chan_upgrade_confirm_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        proof_height,
+    } = fixture;
+
+    let msg = MsgChannelUpgradeConfirm::try_from(dummy_raw_msg_chan_upgrade_confirm(
+        State::FlushComplete as i32,
+        u64::MAX,
+        proof_height,
+    ))
+    .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_b = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state, State::Open);
+    assert_eq!(chan_end_on_b.version, Version::new(VERSION_V2.to_string()));
+
+    let upgrade_on_b = context
+        .ibc_store
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+
+    assert!(upgrade_on_b.is_none());
+
+    let ibc_events = context.get_events();
+
+    assert!(ibc_events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::UpgradeConfirmChannel(_))));
+
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::UpgradeOpenChannel(_))
+    ));
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_counterparty_flushing(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        proof_height,
+    } = fixture;
+
+    let msg = MsgChannelUpgradeConfirm::try_from(dummy_raw_msg_chan_upgrade_confirm(
+        State::Flushing as i32,
+        u64::MAX,
+        proof_height,
+    ))
+    .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_b = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state, State::FlushComplete);
+    assert_eq!(chan_end_on_b.ordering, Order::Unordered);
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_abort_elapsed_timeout(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        proof_height,
+    } = fixture;
+
+    let msg = MsgChannelUpgradeConfirm::try_from(dummy_raw_msg_chan_upgrade_confirm(
+        State::FlushComplete as i32,
+        1,
+        proof_height,
+    ))
+    .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation succeeds for an aborted upgrade");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution succeeds for an aborted upgrade");
+
+    let chan_end_on_b = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state, State::Open);
+    assert_eq!(chan_end_on_b.ordering, Order::Unordered);
+
+    let error_receipt = context
+        .ibc_store
+        .channel_upgrade_error(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap()
+        .expect("error receipt is stored");
+
+    assert_eq!(error_receipt.sequence, 1);
+}
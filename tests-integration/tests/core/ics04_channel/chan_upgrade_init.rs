@@ -0,0 +1,232 @@
+use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeInit};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_query::core::channel::{
+    query_upgrade, query_upgrade_error, QueryUpgradeErrorRequest, QueryUpgradeRequest,
+};
+use ibc_query::error::QueryError;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_init;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::{dummy_account_id, dummy_bech32_account};
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeInit,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id_on_a = mock_client_type().build_client_id(0);
+    let conn_id_on_a = ConnectionId::zero();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_a.clone()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a);
+
+    let mut msg = MsgChannelUpgradeInit::try_from(dummy_raw_msg_chan_upgrade_init()).unwrap();
+    msg.signer = dummy_account_id();
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_init_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state, State::Open);
+    assert_eq!(chan_end_on_a.upgrade_sequence(), 1);
+
+    let upgrade_on_a = context
+        .ibc_store
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap()
+        .expect("upgrade is stored");
+
+    assert_eq!(upgrade_on_a.fields, msg.fields);
+    assert!(!upgrade_on_a.timeout.is_set());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeInitChannel(_)));
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_unauthorized_signer(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+    } = fixture;
+
+    msg.signer = dummy_bech32_account().into();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the signer is not the upgrade authority"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_unchanged_fields(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+    } = fixture;
+
+    msg.fields.version = Version::new(VERSION.to_string());
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proposed fields match the current channel"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_multihop_channel(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture;
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero(), ConnectionId::new(1)],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    let context = context.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because multi-hop channels cannot be upgraded"
+    )
+}
+
+#[rstest]
+fn query_missing_upgrade(fixture: Fixture) {
+    let Fixture { context, msg, .. } = fixture;
+
+    let upgrade_res = query_upgrade(
+        &context.ibc_store,
+        &QueryUpgradeRequest {
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+            query_height: None,
+        },
+    );
+    let upgrade_error_res = query_upgrade_error(
+        &context.ibc_store,
+        &QueryUpgradeErrorRequest {
+            port_id: msg.port_id_on_a,
+            channel_id: msg.chan_id_on_a,
+            query_height: None,
+        },
+    );
+
+    // Both queries report a missing upgrade in the same way.
+    assert!(matches!(
+        upgrade_res,
+        Err(QueryError::Host(HostError::MissingState { .. }))
+    ));
+    assert!(matches!(
+        upgrade_error_res,
+        Err(QueryError::Host(HostError::MissingState { .. }))
+    ));
+}
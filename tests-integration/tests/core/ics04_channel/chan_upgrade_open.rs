@@ -0,0 +1,222 @@
+use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeOpen};
+use ibc::core::channel::types::upgrade::Upgrade;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath, SeqRecvPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{dummy_raw_msg_chan_upgrade_open, dummy_raw_upgrade};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub chan_end_on_a: ChannelEnd,
+    pub proof_height: u64,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+
+    let client_id_on_a = mock_client_type().build_client_id(0);
+    let conn_id_on_a = ConnectionId::zero();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    // Both ends completed flushing, and chain A is about to open the upgraded
+    // channel.
+    let mut chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_a.clone()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+    chan_end_on_a.set_state(State::FlushComplete);
+
+    // The upgrade also orders the channel.
+    let mut upgrade_on_a = Upgrade::try_from(dummy_raw_upgrade(u64::MAX, 1)).unwrap();
+    upgrade_on_a.fields.ordering = Order::Ordered;
+
+    let mut counterparty_upgrade = Upgrade::try_from(dummy_raw_upgrade(u64::MAX, 5)).unwrap();
+    counterparty_upgrade.fields.ordering = Order::Ordered;
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel_upgrade(PortId::transfer(), ChannelId::zero(), upgrade_on_a)
+        .with_counterparty_channel_upgrade(
+            PortId::transfer(),
+            ChannelId::zero(),
+            counterparty_upgrade,
+        );
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        chan_end_on_a,
+        proof_height,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_open_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        chan_end_on_a,
+        proof_height,
+    } = fixture;
+
+    let msg = MsgChannelUpgradeOpen::try_from(dummy_raw_msg_chan_upgrade_open(
+        State::Open as i32,
+        1,
+        proof_height,
+    ))
+    .unwrap();
+
+    let mut context = context.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state, State::Open);
+    assert_eq!(chan_end_on_a.ordering, Order::Ordered);
+
+    // The receive sequence of the now ordered channel carries on from the
+    // counterparty send sequence.
+    let next_seq_recv = context
+        .ibc_store
+        .get_next_sequence_recv(&SeqRecvPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(next_seq_recv, Sequence::from(5));
+
+    let upgrade_on_a = context
+        .ibc_store
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+
+    assert!(upgrade_on_a.is_none());
+
+    let ibc_events = context.get_events();
+
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::UpgradeOpenChannel(_))
+    ));
+}
+
+#[rstest]
+fn chan_upgrade_open_fail_outdated_counterparty_sequence(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        chan_end_on_a,
+        proof_height,
+    } = fixture;
+
+    let msg = MsgChannelUpgradeOpen::try_from(dummy_raw_msg_chan_upgrade_open(
+        State::Open as i32,
+        0,
+        proof_height,
+    ))
+    .unwrap();
+
+    let context = context.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty upgrade sequence is outdated"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_open_fail_still_flushing(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut chan_end_on_a,
+        proof_height,
+    } = fixture;
+
+    chan_end_on_a.set_state(State::Flushing);
+
+    let msg = MsgChannelUpgradeOpen::try_from(dummy_raw_msg_chan_upgrade_open(
+        State::FlushComplete as i32,
+        1,
+        proof_height,
+    ))
+    .unwrap();
+
+    let context = context.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel has not completed flushing"
+    )
+}
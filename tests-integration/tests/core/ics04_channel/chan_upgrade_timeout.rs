@@ -0,0 +1,223 @@
+use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTimeout};
+use ibc::core::channel::types::proto::v1::Channel as RawChannel;
+use ibc::core::channel::types::upgrade::Upgrade;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_channel_end, dummy_raw_msg_chan_upgrade_timeout, dummy_raw_upgrade,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub chan_end_on_a: ChannelEnd,
+    pub proof_height: u64,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+
+    let client_id_on_a = mock_client_type().build_client_id(0);
+    let conn_id_on_a = ConnectionId::zero();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    // Chain A is flushing with an upgrade timeout which has long elapsed.
+    let mut chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_a.clone()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+    chan_end_on_a.set_state(State::Flushing);
+
+    let upgrade_on_a = Upgrade::try_from(dummy_raw_upgrade(1, 1)).unwrap();
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel_upgrade(PortId::transfer(), ChannelId::zero(), upgrade_on_a);
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        chan_end_on_a,
+        proof_height,
+    }
+}
+
+fn dummy_msg_chan_upgrade_timeout(proof_height: u64) -> MsgChannelUpgradeTimeout {
+    let chan_end_on_b = RawChannel {
+        upgrade_sequence: 1,
+        ..dummy_raw_channel_end(State::Open as i32, Some(0))
+    };
+
+    MsgChannelUpgradeTimeout::try_from(dummy_raw_msg_chan_upgrade_timeout(
+        chan_end_on_b,
+        proof_height,
+    ))
+    .unwrap()
+}
+
+#[rstest]
+fn chan_upgrade_timeout_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        chan_end_on_a,
+        proof_height,
+    } = fixture;
+
+    let msg = dummy_msg_chan_upgrade_timeout(proof_height);
+
+    let mut context = context.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state, State::Open);
+    assert_eq!(chan_end_on_a.ordering, Order::Unordered);
+
+    let error_receipt = context
+        .ibc_store
+        .channel_upgrade_error(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap()
+        .expect("error receipt is stored");
+
+    assert_eq!(error_receipt.sequence, 1);
+
+    let upgrade_on_a = context
+        .ibc_store
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+
+    assert!(upgrade_on_a.is_none());
+
+    let ibc_events = context.get_events();
+
+    assert!(ibc_events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::UpgradeTimeoutChannel(_))));
+
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::UpgradeErrorChannel(_))
+    ));
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_timeout_not_elapsed(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        chan_end_on_a,
+        proof_height,
+    } = fixture;
+
+    let msg = dummy_msg_chan_upgrade_timeout(proof_height);
+
+    let context = context
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            Upgrade::try_from(dummy_raw_upgrade(u64::MAX, 1)).unwrap(),
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the upgrade timeout has not elapsed"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_counterparty_flush_complete(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        chan_end_on_a,
+        proof_height,
+    } = fixture;
+
+    let mut msg = dummy_msg_chan_upgrade_timeout(proof_height);
+    msg.chan_end_on_b.set_state(State::FlushComplete);
+
+    let context = context.with_channel(
+        msg.port_id_on_a.clone(),
+        msg.chan_id_on_a.clone(),
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty may be opening the upgraded channel"
+    )
+}
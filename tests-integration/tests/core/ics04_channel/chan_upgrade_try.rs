@@ -0,0 +1,252 @@
+use ibc::apps::transfer::types::VERSION;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTry};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_upgrade_try, dummy_raw_upgrade_fields,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeTry,
+    pub chan_end_on_b: ChannelEnd,
+    pub upgrade_on_b: Upgrade,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+
+    let client_id_on_b = mock_client_type().build_client_id(0);
+    let conn_id_on_b = ConnectionId::zero();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_b.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_b.clone(), conn_end_on_b);
+
+    // The upgrade authority of chain B has already initiated the upgrade.
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_b],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+
+    let upgrade_on_b = Upgrade::new(
+        UpgradeFields::try_from(dummy_raw_upgrade_fields()).unwrap(),
+        UpgradeTimeout::unset(),
+        Sequence::from(0),
+    );
+
+    let msg =
+        MsgChannelUpgradeTry::try_from(dummy_raw_msg_chan_upgrade_try(1, proof_height)).unwrap();
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end_on_b,
+        upgrade_on_b,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_try_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end_on_b,
+        upgrade_on_b,
+    } = fixture;
+
+    let mut context = context
+        .with_channel(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_send_sequence(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            Sequence::from(1),
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            upgrade_on_b,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_b = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state, State::Flushing);
+    assert_eq!(chan_end_on_b.upgrade_sequence(), 1);
+
+    let upgrade_on_b = context
+        .ibc_store
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap()
+        .expect("upgrade is stored");
+
+    assert!(upgrade_on_b.timeout.is_set());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeTryChannel(_)));
+}
+
+/// A counterparty behind on the upgrade sequence aborts the upgrade instead
+/// of failing the transaction.
+#[rstest]
+fn chan_upgrade_try_execute_abort_outdated_sequence(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end_on_b,
+        upgrade_on_b,
+    } = fixture;
+
+    let mut context = context
+        .with_channel(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            chan_end_on_b.with_upgrade_sequence(2),
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            upgrade_on_b,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation succeeds for an aborted upgrade");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution succeeds for an aborted upgrade");
+
+    let chan_end_on_b = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state, State::Open);
+    assert_eq!(chan_end_on_b.ordering, Order::Unordered);
+
+    let error_receipt = context
+        .ibc_store
+        .channel_upgrade_error(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap()
+        .expect("error receipt is stored");
+
+    assert_eq!(error_receipt.sequence, 1);
+
+    let upgrade_on_b = context
+        .ibc_store
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+
+    assert!(upgrade_on_b.is_none());
+
+    let ibc_events = context.get_events();
+
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::UpgradeErrorChannel(_))
+    ));
+}
+
+#[rstest]
+fn chan_upgrade_try_fail_missing_upgrade(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end_on_b,
+        ..
+    } = fixture;
+
+    let context = context.with_channel(
+        msg.port_id_on_b.clone(),
+        msg.chan_id_on_b.clone(),
+        chan_end_on_b,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the upgrade was not initiated on this end"
+    )
+}
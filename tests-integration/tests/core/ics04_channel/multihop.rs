@@ -0,0 +1,503 @@
+use core::time::Duration;
+
+use ibc::core::channel::handler::counterparty_connection_hops;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgAcknowledgement, MsgChannelOpenAck, MsgRecvPacket, MsgTimeout, PacketMsg,
+};
+use ibc::core::channel::types::multihop::{
+    MultihopConnectionProof, MultihopConsensusProof, MultihopProof,
+};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_acknowledgement, dummy_raw_msg_chan_open_ack, dummy_raw_msg_recv_packet,
+    dummy_raw_msg_timeout,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgRecvPacket,
+    pub conn_end_on_b: ConnectionEnd,
+    pub chan_end_on_b: ChannelEnd,
+    pub hop_conn_end: ConnectionEnd,
+}
+
+/// Sets up a channel on B reaching A through one intermediate chain, over
+/// `connection-0` on B and `connection-1` on the intermediate chain.
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let client_height = MockContext::default().latest_height().increment();
+
+    let context = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(client_height),
+    );
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+        .unwrap();
+
+    let packet = msg.packet.clone();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(packet.port_id_on_a, Some(packet.chan_id_on_a)),
+        vec![ConnectionId::new(0), ConnectionId::new(1)],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id,
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(3))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let hop_conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::new("07-tendermint", 1).expect("no error"),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(7))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        hop_conn_end,
+    }
+}
+
+fn multihop_proof(key_proof: CommitmentProofBytes, hop_conn_end: ConnectionEnd) -> MultihopProof {
+    multihop_proof_at(key_proof, hop_conn_end, Height::new(0, 5).unwrap())
+}
+
+/// Returns a multi-hop proof proving the counterparty's consensus state at
+/// `consensus_height`.
+fn multihop_proof_at(
+    key_proof: CommitmentProofBytes,
+    hop_conn_end: ConnectionEnd,
+    consensus_height: Height,
+) -> MultihopProof {
+    MultihopProof {
+        key_proof: key_proof.clone(),
+        connection_proofs: vec![MultihopConnectionProof {
+            connection_end: hop_conn_end,
+            proof: key_proof.clone(),
+        }],
+        consensus_proofs: vec![MultihopConsensusProof {
+            consensus_height,
+            consensus_state: MockConsensusState::new(
+                MockHeader::new(consensus_height).with_current_timestamp(),
+            )
+            .into(),
+            proof: key_proof,
+        }],
+    }
+}
+
+#[rstest]
+fn recv_packet_multihop_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        hop_conn_end,
+    } = fixture;
+
+    msg.proof_commitment_on_a = multihop_proof(msg.proof_commitment_on_a.clone(), hop_conn_end)
+        .try_into()
+        .unwrap();
+
+    let packet = msg.packet.clone();
+    let mut context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(packet.port_id_on_b, packet.chan_id_on_b, chan_end_on_b);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. err: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    assert!(context
+        .get_events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::ReceivePacket(_))));
+}
+
+#[rstest]
+fn recv_packet_multihop_fail_single_hop_proof(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        ..
+    } = fixture;
+
+    let packet = msg.packet.clone();
+    let context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(packet.port_id_on_b, packet.chan_id_on_b, chan_end_on_b);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because a multi-hop channel requires a multi-hop proof"
+    );
+}
+
+#[rstest]
+fn recv_packet_multihop_fail_intermediate_connection_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        mut hop_conn_end,
+    } = fixture;
+
+    hop_conn_end.state = ConnectionState::Init;
+    msg.proof_commitment_on_a = multihop_proof(msg.proof_commitment_on_a.clone(), hop_conn_end)
+        .try_into()
+        .unwrap();
+
+    let packet = msg.packet.clone();
+    let context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(packet.port_id_on_b, packet.chan_id_on_b, chan_end_on_b);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the intermediate connection is not open"
+    );
+}
+
+#[rstest]
+fn recv_packet_multihop_fail_hop_delay_not_passed(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        hop_conn_end,
+    } = fixture;
+
+    // Only the intermediate connection requires a delay, which must still be
+    // enforced by the client of the first hop.
+    let hop_conn_end = ConnectionEnd::new(
+        hop_conn_end.state,
+        hop_conn_end.client_id().clone(),
+        hop_conn_end.counterparty().clone(),
+        hop_conn_end.versions().to_vec(),
+        Duration::from_secs(3600),
+    )
+    .unwrap();
+
+    msg.proof_commitment_on_a = multihop_proof(msg.proof_commitment_on_a.clone(), hop_conn_end)
+        .try_into()
+        .unwrap();
+
+    let packet = msg.packet.clone();
+    let context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(packet.port_id_on_b, packet.chan_id_on_b, chan_end_on_b);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the delay of the intermediate connection has not passed"
+    );
+}
+
+#[rstest]
+fn ack_packet_multihop_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        conn_end_on_b,
+        hop_conn_end,
+        ..
+    } = fixture;
+
+    let proof_height = context.light_client_latest_height(conn_end_on_b.client_id());
+    let mut msg = MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(
+        proof_height.revision_height(),
+    ))
+    .unwrap();
+
+    msg.proof_acked_on_b = multihop_proof(msg.proof_acked_on_b.clone(), hop_conn_end)
+        .try_into()
+        .unwrap();
+
+    // The fixture's connection is used as the first hop of the sending end.
+    let packet = msg.packet.clone();
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
+        vec![ConnectionId::new(0), ConnectionId::new(1)],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            ),
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. err: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    assert!(context
+        .get_events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+}
+
+#[rstest]
+fn timeout_packet_multihop_uses_counterparty_height(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        conn_end_on_b,
+        hop_conn_end,
+        ..
+    } = fixture;
+
+    let proof_height = context.light_client_latest_height(conn_end_on_b.client_id());
+
+    // The packet has not timed out at the proof height, which is a height of
+    // the first intermediate chain, but it has at the proven height of the
+    // counterparty.
+    let timeout_height = proof_height.revision_height() + 5;
+    let counterparty_height = Height::new(0, timeout_height + 5).unwrap();
+
+    let mut msg = MsgTimeout::try_from(dummy_raw_msg_timeout(
+        proof_height.revision_height(),
+        timeout_height,
+        0,
+    ))
+    .unwrap();
+
+    msg.proof_unreceived_on_b = multihop_proof_at(
+        msg.proof_unreceived_on_b.clone(),
+        hop_conn_end,
+        counterparty_height,
+    )
+    .try_into()
+    .unwrap();
+
+    let packet = msg.packet.clone();
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
+        vec![ConnectionId::new(0), ConnectionId::new(1)],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            ),
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. err: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    assert!(context
+        .get_events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::TimeoutPacket(_))));
+}
+
+#[rstest]
+fn chan_open_ack_multihop_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        conn_end_on_b,
+        hop_conn_end,
+        ..
+    } = fixture;
+
+    let proof_height = context.light_client_latest_height(conn_end_on_b.client_id());
+    let mut msg =
+        MsgChannelOpenAck::try_from(dummy_raw_msg_chan_open_ack(proof_height.revision_height()))
+            .unwrap();
+
+    msg.proof_chan_end_on_b = multihop_proof(msg.proof_chan_end_on_b.clone(), hop_conn_end)
+        .try_into()
+        .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Init,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), None),
+        vec![ConnectionId::new(0), ConnectionId::new(1)],
+        msg.version_on_b.clone(),
+    )
+    .unwrap();
+
+    let mut context = context
+        .with_connection(ConnectionId::new(0), conn_end_on_b)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. err: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    assert!(context
+        .get_events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::OpenAckChannel(_))));
+}
+
+#[rstest]
+fn multihop_counterparty_connection_hops(fixture: Fixture) {
+    let Fixture {
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        hop_conn_end,
+        ..
+    } = fixture;
+
+    let proof: CommitmentProofBytes = multihop_proof(msg.proof_commitment_on_a, hop_conn_end)
+        .try_into()
+        .unwrap();
+
+    let conn_hops_on_a =
+        counterparty_connection_hops(&conn_end_on_b, chan_end_on_b.connection_hops(), &proof)
+            .unwrap();
+
+    // The counterparty goes through its own connection to the intermediate
+    // chain first, and then through the one which `connection-0` on B is
+    // connected to.
+    assert_eq!(
+        conn_hops_on_a,
+        vec![ConnectionId::new(7), ConnectionId::new(3)]
+    );
+}
@@ -1,6 +1,6 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet::{Packet, Receipt};
 use ibc::core::channel::types::timeout::TimeoutTimestamp;
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
@@ -13,6 +13,8 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ReceiptPath, SeqRecvPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
@@ -206,6 +208,90 @@ fn recv_packet_timeout_expired(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_timeout_expired_ordered_allow_timeout(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::OrderedAllowTimeout;
+
+    let packet_old = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: Vec::new(),
+        timeout_height_on_b: client_height.into(),
+        timeout_timestamp_on_b: TimeoutTimestamp::from_nanoseconds(1),
+    };
+
+    let msg_packet_old = dummy_msg_recv_packet(
+        packet_old.clone(),
+        msg.proof_commitment_on_a.clone(),
+        msg.proof_height_on_a,
+        dummy_account_id(),
+    );
+
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_recv_sequence(PortId::transfer(), ChannelId::zero(), packet_old.seq_on_a)
+        .advance_block_up_to_height(host_height);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg_packet_old));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "late packets are received on ORDERED_ALLOW_TIMEOUT channels. err: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    // The packet is skipped over without reaching the application, and
+    // without being acknowledged.
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        &ibc_events[0],
+        &IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+
+    let next_seq_recv = context
+        .ibc_store
+        .get_next_sequence_recv(&SeqRecvPath::new(&PortId::transfer(), &ChannelId::zero()))
+        .unwrap();
+    assert_eq!(next_seq_recv, packet_old.seq_on_a.increment());
+
+    let receipt = context
+        .ibc_store
+        .get_packet_receipt(&ReceiptPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            packet_old.seq_on_a,
+        ))
+        .unwrap();
+    assert!(matches!(receipt, Receipt::Timeout));
+}
+
 #[rstest]
 fn recv_packet_execute_happy_path(fixture: Fixture) {
     let Fixture {
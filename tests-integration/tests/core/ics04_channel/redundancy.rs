@@ -0,0 +1,350 @@
+use ibc::core::channel::handler::check_redundancy;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{MsgAcknowledgement, MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::v2::acknowledgement::Acknowledgement as AcknowledgementV2;
+use ibc::core::channel::types::v2::commitment::compute_packet_commitment as compute_packet_v2_commitment;
+use ibc::core::channel::types::v2::msgs::{
+    MsgAcknowledgement as MsgAcknowledgementV2, MsgRecvPacket as MsgRecvPacketV2,
+    PacketMsg as PacketMsgV2,
+};
+use ibc::core::channel::types::v2::packet::Packet as PacketV2;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::execute;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::v2::{CommitmentPath, ReceiptPath};
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_acknowledgement, dummy_raw_msg_recv_packet,
+};
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub client_id: ClientId,
+    pub client_height: Height,
+    pub recv_msg: MsgRecvPacket,
+    pub ack_msg: MsgAcknowledgement,
+    pub chan_end: ChannelEnd,
+}
+
+/// Sets up a client and an open connection, to which the channels of the
+/// relayed packets are bound.
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let client_height = MockContext::default().latest_height().increment();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::empty(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let context = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end);
+
+    let recv_msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+            .unwrap();
+
+    let ack_msg = MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(
+        client_height.revision_height(),
+    ))
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            recv_msg.packet.port_id_on_a.clone(),
+            Some(recv_msg.packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    Fixture {
+        context,
+        client_id,
+        client_height,
+        recv_msg,
+        ack_msg,
+        chan_end,
+    }
+}
+
+fn msg_update_client(client_id: &ClientId, height: Height) -> MsgEnvelope {
+    MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(height).with_current_timestamp().into(),
+        signer: dummy_account_id(),
+    }))
+}
+
+fn packet_v2(client_id: &ClientId, client_height: Height) -> (PacketV2, MsgEnvelope, MsgEnvelope) {
+    let packet = PacketV2 {
+        sequence: Sequence::from(1),
+        source_client: client_id.clone(),
+        destination_client: client_id.clone(),
+        timeout_timestamp: 0,
+        payloads: vec![],
+    };
+
+    let recv_msg = MsgEnvelope::from(PacketMsgV2::Recv(MsgRecvPacketV2 {
+        packet: packet.clone(),
+        proof_commitment: dummy_commitment_proof_bytes(),
+        proof_height: client_height,
+        signer: dummy_account_id(),
+    }));
+
+    let ack_msg = MsgEnvelope::from(PacketMsgV2::Ack(MsgAcknowledgementV2 {
+        packet: packet.clone(),
+        acknowledgement: AcknowledgementV2::new(vec![]),
+        proof_acked: dummy_commitment_proof_bytes(),
+        proof_height: client_height,
+        signer: dummy_account_id(),
+    }));
+
+    (packet, recv_msg, ack_msg)
+}
+
+#[rstest]
+fn redundant_recv_packet_on_unordered_channel(fixture: Fixture) {
+    let Fixture {
+        context,
+        recv_msg,
+        chan_end,
+        ..
+    } = fixture;
+
+    let packet = recv_msg.packet.clone();
+    let mut context = context.with_channel(packet.port_id_on_b, packet.chan_id_on_b, chan_end);
+    let mut router = MockRouter::new_with_transfer();
+
+    let msgs = vec![MsgEnvelope::from(PacketMsg::from(recv_msg))];
+
+    assert!(check_redundancy(&context.ibc_store, &msgs).is_ok());
+
+    execute(&mut context.ibc_store, &mut router, msgs[0].clone()).unwrap();
+
+    // The packet receipt written by the first relayer makes the same message
+    // redundant.
+    assert!(matches!(
+        check_redundancy(&context.ibc_store, &msgs),
+        Err(ChannelError::RedundantRelay)
+    ));
+}
+
+#[rstest]
+fn redundant_recv_packet_on_ordered_channel(fixture: Fixture) {
+    let Fixture {
+        context,
+        recv_msg,
+        mut chan_end,
+        ..
+    } = fixture;
+
+    chan_end.ordering = Order::Ordered;
+
+    let packet = recv_msg.packet.clone();
+    let context = context.with_channel(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        chan_end,
+    );
+
+    let msgs = vec![MsgEnvelope::from(PacketMsg::from(recv_msg))];
+
+    let context = context.with_recv_sequence(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a,
+    );
+
+    assert!(check_redundancy(&context.ibc_store, &msgs).is_ok());
+
+    let context = context.with_recv_sequence(
+        packet.port_id_on_b,
+        packet.chan_id_on_b,
+        packet.seq_on_a.increment(),
+    );
+
+    assert!(matches!(
+        check_redundancy(&context.ibc_store, &msgs),
+        Err(ChannelError::RedundantRelay)
+    ));
+}
+
+#[rstest]
+fn redundant_acknowledgement(fixture: Fixture) {
+    let Fixture {
+        context,
+        ack_msg,
+        chan_end,
+        ..
+    } = fixture;
+
+    let packet = ack_msg.packet.clone();
+    let context = context.with_channel(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        chan_end,
+    );
+
+    let msgs = vec![MsgEnvelope::from(PacketMsg::from(ack_msg))];
+
+    // Without a packet commitment, the packet was already acknowledged.
+    assert!(matches!(
+        check_redundancy(&context.ibc_store, &msgs),
+        Err(ChannelError::RedundantRelay)
+    ));
+
+    let context = context.with_packet_commitment(
+        packet.port_id_on_a,
+        packet.chan_id_on_a,
+        packet.seq_on_a,
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        ),
+    );
+
+    assert!(check_redundancy(&context.ibc_store, &msgs).is_ok());
+}
+
+#[rstest]
+fn redundant_update_client(fixture: Fixture) {
+    let Fixture {
+        context,
+        client_id,
+        client_height,
+        ack_msg,
+        chan_end,
+        ..
+    } = fixture;
+
+    let packet = ack_msg.packet.clone();
+    let context = context.with_channel(packet.port_id_on_a, packet.chan_id_on_a, chan_end);
+
+    let known_height_update = msg_update_client(&client_id, client_height);
+    let new_height_update = msg_update_client(&client_id, client_height.increment());
+    let redundant_ack = MsgEnvelope::from(PacketMsg::from(ack_msg));
+
+    assert!(matches!(
+        check_redundancy(
+            &context.ibc_store,
+            &[known_height_update, redundant_ack.clone()]
+        ),
+        Err(ChannelError::RedundantRelay)
+    ));
+
+    // A single message making progress is enough for the transaction to go
+    // through.
+    assert!(check_redundancy(&context.ibc_store, &[new_height_update, redundant_ack]).is_ok());
+
+    assert!(check_redundancy(&context.ibc_store, &[]).is_ok());
+}
+
+#[rstest]
+fn non_relay_messages_are_skipped(fixture: Fixture) {
+    let Fixture {
+        context,
+        ack_msg,
+        chan_end,
+        ..
+    } = fixture;
+
+    let packet = ack_msg.packet.clone();
+    let context = context.with_channel(packet.port_id_on_a, packet.chan_id_on_a, chan_end);
+
+    let conn_open_init = MsgEnvelope::from(ConnectionMsg::from(dummy_msg_conn_open_init()));
+    let redundant_ack = MsgEnvelope::from(PacketMsg::from(ack_msg));
+
+    // Batching a redundant relay message with other messages does not make it
+    // go through.
+    assert!(matches!(
+        check_redundancy(&context.ibc_store, &[conn_open_init.clone(), redundant_ack]),
+        Err(ChannelError::RedundantRelay)
+    ));
+
+    assert!(check_redundancy(&context.ibc_store, &[conn_open_init]).is_ok());
+}
+
+#[rstest]
+fn redundant_packet_v2_messages(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let (packet, recv_msg, ack_msg) = packet_v2(&client_id, client_height);
+
+    assert!(check_redundancy(&context.ibc_store, core::slice::from_ref(&recv_msg)).is_ok());
+
+    context
+        .ibc_store
+        .store_packet_receipt_v2(
+            &ReceiptPath::new(&packet.destination_client, packet.sequence),
+            Receipt::Ok,
+        )
+        .unwrap();
+
+    assert!(matches!(
+        check_redundancy(&context.ibc_store, &[recv_msg]),
+        Err(ChannelError::RedundantRelay)
+    ));
+
+    // Without a packet commitment, the packet was already acknowledged.
+    assert!(matches!(
+        check_redundancy(&context.ibc_store, core::slice::from_ref(&ack_msg)),
+        Err(ChannelError::RedundantRelay)
+    ));
+
+    context
+        .ibc_store
+        .store_packet_commitment_v2(
+            &CommitmentPath::new(&packet.source_client, packet.sequence),
+            compute_packet_v2_commitment(&packet),
+        )
+        .unwrap();
+
+    assert!(check_redundancy(&context.ibc_store, &[ack_msg]).is_ok());
+}
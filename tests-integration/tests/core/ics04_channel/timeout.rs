@@ -13,7 +13,8 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::path::{ChannelEndPath, ClientConsensusStatePath, SeqAckPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
@@ -418,3 +419,69 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+#[rstest]
+fn timeout_ordered_allow_timeout_chan_execute(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_ordered,
+        ..
+    } = fixture;
+
+    chan_end_on_a_ordered.ordering = Order::OrderedAllowTimeout;
+
+    let packet = msg.packet.clone();
+
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a_ordered)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_ack_sequence(PortId::transfer(), ChannelId::zero(), packet.seq_on_a)
+        .with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Good parameters for ORDERED_ALLOW_TIMEOUT channels"
+    );
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    let ibc_events = ctx.get_events();
+
+    // The channel is not closed
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+
+    let chan_end_on_a = ctx
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state, State::Open);
+
+    let next_seq_ack = ctx
+        .ibc_store
+        .get_next_sequence_ack(&SeqAckPath::new(&PortId::transfer(), &ChannelId::zero()))
+        .unwrap();
+    assert_eq!(next_seq_ack, packet.seq_on_a.increment());
+}
@@ -0,0 +1,587 @@
+use ibc::core::channel::types::commitment::AcknowledgementCommitment;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::v2::acknowledgement::{
+    Acknowledgement, PacketStatus, RecvPacketResult,
+};
+use ibc::core::channel::types::v2::commitment::{
+    compute_ack_commitment, compute_packet_commitment,
+};
+use ibc::core::channel::types::v2::msgs::{
+    MsgAcknowledgement, MsgRecvPacket, MsgSendPacket, MsgTimeout, PacketMsg,
+};
+use ibc::core::channel::types::v2::packet::{Packet, Payload};
+use ibc::core::client::types::msgs::{ClientMsg, MsgRegisterCounterparty};
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::handler::types::error::HandlerError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, PortId, Sequence};
+use ibc::core::host::types::path::v2::{AckPath, CommitmentPath, ReceiptPath, SeqSendPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::Signer;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc::core::router::v2::ModuleV2;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::ModuleState;
+use test_log::test;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+fn client_a() -> ClientId {
+    ClientId::new(mock_client_type().as_str(), 0).expect("no error")
+}
+
+fn client_b() -> ClientId {
+    ClientId::new(mock_client_type().as_str(), 1).expect("no error")
+}
+
+fn register_counterparty_msg(client_id: ClientId, counterparty_client_id: ClientId) -> MsgEnvelope {
+    ClientMsg::from(MsgRegisterCounterparty {
+        client_id,
+        counterparty_client_id,
+        counterparty_prefix: CommitmentPrefix::from_bytes(b"mock"),
+        signer: dummy_account_id(),
+    })
+    .into()
+}
+
+/// Bootstraps two mock clients on the same chain, so that packets sent from
+/// `client_a` are received on `client_b`, and the other way around.
+fn ctx_with_clients() -> MockContext {
+    let ctx = MockContext::default();
+    let light_client_a = ctx.generate_light_client(vec![], &Default::default());
+    let light_client_b = ctx.generate_light_client(vec![], &Default::default());
+
+    ctx.with_light_client(&client_a(), light_client_a)
+        .with_light_client(&client_b(), light_client_b)
+}
+
+fn ctx_with_counterparties() -> MockContext {
+    let mut ctx = ctx_with_clients();
+
+    ctx.dispatch(register_counterparty_msg(client_a(), client_b()))
+        .expect("no error");
+    ctx.dispatch(register_counterparty_msg(client_b(), client_a()))
+        .expect("no error");
+
+    ctx
+}
+
+fn payload(port_id: PortId) -> Payload {
+    Payload {
+        source_port: port_id.clone(),
+        destination_port: port_id,
+        version: "ics20-1".to_string(),
+        encoding: "application/json".to_string(),
+        value: b"value".to_vec(),
+    }
+}
+
+/// Returns a timeout timestamp, in seconds, a minute after the latest block.
+fn timeout_timestamp(ctx: &MockContext) -> u64 {
+    ctx.latest_timestamp().nanoseconds() / NANOS_PER_SECOND + 60
+}
+
+fn send_packet_msg(timeout_timestamp: u64, payloads: Vec<Payload>) -> MsgEnvelope {
+    PacketMsg::Send(MsgSendPacket {
+        source_client: client_a(),
+        timeout_timestamp,
+        payloads,
+        signer: dummy_account_id(),
+    })
+    .into()
+}
+
+fn sent_packet(sequence: u64, timeout_timestamp: u64, payloads: Vec<Payload>) -> Packet {
+    Packet {
+        sequence: Sequence::from(sequence),
+        source_client: client_a(),
+        destination_client: client_b(),
+        timeout_timestamp,
+        payloads,
+    }
+}
+
+fn recv_packet_msg(ctx: &MockContext, packet: Packet) -> MsgEnvelope {
+    PacketMsg::Recv(MsgRecvPacket {
+        packet,
+        proof_commitment: dummy_commitment_proof_bytes(),
+        proof_height: ctx.latest_height(),
+        signer: dummy_account_id(),
+    })
+    .into()
+}
+
+fn ack_packet_msg(
+    ctx: &MockContext,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> MsgEnvelope {
+    PacketMsg::Ack(MsgAcknowledgement {
+        packet,
+        acknowledgement,
+        proof_acked: dummy_commitment_proof_bytes(),
+        proof_height: ctx.latest_height(),
+        signer: dummy_account_id(),
+    })
+    .into()
+}
+
+fn timeout_packet_msg(ctx: &MockContext, packet: Packet) -> MsgEnvelope {
+    PacketMsg::Timeout(MsgTimeout {
+        packet,
+        proof_unreceived: dummy_commitment_proof_bytes(),
+        proof_height: ctx.latest_height(),
+        signer: dummy_account_id(),
+    })
+    .into()
+}
+
+fn stored_ack(ctx: &MockContext, packet: &Packet) -> AcknowledgementCommitment {
+    ctx.ibc_store
+        .get_packet_acknowledgement_v2(&AckPath::new(&packet.destination_client, packet.sequence))
+        .expect("no error")
+}
+
+/// An application whose reception of payloads always ends with the given
+/// status.
+#[derive(Clone, Debug)]
+struct StatusModule(PacketStatus);
+
+impl ModuleState for StatusModule {}
+
+impl ModuleV2 for StatusModule {
+    fn on_send_packet_validate(
+        &self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_send_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        let result = match self.0 {
+            PacketStatus::Success => RecvPacketResult::success(vec![2u8]),
+            PacketStatus::Failure => RecvPacketResult::failure(),
+            PacketStatus::Async => RecvPacketResult::async_ack(),
+        };
+
+        (ModuleExtras::empty(), result)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _source_client: &ClientId,
+        _destination_client: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), ChannelError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+fn with_status_module(mut ctx: MockContext, port_id: &PortId, status: PacketStatus) -> MockContext {
+    let module_id = ModuleId::new(port_id.to_string());
+    let router = ctx.ibc_router_mut();
+
+    router.scope_port_to_module(port_id.clone(), module_id.clone());
+    router
+        .add_route_v2(module_id, StatusModule(status))
+        .expect("no error");
+
+    ctx
+}
+
+#[test]
+fn test_register_counterparty() {
+    let mut ctx = ctx_with_counterparties();
+
+    let counterparty = ctx
+        .ibc_store
+        .client_counterparty(&client_a())
+        .expect("no error")
+        .expect("counterparty is registered");
+    assert_eq!(counterparty.client_id, client_b());
+
+    let next_seq_send = ctx
+        .ibc_store
+        .get_next_sequence_send_v2(&SeqSendPath::new(&client_a()))
+        .expect("no error");
+    assert_eq!(next_seq_send, Sequence::from(1));
+
+    // The counterparty can only be registered once
+    let err = ctx
+        .dispatch(register_counterparty_msg(client_a(), client_b()))
+        .unwrap_err();
+    assert!(err.to_string().contains("duplicate"), "{err}");
+
+    // The client must exist
+    let unknown_client = ClientId::new(mock_client_type().as_str(), 2).expect("no error");
+    assert!(ctx
+        .dispatch(register_counterparty_msg(unknown_client, client_a()))
+        .is_err());
+}
+
+#[test]
+fn test_packet_flow() {
+    let mut ctx = ctx_with_counterparties();
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+    let payloads = vec![payload(PortId::transfer())];
+
+    ctx.dispatch(send_packet_msg(timeout_timestamp, payloads.clone()))
+        .expect("no error");
+
+    let packet = sent_packet(1, timeout_timestamp, payloads);
+
+    let events = ctx.get_events();
+    assert!(matches!(
+        events[events.len() - 2],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(
+        matches!(&events[events.len() - 1], IbcEvent::SendPacketV2(e) if *e.packet() == packet)
+    );
+
+    let commitment_path = CommitmentPath::new(&client_a(), packet.sequence);
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_commitment_v2(&commitment_path)
+            .expect("no error"),
+        compute_packet_commitment(&packet)
+    );
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_send_v2(&SeqSendPath::new(&client_a()))
+            .expect("no error"),
+        Sequence::from(2)
+    );
+
+    ctx.dispatch(recv_packet_msg(&ctx, packet.clone()))
+        .expect("no error");
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_receipt_v2(&ReceiptPath::new(&client_b(), packet.sequence))
+        .expect("no error")
+        .is_ok());
+
+    // The dummy transfer module acknowledges the payload with `[1]`
+    let acknowledgement = Acknowledgement::new(vec![vec![1u8]]);
+    assert_eq!(
+        stored_ack(&ctx, &packet),
+        compute_ack_commitment(&acknowledgement)
+    );
+    assert!(matches!(
+        ctx.get_events().last(),
+        Some(IbcEvent::WriteAcknowledgementV2(_))
+    ));
+
+    // Relaying the packet again is a no-op
+    let num_events = ctx.get_events().len();
+    ctx.dispatch(recv_packet_msg(&ctx, packet.clone()))
+        .expect("no error");
+    assert_eq!(ctx.get_events().len(), num_events);
+
+    ctx.dispatch(ack_packet_msg(&ctx, packet.clone(), acknowledgement))
+        .expect("no error");
+
+    assert!(matches!(
+        ctx.get_events().last(),
+        Some(IbcEvent::AcknowledgePacketV2(_))
+    ));
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment_v2(&commitment_path)
+        .is_err());
+}
+
+#[test]
+fn test_send_packet_without_counterparty() {
+    let mut ctx = ctx_with_clients();
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+    let err = ctx
+        .dispatch(send_packet_msg(
+            timeout_timestamp,
+            vec![payload(PortId::transfer())],
+        ))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("missing counterparty"), "{err}");
+}
+
+#[test]
+fn test_send_packet_invalid_timeout() {
+    let mut ctx = ctx_with_counterparties();
+
+    let now = ctx.latest_timestamp().nanoseconds() / NANOS_PER_SECOND;
+
+    let err = ctx
+        .dispatch(send_packet_msg(now - 1, vec![payload(PortId::transfer())]))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::ExpiredPacketTimestamp)
+        ),
+        "{err}"
+    );
+
+    let err = ctx
+        .dispatch(send_packet_msg(
+            now + 2 * 24 * 60 * 60,
+            vec![payload(PortId::transfer())],
+        ))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::ExcessivePacketTimeout { .. })
+        ),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_send_packet_unknown_port() {
+    let mut ctx = ctx_with_counterparties();
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+    let port_id = PortId::new("unknown".to_string()).expect("no error");
+
+    let err = ctx
+        .dispatch(send_packet_msg(timeout_timestamp, vec![payload(port_id)]))
+        .unwrap_err();
+    assert!(
+        matches!(err, HandlerError::Channel(ChannelError::MissingModule(_))),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_recv_packet_mismatched_counterparty() {
+    let mut ctx = ctx_with_counterparties();
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+    let mut packet = sent_packet(1, timeout_timestamp, vec![payload(PortId::transfer())]);
+    packet.source_client = ClientId::new(mock_client_type().as_str(), 2).expect("no error");
+
+    let err = ctx.dispatch(recv_packet_msg(&ctx, packet)).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::MismatchedCounterpartyClient { .. })
+        ),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_recv_packet_failure_and_async() {
+    let failing_port = PortId::new("failing".to_string()).expect("no error");
+    let async_port = PortId::new("async".to_string()).expect("no error");
+
+    let ctx = ctx_with_counterparties();
+    let ctx = with_status_module(ctx, &failing_port, PacketStatus::Failure);
+    let mut ctx = with_status_module(ctx, &async_port, PacketStatus::Async);
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+
+    // A failed payload gets the packet acknowledged with the error
+    // acknowledgement
+    let packet = sent_packet(1, timeout_timestamp, vec![payload(failing_port)]);
+    ctx.dispatch(recv_packet_msg(&ctx, packet.clone()))
+        .expect("no error");
+    assert_eq!(
+        stored_ack(&ctx, &packet),
+        compute_ack_commitment(&Acknowledgement::error())
+    );
+
+    // No acknowledgement is written for asynchronous payloads
+    let packet = sent_packet(2, timeout_timestamp, vec![payload(async_port.clone())]);
+    ctx.dispatch(recv_packet_msg(&ctx, packet.clone()))
+        .expect("no error");
+    assert!(ctx
+        .ibc_store
+        .get_packet_acknowledgement_v2(&AckPath::new(&client_b(), packet.sequence))
+        .is_err());
+}
+
+#[test]
+fn test_packet_with_several_payloads() {
+    let mut ctx = ctx_with_counterparties();
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+    let payloads = vec![payload(PortId::transfer()), payload(PortId::transfer())];
+
+    let err = ctx
+        .dispatch(send_packet_msg(timeout_timestamp, payloads.clone()))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::InvalidPayload { .. })
+        ),
+        "{err}"
+    );
+
+    // Such packets are not received either, as their payloads could not be
+    // received atomically.
+    let packet = sent_packet(1, timeout_timestamp, payloads);
+    let err = ctx
+        .dispatch(recv_packet_msg(&ctx, packet.clone()))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::InvalidPayload { .. })
+        ),
+        "{err}"
+    );
+    assert!(!ctx
+        .ibc_store
+        .get_packet_receipt_v2(&ReceiptPath::new(&client_b(), packet.sequence))
+        .expect("no error")
+        .is_ok());
+}
+
+#[test]
+fn test_ack_packet_mismatched_acknowledgements() {
+    let mut ctx = ctx_with_counterparties();
+
+    let timeout_timestamp = timeout_timestamp(&ctx);
+    let payloads = vec![payload(PortId::transfer())];
+
+    ctx.dispatch(send_packet_msg(timeout_timestamp, payloads.clone()))
+        .expect("no error");
+
+    let packet = sent_packet(1, timeout_timestamp, payloads);
+
+    let err = ctx
+        .dispatch(ack_packet_msg(
+            &ctx,
+            packet.clone(),
+            Acknowledgement::new(vec![vec![1u8], vec![1u8]]),
+        ))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::InvalidAcknowledgement { .. })
+        ),
+        "{err}"
+    );
+
+    // The error acknowledgement is delivered to the payload
+    ctx.dispatch(ack_packet_msg(&ctx, packet, Acknowledgement::error()))
+        .expect("no error");
+}
+
+#[test]
+fn test_timeout_packet() {
+    let mut ctx = ctx_with_counterparties();
+
+    let timeout_timestamp = ctx.latest_timestamp().nanoseconds() / NANOS_PER_SECOND + 5;
+    let payloads = vec![payload(PortId::transfer())];
+
+    ctx.dispatch(send_packet_msg(timeout_timestamp, payloads.clone()))
+        .expect("no error");
+
+    let packet = sent_packet(1, timeout_timestamp, payloads);
+
+    // The packet has not timed out yet
+    let err = ctx
+        .dispatch(timeout_packet_msg(&ctx, packet.clone()))
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            HandlerError::Channel(ChannelError::UnexpiredPacket { .. })
+        ),
+        "{err}"
+    );
+
+    for _ in 0..3 {
+        ctx.advance_block_height();
+    }
+
+    // The packet can no longer be received
+    assert!(ctx.dispatch(recv_packet_msg(&ctx, packet.clone())).is_err());
+
+    // Update the client to a consensus state past the timeout
+    let light_client_a = ctx.generate_light_client(vec![], &Default::default());
+    let mut ctx = ctx.with_light_client(&client_a(), light_client_a);
+
+    ctx.dispatch(timeout_packet_msg(&ctx, packet.clone()))
+        .expect("no error");
+
+    assert!(matches!(
+        ctx.get_events().last(),
+        Some(IbcEvent::TimeoutPacketV2(_))
+    ));
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment_v2(&CommitmentPath::new(&client_a(), packet.sequence))
+        .is_err());
+}
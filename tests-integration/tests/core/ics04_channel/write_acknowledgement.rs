@@ -0,0 +1,225 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::handler::write_acknowledgement;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::execute;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ReceiptPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, ZERO_DURATION};
+use ibc::core::router::middleware::{Middleware, StackBuilder};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::{MockRouter, ModuleState};
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+
+/// A middleware which withholds the acknowledgements of the packets it
+/// receives, as modules processing them asynchronously do.
+#[derive(Clone, Debug)]
+struct AsyncAckMiddleware<M> {
+    next: M,
+}
+
+impl<M: ModuleState> ModuleState for AsyncAckMiddleware<M> {
+    fn state(&self) -> BTreeMap<String, String> {
+        self.next.state()
+    }
+}
+
+impl<M: Module> Middleware for AsyncAckMiddleware<M> {
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.next
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.next
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, _) = self.next.on_recv_packet_execute(packet, relayer);
+        (extras, None)
+    }
+}
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgRecvPacket,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let context = MockContext::default();
+    let client_height = context.latest_height().increment().increment();
+
+    let msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+        .unwrap();
+    let packet = msg.packet.clone();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(packet.port_id_on_a, Some(packet.chan_id_on_a)),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::empty(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let context = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(packet.port_id_on_b, packet.chan_id_on_b, chan_end_on_b);
+
+    let mut router = MockRouter::default();
+    router
+        .add_stack(
+            ModuleId::new(MODULE_ID_STR.to_string()),
+            StackBuilder::new(PortId::transfer(), DummyTransferModule::new())
+                .wrap(|next| AsyncAckMiddleware { next }),
+        )
+        .unwrap();
+
+    Fixture {
+        context,
+        router,
+        msg,
+    }
+}
+
+fn pending_acknowledgements(ctx: &MockContext, packet: &Packet) -> Vec<u64> {
+    let chan_end_path = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+
+    ctx.ibc_store
+        .pending_acknowledgements(&chan_end_path)
+        .unwrap()
+        .into_iter()
+        .map(u64::from)
+        .collect()
+}
+
+#[rstest]
+fn write_acknowledgement_after_recv_packet(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture;
+    let packet = msg.packet.clone();
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    execute(&mut context.ibc_store, &mut router, msg_env).unwrap();
+
+    assert!(!context
+        .get_events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+    assert_eq!(
+        pending_acknowledgements(&context, &packet),
+        vec![u64::from(packet.seq_on_a)]
+    );
+
+    let acknowledgement = Acknowledgement::try_from(vec![1u8]).unwrap();
+    write_acknowledgement(
+        &mut context.ibc_store,
+        packet.clone(),
+        acknowledgement.clone(),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        context.get_events().last(),
+        Some(IbcEvent::WriteAcknowledgement(_))
+    ));
+    assert!(pending_acknowledgements(&context, &packet).is_empty());
+
+    // The acknowledgement may only be written once.
+    let res = write_acknowledgement(&mut context.ibc_store, packet, acknowledgement);
+    assert!(matches!(res, Err(ChannelError::DuplicateAcknowledgment(_))));
+}
+
+#[rstest]
+fn write_acknowledgement_fails_for_unreceived_packet(fixture: Fixture) {
+    let Fixture {
+        mut context, msg, ..
+    } = fixture;
+
+    let res = write_acknowledgement(
+        &mut context.ibc_store,
+        msg.packet,
+        Acknowledgement::try_from(vec![1u8]).unwrap(),
+    );
+
+    assert!(matches!(
+        res,
+        Err(ChannelError::InvalidAcknowledgement { .. })
+    ));
+}
+
+#[rstest]
+fn write_acknowledgement_fails_for_packet_not_deferred(fixture: Fixture) {
+    let Fixture {
+        mut context, msg, ..
+    } = fixture;
+    let packet = msg.packet;
+
+    // The packet was received, but its acknowledgement was not deferred by
+    // the module.
+    let receipt_path =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    context
+        .ibc_store
+        .store_packet_receipt(&receipt_path, Receipt::Ok)
+        .unwrap();
+
+    let res = write_acknowledgement(
+        &mut context.ibc_store,
+        packet,
+        Acknowledgement::try_from(vec![1u8]).unwrap(),
+    );
+
+    assert!(matches!(
+        res,
+        Err(ChannelError::InvalidAcknowledgement { .. })
+    ));
+}
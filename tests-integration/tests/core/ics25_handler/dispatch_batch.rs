@@ -0,0 +1,179 @@
+use ibc::core::channel::types::msgs::PacketMsg;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::ClientMsg;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::connection::types::State as ConnectionState;
+use ibc::core::entrypoint::dispatch_batch;
+use ibc::core::handler::types::error::BatchError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::ReceiptPath;
+use ibc::core::host::ValidationContext;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::clients::mock::dummy_msg_create_mock_client;
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id,
+};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use test_log::test;
+
+use super::{ctx_with_recv_packet, transfer_module, transfer_router};
+
+#[test]
+fn dispatch_batch_happy_path() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_id = mock_client_type().build_client_id(0);
+
+    // The connection is opened on top of the client created by the first
+    // message of the same batch.
+    let msgs = vec![
+        MsgEnvelope::from(ClientMsg::from(dummy_msg_create_mock_client(
+            ctx.ibc_store.host_timestamp().unwrap(),
+        ))),
+        MsgEnvelope::from(ConnectionMsg::from(
+            dummy_msg_conn_open_init_with_client_id(dummy_msg_conn_open_init(), client_id.clone()),
+        )),
+    ];
+
+    let res = dispatch_batch(&mut ctx.ibc_store, &mut router, msgs);
+
+    let events = res.expect("batch should succeed");
+
+    assert_eq!(events.len(), 2);
+    assert!(events[0]
+        .iter()
+        .any(|event| matches!(event, IbcEvent::CreateClient(_))));
+    assert!(events[1]
+        .iter()
+        .any(|event| matches!(event, IbcEvent::OpenInitConnection(_))));
+
+    assert_eq!(ctx.get_events(), events.concat());
+
+    assert_eq!(ctx.ibc_store.client_counter().unwrap(), 1);
+    assert_eq!(ctx.ibc_store.connection_counter().unwrap(), 1);
+    assert!(ctx.ibc_store.client_state(&client_id).is_ok());
+
+    let conn_end = ctx.ibc_store.connection_end(&ConnectionId::new(0)).unwrap();
+
+    assert_eq!(conn_end.state(), &ConnectionState::Init);
+    assert_eq!(conn_end.client_id(), &client_id);
+}
+
+#[test]
+fn dispatch_batch_rolls_back_on_failure() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_id = mock_client_type().build_client_id(0);
+
+    // The second message refers to a client that does not exist, even after
+    // the first one is processed.
+    let msgs = vec![
+        MsgEnvelope::from(ClientMsg::from(dummy_msg_create_mock_client(
+            ctx.ibc_store.host_timestamp().unwrap(),
+        ))),
+        MsgEnvelope::from(ConnectionMsg::from(
+            dummy_msg_conn_open_init_with_client_id(
+                dummy_msg_conn_open_init(),
+                mock_client_type().build_client_id(1),
+            ),
+        )),
+    ];
+
+    let res = dispatch_batch(&mut ctx.ibc_store, &mut router, msgs);
+
+    assert!(
+        matches!(res, Err(BatchError::Message { index: 1, .. })),
+        "the second message should fail: {res:?}"
+    );
+
+    assert!(ctx.get_events().is_empty());
+    assert!(ctx.get_logs().is_empty());
+
+    assert_eq!(ctx.ibc_store.client_counter().unwrap(), 0);
+    assert_eq!(ctx.ibc_store.connection_counter().unwrap(), 0);
+    assert!(ctx.ibc_store.client_state(&client_id).is_err());
+}
+
+#[test]
+fn dispatch_batch_packet_messages() {
+    let (mut ctx, msg) = ctx_with_recv_packet();
+    let mut router = transfer_router();
+
+    let packet = msg.packet.clone();
+    let receipt_path =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    // The packet is relayed along with a client message, as relayers do.
+    let msgs = vec![
+        MsgEnvelope::from(ClientMsg::from(dummy_msg_create_mock_client(
+            ctx.ibc_store.host_timestamp().unwrap(),
+        ))),
+        MsgEnvelope::from(PacketMsg::from(msg)),
+    ];
+
+    let res = dispatch_batch(&mut ctx.ibc_store, &mut router, msgs);
+
+    let events = res.expect("batch should succeed");
+
+    assert!(events[1]
+        .iter()
+        .any(|event| matches!(event, IbcEvent::ReceivePacket(_))));
+    assert_eq!(ctx.get_events(), events.concat());
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_receipt(&receipt_path)
+        .unwrap()
+        .is_ok());
+
+    let transfer = transfer_module(&router);
+
+    assert!(!transfer.voucher_supply.is_empty());
+}
+
+#[test]
+fn dispatch_batch_rolls_back_module_state() {
+    let (mut ctx, msg) = ctx_with_recv_packet();
+    let mut router = transfer_router();
+
+    let packet = msg.packet.clone();
+    let receipt_path =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    // The transfer module mints the vouchers of the packet before the second
+    // message fails.
+    let msgs = vec![
+        MsgEnvelope::from(PacketMsg::from(msg)),
+        MsgEnvelope::from(ConnectionMsg::from(
+            dummy_msg_conn_open_init_with_client_id(
+                dummy_msg_conn_open_init(),
+                mock_client_type().build_client_id(1),
+            ),
+        )),
+    ];
+
+    let res = dispatch_batch(&mut ctx.ibc_store, &mut router, msgs);
+
+    assert!(
+        matches!(res, Err(BatchError::Message { index: 1, .. })),
+        "the second message should fail: {res:?}"
+    );
+
+    assert!(ctx.get_events().is_empty());
+    assert!(ctx.get_logs().is_empty());
+    assert!(!ctx
+        .ibc_store
+        .get_packet_receipt(&receipt_path)
+        .unwrap()
+        .is_ok());
+
+    let transfer = transfer_module(&router);
+
+    assert!(transfer.voucher_supply.is_empty());
+    assert!(transfer.denom_traces.is_empty());
+}
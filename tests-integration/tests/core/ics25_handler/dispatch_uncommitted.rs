@@ -0,0 +1,168 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::types::msgs::PacketMsg;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::ClientMsg;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::entrypoint::dispatch_uncommitted;
+use ibc::core::handler::cache::StoreKey;
+use ibc::core::handler::types::error::BatchError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{
+    ClientConnectionPath, ClientStatePath, ConnectionPath, NextClientSequencePath,
+    NextConnectionSequencePath, ReceiptPath,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::router::types::module::ModuleId;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::clients::mock::dummy_msg_create_mock_client;
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id,
+};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use test_log::test;
+
+use super::{ctx_with_recv_packet, transfer_module, transfer_router};
+
+#[test]
+fn dispatch_uncommitted_happy_path() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_id = mock_client_type().build_client_id(0);
+
+    let msgs = vec![
+        MsgEnvelope::from(ClientMsg::from(dummy_msg_create_mock_client(
+            ctx.ibc_store.host_timestamp().unwrap(),
+        ))),
+        MsgEnvelope::from(ConnectionMsg::from(
+            dummy_msg_conn_open_init_with_client_id(dummy_msg_conn_open_init(), client_id.clone()),
+        )),
+    ];
+
+    let cache = dispatch_uncommitted(&ctx.ibc_store, &mut router, msgs)
+        .expect("dispatching should succeed");
+
+    assert!(cache
+        .events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::CreateClient(_))));
+    assert!(cache
+        .events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::OpenInitConnection(_))));
+
+    let written_keys = cache.written_keys();
+
+    for key in [
+        StoreKey::Path(NextClientSequencePath.into()),
+        StoreKey::Path(NextConnectionSequencePath.into()),
+        StoreKey::Path(ClientStatePath::new(client_id.clone()).into()),
+        StoreKey::Path(ConnectionPath::new(&ConnectionId::new(0)).into()),
+        StoreKey::Path(ClientConnectionPath::new(client_id.clone()).into()),
+    ] {
+        assert!(written_keys.contains(&key), "{key:?} should be written");
+    }
+
+    // Nothing reaches the context until the cache is committed.
+    assert!(ctx.get_events().is_empty());
+    assert_eq!(ctx.ibc_store.client_counter().unwrap(), 0);
+    assert!(ctx.ibc_store.client_state(&client_id).is_err());
+
+    let events = cache.events().to_vec();
+
+    cache.commit(&mut ctx.ibc_store).unwrap();
+
+    assert_eq!(ctx.get_events(), events);
+    assert_eq!(ctx.ibc_store.connection_counter().unwrap(), 1);
+    assert!(ctx.ibc_store.client_state(&client_id).is_ok());
+}
+
+#[test]
+fn dispatch_uncommitted_packet_message() {
+    let (ctx, msg) = ctx_with_recv_packet();
+    let mut router = transfer_router();
+
+    let packet = msg.packet.clone();
+    let receipt_path =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    let msgs = vec![MsgEnvelope::from(PacketMsg::from(msg))];
+
+    let cache = dispatch_uncommitted(&ctx.ibc_store, &mut router, msgs)
+        .expect("dispatching should succeed");
+
+    assert!(cache
+        .events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::ReceivePacket(_))));
+
+    let written_keys = cache.written_keys();
+
+    assert!(written_keys.contains(&StoreKey::Path(receipt_path.clone().into())));
+
+    // The keys of the vouchers the transfer module would mint are reported
+    // as well.
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+
+    for key in [
+        "denomTraces/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        "voucherSupply/transfer/channel-0/uatom",
+    ] {
+        let key = StoreKey::Module {
+            module_id: module_id.clone(),
+            key: key.as_bytes().to_vec(),
+        };
+
+        assert!(written_keys.contains(&key), "{key:?} should be written");
+    }
+
+    // Neither the context nor the transfer module are written to.
+    assert!(ctx.get_events().is_empty());
+    assert!(!ctx
+        .ibc_store
+        .get_packet_receipt(&receipt_path)
+        .unwrap()
+        .is_ok());
+
+    let transfer = transfer_module(&router);
+
+    assert!(transfer.voucher_supply.is_empty());
+    assert!(transfer.denom_traces.is_empty());
+}
+
+#[test]
+fn dispatch_uncommitted_reports_failing_message() {
+    let (ctx, msg) = ctx_with_recv_packet();
+    let mut router = transfer_router();
+
+    // The packet is received before the second message fails.
+    let msgs = vec![
+        MsgEnvelope::from(PacketMsg::from(msg)),
+        MsgEnvelope::from(ConnectionMsg::from(
+            dummy_msg_conn_open_init_with_client_id(
+                dummy_msg_conn_open_init(),
+                mock_client_type().build_client_id(1),
+            ),
+        )),
+    ];
+
+    let err = match dispatch_uncommitted(&ctx.ibc_store, &mut router, msgs) {
+        Ok(_) => core::panic!("the second message should fail"),
+        Err(err) => err,
+    };
+
+    assert!(
+        matches!(err.error, BatchError::Message { index: 1, .. }),
+        "the second message should fail: {err:?}"
+    );
+    assert!(err
+        .events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::ReceivePacket(_))));
+    assert!(!err.logs.is_empty());
+
+    assert!(transfer_module(&router).voucher_supply.is_empty());
+}
@@ -0,0 +1,144 @@
+use ibc::apps::transfer::module::on_recv_packet_execute;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::MsgRecvPacket;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, ZERO_DURATION};
+use ibc::core::router::middleware::{Middleware, StackBuilder};
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::{MockRouter, ModuleState};
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+
+pub mod dispatch_batch;
+pub mod dispatch_uncommitted;
+
+/// A middleware which receives packets through the ICS-20 handlers, against
+/// the state of the transfer module it wraps.
+#[derive(Clone, Debug)]
+pub struct TransferMiddleware {
+    pub next: DummyTransferModule,
+}
+
+impl ModuleState for TransferMiddleware {
+    fn state(&self) -> BTreeMap<String, String> {
+        self.next.state()
+    }
+}
+
+impl Middleware for TransferMiddleware {
+    type Next = DummyTransferModule;
+
+    fn next(&self) -> &DummyTransferModule {
+        &self.next
+    }
+
+    fn next_mut(&mut self) -> &mut DummyTransferModule {
+        &mut self.next
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = on_recv_packet_execute(&mut self.next, packet);
+        (extras, Some(ack))
+    }
+}
+
+/// Returns a router binding the transfer port to a [`TransferMiddleware`].
+pub fn transfer_router() -> MockRouter {
+    let mut router = MockRouter::default();
+
+    router
+        .add_stack(
+            ModuleId::new(MODULE_ID_STR.to_string()),
+            StackBuilder::new(PortId::transfer(), DummyTransferModule::new())
+                .wrap(|next| TransferMiddleware { next }),
+        )
+        .unwrap();
+
+    router
+}
+
+/// Returns the transfer module of a router returned by [`transfer_router`].
+pub fn transfer_module(router: &MockRouter) -> &DummyTransferModule {
+    &router
+        .module::<TransferMiddleware>(&ModuleId::new(MODULE_ID_STR.to_string()))
+        .unwrap()
+        .next
+}
+
+/// Sets up the receiving end of a transfer channel, along with a message
+/// relaying a packet which transfers `100uatom` over it.
+pub fn ctx_with_recv_packet() -> (MockContext, MsgRecvPacket) {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = MockContext::default().latest_height().increment();
+
+    let mut msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+            .unwrap();
+
+    let data = PacketData {
+        token: "100uatom".parse().unwrap(),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+
+    msg.packet.data = serde_json::to_vec(&data).unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::empty(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            msg.packet.port_id_on_a.clone(),
+            Some(msg.packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end)
+        .with_channel(
+            msg.packet.port_id_on_b.clone(),
+            msg.packet.chan_id_on_b.clone(),
+            chan_end,
+        );
+
+    (ctx, msg)
+}
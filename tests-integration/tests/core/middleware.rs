@@ -0,0 +1,117 @@
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::PortId;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::{Middleware, StackBuilder};
+use ibc::core::router::module::Module;
+use ibc::core::router::router::Router;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc_testkit::fixtures::core::channel::dummy_packet;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::{MockRouter, ModuleState};
+
+/// A middleware which appends its tag to the packets and acknowledgements
+/// going through it, and logs the packets it receives.
+#[derive(Clone, Debug)]
+struct TaggingMiddleware<M> {
+    next: M,
+    tag: u8,
+}
+
+impl<M: Module> TaggingMiddleware<M> {
+    fn new(next: M, tag: u8) -> Self {
+        Self { next, tag }
+    }
+}
+
+impl<M: ModuleState> ModuleState for TaggingMiddleware<M> {
+    fn state(&self) -> BTreeMap<String, String> {
+        self.next.state()
+    }
+}
+
+impl<M: Module> Middleware for TaggingMiddleware<M> {
+    type Next = M;
+
+    fn next(&self) -> &M {
+        &self.next
+    }
+
+    fn next_mut(&mut self) -> &mut M {
+        &mut self.next
+    }
+
+    fn on_send_packet(&mut self, mut packet: Packet) -> Result<Packet, ChannelError> {
+        packet.data.push(self.tag);
+        Ok(packet)
+    }
+
+    fn on_write_acknowledgement(
+        &mut self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, ChannelError> {
+        let mut bytes: Vec<u8> = acknowledgement.into();
+        bytes.push(self.tag);
+        Ok(bytes.try_into().expect("never empty"))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (mut extras, ack) = self.next.on_recv_packet_execute(packet, relayer);
+        extras.log.push(format!("tag {}", self.tag));
+        (extras, ack)
+    }
+}
+
+#[test]
+fn test_stack_callbacks_go_up_and_packets_go_down() {
+    let (port_id, mut stack) = StackBuilder::new(PortId::transfer(), DummyTransferModule::new())
+        .wrap(|app| TaggingMiddleware::new(app, 1))
+        .wrap(|next| TaggingMiddleware::new(next, 2))
+        .build();
+
+    assert_eq!(port_id, PortId::transfer());
+
+    let packet = dummy_packet().call();
+    let relayer: Signer = "cosmos1relayer".to_string().into();
+
+    // Callbacks unwind from the application, through the innermost middleware.
+    let (extras, ack) = Module::on_recv_packet_execute(&mut stack, &packet, &relayer);
+    assert_eq!(extras.log, vec!["tag 1".to_string(), "tag 2".to_string()]);
+    assert!(ack.is_some());
+
+    // Packets go through the innermost middleware first.
+    let sent = stack.send_packet(packet.clone()).unwrap();
+    assert_eq!(sent.data, vec![1, 2]);
+
+    let ack = stack
+        .write_acknowledgement(&packet, vec![0].try_into().unwrap())
+        .unwrap();
+    assert_eq!(ack.as_bytes(), &[0, 1, 2]);
+}
+
+#[test]
+fn test_stack_registered_with_router() {
+    let module_id = ModuleId::new("tagged-transfer".to_string());
+    let stack = StackBuilder::new(PortId::transfer(), DummyTransferModule::new())
+        .wrap(|app| TaggingMiddleware::new(app, 1));
+
+    let mut router = MockRouter::default();
+    router.add_stack(module_id.clone(), stack).unwrap();
+
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(module_id.clone())
+    );
+
+    let module = router.get_route_mut(&module_id).unwrap();
+    let sent = module.send_packet(dummy_packet().call()).unwrap();
+
+    assert_eq!(sent.data, vec![1]);
+}
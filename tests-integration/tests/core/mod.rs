@@ -2,4 +2,7 @@ pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
 #[cfg(feature = "serde")]
+pub mod ics25_handler;
+pub mod middleware;
+#[cfg(feature = "serde")]
 pub mod router;
@@ -21,6 +21,7 @@ use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
+use ibc::core::router::router::Router;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::applications::transfer::{
     dummy_msg_transfer, dummy_packet_data, extract_transfer_packet,
@@ -167,10 +168,7 @@ fn routing_module_and_keepers() {
     msg_to_on_close.packet.timeout_height_on_b = msg_transfer_two.timeout_height_on_b;
     msg_to_on_close.packet.timeout_timestamp_on_b = msg_transfer_two.timeout_timestamp_on_b;
 
-    let packet_data = serde_json::to_vec(&msg_transfer_two.packet_data)
-        .expect("PacketData's infallible Serialize impl failed");
-
-    msg_to_on_close.packet.data = packet_data;
+    msg_to_on_close.packet.data = extract_transfer_packet(&msg_transfer_two, 2u64.into()).data;
 
     let msg_recv_packet = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35)).unwrap();
     let msg_ack_packet = MsgAcknowledgement::try_from(dummy_raw_msg_ack_with_packet(
@@ -205,7 +203,7 @@ fn routing_module_and_keepers() {
     );
     let client_id = match client_id_event.unwrap() {
         IbcEvent::CreateClient(create_client) => create_client.client_id().clone(),
-        event => panic!("unexpected IBC event: {:?}", event),
+        event => core::panic!("unexpected IBC event: {:?}", event),
     };
 
     let tests: Vec<Test> = vec![
@@ -400,11 +398,21 @@ fn routing_module_and_keepers() {
     for test in tests {
         let res = match test.msg.clone() {
             TestMsg::Ics26(msg) => dispatch(&mut ctx.ibc_store, &mut router, msg),
-            TestMsg::Ics20(msg) => send_transfer(&mut ctx.ibc_store, &mut DummyTransferModule, msg)
+            TestMsg::Ics20(msg) => {
+                let module_id = router.lookup_module(&msg.port_id_on_a).expect("bound port");
+                let module = router.get_route_mut(&module_id).expect("registered module");
+
+                send_transfer(
+                    &mut ctx.ibc_store,
+                    &mut DummyTransferModule::new(),
+                    module,
+                    msg,
+                )
                 .map_err(|e| ChannelError::AppSpecific {
                     description: format!("token transfer application error: {e}"),
                 })
-                .map_err(HandlerError::from),
+                .map_err(HandlerError::from)
+            }
         };
 
         assert_eq!(